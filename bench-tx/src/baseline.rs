@@ -0,0 +1,181 @@
+use std::{
+    collections::BTreeMap,
+    fs::{read_to_string, write},
+    path::Path,
+};
+
+use serde::Deserialize;
+use serde_json::{from_str, to_string_pretty, Map, Value};
+
+use crate::{
+    benchmarks::Benchmark,
+    report::{BenchmarkResult, Stages},
+};
+
+// BASELINE
+// ================================================================================================
+
+/// Cycle counts of previously recorded benchmark runs, keyed by benchmark name.
+///
+/// Baselines are stored in the same JSON format as the one written via `--output`; only the cycle
+/// counts are used for comparisons.
+pub struct Baseline {
+    benchmarks: BTreeMap<String, Stages<u32>>,
+}
+
+#[derive(Deserialize)]
+struct BaselineEntry {
+    cycles: Stages<u32>,
+}
+
+impl Baseline {
+    /// Reads the baseline from the specified JSON file.
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let baseline_file = read_to_string(path)
+            .map_err(|e| format!("failed to read baseline {}: {e}", path.display()))?;
+        let entries: BTreeMap<String, BaselineEntry> = from_str(&baseline_file)
+            .map_err(|e| format!("failed to parse baseline {}: {e}", path.display()))?;
+
+        let benchmarks = entries.into_iter().map(|(name, entry)| (name, entry.cycles)).collect();
+        Ok(Self { benchmarks })
+    }
+
+    /// Stores the results of the provided benchmarks in the specified JSON file.
+    ///
+    /// If the file already exists, the results of benchmarks which were not run are preserved.
+    pub fn save(path: &Path, results: &[(Benchmark, BenchmarkResult)]) -> Result<(), String> {
+        let mut baseline_json = match path.exists() {
+            true => {
+                let baseline_file = read_to_string(path).map_err(|e| e.to_string())?;
+                from_str::<Map<String, Value>>(&baseline_file)
+                    .map_err(|e| format!("failed to parse baseline {}: {e}", path.display()))?
+            },
+            false => Map::new(),
+        };
+
+        for (benchmark, result) in results {
+            let result_json = serde_json::to_value(result).map_err(|e| e.to_string())?;
+            baseline_json.insert(benchmark.to_string(), result_json);
+        }
+
+        write(
+            path,
+            to_string_pretty(&Value::Object(baseline_json))
+                .expect("failed to convert json to String"),
+        )
+        .map_err(|e| e.to_string())
+    }
+
+    /// Compares the cycle counts of the provided benchmark result against this baseline.
+    ///
+    /// Returns `None` if the baseline does not contain the specified benchmark.
+    pub fn compare(
+        &self,
+        benchmark: Benchmark,
+        result: &BenchmarkResult,
+        threshold: f64,
+    ) -> Option<Comparison> {
+        let baseline = self.benchmarks.get(&benchmark.to_string())?;
+
+        let mut stages = vec![
+            StageComparison::new("prologue", baseline.prologue, result.cycles.prologue),
+            StageComparison::new(
+                "notes_processing",
+                baseline.notes_processing,
+                result.cycles.notes_processing,
+            ),
+        ];
+
+        // notes are matched by their IDs; notes present in only one of the runs are listed, but
+        // cannot regress
+        let mut note_ids = baseline.note_execution.keys().collect::<Vec<_>>();
+        note_ids.extend(result.cycles.note_execution.keys());
+        note_ids.sort();
+        note_ids.dedup();
+        for note_id in note_ids {
+            stages.push(StageComparison::new(
+                format!("  note {}", &note_id[..12]),
+                baseline.note_execution.get(note_id).copied().flatten(),
+                result.cycles.note_execution.get(note_id).copied().flatten(),
+            ));
+        }
+
+        stages.extend([
+            StageComparison::new(
+                "tx_script_processing",
+                baseline.tx_script_processing,
+                result.cycles.tx_script_processing,
+            ),
+            StageComparison::new("epilogue", baseline.epilogue, result.cycles.epilogue),
+            StageComparison::new("total", Some(baseline.total), Some(result.cycles.total)),
+        ]);
+
+        Some(Comparison { stages, threshold })
+    }
+}
+
+// COMPARISON
+// ================================================================================================
+
+/// Comparison of the cycle counts of a benchmark against its baseline.
+pub struct Comparison {
+    stages: Vec<StageComparison>,
+    threshold: f64,
+}
+
+impl Comparison {
+    /// Returns the names of the stages whose cycle counts increased by more than the threshold
+    /// (in percent).
+    pub fn regressions(&self) -> Vec<&str> {
+        self.stages
+            .iter()
+            .filter(|stage| stage.change().is_some_and(|change| change > self.threshold))
+            .map(|stage| stage.name.trim())
+            .collect()
+    }
+
+    /// Prints the comparison as a table with a row for each stage of the transaction kernel.
+    pub fn print(&self) {
+        println!("  {:<24} {:>10} {:>10} {:>10}", "stage", "baseline", "cycles", "change");
+
+        for stage in self.stages.iter() {
+            let baseline = stage.baseline.map_or("-".to_string(), |cycles| cycles.to_string());
+            let current = stage.current.map_or("-".to_string(), |cycles| cycles.to_string());
+            let (change, status) = match stage.change() {
+                Some(change) if change > self.threshold => (format!("{change:+.2}%"), "regressed"),
+                Some(change) => (format!("{change:+.2}%"), ""),
+                None => ("-".to_string(), ""),
+            };
+            let row =
+                format!("  {:<24} {baseline:>10} {current:>10} {change:>10} {status}", stage.name);
+            println!("{}", row.trim_end());
+        }
+        println!();
+    }
+}
+
+/// Cycle counts of a single stage in the baseline and in the current run.
+struct StageComparison {
+    name: String,
+    baseline: Option<u32>,
+    current: Option<u32>,
+}
+
+impl StageComparison {
+    fn new(name: impl Into<String>, baseline: Option<u32>, current: Option<u32>) -> Self {
+        Self { name: name.into(), baseline, current }
+    }
+
+    /// Returns the change of the cycle count relative to the baseline (in percent), or `None` if
+    /// the stage is missing from either of the runs.
+    fn change(&self) -> Option<f64> {
+        match (self.baseline, self.current) {
+            (Some(0), Some(0)) => Some(0.0),
+            (Some(0), Some(_)) => Some(f64::INFINITY),
+            (Some(baseline), Some(current)) => {
+                Some((current as f64 - baseline as f64) / baseline as f64 * 100.0)
+            },
+            _ => None,
+        }
+    }
+}
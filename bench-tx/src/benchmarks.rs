@@ -0,0 +1,315 @@
+use core::fmt;
+use std::rc::Rc;
+
+use miden_lib::notes::{create_p2id_note, create_swap_note};
+use miden_objects::{
+    accounts::AccountId,
+    assembly::ProgramAst,
+    assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    crypto::rand::RpoRandomCoin,
+    notes::{Note, NoteTag, NoteType},
+    testing::DeterministicRng,
+    transaction::{PreparedTransaction, TransactionArgs},
+    Felt, Word, ZERO,
+};
+use miden_tx::{host::BasicAuthenticator, TransactionExecutor};
+use mock::{builders::TransactionContextBuilder, utils::prepare_word};
+
+use crate::utils::{
+    get_account_with_default_account_code, get_faucet_account_with_max_supply_and_total_issuance,
+    get_new_pk_and_authenticator, get_note_with_fungible_asset_and_script, MockDataStore,
+    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+    ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
+};
+
+// BENCHMARK
+// ================================================================================================
+
+/// Transactions which can be benchmarked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Benchmark {
+    Simple,
+    P2ID,
+    P2IDMultiple(usize),
+    Swap,
+    FaucetMint,
+    FaucetBurn,
+}
+
+impl Benchmark {
+    /// All available benchmarks, in the order in which they are run.
+    pub const ALL: [Benchmark; 8] = [
+        Benchmark::Simple,
+        Benchmark::P2ID,
+        Benchmark::P2IDMultiple(16),
+        Benchmark::P2IDMultiple(64),
+        Benchmark::P2IDMultiple(256),
+        Benchmark::Swap,
+        Benchmark::FaucetMint,
+        Benchmark::FaucetBurn,
+    ];
+
+    /// Returns a short description of the transaction executed by this benchmark.
+    pub fn description(&self) -> String {
+        match self {
+            Benchmark::Simple => {
+                "default transaction with empty script and two default notes".to_string()
+            },
+            Benchmark::P2ID => "basic wallet consuming a single P2ID note".to_string(),
+            Benchmark::P2IDMultiple(num_notes) => {
+                format!("basic wallet consuming {num_notes} P2ID notes")
+            },
+            Benchmark::Swap => "basic wallet consuming a SWAP note".to_string(),
+            Benchmark::FaucetMint => "fungible faucet minting an asset into a note".to_string(),
+            Benchmark::FaucetBurn => "fungible faucet burning the asset of a note".to_string(),
+        }
+    }
+
+    /// Prepares the transaction executed by this benchmark.
+    ///
+    /// Preparation (i.e., loading the account and compiling the transaction program) is performed
+    /// once per benchmark and is not included in the measurements.
+    pub fn prepare(&self) -> Result<BenchmarkTransaction, String> {
+        match self {
+            Benchmark::Simple => prepare_default_tx(),
+            Benchmark::P2ID => prepare_p2id(),
+            Benchmark::P2IDMultiple(num_notes) => prepare_p2id_multiple(*num_notes),
+            Benchmark::Swap => prepare_swap(),
+            Benchmark::FaucetMint => prepare_faucet_mint(),
+            Benchmark::FaucetBurn => prepare_faucet_burn(),
+        }
+    }
+}
+
+impl fmt::Display for Benchmark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Benchmark::Simple => write!(f, "simple"),
+            Benchmark::P2ID => write!(f, "p2id"),
+            Benchmark::P2IDMultiple(num_notes) => write!(f, "p2id-{num_notes}"),
+            Benchmark::Swap => write!(f, "swap"),
+            Benchmark::FaucetMint => write!(f, "faucet-mint"),
+            Benchmark::FaucetBurn => write!(f, "faucet-burn"),
+        }
+    }
+}
+
+/// A prepared transaction together with the authenticator required to execute it.
+pub struct BenchmarkTransaction {
+    pub transaction: PreparedTransaction,
+    pub authenticator: Option<Rc<BasicAuthenticator<DeterministicRng>>>,
+}
+
+// BENCHMARKS
+// ================================================================================================
+
+/// Prepares the default transaction with empty transaction script and two default notes.
+fn prepare_default_tx() -> Result<BenchmarkTransaction, String> {
+    prepare_transaction(MockDataStore::default(), None, None)
+}
+
+/// Prepares the transaction which consumes a P2ID note into a basic wallet.
+fn prepare_p2id() -> Result<BenchmarkTransaction, String> {
+    let (target_pub_key, authenticator) = get_new_pk_and_authenticator();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let note = create_p2id_notes(target_account_id, 1).remove(0);
+
+    let data_store = MockDataStore::with_existing(Some(target_account), Some(vec![note]));
+    prepare_transaction(data_store, Some(DEFAULT_AUTH_SCRIPT), Some(authenticator))
+}
+
+/// Prepares the transaction which consumes the specified number of P2ID notes into a basic
+/// wallet.
+fn prepare_p2id_multiple(num_notes: usize) -> Result<BenchmarkTransaction, String> {
+    let (target_pub_key, authenticator) = get_new_pk_and_authenticator();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let notes = create_p2id_notes(target_account_id, num_notes);
+
+    // the default mock chain can only hold a few notes, and thus the notes are recorded in a
+    // block of a dedicated chain
+    let tx_context = TransactionContextBuilder::new(target_account)
+        .block_num(4)
+        .input_notes(notes)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let data_store = MockDataStore::from(tx_context);
+    prepare_transaction(data_store, Some(DEFAULT_AUTH_SCRIPT), Some(authenticator))
+}
+
+/// Prepares the transaction which consumes a SWAP note into a basic wallet holding the requested
+/// asset.
+fn prepare_swap() -> Result<BenchmarkTransaction, String> {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let offered_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let requested_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(faucet_id_2, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    // Create sender and target account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let (target_pub_key, authenticator) = get_new_pk_and_authenticator();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let target_account = get_account_with_default_account_code(
+        target_account_id,
+        target_pub_key,
+        Some(requested_asset),
+    );
+
+    // Create the note containing the SWAP script
+    let (note, _) = create_swap_note(
+        sender_account_id,
+        offered_asset,
+        requested_asset,
+        NoteType::Public,
+        ZERO,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .map_err(|e| e.to_string())?;
+
+    let data_store = MockDataStore::with_existing(Some(target_account), Some(vec![note]));
+    prepare_transaction(data_store, Some(DEFAULT_AUTH_SCRIPT), Some(authenticator))
+}
+
+/// Prepares the transaction in which a fungible faucet mints an asset into a new note.
+fn prepare_faucet_mint() -> Result<BenchmarkTransaction, String> {
+    let (faucet_pub_key, authenticator) = get_new_pk_and_authenticator();
+    let faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, None);
+
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let tag = NoteTag::for_local_use_case(0, 0).unwrap();
+    let note_type = NoteType::OffChain;
+    let amount = Felt::new(100);
+
+    let tx_script = format!(
+        "
+        use.miden::contracts::faucets::basic_fungible->faucet
+        use.miden::contracts::auth::basic->auth_tx
+
+        begin
+            push.{recipient}
+            push.{note_type}
+            push.{tag}
+            push.{amount}
+            call.faucet::distribute
+
+            call.auth_tx::auth_tx_rpo_falcon512
+            dropw dropw
+        end
+        ",
+        note_type = note_type as u8,
+        recipient = prepare_word(&recipient),
+        tag = u32::from(tag),
+    );
+
+    let data_store = MockDataStore::with_existing(Some(faucet_account), Some(vec![]));
+    prepare_transaction(data_store, Some(&tx_script), Some(authenticator))
+}
+
+/// Prepares the transaction in which a fungible faucet consumes a note burning the asset of the
+/// note.
+fn prepare_faucet_burn() -> Result<BenchmarkTransaction, String> {
+    let (faucet_pub_key, _) = get_new_pk_and_authenticator();
+    let faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, Some(100));
+
+    let fungible_asset = FungibleAsset::new(faucet_account.id(), 100).unwrap();
+    let note_script = ProgramAst::parse(
+        "
+        use.miden::contracts::faucets::basic_fungible->faucet_contract
+        use.miden::note
+
+        # burn the asset
+        begin
+            dropw
+            exec.note::get_assets drop
+            mem_loadw
+            call.faucet_contract::burn
+        end
+        ",
+    )
+    .unwrap();
+    let note = get_note_with_fungible_asset_and_script(fungible_asset, note_script);
+
+    let data_store = MockDataStore::with_existing(Some(faucet_account), Some(vec![note]));
+    prepare_transaction(data_store, None, None)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Prepares the transaction against the account of the provided data store which consumes all
+/// notes of the data store.
+///
+/// If a transaction script is provided, it replaces the transaction arguments of the data store.
+fn prepare_transaction(
+    data_store: MockDataStore,
+    tx_script: Option<&str>,
+    authenticator: Option<Rc<BasicAuthenticator<DeterministicRng>>>,
+) -> Result<BenchmarkTransaction, String> {
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None).with_tracing();
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).map_err(|e| e.to_string())?;
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_args = match tx_script {
+        Some(tx_script) => {
+            let tx_script_code = ProgramAst::parse(tx_script).map_err(|e| e.to_string())?;
+            let tx_script = executor
+                .compile_tx_script(tx_script_code, vec![], vec![])
+                .map_err(|e| e.to_string())?;
+            TransactionArgs::with_tx_script(tx_script)
+        },
+        None => data_store.tx_args().clone(),
+    };
+
+    let transaction = executor
+        .prepare_transaction(account_id, block_ref, &note_ids, tx_args)
+        .map_err(|e| e.to_string())?;
+
+    Ok(BenchmarkTransaction { transaction, authenticator })
+}
+
+/// Returns the specified number of P2ID notes sent to the target account, each carrying a
+/// fungible asset.
+fn create_p2id_notes(target_account_id: AccountId, num_notes: usize) -> Vec<Note> {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    (0..num_notes as u64)
+        .map(|idx| {
+            // each note is created with a different serial number
+            let seed: Word = [Felt::new(1 + idx), Felt::new(2), Felt::new(3), Felt::new(4)];
+            create_p2id_note(
+                sender_account_id,
+                target_account_id,
+                vec![fungible_asset],
+                NoteType::Public,
+                ZERO,
+                RpoRandomCoin::new(seed),
+            )
+            .unwrap()
+        })
+        .collect()
+}
@@ -1,175 +1,308 @@
-use core::fmt;
+//! Benchmarks of the Miden transaction kernel.
+//!
+//! Each benchmark prepares a transaction once, and then executes it a number of times, measuring
+//! the number of VM cycles and the wall-clock time spent in each stage of the transaction kernel.
+//! Optionally, each execution is also proven, measuring the proving time, the size of the proof,
+//! and the peak heap usage.
+//!
+//! The stages of the last measured execution can also be exported in the Chrome trace-event and
+//! folded-stack formats, to be inspected in standard profiling UIs.
+
 use std::{
-    fs::{read_to_string, write, File},
-    io::Write,
-    path::Path,
-    rc::Rc,
+    fs::{create_dir_all, write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Instant,
 };
 
-use miden_lib::{
-    notes::create_p2id_note, transaction::ToTransactionKernelInputs, utils::Serializable,
-};
-use miden_objects::{
-    accounts::{AccountId, AuthSecretKey},
-    assembly::ProgramAst,
-    assets::{Asset, FungibleAsset},
-    crypto::{dsa::rpo_falcon512::SecretKey, rand::RpoRandomCoin},
-    notes::NoteType,
-    transaction::TransactionArgs,
-    Felt,
-};
-use miden_tx::{
-    host::BasicAuthenticator, TransactionExecutor, TransactionHost, TransactionProgress,
-};
-use rand::rngs::StdRng;
-use vm_processor::{ExecutionOptions, RecAdviceProvider, Word};
+use clap::Parser;
+use miden_lib::transaction::ToTransactionKernelInputs;
+use miden_objects::transaction::TransactionWitness;
+use miden_tx::{ProvingOptions, TransactionHost, TransactionProgress, TransactionProver};
+use vm_processor::{ExecutionOptions, RecAdviceProvider};
+
+mod baseline;
+use baseline::Baseline;
+
+mod benchmarks;
+use benchmarks::{Benchmark, BenchmarkTransaction};
+
+mod memory;
+use memory::{peak_memory, reset_peak_memory, TrackingAllocator};
+
+mod report;
+use report::{write_results_to_json, BenchmarkResult, ProvingSample, Sample, Stages};
+
+mod timing;
+use timing::TimingHost;
 
 mod utils;
-use utils::{
-    get_account_with_default_account_code, write_bench_results_to_json, MockDataStore,
-    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
-    ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
-};
-pub enum Benchmark {
-    Simple,
-    P2ID,
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+// CLI
+// ================================================================================================
+
+#[derive(Parser, Debug)]
+#[command(name = "bench-tx", about = "Benchmarks the execution of Miden transactions")]
+struct Cli {
+    /// Run only the benchmarks whose names contain any of the provided filters.
+    filters: Vec<String>,
+
+    /// List the available benchmarks and exit.
+    #[arg(long)]
+    list: bool,
+
+    /// Number of executions of each benchmark performed before the measurements start.
+    #[arg(long, default_value_t = 1)]
+    warmup: u32,
+
+    /// Number of measured executions of each benchmark.
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    /// Prove each measured execution, measuring the proving time, the proof size, and the peak
+    /// heap usage.
+    #[arg(long)]
+    prove: bool,
+
+    /// Write the results to the specified JSON file.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Export the stages of the last measured execution of each benchmark to the specified
+    /// directory, as `<benchmark>.trace.json` (Chrome trace-event format) and `<benchmark>.folded`
+    /// (folded-stack format) files.
+    #[arg(long, value_name = "DIR")]
+    trace_dir: Option<PathBuf>,
+
+    /// Compare the cycle counts of each stage against the baseline stored in the specified JSON
+    /// file, and fail if any of them regressed.
+    #[arg(long, value_name = "FILE")]
+    baseline: Option<PathBuf>,
+
+    /// Store the results in the baseline file instead of comparing against it.
+    #[arg(long, requires = "baseline")]
+    save_baseline: bool,
+
+    /// Maximum increase of the cycle count of a stage (in percent) which is not considered a
+    /// regression.
+    #[arg(long, default_value_t = 0.0)]
+    threshold: f64,
 }
 
-impl fmt::Display for Benchmark {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Benchmark::Simple => write!(f, "simple"),
-            Benchmark::P2ID => write!(f, "p2id"),
-        }
+impl Cli {
+    /// Returns the benchmarks selected by the filters; all benchmarks are selected if no filters
+    /// were provided.
+    fn benchmarks(&self) -> Vec<Benchmark> {
+        Benchmark::ALL
+            .into_iter()
+            .filter(|benchmark| {
+                let name = benchmark.to_string();
+                self.filters.is_empty() || self.filters.iter().any(|filter| name.contains(filter))
+            })
+            .collect()
     }
-}
 
-fn main() -> Result<(), String> {
-    // create a template file for benchmark results
-    let path = Path::new("bench-tx/bench-tx.json");
-    let mut file = File::create(path).map_err(|e| e.to_string())?;
-    file.write_all(b"{}").map_err(|e| e.to_string())?;
+    fn execute(&self) -> Result<(), String> {
+        if self.list {
+            for benchmark in Benchmark::ALL {
+                println!("{:<14} {}", benchmark.to_string(), benchmark.description());
+            }
+            return Ok(());
+        }
 
-    // run all available benchmarks
-    let benchmark_results = vec![
-        (Benchmark::Simple, benchmark_default_tx()?),
-        (Benchmark::P2ID, benchmark_p2id()?),
-    ];
+        let benchmarks = self.benchmarks();
+        if benchmarks.is_empty() {
+            return Err(format!("no benchmarks match filters {:?}", self.filters));
+        }
 
-    // store benchmark results in the JSON file
-    write_bench_results_to_json(path, benchmark_results)?;
+        // the baseline is read before running the benchmarks so that an invalid baseline is
+        // reported right away
+        let baseline = match &self.baseline {
+            Some(path) if !self.save_baseline => Some(Baseline::read(path)?),
+            _ => None,
+        };
 
-    Ok(())
+        let mut results = Vec::with_capacity(benchmarks.len());
+        let mut regressions = Vec::new();
+        for benchmark in benchmarks {
+            let (result, tx_progress) =
+                run_benchmark(benchmark, self.warmup, self.iterations, self.prove)
+                    .map_err(|err| format!("benchmark {benchmark} failed: {err}"))?;
+            result.print(benchmark);
+
+            if let Some(dir) = &self.trace_dir {
+                write_traces(dir, benchmark, &tx_progress)?;
+            }
+
+            if let Some(baseline) = &baseline {
+                match baseline.compare(benchmark, &result, self.threshold) {
+                    Some(comparison) => {
+                        println!("{benchmark}: comparison against baseline");
+                        comparison.print();
+                        regressions.extend(
+                            comparison
+                                .regressions()
+                                .into_iter()
+                                .map(|stage| format!("{benchmark}/{stage}")),
+                        );
+                    },
+                    None => println!("{benchmark}: no baseline found\n"),
+                }
+            }
+
+            results.push((benchmark, result));
+        }
+
+        if let Some(path) = &self.output {
+            write_results_to_json(path, &results)?;
+            println!("results written to {}", path.display());
+        }
+
+        if let Some(path) = self.baseline.as_ref().filter(|_| self.save_baseline) {
+            Baseline::save(path, &results)?;
+            println!("baseline saved to {}", path.display());
+        }
+
+        if !regressions.is_empty() {
+            return Err(format!(
+                "cycle counts regressed by more than {}%: {}",
+                self.threshold,
+                regressions.join(", ")
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().execute() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        },
+    }
 }
 
-// BENCHMARKS
+// BENCHMARK RUNNER
 // ================================================================================================
 
-/// Runs the default transaction with empty transaction script and two default notes.
-pub fn benchmark_default_tx() -> Result<TransactionProgress, String> {
-    let data_store = MockDataStore::default();
-    let mut executor: TransactionExecutor<_, ()> =
-        TransactionExecutor::new(data_store.clone(), None).with_tracing();
+/// Prepares the transaction of the specified benchmark, and then executes it `warmup` times
+/// followed by `iterations` measured times; if `prove` is set, each measured execution is also
+/// proven.
+///
+/// Returns the benchmark result together with the progress of the last measured execution.
+fn run_benchmark(
+    benchmark: Benchmark,
+    warmup: u32,
+    iterations: u32,
+    prove: bool,
+) -> Result<(BenchmarkResult, TransactionProgress), String> {
+    let tx = benchmark.prepare()?;
+    let prover = prove.then(|| TransactionProver::new(ProvingOptions::default()));
 
-    let account_id = data_store.account.id();
-    executor.load_account(account_id).map_err(|e| e.to_string())?;
+    for _ in 0..warmup {
+        execute_transaction(&tx, None)?;
+    }
 
-    let block_ref = data_store.block_header.block_num();
-    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let samples = (0..iterations)
+        .map(|_| execute_transaction(&tx, prover.as_ref()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let tx_progress = samples.last().map(|sample| sample.tx_progress.clone()).unwrap_or_default();
 
-    let transaction = executor
-        .prepare_transaction(account_id, block_ref, &note_ids, data_store.tx_args().clone())
-        .map_err(|e| e.to_string())?;
+    Ok((BenchmarkResult::new(samples)?, tx_progress))
+}
 
-    let (stack_inputs, advice_inputs) = transaction.get_kernel_inputs();
+/// Executes the prepared transaction, measuring the cycles and the wall-clock time spent in each
+/// stage of the transaction kernel.
+///
+/// If a prover is provided, the executed transaction is then proven.
+fn execute_transaction(
+    tx: &BenchmarkTransaction,
+    prover: Option<&TransactionProver>,
+) -> Result<Sample, String> {
+    let (stack_inputs, advice_inputs) = tx.transaction.get_kernel_inputs();
     let advice_recorder: RecAdviceProvider = advice_inputs.into();
-    let mut host: TransactionHost<_, ()> =
-        TransactionHost::new(transaction.account().into(), advice_recorder, None);
+    let host = TransactionHost::new(
+        tx.transaction.account().into(),
+        advice_recorder,
+        tx.authenticator.clone(),
+    );
+    let mut host = TimingHost::new(host);
 
-    vm_processor::execute(
-        transaction.program(),
+    let start = Instant::now();
+    let trace = vm_processor::execute(
+        tx.transaction.program(),
         stack_inputs,
         &mut host,
         ExecutionOptions::default().with_tracing(),
     )
     .map_err(|e| e.to_string())?;
+    let total_time = start.elapsed();
 
-    Ok(host.tx_progress().clone())
-}
+    let tx_progress = host.inner().tx_progress().clone();
+    let total_cycles = trace.trace_len_summary().main_trace_len() as u32;
+    let cycles = Stages::cycles(&tx_progress, total_cycles);
+    let times = Stages::times(&tx_progress, host.stage_times(), total_time);
 
-/// Runs the transaction which consumes a P2ID note into a basic wallet.
-pub fn benchmark_p2id() -> Result<TransactionProgress, String> {
-    // Create assets
-    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
-    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
-
-    // Create sender and target account
-    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
-
-    let target_account_id =
-        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
-    let sec_key = SecretKey::new();
-    let target_pub_key: Word = sec_key.public_key().into();
-    let mut pk_sk_bytes = sec_key.to_bytes();
-    pk_sk_bytes.append(&mut target_pub_key.to_bytes());
-    let target_sk_pk_felt: Vec<Felt> =
-        pk_sk_bytes.iter().map(|a| Felt::new(*a as u64)).collect::<Vec<Felt>>();
-    let target_account =
-        get_account_with_default_account_code(target_account_id, target_pub_key, None);
-
-    // Create the note
-    let note = create_p2id_note(
-        sender_account_id,
-        target_account_id,
-        vec![fungible_asset],
-        NoteType::Public,
-        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
-    )
-    .unwrap();
+    let proving = match prover {
+        Some(prover) => {
+            // build the witness from the advice recorded during the execution, including the
+            // signatures generated by the authenticator
+            let (advice_recorder, _, _, generated_signatures) = host.into_inner().into_parts();
+            let (mut advice_witness, ..) = advice_recorder.finalize();
+            advice_witness.extend_map(generated_signatures);
+            let tx_witness = TransactionWitness::new(
+                tx.transaction.program().clone(),
+                tx.transaction.tx_inputs().clone(),
+                tx.transaction.tx_args().clone(),
+                advice_witness,
+            );
 
-    let data_store =
-        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note.clone()]));
+            Some(prove_transaction(prover, tx_witness)?)
+        },
+        None => None,
+    };
 
-    let mut executor: TransactionExecutor<_, ()> =
-        TransactionExecutor::new(data_store.clone(), None).with_tracing();
-    executor.load_account(target_account_id).unwrap();
-
-    let block_ref = data_store.block_header.block_num();
-    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    Ok(Sample { cycles, times, tx_progress, proving })
+}
 
-    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+/// Proves the transaction, measuring the proving time, the proof size, and the peak heap usage.
+fn prove_transaction(
+    prover: &TransactionProver,
+    tx_witness: TransactionWitness,
+) -> Result<ProvingSample, String> {
+    reset_peak_memory();
+    let start = Instant::now();
+    let proven_tx = prover.prove_transaction(tx_witness).map_err(|e| e.to_string())?;
+    let time = start.elapsed();
+    let peak_memory = peak_memory();
 
-    let tx_script_target = executor
-        .compile_tx_script(
-            tx_script_code.clone(),
-            vec![(target_pub_key, target_sk_pk_felt)],
-            vec![],
-        )
-        .unwrap();
-    let tx_args_target = TransactionArgs::with_tx_script(tx_script_target);
+    Ok(ProvingSample {
+        time,
+        proof_size: proven_tx.proof().to_bytes().len(),
+        peak_memory,
+    })
+}
 
-    // execute transaction
-    let transaction = executor
-        .prepare_transaction(target_account_id, block_ref, &note_ids, tx_args_target)
-        .map_err(|e| e.to_string())?;
+/// Writes the stages of the provided transaction progress to the specified directory, in the
+/// Chrome trace-event and folded-stack formats.
+fn write_traces(
+    dir: &Path,
+    benchmark: Benchmark,
+    tx_progress: &TransactionProgress,
+) -> Result<(), String> {
+    create_dir_all(dir).map_err(|e| e.to_string())?;
 
-    let (stack_inputs, advice_inputs) = transaction.get_kernel_inputs();
-    let advice_recorder: RecAdviceProvider = advice_inputs.into();
-    let authenticator = BasicAuthenticator::<StdRng>::new(&[(
-        sec_key.public_key().into(),
-        AuthSecretKey::RpoFalcon512(sec_key),
-    )]);
-    let authenticator = Some(Rc::new(authenticator));
-    let mut host =
-        TransactionHost::new(transaction.account().into(), advice_recorder, authenticator);
-
-    vm_processor::execute(
-        transaction.program(),
-        stack_inputs,
-        &mut host,
-        ExecutionOptions::default().with_tracing(),
-    )
-    .map_err(|e| e.to_string())?;
+    let trace_path = dir.join(format!("{benchmark}.trace.json"));
+    write(&trace_path, tx_progress.to_chrome_trace()).map_err(|e| e.to_string())?;
+    let folded_path = dir.join(format!("{benchmark}.folded"));
+    write(&folded_path, tx_progress.to_folded_stacks()).map_err(|e| e.to_string())?;
 
-    Ok(host.tx_progress().clone())
+    println!("traces written to {} and {}\n", trace_path.display(), folded_path.display());
+    Ok(())
 }
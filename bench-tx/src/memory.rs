@@ -0,0 +1,71 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Number of bytes currently allocated on the heap.
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+/// Maximum number of bytes allocated on the heap since the last reset.
+static PEAK_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+// TRACKING ALLOCATOR
+// ================================================================================================
+
+/// A global allocator which forwards all requests to the [System] allocator, while keeping track
+/// of the current and peak heap usage.
+pub struct TrackingAllocator;
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            match new_size > layout.size() {
+                true => record_allocation(new_size - layout.size()),
+                false => {
+                    ALLOCATED.fetch_sub(layout.size() - new_size, Ordering::Relaxed);
+                },
+            }
+        }
+        new_ptr
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Resets the peak heap usage to the current heap usage.
+pub fn reset_peak_memory() {
+    PEAK_ALLOCATED.store(ALLOCATED.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+/// Returns the maximum number of bytes allocated on the heap since the last reset.
+pub fn peak_memory() -> usize {
+    PEAK_ALLOCATED.load(Ordering::Relaxed)
+}
+
+fn record_allocation(size: usize) {
+    let allocated = ALLOCATED.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_ALLOCATED.fetch_max(allocated, Ordering::Relaxed);
+}
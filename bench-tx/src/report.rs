@@ -0,0 +1,311 @@
+use std::{collections::BTreeMap, fs::write, path::Path, time::Duration};
+
+use miden_tx::TransactionProgress;
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::{to_string_pretty, Map, Value};
+
+use crate::{benchmarks::Benchmark, timing::StageTimes};
+
+// STAGES
+// ================================================================================================
+
+/// Measurements associated with each stage of the transaction kernel.
+///
+/// Note executions are keyed by the hex-encoded IDs of the executed notes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Stages<T> {
+    pub prologue: Option<T>,
+    pub notes_processing: Option<T>,
+    pub note_execution: BTreeMap<String, Option<T>>,
+    pub tx_script_processing: Option<T>,
+    pub epilogue: Option<T>,
+    pub total: T,
+}
+
+impl<T> Stages<T> {
+    /// Returns the stages with `f` applied to each of the measurements.
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Stages<U> {
+        Stages {
+            prologue: self.prologue.map(&f),
+            notes_processing: self.notes_processing.map(&f),
+            note_execution: self
+                .note_execution
+                .into_iter()
+                .map(|(note_id, value)| (note_id, value.map(&f)))
+                .collect(),
+            tx_script_processing: self.tx_script_processing.map(&f),
+            epilogue: self.epilogue.map(&f),
+            total: f(self.total),
+        }
+    }
+
+    /// Returns the labels and measurements of all stages, in the order of their execution.
+    pub fn rows(&self) -> Vec<(String, Option<&T>)> {
+        let mut rows = vec![
+            ("prologue".to_string(), self.prologue.as_ref()),
+            ("notes_processing".to_string(), self.notes_processing.as_ref()),
+        ];
+        for (note_id, value) in self.note_execution.iter() {
+            rows.push((format!("  note {}", &note_id[..12]), value.as_ref()));
+        }
+        rows.push(("tx_script_processing".to_string(), self.tx_script_processing.as_ref()));
+        rows.push(("epilogue".to_string(), self.epilogue.as_ref()));
+        rows.push(("total".to_string(), Some(&self.total)));
+        rows
+    }
+}
+
+impl Stages<u32> {
+    /// Returns the number of cycles spent in each stage of the provided transaction.
+    pub fn cycles(tx_progress: &TransactionProgress, total: u32) -> Self {
+        Self {
+            prologue: tx_progress.prologue().len(),
+            notes_processing: tx_progress.notes_processing().len(),
+            note_execution: tx_progress
+                .note_execution()
+                .iter()
+                .map(|(note_id, interval)| (note_id.to_hex(), interval.len()))
+                .collect(),
+            tx_script_processing: tx_progress.tx_script_processing().len(),
+            epilogue: tx_progress.epilogue().len(),
+            total,
+        }
+    }
+}
+
+impl Stages<Duration> {
+    /// Returns the wall-clock time spent in each stage of the provided transaction.
+    pub fn times(
+        tx_progress: &TransactionProgress,
+        stage_times: &StageTimes,
+        total: Duration,
+    ) -> Self {
+        Self {
+            prologue: stage_times.prologue,
+            notes_processing: stage_times.notes_processing,
+            note_execution: tx_progress
+                .note_execution()
+                .iter()
+                .zip(stage_times.note_execution.iter())
+                .map(|((note_id, _), time)| (note_id.to_hex(), *time))
+                .collect(),
+            tx_script_processing: stage_times.tx_script_processing,
+            epilogue: stage_times.epilogue,
+            total,
+        }
+    }
+}
+
+impl Stages<Vec<Duration>> {
+    /// Appends the measurements of a single iteration to the collected measurements.
+    fn push(&mut self, sample: Stages<Duration>) {
+        fn push_value(values: &mut Option<Vec<Duration>>, value: Option<Duration>) {
+            if let Some(value) = value {
+                values.get_or_insert_with(Vec::new).push(value);
+            }
+        }
+
+        push_value(&mut self.prologue, sample.prologue);
+        push_value(&mut self.notes_processing, sample.notes_processing);
+        for (note_id, value) in sample.note_execution {
+            push_value(self.note_execution.entry(note_id).or_default(), value);
+        }
+        push_value(&mut self.tx_script_processing, sample.tx_script_processing);
+        push_value(&mut self.epilogue, sample.epilogue);
+        self.total.push(sample.total);
+    }
+}
+
+// TIME SUMMARY
+// ================================================================================================
+
+/// Summary of the wall-clock times measured over several iterations.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct TimeSummary {
+    #[serde(rename = "mean_us", serialize_with = "serialize_micros")]
+    pub mean: Duration,
+    #[serde(rename = "min_us", serialize_with = "serialize_micros")]
+    pub min: Duration,
+    #[serde(rename = "max_us", serialize_with = "serialize_micros")]
+    pub max: Duration,
+}
+
+impl TimeSummary {
+    /// Returns the summary of the provided measurements.
+    ///
+    /// # Panics
+    /// Panics if no measurements are provided.
+    pub fn new(times: &[Duration]) -> Self {
+        let total: Duration = times.iter().sum();
+        Self {
+            mean: total / times.len() as u32,
+            min: *times.iter().min().expect("no measurements"),
+            max: *times.iter().max().expect("no measurements"),
+        }
+    }
+}
+
+fn serialize_micros<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_micros())
+}
+
+// SAMPLE
+// ================================================================================================
+
+/// Measurements of a single execution of a benchmarked transaction.
+pub struct Sample {
+    pub cycles: Stages<u32>,
+    pub times: Stages<Duration>,
+    pub tx_progress: TransactionProgress,
+    pub proving: Option<ProvingSample>,
+}
+
+/// Measurements of a single proof generation for a benchmarked transaction.
+pub struct ProvingSample {
+    pub time: Duration,
+    pub proof_size: usize,
+    pub peak_memory: usize,
+}
+
+// PROVING RESULT
+// ================================================================================================
+
+/// Results of proving a benchmarked transaction over several iterations.
+///
+/// The proof size is taken from the last iteration, while the peak memory is the maximum over all
+/// iterations.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProvingResult {
+    pub time: TimeSummary,
+    #[serde(rename = "proof_size_bytes")]
+    pub proof_size: usize,
+    #[serde(rename = "peak_memory_bytes")]
+    pub peak_memory: usize,
+}
+
+impl ProvingResult {
+    /// Returns the result summarizing the provided samples, or `None` if no samples are provided.
+    fn new(samples: &[ProvingSample]) -> Option<Self> {
+        let last = samples.last()?;
+        let times = samples.iter().map(|sample| sample.time).collect::<Vec<_>>();
+
+        Some(Self {
+            time: TimeSummary::new(&times),
+            proof_size: last.proof_size,
+            peak_memory: samples.iter().map(|sample| sample.peak_memory).max().unwrap_or_default(),
+        })
+    }
+}
+
+// BENCHMARK RESULT
+// ================================================================================================
+
+/// Results of a benchmark measured over several iterations.
+///
+/// The number of cycles is deterministic, and thus is taken from the last iteration. Proving
+/// results are present only if the transaction was proven in each iteration.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkResult {
+    pub iterations: usize,
+    pub cycles: Stages<u32>,
+    pub wall_clock: Stages<TimeSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proving: Option<ProvingResult>,
+}
+
+impl BenchmarkResult {
+    /// Returns the result summarizing the provided samples.
+    ///
+    /// # Errors
+    /// Returns an error if no samples are provided.
+    pub fn new(samples: Vec<Sample>) -> Result<Self, String> {
+        let iterations = samples.len();
+
+        let mut cycles = None;
+        let mut times = Stages::<Vec<Duration>>::default();
+        let mut proving = Vec::new();
+        for sample in samples {
+            cycles = Some(sample.cycles);
+            times.push(sample.times);
+            proving.extend(sample.proving);
+        }
+        let cycles = cycles.ok_or("benchmark must be run at least once")?;
+
+        Ok(Self {
+            iterations,
+            cycles,
+            wall_clock: times.map(|times| TimeSummary::new(&times)),
+            proving: ProvingResult::new(&proving),
+        })
+    }
+
+    /// Prints the results as a table with a row for each stage of the transaction kernel.
+    pub fn print(&self, benchmark: Benchmark) {
+        println!("{benchmark}: {} ({} iterations)", benchmark.description(), self.iterations);
+        println!("  {:<24} {:>10} {:>12} {:>12} {:>12}", "stage", "cycles", "mean", "min", "max");
+
+        for ((stage, cycles), (_, time)) in
+            self.cycles.rows().into_iter().zip(self.wall_clock.rows())
+        {
+            let cycles = cycles.map_or("-".to_string(), u32::to_string);
+            let (mean, min, max) = match time {
+                Some(time) => {
+                    (format_time(time.mean), format_time(time.min), format_time(time.max))
+                },
+                None => ("-".to_string(), "-".to_string(), "-".to_string()),
+            };
+            println!("  {stage:<24} {cycles:>10} {mean:>12} {min:>12} {max:>12}");
+        }
+
+        if let Some(proving) = &self.proving {
+            println!(
+                "  {:<24} {:>10} {:>12} {:>12} {:>12}",
+                "proving",
+                "-",
+                format_time(proving.time.mean),
+                format_time(proving.time.min),
+                format_time(proving.time.max)
+            );
+            println!("  proof size: {}", format_bytes(proving.proof_size));
+            println!("  peak memory: {}", format_bytes(proving.peak_memory));
+        }
+        println!();
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the results of the provided benchmarks to the specified JSON file.
+pub fn write_results_to_json(
+    path: &Path,
+    results: &[(Benchmark, BenchmarkResult)],
+) -> Result<(), String> {
+    let mut results_json = Map::new();
+    for (benchmark, result) in results {
+        let result_json = serde_json::to_value(result).map_err(|e| e.to_string())?;
+        results_json.insert(benchmark.to_string(), result_json);
+    }
+
+    write(
+        path,
+        to_string_pretty(&Value::Object(results_json)).expect("failed to convert json to String"),
+    )
+    .map_err(|e| e.to_string())
+}
+
+fn format_time(time: Duration) -> String {
+    format!("{time:.3?}")
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.2} {} ({bytes} bytes)", UNITS[unit])
+}
@@ -0,0 +1,138 @@
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use miden_lib::transaction::TransactionTrace;
+use vm_processor::{
+    AdviceExtractor, AdviceInjector, ExecutionError, Host, HostResponse, ProcessState,
+};
+
+// TIMING HOST
+// ================================================================================================
+
+/// A [Host] which measures the wall-clock time spent in each stage of the transaction kernel.
+///
+/// All requests are forwarded to the wrapped host; the kernel stage boundaries are detected via
+/// the [TransactionTrace] events emitted by the kernel, and thus are only recorded when the
+/// program is executed with tracing enabled.
+pub struct TimingHost<H> {
+    host: H,
+    stage_starts: BTreeMap<u32, Instant>,
+    stage_times: StageTimes,
+}
+
+impl<H: Host> TimingHost<H> {
+    /// Returns a new [TimingHost] wrapping the provided host.
+    pub fn new(host: H) -> Self {
+        Self {
+            host,
+            stage_starts: BTreeMap::new(),
+            stage_times: StageTimes::default(),
+        }
+    }
+
+    /// Returns the wrapped host.
+    pub fn inner(&self) -> &H {
+        &self.host
+    }
+
+    /// Consumes this host and returns the wrapped host.
+    pub fn into_inner(self) -> H {
+        self.host
+    }
+
+    /// Returns the wall-clock time measured for each kernel stage.
+    pub fn stage_times(&self) -> &StageTimes {
+        &self.stage_times
+    }
+
+    /// Returns the time elapsed between the specified stage start event and `end`.
+    fn stage_time(&mut self, start: TransactionTrace, end: Instant) -> Option<Duration> {
+        self.stage_starts.remove(&(start as u32)).map(|start| end.duration_since(start))
+    }
+}
+
+impl<H: Host> Host for TimingHost<H> {
+    fn get_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        extractor: AdviceExtractor,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.get_advice(process, extractor)
+    }
+
+    fn set_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        injector: AdviceInjector,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.set_advice(process, injector)
+    }
+
+    fn on_event<S: ProcessState>(
+        &mut self,
+        process: &S,
+        event_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.on_event(process, event_id)
+    }
+
+    fn on_trace<S: ProcessState>(
+        &mut self,
+        process: &S,
+        trace_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        let now = Instant::now();
+
+        // stages may be nested (i.e., note executions happen within the notes processing stage),
+        // and thus the start of each stage is tracked separately
+        use TransactionTrace::*;
+        match TransactionTrace::try_from(trace_id) {
+            Ok(
+                PrologueStart
+                | NotesProcessingStart
+                | NoteExecutionStart
+                | TxScriptProcessingStart
+                | EpilogueStart,
+            ) => {
+                self.stage_starts.insert(trace_id, now);
+            },
+            Ok(PrologueEnd) => self.stage_times.prologue = self.stage_time(PrologueStart, now),
+            Ok(NotesProcessingEnd) => {
+                self.stage_times.notes_processing = self.stage_time(NotesProcessingStart, now)
+            },
+            Ok(NoteExecutionEnd) => {
+                let time = self.stage_time(NoteExecutionStart, now);
+                self.stage_times.note_execution.push(time);
+            },
+            Ok(TxScriptProcessingEnd) => {
+                self.stage_times.tx_script_processing =
+                    self.stage_time(TxScriptProcessingStart, now)
+            },
+            Ok(EpilogueEnd) => self.stage_times.epilogue = self.stage_time(EpilogueStart, now),
+            Err(_) => (),
+        }
+
+        self.host.on_trace(process, trace_id)
+    }
+
+    fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
+        self.host.on_assert_failed(process, err_code)
+    }
+}
+
+// STAGE TIMES
+// ================================================================================================
+
+/// Wall-clock time spent in each stage of the transaction kernel.
+///
+/// Note executions are listed in the order in which the notes were executed.
+#[derive(Debug, Clone, Default)]
+pub struct StageTimes {
+    pub prologue: Option<Duration>,
+    pub notes_processing: Option<Duration>,
+    pub note_execution: Vec<Option<Duration>>,
+    pub tx_script_processing: Option<Duration>,
+    pub epilogue: Option<Duration>,
+}
@@ -1,30 +1,36 @@
-extern crate alloc;
-pub use alloc::collections::BTreeMap;
+use std::rc::Rc;
 
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::transaction::{memory::FAUCET_STORAGE_DATA_SLOT, TransactionKernel};
 use miden_objects::{
-    accounts::{Account, AccountCode, AccountId, AccountStorage, SlotItem, StorageSlot},
-    assembly::ModuleAst,
-    assets::{Asset, AssetVault},
-    notes::{Note, NoteId},
-    transaction::{ChainMmr, InputNote, InputNotes, OutputNote, TransactionArgs},
-    BlockHeader, Felt, Word,
+    accounts::{
+        Account, AccountCode, AccountId, AccountStorage, AuthSecretKey, SlotItem, StorageSlot,
+    },
+    assembly::{ModuleAst, ProgramAst},
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::dsa::rpo_falcon512::SecretKey,
+    notes::{
+        Note, NoteAssets, NoteId, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteType,
+    },
+    testing::DeterministicRng,
+    transaction::{ChainMmr, InputNote, OutputNote, TransactionArgs},
+    BlockHeader, Felt, Word, ZERO,
 };
-use miden_tx::{DataStore, DataStoreError, TransactionInputs, TransactionProgress};
-use mock::mock::{
-    account::MockAccountType,
-    notes::AssetPreservationStatus,
-    transaction::{mock_inputs, mock_inputs_with_existing},
+use miden_tx::{host::BasicAuthenticator, DataStore, DataStoreError};
+use mock::{
+    builders::TransactionContext,
+    mock::{
+        account::MockAccountType,
+        notes::AssetPreservationStatus,
+        transaction::{mock_inputs, mock_inputs_with_existing},
+    },
 };
-use serde::Serialize;
-use serde_json::{from_str, to_string_pretty, Value};
-
-use super::{read_to_string, write, Benchmark, Path};
 
 // CONSTANTS
 // ================================================================================================
 
 pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN: u64 = 0x200000000000001F; // 2305843009213693983
+pub const ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN: u64 = 0xA00000000000001F; // 11529215046068469791
+pub const ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN: u64 = 0x300000000000002F; // 3458764513820540975
 pub const ACCOUNT_ID_SENDER: u64 = 0x800000000000001F; // 9223372036854775839
 pub const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN: u64 = 0x900000000000003F; // 10376293541461622847
 
@@ -42,6 +48,9 @@ pub const DEFAULT_ACCOUNT_CODE: &str = "
 
     export.basic_wallet::receive_asset
     export.basic_wallet::send_asset
+    export.basic_wallet::move_asset_to_note
+    export.basic_wallet::set_spending_limit
+    export.basic_wallet::set_asset_filter_entry
     export.basic_eoa::auth_tx_rpo_falcon512
 ";
 
@@ -88,7 +97,7 @@ impl MockDataStore {
         );
         let output_notes = created_notes.into_iter().filter_map(|note| match note {
             OutputNote::Full(note) => Some(note),
-            OutputNote::Header(_) => None,
+            OutputNote::Partial(_) | OutputNote::Header(_) => None,
         });
         let mut tx_args = TransactionArgs::default();
         tx_args.extend_expected_output_notes(output_notes);
@@ -107,6 +116,20 @@ impl MockDataStore {
     }
 }
 
+impl<A> From<TransactionContext<A>> for MockDataStore {
+    fn from(tx_context: TransactionContext<A>) -> Self {
+        let (tx_inputs, tx_args) = tx_context.into_parts();
+        let (account, _, block_header, block_chain, notes) = tx_inputs.into_parts();
+        Self {
+            account,
+            block_header,
+            block_chain,
+            notes: notes.into_vec(),
+            tx_args,
+        }
+    }
+}
+
 impl Default for MockDataStore {
     fn default() -> Self {
         Self::new(AssetPreservationStatus::Preserved)
@@ -114,31 +137,36 @@ impl Default for MockDataStore {
 }
 
 impl DataStore for MockDataStore {
-    fn get_transaction_inputs(
+    fn get_account(
         &self,
         account_id: AccountId,
-        block_num: u32,
-        notes: &[NoteId],
-    ) -> Result<TransactionInputs, DataStoreError> {
+    ) -> Result<(Account, Option<Word>), DataStoreError> {
         assert_eq!(account_id, self.account.id());
+        Ok((self.account.clone(), None))
+    }
+
+    fn get_block_header(&self, block_num: u32) -> Result<BlockHeader, DataStoreError> {
         assert_eq!(block_num, self.block_header.block_num());
-        assert_eq!(notes.len(), self.notes.len());
+        Ok(self.block_header)
+    }
 
-        let notes = self
-            .notes
+    fn get_chain_mmr(&self, block_ref: u32, _blocks: &[u32]) -> Result<ChainMmr, DataStoreError> {
+        assert_eq!(block_ref, self.block_header.block_num());
+        Ok(self.block_chain.clone())
+    }
+
+    fn get_notes(&self, note_ids: &[NoteId]) -> Result<Vec<InputNote>, DataStoreError> {
+        assert_eq!(note_ids.len(), self.notes.len());
+        note_ids
             .iter()
-            .filter(|note| notes.contains(&note.id()))
-            .cloned()
-            .collect::<Vec<_>>();
-
-        Ok(TransactionInputs::new(
-            self.account.clone(),
-            None,
-            self.block_header,
-            self.block_chain.clone(),
-            InputNotes::new(notes).unwrap(),
-        )
-        .unwrap())
+            .map(|&note_id| {
+                self.notes
+                    .iter()
+                    .find(|note| note.id() == note_id)
+                    .cloned()
+                    .ok_or(DataStoreError::NoteNotFound(note_id))
+            })
+            .collect()
     }
 
     fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError> {
@@ -147,43 +175,6 @@ impl DataStore for MockDataStore {
     }
 }
 
-// TRANSACTION BENCHMARK
-// ================================================================================================
-
-#[derive(Serialize)]
-pub struct TransactionBenchmark {
-    prologue: Option<u32>,
-    notes_processing: Option<u32>,
-    note_execution: BTreeMap<String, Option<u32>>,
-    tx_script_processing: Option<u32>,
-    epilogue: Option<u32>,
-}
-
-impl From<TransactionProgress> for TransactionBenchmark {
-    fn from(tx_progress: TransactionProgress) -> Self {
-        let prologue = tx_progress.prologue().len();
-
-        let notes_processing = tx_progress.notes_processing().len();
-
-        let mut note_execution = BTreeMap::new();
-        tx_progress.note_execution().iter().for_each(|(note_id, interval)| {
-            note_execution.insert(note_id.to_hex(), interval.len());
-        });
-
-        let tx_script_processing = tx_progress.tx_script_processing().len();
-
-        let epilogue = tx_progress.epilogue().len();
-
-        Self {
-            prologue,
-            notes_processing,
-            note_execution,
-            tx_script_processing,
-            epilogue,
-        }
-    }
-}
-
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -214,28 +205,77 @@ pub fn get_account_with_default_account_code(
     Account::new(account_id, account_vault, account_storage, account_code, Felt::new(1))
 }
 
-pub fn write_bench_results_to_json(
-    path: &Path,
-    tx_benchmarks: Vec<(Benchmark, TransactionProgress)>,
-) -> Result<(), String> {
-    // convert benchmark file internals to the JSON Value
-    let benchmark_file = read_to_string(path).map_err(|e| e.to_string())?;
-    let mut benchmark_json: Value = from_str(&benchmark_file).map_err(|e| e.to_string())?;
+pub fn get_faucet_account_with_max_supply_and_total_issuance(
+    public_key: Word,
+    max_supply: u64,
+    total_issuance: Option<u64>,
+) -> Account {
+    let faucet_account_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let faucet_account_code_src =
+        include_str!("../../miden-lib/asm/miden/contracts/faucets/basic_fungible.masm");
+    let faucet_account_code_ast = ModuleAst::parse(faucet_account_code_src).unwrap();
+    let account_assembler = TransactionKernel::assembler();
 
-    // fill becnhmarks JSON with results of each benchmark
-    for (bench_type, tx_progress) in tx_benchmarks {
-        let tx_benchmark = TransactionBenchmark::from(tx_progress);
-        let tx_benchmark_json = serde_json::to_value(tx_benchmark).map_err(|e| e.to_string())?;
+    let faucet_account_code =
+        AccountCode::new(faucet_account_code_ast.clone(), &account_assembler).unwrap();
+
+    let faucet_storage_slot_1 = [Felt::new(max_supply), Felt::new(0), Felt::new(0), Felt::new(0)];
+    let mut faucet_account_storage = AccountStorage::new(
+        vec![
+            SlotItem {
+                index: 0,
+                slot: StorageSlot::new_value(public_key),
+            },
+            SlotItem {
+                index: 1,
+                slot: StorageSlot::new_value(faucet_storage_slot_1),
+            },
+        ],
+        vec![],
+    )
+    .unwrap();
 
-        benchmark_json[bench_type.to_string()] = tx_benchmark_json;
-    }
+    if let Some(total_issuance) = total_issuance {
+        let faucet_storage_slot_254 =
+            [Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(total_issuance)];
+        faucet_account_storage
+            .set_item(FAUCET_STORAGE_DATA_SLOT, faucet_storage_slot_254)
+            .unwrap();
+    };
 
-    // write the becnhmarks JSON to the results file
-    write(
-        path,
-        to_string_pretty(&benchmark_json).expect("failed to convert json to String"),
+    Account::new(
+        faucet_account_id,
+        AssetVault::new(&[]).unwrap(),
+        faucet_account_storage,
+        faucet_account_code,
+        Felt::new(1),
     )
-    .map_err(|e| e.to_string())?;
+}
+
+pub fn get_note_with_fungible_asset_and_script(
+    fungible_asset: FungibleAsset,
+    note_script: ProgramAst,
+) -> Note {
+    let note_assembler = TransactionKernel::assembler();
+    let (note_script, _) = NoteScript::new(note_script, &note_assembler).unwrap();
+    const SERIAL_NUM: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let vault = NoteAssets::new(vec![fungible_asset.into()]).unwrap();
+    let metadata = NoteMetadata::new(sender_id, NoteType::Public, 1.into(), ZERO).unwrap();
+    let inputs = NoteInputs::new(vec![]).unwrap();
+    let recipient = NoteRecipient::new(SERIAL_NUM, note_script, inputs);
+
+    Note::new(vault, metadata, recipient)
+}
+
+pub fn get_new_pk_and_authenticator() -> (Word, Rc<BasicAuthenticator<DeterministicRng>>) {
+    let mut rng = DeterministicRng::default();
+    let sec_key = SecretKey::with_rng(&mut rng);
+    let pub_key: Word = sec_key.public_key().into();
+
+    let authenticator =
+        BasicAuthenticator::new_with_rng(&[(pub_key, AuthSecretKey::RpoFalcon512(sec_key))], rng);
 
-    Ok(())
+    (pub_key, Rc::new(authenticator))
 }
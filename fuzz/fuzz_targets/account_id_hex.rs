@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miden_objects::accounts::AccountId;
+
+fuzz_target!(|data: &str| {
+    if let Ok(id) = AccountId::from_hex(data) {
+        assert_eq!(AccountId::from_hex(&id.to_hex()), Ok(id));
+    }
+});
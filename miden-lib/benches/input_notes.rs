@@ -0,0 +1,103 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    accounts::account_id::testing::{
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        ACCOUNT_ID_SENDER,
+    },
+    assembly::ProgramAst,
+    assets::FungibleAsset,
+    crypto::merkle::{LeafIndex, Mmr, PartialMmr, SimpleSmt},
+    notes::{
+        Note, NoteAssets, NoteInclusionProof, NoteInputs, NoteMetadata, NoteRecipient, NoteScript,
+        NoteType,
+    },
+    transaction::{ChainMmr, InputNote, InputNotes, TransactionArgs, TransactionInputs},
+    Felt, NOTE_TREE_DEPTH, ONE, ZERO,
+};
+use mock::mock::{
+    account::{mock_account, mock_account_code},
+    block::mock_block_header,
+};
+
+const NUM_INPUT_NOTES: usize = 1000;
+
+fn build_input_notes_advice_inputs(c: &mut Criterion) {
+    let tx_inputs = build_tx_inputs(NUM_INPUT_NOTES);
+    let tx_args = TransactionArgs::default();
+
+    c.bench_function("Build advice inputs for 1000 input notes", |bench| {
+        bench.iter(|| TransactionKernel::build_advice_inputs(&tx_inputs, &tx_args).unwrap())
+    });
+}
+
+/// Returns transaction inputs consuming the specified number of notes, all of which were created
+/// in the block referenced by the transaction.
+fn build_tx_inputs(num_notes: usize) -> TransactionInputs {
+    let assembler = TransactionKernel::assembler();
+    let account = mock_account(
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        ONE,
+        mock_account_code(&assembler),
+    );
+
+    let (note_script, _) =
+        NoteScript::new(ProgramAst::parse("begin push.1 drop end").unwrap(), &assembler).unwrap();
+    let sender = ACCOUNT_ID_SENDER.try_into().unwrap();
+    let faucet_id = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap();
+    let notes = (0..num_notes as u64)
+        .map(|i| {
+            let asset = FungibleAsset::new(faucet_id, i + 1).unwrap();
+            let metadata = NoteMetadata::new(sender, NoteType::Public, 0.into(), ZERO).unwrap();
+            let inputs = NoteInputs::new(vec![Felt::new(i)]).unwrap();
+            let serial_num = [Felt::new(i), ZERO, ZERO, ZERO];
+            let recipient = NoteRecipient::new(serial_num, note_script.clone(), inputs);
+            Note::new(NoteAssets::new(vec![asset.into()]).unwrap(), metadata, recipient)
+        })
+        .collect::<Vec<_>>();
+
+    let note_tree = SimpleSmt::<NOTE_TREE_DEPTH>::with_leaves(
+        notes
+            .iter()
+            .enumerate()
+            .map(|(i, note)| (i as u64, note.authentication_hash().into())),
+    )
+    .unwrap();
+
+    let block_chain =
+        ChainMmr::new(PartialMmr::from_peaks(Mmr::default().peaks(0).unwrap()), vec![]).unwrap();
+    let block_header = mock_block_header(
+        0,
+        Some(block_chain.peaks().hash_peaks()),
+        Some(note_tree.root()),
+        &[account.clone()],
+    );
+
+    let input_notes = notes
+        .into_iter()
+        .enumerate()
+        .map(|(i, note)| {
+            let proof = NoteInclusionProof::new(
+                block_header.block_num(),
+                block_header.sub_hash(),
+                block_header.note_root(),
+                i as u64,
+                note_tree.open(&LeafIndex::new(i as u64).unwrap()).path,
+            )
+            .unwrap();
+            InputNote::authenticated(note, proof)
+        })
+        .collect();
+
+    TransactionInputs::new(
+        account,
+        None,
+        block_header,
+        block_chain,
+        InputNotes::new(input_notes).unwrap(),
+    )
+    .unwrap()
+}
+
+criterion_group!(input_notes, build_input_notes_advice_inputs);
+criterion_main!(input_notes);
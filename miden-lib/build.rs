@@ -1,14 +1,14 @@
 use std::{
-    env, fs,
-    fs::File,
-    io::{self, BufRead, BufReader, Write},
+    collections::BTreeMap,
+    env, fs, io,
     path::{Path, PathBuf},
 };
 
 use assembly::{
     ast::{AstSerdeOptions, ProgramAst},
-    LibraryNamespace, MaslLibrary, Version,
+    Assembler, LibraryNamespace, MaslLibrary, Version,
 };
+use miden_stdlib::StdLibrary;
 
 // CONSTANTS
 // ================================================================================================
@@ -18,6 +18,12 @@ const ASM_DIR: &str = "asm";
 const ASM_MIDEN_DIR: &str = "miden";
 const ASM_NOTE_SCRIPTS_DIR: &str = "note_scripts";
 const ASM_KERNELS_DIR: &str = "kernels/transaction";
+const ERROR_LOCATIONS_FILE: &str = "assertion_locations.rs";
+const NOTE_SCRIPTS_MODULE_FILE: &str = "note_scripts.masm";
+
+/// Note scripts whose roots are exposed by the generated `miden::note_scripts` module, together
+/// with the names of the procedures returning the roots.
+const NOTE_SCRIPT_ROOTS: [(&str, &str); 1] = [("P2ID", "p2id_script_root")];
 
 // PRE-PROCESSING
 // ================================================================================================
@@ -25,7 +31,10 @@ const ASM_KERNELS_DIR: &str = "kernels/transaction";
 /// Read and parse the contents from `./asm`.
 /// - Compiles contents of asm/miden directory into a Miden library file (.masl) under
 ///   miden namespace.
+/// - Generates the `miden::note_scripts` module exposing the roots of the note scripts which are
+///   used by other note scripts, and adds it to the Miden library.
 /// - Compiles contents of asm/scripts directory into individual .masb files.
+/// - Generates a table mapping error codes of assertions to their locations in the MASM sources.
 fn main() -> io::Result<()> {
     // re-build when the MASM code changes
     println!("cargo:rerun-if-changed=asm");
@@ -43,7 +52,10 @@ fn main() -> io::Result<()> {
     // set target directory to {OUT_DIR}/assets
     let target_dir = Path::new(&build_dir).join(ASSETS_DIR);
 
-    // compile miden library
+    // compile miden library; the roots of the note scripts depend on the library, so the library
+    // is compiled again once the module exposing these roots has been generated
+    let miden_lib = compile_miden_lib(&source_dir, &target_dir)?;
+    generate_note_scripts_module(&source_dir, &miden_lib)?;
     compile_miden_lib(&source_dir, &target_dir)?;
 
     // compile kernel and note scripts
@@ -53,61 +65,67 @@ fn main() -> io::Result<()> {
         &target_dir.join(ASM_NOTE_SCRIPTS_DIR),
     )?;
 
+    // locations are taken from the original sources, as the copied ones may have been patched
+    generate_assertion_locations(
+        Path::new(&crate_dir),
+        &Path::new(&build_dir).join(ERROR_LOCATIONS_FILE),
+    )?;
+
     Ok(())
 }
 
 // COMPILE MIDEN LIB
 // ================================================================================================
 
-fn compile_miden_lib(source_dir: &Path, target_dir: &Path) -> io::Result<()> {
+fn compile_miden_lib(source_dir: &Path, target_dir: &Path) -> io::Result<MaslLibrary> {
     let source_dir = source_dir.join(ASM_MIDEN_DIR);
 
-    // if this build has the testing flag set, modify the code and reduce the cost of proof-of-work
-    match env::var("CARGO_FEATURE_TESTING") {
-        Ok(ref s) if s == "1" => {
-            let constants = source_dir.join("kernels/tx/constants.masm");
-            let patched = source_dir.join("kernels/tx/constants.masm.patched");
-
-            // scope for file handlers
-            {
-                let read = File::open(&constants).unwrap();
-                let mut write = File::create(&patched).unwrap();
-                let modified = BufReader::new(read).lines().map(decrease_pow);
-
-                for line in modified {
-                    write.write_all(line.unwrap().as_bytes()).unwrap();
-                    write.write_all(&[b'\n']).unwrap();
-                }
-                write.flush().unwrap();
-            }
-
-            fs::remove_file(&constants).unwrap();
-            fs::rename(&patched, &constants).unwrap();
-        },
-        _ => (),
-    }
-
     let ns = LibraryNamespace::try_from("miden".to_string()).expect("invalid base namespace");
     let version = Version::try_from(env!("CARGO_PKG_VERSION")).expect("invalid cargo version");
     let miden_lib = MaslLibrary::read_from_dir(source_dir, ns, true, version)?;
 
     miden_lib.write_to_dir(target_dir)?;
 
-    Ok(())
+    Ok(miden_lib)
 }
 
-fn decrease_pow(line: io::Result<String>) -> io::Result<String> {
-    let mut line = line?;
-    if line.starts_with("const.REGULAR_ACCOUNT_SEED_DIGEST_MODULUS") {
-        line.clear();
-        // 2**5
-        line.push_str("const.REGULAR_ACCOUNT_SEED_DIGEST_MODULUS=32 # reduced via build.rs");
-    } else if line.starts_with("const.FAUCET_ACCOUNT_SEED_DIGEST_MODULUS") {
-        line.clear();
-        // 2**6
-        line.push_str("const.FAUCET_ACCOUNT_SEED_DIGEST_MODULUS=64 # reduced via build.rs");
+// GENERATE NOTE SCRIPTS MODULE
+// ================================================================================================
+
+/// Compiles the note scripts listed in [NOTE_SCRIPT_ROOTS] against the provided Miden library and
+/// the transaction kernel, and writes the `miden::note_scripts` module, with a procedure pushing
+/// the root of each of these scripts onto the stack, into the Miden library sources.
+///
+/// The roots of the listed scripts must not depend on the generated module.
+fn generate_note_scripts_module(source_dir: &Path, miden_lib: &MaslLibrary) -> io::Result<()> {
+    let kernel = fs::read_to_string(source_dir.join(ASM_KERNELS_DIR).join("api.masm"))?;
+    let assembler = Assembler::default()
+        .with_library(miden_lib)
+        .and_then(|assembler| assembler.with_library(&StdLibrary::default()))
+        .and_then(|assembler| assembler.with_kernel(&kernel))
+        .map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))?;
+
+    let mut module = String::from(
+        "# This module is generated by the build script of miden-lib and must not be edited.\n",
+    );
+    for (script_name, proc_name) in NOTE_SCRIPT_ROOTS {
+        let script_path =
+            source_dir.join(ASM_NOTE_SCRIPTS_DIR).join(script_name).with_extension("masm");
+        let program = assembler
+            .compile(fs::read_to_string(script_path)?)
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))?;
+        let [r0, r1, r2, r3]: [u64; 4] = program.hash().into();
+
+        module.push_str(&format!(
+            "\n#! Returns the root of the {script_name} note script.\n\
+             #!\n\
+             #! Stack: []\n\
+             #! Output: [SCRIPT_ROOT]\n\
+             export.{proc_name}\n    push.{r0}.{r1}.{r2}.{r3}\nend\n"
+        ));
     }
-    Ok(line)
+
+    fs::write(source_dir.join(ASM_MIDEN_DIR).join(NOTE_SCRIPTS_MODULE_FILE), module)
 }
 
 // COMPILE EXECUTABLE MODULES
@@ -156,6 +174,89 @@ fn compile_kernels(source_dir: &Path, target_dir: &Path) -> io::Result<()> {
     Ok(())
 }
 
+// GENERATE ASSERTION LOCATIONS
+// ================================================================================================
+
+/// Scans all MASM files under `{crate_dir}/asm` for assertions with error codes (e.g.,
+/// `assert.err=ERR_NAME`), and writes a Rust source file defining the `ASSERTION_LOCATIONS` table
+/// with the error code, error constant name, file path, and line of each such assertion.
+///
+/// Error codes must be defined via constants in the same file as the assertions using them; other
+/// assertions are skipped.
+fn generate_assertion_locations(crate_dir: &Path, target_file: &Path) -> io::Result<()> {
+    let mut locations = Vec::new();
+
+    let mut todo = vec![crate_dir.join(ASM_DIR)];
+    while let Some(dir) = todo.pop() {
+        let mut entries = fs::read_dir(dir)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        entries.sort();
+        for path in entries {
+            if path.is_dir() {
+                todo.push(path);
+            } else if is_masm_file(&path)? {
+                let relative_path =
+                    path.strip_prefix(crate_dir).unwrap().to_string_lossy().replace('\\', "/");
+                locations.extend(
+                    find_assertion_locations(&fs::read_to_string(&path)?)
+                        .into_iter()
+                        .map(|(code, name, line)| (code, name, relative_path.clone(), line)),
+                );
+            }
+        }
+    }
+    locations.sort();
+
+    let mut table =
+        format!("pub const ASSERTION_LOCATIONS: [AssertionLocation; {}] = [\n", locations.len());
+    for (code, name, file, line) in locations {
+        table.push_str(&format!(
+            "    AssertionLocation {{ err_code: {code}, err_name: \"{name}\", file: \"{file}\", line: {line} }},\n"
+        ));
+    }
+    table.push_str("];\n");
+
+    fs::write(target_file, table)
+}
+
+/// Returns the error code, error constant name, and line number (starting at 1) of each assertion
+/// in the provided MASM source which uses an error constant defined in the same source.
+fn find_assertion_locations(source: &str) -> Vec<(u32, String, usize)> {
+    let mut constants = BTreeMap::new();
+    for line in source.lines() {
+        let Some((name, value)) =
+            line.trim().strip_prefix("const.").and_then(|c| c.split_once('='))
+        else {
+            continue;
+        };
+        let value = value.split_whitespace().next().unwrap_or_default();
+        let value = match value.strip_prefix("0x") {
+            Some(hex) => u32::from_str_radix(hex, 16),
+            None => value.parse::<u32>(),
+        };
+        if let Ok(value) = value {
+            constants.insert(name.trim().to_string(), value);
+        }
+    }
+
+    let mut locations = Vec::new();
+    for (line_idx, line) in source.lines().enumerate() {
+        // skip comments
+        let code = line.split('#').next().unwrap_or_default();
+        for (pos, _) in code.match_indices(".err=") {
+            let name = code[pos + 5..]
+                .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .next()
+                .unwrap_or_default();
+            if let Some(&err_code) = constants.get(name) {
+                locations.push((err_code, name.to_string(), line_idx + 1));
+            }
+        }
+    }
+    locations
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -0,0 +1,138 @@
+use alloc::{vec, vec::Vec};
+
+use miden_objects::{
+    accounts::{AccountStorage, SlotItem, StorageMap, StorageSlot, StorageSlotType},
+    AccountError, Digest, Felt, Word, ZERO,
+};
+
+// STORAGE ARRAY
+// ================================================================================================
+
+/// Layout of a growable array of words kept in the account storage by the `miden::array` module
+/// of the Miden library.
+///
+/// The array spans two storage slots:
+/// - the length slot stores the number of elements of the array as `[length, 0, 0, 0]`.
+/// - the entries slot holds a storage map from `[0, 0, 0, index]` to the element at `index`.
+///
+/// Account components which use the array pass both slot indices to the `push`, `get` and `set`
+/// procedures of `miden::array`. This type can be used to build the initial storage of such
+/// components, and to read arrays back from the account storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageArray {
+    length_slot: u8,
+    entries_slot: u8,
+}
+
+impl StorageArray {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [StorageArray] stored at the provided length and entries slots.
+    pub const fn new(length_slot: u8, entries_slot: u8) -> Self {
+        Self { length_slot, entries_slot }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the index of the storage slot at which the length of the array is stored.
+    pub fn length_slot(&self) -> u8 {
+        self.length_slot
+    }
+
+    /// Returns the index of the storage slot at which the map of array elements is stored.
+    pub fn entries_slot(&self) -> u8 {
+        self.entries_slot
+    }
+
+    /// Returns the key under which the element at the specified index is stored in the map of
+    /// array elements.
+    pub fn entry_key(index: u64) -> Digest {
+        Digest::from([ZERO, ZERO, ZERO, Felt::new(index)])
+    }
+
+    /// Returns the storage slot items and the storage map which initialize the array with the
+    /// provided elements.
+    ///
+    /// # Errors
+    /// Returns an error if the storage map cannot be built from the provided elements.
+    pub fn storage_items(
+        &self,
+        elements: &[Word],
+    ) -> Result<(Vec<SlotItem>, StorageMap), AccountError> {
+        let entries = StorageMap::with_entries(
+            elements
+                .iter()
+                .enumerate()
+                .map(|(index, &value)| (Self::entry_key(index as u64), value)),
+        )?;
+        let length = [Felt::new(elements.len() as u64), ZERO, ZERO, ZERO];
+
+        let items = vec![
+            SlotItem {
+                index: self.length_slot,
+                slot: StorageSlot::new_value(length),
+            },
+            SlotItem {
+                index: self.entries_slot,
+                slot: StorageSlot::new_map(entries.root().into()),
+            },
+        ];
+
+        Ok((items, entries))
+    }
+
+    /// Returns the number of elements of the array in the provided account storage.
+    pub fn len(&self, storage: &AccountStorage) -> u64 {
+        storage.get_item(self.length_slot)[0].as_int()
+    }
+
+    /// Returns true if the array in the provided account storage has no elements.
+    pub fn is_empty(&self, storage: &AccountStorage) -> bool {
+        self.len(storage) == 0
+    }
+
+    /// Returns the element at the specified index of the array in the provided account storage, or
+    /// None if the index is not smaller than the length of the array.
+    ///
+    /// # Errors
+    /// Returns an error if the entries slot is not a map slot, or if its map is not part of the
+    /// provided account storage.
+    pub fn get(&self, storage: &AccountStorage, index: u64) -> Result<Option<Word>, AccountError> {
+        if index >= self.len(storage) {
+            return Ok(None);
+        }
+
+        Ok(Some(self.entries(storage)?.get_value(&Self::entry_key(index))))
+    }
+
+    /// Returns all elements of the array in the provided account storage.
+    ///
+    /// # Errors
+    /// Returns an error if the entries slot is not a map slot, or if its map is not part of the
+    /// provided account storage.
+    pub fn elements(&self, storage: &AccountStorage) -> Result<Vec<Word>, AccountError> {
+        let entries = self.entries(storage)?;
+        Ok((0..self.len(storage))
+            .map(|index| entries.get_value(&Self::entry_key(index)))
+            .collect())
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the map of array elements stored in the provided account storage.
+    fn entries<'a>(&self, storage: &'a AccountStorage) -> Result<&'a StorageMap, AccountError> {
+        if !matches!(storage.layout()[self.entries_slot as usize], StorageSlotType::Map { .. }) {
+            return Err(AccountError::StorageSlotNotMap(self.entries_slot));
+        }
+
+        let root = storage.get_item(self.entries_slot);
+        storage
+            .maps()
+            .iter()
+            .find(|map| map.root() == root)
+            .ok_or(AccountError::StorageMapNotFound { index: self.entries_slot as u64 })
+    }
+}
@@ -1,35 +1,56 @@
-use alloc::string::ToString;
+use alloc::{collections::BTreeMap, format, string::ToString};
 
 use miden_objects::{
     accounts::{
-        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountType, SlotItem,
-        StorageSlot,
+        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountType,
+        FungibleFaucetState, SlotItem, StorageMap, StorageSlot,
     },
     assembly::LibraryPath,
-    assets::{AssetVault, TokenSymbol},
-    AccountError, Felt, Word, ZERO,
+    assets::{AssetVault, FaucetMetadata, NonFungibleAsset, NonFungibleAssetDetails, TokenSymbol},
+    crypto::{hash::rpo::Rpo256 as Hasher, merkle::Smt},
+    AccountError, AssetError, Digest, Felt, Word, ZERO,
 };
 
-use super::{AuthScheme, Library, MidenLib, TransactionKernel};
+use super::{get_library_procedure_roots, AuthScheme, Library, MidenLib, TransactionKernel};
+use crate::{
+    notes::utils::migration_voucher_script_root, transaction::memory::FAUCET_STORAGE_DATA_SLOT,
+};
 
 // FUNGIBLE FAUCET
 // ================================================================================================
 
-const MAX_MAX_SUPPLY: u64 = (1 << 63) - 1;
-const MAX_DECIMALS: u8 = 12;
+/// Index of the storage slot at which the basic fungible faucet stores its [FaucetMetadata].
+pub const FAUCET_METADATA_SLOT: u8 = 1;
+
+/// Index of the storage slot at which the basic fungible faucet stores its migration
+/// configuration as `[successor_id, predecessor_id, 0, 0]`.
+pub const FAUCET_MIGRATION_SLOT: u8 = 2;
+
+/// Index of the storage slot at which the basic fungible faucet stores the root of the
+/// MIGRATION_VOUCHER note script.
+pub const FAUCET_VOUCHER_SCRIPT_ROOT_SLOT: u8 = 3;
 
 /// Creates a new faucet account with basic fungible faucet interface,
 /// account storage type, specified authentication scheme, and provided meta data (token symbol, decimals, max supply).
 ///
-/// The basic faucet interface exposes two procedures:
+/// The basic faucet interface exposes the following procedures:
 /// - `distribute`, which mints an assets and create a note for the provided recipient.
 /// - `burn`, which burns the provided asset.
+/// - `set_migration_successor`, which sets the faucet to which the tokens of this faucet can be
+///   migrated.
+/// - `burn_for_migration`, which burns the provided asset and creates a voucher note for the
+///   successor of the faucet.
+/// - `redeem_migration_voucher`, which mints the replacement of an asset burned by the predecessor
+///   of the faucet.
 ///
-/// `distribute` requires authentication. The authentication procedure is defined by the specified
-/// authentication scheme. `burn` does not require authentication and can be called by anyone.
+/// `distribute` and `set_migration_successor` require authentication. The authentication procedure
+/// is defined by the specified authentication scheme, and `set_migration_successor` cannot be
+/// invoked from a note. The other procedures do not require authentication and can be called by
+/// anyone.
 ///
 /// Public key information for the scheme is stored in the account storage at slot 0. The token
-/// metadata is stored in the account storage at slot 1.
+/// metadata is stored in the account storage at slot 1. The migration configuration and the root
+/// of the MIGRATION_VOUCHER note script are stored at slots 2 and 3.
 pub fn create_basic_fungible_faucet(
     init_seed: [u8; 32],
     symbol: TokenSymbol,
@@ -38,15 +59,108 @@ pub fn create_basic_fungible_faucet(
     account_storage_type: AccountStorageType,
     auth_scheme: AuthScheme,
 ) -> Result<(Account, Word), AccountError> {
-    // Atm we only have RpoFalcon512 as authentication scheme and this is also the default in the
-    // faucet contract, so we can just use the public key as storage slot 0.
-    // TODO: consider using a trait when we have more auth schemes.
-    let auth_data: Word = match auth_scheme {
-        AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
-    };
+    let metadata = FaucetMetadata::new(symbol, decimals, max_supply.as_int())?;
+    create_fungible_faucet_with_metadata(init_seed, metadata, account_storage_type, auth_scheme)
+}
+
+/// Creates a new faucet account with basic fungible faucet interface, account storage type,
+/// specified authentication scheme, and its storage pre-populated with the provided
+/// [FaucetMetadata].
+///
+/// See [create_basic_fungible_faucet()] for the description of the faucet interface and storage
+/// layout. The metadata can be read back from the account using [get_faucet_metadata()].
+pub fn create_fungible_faucet_with_metadata(
+    init_seed: [u8; 32],
+    metadata: FaucetMetadata,
+    account_storage_type: AccountStorageType,
+    auth_scheme: AuthScheme,
+) -> Result<(Account, Word), AccountError> {
+    build_basic_fungible_faucet(init_seed, metadata, None, account_storage_type, auth_scheme)
+}
+
+/// Creates a new faucet account with basic fungible faucet interface which replaces the provided
+/// `predecessor` faucet.
+///
+/// The new faucet mints the replacements of the assets burned by its predecessor when consuming
+/// the MIGRATION_VOUCHER notes created by the predecessor. For the migration to work, the
+/// predecessor must set the new faucet as its successor via the `set_migration_successor`
+/// procedure.
+///
+/// See [create_basic_fungible_faucet()] for the description of the faucet interface and storage
+/// layout.
+pub fn create_fungible_faucet_with_predecessor(
+    init_seed: [u8; 32],
+    metadata: FaucetMetadata,
+    predecessor: AccountId,
+    account_storage_type: AccountStorageType,
+    auth_scheme: AuthScheme,
+) -> Result<(Account, Word), AccountError> {
+    build_basic_fungible_faucet(
+        init_seed,
+        metadata,
+        Some(predecessor),
+        account_storage_type,
+        auth_scheme,
+    )
+}
+
+// MULTI-ASSET FUNGIBLE FAUCET
+// ================================================================================================
+
+/// Index of the storage slot at which the multi-asset fungible faucet stores the map of sub-token
+/// ids to their [FaucetMetadata].
+pub const MULTI_FAUCET_METADATA_SLOT: u8 = 1;
+
+/// Index of the storage slot at which the multi-asset fungible faucet stores the map of sub-token
+/// ids to their issued supply.
+pub const MULTI_FAUCET_SUPPLY_SLOT: u8 = 2;
+
+/// Creates a new faucet account with multi-asset fungible faucet interface, account storage type,
+/// specified authentication scheme, and the provided token classes.
+///
+/// Each token class is identified by a sub-token ID and described by its [FaucetMetadata]. The
+/// multi-asset faucet interface exposes two procedures:
+/// - `distribute`, which mints an asset of the specified sub-token and creates a note for the
+///   provided recipient.
+/// - `burn`, which burns the provided asset.
+///
+/// `distribute` requires authentication. The authentication procedure is defined by the specified
+/// authentication scheme. `burn` does not require authentication and can be called by anyone.
+///
+/// Public key information for the scheme is stored in the account storage at slot 0. The metadata
+/// of the token classes is stored in a storage map at slot 1 and the issued supply of each token
+/// class is tracked in a storage map at slot 2.
+///
+/// # Errors
+/// Returns an error if:
+/// - No token classes were provided.
+/// - The same sub-token ID was provided more than once.
+/// - The authentication scheme uses session keys, which are not supported by faucets.
+pub fn create_multi_fungible_faucet(
+    init_seed: [u8; 32],
+    token_classes: &[(u32, FaucetMetadata)],
+    account_storage_type: AccountStorageType,
+    auth_scheme: AuthScheme,
+) -> Result<(Account, Word), AccountError> {
+    if token_classes.is_empty() {
+        return Err(AccountError::FungibleFaucetInvalidMetadata(
+            "At least one token class must be provided".to_string(),
+        ));
+    }
+
+    let mut classes = BTreeMap::new();
+    for &(sub_token_id, metadata) in token_classes {
+        if classes.insert(sub_token_id, metadata).is_some() {
+            return Err(AccountError::FungibleFaucetInvalidMetadata(format!(
+                "Duplicate sub-token id {sub_token_id}"
+            )));
+        }
+    }
+
+    let auth_data = faucet_auth_data(auth_scheme)?;
 
     let miden = MidenLib::default();
-    let path = "miden::contracts::faucets::basic_fungible";
+    let path = "miden::contracts::faucets::multi_fungible";
     let faucet_code_ast = miden
         .get_module_ast(&LibraryPath::new(path).unwrap())
         .expect("Getting module AST failed");
@@ -54,23 +168,328 @@ pub fn create_basic_fungible_faucet(
     let account_assembler = TransactionKernel::assembler();
     let account_code = AccountCode::new(faucet_code_ast.clone(), &account_assembler)?;
 
-    // First check that the metadata is valid.
-    if decimals > MAX_DECIMALS {
+    // We store the authentication data and the token classes in the account storage:
+    // - slot 0: authentication data
+    // - slot 1: map of sub-token ids to [max_supply, decimals, token_symbol, 0]
+    // - slot 2: map of sub-token ids to [issued_supply, 0, 0, 0], initially empty
+    let metadata_map = StorageMap::with_entries(
+        classes
+            .into_iter()
+            .map(|(sub_token_id, metadata)| (sub_token_map_key(sub_token_id), metadata.into())),
+    )?;
+    let supply_map = StorageMap::new();
+
+    let account_storage = AccountStorage::new(
+        vec![
+            SlotItem {
+                index: 0,
+                slot: StorageSlot::new_value(auth_data),
+            },
+            SlotItem {
+                index: MULTI_FAUCET_METADATA_SLOT,
+                slot: StorageSlot::new_map(metadata_map.root().into()),
+            },
+            SlotItem {
+                index: MULTI_FAUCET_SUPPLY_SLOT,
+                slot: StorageSlot::new_map(supply_map.root().into()),
+            },
+        ],
+        vec![metadata_map, supply_map],
+    )?;
+    let account_vault = AssetVault::new(&[]).expect("error on empty vault");
+
+    let account_seed = AccountId::get_account_seed(
+        init_seed,
+        AccountType::FungibleFaucet,
+        account_storage_type,
+        account_code.root(),
+        account_storage.root(),
+    )?;
+    let account_id = AccountId::new(account_seed, account_code.root(), account_storage.root())?;
+    Ok((
+        Account::new(account_id, account_vault, account_storage, account_code, ZERO),
+        account_seed,
+    ))
+}
+
+/// Returns the [FaucetMetadata] of the specified sub-token stored in the provided multi-asset
+/// fungible faucet account.
+///
+/// # Errors
+/// Returns an error if:
+/// - The provided account is not a fungible faucet.
+/// - The account does not store a map of token classes at [MULTI_FAUCET_METADATA_SLOT].
+/// - The sub-token is not registered in the faucet.
+pub fn get_sub_token_metadata(
+    account: &Account,
+    sub_token_id: u32,
+) -> Result<FaucetMetadata, AccountError> {
+    let value = get_sub_token_item(account, MULTI_FAUCET_METADATA_SLOT, sub_token_id)?;
+    if value == Word::default() {
+        return Err(AccountError::FungibleFaucetInvalidMetadata(format!(
+            "Sub-token id {sub_token_id} is not registered in the faucet"
+        )));
+    }
+
+    FaucetMetadata::try_from(value)
+}
+
+/// Returns the supply of the specified sub-token issued so far by the provided multi-asset
+/// fungible faucet account.
+///
+/// # Errors
+/// Returns an error if:
+/// - The provided account is not a fungible faucet.
+/// - The account does not store a map of issued supplies at [MULTI_FAUCET_SUPPLY_SLOT].
+pub fn get_sub_token_supply(account: &Account, sub_token_id: u32) -> Result<u64, AccountError> {
+    Ok(get_sub_token_item(account, MULTI_FAUCET_SUPPLY_SLOT, sub_token_id)?[0].as_int())
+}
+
+/// Returns the [FaucetMetadata] stored in the storage of the provided fungible faucet account.
+///
+/// # Errors
+/// Returns an error if:
+/// - The provided account is not a fungible faucet.
+/// - The metadata slot of the account does not contain valid token metadata.
+pub fn get_faucet_metadata(account: &Account) -> Result<FaucetMetadata, AccountError> {
+    if account.account_type() != AccountType::FungibleFaucet {
         return Err(AccountError::FungibleFaucetInvalidMetadata(
-            "Decimals must be less than 13".to_string(),
+            "Account is not a fungible faucet".to_string(),
         ));
-    } else if max_supply.as_int() > MAX_MAX_SUPPLY {
-        return Err(AccountError::FungibleFaucetInvalidMetadata(
-            "Max supply must be < 2^63".to_string(),
+    }
+
+    FaucetMetadata::try_from(Word::from(account.storage().get_item(FAUCET_METADATA_SLOT)))
+}
+
+/// Returns the [FungibleFaucetState] of the provided basic fungible faucet account, i.e., its total
+/// issuance together with the max supply stored in its [FaucetMetadata].
+///
+/// # Errors
+/// Returns an error if:
+/// - The provided account is not a fungible faucet.
+/// - The reserved faucet data slot or the metadata slot of the account hold invalid data.
+pub fn get_fungible_faucet_state(account: &Account) -> Result<FungibleFaucetState, AccountError> {
+    account.fungible_faucet_state(FAUCET_METADATA_SLOT)
+}
+
+// NON-FUNGIBLE FAUCET
+// ================================================================================================
+
+/// Index of the storage slot at which the non-fungible faucet stores the number of issued tokens.
+pub const NFT_FAUCET_ISSUED_COUNT_SLOT: u8 = 1;
+
+/// Index of the storage slot at which the non-fungible faucet stores the map of token ids to the
+/// issued [NonFungibleAsset]s.
+pub const NFT_FAUCET_TOKENS_SLOT: u8 = 2;
+
+/// Index of the storage slot at which the non-fungible faucet stores its royalty recipient.
+pub const NFT_FAUCET_ROYALTY_SLOT: u8 = 3;
+
+/// Creates a new faucet account with basic non-fungible faucet interface, account storage type,
+/// specified authentication scheme, and an optional royalty recipient.
+///
+/// The basic non-fungible faucet interface exposes three procedures:
+/// - `distribute`, which mints a non-fungible asset and creates a note for the provided recipient.
+/// - `burn`, which burns the provided asset.
+/// - `set_royalty_recipient`, which updates the royalty recipient of the faucet.
+///
+/// `distribute` and `set_royalty_recipient` require authentication. The authentication procedure
+/// is defined by the specified authentication scheme, and `set_royalty_recipient` cannot be invoked
+/// from a note. `burn` does not require authentication and can be called by anyone.
+///
+/// Public key information for the scheme is stored in the account storage at slot 0. Issued tokens
+/// are enumerated in the account storage: slot 1 holds the number of tokens issued so far and slot
+/// 2 holds a storage map from token ids to the issued assets. The royalty recipient is stored at
+/// slot 3.
+pub fn create_nft_faucet(
+    init_seed: [u8; 32],
+    royalty_recipient: Option<AccountId>,
+    account_storage_type: AccountStorageType,
+    auth_scheme: AuthScheme,
+) -> Result<(Account, Word), AccountError> {
+    let auth_data = faucet_auth_data(auth_scheme)?;
+
+    let miden = MidenLib::default();
+    let path = "miden::contracts::faucets::basic_non_fungible";
+    let faucet_code_ast = miden
+        .get_module_ast(&LibraryPath::new(path).unwrap())
+        .expect("Getting module AST failed");
+
+    let account_assembler = TransactionKernel::assembler();
+    let auth_procedures = get_library_procedure_roots(
+        &["miden::contracts::faucets::basic_non_fungible::set_royalty_recipient"],
+        &account_assembler,
+    )?;
+    let account_code = AccountCode::new(faucet_code_ast.clone(), &account_assembler)?
+        .with_auth_procedures(&auth_procedures)?;
+
+    // We store the authentication data, the issued tokens and the royalty recipient in the account
+    // storage:
+    // - slot 0: authentication data
+    // - slot 1: number of issued tokens as [num_issued, 0, 0, 0]
+    // - slot 2: map of token ids to the issued assets, initially empty
+    // - slot 3: royalty recipient as [recipient_id, 0, 0, 0], or an empty word if there is none
+    // - slot 254: the tree of issued assets maintained by the kernel, initially empty
+    let tokens_map = StorageMap::new();
+    let royalty_data = match royalty_recipient {
+        Some(recipient) => [recipient.into(), ZERO, ZERO, ZERO],
+        None => Word::default(),
+    };
+
+    let account_storage = AccountStorage::new(
+        vec![
+            SlotItem {
+                index: 0,
+                slot: StorageSlot::new_value(auth_data),
+            },
+            SlotItem {
+                index: NFT_FAUCET_ISSUED_COUNT_SLOT,
+                slot: StorageSlot::new_value(Word::default()),
+            },
+            SlotItem {
+                index: NFT_FAUCET_TOKENS_SLOT,
+                slot: StorageSlot::new_map(tokens_map.root().into()),
+            },
+            SlotItem {
+                index: NFT_FAUCET_ROYALTY_SLOT,
+                slot: StorageSlot::new_value(royalty_data),
+            },
+            SlotItem {
+                index: FAUCET_STORAGE_DATA_SLOT,
+                slot: StorageSlot::new_map(*Smt::default().root()),
+            },
+        ],
+        vec![tokens_map],
+    )?;
+    let account_vault = AssetVault::new(&[]).expect("error on empty vault");
+
+    let account_seed = AccountId::get_account_seed(
+        init_seed,
+        AccountType::NonFungibleFaucet,
+        account_storage_type,
+        account_code.root(),
+        account_storage.root(),
+    )?;
+    let account_id = AccountId::new(account_seed, account_code.root(), account_storage.root())?;
+    Ok((
+        Account::new(account_id, account_vault, account_storage, account_code, ZERO),
+        account_seed,
+    ))
+}
+
+/// Returns the data hash to be passed to the `distribute` procedure of a non-fungible faucet in
+/// order to mint the asset described by the provided details, together with the resulting
+/// [NonFungibleAsset].
+///
+/// # Errors
+/// Returns an error if the faucet of the provided details is not a non-fungible faucet.
+pub fn mint_nft(details: &NonFungibleAssetDetails) -> Result<(Word, NonFungibleAsset), AssetError> {
+    let data_hash: Word = Hasher::hash(details.asset_data()).into();
+    let asset = NonFungibleAsset::from_parts(details.faucet_id(), data_hash)?;
+    Ok((data_hash, asset))
+}
+
+/// Returns the number of tokens issued so far by the provided non-fungible faucet account.
+///
+/// # Errors
+/// Returns an error if the provided account is not a non-fungible faucet.
+pub fn get_nft_issued_count(account: &Account) -> Result<u64, AccountError> {
+    ensure_nft_faucet(account)?;
+    Ok(account.storage().get_item(NFT_FAUCET_ISSUED_COUNT_SLOT)[0].as_int())
+}
+
+/// Returns the [NonFungibleAsset] issued by the provided non-fungible faucet account under the
+/// specified token id, or None if no token with this id was issued.
+///
+/// # Errors
+/// Returns an error if:
+/// - The provided account is not a non-fungible faucet.
+/// - The account does not store a map of issued tokens at [NFT_FAUCET_TOKENS_SLOT].
+/// - The value stored for the token is not a valid non-fungible asset.
+pub fn get_nft_token(
+    account: &Account,
+    token_id: u64,
+) -> Result<Option<NonFungibleAsset>, AccountError> {
+    ensure_nft_faucet(account)?;
+
+    let root = account.storage().get_item(NFT_FAUCET_TOKENS_SLOT);
+    let value = account
+        .storage()
+        .maps()
+        .iter()
+        .find(|map| map.root() == root)
+        .map(|map| map.get_value(&Digest::from([ZERO, ZERO, ZERO, Felt::new(token_id)])))
+        .ok_or(AccountError::StorageMapNotFound { index: NFT_FAUCET_TOKENS_SLOT as u64 })?;
+
+    if value == Word::default() {
+        return Ok(None);
+    }
+
+    NonFungibleAsset::try_from(value)
+        .map(Some)
+        .map_err(|err| AccountError::NonFungibleFaucetInvalidData(err.to_string()))
+}
+
+/// Returns the royalty recipient of the provided non-fungible faucet account, or None if the
+/// faucet has no royalty recipient.
+///
+/// # Errors
+/// Returns an error if:
+/// - The provided account is not a non-fungible faucet.
+/// - The royalty slot of the account does not contain a valid account id.
+pub fn get_nft_royalty_recipient(account: &Account) -> Result<Option<AccountId>, AccountError> {
+    ensure_nft_faucet(account)?;
+
+    let [recipient, padding @ ..] = Word::from(account.storage().get_item(NFT_FAUCET_ROYALTY_SLOT));
+    if padding.iter().any(|&felt| felt != ZERO) {
+        return Err(AccountError::NonFungibleFaucetInvalidData(
+            "Royalty recipient must be stored as [recipient_id, 0, 0, 0]".to_string(),
         ));
     }
 
-    // Note: data is stored as [a0, a1, a2, a3] but loaded onto the stack as [a3, a2, a1, a0, ...]
-    let metadata = [max_supply, Felt::from(decimals), symbol.into(), ZERO];
+    match recipient {
+        recipient if recipient == ZERO => Ok(None),
+        recipient => AccountId::try_from(recipient).map(Some),
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
 
-    // We store the authentication data and the token metadata in the account storage:
+/// Builds a faucet account with basic fungible faucet interface.
+fn build_basic_fungible_faucet(
+    init_seed: [u8; 32],
+    metadata: FaucetMetadata,
+    predecessor: Option<AccountId>,
+    account_storage_type: AccountStorageType,
+    auth_scheme: AuthScheme,
+) -> Result<(Account, Word), AccountError> {
+    let auth_data = faucet_auth_data(auth_scheme)?;
+
+    let miden = MidenLib::default();
+    let path = "miden::contracts::faucets::basic_fungible";
+    let faucet_code_ast = miden
+        .get_module_ast(&LibraryPath::new(path).unwrap())
+        .expect("Getting module AST failed");
+
+    let account_assembler = TransactionKernel::assembler();
+    let auth_procedures = get_library_procedure_roots(
+        &["miden::contracts::faucets::basic_fungible::set_migration_successor"],
+        &account_assembler,
+    )?;
+    let account_code = AccountCode::new(faucet_code_ast.clone(), &account_assembler)?
+        .with_auth_procedures(&auth_procedures)?;
+
+    // We store the authentication data, the token metadata and the migration data in the account
+    // storage:
     // - slot 0: authentication data
     // - slot 1: token metadata as [max_supply, decimals, token_symbol, 0]
+    // - slot 2: migration configuration as [successor_id, predecessor_id, 0, 0]
+    // - slot 3: root of the MIGRATION_VOUCHER note script
+    let migration_data = [ZERO, predecessor.map(Felt::from).unwrap_or(ZERO), ZERO, ZERO];
+    let voucher_script_root =
+        migration_voucher_script_root().expect("MIGRATION_VOUCHER script compilation failed");
+
     let account_storage = AccountStorage::new(
         vec![
             SlotItem {
@@ -78,8 +497,16 @@ pub fn create_basic_fungible_faucet(
                 slot: StorageSlot::new_value(auth_data),
             },
             SlotItem {
-                index: 1,
-                slot: StorageSlot::new_value(metadata),
+                index: FAUCET_METADATA_SLOT,
+                slot: StorageSlot::new_value(metadata.into()),
+            },
+            SlotItem {
+                index: FAUCET_MIGRATION_SLOT,
+                slot: StorageSlot::new_value(migration_data),
+            },
+            SlotItem {
+                index: FAUCET_VOUCHER_SCRIPT_ROOT_SLOT,
+                slot: StorageSlot::new_value(voucher_script_root.into()),
             },
         ],
         vec![],
@@ -99,3 +526,61 @@ pub fn create_basic_fungible_faucet(
         account_seed,
     ))
 }
+
+/// Returns the authentication data stored by faucets at slot 0 for the specified authentication
+/// scheme.
+///
+/// Faucet contracts authenticate transactions using RpoFalcon512, and thus, the public key of the
+/// scheme is stored at slot 0.
+///
+/// # Errors
+/// Returns an error if the authentication scheme uses session keys, which are not supported by
+/// faucets.
+fn faucet_auth_data(auth_scheme: AuthScheme) -> Result<Word, AccountError> {
+    match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => Ok(pub_key.into()),
+        AuthScheme::RpoFalcon512Session { .. } => Err(AccountError::UnsupportedAuthScheme(
+            "Faucets do not support session keys".to_string(),
+        )),
+    }
+}
+
+/// Returns the key under which the data of the specified sub-token is stored in the storage maps
+/// of a multi-asset fungible faucet.
+fn sub_token_map_key(sub_token_id: u32) -> Digest {
+    Digest::from([ZERO, ZERO, ZERO, Felt::from(sub_token_id)])
+}
+
+/// Returns the value stored for the specified sub-token in the storage map at the specified slot of
+/// a multi-asset fungible faucet.
+fn get_sub_token_item(
+    account: &Account,
+    slot: u8,
+    sub_token_id: u32,
+) -> Result<Word, AccountError> {
+    if account.account_type() != AccountType::FungibleFaucet {
+        return Err(AccountError::FungibleFaucetInvalidMetadata(
+            "Account is not a fungible faucet".to_string(),
+        ));
+    }
+
+    let root = account.storage().get_item(slot);
+    account
+        .storage()
+        .maps()
+        .iter()
+        .find(|map| map.root() == root)
+        .map(|map| map.get_value(&sub_token_map_key(sub_token_id)))
+        .ok_or(AccountError::StorageMapNotFound { index: slot as u64 })
+}
+
+/// Returns an error if the provided account is not a non-fungible faucet.
+fn ensure_nft_faucet(account: &Account) -> Result<(), AccountError> {
+    if account.account_type() != AccountType::NonFungibleFaucet {
+        return Err(AccountError::NonFungibleFaucetInvalidData(
+            "Account is not a non-fungible faucet".to_string(),
+        ));
+    }
+
+    Ok(())
+}
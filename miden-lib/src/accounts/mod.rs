@@ -1,4 +1,65 @@
+use alloc::{format, vec::Vec};
+
+use miden_objects::{
+    accounts::{AccountCode, AccountProcedureInfo},
+    assembly::{Assembler, ModuleAst},
+    AccountError, Digest,
+};
+
 use super::{auth::AuthScheme, transaction::TransactionKernel, Library, MidenLib};
 
+pub mod array;
 pub mod faucets;
+pub mod oracles;
 pub mod wallets;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the MAST roots of the specified procedures of the Miden library, e.g.
+/// `miden::contracts::wallets::basic::send_asset`.
+///
+/// The roots are needed to mark account procedures re-exported from the library as requiring
+/// authentication via [AccountCode::with_auth_procedures()].
+///
+/// # Errors
+/// Returns an error if any of the procedures cannot be compiled.
+fn get_library_procedure_roots(
+    procedures: &[&str],
+    assembler: &Assembler,
+) -> Result<Vec<Digest>, AccountError> {
+    procedures
+        .iter()
+        .map(|path| {
+            let (module, name) = path.rsplit_once("::").expect("procedure path is not valid");
+            let source = format!("use.{module}->lib export.lib::{name}");
+            let module = ModuleAst::parse(&source).expect("procedure path is not valid");
+            Ok(AccountCode::new(module, assembler)?.procedures()[0])
+        })
+        .collect()
+}
+
+/// Attaches [AccountProcedureInfo] to the specified procedures of the Miden library re-exported by
+/// the provided account code.
+///
+/// Each procedure is specified by its path, the number of its inputs and outputs, and the index of
+/// the first storage slot it may access. The name of the procedure is the last component of its
+/// path.
+///
+/// # Errors
+/// Returns an error if any of the procedures cannot be compiled or is not defined by the provided
+/// account code.
+fn with_library_procedure_info(
+    mut account_code: AccountCode,
+    procedures: &[(&str, u8, u8, Option<u8>)],
+    assembler: &Assembler,
+) -> Result<AccountCode, AccountError> {
+    for &(path, num_inputs, num_outputs, storage_offset) in procedures {
+        let root = get_library_procedure_roots(&[path], assembler)?[0];
+        let (_, name) = path.rsplit_once("::").expect("procedure path is not valid");
+        let info = AccountProcedureInfo::new(name, num_inputs, num_outputs, storage_offset)?;
+        account_code = account_code.with_procedure_info(root, info)?;
+    }
+
+    Ok(account_code)
+}
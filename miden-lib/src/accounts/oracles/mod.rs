@@ -0,0 +1,269 @@
+use alloc::{string::ToString, vec::Vec};
+
+use miden_objects::{
+    accounts::{
+        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountType, SlotItem,
+        StorageMap, StorageSlot,
+    },
+    assembly::{LibraryPath, ProgramAst},
+    assets::AssetVault,
+    crypto::hash::rpo::Rpo256 as Hasher,
+    AccountError, Digest, Felt, Word, ZERO,
+};
+
+use super::{
+    get_library_procedure_roots, with_library_procedure_info, AuthScheme, Library, MidenLib,
+    TransactionKernel,
+};
+
+// BASIC ORACLE
+// ================================================================================================
+
+/// Index of the storage slot at which the basic oracle stores the map of feed ids to the data
+/// words last published in the feeds.
+pub const ORACLE_DATA_SLOT: u8 = 1;
+
+/// Index of the storage slot at which the basic oracle stores the map of feed ids to the blocks at
+/// which the data of the feeds was last published, as `[block_num, 0, 0, 0]`.
+pub const ORACLE_PUBLISHED_BLOCKS_SLOT: u8 = 2;
+
+/// Creates a new public account with basic oracle interface and the specified authentication
+/// scheme.
+///
+/// The basic oracle publishes data feeds, e.g., asset prices. Each data feed is identified by a
+/// feed id and holds a single data word. The oracle interface exposes two procedures:
+/// - `publish_data`, which replaces the data word of a feed and records the block at which it was
+///   published. Every data word must be signed by the key of the oracle (see
+///   [DataFeedUpdate::message()]). The procedure cannot be invoked from a note.
+/// - `get_data`, which returns the data word last published in a feed and the block at which it
+///   was published.
+///
+/// Publishing data additionally requires the transaction to be authenticated. Public key
+/// information for the scheme is stored in the account storage at slot 0. The published data and
+/// the blocks at which it was published are stored in storage maps at slots 1 and 2 respectively.
+///
+/// The oracle is always created with public storage, so that its data feeds can be read by anyone
+/// via [get_data_feed_value()].
+///
+/// # Errors
+/// Returns an error if the authentication scheme uses session keys, which are not supported by
+/// oracles.
+pub fn create_basic_oracle(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+) -> Result<(Account, Word), AccountError> {
+    let auth_data: Word = match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
+        AuthScheme::RpoFalcon512Session { .. } => {
+            return Err(AccountError::UnsupportedAuthScheme(
+                "Oracles do not support session keys".to_string(),
+            ))
+        },
+    };
+
+    let miden = MidenLib::default();
+    let path = "miden::contracts::oracles::basic";
+    let oracle_code_ast = miden
+        .get_module_ast(&LibraryPath::new(path).unwrap())
+        .expect("Getting module AST failed");
+
+    let account_assembler = TransactionKernel::assembler();
+    let auth_procedures = get_library_procedure_roots(
+        &["miden::contracts::oracles::basic::publish_data"],
+        &account_assembler,
+    )?;
+    let account_code = AccountCode::new(oracle_code_ast.clone(), &account_assembler)?
+        .with_auth_procedures(&auth_procedures)?;
+    let account_code = with_library_procedure_info(
+        account_code,
+        &[
+            ("miden::contracts::oracles::basic::publish_data", 5, 0, Some(ORACLE_DATA_SLOT)),
+            ("miden::contracts::oracles::basic::get_data", 1, 5, Some(ORACLE_DATA_SLOT)),
+        ],
+        &account_assembler,
+    )?;
+
+    // We store the authentication data and the data feeds in the account storage:
+    // - slot 0: authentication data
+    // - slot 1: map of feed ids to the published data words, initially empty
+    // - slot 2: map of feed ids to [published_block, 0, 0, 0], initially empty
+    let data_map = StorageMap::new();
+    let published_blocks_map = StorageMap::new();
+
+    let account_storage = AccountStorage::new(
+        vec![
+            SlotItem {
+                index: 0,
+                slot: StorageSlot::new_value(auth_data),
+            },
+            SlotItem {
+                index: ORACLE_DATA_SLOT,
+                slot: StorageSlot::new_map(data_map.root().into()),
+            },
+            SlotItem {
+                index: ORACLE_PUBLISHED_BLOCKS_SLOT,
+                slot: StorageSlot::new_map(published_blocks_map.root().into()),
+            },
+        ],
+        vec![data_map, published_blocks_map],
+    )?;
+    let account_vault = AssetVault::new(&[]).expect("error on empty vault");
+
+    let account_seed = AccountId::get_account_seed(
+        init_seed,
+        AccountType::RegularAccountImmutableCode,
+        AccountStorageType::OnChain,
+        account_code.root(),
+        account_storage.root(),
+    )?;
+    let account_id = AccountId::new(account_seed, account_code.root(), account_storage.root())?;
+    Ok((
+        Account::new(account_id, account_vault, account_storage, account_code, ZERO),
+        account_seed,
+    ))
+}
+
+// DATA FEEDS
+// ================================================================================================
+
+/// An update of a data feed published by a basic oracle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataFeedUpdate {
+    feed_id: Felt,
+    data: Word,
+}
+
+impl DataFeedUpdate {
+    /// Returns a new [DataFeedUpdate] which publishes the provided data word in the specified data
+    /// feed.
+    pub fn new(feed_id: Felt, data: Word) -> Self {
+        Self { feed_id, data }
+    }
+
+    /// Returns the id of the updated data feed.
+    pub fn feed_id(&self) -> Felt {
+        self.feed_id
+    }
+
+    /// Returns the published data word.
+    pub fn data(&self) -> Word {
+        self.data
+    }
+
+    /// Returns the message which must be signed by the oracle to publish this update, computed as
+    /// h([0, 0, 0, feed_id], DATA).
+    pub fn message(&self) -> Digest {
+        Hasher::merge(&[feed_map_key(self.feed_id), self.data.into()])
+    }
+
+    /// Returns the key under which the signature of the oracle with the provided public key over
+    /// this update must be provided in the advice map of the publishing transaction.
+    pub fn signature_key(&self, pub_key: Word) -> Digest {
+        Hasher::merge(&[pub_key.into(), self.message()])
+    }
+}
+
+/// The data word last published in a data feed of a basic oracle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataFeedValue {
+    data: Word,
+    published_block: u32,
+}
+
+impl DataFeedValue {
+    /// Returns the published data word.
+    pub fn data(&self) -> Word {
+        self.data
+    }
+
+    /// Returns the number of the block at which the data word was published.
+    pub fn published_block(&self) -> u32 {
+        self.published_block
+    }
+}
+
+/// Returns the data word last published in the specified data feed of the provided basic oracle
+/// account, or None if no data was published in the feed.
+///
+/// # Errors
+/// Returns an error if the account does not store the maps of the data feeds at
+/// [ORACLE_DATA_SLOT] and [ORACLE_PUBLISHED_BLOCKS_SLOT].
+pub fn get_data_feed_value(
+    account: &Account,
+    feed_id: Felt,
+) -> Result<Option<DataFeedValue>, AccountError> {
+    let data = get_feed_item(account, ORACLE_DATA_SLOT, feed_id)?;
+    let published_block = get_feed_item(account, ORACLE_PUBLISHED_BLOCKS_SLOT, feed_id)?[0];
+    if data == Word::default() && published_block == ZERO {
+        return Ok(None);
+    }
+
+    Ok(Some(DataFeedValue {
+        data,
+        published_block: published_block.as_int() as u32,
+    }))
+}
+
+/// Returns a transaction script which publishes the provided updates of the data feeds of a basic
+/// oracle.
+///
+/// The updates are published in the provided order, and thus, if a feed is updated more than once,
+/// the last update is retained. The signature of every update must be provided in the advice map
+/// under [DataFeedUpdate::signature_key()]. The transaction is authenticated by the script itself
+/// using the RpoFalcon512 authentication scheme of the oracle.
+pub fn build_publish_data_script(updates: &[DataFeedUpdate]) -> ProgramAst {
+    let publish_calls = updates
+        .iter()
+        .map(|update| {
+            format!(
+                "push.{data}
+        push.{feed_id}
+        call.oracle::publish_data
+        dropw drop",
+                data = update
+                    .data
+                    .iter()
+                    .map(|x| x.as_int().to_string())
+                    .collect::<Vec<_>>()
+                    .join("."),
+                feed_id = update.feed_id,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n        ");
+
+    let script = format!(
+        "
+    use.miden::contracts::oracles::basic->oracle
+
+    begin
+        {publish_calls}
+
+        call.oracle::auth_tx_rpo_falcon512
+    end
+    "
+    );
+
+    ProgramAst::parse(&script).expect("publish data script is valid")
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the key under which the items of the specified data feed are stored.
+fn feed_map_key(feed_id: Felt) -> Digest {
+    Digest::from([ZERO, ZERO, ZERO, feed_id])
+}
+
+/// Returns the value stored for the specified data feed in the storage map at the specified slot
+/// of a basic oracle.
+fn get_feed_item(account: &Account, slot: u8, feed_id: Felt) -> Result<Word, AccountError> {
+    let root = account.storage().get_item(slot);
+    account
+        .storage()
+        .maps()
+        .iter()
+        .find(|map| map.root() == root)
+        .map(|map| map.get_value(&feed_map_key(feed_id)))
+        .ok_or(AccountError::StorageMapNotFound { index: slot as u64 })
+}
@@ -1,36 +1,744 @@
-use alloc::string::{String, ToString};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use miden_objects::{
     accounts::{
-        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountType,
-        StorageSlot,
+        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountType, SlotItem,
+        StorageMap, StorageSlot,
     },
-    assembly::ModuleAst,
-    assets::AssetVault,
-    AccountError, Word, ZERO,
+    assembly::{ModuleAst, ProgramAst},
+    assets::{AssetVault, FungibleAsset},
+    crypto::dsa::rpo_falcon512::PublicKey,
+    AccountError, Digest, Felt, Word, ONE, ZERO,
 };
 
-use super::{AuthScheme, TransactionKernel};
+use super::{
+    get_library_procedure_roots, with_library_procedure_info, AuthScheme, TransactionKernel,
+};
 
 // BASIC WALLET
 // ================================================================================================
 
+/// Index of the storage slot at which the basic wallet stores the map of its spending limits.
+pub const WALLET_SPENDING_LIMITS_SLOT: u8 = 1;
+
 /// Creates a new account with basic wallet interface, the specified authentication scheme and the account storage type.
 /// Basic wallets can be specified to have either mutable or immutable code.
 ///
-/// The basic wallet interface exposes two procedures:
-/// - `receive_asset`, which can be used to add an asset to the account.
+/// The basic wallet interface exposes five procedures:
+/// - `receive_asset`, which can be used to add an asset to the account. Assets of faucets rejected
+///   by the asset filter of the wallet are not accepted, see
+///   [create_basic_wallet_with_asset_filter()].
 /// - `send_asset`, which can be used to remove an asset from the account and put into a note
 ///    addressed to the specified recipient.
+/// - `move_asset_to_note`, which can be used to remove an asset from the account and add it to a
+///    note created by `send_asset`, e.g., to create a note holding several assets.
+/// - `set_spending_limit`, which can be used to update the spending limit for a faucet. This
+///    procedure only works for wallets created with
+///    [create_basic_wallet_with_spending_limits()], cannot be invoked from a note and must be
+///    invoked before the transaction is authenticated.
+/// - `set_asset_filter_entry`, which can be used to add a faucet to or remove it from the asset
+///    filter. This procedure only works for wallets created with
+///    [create_basic_wallet_with_asset_filter()], and cannot be invoked from a note.
 ///
-/// Both methods require authentication. The authentication procedure is defined by the specified
+/// All methods require authentication. The authentication procedure is defined by the specified
 /// authentication scheme. Public key information for the scheme is stored in the account storage
 /// at slot 0.
+///
+/// Wallets created with [AuthScheme::RpoFalcon512Session] additionally expose `create_session`
+/// and `revoke_session`, which manage the session key of the wallet and cannot be invoked from a
+/// note. The session key and the session info are stored at slots 2 and 3 respectively, and
+/// `send_asset` and `move_asset_to_note` record the sent assets so that the spending cap of the
+/// session can be enforced by the authentication procedure. Transactions which change the spending
+/// limits or the asset filter, or cancel a recovery, can only be authenticated by the main key.
 pub fn create_basic_wallet(
     init_seed: [u8; 32],
     auth_scheme: AuthScheme,
     account_type: AccountType,
     account_storage_type: AccountStorageType,
+) -> Result<(Account, Word), AccountError> {
+    build_basic_wallet(init_seed, auth_scheme, account_type, account_storage_type, None, None, None)
+}
+
+/// A limit on the amount of the fungible assets of a faucet which a basic wallet can send per
+/// period.
+///
+/// A period starts at the first sending once the specified number of blocks have passed since the
+/// start of the previous period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpendingLimit {
+    amount: u64,
+    period: u32,
+}
+
+impl SpendingLimit {
+    /// Returns a new [SpendingLimit] allowing the specified amount to be sent per period of the
+    /// specified number of blocks.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The amount is 0 or greater than the maximum amount of a fungible asset.
+    /// - The period is 0.
+    pub fn new(amount: u64, period: u32) -> Result<Self, AccountError> {
+        if amount == 0 || amount > FungibleAsset::MAX_AMOUNT {
+            return Err(AccountError::WalletInvalidSpendingLimit(format!(
+                "Spending limit {amount} must be between 1 and the maximum amount of a fungible asset"
+            )));
+        } else if period == 0 {
+            return Err(AccountError::WalletInvalidSpendingLimit(
+                "Period of the spending limit must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(Self { amount, period })
+    }
+
+    /// Returns the maximum amount which can be sent per period.
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Returns the length of the period in blocks.
+    pub fn period(&self) -> u32 {
+        self.period
+    }
+}
+
+/// Creates a new account with basic wallet interface, the specified authentication scheme and the
+/// account storage type, which limits the amount of the fungible assets of the specified faucets
+/// which can be sent per period.
+///
+/// The spending limits are stored in a storage map at slot 1 as
+/// `[limit, period, spent, period_start]` and are enforced by `send_asset` and
+/// `move_asset_to_note`. Assets of faucets without a spending limit can be sent without
+/// restrictions. A limit can be changed or removed later on by executing the script built by
+/// [build_set_spending_limit_script()].
+///
+/// See [create_basic_wallet()] for the description of the wallet interface.
+///
+/// # Errors
+/// Returns an error if:
+/// - Any of the provided IDs is not an ID of a fungible faucet.
+/// - The same faucet was provided more than once.
+pub fn create_basic_wallet_with_spending_limits(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    account_type: AccountType,
+    account_storage_type: AccountStorageType,
+    spending_limits: &[(AccountId, SpendingLimit)],
+) -> Result<(Account, Word), AccountError> {
+    let mut limits = BTreeMap::new();
+    for &(faucet_id, limit) in spending_limits {
+        if faucet_id.account_type() != AccountType::FungibleFaucet {
+            return Err(AccountError::WalletInvalidSpendingLimit(format!(
+                "Account {faucet_id} is not a fungible faucet"
+            )));
+        } else if limits.insert(faucet_id, limit).is_some() {
+            return Err(AccountError::WalletInvalidSpendingLimit(format!(
+                "Duplicate spending limit for faucet {faucet_id}"
+            )));
+        }
+    }
+
+    let limits_map = StorageMap::with_entries(limits.into_iter().map(|(faucet_id, limit)| {
+        (
+            spending_limit_map_key(faucet_id),
+            [Felt::new(limit.amount), Felt::from(limit.period), ZERO, ZERO],
+        )
+    }))?;
+
+    build_basic_wallet(
+        init_seed,
+        auth_scheme,
+        account_type,
+        account_storage_type,
+        Some(limits_map),
+        None,
+        None,
+    )
+}
+
+/// Returns the spending limit set for the specified faucet in the provided basic wallet account,
+/// or None if the assets of the faucet can be sent without restrictions.
+///
+/// # Errors
+/// Returns an error if the account stores a map root at [WALLET_SPENDING_LIMITS_SLOT] but not the
+/// map itself.
+pub fn get_spending_limit(
+    account: &Account,
+    faucet_id: AccountId,
+) -> Result<Option<SpendingLimit>, AccountError> {
+    let root = account.storage().get_item(WALLET_SPENDING_LIMITS_SLOT);
+    if root == Digest::default() {
+        return Ok(None);
+    }
+
+    let info = account
+        .storage()
+        .maps()
+        .iter()
+        .find(|map| map.root() == root)
+        .map(|map| map.get_value(&spending_limit_map_key(faucet_id)))
+        .ok_or(AccountError::StorageMapNotFound {
+            index: WALLET_SPENDING_LIMITS_SLOT as u64,
+        })?;
+
+    Ok((info[0] != ZERO).then_some(SpendingLimit {
+        amount: info[0].as_int(),
+        period: info[1].as_int() as u32,
+    }))
+}
+
+/// Returns a transaction script which sets the spending limit for the specified faucet in a basic
+/// wallet created with [create_basic_wallet_with_spending_limits()], or removes it if no limit is
+/// provided.
+///
+/// The amount already sent in the current period counts against the new limit. The script
+/// authenticates the transaction with the main key of the wallet after setting the limit, as the
+/// wallet rejects changes made once the nonce has been incremented.
+pub fn build_set_spending_limit_script(
+    faucet_id: AccountId,
+    limit: Option<SpendingLimit>,
+    auth_scheme: &AuthScheme,
+) -> ProgramAst {
+    let (amount, period) = limit.map_or((0, 0), |limit| (limit.amount, limit.period));
+    let (wallet_import, wallet_module) = wallet_settings_script_parts(auth_scheme);
+    let (auth_import, auth_call) = auth_tx_script_parts(auth_scheme);
+    let script = format!(
+        "
+    {wallet_import}
+    {auth_import}
+
+    begin
+        push.{period}
+        push.{amount}
+        push.{faucet_id}
+        call.{wallet_module}::set_spending_limit
+        drop drop drop
+
+        {auth_call}
+    end
+    ",
+        faucet_id = u64::from(faucet_id),
+    );
+
+    ProgramAst::parse(&script).expect("set spending limit script is valid")
+}
+
+// SESSION KEYS
+// ================================================================================================
+
+/// Index of the storage slot at which a basic wallet using session keys stores the public key of
+/// its session.
+pub const WALLET_SESSION_KEY_SLOT: u8 = 2;
+
+/// Index of the storage slot at which a basic wallet using session keys stores the info of its
+/// session as `[expiry_block, spending_cap, 0, 0]`.
+pub const WALLET_SESSION_INFO_SLOT: u8 = 3;
+
+/// A temporary key which can authenticate transactions of a basic wallet created with
+/// [AuthScheme::RpoFalcon512Session].
+///
+/// The session key can authenticate transactions until its expiry block (inclusive). The total
+/// amount of fungible assets sent in the transactions authenticated by the session key is limited
+/// by its spending cap, and the transactions cannot send non-fungible assets or manage sessions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionKey {
+    pub_key: PublicKey,
+    expiry_block: u32,
+    spending_cap: u64,
+}
+
+impl SessionKey {
+    /// Returns a new [SessionKey] with the specified public key, expiry block and spending cap.
+    ///
+    /// # Errors
+    /// Returns an error if the spending cap is greater than the maximum amount of a fungible asset.
+    pub fn new(
+        pub_key: PublicKey,
+        expiry_block: u32,
+        spending_cap: u64,
+    ) -> Result<Self, AccountError> {
+        if spending_cap > FungibleAsset::MAX_AMOUNT {
+            return Err(AccountError::WalletInvalidSessionKey(format!(
+                "Spending cap {spending_cap} exceeds the maximum amount of a fungible asset"
+            )));
+        }
+
+        Ok(Self { pub_key, expiry_block, spending_cap })
+    }
+
+    /// Returns the public key of the session.
+    pub fn pub_key(&self) -> PublicKey {
+        self.pub_key
+    }
+
+    /// Returns the number of the last block at which the session key can be used.
+    pub fn expiry_block(&self) -> u32 {
+        self.expiry_block
+    }
+
+    /// Returns the amount of fungible assets which can still be sent in the transactions
+    /// authenticated by the session key.
+    pub fn spending_cap(&self) -> u64 {
+        self.spending_cap
+    }
+}
+
+/// Returns the session key registered in the provided basic wallet account, or None if no session
+/// key is registered.
+///
+/// The spending cap of the returned session key is reduced by the amounts already sent in the
+/// transactions authenticated by the session key.
+pub fn get_session_key(account: &Account) -> Option<SessionKey> {
+    let pub_key: Word = account.storage().get_item(WALLET_SESSION_KEY_SLOT).into();
+    if pub_key == Word::default() {
+        return None;
+    }
+
+    let info: Word = account.storage().get_item(WALLET_SESSION_INFO_SLOT).into();
+    Some(SessionKey {
+        pub_key: PublicKey::new(pub_key),
+        expiry_block: info[0].as_int() as u32,
+        spending_cap: info[1].as_int(),
+    })
+}
+
+/// Returns a transaction script which registers the provided session key in a basic wallet
+/// created with [AuthScheme::RpoFalcon512Session], replacing the previously registered one.
+///
+/// The transaction must be authenticated by the main key of the wallet, which is done by the
+/// script itself.
+pub fn build_create_session_script(session_key: &SessionKey) -> ProgramAst {
+    let pub_key: Word = session_key.pub_key.into();
+    let script = format!(
+        "
+    use.miden::contracts::auth::session
+
+    begin
+        push.{spending_cap}.{expiry_block}
+        push.{pub_key}
+        call.session::create_session
+        dropw drop drop
+
+        push.0
+        call.session::auth_tx_rpo_falcon512_session
+        drop
+    end
+    ",
+        spending_cap = session_key.spending_cap,
+        expiry_block = session_key.expiry_block,
+        pub_key = pub_key.iter().map(|x| x.as_int().to_string()).collect::<Vec<_>>().join("."),
+    );
+
+    ProgramAst::parse(&script).expect("create session script is valid")
+}
+
+/// Returns a transaction script which removes the session key registered in a basic wallet
+/// created with [AuthScheme::RpoFalcon512Session].
+///
+/// The transaction must be authenticated by the main key of the wallet, which is done by the
+/// script itself.
+pub fn build_revoke_session_script() -> ProgramAst {
+    let script = "
+    use.miden::contracts::auth::session
+
+    begin
+        call.session::revoke_session
+
+        push.0
+        call.session::auth_tx_rpo_falcon512_session
+        drop
+    end
+    ";
+
+    ProgramAst::parse(script).expect("revoke session script is valid")
+}
+
+// SOCIAL RECOVERY
+// ================================================================================================
+
+/// Index of the storage slot at which a basic wallet with social recovery stores the map of its
+/// guardians.
+pub const WALLET_GUARDIANS_SLOT: u8 = 4;
+
+/// Index of the storage slot at which a basic wallet with social recovery stores its recovery
+/// config as `[threshold, timelock_blocks, 0, 0]`.
+pub const WALLET_RECOVERY_CONFIG_SLOT: u8 = 5;
+
+/// Index of the storage slot at which a basic wallet with social recovery stores the public key
+/// of the pending recovery.
+pub const WALLET_PENDING_RECOVERY_KEY_SLOT: u8 = 6;
+
+/// Index of the storage slot at which a basic wallet with social recovery stores the state of the
+/// pending recovery as `[initiated_block, num_approvals, recovery_id, 0]`.
+pub const WALLET_RECOVERY_STATE_SLOT: u8 = 7;
+
+/// Creates a new account with basic wallet interface, the specified authentication scheme and the
+/// account storage type, whose main public key can be replaced by the specified guardians.
+///
+/// Guardians approve the replacement of the key by sending notes created via
+/// [create_recovery_approval_note()](crate::notes::create_recovery_approval_note) to the wallet.
+/// The first approval of a key initiates its recovery, which can be finalized by executing the
+/// script built by [build_finalize_recovery_script()] once it has been approved by `threshold`
+/// guardians and `timelock_blocks` blocks have passed since its initiation. Until then, the
+/// recovery can be cancelled by executing the script built by [build_cancel_recovery_script()],
+/// which must be authenticated by the main key.
+///
+/// Approving and finalizing a recovery increments the nonce of the wallet without the transaction
+/// being authenticated by the main key. Therefore, both procedures fail if the transaction changed
+/// the vault, the code or any storage slot other than the main key and the recovery slots of the
+/// wallet before invoking them.
+///
+/// In addition to the procedures described in [create_basic_wallet()], the wallet exposes
+/// `approve_recovery`, `finalize_recovery` and `cancel_recovery`, where `cancel_recovery` cannot
+/// be invoked from a note. The guardians, the recovery config, the pending key and the state of
+/// the recovery are stored at slots 4, 5, 6 and 7 respectively.
+///
+/// # Errors
+/// Returns an error if:
+/// - No guardians were provided or the same guardian was provided more than once.
+/// - The threshold is 0 or greater than the number of guardians.
+pub fn create_basic_wallet_with_recovery(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    account_type: AccountType,
+    account_storage_type: AccountStorageType,
+    guardians: &[AccountId],
+    threshold: u32,
+    timelock_blocks: u32,
+) -> Result<(Account, Word), AccountError> {
+    let mut guardian_ids = BTreeSet::new();
+    for &guardian_id in guardians {
+        if !guardian_ids.insert(guardian_id) {
+            return Err(AccountError::WalletInvalidRecoveryConfig(format!(
+                "Duplicate guardian {guardian_id}"
+            )));
+        }
+    }
+
+    if guardian_ids.is_empty() {
+        return Err(AccountError::WalletInvalidRecoveryConfig(
+            "At least one guardian must be provided".to_string(),
+        ));
+    } else if threshold == 0 || threshold as usize > guardian_ids.len() {
+        return Err(AccountError::WalletInvalidRecoveryConfig(format!(
+            "Threshold {threshold} must be between 1 and the number of guardians {}",
+            guardian_ids.len()
+        )));
+    }
+
+    let guardians_map = StorageMap::with_entries(
+        guardian_ids
+            .into_iter()
+            .map(|guardian_id| (guardian_map_key(guardian_id), [ONE, ZERO, ZERO, ZERO])),
+    )?;
+    let config = [Felt::from(threshold), Felt::from(timelock_blocks), ZERO, ZERO];
+
+    build_basic_wallet(
+        init_seed,
+        auth_scheme,
+        account_type,
+        account_storage_type,
+        None,
+        Some((guardians_map, config)),
+        None,
+    )
+}
+
+/// A pending replacement of the main public key of a basic wallet created with
+/// [create_basic_wallet_with_recovery()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingRecovery {
+    new_pub_key: PublicKey,
+    initiated_block: u32,
+    num_approvals: u32,
+}
+
+impl PendingRecovery {
+    /// Returns the public key which is to replace the main public key of the wallet.
+    pub fn new_pub_key(&self) -> PublicKey {
+        self.new_pub_key
+    }
+
+    /// Returns the number of the block at which the recovery was initiated.
+    pub fn initiated_block(&self) -> u32 {
+        self.initiated_block
+    }
+
+    /// Returns the number of guardians which approved the recovery.
+    pub fn num_approvals(&self) -> u32 {
+        self.num_approvals
+    }
+}
+
+/// Returns the pending recovery of the provided basic wallet account, or None if no recovery is
+/// pending.
+pub fn get_pending_recovery(account: &Account) -> Option<PendingRecovery> {
+    let new_pub_key: Word = account.storage().get_item(WALLET_PENDING_RECOVERY_KEY_SLOT).into();
+    if new_pub_key == Word::default() {
+        return None;
+    }
+
+    let state: Word = account.storage().get_item(WALLET_RECOVERY_STATE_SLOT).into();
+    Some(PendingRecovery {
+        new_pub_key: PublicKey::new(new_pub_key),
+        initiated_block: state[0].as_int() as u32,
+        num_approvals: state[1].as_int() as u32,
+    })
+}
+
+/// Returns a transaction script which replaces the main public key of a basic wallet created with
+/// [create_basic_wallet_with_recovery()] by the key of its pending recovery.
+///
+/// The script can be executed by anyone once the recovery has been approved by enough guardians
+/// and its timelock has elapsed, and thus, does not authenticate the transaction.
+pub fn build_finalize_recovery_script() -> ProgramAst {
+    let script = "
+    use.miden::contracts::auth::recovery
+
+    begin
+        call.recovery::finalize_recovery
+    end
+    ";
+
+    ProgramAst::parse(script).expect("finalize recovery script is valid")
+}
+
+/// Returns a transaction script which cancels the pending recovery of a basic wallet created with
+/// [create_basic_wallet_with_recovery()] and the specified authentication scheme.
+///
+/// The transaction must be authenticated by the main key of the wallet, which is done by the
+/// script itself.
+pub fn build_cancel_recovery_script(auth_scheme: &AuthScheme) -> ProgramAst {
+    let (recovery_import, recovery_module) = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => ("use.miden::contracts::auth::recovery", "recovery"),
+        AuthScheme::RpoFalcon512Session { .. } => ("", "session"),
+    };
+    let (auth_import, auth_call) = auth_tx_script_parts(auth_scheme);
+    let script = format!(
+        "
+    {recovery_import}
+    {auth_import}
+
+    begin
+        call.{recovery_module}::cancel_recovery
+
+        {auth_call}
+    end
+    "
+    );
+
+    ProgramAst::parse(&script).expect("cancel recovery script is valid")
+}
+
+// ASSET FILTER
+// ================================================================================================
+
+/// Index of the storage slot at which a basic wallet with an asset filter stores the map of the
+/// faucets of its asset filter.
+pub const WALLET_ASSET_FILTER_SLOT: u8 = 8;
+
+/// Index of the storage slot at which a basic wallet with an asset filter stores the mode of its
+/// asset filter as `[is_allowlist, 0, 0, 0]`.
+pub const WALLET_ASSET_FILTER_MODE_SLOT: u8 = 9;
+
+/// Determines which assets are accepted by a basic wallet created with
+/// [create_basic_wallet_with_asset_filter()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetFilterMode {
+    /// Only assets of the listed faucets are accepted.
+    Allowlist,
+    /// Assets of all faucets except for the listed ones are accepted.
+    Denylist,
+}
+
+/// Creates a new account with basic wallet interface, the specified authentication scheme and the
+/// account storage type, which only accepts assets of the faucets permitted by its asset filter.
+///
+/// The faucets of the asset filter are stored in a storage map at slot 8 and the mode of the
+/// filter at slot 9. `receive_asset` rejects assets of faucets which are not listed in an
+/// allowlist, or which are listed in a denylist, e.g., to protect the wallet from spam assets.
+/// Faucets can be added to or removed from the filter later on by executing the script built by
+/// [build_set_asset_filter_entry_script()].
+///
+/// See [create_basic_wallet()] for the description of the wallet interface.
+///
+/// # Errors
+/// Returns an error if:
+/// - Any of the provided IDs is not an ID of a faucet.
+/// - The same faucet was provided more than once.
+pub fn create_basic_wallet_with_asset_filter(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    account_type: AccountType,
+    account_storage_type: AccountStorageType,
+    mode: AssetFilterMode,
+    faucet_ids: &[AccountId],
+) -> Result<(Account, Word), AccountError> {
+    let mut listed_ids = BTreeSet::new();
+    for &faucet_id in faucet_ids {
+        if !faucet_id.is_faucet() {
+            return Err(AccountError::WalletInvalidAssetFilter(format!(
+                "Account {faucet_id} is not a faucet"
+            )));
+        } else if !listed_ids.insert(faucet_id) {
+            return Err(AccountError::WalletInvalidAssetFilter(format!(
+                "Duplicate faucet {faucet_id}"
+            )));
+        }
+    }
+
+    let filter_map = StorageMap::with_entries(
+        listed_ids
+            .into_iter()
+            .map(|faucet_id| (asset_filter_map_key(faucet_id), [ONE, ZERO, ZERO, ZERO])),
+    )?;
+
+    build_basic_wallet(
+        init_seed,
+        auth_scheme,
+        account_type,
+        account_storage_type,
+        None,
+        None,
+        Some((filter_map, mode)),
+    )
+}
+
+/// Returns the mode of the asset filter of the provided basic wallet account, or None if the
+/// wallet accepts assets of all faucets.
+pub fn get_asset_filter_mode(account: &Account) -> Option<AssetFilterMode> {
+    if account.storage().get_item(WALLET_ASSET_FILTER_SLOT) == Digest::default() {
+        return None;
+    }
+
+    if account.storage().get_item(WALLET_ASSET_FILTER_MODE_SLOT)[0] != ZERO {
+        Some(AssetFilterMode::Allowlist)
+    } else {
+        Some(AssetFilterMode::Denylist)
+    }
+}
+
+/// Returns true if the provided basic wallet account accepts assets of the specified faucet.
+///
+/// # Errors
+/// Returns an error if the account stores a map root at [WALLET_ASSET_FILTER_SLOT] but not the
+/// map itself.
+pub fn is_asset_accepted(account: &Account, faucet_id: AccountId) -> Result<bool, AccountError> {
+    let Some(mode) = get_asset_filter_mode(account) else {
+        return Ok(true);
+    };
+
+    let root = account.storage().get_item(WALLET_ASSET_FILTER_SLOT);
+    let is_listed = account
+        .storage()
+        .maps()
+        .iter()
+        .find(|map| map.root() == root)
+        .map(|map| map.get_value(&asset_filter_map_key(faucet_id))[0] != ZERO)
+        .ok_or(AccountError::StorageMapNotFound { index: WALLET_ASSET_FILTER_SLOT as u64 })?;
+
+    Ok(is_listed == (mode == AssetFilterMode::Allowlist))
+}
+
+/// Returns a transaction script which adds the specified faucet to (`is_listed` is true) or
+/// removes it from (`is_listed` is false) the asset filter of a basic wallet created with
+/// [create_basic_wallet_with_asset_filter()] and the specified authentication scheme.
+///
+/// The transaction must be authenticated by the main key of the wallet, which is done by the
+/// script itself.
+pub fn build_set_asset_filter_entry_script(
+    faucet_id: AccountId,
+    is_listed: bool,
+    auth_scheme: &AuthScheme,
+) -> ProgramAst {
+    let (wallet_import, wallet_module) = wallet_settings_script_parts(auth_scheme);
+    let (auth_import, auth_call) = auth_tx_script_parts(auth_scheme);
+    let script = format!(
+        "
+    {wallet_import}
+    {auth_import}
+
+    begin
+        push.{is_listed}
+        push.{faucet_id}
+        call.{wallet_module}::set_asset_filter_entry
+        drop drop
+
+        {auth_call}
+    end
+    ",
+        is_listed = u8::from(is_listed),
+        faucet_id = u64::from(faucet_id),
+    );
+
+    ProgramAst::parse(&script).expect("set asset filter entry script is valid")
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the key under which the spending limit for the specified faucet is stored.
+fn spending_limit_map_key(faucet_id: AccountId) -> Digest {
+    Digest::from([ZERO, ZERO, ZERO, faucet_id.into()])
+}
+
+/// Returns the key under which the info of the specified guardian is stored.
+fn guardian_map_key(guardian_id: AccountId) -> Digest {
+    Digest::from([ZERO, ZERO, ZERO, guardian_id.into()])
+}
+
+/// Returns the key under which the specified faucet is stored in the map of the asset filter.
+fn asset_filter_map_key(faucet_id: AccountId) -> Digest {
+    Digest::from([ZERO, ZERO, ZERO, faucet_id.into()])
+}
+
+/// Returns the import and the invocation of the procedure which authenticates a transaction
+/// script with the specified authentication scheme.
+fn auth_tx_script_parts(auth_scheme: &AuthScheme) -> (&'static str, &'static str) {
+    match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => (
+            "use.miden::contracts::auth::basic->auth_tx",
+            "call.auth_tx::auth_tx_rpo_falcon512",
+        ),
+        AuthScheme::RpoFalcon512Session { .. } => (
+            "use.miden::contracts::auth::session",
+            "push.0
+        call.session::auth_tx_rpo_falcon512_session
+        drop",
+        ),
+    }
+}
+
+/// Returns the import and the name of the module exposing the procedures which change the settings
+/// of a basic wallet with the specified authentication scheme.
+///
+/// The session module is imported by [auth_tx_script_parts()] already, and thus, is not imported
+/// again.
+fn wallet_settings_script_parts(auth_scheme: &AuthScheme) -> (&'static str, &'static str) {
+    match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => {
+            ("use.miden::contracts::wallets::basic->basic_wallet", "basic_wallet")
+        },
+        AuthScheme::RpoFalcon512Session { .. } => ("", "session"),
+    }
+}
+
+/// Builds an account with basic wallet interface, optionally storing the provided map of spending
+/// limits and asset filter, and exposing social recovery with the provided map of guardians and
+/// recovery config.
+fn build_basic_wallet(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    account_type: AccountType,
+    account_storage_type: AccountStorageType,
+    spending_limits: Option<StorageMap>,
+    recovery: Option<(StorageMap, Word)>,
+    asset_filter: Option<(StorageMap, AssetFilterMode)>,
 ) -> Result<(Account, Word), AccountError> {
     if matches!(account_type, AccountType::FungibleFaucet | AccountType::NonFungibleFaucet) {
         return Err(AccountError::AccountIdInvalidFieldElement(
@@ -38,35 +746,149 @@ pub fn create_basic_wallet(
         ));
     }
 
-    let (auth_scheme_procedure, storage_slot_0_data): (&str, Word) = match auth_scheme {
-        AuthScheme::RpoFalcon512 { pub_key } => ("basic::auth_tx_rpo_falcon512", pub_key.into()),
+    // wallets using session keys expose the sending and settings procedures of the session
+    // module, which restrict what can be authenticated by the session key
+    let (wallet_module_path, cancel_recovery_module) = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => ("miden::contracts::wallets::basic", "recovery"),
+        AuthScheme::RpoFalcon512Session { .. } => ("miden::contracts::auth::session", "session"),
+    };
+    let send_asset_path = format!("{wallet_module_path}::send_asset");
+    let move_asset_to_note_path = format!("{wallet_module_path}::move_asset_to_note");
+    let set_spending_limit_path = format!("{wallet_module_path}::set_spending_limit");
+    let set_asset_filter_entry_path = format!("{wallet_module_path}::set_asset_filter_entry");
+    let cancel_recovery_path =
+        format!("miden::contracts::auth::{cancel_recovery_module}::cancel_recovery");
+    let (auth_module, wallet_module, auth_scheme_procedures, storage_slot_0_data): (
+        &str,
+        &str,
+        &[&str],
+        Word,
+    ) = match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => {
+            ("basic", "basic_wallet", &["basic::auth_tx_rpo_falcon512"], pub_key.into())
+        },
+        AuthScheme::RpoFalcon512Session { pub_key } => (
+            "session",
+            "session",
+            &[
+                "session::create_session",
+                "session::revoke_session",
+                "session::auth_tx_rpo_falcon512_session",
+            ],
+            pub_key.into(),
+        ),
+    };
+
+    let recovery_procedures = if recovery.is_some() {
+        vec![
+            "recovery::approve_recovery".to_string(),
+            "recovery::finalize_recovery".to_string(),
+            format!("{cancel_recovery_module}::cancel_recovery"),
+        ]
+    } else {
+        vec![]
     };
 
     let account_code_string: String = format!(
         "
     use.miden::contracts::wallets::basic->basic_wallet
-    use.miden::contracts::auth::basic
+    use.miden::contracts::auth::{auth_module}
+    {recovery_import}
 
     export.basic_wallet::receive_asset
-    export.basic_wallet::send_asset
-    export.{auth_scheme_procedure}
+    export.{wallet_module}::send_asset
+    export.{wallet_module}::move_asset_to_note
+    export.{wallet_module}::set_spending_limit
+    export.{wallet_module}::set_asset_filter_entry
+    {exports}
 
-    "
+    ",
+        recovery_import = if recovery.is_some() {
+            "use.miden::contracts::auth::recovery"
+        } else {
+            ""
+        },
+        exports = auth_scheme_procedures
+            .iter()
+            .map(|procedure| procedure.to_string())
+            .chain(recovery_procedures)
+            .map(|procedure| format!("export.{procedure}"))
+            .collect::<Vec<_>>()
+            .join("\n    "),
     );
     let account_code_src: &str = &account_code_string;
 
     let account_code_ast = ModuleAst::parse(account_code_src)
         .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?;
     let account_assembler = TransactionKernel::assembler();
-    let account_code = AccountCode::new(account_code_ast.clone(), &account_assembler)?;
-
-    let account_storage = AccountStorage::new(
-        vec![miden_objects::accounts::SlotItem {
-            index: 0,
-            slot: StorageSlot::new_value(storage_slot_0_data),
-        }],
-        vec![],
+    let mut auth_procedure_paths =
+        vec![set_spending_limit_path.as_str(), set_asset_filter_entry_path.as_str()];
+    if matches!(auth_scheme, AuthScheme::RpoFalcon512Session { .. }) {
+        auth_procedure_paths.extend([
+            "miden::contracts::auth::session::create_session",
+            "miden::contracts::auth::session::revoke_session",
+        ]);
+    }
+    if recovery.is_some() {
+        auth_procedure_paths.push(cancel_recovery_path.as_str());
+    }
+    let auth_procedures = get_library_procedure_roots(&auth_procedure_paths, &account_assembler)?;
+    let account_code = AccountCode::new(account_code_ast.clone(), &account_assembler)?
+        .with_auth_procedures(&auth_procedures)?;
+    let account_code = with_library_procedure_info(
+        account_code,
+        &[
+            (
+                "miden::contracts::wallets::basic::receive_asset",
+                4,
+                4,
+                Some(WALLET_ASSET_FILTER_SLOT),
+            ),
+            (send_asset_path.as_str(), 10, 10, Some(WALLET_SPENDING_LIMITS_SLOT)),
+            (move_asset_to_note_path.as_str(), 5, 5, Some(WALLET_SPENDING_LIMITS_SLOT)),
+            (set_spending_limit_path.as_str(), 3, 0, Some(WALLET_SPENDING_LIMITS_SLOT)),
+            (set_asset_filter_entry_path.as_str(), 2, 0, Some(WALLET_ASSET_FILTER_SLOT)),
+        ],
+        &account_assembler,
     )?;
+
+    let mut slots = vec![SlotItem {
+        index: 0,
+        slot: StorageSlot::new_value(storage_slot_0_data),
+    }];
+    let mut maps = vec![];
+    if let Some(limits_map) = spending_limits {
+        slots.push(SlotItem {
+            index: WALLET_SPENDING_LIMITS_SLOT,
+            slot: StorageSlot::new_map(limits_map.root().into()),
+        });
+        maps.push(limits_map);
+    }
+    if let Some((guardians_map, config)) = recovery {
+        slots.push(SlotItem {
+            index: WALLET_GUARDIANS_SLOT,
+            slot: StorageSlot::new_map(guardians_map.root().into()),
+        });
+        slots.push(SlotItem {
+            index: WALLET_RECOVERY_CONFIG_SLOT,
+            slot: StorageSlot::new_value(config),
+        });
+        maps.push(guardians_map);
+    }
+    if let Some((filter_map, mode)) = asset_filter {
+        let is_allowlist = if mode == AssetFilterMode::Allowlist { ONE } else { ZERO };
+        slots.push(SlotItem {
+            index: WALLET_ASSET_FILTER_SLOT,
+            slot: StorageSlot::new_map(filter_map.root().into()),
+        });
+        slots.push(SlotItem {
+            index: WALLET_ASSET_FILTER_MODE_SLOT,
+            slot: StorageSlot::new_value([is_allowlist, ZERO, ZERO, ZERO]),
+        });
+        maps.push(filter_map);
+    }
+
+    let account_storage = AccountStorage::new(slots, maps)?;
     let account_vault = AssetVault::new(&[]).expect("error on empty vault");
 
     let account_seed = AccountId::get_account_seed(
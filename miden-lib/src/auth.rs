@@ -7,4 +7,9 @@ pub enum AuthScheme {
     /// the standard in that instead of using SHAKE256 hash function in the hash-to-point algorithm we
     /// use RPO256. This makes the signature more efficient to verify in Miden VM.
     RpoFalcon512 { pub_key: rpo_falcon512::PublicKey },
+    /// An RPO Falcon512 authentication scheme in which transactions can be authenticated either by
+    /// the main key or by a temporary session key. A session key is registered by the main key
+    /// together with an expiry block and a cap on the amount of fungible assets which can be sent
+    /// in the transactions it authenticates. Session keys are supported only by basic wallets.
+    RpoFalcon512Session { pub_key: rpo_falcon512::PublicKey },
 }
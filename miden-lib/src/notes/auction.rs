@@ -0,0 +1,313 @@
+use alloc::{format, vec};
+
+use miden_objects::{
+    accounts::AccountId,
+    assets::{Asset, FungibleAsset},
+    crypto::rand::FeltRng,
+    notes::{
+        Note, NoteAssets, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient, NoteTag,
+        NoteType,
+    },
+    Felt, Hasher, NoteError, Word, ZERO,
+};
+
+use super::utils::{build_note_script, build_p2id_recipient};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Use case ID of the tags of AUCTION notes.
+const AUCTION_USE_CASE_ID: u16 = 1;
+
+/// Kinds of the notes created from an AUCTION note, from which their serial numbers are derived.
+const NEXT_AUCTION: u64 = 1;
+const REFUND: u64 = 2;
+const SETTLEMENT: u64 = 3;
+
+// AUCTION NOTES
+// ================================================================================================
+
+/// Generates an AUCTION note - an ascending-bid auction of the `lot` offered by the `seller`.
+///
+/// Until the block at `end_height`, any account can bid on the lot by consuming the note with the
+/// note args built by [build_auction_bid_args()]; note args are ignored when settling the auction
+/// but must still be provided. The first bid must reach `reserve_price` and
+/// each subsequent bid must exceed the highest bid; all bids are fungible assets issued by
+/// `bid_faucet`. The bidder escrows its bid by re-emitting the auction note holding the lot and
+/// the bid, and refunds the previous highest bid to its bidder in a P2ID note. From `end_height`
+/// on, the auction is settled by the highest bidder or the seller, so that the highest bidder
+/// receives the lot and the seller receives the highest bid. Without bids, the seller reclaims
+/// the lot.
+///
+/// The notes created from the auction are public and can be computed via
+/// [create_auction_bid_notes()] and [create_auction_settlement_note()]; they must be added as
+/// expected output notes to the arguments of the consuming transaction. Bidders must expose the
+/// `move_asset_to_note` procedure of the basic wallet.
+///
+/// The passed-in `rng` is used to generate the serial number of the note, from which the serial
+/// numbers of the notes created from the auction are derived.
+///
+/// # Errors
+/// Returns an error if:
+/// - `bid_faucet` is not a fungible faucet or `reserve_price` is not a valid amount.
+/// - `lot` is a fungible asset of the bids, i.e., it is issued by `bid_faucet` and has no
+///   sub-token, as the lot and the bids could not be told apart in the note vault.
+/// - Deserialization or compilation of the `AUCTION` script fails.
+pub fn create_auction_note<R: FeltRng>(
+    seller: AccountId,
+    lot: Asset,
+    bid_faucet: AccountId,
+    reserve_price: u64,
+    end_height: u32,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/AUCTION.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    // make sure the reserve price is a valid amount of the bid asset
+    let reserve =
+        FungibleAsset::new(bid_faucet, reserve_price).map_err(NoteError::InvalidAssetData)?;
+
+    // the AUCTION script rejects bids on lots which would be merged with the bids
+    if let Asset::Fungible(lot) = lot {
+        if lot.is_same_token(&reserve) {
+            return Err(NoteError::invalid_note_inputs("the lot cannot be the asset of the bids"));
+        }
+    }
+
+    let auction_inputs = AuctionInputs {
+        seller,
+        bid_faucet,
+        reserve_price,
+        end_height,
+        highest_bidder: None,
+        bidder_tag: 0,
+        seller_tag: NoteTag::from_account_id(seller, NoteExecutionHint::Local)?.inner(),
+        tag: build_auction_tag(&lot, bid_faucet)?,
+        serial_num: rng.draw_word(),
+    };
+
+    let metadata = NoteMetadata::new(seller, NoteType::Public, auction_inputs.tag, ZERO)?;
+    let assets = NoteAssets::new(vec![lot])?;
+    let recipient =
+        NoteRecipient::new(auction_inputs.serial_num, note_script, auction_inputs.to_inputs()?);
+    Ok(Note::new(assets, metadata, recipient))
+}
+
+/// Returns the note args for bidding `bid_amount` on an AUCTION note.
+///
+/// The notes sent to the bidder once it is outbid or wins the auction are tagged with
+/// `bidder_tag`.
+pub fn build_auction_bid_args(bid_amount: u64, bidder_tag: NoteTag) -> Word {
+    [Felt::new(bid_amount), bidder_tag.into(), ZERO, ZERO]
+}
+
+/// Returns the notes created when `bidder` bids `bid_amount` on the provided AUCTION note: the
+/// re-emitted auction note and, if a bid was placed before, the P2ID note refunding the previous
+/// highest bid to its bidder.
+///
+/// # Errors
+/// Returns an error if the provided note is not a valid AUCTION note or the bid amount is not
+/// valid.
+pub fn create_auction_bid_notes(
+    auction: &Note,
+    bidder: AccountId,
+    bid_amount: u64,
+    bidder_tag: NoteTag,
+) -> Result<(Note, Option<Note>), NoteError> {
+    let auction_inputs = AuctionInputs::read(auction.inputs())?;
+    let bid = FungibleAsset::new(auction_inputs.bid_faucet, bid_amount)
+        .map_err(NoteError::InvalidAssetData)?;
+    let (lot, highest_bid) = auction_inputs.split_assets(auction.assets())?;
+
+    let refund_note = match (auction_inputs.highest_bidder, highest_bid) {
+        (Some(highest_bidder), Some(highest_bid)) => Some(auction_inputs.create_p2id_note(
+            bidder,
+            highest_bidder,
+            highest_bid,
+            auction_inputs.bidder_tag,
+            REFUND,
+        )?),
+        _ => None,
+    };
+
+    let next_inputs = AuctionInputs {
+        highest_bidder: Some(bidder),
+        bidder_tag: bidder_tag.inner(),
+        serial_num: auction_inputs.derive_serial_num(NEXT_AUCTION),
+        ..auction_inputs
+    };
+    let metadata = NoteMetadata::new(bidder, NoteType::Public, next_inputs.tag, ZERO)?;
+    let assets = NoteAssets::new(vec![lot, bid.into()])?;
+    let recipient = NoteRecipient::new(
+        next_inputs.serial_num,
+        auction.script().clone(),
+        next_inputs.to_inputs()?,
+    );
+
+    Ok((Note::new(assets, metadata, recipient), refund_note))
+}
+
+/// Returns the P2ID note created when `claimant` settles the provided AUCTION note after the
+/// auction ended, or None if no note is created, i.e., if no bid was placed.
+///
+/// If the claimant is the highest bidder, the note carries the highest bid to the seller. If the
+/// claimant is the seller, the note carries the lot to the highest bidder.
+///
+/// # Errors
+/// Returns an error if the provided note is not a valid AUCTION note or the claimant is neither
+/// the seller nor the highest bidder.
+pub fn create_auction_settlement_note(
+    auction: &Note,
+    claimant: AccountId,
+) -> Result<Option<Note>, NoteError> {
+    let auction_inputs = AuctionInputs::read(auction.inputs())?;
+    let (lot, highest_bid) = auction_inputs.split_assets(auction.assets())?;
+
+    match (auction_inputs.highest_bidder, highest_bid) {
+        (Some(highest_bidder), Some(highest_bid)) if claimant == highest_bidder => {
+            let note = auction_inputs.create_p2id_note(
+                claimant,
+                auction_inputs.seller,
+                highest_bid,
+                auction_inputs.seller_tag,
+                SETTLEMENT,
+            )?;
+            Ok(Some(note))
+        },
+        (Some(highest_bidder), Some(_)) if claimant == auction_inputs.seller => {
+            let note = auction_inputs.create_p2id_note(
+                claimant,
+                highest_bidder,
+                lot,
+                auction_inputs.bidder_tag,
+                SETTLEMENT,
+            )?;
+            Ok(Some(note))
+        },
+        (None, None) if claimant == auction_inputs.seller => Ok(None),
+        _ => Err(NoteError::InvalidAuctionClaimant(claimant)),
+    }
+}
+
+// AUCTION INPUTS
+// ================================================================================================
+
+/// The inputs of an AUCTION note.
+#[derive(Debug, Clone, Copy)]
+struct AuctionInputs {
+    seller: AccountId,
+    bid_faucet: AccountId,
+    reserve_price: u64,
+    end_height: u32,
+    highest_bidder: Option<AccountId>,
+    bidder_tag: u32,
+    seller_tag: u32,
+    tag: NoteTag,
+    serial_num: Word,
+}
+
+impl AuctionInputs {
+    /// Decodes the inputs of an AUCTION note.
+    fn read(inputs: &NoteInputs) -> Result<Self, NoteError> {
+        let mut reader = inputs.reader();
+        let seller = reader.read_account_id()?;
+        let bid_faucet = reader.read_account_id()?;
+        let reserve_price = reader.read_u64()?;
+        let end_height = reader.read_u32()?;
+        let highest_bidder = reader.read_felt()?;
+        let highest_bidder = if highest_bidder == ZERO {
+            None
+        } else {
+            let highest_bidder = AccountId::try_from(highest_bidder).map_err(|err| {
+                NoteError::invalid_note_inputs(format!("highest bidder is not valid: {err}"))
+            })?;
+            Some(highest_bidder)
+        };
+        let bidder_tag = reader.read_u32()?;
+        let seller_tag = reader.read_u32()?;
+        let tag = NoteTag::from(reader.read_u32()?);
+        let serial_num = reader.read_word()?;
+        reader.finish()?;
+
+        Ok(Self {
+            seller,
+            bid_faucet,
+            reserve_price,
+            end_height,
+            highest_bidder,
+            bidder_tag,
+            seller_tag,
+            tag,
+            serial_num,
+        })
+    }
+
+    /// Encodes these inputs as the inputs of an AUCTION note.
+    fn to_inputs(self) -> Result<NoteInputs, NoteError> {
+        let builder = NoteInputs::builder()
+            .push_account_id(self.seller)
+            .push_account_id(self.bid_faucet)
+            .push_u64(self.reserve_price)
+            .push_u32(self.end_height);
+        let builder = match self.highest_bidder {
+            Some(highest_bidder) => builder.push_account_id(highest_bidder),
+            None => builder.push_felt(ZERO),
+        };
+
+        builder
+            .push_u32(self.bidder_tag)
+            .push_u32(self.seller_tag)
+            .push_u32(self.tag.inner())
+            .push_word(self.serial_num)
+            .build()
+    }
+
+    /// Returns the lot and the highest bid, if any, of an AUCTION note with these inputs.
+    fn split_assets(&self, assets: &NoteAssets) -> Result<(Asset, Option<Asset>), NoteError> {
+        let mut assets = assets.iter().copied();
+        let lot = assets.next();
+        let highest_bid = assets.next();
+
+        match (lot, highest_bid.is_some() == self.highest_bidder.is_some(), assets.next()) {
+            (Some(lot), true, None) => Ok((lot, highest_bid)),
+            _ => Err(NoteError::invalid_note_inputs(
+                "assets of the auction do not match its highest bidder",
+            )),
+        }
+    }
+
+    /// Returns the serial number of a note of the specified kind created from the auction.
+    fn derive_serial_num(&self, kind: u64) -> Word {
+        Hasher::merge(&[self.serial_num.into(), [Felt::new(kind), ZERO, ZERO, ZERO].into()]).into()
+    }
+
+    /// Returns the public P2ID note of the specified kind created by the sender from the auction.
+    fn create_p2id_note(
+        &self,
+        sender: AccountId,
+        target: AccountId,
+        asset: Asset,
+        tag: u32,
+        kind: u64,
+    ) -> Result<Note, NoteError> {
+        let recipient = build_p2id_recipient(target, self.derive_serial_num(kind))?;
+        let metadata = NoteMetadata::new(sender, NoteType::Public, NoteTag::from(tag), ZERO)?;
+        Ok(Note::new(NoteAssets::new(vec![asset])?, metadata, recipient))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a note tag for an auction note of the specified lot and bid faucet.
+///
+/// Use case ID for the returned tag is set to 1, and the payload is constructed the same way as
+/// the payload of SWAP note tags, from the faucet IDs of the lot and the bids.
+fn build_auction_tag(lot: &Asset, bid_faucet: AccountId) -> Result<NoteTag, NoteError> {
+    let lot_tag = (u64::from(lot.faucet_id()) >> 52) as u8;
+    let bid_tag = (u64::from(bid_faucet) >> 52) as u8;
+    let payload = ((lot_tag as u16) << 8) | (bid_tag as u16);
+
+    NoteTag::for_public_use_case(AUCTION_USE_CASE_ID, payload, NoteExecutionHint::Local)
+}
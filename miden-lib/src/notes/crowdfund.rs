@@ -0,0 +1,302 @@
+use alloc::{vec, vec::Vec};
+
+use miden_objects::{
+    accounts::AccountId,
+    assets::{Asset, FungibleAsset},
+    crypto::rand::FeltRng,
+    notes::{
+        Note, NoteAssets, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient, NoteTag,
+        NoteType,
+    },
+    Felt, Hasher, NoteError, Word, ZERO,
+};
+
+use super::utils::{build_note_script, build_p2id_recipient};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Use case ID of the tags of CROWDFUND notes.
+const CROWDFUND_USE_CASE_ID: u16 = 2;
+
+/// Maximum number of contributions to a campaign, limited by the maximum number of note inputs.
+const MAX_CONTRIBUTIONS: usize = 29;
+
+/// Kinds of the notes created from a CROWDFUND note, from which their serial numbers are derived.
+const NEXT_CROWDFUND: u64 = 1;
+const REFUND: u64 = 2;
+
+// CROWDFUND NOTES
+// ================================================================================================
+
+/// Generates a CROWDFUND note - a campaign raising more than `target` of the fungible asset issued
+/// by `faucet_id` for the `beneficiary`.
+///
+/// Until the block at `deadline`, any account can contribute at least `min_contribution` to the
+/// campaign by consuming the note with the note args built by
+/// [build_crowdfund_contribution_args()]; the contributor re-emits the note holding the funds
+/// contributed so far and its contribution. Once the funds exceed the target, the beneficiary
+/// claims them by consuming the note. If the funds did not exceed the target by the deadline, any
+/// account consuming the note refunds all contributions to their contributors in P2ID notes. A
+/// campaign accepts at most 29 contributions.
+///
+/// The note args are only used by contributions but must be provided whenever the note is
+/// consumed. The notes created from the campaign are public and can be computed via
+/// [create_crowdfund_contribution_note()] and [create_crowdfund_refund_notes()]; they must be added
+/// as expected output notes to the arguments of the consuming transaction.
+///
+/// The created note holds no assets. The passed-in `rng` is used to generate the serial number of
+/// the note, from which the serial numbers of the notes created from the campaign are derived.
+///
+/// # Errors
+/// Returns an error if:
+/// - `faucet_id` is not a fungible faucet or `target` is zero or not a valid amount.
+/// - `min_contribution` is zero or greater than `target`.
+/// - Deserialization or compilation of the `CROWDFUND` script fails.
+pub fn create_crowdfund_note<R: FeltRng>(
+    sender: AccountId,
+    beneficiary: AccountId,
+    faucet_id: AccountId,
+    target: u64,
+    min_contribution: u64,
+    deadline: u32,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/CROWDFUND.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    // make sure the target is a valid amount of the contributed asset
+    FungibleAsset::new(faucet_id, target).map_err(NoteError::InvalidAssetData)?;
+    if target == 0 {
+        return Err(NoteError::invalid_note_inputs("crowdfund target must not be zero"));
+    }
+    if min_contribution == 0 || min_contribution > target {
+        return Err(NoteError::invalid_note_inputs(
+            "crowdfund minimum contribution must be between one and the target",
+        ));
+    }
+
+    let crowdfund_inputs = CrowdfundInputs {
+        beneficiary,
+        faucet_id,
+        target,
+        min_contribution,
+        deadline,
+        tag: build_crowdfund_tag(faucet_id)?,
+        serial_num: rng.draw_word(),
+        contributions: Vec::new(),
+    };
+
+    let metadata = NoteMetadata::new(sender, NoteType::Public, crowdfund_inputs.tag, ZERO)?;
+    let recipient =
+        NoteRecipient::new(crowdfund_inputs.serial_num, note_script, crowdfund_inputs.to_inputs()?);
+    Ok(Note::new(NoteAssets::default(), metadata, recipient))
+}
+
+/// Returns the note args for contributing `amount` to a CROWDFUND note.
+///
+/// If the campaign fails, the contribution is refunded in a note tagged with `contributor_tag`.
+pub fn build_crowdfund_contribution_args(amount: u64, contributor_tag: NoteTag) -> Word {
+    [Felt::new(amount), contributor_tag.into(), ZERO, ZERO]
+}
+
+/// Returns the CROWDFUND note re-emitted when `contributor` contributes `amount` to the provided
+/// CROWDFUND note.
+///
+/// # Errors
+/// Returns an error if the provided note is not a valid CROWDFUND note, the amount is below the
+/// minimum contribution or not valid, or the campaign holds the maximum number of contributions.
+pub fn create_crowdfund_contribution_note(
+    crowdfund: &Note,
+    contributor: AccountId,
+    amount: u64,
+    contributor_tag: NoteTag,
+) -> Result<Note, NoteError> {
+    let mut crowdfund_inputs = CrowdfundInputs::read(crowdfund.inputs())?;
+    let funds = crowdfund_inputs.funds(crowdfund.assets())?;
+
+    if amount == 0 || amount < crowdfund_inputs.min_contribution {
+        return Err(NoteError::invalid_note_inputs(
+            "crowdfund contribution must not be zero nor below the minimum contribution",
+        ));
+    }
+    if crowdfund_inputs.contributions.len() >= MAX_CONTRIBUTIONS {
+        return Err(NoteError::invalid_note_inputs(
+            "crowdfund holds the maximum number of contributions",
+        ));
+    }
+    let contribution = FungibleAsset::new(crowdfund_inputs.faucet_id, amount)
+        .map_err(NoteError::InvalidAssetData)?;
+    let funds = FungibleAsset::new(crowdfund_inputs.faucet_id, funds + contribution.amount())
+        .map_err(NoteError::InvalidAssetData)?;
+
+    crowdfund_inputs.serial_num = crowdfund_inputs.derive_serial_num(NEXT_CROWDFUND, 0);
+    crowdfund_inputs.contributions.push(Contribution {
+        contributor,
+        amount,
+        tag: contributor_tag.inner(),
+    });
+
+    let metadata = NoteMetadata::new(contributor, NoteType::Public, crowdfund_inputs.tag, ZERO)?;
+    let recipient = NoteRecipient::new(
+        crowdfund_inputs.serial_num,
+        crowdfund.script().clone(),
+        crowdfund_inputs.to_inputs()?,
+    );
+    Ok(Note::new(NoteAssets::new(vec![funds.into()])?, metadata, recipient))
+}
+
+/// Returns the P2ID notes created when `sender` consumes the provided CROWDFUND note after the
+/// campaign failed, refunding each contribution to its contributor in the order of the
+/// contributions.
+///
+/// Since the consuming account sends out all funds it receives from the note, its vault is left
+/// unchanged and the transaction must not increment its nonce, i.e., it must be executed without
+/// an authentication script.
+///
+/// # Errors
+/// Returns an error if the provided note is not a valid CROWDFUND note or its funds exceed the
+/// target.
+pub fn create_crowdfund_refund_notes(
+    crowdfund: &Note,
+    sender: AccountId,
+) -> Result<Vec<Note>, NoteError> {
+    let crowdfund_inputs = CrowdfundInputs::read(crowdfund.inputs())?;
+    if crowdfund_inputs.funds(crowdfund.assets())? > crowdfund_inputs.target {
+        return Err(NoteError::invalid_note_inputs("crowdfund exceeded its target"));
+    }
+
+    crowdfund_inputs
+        .contributions
+        .iter()
+        .enumerate()
+        .map(|(index, contribution)| {
+            let serial_num = crowdfund_inputs.derive_serial_num(REFUND, index as u64);
+            let recipient = build_p2id_recipient(contribution.contributor, serial_num)?;
+            let metadata =
+                NoteMetadata::new(sender, NoteType::Public, NoteTag::from(contribution.tag), ZERO)?;
+            let refund = FungibleAsset::new(crowdfund_inputs.faucet_id, contribution.amount)
+                .map_err(NoteError::InvalidAssetData)?;
+            Ok(Note::new(NoteAssets::new(vec![refund.into()])?, metadata, recipient))
+        })
+        .collect()
+}
+
+// CROWDFUND INPUTS
+// ================================================================================================
+
+/// A contribution recorded in the inputs of a CROWDFUND note.
+#[derive(Debug, Clone, Copy)]
+struct Contribution {
+    contributor: AccountId,
+    amount: u64,
+    tag: u32,
+}
+
+/// The inputs of a CROWDFUND note.
+#[derive(Debug, Clone)]
+struct CrowdfundInputs {
+    beneficiary: AccountId,
+    faucet_id: AccountId,
+    target: u64,
+    min_contribution: u64,
+    deadline: u32,
+    tag: NoteTag,
+    serial_num: Word,
+    contributions: Vec<Contribution>,
+}
+
+impl CrowdfundInputs {
+    /// Decodes the inputs of a CROWDFUND note.
+    fn read(inputs: &NoteInputs) -> Result<Self, NoteError> {
+        let mut reader = inputs.reader();
+        let beneficiary = reader.read_account_id()?;
+        let faucet_id = reader.read_account_id()?;
+        let target = reader.read_u64()?;
+        let deadline = reader.read_u32()?;
+        let tag = NoteTag::from(reader.read_u32()?);
+        let min_contribution = reader.read_u64()?;
+        for _ in 0..2 {
+            reader.read_felt()?;
+        }
+        let serial_num = reader.read_word()?;
+
+        let mut contributions = Vec::new();
+        while reader.num_remaining() > 0 {
+            let contributor = reader.read_account_id()?;
+            let amount = reader.read_u64()?;
+            let tag = reader.read_u32()?;
+            reader.read_felt()?;
+            contributions.push(Contribution { contributor, amount, tag });
+        }
+        reader.finish()?;
+
+        Ok(Self {
+            beneficiary,
+            faucet_id,
+            target,
+            min_contribution,
+            deadline,
+            tag,
+            serial_num,
+            contributions,
+        })
+    }
+
+    /// Encodes these inputs as the inputs of a CROWDFUND note.
+    fn to_inputs(&self) -> Result<NoteInputs, NoteError> {
+        let builder = NoteInputs::builder()
+            .push_account_id(self.beneficiary)
+            .push_account_id(self.faucet_id)
+            .push_u64(self.target)
+            .push_u32(self.deadline)
+            .push_u32(self.tag.inner())
+            .push_u64(self.min_contribution)
+            .push_felt(ZERO)
+            .push_felt(ZERO)
+            .push_word(self.serial_num);
+
+        self.contributions
+            .iter()
+            .fold(builder, |builder, contribution| {
+                builder
+                    .push_account_id(contribution.contributor)
+                    .push_u64(contribution.amount)
+                    .push_u32(contribution.tag)
+                    .push_felt(ZERO)
+            })
+            .build()
+    }
+
+    /// Returns the amount of the funds held by a CROWDFUND note with these inputs.
+    fn funds(&self, assets: &NoteAssets) -> Result<u64, NoteError> {
+        let mut assets = assets.iter();
+        match (assets.next(), assets.next(), self.contributions.is_empty()) {
+            (None, None, true) => Ok(0),
+            (Some(Asset::Fungible(funds)), None, false) if funds.faucet_id() == self.faucet_id => {
+                Ok(funds.amount())
+            },
+            _ => Err(NoteError::invalid_note_inputs(
+                "assets of the crowdfund do not match its contributions",
+            )),
+        }
+    }
+
+    /// Returns the serial number of a note of the specified kind created from the campaign.
+    fn derive_serial_num(&self, kind: u64, index: u64) -> Word {
+        let kind = [Felt::new(kind), Felt::new(index), ZERO, ZERO];
+        Hasher::merge(&[self.serial_num.into(), kind.into()]).into()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a note tag for a crowdfund note raising assets of the specified faucet.
+///
+/// Use case ID for the returned tag is set to 2, and the payload is built from the 16 most
+/// significant bits of the faucet ID.
+fn build_crowdfund_tag(faucet_id: AccountId) -> Result<NoteTag, NoteError> {
+    let payload = (u64::from(faucet_id) >> 48) as u16;
+    NoteTag::for_public_use_case(CROWDFUND_USE_CASE_ID, payload, NoteExecutionHint::Local)
+}
@@ -2,17 +2,29 @@ use alloc::vec::Vec;
 
 use miden_objects::{
     accounts::AccountId,
-    assets::Asset,
-    crypto::rand::FeltRng,
+    assets::{Asset, FungibleAsset},
+    crypto::{dsa::rpo_falcon512::PublicKey, rand::FeltRng},
     notes::{
-        Note, NoteAssets, NoteDetails, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient,
-        NoteTag, NoteType,
+        Note, NoteAssets, NoteAux, NoteDetails, NoteExecutionHint, NoteInputs, NoteMetadata,
+        NoteRecipient, NoteScript, NoteTag, NoteType, PartialNote,
     },
-    NoteError, Word, ZERO,
+    Digest, Felt, NoteError, Word, MAX_OUTPUT_NOTES_PER_TX, ZERO,
 };
 
 use self::utils::build_note_script;
 
+mod auction;
+pub use auction::{
+    build_auction_bid_args, create_auction_bid_notes, create_auction_note,
+    create_auction_settlement_note,
+};
+
+mod crowdfund;
+pub use crowdfund::{
+    build_crowdfund_contribution_args, create_crowdfund_contribution_note, create_crowdfund_note,
+    create_crowdfund_refund_notes,
+};
+
 pub mod utils;
 
 // STANDARDIZED SCRIPTS
@@ -24,29 +36,72 @@ pub mod utils;
 /// by specifying the target's account ID.
 ///
 /// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
-/// is set to the target's account ID.
+/// is set to the target's account ID, and its `aux` value to the provided one, which must encode a
+/// [NoteAux](miden_objects::notes::NoteAux).
 ///
 /// # Errors
-/// Returns an error if deserialization or compilation of the `P2ID` script fails.
+/// Returns an error if:
+/// - The `aux` value does not encode a known aux schema.
+/// - Deserialization or compilation of the `P2ID` script fails.
 pub fn create_p2id_note<R: FeltRng>(
     sender: AccountId,
     target: AccountId,
     assets: Vec<Asset>,
     note_type: NoteType,
+    aux: Felt,
     mut rng: R,
 ) -> Result<Note, NoteError> {
+    NoteAux::try_from(aux)?;
+
     let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"));
     let note_script = build_note_script(bytes)?;
 
-    let inputs = NoteInputs::new(vec![target.into()])?;
-    let tag = NoteTag::from_account_id(target, NoteExecutionHint::Local)?;
-    let serial_num = rng.draw_word();
-    let aux = ZERO;
+    build_p2id_note(sender, target, assets, note_type, aux, note_script, rng.draw_word())
+}
 
-    let metadata = NoteMetadata::new(sender, note_type, tag, aux)?;
-    let vault = NoteAssets::new(assets)?;
-    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
-    Ok(Note::new(vault, metadata, recipient))
+/// Generates a batch of P2ID notes paying out the specified assets to each of the targets, e.g.,
+/// to disburse a payroll from the `sender` account in a single transaction.
+///
+/// The notes are returned in the order of the payouts and can be created in a single transaction
+/// via the `send_notes()` procedure of the transaction script builder. The passed-in `rng` is used
+/// to generate the serial numbers of the notes. The tag of each note is set to its target's
+/// account ID.
+///
+/// # Errors
+/// Returns an error if:
+/// - The number of payouts exceeds the maximum number of output notes of a transaction.
+/// - A payout holds no assets, or its assets cannot be stored in a note.
+/// - Deserialization or compilation of the `P2ID` script fails.
+pub fn create_batch_p2id_notes<R: FeltRng>(
+    sender: AccountId,
+    payouts: Vec<(AccountId, Vec<Asset>)>,
+    note_type: NoteType,
+    mut rng: R,
+) -> Result<Vec<Note>, NoteError> {
+    if payouts.len() > MAX_OUTPUT_NOTES_PER_TX {
+        return Err(NoteError::TooManyNotes(payouts.len()));
+    }
+
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    payouts
+        .into_iter()
+        .map(|(target, assets)| {
+            if assets.is_empty() {
+                return Err(NoteError::EmptyNoteAssets);
+            }
+            build_p2id_note(
+                sender,
+                target,
+                assets,
+                note_type,
+                ZERO,
+                note_script.clone(),
+                rng.draw_word(),
+            )
+        })
+        .collect()
 }
 
 /// Generates a P2IDR note - pay to id with recall after a certain block height.
@@ -57,25 +112,30 @@ pub fn create_p2id_note<R: FeltRng>(
 /// specified timeframe.
 ///
 /// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
-/// is set to the target's account ID.
+/// is set to the target's account ID, and its `aux` value to the provided one, which must encode a
+/// [NoteAux](miden_objects::notes::NoteAux).
 ///
 /// # Errors
-/// Returns an error if deserialization or compilation of the `P2IDR` script fails.
+/// Returns an error if:
+/// - The `aux` value does not encode a known aux schema.
+/// - Deserialization or compilation of the `P2IDR` script fails.
 pub fn create_p2idr_note<R: FeltRng>(
     sender: AccountId,
     target: AccountId,
     assets: Vec<Asset>,
     note_type: NoteType,
+    aux: Felt,
     recall_height: u32,
     mut rng: R,
 ) -> Result<Note, NoteError> {
+    NoteAux::try_from(aux)?;
+
     let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDR.masb"));
     let note_script = build_note_script(bytes)?;
 
-    let inputs = NoteInputs::new(vec![target.into(), recall_height.into()])?;
+    let inputs = NoteInputs::builder().push_account_id(target).push_u32(recall_height).build()?;
     let tag = NoteTag::from_account_id(target, NoteExecutionHint::Local)?;
     let serial_num = rng.draw_word();
-    let aux = ZERO;
 
     let vault = NoteAssets::new(assets)?;
     let metadata = NoteMetadata::new(sender, note_type, tag, aux)?;
@@ -90,41 +150,40 @@ pub fn create_p2idr_note<R: FeltRng>(
 /// is willing to consume the note. The consumer will receive the `offered_asset` and will create a
 /// new P2ID note with `sender` as target, containing the `requested_asset`.
 ///
+/// The `aux` value of the SWAP note is set to the provided one, which must encode a
+/// [NoteAux](miden_objects::notes::NoteAux), e.g., the ID of the order filled by the swap.
+///
 /// # Errors
-/// Returns an error if deserialization or compilation of the `SWAP` script fails.
+/// Returns an error if:
+/// - The `aux` value does not encode a known aux schema.
+/// - Deserialization or compilation of the `SWAP` script fails.
 pub fn create_swap_note<R: FeltRng>(
     sender: AccountId,
     offered_asset: Asset,
     requested_asset: Asset,
     note_type: NoteType,
+    aux: Felt,
     mut rng: R,
 ) -> Result<(Note, NoteDetails), NoteError> {
+    NoteAux::try_from(aux)?;
+
     let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb"));
     let note_script = build_note_script(bytes)?;
 
     let payback_serial_num = rng.draw_word();
     let payback_recipient = utils::build_p2id_recipient(sender, payback_serial_num)?;
 
-    let payback_recipient_word: Word = payback_recipient.digest().into();
-    let requested_asset_word: Word = requested_asset.into();
     let payback_tag = NoteTag::from_account_id(sender, NoteExecutionHint::Local)?;
 
-    let inputs = NoteInputs::new(vec![
-        payback_recipient_word[0],
-        payback_recipient_word[1],
-        payback_recipient_word[2],
-        payback_recipient_word[3],
-        requested_asset_word[0],
-        requested_asset_word[1],
-        requested_asset_word[2],
-        requested_asset_word[3],
-        payback_tag.inner().into(),
-    ])?;
+    let inputs = NoteInputs::builder()
+        .push_word(payback_recipient.digest().into())
+        .push_word(requested_asset.into())
+        .push_u32(payback_tag.inner())
+        .build()?;
 
     // build the tag for the SWAP use case
     let tag = build_swap_tag(note_type, &offered_asset, &requested_asset)?;
     let serial_num = rng.draw_word();
-    let aux = ZERO;
 
     // build the outgoing note
     let metadata = NoteMetadata::new(sender, note_type, tag, aux)?;
@@ -139,6 +198,169 @@ pub fn create_swap_note<R: FeltRng>(
     Ok((note, payback_note))
 }
 
+/// Generates a note carrying the provided `asset` to a recipient known only by its digest.
+///
+/// This enables paying to a recipient which was communicated as a single `RECIPIENT` digest, e.g.,
+/// by an exchange, without knowing the script, inputs and serial number of the note. Since the
+/// details of the note are not known to the sender, the returned [PartialNote] can only be used to
+/// compute the ID of the note, and the note must be of [NoteType::OffChain] type.
+///
+/// The returned note can be created in a transaction via the `send_asset` procedure of the basic
+/// wallet.
+///
+/// # Errors
+/// Returns an error if `note_type` is not [NoteType::OffChain] or if the tag is not valid for
+/// off-chain notes.
+pub fn create_note_to_recipient(
+    sender: AccountId,
+    recipient: Digest,
+    asset: Asset,
+    tag: NoteTag,
+    note_type: NoteType,
+) -> Result<PartialNote, NoteError> {
+    if note_type != NoteType::OffChain {
+        return Err(NoteError::InvalidNoteType(note_type));
+    }
+
+    let metadata = NoteMetadata::new(sender, note_type, tag, ZERO)?;
+    let assets = NoteAssets::new(vec![asset])?;
+    Ok(PartialNote::new(metadata, recipient, assets))
+}
+
+/// Generates a MIGRATE note - migration of a fungible asset to the successor of its faucet - and
+/// returns the note as well as the MIGRATION_VOUCHER note which the faucet creates when consuming
+/// it.
+///
+/// This script enables the `sender` account to migrate the provided `asset` to a new version of
+/// the faucet which issued it. The note is consumed by the issuing faucet, which burns the asset
+/// and creates the voucher note. The voucher can only be consumed by the `successor` faucet, which
+/// mints the same amount of its own asset and sends it to `sender` in a P2ID note.
+///
+/// The passed-in `rng` is used to generate the serial numbers of the MIGRATE note, the voucher note
+/// and the P2ID note carrying the replacement asset. The tag of MIGRATE note is set to the ID of the
+/// issuing faucet.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `MIGRATE` or `MIGRATION_VOUCHER`
+/// scripts fails.
+pub fn create_migrate_note<R: FeltRng>(
+    sender: AccountId,
+    asset: FungibleAsset,
+    successor: AccountId,
+    note_type: NoteType,
+    mut rng: R,
+) -> Result<(Note, Note), NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/MIGRATE.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    // build the voucher note which the faucet will create when consuming the MIGRATE note
+    let replacement_serial_num = rng.draw_word();
+    let replacement_recipient = utils::build_p2id_recipient(sender, replacement_serial_num)?;
+    let replacement_tag = NoteTag::from_account_id(sender, NoteExecutionHint::Local)?;
+    let voucher_serial_num = rng.draw_word();
+    let voucher = create_migration_voucher_note(
+        asset.faucet_id(),
+        successor,
+        asset.amount(),
+        replacement_tag,
+        replacement_recipient.digest(),
+        note_type,
+        voucher_serial_num,
+    )?;
+
+    let inputs = NoteInputs::builder()
+        .push_word(replacement_recipient.digest().into())
+        .push_word(voucher_serial_num)
+        .push_felt(note_type.into())
+        .push_u32(voucher.metadata().tag().inner())
+        .push_felt(note_type.into())
+        .push_u32(replacement_tag.inner())
+        .build()?;
+
+    let tag = NoteTag::from_account_id(asset.faucet_id(), NoteExecutionHint::Local)?;
+    let serial_num = rng.draw_word();
+    let aux = ZERO;
+
+    let metadata = NoteMetadata::new(sender, note_type, tag, aux)?;
+    let assets = NoteAssets::new(vec![asset.into()])?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok((Note::new(assets, metadata, recipient), voucher))
+}
+
+/// Generates a MIGRATION_VOUCHER note - a voucher for minting the replacement of an asset burned
+/// by the `faucet` in the `successor` faucet.
+///
+/// This is the note created by the `faucet` when consuming a MIGRATE note. When consuming the
+/// voucher, the `successor` mints `amount` of its own asset and sends it in a note with the
+/// provided tag and recipient. Both the voucher and the replacement note are of the provided
+/// `note_type`. The returned note's tag is set to the successor's account ID.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `MIGRATION_VOUCHER` script fails.
+pub fn create_migration_voucher_note(
+    faucet: AccountId,
+    successor: AccountId,
+    amount: u64,
+    replacement_tag: NoteTag,
+    replacement_recipient: Digest,
+    note_type: NoteType,
+    serial_num: Word,
+) -> Result<Note, NoteError> {
+    let inputs = NoteInputs::builder()
+        .push_felt(note_type.into())
+        .push_u32(replacement_tag.inner())
+        .push_u64(amount)
+        .push_account_id(successor)
+        .push_word(replacement_recipient.into())
+        .build()?;
+
+    let tag = NoteTag::from_account_id(successor, NoteExecutionHint::Local)?;
+    let aux = ZERO;
+
+    let metadata = NoteMetadata::new(faucet, note_type, tag, aux)?;
+    let assets = NoteAssets::new(vec![])?;
+    let recipient = utils::build_migration_voucher_recipient(serial_num, inputs)?;
+    Ok(Note::new(assets, metadata, recipient))
+}
+
+/// Generates a RECOVERY_APPROVAL note - an approval of the replacement of the main public key of
+/// the `target` account by the `guardian` account.
+///
+/// The `target` must be a basic wallet created with
+/// [create_basic_wallet_with_recovery()](crate::accounts::wallets::create_basic_wallet_with_recovery)
+/// which lists the `guardian` among its guardians. The first approval of `new_pub_key` initiates
+/// its recovery in the `target` account.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is set to the target's account ID.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `RECOVERY_APPROVAL` script fails.
+pub fn create_recovery_approval_note<R: FeltRng>(
+    guardian: AccountId,
+    target: AccountId,
+    new_pub_key: PublicKey,
+    note_type: NoteType,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let bytes =
+        include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/RECOVERY_APPROVAL.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let inputs = NoteInputs::builder()
+        .push_word(new_pub_key.into())
+        .push_account_id(target)
+        .build()?;
+    let tag = NoteTag::from_account_id(target, NoteExecutionHint::Local)?;
+    let serial_num = rng.draw_word();
+    let aux = ZERO;
+
+    let metadata = NoteMetadata::new(guardian, note_type, tag, aux)?;
+    let assets = NoteAssets::new(vec![])?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(assets, metadata, recipient))
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -175,3 +397,22 @@ fn build_swap_tag(
         _ => NoteTag::for_local_use_case(SWAP_USE_CASE_ID, payload),
     }
 }
+
+/// Returns a P2ID note with the specified script paying out the assets to the target.
+fn build_p2id_note(
+    sender: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    aux: Felt,
+    note_script: NoteScript,
+    serial_num: Word,
+) -> Result<Note, NoteError> {
+    let inputs = NoteInputs::builder().push_account_id(target).build()?;
+    let tag = NoteTag::from_account_id(target, NoteExecutionHint::Local)?;
+
+    let metadata = NoteMetadata::new(sender, note_type, tag, aux)?;
+    let vault = NoteAssets::new(assets)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(vault, metadata, recipient))
+}
@@ -1,8 +1,10 @@
+use alloc::collections::BTreeMap;
+
 use miden_objects::{
     accounts::AccountId,
     assembly::ProgramAst,
     notes::{NoteInputs, NoteRecipient, NoteScript},
-    NoteError, Word,
+    Digest, NoteError, Word,
 };
 
 use crate::transaction::TransactionKernel;
@@ -29,7 +31,75 @@ pub fn build_p2id_recipient(
     // the script hash every time we call the SWAP script
     let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"));
     let note_script = build_note_script(bytes)?;
-    let note_inputs = NoteInputs::new(vec![target.into()])?;
+    let note_inputs = NoteInputs::builder().push_account_id(target).build()?;
 
     Ok(NoteRecipient::new(serial_num, note_script, note_inputs))
 }
+
+/// Creates a [NoteRecipient] for the MIGRATION_VOUCHER note.
+///
+/// Notes created with this recipient will be MIGRATION_VOUCHER notes consumable by the successor
+/// faucet specified in the inputs.
+pub fn build_migration_voucher_recipient(
+    serial_num: Word,
+    inputs: NoteInputs,
+) -> Result<NoteRecipient, NoteError> {
+    let bytes =
+        include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/MIGRATION_VOUCHER.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    Ok(NoteRecipient::new(serial_num, note_script, inputs))
+}
+
+/// Returns the root of the MIGRATION_VOUCHER note script.
+pub fn migration_voucher_script_root() -> Result<Digest, NoteError> {
+    let bytes =
+        include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/MIGRATION_VOUCHER.masb"));
+    Ok(build_note_script(bytes)?.hash())
+}
+
+/// Returns a map of the roots of all standard note scripts to their names.
+///
+/// The map can be used to recognize well-known scripts, e.g., when rendering notes via
+/// `Note::to_pretty_json()`.
+pub fn well_known_note_scripts() -> Result<BTreeMap<Digest, &'static str>, NoteError> {
+    let scripts: [(&'static str, &[u8]); 8] = [
+        (
+            "P2ID",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb")),
+        ),
+        (
+            "P2IDR",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDR.masb")),
+        ),
+        (
+            "SWAP",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb")),
+        ),
+        (
+            "MIGRATE",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/MIGRATE.masb")),
+        ),
+        (
+            "MIGRATION_VOUCHER",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/MIGRATION_VOUCHER.masb")),
+        ),
+        (
+            "AUCTION",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/AUCTION.masb")),
+        ),
+        (
+            "CROWDFUND",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/CROWDFUND.masb")),
+        ),
+        (
+            "RECOVERY_APPROVAL",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/RECOVERY_APPROVAL.masb")),
+        ),
+    ];
+
+    scripts
+        .into_iter()
+        .map(|(name, bytes)| Ok((build_note_script(bytes)?.hash(), name)))
+        .collect()
+}
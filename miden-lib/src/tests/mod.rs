@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use std::path::PathBuf;
 
 use miden_objects::{vm::StackInputs, Felt, Hasher, Word, ONE, ZERO};
@@ -6,6 +7,7 @@ use vm_processor::{ContextId, MemAdviceProvider, Process, ProcessState};
 use super::Library;
 
 mod test_account;
+mod test_array;
 mod test_asset;
 mod test_asset_vault;
 mod test_epilogue;
@@ -37,6 +39,43 @@ fn test_compile() {
     assert!(exists);
 }
 
+#[test]
+fn test_assertion_locations() {
+    // an error code shared by assertions in different modules maps to all of them
+    let err_code = 0x0002002a;
+    let locations = super::transaction::assertion_locations(err_code).collect::<Vec<_>>();
+    assert_eq!(locations.len(), 2);
+
+    for location in locations {
+        assert_eq!(location.err_code, err_code);
+        assert_eq!(location.err_name, "ERR_NOTE_TOO_MANY_ASSETS");
+
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(location.file);
+        let source = std::fs::read_to_string(path).unwrap();
+        let line = source.lines().nth(location.line as usize - 1).unwrap();
+        assert!(line.contains(".err=ERR_NOTE_TOO_MANY_ASSETS"), "{location}: {line}");
+    }
+
+    assert_eq!(super::transaction::assertion_locations(0).count(), 0);
+}
+
+#[test]
+fn test_kernel_limits() {
+    let path = build_module_path(TX_KERNEL_DIR, "constants.masm");
+    let source = std::fs::read_to_string(path).unwrap();
+    let constant = |name: &str| -> usize {
+        let prefix = format!("const.{name}=");
+        let line = source.lines().find(|line| line.starts_with(&prefix)).unwrap();
+        line[prefix.len()..].parse().unwrap()
+    };
+
+    let limits = super::transaction::TransactionKernel::limits();
+    assert_eq!(constant("MAX_INPUT_NOTES_PER_TX"), limits.max_input_notes());
+    assert_eq!(constant("MAX_OUTPUT_NOTES_PER_TX"), limits.max_output_notes());
+    assert_eq!(constant("MAX_INPUTS_PER_NOTE"), limits.max_note_inputs());
+    assert_eq!(constant("MAX_ASSETS_PER_NOTE"), limits.max_note_assets());
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -0,0 +1,146 @@
+use miden_objects::accounts::AccountStorage;
+use mock::{
+    mock::{
+        account::{storage_map_2, MockAccountType, STORAGE_INDEX_2},
+        notes::AssetPreservationStatus,
+        transaction::mock_inputs,
+    },
+    prepare_transaction,
+    procedures::prepare_word,
+    run_tx,
+};
+
+use super::{Felt, ProcessState, Word, ZERO};
+use crate::accounts::array::StorageArray;
+
+// CONSTANTS
+// ================================================================================================
+
+/// An empty value slot of the mock account used as the length slot of the array.
+const LENGTH_SLOT: u8 = 5;
+
+const VALUE_0: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+const VALUE_1: Word = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+const VALUE_2: Word = [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)];
+
+// STORAGE ARRAY TESTS
+// ================================================================================================
+
+#[test]
+fn test_array_push_get_set() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    // the array elements are added to the map which already backs the entries slot
+    let mut entries = storage_map_2();
+    entries.insert(StorageArray::entry_key(0), VALUE_0);
+    entries.insert(StorageArray::entry_key(1), VALUE_2);
+
+    let sentinel: Word = [Felt::new(91), Felt::new(92), Felt::new(93), Felt::new(94)];
+
+    let code = format!(
+        "
+        use.miden::account
+        use.miden::array
+        use.miden::kernels::tx::prologue
+
+        begin
+            # prepare the transaction
+            exec.prologue::prepare_transaction
+
+            # push a word which must be preserved by the array procedures
+            push.{sentinel}
+
+            # append two elements
+            push.{value_0} push.{entries_slot} push.{length_slot} exec.array::push
+            push.{value_1} push.{entries_slot} push.{length_slot} exec.array::push
+            # => [1, 0, SENTINEL]
+
+            # replace the second element
+            push.{value_2} push.1 push.{entries_slot} push.{length_slot} exec.array::set
+            push.{value_1} assert_eqw
+
+            # read the elements back
+            push.0 push.{entries_slot} push.{length_slot} exec.array::get
+            push.{value_0} assert_eqw
+            push.1 push.{entries_slot} push.{length_slot} exec.array::get
+            push.{value_2} assert_eqw
+
+            # check the length of the array and the map of its elements
+            push.{length_slot} exec.array::get_length push.2 assert_eq
+            push.{entries_slot} exec.account::get_item push.{entries_root} assert_eqw
+        end
+        ",
+        sentinel = prepare_word(&sentinel),
+        value_0 = prepare_word(&VALUE_0),
+        value_1 = prepare_word(&VALUE_1),
+        value_2 = prepare_word(&VALUE_2),
+        entries_slot = STORAGE_INDEX_2,
+        length_slot = LENGTH_SLOT,
+        entries_root = prepare_word(&entries.root()),
+    );
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, code.as_str(), None);
+    let process = run_tx(&transaction).unwrap();
+
+    // the returned indices are followed by the untouched rest of the stack
+    assert_eq!(process.get_stack_item(0), Felt::new(1));
+    assert_eq!(process.get_stack_item(1), ZERO);
+    assert_eq!(
+        [
+            process.get_stack_item(2),
+            process.get_stack_item(3),
+            process.get_stack_item(4),
+            process.get_stack_item(5)
+        ],
+        [sentinel[3], sentinel[2], sentinel[1], sentinel[0]]
+    );
+    assert_eq!(process.get_stack_item(6), ZERO);
+}
+
+#[test]
+fn test_array_index_out_of_bounds() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    for procedure in ["get", "set"] {
+        let code = format!(
+            "
+            use.miden::array
+            use.miden::kernels::tx::prologue
+
+            begin
+                exec.prologue::prepare_transaction
+
+                push.{value_0} push.{entries_slot} push.{length_slot} exec.array::push drop
+                push.{value_1} push.1 push.{entries_slot} push.{length_slot} exec.array::{procedure}
+            end
+            ",
+            value_0 = prepare_word(&VALUE_0),
+            value_1 = prepare_word(&VALUE_1),
+            entries_slot = STORAGE_INDEX_2,
+            length_slot = LENGTH_SLOT,
+        );
+
+        let transaction =
+            prepare_transaction(tx_inputs.clone(), tx_args.clone(), code.as_str(), None);
+        assert!(run_tx(&transaction).is_err());
+    }
+}
+
+#[test]
+fn test_array_storage_layout() {
+    let array = StorageArray::new(LENGTH_SLOT, STORAGE_INDEX_2);
+    let (items, entries) = array.storage_items(&[VALUE_0, VALUE_1]).unwrap();
+    let storage = AccountStorage::new(items, vec![entries]).unwrap();
+
+    assert_eq!(array.len(&storage), 2);
+    assert_eq!(array.get(&storage, 1).unwrap(), Some(VALUE_1));
+    assert_eq!(array.get(&storage, 2).unwrap(), None);
+    assert_eq!(array.elements(&storage).unwrap(), vec![VALUE_0, VALUE_1]);
+
+    // the entries slot must hold the map of the array elements
+    let storage = AccountStorage::new(vec![], vec![]).unwrap();
+    assert!(array.is_empty(&storage));
+    assert!(array.elements(&storage).is_err());
+}
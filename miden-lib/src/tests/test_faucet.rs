@@ -650,3 +650,14 @@ fn test_get_total_issuance_succeeds() {
     let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
     let _process = run_tx(&transaction).unwrap();
 }
+
+// FAUCET STORAGE TESTS
+// ================================================================================================
+
+#[test]
+fn test_faucet_storage_data_slot_matches_account_fixtures() {
+    assert_eq!(
+        FAUCET_STORAGE_DATA_SLOT,
+        miden_objects::testing::accounts::FAUCET_STORAGE_DATA_SLOT
+    );
+}
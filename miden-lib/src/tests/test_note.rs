@@ -1,9 +1,16 @@
 use alloc::{collections::BTreeMap, string::String};
 
 use miden_objects::{
-    notes::Note,
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        AccountId,
+    },
+    crypto::rand::RpoRandomCoin,
+    notes::{Note, NoteAux, NoteType},
     transaction::{PreparedTransaction, TransactionArgs},
-    WORD_SIZE,
+    NoteError, ONE, WORD_SIZE,
 };
 use mock::{
     consumed_note_data_ptr,
@@ -17,7 +24,10 @@ use mock::{
 };
 
 use super::{ContextId, Felt, Process, ProcessState, ZERO};
-use crate::transaction::memory::CURRENT_CONSUMED_NOTE_PTR;
+use crate::{
+    notes::{create_p2id_note, utils::well_known_note_scripts},
+    transaction::{kernel_memory::KernelMemoryReader, memory::CURRENT_CONSUMED_NOTE_PTR},
+};
 
 #[test]
 fn test_get_sender_no_sender() {
@@ -361,6 +371,36 @@ fn test_note_script_and_note_args() {
     assert_eq!(process.stack.get_word(1), note_args[1]);
 }
 
+#[test]
+fn test_well_known_note_scripts() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let rng = RpoRandomCoin::new([ONE; 4]);
+    let note = create_p2id_note(sender, target, vec![], NoteType::Public, ZERO, rng).unwrap();
+
+    let known_scripts = well_known_note_scripts().unwrap();
+    assert_eq!(known_scripts.len(), 8);
+    assert_eq!(known_scripts.get(&note.script().hash()), Some(&"P2ID"));
+}
+
+#[test]
+fn test_standard_notes_validate_aux() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let rng = RpoRandomCoin::new([ONE; 4]);
+
+    let aux = NoteAux::payment_reference(42).unwrap();
+    let note = create_p2id_note(sender, target, vec![], NoteType::Public, aux.into(), rng).unwrap();
+    assert_eq!(note.metadata().structured_aux(), Ok(aux));
+
+    // aux values of unknown schemas are rejected
+    let aux = Felt::new(u64::from(u8::MAX) << 56);
+    assert!(matches!(
+        create_p2id_note(sender, target, vec![], NoteType::Public, aux, rng),
+        Err(NoteError::InvalidNoteAuxSchema(u8::MAX))
+    ));
+}
+
 fn note_setup_stack_assertions(process: &Process<MockHost>, inputs: &PreparedTransaction) {
     let mut expected_stack = [ZERO; 16];
 
@@ -379,4 +419,15 @@ fn note_setup_memory_assertions(process: &Process<MockHost>) {
         process.get_mem_value(ContextId::root(), CURRENT_CONSUMED_NOTE_PTR).unwrap()[0],
         Felt::from(consumed_note_data_ptr(0))
     );
+
+    // assert that the kernel memory reader resolves the pointer to the first note
+    let kernel_memory = KernelMemoryReader::new(process);
+    assert_eq!(
+        kernel_memory.current_consumed_note_ptr().unwrap(),
+        Some(consumed_note_data_ptr(0))
+    );
+    assert_eq!(
+        kernel_memory.current_consumed_note_id().unwrap(),
+        kernel_memory.consumed_note_id(0)
+    );
 }
@@ -1,24 +1,36 @@
 use alloc::{collections::BTreeMap, vec::Vec};
 
 use miden_objects::{
+    accounts::{
+        Account, AccountId, AccountIdAnchor, AccountStorageType, AccountType, NetworkParams,
+    },
     assembly::ProgramAst,
-    transaction::{PreparedTransaction, TransactionArgs, TransactionScript},
-    Digest,
+    assets::AssetVault,
+    notes::Nullifier,
+    transaction::{
+        InputNote, InputNotes, PreparedTransaction, ToInputNoteCommitments, TransactionArgs,
+        TransactionInputs, TransactionScript,
+    },
+    Digest, Hasher,
 };
 use mock::{
     consumed_note_data_ptr,
     mock::{
-        account::{generate_account_seed, AccountSeedType, MockAccountType},
+        account::{
+            generate_account_seed, mock_account_code, mock_account_storage, AccountSeedType,
+            MockAccountType,
+        },
         host::MockHost,
         notes::AssetPreservationStatus,
         transaction::{mock_inputs, mock_inputs_with_account_seed},
     },
-    prepare_transaction, run_tx, run_tx_with_inputs,
+    prepare_transaction, run_tx, run_tx_with_inputs, run_tx_with_network_params,
 };
 use vm_processor::AdviceInputs;
 
 use super::{build_module_path, ContextId, Felt, Process, ProcessState, Word, TX_KERNEL_DIR, ZERO};
 use crate::transaction::{
+    kernel_memory::{memory_section, KernelMemoryReader, KERNEL_MEMORY_SECTIONS},
     memory::{
         MemoryOffset, ACCT_CODE_ROOT_PTR, ACCT_DB_ROOT_PTR, ACCT_ID_AND_NONCE_PTR, ACCT_ID_PTR,
         ACCT_STORAGE_ROOT_PTR, ACCT_STORAGE_SLOT_TYPE_DATA_OFFSET, ACCT_VAULT_ROOT_PTR,
@@ -28,9 +40,10 @@ use crate::transaction::{
         CONSUMED_NOTE_INPUTS_HASH_OFFSET, CONSUMED_NOTE_METADATA_OFFSET,
         CONSUMED_NOTE_NUM_ASSETS_OFFSET, CONSUMED_NOTE_NUM_INPUTS_OFFSET,
         CONSUMED_NOTE_SCRIPT_ROOT_OFFSET, CONSUMED_NOTE_SECTION_OFFSET,
-        CONSUMED_NOTE_SERIAL_NUM_OFFSET, INIT_ACCT_HASH_PTR, INIT_NONCE_PTR, NOTE_ROOT_PTR,
-        NULLIFIER_COM_PTR, NULLIFIER_DB_ROOT_PTR, PREV_BLOCK_HASH_PTR, PROOF_HASH_PTR,
-        PROTOCOL_VERSION_IDX, TIMESTAMP_IDX, TX_SCRIPT_ROOT_PTR,
+        CONSUMED_NOTE_SERIAL_NUM_OFFSET, FAUCET_ACCT_SEED_DIGEST_MODULUS_PTR, INIT_ACCT_HASH_PTR,
+        INIT_NONCE_PTR, NOTE_ROOT_PTR, NULLIFIER_COM_PTR, NULLIFIER_DB_ROOT_PTR,
+        PREV_BLOCK_HASH_PTR, PROOF_HASH_PTR, PROTOCOL_VERSION_IDX,
+        REGULAR_ACCT_SEED_DIGEST_MODULUS_PTR, TIMESTAMP_IDX, TX_SCRIPT_ROOT_PTR,
     },
     TransactionKernel,
 };
@@ -90,6 +103,94 @@ fn test_transaction_prologue() {
     chain_mmr_memory_assertions(&process, &transaction);
     account_data_memory_assertions(&process, &transaction);
     consumed_notes_memory_assertions(&process, &transaction, &note_args);
+    kernel_memory_reader_assertions(&process, &transaction);
+}
+
+#[test]
+fn test_prologue_unauthenticated_notes() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    // consume the first note without authenticating it
+    let (account, account_seed, block_header, block_chain, input_notes) = tx_inputs.into_parts();
+    let input_notes = input_notes
+        .into_iter()
+        .enumerate()
+        .map(|(idx, note)| match idx {
+            0 => InputNote::unauthenticated(note.note().clone()),
+            _ => note,
+        })
+        .collect();
+    let input_notes = InputNotes::new(input_notes).unwrap();
+    assert_eq!(input_notes.num_unauthenticated_notes(), 1);
+
+    let tx_inputs =
+        TransactionInputs::new(account, account_seed, block_header, block_chain, input_notes)
+            .unwrap();
+
+    let code = "
+        begin
+            exec.prepare_transaction
+        end
+        ";
+
+    let assembly_file = build_module_path(TX_KERNEL_DIR, PROLOGUE_FILE);
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, Some(assembly_file));
+    let process = run_tx(&transaction).unwrap();
+
+    // the input notes commitment includes the authentication hash of the unauthenticated note
+    let input_notes = transaction.input_notes();
+    assert_eq!(
+        read_root_mem_value(&process, NULLIFIER_COM_PTR),
+        input_notes.commitment().as_elements()
+    );
+
+    let nullifiers: Vec<Nullifier> = input_notes.iter().map(|note| note.nullifier()).collect();
+    assert_ne!(input_notes.commitment(), InputNotes::new(nullifiers).unwrap().commitment());
+}
+
+fn kernel_memory_reader_assertions(process: &Process<MockHost>, inputs: &PreparedTransaction) {
+    let kernel_memory = KernelMemoryReader::new(process);
+
+    assert_eq!(kernel_memory.block_hash(), Some(inputs.block_header().hash()));
+    assert_eq!(kernel_memory.block_num().unwrap(), Some(inputs.block_header().block_num()));
+    assert_eq!(kernel_memory.account_id().unwrap(), Some(inputs.account().id()));
+    assert_eq!(kernel_memory.account_data_id().unwrap(), Some(inputs.account().id()));
+    assert_eq!(kernel_memory.init_account_hash(), Some(inputs.account().hash()));
+    assert_eq!(kernel_memory.init_nonce(), Some(inputs.account().nonce()));
+    assert_eq!(kernel_memory.account_nonce(), Some(inputs.account().nonce()));
+    assert_eq!(kernel_memory.account_vault_root(), Some(inputs.account().vault().commitment()));
+    assert_eq!(kernel_memory.account_storage_root(), Some(inputs.account().storage().root()));
+    assert_eq!(kernel_memory.account_code_root(), Some(inputs.account().code().root()));
+
+    // the prologue points the current consumed note to the first note
+    assert_eq!(
+        kernel_memory.current_consumed_note_ptr().unwrap(),
+        Some(consumed_note_data_ptr(0))
+    );
+
+    let input_notes = inputs.input_notes();
+    assert_eq!(
+        kernel_memory.num_consumed_notes().unwrap(),
+        Some(input_notes.num_notes() as u32)
+    );
+    for (note_idx, input_note) in input_notes.iter().enumerate() {
+        let note_idx = note_idx as u32;
+        assert_eq!(kernel_memory.consumed_note_id(note_idx), Some(input_note.id()));
+        assert_eq!(
+            kernel_memory.consumed_note_nullifier(note_idx),
+            Some(input_note.note().nullifier().inner())
+        );
+    }
+
+    // the sections are ordered and disjoint, and cover all pointers read above
+    for sections in KERNEL_MEMORY_SECTIONS.windows(2) {
+        assert!(sections[0].end <= sections[1].start);
+    }
+    assert_eq!(memory_section(ACCT_STORAGE_ROOT_PTR).unwrap().name, "account_data");
+    assert_eq!(memory_section(consumed_note_data_ptr(1)).unwrap().name, "consumed_notes");
+    assert_eq!(memory_section(NOTE_ROOT_PTR).unwrap().name, "block_data");
+    assert!(memory_section(CHAIN_MMR_PEAKS_PTR + 200).is_none());
 }
 
 fn global_input_memory_assertions(process: &Process<MockHost>, inputs: &PreparedTransaction) {
@@ -122,6 +223,18 @@ fn global_input_memory_assertions(process: &Process<MockHost>, inputs: &Prepared
         read_root_mem_value(process, TX_SCRIPT_ROOT_PTR),
         **inputs.tx_args().tx_script().as_ref().unwrap().hash()
     );
+
+    // The moduli used to check the proof-of-work of new account seeds should be stored at the
+    // REGULAR_ACCT_SEED_DIGEST_MODULUS_PTR and FAUCET_ACCT_SEED_DIGEST_MODULUS_PTR
+    let network_params = NetworkParams::testing();
+    assert_eq!(
+        read_root_mem_value(process, REGULAR_ACCT_SEED_DIGEST_MODULUS_PTR)[0],
+        Felt::new(1 << network_params.regular_account_seed_digest_min_trailing_zeros())
+    );
+    assert_eq!(
+        read_root_mem_value(process, FAUCET_ACCT_SEED_DIGEST_MODULUS_PTR)[0],
+        Felt::new(1 << network_params.faucet_seed_digest_min_trailing_zeros())
+    );
 }
 
 fn block_data_memory_assertions(process: &Process<MockHost>, inputs: &PreparedTransaction) {
@@ -360,6 +473,67 @@ pub fn test_prologue_create_account() {
     let _process = run_tx(&transaction).unwrap();
 }
 
+#[test]
+pub fn test_prologue_create_account_anchored() {
+    let (tx_inputs, _) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let (_, _, block_header, block_chain, input_notes) = tx_inputs.into_parts();
+
+    let anchor = AccountIdAnchor::try_from(block_chain.get_block(0).unwrap()).unwrap();
+    let code = mock_account_code(&TransactionKernel::assembler());
+    let storage = mock_account_storage();
+    let network_params = NetworkParams::testing();
+    let account_seed = AccountId::get_anchored_account_seed(
+        [5; 32],
+        anchor,
+        AccountType::RegularAccountUpdatableCode,
+        AccountStorageType::OffChain,
+        code.root(),
+        storage.root(),
+        &network_params,
+    )
+    .unwrap();
+    let account_id = AccountId::new_anchored_with_params(
+        account_seed,
+        anchor,
+        AccountType::RegularAccountUpdatableCode,
+        AccountStorageType::OffChain,
+        code.root(),
+        storage.root(),
+        &network_params,
+    )
+    .unwrap();
+    let account = Account::new(account_id, AssetVault::default(), storage, code, ZERO);
+
+    let tx_inputs =
+        TransactionInputs::new(account, Some(account_seed), block_header, block_chain, input_notes)
+            .unwrap();
+    let code = "
+    use.miden::kernels::tx::prologue
+
+    begin
+        exec.prologue::prepare_transaction
+    end
+    ";
+
+    let transaction = prepare_transaction(tx_inputs, TransactionArgs::default(), code, None);
+    let _process = run_tx(&transaction).unwrap();
+
+    // the seed is rejected by a network requiring more proof-of-work
+    let network_params = NetworkParams::new(31, 31).unwrap();
+    let process =
+        run_tx_with_network_params(&transaction, AdviceInputs::default(), &network_params);
+    assert!(process.is_err());
+
+    // lets override the seed with an invalid seed to ensure the kernel fails
+    let account_seed_key = [account_id.into(), ZERO, ZERO, ZERO];
+    let adv_inputs =
+        AdviceInputs::default().with_map([(Digest::from(account_seed_key), vec![ZERO; 4])]);
+
+    let process = run_tx_with_inputs(&transaction, adv_inputs);
+    assert!(process.is_err());
+}
+
 #[cfg_attr(not(feature = "testing"), ignore)]
 #[test]
 pub fn test_prologue_create_account_valid_fungible_faucet_reserved_slot() {
@@ -502,6 +676,51 @@ pub fn test_prologue_create_account_invalid_seed() {
     assert!(process.is_err());
 }
 
+#[cfg_attr(not(feature = "testing"), ignore)]
+#[test]
+pub fn test_prologue_create_account_seed_difficulty() {
+    let (acct_id, account_seed) =
+        generate_account_seed(AccountSeedType::NonFungibleFaucetValidReservedSlot);
+    let (tx_inputs, tx_args) = mock_inputs_with_account_seed(
+        MockAccountType::NonFungibleFaucet {
+            acct_id: acct_id.into(),
+            nonce: ZERO,
+            empty_reserved_slot: true,
+        },
+        AssetPreservationStatus::Preserved,
+        Some(account_seed),
+    );
+
+    // compute the proof-of-work of the seed
+    let account = tx_inputs.account();
+    let mut elements = account_seed.to_vec();
+    elements.extend(*account.code().root());
+    elements.extend(*account.storage().root());
+    elements.resize(16, ZERO);
+    let pow = Hasher::hash_elements(&elements)[3].as_int().trailing_zeros();
+
+    let code = "
+    use.miden::kernels::tx::prologue
+
+    begin
+        exec.prologue::prepare_transaction
+    end
+    ";
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, None);
+
+    // the seed is accepted by a network requiring exactly its proof-of-work
+    let network_params = NetworkParams::new(0, pow).unwrap();
+    let process =
+        run_tx_with_network_params(&transaction, AdviceInputs::default(), &network_params);
+    assert!(process.is_ok());
+
+    // the seed is rejected by a network requiring more proof-of-work
+    let network_params = NetworkParams::new(0, pow + 1).unwrap();
+    let process =
+        run_tx_with_network_params(&transaction, AdviceInputs::default(), &network_params);
+    assert!(process.is_err());
+}
+
 #[test]
 fn test_get_blk_version() {
     let (tx_inputs, tx_args) =
@@ -458,6 +458,26 @@ fn test_create_note_and_add_same_nft_twice() {
     );
 }
 
+#[test]
+fn test_get_block_timestamp() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let code = "
+    use.miden::kernels::tx::prologue
+    use.miden::tx
+
+    begin
+        exec.prologue::prepare_transaction
+        exec.tx::get_block_timestamp
+    end
+    ";
+
+    let transaction = prepare_transaction(tx_inputs.clone(), tx_args, code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    assert_eq!(process.stack.get(0), tx_inputs.block_timestamp().into());
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -0,0 +1,42 @@
+use core::fmt;
+
+// ASSERTION LOCATIONS
+// ================================================================================================
+
+// Table of all assertions with error codes in the MASM sources of this crate, sorted by error
+// code; generated by the build script.
+include!(concat!(env!("OUT_DIR"), "/assertion_locations.rs"));
+
+/// Location of an assertion with an error code in the MASM sources of this crate.
+///
+/// The assembled programs do not carry any debug info, and thus the locations are collected from
+/// the MASM sources when the crate is built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssertionLocation {
+    /// Error code of the assertion.
+    pub err_code: u32,
+    /// Name of the constant defining the error code (e.g., `ERR_NOTE_TOO_MANY_ASSETS`).
+    pub err_name: &'static str,
+    /// Path of the MASM file relative to the root of this crate (e.g., `asm/miden/note.masm`).
+    pub file: &'static str,
+    /// Line of the assertion, starting at 1.
+    pub line: u32,
+}
+
+impl fmt::Display for AssertionLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
+
+/// Returns the locations of all assertions with the specified error code.
+///
+/// An error code may be used by several assertions, in which case all of their locations are
+/// returned in the order of their files and lines. If the error code is not used by any assertion
+/// in the MASM sources of this crate, the iterator is empty.
+pub fn assertion_locations(err_code: u32) -> impl Iterator<Item = &'static AssertionLocation> {
+    let start = ASSERTION_LOCATIONS.partition_point(|location| location.err_code < err_code);
+    ASSERTION_LOCATIONS[start..]
+        .iter()
+        .take_while(move |location| location.err_code == err_code)
+}
@@ -2,20 +2,27 @@ use alloc::{string::String, vec::Vec};
 use core::fmt;
 
 use miden_objects::{
-    accounts::AccountStorage, notes::NoteMetadata, AccountError, AssetError, Digest, Felt,
-    NoteError,
+    accounts::AccountStorage,
+    notes::{NoteId, NoteMetadata},
+    AccountError, AssetError, Digest, Felt, NoteError,
 };
 
+use super::memory::MemoryAddress;
+
 // TRANSACTION KERNEL ERROR
 // ================================================================================================
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum TransactionKernelError {
+    /// The transaction created two identical notes, i.e., notes with the same recipient and
+    /// assets. The notes cannot be merged by the host, as the kernel commits to both of them.
+    DuplicateOutputNote(NoteId),
     FailedToAddAssetToNote(NoteError),
     InvalidStorageSlotIndex(u64),
     MalformedAccountId(AccountError),
     MalformedAsset(AssetError),
     MalformedAssetOnAccountVaultUpdate(AssetError),
+    MalformedMemoryValue(MemoryAddress, Felt),
     MalformedNoteInputs(NoteError),
     MalformedNoteMetadata(NoteError),
     MalformedNotePointer(String),
@@ -24,6 +31,7 @@ pub enum TransactionKernelError {
     MalformedRecipientData(Vec<Felt>),
     MalformedTag(Felt),
     MissingNoteDetails(NoteMetadata, Digest),
+    MissingStorageMapUpdate,
     MissingStorageSlotValue(u8, String),
     UnknownAccountProcedure(Digest),
     MissingNote(String),
@@ -32,6 +40,9 @@ pub enum TransactionKernelError {
 impl fmt::Display for TransactionKernelError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            TransactionKernelError::DuplicateOutputNote(note_id) => {
+                write!(f, "note {note_id} was created more than once by the transaction")
+            },
             TransactionKernelError::FailedToAddAssetToNote(err) => {
                 write!(f, "failed to add asset to note: {err}")
             },
@@ -48,6 +59,12 @@ impl fmt::Display for TransactionKernelError {
             TransactionKernelError::MalformedAssetOnAccountVaultUpdate(err) => {
                 write!(f, "malformed asset during account vault update: {err}")
             },
+            TransactionKernelError::MalformedMemoryValue(address, value) => {
+                write!(
+                    f,
+                    "value {value} stored at kernel memory address {address} is not well formed"
+                )
+            },
             TransactionKernelError::MalformedNoteInputs(err) => {
                 write!( f, "Note inputs data extracted from the advice map by the event handler is not well formed {err}")
             },
@@ -78,6 +95,9 @@ impl fmt::Display for TransactionKernelError {
             TransactionKernelError::MissingNoteDetails(metadata, recipient) => {
                 write!( f, "Public note missing the details in the advice provider. metadata: {metadata:?}, recipient: {recipient:?}")
             },
+            TransactionKernelError::MissingStorageMapUpdate => {
+                write!(f, "storage map update completed without a preceding storage map update")
+            },
             TransactionKernelError::MissingStorageSlotValue(index, err) => {
                 write!(f, "value for storage slot {index} could not be found: {err}")
             },
@@ -19,6 +19,7 @@ const ACCOUNT_PUSH_PROCEDURE_INDEX: u32 = 0x2_0004; // 131076
 const NOTE_CREATED: u32 = 0x2_0005; // 131077
 const ACCOUNT_STORAGE_SET_MAP_ITEM: u32 = 0x2_0006; // 131078
 const NOTE_ADD_ASSET: u32 = 0x2_0007; // 131079
+const ACCOUNT_STORAGE_AFTER_SET_MAP_ITEM: u32 = 0x2_0008; // 131080
 
 /// Events which may be emitted by a transaction kernel.
 ///
@@ -38,6 +39,7 @@ pub enum TransactionEvent {
     NoteCreated = NOTE_CREATED,
     AccountStorageSetMapItem = ACCOUNT_STORAGE_SET_MAP_ITEM,
     NoteAddAsset = NOTE_ADD_ASSET,
+    AccountStorageAfterSetMapItem = ACCOUNT_STORAGE_AFTER_SET_MAP_ITEM,
 }
 
 impl fmt::Display for TransactionEvent {
@@ -63,6 +65,9 @@ impl TryFrom<u32> for TransactionEvent {
             NOTE_CREATED => Ok(TransactionEvent::NoteCreated),
             ACCOUNT_STORAGE_SET_MAP_ITEM => Ok(TransactionEvent::AccountStorageSetMapItem),
             NOTE_ADD_ASSET => Ok(TransactionEvent::NoteAddAsset),
+            ACCOUNT_STORAGE_AFTER_SET_MAP_ITEM => {
+                Ok(TransactionEvent::AccountStorageAfterSetMapItem)
+            },
             _ => Err(TransactionEventParsingError::InvalidTransactionEvent(value)),
         }
     }
@@ -1,14 +1,18 @@
 use alloc::vec::Vec;
 
 use miden_objects::{
-    accounts::Account,
+    accounts::{hash_account, Account, NetworkParams},
+    assets::PartialVault,
+    crypto::merkle::InnerNodeInfo,
     transaction::{
-        ChainMmr, ExecutedTransaction, InputNotes, PreparedTransaction, TransactionArgs,
-        TransactionInputs, TransactionScript, TransactionWitness,
+        AdviceInputsBuilder, ChainMmr, ExecutedTransaction, InputNote, InputNotes,
+        PreparedTransaction, TransactionArgs, TransactionInputs, TransactionWitness,
     },
     vm::{AdviceInputs, StackInputs},
-    Felt, Word, ZERO,
+    AdviceInputsError, Digest, Felt, Word, ONE, ZERO,
 };
+#[cfg(feature = "concurrent")]
+use rayon::prelude::*;
 
 use super::TransactionKernel;
 
@@ -17,57 +21,102 @@ use super::TransactionKernel;
 
 /// Defines how inputs required to execute a transaction kernel can be extracted from self.
 pub trait ToTransactionKernelInputs {
-    /// Returns stack and advice inputs required to execute the transaction kernel.
-    fn get_kernel_inputs(&self) -> (StackInputs, AdviceInputs);
+    /// Returns stack and advice inputs required to execute the transaction kernel on a network
+    /// with [NetworkParams::MAINNET].
+    ///
+    /// # Panics
+    /// Panics if the advice map of the transaction arguments conflicts with the advice data
+    /// derived from the transaction inputs.
+    fn get_kernel_inputs(&self) -> (StackInputs, AdviceInputs) {
+        self.get_kernel_inputs_with_params(&NetworkParams::default())
+    }
+
+    /// Returns stack and advice inputs required to execute the transaction kernel on a network
+    /// with the provided parameters.
+    ///
+    /// # Panics
+    /// Panics if the advice map of the transaction arguments conflicts with the advice data
+    /// derived from the transaction inputs.
+    fn get_kernel_inputs_with_params(
+        &self,
+        network_params: &NetworkParams,
+    ) -> (StackInputs, AdviceInputs);
 }
 
 impl ToTransactionKernelInputs for PreparedTransaction {
-    fn get_kernel_inputs(&self) -> (StackInputs, AdviceInputs) {
+    fn get_kernel_inputs_with_params(
+        &self,
+        network_params: &NetworkParams,
+    ) -> (StackInputs, AdviceInputs) {
         let account = self.account();
         let stack_inputs = TransactionKernel::build_input_stack(
             account.id(),
-            account.init_hash(),
+            self.tx_inputs().account_init_commitment(self.tx_args().init_account_salt()),
             self.input_notes().commitment(),
             self.block_header().hash(),
+            network_params,
         );
 
-        let mut advice_inputs = AdviceInputs::default();
-        extend_advice_inputs(self.tx_inputs(), self.tx_args(), &mut advice_inputs);
+        let advice_inputs = build_advice_inputs(self.tx_inputs(), self.tx_args())
+            .expect("invalid transaction advice inputs")
+            .build();
 
         (stack_inputs, advice_inputs)
     }
 }
 
 impl ToTransactionKernelInputs for ExecutedTransaction {
-    fn get_kernel_inputs(&self) -> (StackInputs, AdviceInputs) {
+    fn get_kernel_inputs_with_params(
+        &self,
+        network_params: &NetworkParams,
+    ) -> (StackInputs, AdviceInputs) {
         let account = self.initial_account();
         let stack_inputs = TransactionKernel::build_input_stack(
             account.id(),
-            account.init_hash(),
+            self.tx_inputs().account_init_commitment(self.tx_args().init_account_salt()),
             self.input_notes().commitment(),
             self.block_header().hash(),
+            network_params,
         );
 
         let mut advice_inputs = self.advice_witness().clone();
-        extend_advice_inputs(self.tx_inputs(), self.tx_args(), &mut advice_inputs);
+        advice_inputs.extend(
+            build_advice_inputs(self.tx_inputs(), self.tx_args())
+                .expect("invalid transaction advice inputs")
+                .build(),
+        );
 
         (stack_inputs, advice_inputs)
     }
 }
 
 impl ToTransactionKernelInputs for TransactionWitness {
-    fn get_kernel_inputs(&self) -> (StackInputs, AdviceInputs) {
+    fn get_kernel_inputs_with_params(
+        &self,
+        network_params: &NetworkParams,
+    ) -> (StackInputs, AdviceInputs) {
         let account = self.account();
 
         let stack_inputs = TransactionKernel::build_input_stack(
             account.id(),
-            account.init_hash(),
+            self.tx_inputs().account_init_commitment(self.tx_args().init_account_salt()),
             self.input_notes().commitment(),
             self.block_header().hash(),
+            network_params,
         );
 
+        // the advice witness of a minimized witness already contains all advice data accessed
+        // during execution
         let mut advice_inputs = self.advice_witness().clone();
-        extend_advice_inputs(self.tx_inputs(), self.tx_args(), &mut advice_inputs);
+        if self.is_minimal() {
+            return (stack_inputs, advice_inputs);
+        }
+
+        advice_inputs.extend(
+            build_advice_inputs(self.tx_inputs(), self.tx_args())
+                .expect("invalid transaction advice inputs")
+                .build(),
+        );
 
         (stack_inputs, advice_inputs)
     }
@@ -76,31 +125,65 @@ impl ToTransactionKernelInputs for TransactionWitness {
 // ADVICE INPUTS
 // ================================================================================================
 
-/// Extends the provided advice inputs with the data required for executing a transaction with the
-/// specified inputs.
+/// Returns a builder of the advice inputs required for executing a transaction with the specified
+/// inputs.
 ///
 /// This includes the initial account, an optional account seed (required for new accounts), and
 /// the input note data, including core note data + authentication paths all the way to the root
-/// of one of chain MMR peaks.
-fn extend_advice_inputs(
+/// of one of chain MMR peaks. The advice map of the transaction arguments is merged into the
+/// resulting advice map.
+///
+/// # Errors
+/// Returns an error if an entry of the advice map of the transaction arguments conflicts with the
+/// advice data derived from the transaction inputs.
+pub(super) fn build_advice_inputs(
     tx_inputs: &TransactionInputs,
     tx_args: &TransactionArgs,
-    advice_inputs: &mut AdviceInputs,
-) {
+) -> Result<AdviceInputsBuilder, AdviceInputsError> {
+    let mut advice_inputs = AdviceInputsBuilder::new();
+
     // build the advice stack
-    build_advice_stack(tx_inputs, tx_args.tx_script(), advice_inputs);
+    build_advice_stack(tx_inputs, tx_args, &mut advice_inputs);
 
     // build the advice map and Merkle store for relevant components
-    add_chain_mmr_to_advice_inputs(tx_inputs.block_chain(), advice_inputs);
-    add_account_to_advice_inputs(tx_inputs.account(), tx_inputs.account_seed(), advice_inputs);
-    add_input_notes_to_advice_inputs(tx_inputs.input_notes(), tx_args, advice_inputs);
-    advice_inputs.extend_map(tx_args.advice_map().clone());
+    add_chain_mmr_to_advice_inputs(tx_inputs.block_chain(), &mut advice_inputs)?;
+    add_account_to_advice_inputs(
+        tx_inputs.account(),
+        tx_inputs.partial_vault(),
+        tx_inputs.account_seed(),
+        &mut advice_inputs,
+    )?;
+    add_input_notes_to_advice_inputs(tx_inputs.input_notes(), tx_args, &mut advice_inputs)?;
+    advice_inputs.extend_map(tx_args.advice_map().clone())?;
+
+    Ok(advice_inputs)
+}
+
+/// Returns the advice inputs required to execute a read-only procedure of the specified account.
+///
+/// The advice stack contains the core account data in the same layout as the account section of
+/// the transaction advice stack, while the advice map and Merkle store contain the full account
+/// storage, vault, and code data.
+pub(super) fn build_account_view_advice_inputs(account: &Account) -> AdviceInputs {
+    let mut inputs = AdviceInputsBuilder::new();
+
+    inputs.extend_stack([account.id().into(), ZERO, ZERO, account.nonce()]);
+    inputs.extend_stack(account.vault().commitment());
+    inputs.extend_stack(account.storage().root());
+    inputs.extend_stack(account.code().root());
+
+    // the advice map entries of an account are keyed by the hashes of their values, and thus
+    // cannot conflict
+    add_account_to_advice_inputs(account, None, None, &mut inputs)
+        .expect("conflicting account advice data");
+
+    inputs.build()
 }
 
 // ADVICE STACK BUILDER
 // ------------------------------------------------------------------------------------------------
 
-/// Builds the advice stack for the provided transaction inputs.
+/// Builds the advice stack for the provided transaction inputs and arguments.
 ///
 /// The advice stack is arranged as follows:
 ///  elements[0..3]    = hash of previous block
@@ -112,16 +195,18 @@ fn extend_advice_inputs(
 ///  elements[24..27]  = [block_num, version, timestamp, ZERO]
 ///  elements[28..31]  = [ZERO; 4]
 ///  elements[32..35]  = notes root
-///  elements[36..39]  = [account ID, ZERO, ZERO, account nonce]
-///  elements[40..43]  = account vault root
-///  elements[44..47]  = account storage root
-///  elements[48..51]  = account code root
-///  elements[52]      = number of input notes
-///  elements[53..57]  = account seed, if one was provided; otherwise [ZERO; 4]
+///  elements[36..39]  = initial account salt, if one was provided; otherwise [ZERO; 4]
+///  elements[40..43]  = final account salt, if one was provided; otherwise [ZERO; 4]
+///  elements[44..47]  = [account ID, ZERO, ZERO, account nonce]
+///  elements[48..51]  = account vault root
+///  elements[52..55]  = account storage root
+///  elements[56..59]  = account code root
+///  elements[60]      = number of input notes
+///  elements[61..64]  = transaction script root, if one was provided; otherwise [ZERO; 4]
 fn build_advice_stack(
     tx_inputs: &TransactionInputs,
-    tx_script: Option<&TransactionScript>,
-    inputs: &mut AdviceInputs,
+    tx_args: &TransactionArgs,
+    inputs: &mut AdviceInputsBuilder,
 ) {
     // push block header info into the stack
     let header = tx_inputs.block_header();
@@ -140,10 +225,14 @@ fn build_advice_stack(
     inputs.extend_stack([ZERO; 4]);
     inputs.extend_stack(header.note_root());
 
+    // push the salts of the account state commitments onto the stack
+    inputs.extend_stack(tx_args.init_account_salt().unwrap_or_default());
+    inputs.extend_stack(tx_args.final_account_salt().unwrap_or_default());
+
     // push core account items onto the stack
     let account = tx_inputs.account();
     inputs.extend_stack([account.id().into(), ZERO, ZERO, account.nonce()]);
-    inputs.extend_stack(account.vault().commitment());
+    inputs.extend_stack(tx_inputs.account_vault_root());
     inputs.extend_stack(account.storage().root());
     inputs.extend_stack(account.code().root());
 
@@ -151,7 +240,7 @@ fn build_advice_stack(
     inputs.extend_stack([Felt::from(tx_inputs.input_notes().num_notes() as u32)]);
 
     // push tx_script root onto the stack
-    if let Some(tx_script) = tx_script {
+    if let Some(tx_script) = tx_args.tx_script() {
         // insert the transaction script hash into the advice stack
         inputs.extend_stack(*tx_script.hash());
     } else {
@@ -176,7 +265,10 @@ fn build_advice_stack(
 ///  elements[0]       = number of leaves in the MMR
 ///  elements[1..4]    = padding ([Felt::ZERO; 3])
 ///  elements[4..]     = MMR peak roots
-fn add_chain_mmr_to_advice_inputs(mmr: &ChainMmr, inputs: &mut AdviceInputs) {
+fn add_chain_mmr_to_advice_inputs(
+    mmr: &ChainMmr,
+    inputs: &mut AdviceInputsBuilder,
+) -> Result<(), AdviceInputsError> {
     // add authentication paths from the MMR to the Merkle store
     inputs.extend_merkle_store(mmr.inner_nodes());
 
@@ -184,7 +276,9 @@ fn add_chain_mmr_to_advice_inputs(mmr: &ChainMmr, inputs: &mut AdviceInputs) {
     let peaks = mmr.peaks();
     let mut elements = vec![Felt::new(peaks.num_leaves() as u64), ZERO, ZERO, ZERO];
     elements.extend(peaks.flatten_and_pad_peaks());
-    inputs.extend_map([(peaks.hash_peaks(), elements)]);
+    inputs.insert_map_entry(peaks.hash_peaks(), elements)?;
+
+    Ok(())
 }
 
 // ACCOUNT DATA INJECTOR
@@ -194,21 +288,43 @@ fn add_chain_mmr_to_advice_inputs(mmr: &ChainMmr, inputs: &mut AdviceInputs) {
 ///
 /// Inserts the following items into the Merkle store:
 /// - The Merkle nodes associated with the storage slots tree.
-/// - The Merkle nodes associated with the account vault tree.
+/// - The Merkle nodes associated with the account vault tree. If the vault was provided as a
+///   partial vault, only the nodes of the authentication paths of the tracked leaves are inserted.
 /// - The Merkle nodes associated with the account code procedures tree.
 /// - If present, the Merkle nodes associated with the account storage maps.
 ///
 /// Inserts the following entries into the advice map:
+/// - The account hash |-> [account_id, 0, 0, nonce, VAULT_ROOT, STORAGE_ROOT, CODE_ROOT].
 /// - The storage types commitment |-> storage slot types vector.
 /// - The account procedure root |-> procedure index, for each account procedure.
-/// - The node |-> (key, value), for all leaf nodes of the asset vault SMT.
+/// - The node |-> (key, value), for all (tracked) leaf nodes of the asset vault SMT.
 /// - [account_id, 0, 0, 0] |-> account_seed, when account seed is provided.
 /// - If present, the Merkle leaves associated with the account storage maps.
 fn add_account_to_advice_inputs(
     account: &Account,
+    partial_vault: Option<&PartialVault>,
     account_seed: Option<Word>,
-    inputs: &mut AdviceInputs,
-) {
+    inputs: &mut AdviceInputsBuilder,
+) -> Result<(), AdviceInputsError> {
+    // --- account data -------------------------------------------------------
+    let vault_root = match partial_vault {
+        Some(vault) => vault.commitment(),
+        None => account.vault().commitment(),
+    };
+    let storage_root = account.storage().root();
+    let code_root = account.code().root();
+
+    // extend advice map with account hash |-> account data, so that account procedures can
+    // inspect the initial state of the account
+    let mut account_data = vec![account.id().into(), ZERO, ZERO, account.nonce()];
+    account_data.extend_from_slice(vault_root.as_elements());
+    account_data.extend_from_slice(storage_root.as_elements());
+    account_data.extend_from_slice(code_root.as_elements());
+    inputs.insert_map_entry(
+        hash_account(account.id(), account.nonce(), vault_root, storage_root, code_root),
+        account_data,
+    )?;
+
     // --- account storage ----------------------------------------------------
     let storage = account.storage();
 
@@ -216,10 +332,10 @@ fn add_account_to_advice_inputs(
     inputs.extend_merkle_store(account.storage().slots().inner_nodes());
 
     // extend advice map with storage types commitment |-> storage types
-    inputs.extend_map([(
+    inputs.insert_map_entry(
         storage.layout_commitment(),
         storage.layout().iter().map(Felt::from).collect(),
-    )]);
+    )?;
 
     // If there are storage maps, we populate the merkle store and advice map
     if !account.storage().maps().is_empty() {
@@ -228,19 +344,36 @@ fn add_account_to_advice_inputs(
             inputs.extend_merkle_store(map.inner_nodes());
 
             // populate advice map with Sparse Merkle Tree leaf nodes
-            inputs.extend_map(map.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+            inputs.extend_map(map.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())))?;
         }
     }
 
     // --- account vault ------------------------------------------------------
-    let vault = account.vault();
-
-    // extend the merkle store with account vault data
-    inputs.extend_merkle_store(vault.asset_tree().inner_nodes());
+    match partial_vault {
+        Some(vault) => {
+            // extend the merkle store with the authentication paths of the tracked vault leaves
+            inputs.extend_merkle_store(vault.inner_nodes());
+
+            // populate advice map with the tracked non-empty Sparse Merkle Tree leaf nodes
+            inputs.extend_map(
+                vault
+                    .leaves()
+                    .filter(|leaf| !leaf.is_empty())
+                    .map(|leaf| (leaf.hash(), leaf.to_elements())),
+            )?;
+        },
+        None => {
+            let vault = account.vault();
+
+            // extend the merkle store with account vault data
+            inputs.extend_merkle_store(vault.asset_tree().inner_nodes());
 
-    // populate advice map with Sparse Merkle Tree leaf nodes
-    inputs
-        .extend_map(vault.asset_tree().leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+            // populate advice map with Sparse Merkle Tree leaf nodes
+            inputs.extend_map(
+                vault.asset_tree().leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())),
+            )?;
+        },
+    }
 
     // --- account code -------------------------------------------------------
     let code = account.code();
@@ -248,13 +381,28 @@ fn add_account_to_advice_inputs(
     // extend the merkle store with account code tree
     inputs.extend_merkle_store(code.procedure_tree().inner_nodes());
 
+    // populate advice map with the procedures requiring authentication, which are needed to
+    // interpret the leaves of the account code tree
+    if !code.auth_procedures().is_empty() {
+        inputs.insert_map_entry(
+            code.root(),
+            code.auth_procedures()
+                .iter()
+                .flat_map(|root| root.as_elements())
+                .copied()
+                .collect(),
+        )?;
+    }
+
     // --- account seed -------------------------------------------------------
     if let Some(account_seed) = account_seed {
-        inputs.extend_map(vec![(
+        inputs.insert_map_entry(
             [account.id().into(), ZERO, ZERO, ZERO].into(),
             account_seed.to_vec(),
-        )]);
+        )?;
     }
+
+    Ok(())
 }
 
 // INPUT NOTE INJECTOR
@@ -281,50 +429,95 @@ fn add_account_to_advice_inputs(
 ///   ...
 ///   out[34 + num_assets * 4..] = Word::default() (this is conditional padding only applied
 ///                                                 if the number of assets is odd)
+///   out[-11]      = is_authenticated
 ///   out[-10]      = origin.block_number
 ///   out[-9..-5]   = origin.SUB_HASH
 ///   out[-5..-1]   = origin.NOTE_ROOT
 ///   out[-1]       = origin.node_index
 ///
+/// The origin data is present only for authenticated notes; for unauthenticated notes, the
+/// `is_authenticated` flag is the last item of the note data.
+///
 /// Inserts the following items into the Merkle store:
-/// - The Merkle nodes associated with the note's authentication path.
+/// - The Merkle nodes associated with the note's authentication path (authenticated notes only).
 ///
 /// Inserts the following entries into the advice map:
 /// - inputs_hash |-> inputs
 /// - asset_hash |-> assets
 /// - notes_hash |-> combined note data
+///
+/// The advice data of each note is added to the advice inputs as soon as it has been derived, and
+/// so the advice data of all notes is never held in memory alongside the advice inputs. When the
+/// `concurrent` feature is enabled, the advice data is derived in parallel for chunks of
+/// [NOTE_ADVICE_CHUNK_SIZE] notes at a time. In both cases, the data is added to the advice inputs
+/// in the order of the notes.
 fn add_input_notes_to_advice_inputs(
     notes: &InputNotes,
     tx_args: &TransactionArgs,
-    inputs: &mut AdviceInputs,
-) {
+    inputs: &mut AdviceInputsBuilder,
+) -> Result<(), AdviceInputsError> {
     // if there are no input notes, nothing is added to the advice inputs
     if notes.is_empty() {
-        return;
+        return Ok(());
     }
 
     let mut note_data = Vec::new();
+
+    #[cfg(feature = "concurrent")]
+    {
+        let input_notes = notes.iter().collect::<Vec<_>>();
+        for chunk in input_notes.chunks(NOTE_ADVICE_CHUNK_SIZE) {
+            let chunk_advice_data = chunk
+                .par_iter()
+                .map(|input_note| InputNoteAdviceData::new(input_note, tx_args))
+                .collect::<Vec<_>>();
+            for advice_data in chunk_advice_data {
+                advice_data.add_to_advice_inputs(inputs, &mut note_data)?;
+            }
+        }
+    }
+
+    #[cfg(not(feature = "concurrent"))]
     for input_note in notes.iter() {
+        InputNoteAdviceData::new(input_note, tx_args)
+            .add_to_advice_inputs(inputs, &mut note_data)?;
+    }
+
+    // insert the combined note data into the advice map
+    inputs.insert_map_entry(notes.commitment(), note_data)?;
+
+    Ok(())
+}
+
+/// The number of notes for which advice data is derived in parallel before it is added to the
+/// advice inputs.
+#[cfg(feature = "concurrent")]
+const NOTE_ADVICE_CHUNK_SIZE: usize = 64;
+
+/// Advice data derived from a single input note, as described in
+/// [add_input_notes_to_advice_inputs()].
+struct InputNoteAdviceData {
+    /// Note inputs and assets keyed by their commitments.
+    map_entries: [(Digest, Vec<Felt>); 2],
+    /// Merkle nodes of the note's authentication path.
+    merkle_nodes: Vec<InnerNodeInfo>,
+    /// Note data to be added to the combined note data vector.
+    note_data: Vec<Felt>,
+}
+
+impl InputNoteAdviceData {
+    fn new(input_note: &InputNote, tx_args: &TransactionArgs) -> Self {
         let note = input_note.note();
         let assets = note.assets();
-        let proof = input_note.proof();
         let recipient = note.recipient();
         let note_arg = tx_args.get_note_args(note.id()).unwrap_or(&[ZERO; 4]);
 
-        // insert note inputs and assets into the advice map
-        inputs
-            .extend_map([(recipient.inputs().commitment(), recipient.inputs().to_padded_values())]);
-        inputs.extend_map([(assets.commitment(), assets.to_padded_assets())]);
-
-        // insert note authentication path nodes into the Merkle store
-        inputs.extend_merkle_store(
-            proof
-                .note_path()
-                .inner_nodes(proof.origin().node_index.value(), note.authentication_hash())
-                .unwrap(),
-        );
+        let map_entries = [
+            (recipient.inputs().commitment(), recipient.inputs().to_padded_values()),
+            (assets.commitment(), assets.to_padded_assets()),
+        ];
 
-        // add the note elements to the combined vector of note data
+        let mut note_data = Vec::new();
         note_data.extend(recipient.serial_num());
         note_data.extend(*recipient.script().hash());
         note_data.extend(*recipient.inputs().commitment());
@@ -338,19 +531,49 @@ fn add_input_notes_to_advice_inputs(
         note_data.push((assets.num_assets() as u32).into());
         note_data.extend(assets.to_padded_assets());
 
-        note_data.push(proof.origin().block_num.into());
-        note_data.extend(*proof.sub_hash());
-        note_data.extend(*proof.note_root());
-        note_data.push(
-            proof
-                .origin()
-                .node_index
-                .value()
-                .try_into()
-                .expect("value is greater than or equal to the field modulus"),
-        );
+        let merkle_nodes = match input_note {
+            InputNote::Authenticated { proof, .. } => {
+                note_data.push(ONE);
+                note_data.push(proof.origin().block_num.into());
+                note_data.extend(*proof.sub_hash());
+                note_data.extend(*proof.note_root());
+                note_data.push(
+                    proof
+                        .origin()
+                        .node_index
+                        .value()
+                        .try_into()
+                        .expect("value is greater than or equal to the field modulus"),
+                );
+
+                proof
+                    .note_path()
+                    .inner_nodes(proof.origin().node_index.value(), note.authentication_hash())
+                    .unwrap()
+                    .collect()
+            },
+            InputNote::Unauthenticated { .. } => {
+                note_data.push(ZERO);
+                Vec::new()
+            },
+        };
+
+        Self { map_entries, merkle_nodes, note_data }
     }
 
-    // insert the combined note data into the advice map
-    inputs.extend_map([(notes.commitment(), note_data)]);
+    /// Adds the note inputs, assets, and authentication path of the note to the advice inputs,
+    /// and appends the core note data to the combined note data vector.
+    fn add_to_advice_inputs(
+        self,
+        inputs: &mut AdviceInputsBuilder,
+        note_data: &mut Vec<Felt>,
+    ) -> Result<(), AdviceInputsError> {
+        for (key, values) in self.map_entries {
+            inputs.insert_map_entry(key, values)?;
+        }
+        inputs.extend_merkle_store(self.merkle_nodes);
+        note_data.extend(self.note_data);
+
+        Ok(())
+    }
 }
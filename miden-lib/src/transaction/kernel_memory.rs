@@ -0,0 +1,337 @@
+use miden_objects::{
+    accounts::{AccountId, AccountStorage},
+    notes::NoteId,
+    vm::{ContextId, ProcessState},
+    Digest, Felt, Word, MAX_INPUT_NOTES_PER_TX, MAX_OUTPUT_NOTES_PER_TX,
+};
+
+use super::{
+    memory::{
+        MemoryAddress, ACCT_CODE_ROOT_PTR, ACCT_DATA_SECTION_OFFSET, ACCT_ID_AND_NONCE_PTR,
+        ACCT_ID_IDX, ACCT_ID_PTR, ACCT_NEW_CODE_ROOT_PTR, ACCT_NONCE_IDX, ACCT_STORAGE_ROOT_PTR,
+        ACCT_STORAGE_SLOT_TYPE_DATA_OFFSET, ACCT_VAULT_ROOT_PTR, BLK_HASH_PTR,
+        BLOCK_DATA_SECTION_OFFSET, BLOCK_METADATA_PTR, BLOCK_NUMBER_IDX, CHAIN_MMR_PTR,
+        CONSUMED_NOTE_DATA_SECTION_OFFSET, CONSUMED_NOTE_ID_OFFSET, CONSUMED_NOTE_NUM_PTR,
+        CONSUMED_NOTE_SECTION_OFFSET, CREATED_NOTE_ID_OFFSET, CREATED_NOTE_SECTION_OFFSET,
+        CURRENT_CONSUMED_NOTE_PTR, GLOBAL_INPUTS_SECTION_OFFSET, INIT_ACCT_HASH_PTR,
+        INIT_NONCE_PTR, NOTE_MEM_SIZE, NUM_CREATED_NOTES_PTR, TX_SCRIPT_ROOT_PTR,
+        TX_VAULT_ROOT_PTR,
+    },
+    TransactionKernelError,
+};
+
+// MEMORY SECTIONS
+// ================================================================================================
+
+/// A contiguous range of word addresses in the root context memory which the transaction kernel
+/// reserves for a specific purpose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemorySection {
+    /// The name of the section.
+    pub name: &'static str,
+    /// The address of the first word of the section.
+    pub start: MemoryAddress,
+    /// The address following the last word of the section.
+    pub end: MemoryAddress,
+}
+
+impl MemorySection {
+    /// Returns a new [MemorySection] spanning addresses from `start` (inclusive) to `end`
+    /// (exclusive).
+    pub const fn new(name: &'static str, start: MemoryAddress, end: MemoryAddress) -> Self {
+        Self { name, start, end }
+    }
+
+    /// Returns the number of words in this section.
+    pub const fn len(&self) -> u32 {
+        self.end - self.start
+    }
+
+    /// Returns true if this section contains no words.
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns true if the specified address lies within this section.
+    pub const fn contains(&self, address: MemoryAddress) -> bool {
+        self.start <= address && address < self.end
+    }
+}
+
+/// The section holding the bookkeeping data, such as the pointer to the consumed note being
+/// executed and the number of created notes.
+pub const BOOKKEEPING_SECTION: MemorySection =
+    MemorySection::new("bookkeeping", 0, GLOBAL_INPUTS_SECTION_OFFSET);
+
+/// The section holding the global inputs of the transaction.
+pub const GLOBAL_INPUTS_SECTION: MemorySection =
+    MemorySection::new("global_inputs", GLOBAL_INPUTS_SECTION_OFFSET, BLOCK_DATA_SECTION_OFFSET);
+
+/// The section holding the data of the reference block.
+pub const BLOCK_DATA_SECTION: MemorySection =
+    MemorySection::new("block_data", BLOCK_DATA_SECTION_OFFSET, CHAIN_MMR_PTR);
+
+/// The section holding the chain MMR.
+pub const CHAIN_MMR_SECTION: MemorySection =
+    MemorySection::new("chain_mmr", CHAIN_MMR_PTR, ACCT_DATA_SECTION_OFFSET);
+
+/// The section holding the data of the account against which the transaction is executed,
+/// including the types of its storage slots (four slot types per word).
+pub const ACCOUNT_DATA_SECTION: MemorySection = MemorySection::new(
+    "account_data",
+    ACCT_DATA_SECTION_OFFSET,
+    ACCT_STORAGE_SLOT_TYPE_DATA_OFFSET + (AccountStorage::NUM_STORAGE_SLOTS / 4) as u32,
+);
+
+/// The section holding the number of consumed notes, their nullifiers, and their data.
+pub const CONSUMED_NOTES_SECTION: MemorySection = MemorySection::new(
+    "consumed_notes",
+    CONSUMED_NOTE_SECTION_OFFSET,
+    CONSUMED_NOTE_DATA_SECTION_OFFSET + MAX_INPUT_NOTES_PER_TX as u32 * NOTE_MEM_SIZE,
+);
+
+/// The section holding the data of the created notes.
+pub const CREATED_NOTES_SECTION: MemorySection = MemorySection::new(
+    "created_notes",
+    CREATED_NOTE_SECTION_OFFSET,
+    CREATED_NOTE_SECTION_OFFSET + MAX_OUTPUT_NOTES_PER_TX as u32 * NOTE_MEM_SIZE,
+);
+
+/// All sections of the transaction kernel memory, ordered by their start addresses.
+pub const KERNEL_MEMORY_SECTIONS: [MemorySection; 7] = [
+    BOOKKEEPING_SECTION,
+    GLOBAL_INPUTS_SECTION,
+    BLOCK_DATA_SECTION,
+    CHAIN_MMR_SECTION,
+    ACCOUNT_DATA_SECTION,
+    CONSUMED_NOTES_SECTION,
+    CREATED_NOTES_SECTION,
+];
+
+/// Returns the section of the transaction kernel memory which contains the specified address, or
+/// `None` if the address does not belong to any section.
+pub fn memory_section(address: MemoryAddress) -> Option<&'static MemorySection> {
+    KERNEL_MEMORY_SECTIONS.iter().find(|section| section.contains(address))
+}
+
+// NOTE POINTERS
+// ================================================================================================
+
+/// Returns the memory address at which the nullifier of the consumed note with the specified index
+/// is stored.
+pub const fn consumed_note_nullifier_ptr(note_idx: u32) -> MemoryAddress {
+    CONSUMED_NOTE_NUM_PTR + 1 + note_idx
+}
+
+/// Returns the memory address at which the data segment of the consumed note with the specified
+/// index begins.
+pub const fn consumed_note_data_ptr(note_idx: u32) -> MemoryAddress {
+    CONSUMED_NOTE_DATA_SECTION_OFFSET + note_idx * NOTE_MEM_SIZE
+}
+
+/// Returns the memory address at which the data segment of the created note with the specified
+/// index begins.
+pub const fn created_note_data_ptr(note_idx: u32) -> MemoryAddress {
+    CREATED_NOTE_SECTION_OFFSET + note_idx * NOTE_MEM_SIZE
+}
+
+// KERNEL MEMORY READER
+// ================================================================================================
+
+/// Reads the data stored by the transaction kernel from the memory of a [ProcessState].
+///
+/// All reads are performed in the root context. Each accessor returns `None` if the corresponding
+/// memory has not been initialized yet, e.g., because the prologue has not been executed.
+pub struct KernelMemoryReader<'a, S: ProcessState> {
+    process: &'a S,
+}
+
+impl<'a, S: ProcessState> KernelMemoryReader<'a, S> {
+    /// Returns a new [KernelMemoryReader] reading from the provided process state.
+    pub fn new(process: &'a S) -> Self {
+        Self { process }
+    }
+
+    // BOOKKEEPING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the transaction vault.
+    pub fn tx_vault_root(&self) -> Option<Digest> {
+        self.read_word(TX_VAULT_ROOT_PTR).map(Digest::from)
+    }
+
+    /// Returns the pointer to the data segment of the consumed note being executed, or `None` if
+    /// the pointer has not been set by the prologue yet or all notes have already been processed.
+    ///
+    /// # Errors
+    /// Returns an error if the stored pointer is not a valid memory address.
+    pub fn current_consumed_note_ptr(
+        &self,
+    ) -> Result<Option<MemoryAddress>, TransactionKernelError> {
+        // the pointer is set to zero once the execution of the notes has ended
+        match self.read_u32(CURRENT_CONSUMED_NOTE_PTR)? {
+            Some(0) | None => Ok(None),
+            Some(note_ptr) => Ok(Some(note_ptr)),
+        }
+    }
+
+    /// Returns the ID of the consumed note being executed, or `None` if the pointer to it has not
+    /// been set by the prologue yet or all notes have already been processed.
+    ///
+    /// # Errors
+    /// Returns an error if the stored pointer is not a valid memory address.
+    pub fn current_consumed_note_id(&self) -> Result<Option<NoteId>, TransactionKernelError> {
+        Ok(self
+            .current_consumed_note_ptr()?
+            .and_then(|note_ptr| self.read_word(note_ptr + CONSUMED_NOTE_ID_OFFSET))
+            .map(NoteId::from))
+    }
+
+    /// Returns the number of notes created so far.
+    ///
+    /// # Errors
+    /// Returns an error if the stored number is not a valid `u32` value.
+    pub fn num_created_notes(&self) -> Result<Option<u32>, TransactionKernelError> {
+        self.read_u32(NUM_CREATED_NOTES_PTR)
+    }
+
+    // GLOBAL INPUTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the hash of the reference block.
+    pub fn block_hash(&self) -> Option<Digest> {
+        self.read_word(BLK_HASH_PTR).map(Digest::from)
+    }
+
+    /// Returns the ID of the account against which the transaction is executed.
+    ///
+    /// # Errors
+    /// Returns an error if the stored value is not a valid account ID.
+    pub fn account_id(&self) -> Result<Option<AccountId>, TransactionKernelError> {
+        self.read_word(ACCT_ID_PTR)
+            .map(|word| AccountId::try_from(word[0]))
+            .transpose()
+            .map_err(TransactionKernelError::MalformedAccountId)
+    }
+
+    /// Returns the hash of the account at the beginning of the transaction.
+    pub fn init_account_hash(&self) -> Option<Digest> {
+        self.read_word(INIT_ACCT_HASH_PTR).map(Digest::from)
+    }
+
+    /// Returns the nonce of the account at the beginning of the transaction.
+    pub fn init_nonce(&self) -> Option<Felt> {
+        self.read_word(INIT_NONCE_PTR).map(|word| word[0])
+    }
+
+    /// Returns the root of the transaction script, which is [ZERO; 4] if the transaction has no
+    /// script.
+    pub fn tx_script_root(&self) -> Option<Digest> {
+        self.read_word(TX_SCRIPT_ROOT_PTR).map(Digest::from)
+    }
+
+    // BLOCK DATA
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of the reference block.
+    ///
+    /// # Errors
+    /// Returns an error if the stored number is not a valid `u32` value.
+    pub fn block_num(&self) -> Result<Option<u32>, TransactionKernelError> {
+        self.read_word(BLOCK_METADATA_PTR)
+            .map(|word| felt_to_u32(BLOCK_METADATA_PTR, word[BLOCK_NUMBER_IDX]))
+            .transpose()
+    }
+
+    // ACCOUNT DATA
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the current nonce of the account.
+    pub fn account_nonce(&self) -> Option<Felt> {
+        self.read_word(ACCT_ID_AND_NONCE_PTR).map(|word| word[ACCT_NONCE_IDX])
+    }
+
+    /// Returns the ID of the account as stored in the account data section.
+    ///
+    /// # Errors
+    /// Returns an error if the stored value is not a valid account ID.
+    pub fn account_data_id(&self) -> Result<Option<AccountId>, TransactionKernelError> {
+        self.read_word(ACCT_ID_AND_NONCE_PTR)
+            .map(|word| AccountId::try_from(word[ACCT_ID_IDX]))
+            .transpose()
+            .map_err(TransactionKernelError::MalformedAccountId)
+    }
+
+    /// Returns the current root of the account vault.
+    pub fn account_vault_root(&self) -> Option<Digest> {
+        self.read_word(ACCT_VAULT_ROOT_PTR).map(Digest::from)
+    }
+
+    /// Returns the current root of the account storage.
+    pub fn account_storage_root(&self) -> Option<Digest> {
+        self.read_word(ACCT_STORAGE_ROOT_PTR).map(Digest::from)
+    }
+
+    /// Returns the root of the account code.
+    pub fn account_code_root(&self) -> Option<Digest> {
+        self.read_word(ACCT_CODE_ROOT_PTR).map(Digest::from)
+    }
+
+    /// Returns the root of the new account code, which is set if the account code was updated
+    /// during the transaction.
+    pub fn account_new_code_root(&self) -> Option<Digest> {
+        self.read_word(ACCT_NEW_CODE_ROOT_PTR).map(Digest::from)
+    }
+
+    // CONSUMED NOTES
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of notes consumed by the transaction.
+    ///
+    /// # Errors
+    /// Returns an error if the stored number is not a valid `u32` value.
+    pub fn num_consumed_notes(&self) -> Result<Option<u32>, TransactionKernelError> {
+        self.read_u32(CONSUMED_NOTE_NUM_PTR)
+    }
+
+    /// Returns the nullifier of the consumed note with the specified index.
+    pub fn consumed_note_nullifier(&self, note_idx: u32) -> Option<Digest> {
+        self.read_word(consumed_note_nullifier_ptr(note_idx)).map(Digest::from)
+    }
+
+    /// Returns the ID of the consumed note with the specified index.
+    pub fn consumed_note_id(&self, note_idx: u32) -> Option<NoteId> {
+        self.read_word(consumed_note_data_ptr(note_idx) + CONSUMED_NOTE_ID_OFFSET)
+            .map(NoteId::from)
+    }
+
+    // CREATED NOTES
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the created note with the specified index.
+    pub fn created_note_id(&self, note_idx: u32) -> Option<NoteId> {
+        self.read_word(created_note_data_ptr(note_idx) + CREATED_NOTE_ID_OFFSET)
+            .map(NoteId::from)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the word stored at the specified address of the root context memory.
+    pub fn read_word(&self, address: MemoryAddress) -> Option<Word> {
+        self.process.get_mem_value(ContextId::root(), address)
+    }
+
+    /// Returns the first element of the word stored at the specified address as a `u32` value.
+    fn read_u32(&self, address: MemoryAddress) -> Result<Option<u32>, TransactionKernelError> {
+        self.read_word(address).map(|word| felt_to_u32(address, word[0])).transpose()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn felt_to_u32(address: MemoryAddress, value: Felt) -> Result<u32, TransactionKernelError> {
+    u32::try_from(value.as_int())
+        .map_err(|_| TransactionKernelError::MalformedMemoryValue(address, value))
+}
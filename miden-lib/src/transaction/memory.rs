@@ -39,6 +39,13 @@ pub const INPUT_VAULT_ROOT_PTR: MemoryAddress = 3;
 /// The memory address at which the output vault root is stored
 pub const OUTPUT_VAULT_ROOT_PTR: MemoryAddress = 4;
 
+/// The memory address at which the commitment to the changes made to the account vault is stored.
+pub const ACCT_VAULT_DELTA_COMMITMENT_PTR: MemoryAddress = 5;
+
+/// The memory address at which the commitment to the changes made to the account storage is
+/// stored.
+pub const ACCT_STORAGE_DELTA_COMMITMENT_PTR: MemoryAddress = 6;
+
 // GLOBAL INPUTS
 // ------------------------------------------------------------------------------------------------
 
@@ -63,6 +70,20 @@ pub const INIT_NONCE_PTR: MemoryAddress = 104;
 /// The memory address at which the transaction script mast root is store
 pub const TX_SCRIPT_ROOT_PTR: MemoryAddress = 105;
 
+/// The memory address at which the salt of the initial account state commitment is stored.
+pub const INIT_ACCT_SALT_PTR: MemoryAddress = 106;
+
+/// The memory address at which the salt of the final account state commitment is stored.
+pub const FINAL_ACCT_SALT_PTR: MemoryAddress = 107;
+
+/// The memory address at which the modulus used to check the seed digest of a new regular account
+/// is stored.
+pub const REGULAR_ACCT_SEED_DIGEST_MODULUS_PTR: MemoryAddress = 108;
+
+/// The memory address at which the modulus used to check the seed digest of a new faucet is
+/// stored.
+pub const FAUCET_ACCT_SEED_DIGEST_MODULUS_PTR: MemoryAddress = 109;
+
 // BLOCK DATA
 // ------------------------------------------------------------------------------------------------
 
@@ -1,19 +1,26 @@
 use alloc::{string::ToString, vec::Vec};
 
 use miden_objects::{
-    accounts::AccountId,
+    accounts::{salt_account_hash, Account, AccountId, NetworkParams},
     assembly::{Assembler, AssemblyContext, ProgramAst},
-    transaction::{OutputNote, OutputNotes, TransactionOutputs},
+    transaction::{
+        AdviceInputsBuilder, KernelVersion, OutputNote, OutputNotes, TransactionArgs,
+        TransactionInputs, TransactionOutputs,
+    },
     utils::{group_slice_elements, serde::DeserializationError},
-    vm::{AdviceMap, ProgramInfo, StackInputs, StackOutputs},
-    Digest, Felt, TransactionOutputError, Word,
+    vm::{AdviceInputs, AdviceMap, ProgramInfo, StackInputs, StackOutputs},
+    AdviceInputsError, Digest, Felt, TransactionOutputError, Word, EMPTY_WORD, WORD_SIZE,
 };
 use miden_stdlib::StdLibrary;
 
 use super::MidenLib;
 
+pub mod kernel_memory;
 pub mod memory;
 
+mod assertions;
+pub use assertions::{assertion_locations, AssertionLocation};
+
 mod events;
 pub use events::{TransactionEvent, TransactionTrace};
 
@@ -29,6 +36,7 @@ mod errors;
 pub use errors::{
     TransactionEventParsingError, TransactionKernelError, TransactionTraceParsingError,
 };
+pub use miden_objects::transaction::KernelLimits;
 
 // TRANSACTION KERNEL
 // ================================================================================================
@@ -36,6 +44,43 @@ pub use errors::{
 pub struct TransactionKernel;
 
 impl TransactionKernel {
+    // KERNEL VERSION
+    // --------------------------------------------------------------------------------------------
+
+    /// Semantic version (major, minor, patch) of the transaction kernel.
+    ///
+    /// The version must be bumped whenever the kernel changes in a way which affects its root, so
+    /// that transactions proven against different kernels can be told apart.
+    pub const SEMVER: (u8, u8, u8) = (0, 9, 0);
+
+    /// Returns the version of the transaction kernel, consisting of its semantic version and of
+    /// the kernel root computed from [TransactionKernel::program_info()].
+    ///
+    /// # Panics
+    /// Panics if the transaction kernel source is not well-formed.
+    pub fn version() -> KernelVersion {
+        Self::version_from_program_info(&Self::program_info())
+    }
+
+    /// Returns the version of the transaction kernel with the kernel root computed from the
+    /// provided [ProgramInfo].
+    ///
+    /// This is useful to avoid re-compiling the kernel main program when its [ProgramInfo] is
+    /// already known.
+    pub fn version_from_program_info(program_info: &ProgramInfo) -> KernelVersion {
+        let (major, minor, patch) = Self::SEMVER;
+        KernelVersion::new(major, minor, patch, KernelVersion::compute_root(program_info))
+    }
+
+    // KERNEL LIMITS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the limits enforced by the transaction kernel, e.g., the maximum number of notes
+    /// consumed and created by a single transaction.
+    pub const fn limits() -> KernelLimits {
+        KernelLimits::CURRENT
+    }
+
     // KERNEL SOURCE CODE
     // --------------------------------------------------------------------------------------------
 
@@ -89,10 +134,11 @@ impl TransactionKernel {
 
     /// Returns the input stack required to execute the transaction kernel.
     ///
-    /// This includes the input notes commitment, the account hash, the account id, and the block
-    /// hash.
+    /// This includes the input notes commitment, the account hash, the account id, the block
+    /// hash, and the proof-of-work difficulty of new account seeds specified by the network
+    /// parameters.
     ///
-    /// Stack: [BH, acct_id, IAH, NC]
+    /// Stack: [BH, acct_id, IAH, NC, regular_pow, faucet_pow]
     ///
     /// Where:
     /// - BH is the latest known block hash at the time of transaction execution.
@@ -101,13 +147,18 @@ impl TransactionKernel {
     ///   newly created accounts, initial state hash is provided as [ZERO; 4].
     /// - NC is a commitment to the input notes. This is a sequential hash of all (nullifier, ZERO)
     ///   tuples for the notes consumed by the transaction.
+    /// - regular_pow and faucet_pow are the minimum numbers of trailing zeros required in the seed
+    ///   digest of a new regular account and of a new faucet, respectively.
     pub fn build_input_stack(
         acct_id: AccountId,
         init_acct_hash: Digest,
         input_notes_hash: Digest,
         block_hash: Digest,
+        network_params: &NetworkParams,
     ) -> StackInputs {
-        let mut inputs: Vec<Felt> = Vec::with_capacity(13);
+        let mut inputs: Vec<Felt> = Vec::with_capacity(15);
+        inputs.push(Felt::from(network_params.faucet_seed_digest_min_trailing_zeros()));
+        inputs.push(Felt::from(network_params.regular_account_seed_digest_min_trailing_zeros()));
         inputs.extend(input_notes_hash);
         inputs.extend_from_slice(init_acct_hash.as_elements());
         inputs.push(acct_id.into());
@@ -150,6 +201,46 @@ impl TransactionKernel {
         (final_account_hash, output_notes_hash)
     }
 
+    // TRANSACTION ADVICE INPUTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a builder of the advice inputs required to execute a transaction with the provided
+    /// inputs and arguments.
+    ///
+    /// The returned builder can be used to inspect the size of the advice data (see
+    /// [AdviceInputsBuilder::size_estimate()]) before the advice inputs are built.
+    ///
+    /// # Errors
+    /// Returns an error if an entry of the advice map of the transaction arguments conflicts with
+    /// the advice data derived from the transaction inputs.
+    pub fn build_advice_inputs(
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+    ) -> Result<AdviceInputsBuilder, AdviceInputsError> {
+        inputs::build_advice_inputs(tx_inputs, tx_args)
+    }
+
+    // ACCOUNT VIEW INPUTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the advice inputs required to execute a read-only procedure of the provided
+    /// account outside of a transaction.
+    ///
+    /// Advice stack: [acct_id, ZERO, ZERO, nonce, AVR, ASR, ACR]
+    ///
+    /// Where:
+    /// - acct_id is the id of the account.
+    /// - nonce is the account nonce.
+    /// - AVR is the account vault root.
+    /// - ASR is the account storage root.
+    /// - ACR is the account code root.
+    ///
+    /// The advice map and Merkle store are populated with the account storage, vault, and code
+    /// data.
+    pub fn build_account_view_advice_inputs(account: &Account) -> AdviceInputs {
+        inputs::build_account_view_advice_inputs(account)
+    }
+
     // TRANSACTION OUTPUT PARSER
     // --------------------------------------------------------------------------------------------
 
@@ -162,16 +253,38 @@ impl TransactionKernel {
     /// Where:
     /// - CNC is the commitment to the notes created by the transaction.
     /// - FAH is the final account hash of the account that the transaction is being
-    ///   executed against.
+    ///   executed against, salted with the provided final account salt.
     ///
     /// The actual data describing the new account state and output notes is expected to be located
-    /// in the provided advice map under keys CNC and FAH.
+    /// in the provided advice map under keys CNC and the unsalted final account hash. If the final
+    /// account hash is salted, the unsalted hash and the salt are expected to be located in the
+    /// advice map under key FAH.
     pub fn from_transaction_parts(
         stack: &StackOutputs,
         adv_map: &AdviceMap,
         output_notes: Vec<OutputNote>,
+        final_account_salt: Option<Word>,
     ) -> Result<TransactionOutputs, TransactionOutputError> {
-        let (final_acct_hash, output_notes_hash) = Self::parse_output_stack(stack);
+        let (final_acct_commitment, output_notes_hash) = Self::parse_output_stack(stack);
+
+        // recover the unsalted final account hash
+        let final_acct_hash = match final_account_salt.filter(|salt| *salt != EMPTY_WORD) {
+            Some(salt) => {
+                let final_acct_hash = adv_map
+                    .get(&final_acct_commitment)
+                    .and_then(|preimage| preimage.get(..WORD_SIZE))
+                    .and_then(|hash| Word::try_from(hash).ok())
+                    .ok_or(TransactionOutputError::FinalAccountDataNotFound)?
+                    .into();
+                if salt_account_hash(final_acct_hash, Some(salt)) != final_acct_commitment {
+                    return Err(TransactionOutputError::FinalAccountCommitmentInvalid(
+                        final_acct_commitment,
+                    ));
+                }
+                final_acct_hash
+            },
+            None => final_acct_commitment,
+        };
 
         // parse final account state
         let final_account_data: &[Word] = group_slice_elements(
@@ -185,10 +298,10 @@ impl TransactionKernel {
         // validate output notes
         let output_notes = OutputNotes::new(output_notes)?;
         if output_notes_hash != output_notes.commitment() {
-            return Err(TransactionOutputError::OutputNotesCommitmentInconsistent(
-                output_notes_hash,
-                output_notes.commitment(),
-            ));
+            return Err(TransactionOutputError::OutputNotesCommitmentInconsistent {
+                expected: output_notes_hash,
+                actual: output_notes.commitment(),
+            });
         }
 
         Ok(TransactionOutputs { account, output_notes })
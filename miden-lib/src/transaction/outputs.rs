@@ -24,7 +24,10 @@ pub const FINAL_ACCOUNT_HASH_WORD_IDX: usize = 1;
 /// Returns a tuple of account ID, vault root, storage root, code root, and nonce.
 pub fn parse_final_account_stub(elements: &[Word]) -> Result<AccountStub, AccountError> {
     if elements.len() != ACCT_DATA_MEM_SIZE {
-        return Err(AccountError::StubDataIncorrectLength(elements.len(), ACCT_DATA_MEM_SIZE));
+        return Err(AccountError::StubDataIncorrectLength {
+            actual: elements.len(),
+            expected: ACCT_DATA_MEM_SIZE,
+        });
     }
 
     let id = AccountId::try_from(elements[ACCT_ID_AND_NONCE_OFFSET as usize][ACCT_ID_IDX])?;
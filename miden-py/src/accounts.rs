@@ -0,0 +1,73 @@
+use miden_objects::accounts;
+use pyo3::{prelude::*, pyclass::CompareOp};
+
+use crate::miden_error;
+
+// ACCOUNT ID
+// ================================================================================================
+
+/// Unique identifier of an account.
+#[pyclass(module = "miden", frozen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountId(pub(crate) accounts::AccountId);
+
+#[pymethods]
+impl AccountId {
+    /// Returns an account ID for the provided integer value.
+    #[new]
+    fn new(value: u64) -> PyResult<Self> {
+        accounts::AccountId::try_from(value).map(Self).map_err(miden_error)
+    }
+
+    /// Returns an account ID for the provided hex-encoded value.
+    #[staticmethod]
+    fn from_hex(value: &str) -> PyResult<Self> {
+        accounts::AccountId::from_hex(value).map(Self).map_err(miden_error)
+    }
+
+    /// Returns the hex encoding of this account ID.
+    #[allow(clippy::wrong_self_convention)]
+    fn to_hex(&self) -> String {
+        self.0.to_hex()
+    }
+
+    /// Type of the account, e.g., "FungibleFaucet" or "RegularAccountUpdatableCode".
+    #[getter]
+    fn account_type(&self) -> String {
+        format!("{:?}", self.0.account_type())
+    }
+
+    /// True if the account is a fungible or non-fungible faucet.
+    #[getter]
+    fn is_faucet(&self) -> bool {
+        self.0.is_faucet()
+    }
+
+    /// True if the state of the account is stored on-chain.
+    #[getter]
+    fn is_on_chain(&self) -> bool {
+        self.0.is_on_chain()
+    }
+
+    fn __int__(&self) -> u64 {
+        self.0.into()
+    }
+
+    fn __hash__(&self) -> u64 {
+        self.0.into()
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.0.cmp(&other.0))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("AccountId({})", self.0)
+    }
+}
+
+impl From<AccountId> for accounts::AccountId {
+    fn from(account_id: AccountId) -> Self {
+        account_id.0
+    }
+}
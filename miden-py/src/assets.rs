@@ -0,0 +1,65 @@
+use miden_objects::assets::{self, FungibleAsset};
+use pyo3::prelude::*;
+
+use crate::{miden_error, AccountId};
+
+// ASSET
+// ================================================================================================
+
+/// A fungible or non-fungible asset.
+#[pyclass(module = "miden", frozen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Asset(pub(crate) assets::Asset);
+
+#[pymethods]
+impl Asset {
+    /// Returns a fungible asset issued by the specified faucet.
+    #[staticmethod]
+    fn fungible(faucet_id: AccountId, amount: u64) -> PyResult<Self> {
+        FungibleAsset::new(faucet_id.into(), amount)
+            .map(|asset| Self(asset.into()))
+            .map_err(miden_error)
+    }
+
+    /// ID of the faucet which issued this asset.
+    #[getter]
+    fn faucet_id(&self) -> AccountId {
+        AccountId(self.0.faucet_id())
+    }
+
+    /// True if this is a fungible asset.
+    #[getter]
+    fn is_fungible(&self) -> bool {
+        self.0.is_fungible()
+    }
+
+    /// Amount of a fungible asset, or None for non-fungible assets.
+    #[getter]
+    fn amount(&self) -> Option<u64> {
+        match self.0 {
+            assets::Asset::Fungible(asset) => Some(asset.amount()),
+            assets::Asset::NonFungible(_) => None,
+        }
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    fn __repr__(&self) -> String {
+        match self.0 {
+            assets::Asset::Fungible(asset) => {
+                format!("Asset.fungible({}, {})", asset.faucet_id(), asset.amount())
+            },
+            assets::Asset::NonFungible(asset) => {
+                format!("Asset.non_fungible({})", asset.faucet_id())
+            },
+        }
+    }
+}
+
+impl From<Asset> for assets::Asset {
+    fn from(asset: Asset) -> Self {
+        asset.0
+    }
+}
@@ -0,0 +1,65 @@
+//! Python bindings for Miden rollup objects and transactions.
+//!
+//! The bindings are exposed as the `miden` Python module, built via `maturin` with the
+//! `extension-module` feature enabled. The module provides:
+//!
+//! - Wrappers for core objects: [AccountId], [Asset], [NoteType], and [Note].
+//! - Constructors for the standard P2ID, P2IDR, and SWAP notes.
+//! - Transaction execution against a data store implemented in Python, as well as proving and
+//!   verification of executed transactions.
+//!
+//! All errors raised by the bindings are reported as `miden.MidenError` exceptions.
+
+use core::fmt::Debug;
+
+use pyo3::{create_exception, exceptions::PyException, prelude::*};
+
+mod accounts;
+pub use accounts::AccountId;
+
+mod assets;
+pub use assets::Asset;
+
+mod notes;
+pub use notes::{Note, NoteType};
+
+mod transaction;
+pub use transaction::{ExecutedTransaction, ProvenTransaction};
+
+#[cfg(test)]
+mod tests;
+
+create_exception!(miden, MidenError, PyException, "Error raised by the Miden bindings.");
+
+// MODULE
+// ================================================================================================
+
+/// Python bindings for Miden rollup objects and transactions.
+#[pymodule]
+fn miden(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("MidenError", m.py().get_type_bound::<MidenError>())?;
+
+    m.add_class::<AccountId>()?;
+    m.add_class::<Asset>()?;
+    m.add_class::<NoteType>()?;
+    m.add_class::<Note>()?;
+    m.add_class::<ExecutedTransaction>()?;
+    m.add_class::<ProvenTransaction>()?;
+
+    m.add_function(wrap_pyfunction!(notes::create_p2id_note, m)?)?;
+    m.add_function(wrap_pyfunction!(notes::create_p2idr_note, m)?)?;
+    m.add_function(wrap_pyfunction!(notes::create_swap_note, m)?)?;
+    m.add_function(wrap_pyfunction!(transaction::execute_transaction, m)?)?;
+    m.add_function(wrap_pyfunction!(transaction::prove_transaction, m)?)?;
+    m.add_function(wrap_pyfunction!(transaction::verify_transaction, m)?)?;
+
+    Ok(())
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts the provided error into a [MidenError] exception.
+fn miden_error<E: Debug>(err: E) -> PyErr {
+    MidenError::new_err(format!("{err:?}"))
+}
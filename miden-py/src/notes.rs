@@ -0,0 +1,226 @@
+use miden_objects::{
+    crypto::rand::RpoRandomCoin,
+    notes,
+    utils::serde::{Deserializable, Serializable},
+    Felt, Word,
+};
+use pyo3::{prelude::*, types::PyBytes};
+
+use crate::{miden_error, AccountId, Asset};
+
+// NOTE TYPE
+// ================================================================================================
+
+/// Defines how much of a note is shared with the network.
+#[pyclass(module = "miden", frozen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteType {
+    OffChain,
+    Encrypted,
+    Public,
+}
+
+impl From<NoteType> for notes::NoteType {
+    fn from(note_type: NoteType) -> Self {
+        match note_type {
+            NoteType::OffChain => notes::NoteType::OffChain,
+            NoteType::Encrypted => notes::NoteType::Encrypted,
+            NoteType::Public => notes::NoteType::Public,
+        }
+    }
+}
+
+impl From<notes::NoteType> for NoteType {
+    fn from(note_type: notes::NoteType) -> Self {
+        match note_type {
+            notes::NoteType::OffChain => NoteType::OffChain,
+            notes::NoteType::Encrypted => NoteType::Encrypted,
+            notes::NoteType::Public => NoteType::Public,
+        }
+    }
+}
+
+// NOTE
+// ================================================================================================
+
+/// A note which can be consumed by a transaction.
+#[pyclass(module = "miden", frozen)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Note(pub(crate) notes::Note);
+
+#[pymethods]
+impl Note {
+    /// Deserializes a note from its binary encoding.
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        notes::Note::read_from_bytes(bytes).map(Self).map_err(miden_error)
+    }
+
+    /// Returns the binary encoding of this note.
+    fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &self.0.to_bytes())
+    }
+
+    /// Hex-encoded ID of this note.
+    #[getter]
+    fn id(&self) -> String {
+        self.0.id().inner().to_hex()
+    }
+
+    /// Hex-encoded nullifier of this note.
+    #[getter]
+    fn nullifier(&self) -> String {
+        self.0.nullifier().inner().to_hex()
+    }
+
+    /// ID of the account which created this note.
+    #[getter]
+    fn sender(&self) -> AccountId {
+        AccountId(self.0.metadata().sender())
+    }
+
+    #[getter]
+    fn note_type(&self) -> NoteType {
+        self.0.metadata().note_type().into()
+    }
+
+    #[getter]
+    fn tag(&self) -> u32 {
+        self.0.metadata().tag().inner()
+    }
+
+    /// The user-defined aux value of the note.
+    #[getter]
+    fn aux(&self) -> u64 {
+        self.0.metadata().aux().as_int()
+    }
+
+    /// Assets carried by this note.
+    #[getter]
+    fn assets(&self) -> Vec<Asset> {
+        self.0.assets().iter().map(|asset| Asset(*asset)).collect()
+    }
+
+    /// Hex-encoded root of the note script.
+    #[getter]
+    fn script_root(&self) -> String {
+        self.0.script().hash().to_hex()
+    }
+
+    /// Inputs of the note script.
+    #[getter]
+    fn inputs(&self) -> Vec<u64> {
+        self.0.inputs().values().iter().map(Felt::as_int).collect()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Note({})", self.id())
+    }
+}
+
+// NOTE CONSTRUCTORS
+// ================================================================================================
+
+/// Creates a P2ID note transferring the provided assets from `sender` to `target`.
+///
+/// The serial number of the note is drawn from a random coin initialized with `seed`, and `aux`
+/// must be an encoded note aux value.
+#[pyfunction]
+#[pyo3(signature = (sender, target, assets, note_type, seed, aux=0))]
+pub fn create_p2id_note(
+    sender: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    seed: [u64; 4],
+    aux: u64,
+) -> PyResult<Note> {
+    miden_lib::notes::create_p2id_note(
+        sender.into(),
+        target.into(),
+        assets.into_iter().map(Into::into).collect(),
+        note_type.into(),
+        felt(aux)?,
+        random_coin(seed)?,
+    )
+    .map(Note)
+    .map_err(miden_error)
+}
+
+/// Creates a P2IDR note transferring the provided assets from `sender` to `target`, which can be
+/// recalled by `sender` once the chain reaches `recall_height`.
+///
+/// The serial number of the note is drawn from a random coin initialized with `seed`, and `aux`
+/// must be an encoded note aux value.
+#[pyfunction]
+#[pyo3(signature = (sender, target, assets, note_type, recall_height, seed, aux=0))]
+pub fn create_p2idr_note(
+    sender: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    recall_height: u32,
+    seed: [u64; 4],
+    aux: u64,
+) -> PyResult<Note> {
+    miden_lib::notes::create_p2idr_note(
+        sender.into(),
+        target.into(),
+        assets.into_iter().map(Into::into).collect(),
+        note_type.into(),
+        felt(aux)?,
+        recall_height,
+        random_coin(seed)?,
+    )
+    .map(Note)
+    .map_err(miden_error)
+}
+
+/// Creates a SWAP note offering `offered_asset` in exchange for `requested_asset`.
+///
+/// Returns the SWAP note together with the binary encoding of the details of the payback note
+/// which is created for `sender` when the SWAP note is consumed. The serial numbers of both notes
+/// are drawn from a random coin initialized with `seed`, and `aux` must be an encoded note aux
+/// value.
+#[pyfunction]
+#[pyo3(signature = (sender, offered_asset, requested_asset, note_type, seed, aux=0))]
+pub fn create_swap_note(
+    py: Python<'_>,
+    sender: AccountId,
+    offered_asset: Asset,
+    requested_asset: Asset,
+    note_type: NoteType,
+    seed: [u64; 4],
+    aux: u64,
+) -> PyResult<(Note, Bound<'_, PyBytes>)> {
+    let (note, payback_note) = miden_lib::notes::create_swap_note(
+        sender.into(),
+        offered_asset.into(),
+        requested_asset.into(),
+        note_type.into(),
+        felt(aux)?,
+        random_coin(seed)?,
+    )
+    .map_err(miden_error)?;
+
+    Ok((Note(note), PyBytes::new_bound(py, &payback_note.to_bytes())))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a random coin initialized with the provided seed.
+fn random_coin(seed: [u64; 4]) -> PyResult<RpoRandomCoin> {
+    let seed: Word = [felt(seed[0])?, felt(seed[1])?, felt(seed[2])?, felt(seed[3])?];
+    Ok(RpoRandomCoin::new(seed))
+}
+
+/// Returns a field element for the provided value, or an error if the value is not a canonical
+/// field element.
+fn felt(value: u64) -> PyResult<Felt> {
+    Felt::try_from(value).map_err(miden_error)
+}
@@ -0,0 +1,220 @@
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    accounts::account_id::testing::{
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        ACCOUNT_ID_SENDER,
+    },
+    assets::FungibleAsset,
+    crypto::rand::RpoRandomCoin,
+    notes,
+    transaction::OutputNote,
+    utils::serde::Serializable,
+    Felt, ZERO,
+};
+use mock::mock::{
+    account::{mock_account_code, MockAccountType},
+    notes::{mock_notes, AssetPreservationStatus},
+    transaction::mock_inputs,
+};
+use pyo3::{
+    prelude::*,
+    types::{PyBytes, PyDict},
+};
+
+use crate::{AccountId, Note};
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn account_id_from_python() {
+    run_python(
+        &format!(
+            r#"
+account_id = miden.AccountId({ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN})
+assert int(account_id) == {ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN}
+assert account_id.is_faucet
+assert account_id.is_on_chain
+assert account_id.account_type == "FungibleFaucet"
+assert miden.AccountId.from_hex(account_id.to_hex()) == account_id
+
+try:
+    miden.AccountId(0)
+    assert False, "invalid account ID was accepted"
+except miden.MidenError:
+    pass
+"#
+        ),
+        PyDict::new_bound,
+    );
+}
+
+#[test]
+fn p2id_note_from_python() {
+    let sender = ACCOUNT_ID_SENDER.try_into().unwrap();
+    let target = ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN.try_into().unwrap();
+    let faucet_id = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap();
+    let asset = FungibleAsset::new(faucet_id, 100).unwrap();
+    let seed = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let expected = miden_lib::notes::create_p2id_note(
+        sender,
+        target,
+        vec![asset.into()],
+        notes::NoteType::Public,
+        ZERO,
+        RpoRandomCoin::new(seed),
+    )
+    .unwrap();
+
+    run_python(
+        &format!(
+            r#"
+sender = miden.AccountId({ACCOUNT_ID_SENDER})
+target = miden.AccountId({ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN})
+asset = miden.Asset.fungible(miden.AccountId({ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN}), 100)
+
+note = miden.create_p2id_note(sender, target, [asset], miden.NoteType.Public, [1, 2, 3, 4])
+assert note.id == expected.id
+assert note.sender == sender
+assert note.note_type == miden.NoteType.Public
+assert note.assets == [asset]
+assert note.inputs == [int(target)]
+assert miden.Note.from_bytes(note.to_bytes()) == note
+
+recallable = miden.create_p2idr_note(sender, target, [asset], miden.NoteType.Public, 10, [1, 2, 3, 4], aux=27)
+assert recallable.inputs == [int(target), 10]
+assert recallable.aux == 27
+
+offered = miden.Asset.fungible(miden.AccountId({ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN}), 50)
+swap, payback = miden.create_swap_note(sender, offered, asset, miden.NoteType.Public, [1, 2, 3, 4])
+assert swap.assets == [offered]
+assert isinstance(payback, bytes)
+"#
+        ),
+        |py| {
+            let globals = PyDict::new_bound(py);
+            globals.set_item("expected", Note(expected).into_py(py)).unwrap();
+            globals
+        },
+    );
+}
+
+#[test]
+fn execute_transaction_from_python() {
+    run_python(
+        &format!(
+            r#"{MOCK_DATA_STORE}
+data_store = DataStore()
+tx = miden.execute_transaction(
+    data_store, account_id, block_ref, note_ids, expected_output_notes=expected_output_notes
+)
+assert tx.account_id == account_id
+assert data_store.requests == [(account_id, block_ref, note_ids)]
+assert len(tx.output_notes()) > 0
+
+# errors raised by the data store are reported as Miden errors
+class FailingDataStore(DataStore):
+    def get_transaction_inputs(self, account_id, block_ref, note_ids):
+        raise RuntimeError("block not available")
+
+try:
+    miden.execute_transaction(FailingDataStore(), account_id, block_ref, note_ids)
+    assert False, "data store error was not propagated"
+except miden.MidenError as err:
+    assert "block not available" in str(err)
+"#
+        ),
+        mock_transaction_globals,
+    );
+}
+
+#[test]
+fn prove_transaction_from_python() {
+    run_python(
+        &format!(
+            r#"{MOCK_DATA_STORE}
+tx = miden.execute_transaction(
+    DataStore(), account_id, block_ref, note_ids, expected_output_notes=expected_output_notes
+)
+proven_tx = miden.prove_transaction(tx)
+assert proven_tx.id == tx.id
+
+proven_tx = miden.ProvenTransaction.from_bytes(proven_tx.to_bytes())
+miden.verify_transaction(proven_tx)
+"#
+        ),
+        mock_transaction_globals,
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// A Python data store serving the transaction inputs provided by [mock_transaction_globals()].
+const MOCK_DATA_STORE: &str = r#"
+class DataStore:
+    def __init__(self):
+        self.requests = []
+
+    def get_transaction_inputs(self, account_id, block_ref, note_ids):
+        self.requests.append((account_id, block_ref, note_ids))
+        return tx_inputs
+
+    def get_account_code(self, account_id):
+        return account_code
+"#;
+
+/// Returns the mock transaction data used by [MOCK_DATA_STORE], as well as the ID of the executing
+/// account, the reference block, the IDs of the consumed notes, and the expected output notes.
+fn mock_transaction_globals(py: Python<'_>) -> Bound<'_, PyDict> {
+    let (tx_inputs, _) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let note_ids = tx_inputs
+        .input_notes()
+        .iter()
+        .map(|note| note.id().inner().to_hex())
+        .collect::<Vec<_>>();
+
+    // note scripts call account procedures, and so the account code must be compiled first
+    let assembler = TransactionKernel::assembler();
+    mock_account_code(&assembler);
+    let (_, output_notes) = mock_notes(&assembler, &AssetPreservationStatus::Preserved);
+    let expected_output_notes = output_notes
+        .into_iter()
+        .filter_map(|note| match note {
+            OutputNote::Full(note) => Some(Note(note)),
+            OutputNote::Partial(_) | OutputNote::Header(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    let globals = PyDict::new_bound(py);
+    let account_code = tx_inputs.account().code().to_bytes();
+    globals
+        .set_item("tx_inputs", PyBytes::new_bound(py, &tx_inputs.to_bytes()))
+        .unwrap();
+    globals.set_item("account_code", PyBytes::new_bound(py, &account_code)).unwrap();
+    globals
+        .set_item("account_id", AccountId(tx_inputs.account().id()).into_py(py))
+        .unwrap();
+    globals.set_item("block_ref", tx_inputs.block_header().block_num()).unwrap();
+    globals.set_item("note_ids", note_ids).unwrap();
+    globals
+        .set_item("expected_output_notes", expected_output_notes.into_py(py))
+        .unwrap();
+    globals
+}
+
+/// Runs the provided Python code with the `miden` module imported and the variables returned by
+/// `globals` in scope, panicking if the code raises an exception.
+fn run_python(code: &str, globals: impl for<'py> FnOnce(Python<'py>) -> Bound<'py, PyDict>) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let globals = globals(py);
+        globals.set_item("miden", pyo3::wrap_pymodule!(crate::miden)(py)).unwrap();
+
+        if let Err(err) = py.run_bound(code, Some(&globals), None) {
+            err.print(py);
+            panic!("Python code raised an exception: {err}");
+        }
+    });
+}
@@ -0,0 +1,268 @@
+use std::borrow::Cow;
+
+use miden_objects::{
+    accounts::{self, AccountCode},
+    assembly::{ModuleAst, ProgramAst},
+    notes::NoteId,
+    transaction::{self, ChainMmr, InputNote, TransactionArgs, TransactionInputs},
+    utils::serde::{Deserializable, Serializable},
+    BlockHeader, Digest, Word, MIN_PROOF_SECURITY_LEVEL,
+};
+use miden_tx::{
+    DataStore, DataStoreError, ProvingOptions, ScriptTarget, TransactionExecutor,
+    TransactionProver, TransactionVerifier,
+};
+use pyo3::{prelude::*, types::PyBytes};
+
+use crate::{miden_error, AccountId, Note};
+
+// EXECUTED TRANSACTION
+// ================================================================================================
+
+/// Result of executing a transaction, which can be proven via `prove_transaction()`.
+#[pyclass(module = "miden", frozen)]
+#[derive(Debug, Clone)]
+pub struct ExecutedTransaction(pub(crate) transaction::ExecutedTransaction);
+
+#[pymethods]
+impl ExecutedTransaction {
+    /// Hex-encoded ID of this transaction.
+    #[getter]
+    fn id(&self) -> String {
+        self.0.id().to_hex()
+    }
+
+    /// ID of the account against which this transaction was executed.
+    #[getter]
+    fn account_id(&self) -> AccountId {
+        AccountId(self.0.account_id())
+    }
+
+    /// Hex-encoded hash of the account state after this transaction.
+    #[getter]
+    fn final_account_hash(&self) -> String {
+        self.0.final_account().hash().to_hex()
+    }
+
+    /// Returns the binary encoding of the changes made to the account by this transaction.
+    fn account_delta<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &self.0.account_delta().to_bytes())
+    }
+
+    /// Returns the binary encoding of the notes created by this transaction.
+    fn output_notes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &self.0.output_notes().to_bytes())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ExecutedTransaction({})", self.id())
+    }
+}
+
+// PROVEN TRANSACTION
+// ================================================================================================
+
+/// A transaction together with a proof of its correct execution.
+#[pyclass(module = "miden", frozen)]
+#[derive(Debug, Clone)]
+pub struct ProvenTransaction(pub(crate) transaction::ProvenTransaction);
+
+#[pymethods]
+impl ProvenTransaction {
+    /// Deserializes a proven transaction from its binary encoding.
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        transaction::ProvenTransaction::read_from_bytes(bytes)
+            .map(Self)
+            .map_err(miden_error)
+    }
+
+    /// Returns the binary encoding of this proven transaction.
+    fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &self.0.to_bytes())
+    }
+
+    /// Hex-encoded ID of this transaction.
+    #[getter]
+    fn id(&self) -> String {
+        self.0.id().to_hex()
+    }
+
+    /// ID of the account against which this transaction was executed.
+    #[getter]
+    fn account_id(&self) -> AccountId {
+        AccountId(self.0.account_id())
+    }
+
+    /// Version of the transaction kernel this transaction was proven with.
+    #[getter]
+    fn kernel_version(&self) -> String {
+        self.0.kernel_version().to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ProvenTransaction({})", self.id())
+    }
+}
+
+// TRANSACTION FUNCTIONS
+// ================================================================================================
+
+/// Executes a transaction against the specified account, consuming the notes with the provided
+/// hex-encoded IDs.
+///
+/// Transaction data is loaded from `data_store`, which must provide the following methods:
+/// - `get_transaction_inputs(account_id, block_ref, note_ids) -> bytes`, returning the binary
+///   encoding of the transaction inputs.
+/// - `get_account_code(account_id) -> str | bytes`, returning either the source code of the account
+///   or the binary encoding of its compiled code.
+///
+/// If provided, `tx_script` is compiled against the interface of the account and executed as part
+/// of the transaction. The notes expected to be created by the transaction must be listed in
+/// `expected_output_notes`; this is required for all public output notes.
+#[pyfunction]
+#[pyo3(signature = (data_store, account_id, block_ref, note_ids, tx_script=None, expected_output_notes=Vec::new()))]
+pub fn execute_transaction(
+    data_store: PyObject,
+    account_id: AccountId,
+    block_ref: u32,
+    note_ids: Vec<String>,
+    tx_script: Option<&str>,
+    expected_output_notes: Vec<Note>,
+) -> PyResult<ExecutedTransaction> {
+    let account_id = account_id.into();
+    let note_ids = note_ids
+        .iter()
+        .map(|note_id| Digest::try_from(note_id.as_str()).map(NoteId::from))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(miden_error)?;
+
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(PyDataStore(data_store), None);
+    executor.load_account(account_id).map_err(miden_error)?;
+
+    let mut tx_args = match tx_script {
+        Some(tx_script) => {
+            let tx_script = ProgramAst::parse(tx_script).map_err(miden_error)?;
+            let target = vec![ScriptTarget::AccountId(account_id)];
+            let tx_script =
+                executor.compile_tx_script(tx_script, vec![], target).map_err(miden_error)?;
+            TransactionArgs::with_tx_script(tx_script)
+        },
+        None => TransactionArgs::default(),
+    };
+    tx_args.extend_expected_output_notes(expected_output_notes.into_iter().map(|note| note.0));
+
+    executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args)
+        .map(ExecutedTransaction)
+        .map_err(miden_error)
+}
+
+/// Proves the provided executed transaction using the default proving options.
+#[pyfunction]
+pub fn prove_transaction(
+    py: Python<'_>,
+    executed_transaction: &ExecutedTransaction,
+) -> PyResult<ProvenTransaction> {
+    let executed_transaction = executed_transaction.0.clone();
+    let prover = TransactionProver::new(ProvingOptions::default());
+
+    // proving takes a while, and so the GIL is released to let other Python threads run
+    py.allow_threads(|| prover.prove_transaction(executed_transaction))
+        .map(ProvenTransaction)
+        .map_err(miden_error)
+}
+
+/// Verifies the proof of the provided transaction, raising `MidenError` if the proof is invalid or
+/// does not reach the specified security level.
+#[pyfunction]
+#[pyo3(signature = (proven_transaction, security_level=MIN_PROOF_SECURITY_LEVEL))]
+pub fn verify_transaction(
+    proven_transaction: &ProvenTransaction,
+    security_level: u32,
+) -> PyResult<()> {
+    TransactionVerifier::new(security_level)
+        .verify(proven_transaction.0.clone())
+        .map_err(miden_error)
+}
+
+// DATA STORE
+// ================================================================================================
+
+/// A data store which delegates to a Python object.
+///
+/// Python data stores provide the transaction inputs as a whole, and so the individual parts of
+/// the inputs cannot be requested from them.
+struct PyDataStore(PyObject);
+
+/// Account code returned by a Python data store, either as source code or as the binary encoding
+/// of the compiled account code.
+#[derive(FromPyObject)]
+enum AccountCodeSource {
+    Source(String),
+    Bytes(Vec<u8>),
+}
+
+impl DataStore for PyDataStore {
+    fn get_account(
+        &self,
+        _account_id: accounts::AccountId,
+    ) -> Result<(accounts::Account, Option<Word>), DataStoreError> {
+        Err(parts_not_supported("accounts"))
+    }
+
+    fn get_block_header(&self, _block_num: u32) -> Result<BlockHeader, DataStoreError> {
+        Err(parts_not_supported("block headers"))
+    }
+
+    fn get_chain_mmr(&self, _block_ref: u32, _blocks: &[u32]) -> Result<ChainMmr, DataStoreError> {
+        Err(parts_not_supported("chain MMRs"))
+    }
+
+    fn get_notes(&self, _note_ids: &[NoteId]) -> Result<Vec<InputNote>, DataStoreError> {
+        Err(parts_not_supported("notes"))
+    }
+
+    fn get_transaction_inputs(
+        &self,
+        account_id: accounts::AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, DataStoreError> {
+        let note_ids = notes.iter().map(|note_id| note_id.inner().to_hex()).collect::<Vec<_>>();
+
+        let bytes = Python::with_gil(|py| {
+            let args = (AccountId(account_id), block_ref, note_ids);
+            let result = self.0.call_method1(py, "get_transaction_inputs", args)?;
+            result.extract::<Cow<[u8]>>(py).map(Cow::into_owned)
+        })
+        .map_err(|err| DataStoreError::InternalError(err.to_string()))?;
+
+        TransactionInputs::read_from_bytes(&bytes)
+            .map_err(|err| DataStoreError::InternalError(err.to_string()))
+    }
+
+    fn get_account_code(
+        &self,
+        account_id: accounts::AccountId,
+    ) -> Result<ModuleAst, DataStoreError> {
+        let code = Python::with_gil(|py| {
+            let result = self.0.call_method1(py, "get_account_code", (AccountId(account_id),))?;
+            result.extract::<AccountCodeSource>(py)
+        })
+        .map_err(|err| DataStoreError::InternalError(err.to_string()))?;
+
+        match code {
+            AccountCodeSource::Source(source) => ModuleAst::parse(&source)
+                .map_err(|err| DataStoreError::InternalError(err.to_string())),
+            AccountCodeSource::Bytes(bytes) => AccountCode::read_from_bytes(&bytes)
+                .map(|code| code.module().clone())
+                .map_err(|err| DataStoreError::InternalError(err.to_string())),
+        }
+    }
+}
+
+fn parts_not_supported(part: &str) -> DataStoreError {
+    DataStoreError::InternalError(format!("Python data stores do not provide {part} separately"))
+}
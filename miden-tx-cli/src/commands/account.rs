@@ -0,0 +1,155 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use miden_lib::{
+    accounts::{faucets::create_basic_fungible_faucet, wallets::create_basic_wallet},
+    AuthScheme,
+};
+use miden_objects::{
+    accounts::{AccountData, AccountStorageType, AccountType, AuthSecretKey},
+    assets::TokenSymbol,
+    crypto::dsa::rpo_falcon512::SecretKey,
+    Felt,
+};
+use rand::Rng;
+
+use crate::utils::{read_object, rng, write_object};
+
+// ACCOUNT COMMAND
+// ================================================================================================
+
+#[derive(Subcommand, Debug)]
+pub enum AccountCommand {
+    /// Create a new account with a fresh RPO Falcon512 key and write its data to a file.
+    New(NewAccountCmd),
+
+    /// Print the details of an account stored in a file.
+    Show {
+        /// File containing the account data.
+        path: PathBuf,
+    },
+}
+
+impl AccountCommand {
+    pub fn execute(self) -> Result<(), String> {
+        match self {
+            AccountCommand::New(cmd) => cmd.execute(),
+            AccountCommand::Show { path } => {
+                let account_data: AccountData = read_object(&path)?;
+                let account = &account_data.account;
+
+                println!("id: {}", account.id().to_labeled_string());
+                println!("hash: {}", account.hash());
+                println!("nonce: {}", account.nonce());
+                println!("new: {}", account.is_new());
+                for asset in account.vault().assets() {
+                    println!("asset: {asset:?}");
+                }
+
+                Ok(())
+            },
+        }
+    }
+}
+
+// NEW ACCOUNT
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum AccountTemplate {
+    /// A basic wallet with immutable code.
+    Wallet,
+    /// A basic wallet with updatable code.
+    UpdatableWallet,
+    /// A basic fungible faucet.
+    FungibleFaucet,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum StorageMode {
+    OnChain,
+    OffChain,
+}
+
+#[derive(Parser, Debug)]
+pub struct NewAccountCmd {
+    /// Kind of account to create.
+    #[arg(long = "type", value_enum, default_value = "wallet")]
+    template: AccountTemplate,
+
+    /// Where the state of the account is stored.
+    #[arg(long, value_enum, default_value = "off-chain")]
+    storage: StorageMode,
+
+    /// Token symbol of a fungible faucet.
+    #[arg(long, required_if_eq("template", "fungible-faucet"))]
+    token_symbol: Option<String>,
+
+    /// Number of decimals of the tokens issued by a fungible faucet.
+    #[arg(long, default_value_t = 8)]
+    decimals: u8,
+
+    /// Maximum supply of the tokens issued by a fungible faucet.
+    #[arg(long, default_value_t = 1_000_000)]
+    max_supply: u64,
+
+    /// Value used to seed the RNG generating the account seed and key; if not provided, the RNG
+    /// is seeded from the OS entropy source.
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// File to which the account data is written.
+    #[arg(short, long)]
+    output: PathBuf,
+}
+
+impl NewAccountCmd {
+    pub fn execute(self) -> Result<(), String> {
+        let mut rng = rng(self.seed);
+        let init_seed: [u8; 32] = rng.gen();
+        let secret_key = SecretKey::with_rng(&mut rng);
+        let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: secret_key.public_key() };
+
+        let storage_type = match self.storage {
+            StorageMode::OnChain => AccountStorageType::OnChain,
+            StorageMode::OffChain => AccountStorageType::OffChain,
+        };
+
+        let (account, account_seed) = match self.template {
+            AccountTemplate::Wallet => create_basic_wallet(
+                init_seed,
+                auth_scheme,
+                AccountType::RegularAccountImmutableCode,
+                storage_type,
+            ),
+            AccountTemplate::UpdatableWallet => create_basic_wallet(
+                init_seed,
+                auth_scheme,
+                AccountType::RegularAccountUpdatableCode,
+                storage_type,
+            ),
+            AccountTemplate::FungibleFaucet => {
+                let symbol = self.token_symbol.expect("token symbol is required by clap");
+                let symbol = TokenSymbol::new(&symbol)
+                    .map_err(|err| format!("invalid token symbol {symbol}: {err}"))?;
+                create_basic_fungible_faucet(
+                    init_seed,
+                    symbol,
+                    self.decimals,
+                    Felt::try_from(self.max_supply).map_err(|err| err.to_string())?,
+                    storage_type,
+                    auth_scheme,
+                )
+            },
+        }
+        .map_err(|err| format!("failed to create account: {err}"))?;
+
+        let account_id = account.id();
+        let account_data =
+            AccountData::new(account, Some(account_seed), AuthSecretKey::RpoFalcon512(secret_key));
+        write_object(&self.output, &account_data)?;
+
+        println!("created account {account_id} in {}", self.output.display());
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+mod account;
+pub use account::AccountCommand;
+
+mod note;
+pub use note::NoteCommand;
+
+mod transaction;
+pub use transaction::{ExecuteCmd, ProveCmd, VerifyCmd};
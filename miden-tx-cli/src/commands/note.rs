@@ -0,0 +1,118 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use miden_lib::notes::create_p2id_note;
+use miden_objects::{
+    assets::Asset,
+    crypto::rand::RpoRandomCoin,
+    notes::{Note, NoteType},
+    Felt,
+};
+
+use crate::utils::{
+    parse_account_id, parse_fungible_asset, random_word, read_object, rng, write_object,
+};
+
+// NOTE COMMAND
+// ================================================================================================
+
+#[derive(Subcommand, Debug)]
+pub enum NoteCommand {
+    /// Create a P2ID note transferring assets to a target account, and write it to a file.
+    CreateP2id(CreateP2idCmd),
+
+    /// Print the details of a note stored in a file.
+    Show {
+        /// File containing the note.
+        path: PathBuf,
+    },
+}
+
+impl NoteCommand {
+    pub fn execute(self) -> Result<(), String> {
+        match self {
+            NoteCommand::CreateP2id(cmd) => cmd.execute(),
+            NoteCommand::Show { path } => {
+                let note: Note = read_object(&path)?;
+
+                println!("id: {}", note.id());
+                println!("nullifier: {}", note.nullifier());
+                println!("sender: {}", note.metadata().sender());
+                println!("type: {:?}", note.metadata().note_type());
+                println!("tag: {}", note.metadata().tag().inner());
+                println!("script: {}", note.script().hash());
+                for asset in note.assets().iter() {
+                    println!("asset: {asset:?}");
+                }
+
+                Ok(())
+            },
+        }
+    }
+}
+
+// CREATE P2ID
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum NoteVisibility {
+    Public,
+    Private,
+}
+
+#[derive(Parser, Debug)]
+pub struct CreateP2idCmd {
+    /// Hex-encoded ID of the account sending the note.
+    #[arg(long)]
+    sender: String,
+
+    /// Hex-encoded ID of the account which can consume the note.
+    #[arg(long)]
+    target: String,
+
+    /// Fungible asset carried by the note, specified as `<faucet ID>:<amount>`; can be repeated.
+    #[arg(long = "asset", required = true)]
+    assets: Vec<String>,
+
+    /// Whether the note details are shared with the network.
+    #[arg(long, value_enum, default_value = "private")]
+    note_type: NoteVisibility,
+
+    /// Auxiliary value attached to the note metadata; must be a valid `NoteAux` encoding.
+    #[arg(long, default_value_t = 0)]
+    aux: u64,
+
+    /// Value used to seed the RNG generating the note serial number; if not provided, the RNG is
+    /// seeded from the OS entropy source.
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// File to which the note is written.
+    #[arg(short, long)]
+    output: PathBuf,
+}
+
+impl CreateP2idCmd {
+    pub fn execute(self) -> Result<(), String> {
+        let sender = parse_account_id(&self.sender)?;
+        let target = parse_account_id(&self.target)?;
+        let assets = self
+            .assets
+            .iter()
+            .map(|asset| parse_fungible_asset(asset))
+            .collect::<Result<Vec<Asset>, _>>()?;
+        let note_type = match self.note_type {
+            NoteVisibility::Public => NoteType::Public,
+            NoteVisibility::Private => NoteType::OffChain,
+        };
+        let aux = Felt::try_from(self.aux)?;
+        let rng = RpoRandomCoin::new(random_word(&mut rng(self.seed)));
+
+        let note = create_p2id_note(sender, target, assets, note_type, aux, rng)
+            .map_err(|err| format!("failed to create note: {err}"))?;
+        write_object(&self.output, &note)?;
+
+        println!("created note {} in {}", note.id(), self.output.display());
+        Ok(())
+    }
+}
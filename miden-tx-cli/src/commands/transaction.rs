@@ -0,0 +1,266 @@
+use std::{fs, path::PathBuf, rc::Rc};
+
+use clap::Parser;
+use miden_objects::{
+    accounts::{Account, AccountData, AccountId, AuthSecretKey},
+    assembly::{ModuleAst, ProgramAst},
+    notes::{Note, NoteId},
+    transaction::{
+        ChainMmr, ExecutedTransaction, InputNote, ProvenTransaction, TransactionArgs,
+        TransactionInputs,
+    },
+    BlockHeader, Word, MIN_PROOF_SECURITY_LEVEL,
+};
+use miden_tx::{
+    BasicAuthenticator, DataStore, DataStoreError, ProvingOptions, ScriptTarget,
+    TransactionExecutor, TransactionProver, TransactionVerifier,
+};
+use rand::rngs::StdRng;
+
+use crate::utils::{read_object, write_object};
+
+// TRANSACTION ARGUMENTS
+// ================================================================================================
+
+/// Arguments describing the transaction to be executed.
+#[derive(Parser, Debug)]
+pub struct TransactionCmdArgs {
+    /// File containing the serialized transaction inputs; all input notes contained in the inputs
+    /// are consumed by the transaction.
+    #[arg(short, long)]
+    inputs: PathBuf,
+
+    /// File containing the MASM source of the transaction script.
+    #[arg(short, long)]
+    script: Option<PathBuf>,
+
+    /// File containing a note expected to be created by the transaction; details of all public
+    /// output notes must be provided. Can be repeated.
+    #[arg(long = "expected-note")]
+    expected_notes: Vec<PathBuf>,
+
+    /// File containing the data of the executing account; the secret key stored in it is used to
+    /// sign the transaction.
+    #[arg(long)]
+    account_data: Option<PathBuf>,
+}
+
+impl TransactionCmdArgs {
+    /// Executes the transaction described by these arguments.
+    fn execute_transaction(&self) -> Result<ExecutedTransaction, String> {
+        let tx_inputs: TransactionInputs = read_object(&self.inputs)?;
+        let tx_script = self
+            .script
+            .as_ref()
+            .map(|path| {
+                let source = fs::read_to_string(path)
+                    .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+                ProgramAst::parse(&source)
+                    .map_err(|err| format!("invalid transaction script {}: {err}", path.display()))
+            })
+            .transpose()?;
+        let expected_notes = self
+            .expected_notes
+            .iter()
+            .map(|path| read_object::<Note>(path))
+            .collect::<Result<Vec<_>, _>>()?;
+        let authenticator = self
+            .account_data
+            .as_ref()
+            .map(|path| {
+                let account_data: AccountData = read_object(path)?;
+                let pub_key: Word = match &account_data.auth_secret_key {
+                    AuthSecretKey::RpoFalcon512(secret_key) => secret_key.public_key().into(),
+                };
+                Ok::<_, String>(Rc::new(BasicAuthenticator::<StdRng>::new(&[(
+                    pub_key,
+                    account_data.auth_secret_key,
+                )])))
+            })
+            .transpose()?;
+
+        let account_id = tx_inputs.account().id();
+        let block_ref = tx_inputs.block_header().block_num();
+        let note_ids = tx_inputs.input_notes().iter().map(|note| note.id()).collect::<Vec<_>>();
+
+        let mut executor = TransactionExecutor::new(InputsDataStore(tx_inputs), authenticator);
+        executor
+            .load_account(account_id)
+            .map_err(|err| format!("failed to load account {account_id}: {err:?}"))?;
+
+        let mut tx_args = match tx_script {
+            Some(tx_script) => {
+                let target = vec![ScriptTarget::AccountId(account_id)];
+                let tx_script = executor
+                    .compile_tx_script(tx_script, vec![], target)
+                    .map_err(|err| format!("failed to compile transaction script: {err:?}"))?;
+                TransactionArgs::with_tx_script(tx_script)
+            },
+            None => TransactionArgs::default(),
+        };
+        tx_args.extend_expected_output_notes(expected_notes);
+
+        executor
+            .execute_transaction(account_id, block_ref, &note_ids, tx_args)
+            .map_err(|err| format!("failed to execute transaction: {err:?}"))
+    }
+}
+
+// EXECUTE
+// ================================================================================================
+
+#[derive(Parser, Debug)]
+pub struct ExecuteCmd {
+    #[command(flatten)]
+    args: TransactionCmdArgs,
+
+    /// File to which the account delta produced by the transaction is written.
+    #[arg(long)]
+    delta_output: Option<PathBuf>,
+
+    /// File to which the output notes produced by the transaction are written.
+    #[arg(long)]
+    notes_output: Option<PathBuf>,
+}
+
+impl ExecuteCmd {
+    pub fn execute(self) -> Result<(), String> {
+        let executed_transaction = self.args.execute_transaction()?;
+
+        println!("transaction: {}", executed_transaction.id());
+        println!("account: {}", executed_transaction.account_id());
+        println!("initial account hash: {}", executed_transaction.initial_account().hash());
+        println!("final account hash: {}", executed_transaction.final_account().hash());
+        println!("input notes: {}", executed_transaction.input_notes().num_notes());
+        for note in executed_transaction.output_notes().iter() {
+            println!("output note: {}", note.id());
+        }
+        println!("account delta:");
+        print!(
+            "{}",
+            executed_transaction
+                .account_delta()
+                .to_table(executed_transaction.initial_account())
+        );
+
+        if let Some(path) = &self.delta_output {
+            write_object(path, executed_transaction.account_delta())?;
+        }
+        if let Some(path) = &self.notes_output {
+            write_object(path, executed_transaction.output_notes())?;
+        }
+
+        Ok(())
+    }
+}
+
+// PROVE
+// ================================================================================================
+
+#[derive(Parser, Debug)]
+pub struct ProveCmd {
+    #[command(flatten)]
+    args: TransactionCmdArgs,
+
+    /// File to which the proven transaction is written.
+    #[arg(short, long)]
+    output: PathBuf,
+}
+
+impl ProveCmd {
+    pub fn execute(self) -> Result<(), String> {
+        let executed_transaction = self.args.execute_transaction()?;
+
+        let prover = TransactionProver::new(ProvingOptions::default());
+        let proven_transaction = prover
+            .prove_transaction(executed_transaction)
+            .map_err(|err| format!("failed to prove transaction: {err:?}"))?;
+        write_object(&self.output, &proven_transaction)?;
+
+        println!("proved transaction {} in {}", proven_transaction.id(), self.output.display());
+        Ok(())
+    }
+}
+
+// VERIFY
+// ================================================================================================
+
+#[derive(Parser, Debug)]
+pub struct VerifyCmd {
+    /// File containing the proven transaction.
+    path: PathBuf,
+
+    /// Minimum security level, in bits, required of the proof.
+    #[arg(long, default_value_t = MIN_PROOF_SECURITY_LEVEL)]
+    security_level: u32,
+}
+
+impl VerifyCmd {
+    pub fn execute(self) -> Result<(), String> {
+        let proven_transaction: ProvenTransaction = read_object(&self.path)?;
+
+        TransactionVerifier::new(self.security_level)
+            .verify(proven_transaction.clone())
+            .map_err(|err| format!("failed to verify transaction: {err:?}"))?;
+
+        println!("transaction {} is valid", proven_transaction.id());
+        Ok(())
+    }
+}
+
+// DATA STORE
+// ================================================================================================
+
+/// A [DataStore] serving a single set of transaction inputs.
+struct InputsDataStore(TransactionInputs);
+
+impl DataStore for InputsDataStore {
+    fn get_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(Account, Option<Word>), DataStoreError> {
+        if account_id != self.0.account().id() {
+            return Err(DataStoreError::AccountNotFound(account_id));
+        }
+
+        Ok((self.0.account().clone(), self.0.account_seed()))
+    }
+
+    fn get_block_header(&self, block_num: u32) -> Result<BlockHeader, DataStoreError> {
+        if block_num != self.0.block_header().block_num() {
+            return Err(DataStoreError::BlockNotFound(block_num));
+        }
+
+        Ok(*self.0.block_header())
+    }
+
+    fn get_chain_mmr(&self, block_ref: u32, _blocks: &[u32]) -> Result<ChainMmr, DataStoreError> {
+        if block_ref != self.0.block_header().block_num() {
+            return Err(DataStoreError::BlockNotFound(block_ref));
+        }
+
+        Ok(self.0.block_chain().clone())
+    }
+
+    fn get_notes(&self, note_ids: &[NoteId]) -> Result<Vec<InputNote>, DataStoreError> {
+        note_ids
+            .iter()
+            .map(|&note_id| {
+                self.0
+                    .input_notes()
+                    .iter()
+                    .find(|note| note.id() == note_id)
+                    .cloned()
+                    .ok_or(DataStoreError::NoteNotFound(note_id))
+            })
+            .collect()
+    }
+
+    fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError> {
+        if account_id != self.0.account().id() {
+            return Err(DataStoreError::AccountNotFound(account_id));
+        }
+
+        Ok(self.0.account().code().module().clone())
+    }
+}
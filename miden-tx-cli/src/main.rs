@@ -0,0 +1,66 @@
+//! Command-line tool for offline transaction workflows.
+//!
+//! All objects are read from and written to files using the binary encoding of `miden-objects`:
+//! accounts are stored as [AccountData](miden_objects::accounts::AccountData), notes as
+//! [Note](miden_objects::notes::Note), transaction inputs as
+//! [TransactionInputs](miden_objects::transaction::TransactionInputs), and proven transactions as
+//! [ProvenTransaction](miden_objects::transaction::ProvenTransaction).
+
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+mod commands;
+use commands::{AccountCommand, ExecuteCmd, NoteCommand, ProveCmd, VerifyCmd};
+
+mod utils;
+
+// CLI
+// ================================================================================================
+
+#[derive(Parser, Debug)]
+#[command(name = "miden-tx-cli", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Create and inspect accounts.
+    #[command(subcommand)]
+    Account(AccountCommand),
+
+    /// Create and inspect notes.
+    #[command(subcommand)]
+    Note(NoteCommand),
+
+    /// Execute a transaction and report its effects.
+    Execute(ExecuteCmd),
+
+    /// Execute and prove a transaction.
+    Prove(ProveCmd),
+
+    /// Verify the proof of a proven transaction.
+    Verify(VerifyCmd),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Account(cmd) => cmd.execute(),
+        Command::Note(cmd) => cmd.execute(),
+        Command::Execute(cmd) => cmd.execute(),
+        Command::Prove(cmd) => cmd.execute(),
+        Command::Verify(cmd) => cmd.execute(),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        },
+    }
+}
@@ -0,0 +1,95 @@
+use std::{fs, path::Path};
+
+use miden_objects::{
+    accounts::AccountId,
+    assets::{Asset, FungibleAsset},
+    utils::serde::{Deserializable, Serializable},
+    Felt, Word,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// FILES
+// ================================================================================================
+
+/// Reads and deserializes an object from the specified file.
+pub fn read_object<T: Deserializable>(path: &Path) -> Result<T, String> {
+    let bytes =
+        fs::read(path).map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    T::read_from_bytes(&bytes)
+        .map_err(|err| format!("failed to deserialize {}: {err}", path.display()))
+}
+
+/// Serializes and writes an object to the specified file.
+pub fn write_object<T: Serializable>(path: &Path, object: &T) -> Result<(), String> {
+    fs::write(path, object.to_bytes())
+        .map_err(|err| format!("failed to write {}: {err}", path.display()))
+}
+
+// PARSING
+// ================================================================================================
+
+/// Parses an account ID, either hex-encoded or in its labeled form (e.g.,
+/// `fungible-faucet/onchain/0x...`).
+pub fn parse_account_id(value: &str) -> Result<AccountId, String> {
+    value.parse().map_err(|err| format!("invalid account ID {value}: {err}"))
+}
+
+/// Parses a fungible asset specified as `<faucet ID>:<amount>`.
+pub fn parse_fungible_asset(value: &str) -> Result<Asset, String> {
+    let (faucet_id, amount) = value
+        .split_once(':')
+        .ok_or_else(|| format!("invalid asset {value}: expected <faucet ID>:<amount>"))?;
+    let faucet_id = parse_account_id(faucet_id)?;
+    let amount = amount.parse().map_err(|err| format!("invalid asset amount {amount}: {err}"))?;
+
+    FungibleAsset::new(faucet_id, amount)
+        .map(Asset::from)
+        .map_err(|err| format!("invalid asset {value}: {err}"))
+}
+
+// RANDOMNESS
+// ================================================================================================
+
+/// Returns an RNG seeded with the provided value, or from the OS entropy source if no seed was
+/// provided.
+pub fn rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// Returns a word of random field elements.
+pub fn random_word<R: Rng>(rng: &mut R) -> Word {
+    [
+        Felt::new(rng.gen()),
+        Felt::new(rng.gen()),
+        Felt::new(rng.gen()),
+        Felt::new(rng.gen()),
+    ]
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::accounts::account_id::testing::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN;
+
+    use super::*;
+
+    #[test]
+    fn fungible_asset_parsing() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset = parse_fungible_asset(&format!("{}:100", faucet_id.to_hex())).unwrap();
+        assert_eq!(asset, FungibleAsset::new(faucet_id, 100).unwrap().into());
+
+        assert!(parse_fungible_asset(&faucet_id.to_hex()).is_err());
+        assert!(parse_fungible_asset(&format!("{}:abc", faucet_id.to_hex())).is_err());
+        assert!(parse_fungible_asset("0x1234:100").is_err());
+
+        let asset =
+            parse_fungible_asset(&format!("{}:100", faucet_id.to_labeled_string())).unwrap();
+        assert_eq!(asset, FungibleAsset::new(faucet_id, 100).unwrap().into());
+    }
+}
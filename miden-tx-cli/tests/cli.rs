@@ -0,0 +1,112 @@
+use std::{path::Path, process::Command};
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    accounts::{account_id::testing::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, AccountData, AccountId},
+    notes::Note,
+    transaction::OutputNote,
+    utils::serde::{Deserializable, Serializable},
+};
+use mock::mock::{
+    account::{mock_account_code, MockAccountType},
+    notes::{mock_notes, AssetPreservationStatus},
+    transaction::mock_inputs,
+};
+use tempfile::tempdir;
+
+// HELPERS
+// ================================================================================================
+
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_miden-tx-cli"))
+        .args(args)
+        .output()
+        .expect("failed to run miden-tx-cli");
+    assert!(
+        output.status.success(),
+        "miden-tx-cli {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn path_str(path: &Path) -> &str {
+    path.to_str().unwrap()
+}
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn account_and_note_creation() {
+    let dir = tempdir().unwrap();
+    let account_path = dir.path().join("account.mac");
+    let note_path = dir.path().join("note.mno");
+
+    run(&["account", "new", "--seed", "1", "--output", path_str(&account_path)]);
+    let account_data = AccountData::read(&account_path).unwrap();
+    assert!(account_data.account.is_new());
+    assert!(account_data.account_seed.is_some());
+
+    let output = run(&["account", "show", path_str(&account_path)]);
+    assert!(output.contains(&account_data.account.id().to_string()));
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset = format!("{}:100", faucet_id.to_hex());
+    let sender = account_data.account.id().to_hex();
+    run(&[
+        "note",
+        "create-p2id",
+        "--sender",
+        &sender,
+        "--target",
+        &sender,
+        "--asset",
+        &asset,
+        "--seed",
+        "2",
+        "--output",
+        path_str(&note_path),
+    ]);
+    let note = Note::read_from_bytes(&std::fs::read(&note_path).unwrap()).unwrap();
+    assert_eq!(note.metadata().sender(), account_data.account.id());
+    assert_eq!(note.assets().num_assets(), 1);
+
+    let output = run(&["note", "show", path_str(&note_path)]);
+    assert!(output.contains(&note.id().to_string()));
+}
+
+#[test]
+fn transaction_execution() {
+    let dir = tempdir().unwrap();
+    let inputs_path = dir.path().join("inputs.bin");
+    let delta_path = dir.path().join("delta.bin");
+
+    let (tx_inputs, _) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    std::fs::write(&inputs_path, tx_inputs.to_bytes()).unwrap();
+
+    let assembler = TransactionKernel::assembler();
+    mock_account_code(&assembler);
+    let (_, output_notes) = mock_notes(&assembler, &AssetPreservationStatus::Preserved);
+
+    let mut args = vec![
+        "execute".to_string(),
+        "--inputs".to_string(),
+        path_str(&inputs_path).to_string(),
+        "--delta-output".to_string(),
+        path_str(&delta_path).to_string(),
+    ];
+    for (i, note) in output_notes.iter().enumerate() {
+        if let OutputNote::Full(note) = note {
+            let note_path = dir.path().join(format!("note_{i}.mno"));
+            std::fs::write(&note_path, note.to_bytes()).unwrap();
+            args.push("--expected-note".to_string());
+            args.push(path_str(&note_path).to_string());
+        }
+    }
+
+    let output = run(&args.iter().map(String::as_str).collect::<Vec<_>>());
+    assert!(output.contains(&format!("account: {}", tx_inputs.account().id())));
+    assert!(delta_path.exists());
+}
@@ -0,0 +1,102 @@
+/*
+ * C bindings for executing, proving, and verifying Miden rollup transactions.
+ *
+ * Objects are passed across the boundary as opaque handles which must be released via the
+ * matching `*_free` function. Inputs are byte buffers holding objects serialized with the binary
+ * encoding of `miden-objects`; outputs are returned in `MidenBytes` buffers which must be
+ * released via `miden_bytes_free`. On failure, a description of the error can be retrieved via
+ * `miden_last_error_message`.
+ */
+
+#ifndef MIDEN_TX_FFI_H
+#define MIDEN_TX_FFI_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Minimum security level of transaction proofs accepted by the Miden network. */
+#define MIDEN_MIN_PROOF_SECURITY_LEVEL 96
+
+typedef enum MidenStatus {
+    MIDEN_STATUS_OK = 0,
+    MIDEN_STATUS_NULL_POINTER = 1,
+    MIDEN_STATUS_INVALID_INPUT = 2,
+    MIDEN_STATUS_EXECUTION_FAILED = 3,
+    MIDEN_STATUS_PROVING_FAILED = 4,
+    MIDEN_STATUS_VERIFICATION_FAILED = 5,
+    MIDEN_STATUS_PANIC = 6,
+} MidenStatus;
+
+typedef struct MidenBytes {
+    uint8_t *data;
+    size_t len;
+} MidenBytes;
+
+typedef struct MidenAccount MidenAccount;
+typedef struct MidenNote MidenNote;
+typedef struct MidenExecutedTransaction MidenExecutedTransaction;
+typedef struct MidenProvenTransaction MidenProvenTransaction;
+
+/* BYTES AND ERRORS */
+
+void miden_bytes_free(MidenBytes bytes);
+MidenBytes miden_last_error_message(void);
+
+/* ACCOUNTS */
+
+MidenStatus miden_account_from_bytes(const uint8_t *data, size_t len, MidenAccount **out);
+MidenStatus miden_account_to_bytes(const MidenAccount *account, MidenBytes *out);
+MidenStatus miden_account_id(const MidenAccount *account, uint64_t *out);
+MidenStatus miden_account_nonce(const MidenAccount *account, uint64_t *out);
+MidenStatus miden_account_hash(const MidenAccount *account, uint8_t (*out)[32]);
+void miden_account_free(MidenAccount *account);
+
+/* NOTES */
+
+MidenStatus miden_note_new(
+    const uint8_t *assets, size_t assets_len,
+    const uint8_t *metadata, size_t metadata_len,
+    const uint8_t *recipient, size_t recipient_len,
+    MidenNote **out);
+MidenStatus miden_note_from_bytes(const uint8_t *data, size_t len, MidenNote **out);
+MidenStatus miden_note_to_bytes(const MidenNote *note, MidenBytes *out);
+MidenStatus miden_note_id(const MidenNote *note, uint8_t (*out)[32]);
+MidenStatus miden_note_nullifier(const MidenNote *note, uint8_t (*out)[32]);
+void miden_note_free(MidenNote *note);
+
+/* TRANSACTIONS */
+
+/*
+ * `tx_script`, `expected_output_notes`, and `auth_secret_key` are optional and may be NULL.
+ */
+MidenStatus miden_execute_transaction(
+    const uint8_t *tx_inputs, size_t tx_inputs_len,
+    const uint8_t *tx_script, size_t tx_script_len,
+    const MidenNote *const *expected_output_notes, size_t num_expected_output_notes,
+    const uint8_t *auth_secret_key, size_t auth_secret_key_len,
+    MidenExecutedTransaction **out);
+MidenStatus miden_executed_transaction_id(const MidenExecutedTransaction *tx, uint8_t (*out)[32]);
+MidenStatus miden_executed_transaction_account_delta(
+    const MidenExecutedTransaction *tx, MidenBytes *out);
+MidenStatus miden_executed_transaction_output_notes(
+    const MidenExecutedTransaction *tx, MidenBytes *out);
+void miden_executed_transaction_free(MidenExecutedTransaction *tx);
+
+MidenStatus miden_prove_transaction(
+    const MidenExecutedTransaction *tx, MidenProvenTransaction **out);
+MidenStatus miden_verify_transaction(const MidenProvenTransaction *tx, uint32_t security_level);
+MidenStatus miden_proven_transaction_from_bytes(
+    const uint8_t *data, size_t len, MidenProvenTransaction **out);
+MidenStatus miden_proven_transaction_to_bytes(const MidenProvenTransaction *tx, MidenBytes *out);
+MidenStatus miden_proven_transaction_id(const MidenProvenTransaction *tx, uint8_t (*out)[32]);
+void miden_proven_transaction_free(MidenProvenTransaction *tx);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* MIDEN_TX_FFI_H */
@@ -0,0 +1,89 @@
+use miden_objects::{
+    accounts::Account,
+    utils::serde::{Deserializable, Serializable},
+};
+
+use crate::{
+    bytes, ffi_call, free_handle, handle, write_handle, write_out, FfiError, MidenBytes,
+    MidenStatus,
+};
+
+// ACCOUNT
+// ================================================================================================
+
+/// Opaque handle to an [Account].
+pub struct MidenAccount(pub(crate) Account);
+
+/// Deserializes an account from the provided bytes and writes a handle to it into `out`.
+///
+/// # Safety
+/// `data` must point to `len` readable bytes, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_account_from_bytes(
+    data: *const u8,
+    len: usize,
+    out: *mut *mut MidenAccount,
+) -> MidenStatus {
+    ffi_call(|| {
+        let account = Account::read_from_bytes(bytes(data, len)?)
+            .map_err(|err| FfiError::invalid_input(format!("invalid account: {err}")))?;
+        write_handle(out, MidenAccount(account))
+    })
+}
+
+/// Serializes the account behind the provided handle into `out`.
+///
+/// # Safety
+/// `account` must be a live account handle, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_account_to_bytes(
+    account: *const MidenAccount,
+    out: *mut MidenBytes,
+) -> MidenStatus {
+    ffi_call(|| write_out(out, handle(account)?.0.to_bytes().into()))
+}
+
+/// Writes the ID of the account behind the provided handle into `out`.
+///
+/// # Safety
+/// `account` must be a live account handle, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_account_id(
+    account: *const MidenAccount,
+    out: *mut u64,
+) -> MidenStatus {
+    ffi_call(|| write_out(out, handle(account)?.0.id().into()))
+}
+
+/// Writes the nonce of the account behind the provided handle into `out`.
+///
+/// # Safety
+/// `account` must be a live account handle, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_account_nonce(
+    account: *const MidenAccount,
+    out: *mut u64,
+) -> MidenStatus {
+    ffi_call(|| write_out(out, handle(account)?.0.nonce().as_int()))
+}
+
+/// Writes the hash of the account behind the provided handle into `out`.
+///
+/// # Safety
+/// `account` must be a live account handle, and `out` must be valid for writes of 32 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_account_hash(
+    account: *const MidenAccount,
+    out: *mut [u8; 32],
+) -> MidenStatus {
+    ffi_call(|| write_out(out, handle(account)?.0.hash().as_bytes()))
+}
+
+/// Releases the account behind the provided handle.
+///
+/// # Safety
+/// `account` must be null or a live account handle; the handle must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn miden_account_free(account: *mut MidenAccount) {
+    free_handle(account)
+}
@@ -0,0 +1,201 @@
+//! C bindings for executing, proving, and verifying Miden rollup transactions.
+//!
+//! The bindings expose a stable C ABI intended to be consumed from mobile wallets (e.g., via Swift
+//! or Kotlin) and other non-Rust hosts. The API follows a few simple conventions:
+//!
+//! - Objects (accounts, notes, executed and proven transactions) are passed across the boundary as
+//!   opaque handles. Handles are created by the library and must be released via the matching
+//!   `*_free` function.
+//! - Inputs are passed as byte buffers holding objects serialized with the binary encoding of
+//!   `miden-objects`, and outputs are returned in [MidenBytes] buffers owned by the library, which
+//!   must be released via [miden_bytes_free()].
+//! - Every fallible function returns a [MidenStatus]. On failure, a description of the error can
+//!   be retrieved via [miden_last_error_message()].
+//!
+//! The C declarations of the API are provided in `include/miden_tx_ffi.h`.
+
+use std::{cell::RefCell, panic, ptr, slice};
+
+mod account;
+pub use account::*;
+
+mod note;
+pub use note::*;
+
+mod transaction;
+pub use transaction::*;
+
+#[cfg(test)]
+mod tests;
+
+// STATUS
+// ================================================================================================
+
+/// Result of a call into the library.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MidenStatus {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidInput = 2,
+    ExecutionFailed = 3,
+    ProvingFailed = 4,
+    VerificationFailed = 5,
+    Panic = 6,
+}
+
+// BYTES
+// ================================================================================================
+
+/// A byte buffer allocated by the library.
+#[repr(C)]
+#[derive(Debug)]
+pub struct MidenBytes {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl MidenBytes {
+    /// Returns an empty buffer which does not need to be released.
+    fn empty() -> Self {
+        Self { data: ptr::null_mut(), len: 0 }
+    }
+}
+
+impl From<Vec<u8>> for MidenBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        let len = bytes.len();
+        let data = Box::into_raw(bytes.into_boxed_slice()) as *mut u8;
+        Self { data, len }
+    }
+}
+
+/// Releases a buffer returned by the library.
+///
+/// # Safety
+/// `bytes` must have been returned by the library and must not have been released before.
+#[no_mangle]
+pub unsafe extern "C" fn miden_bytes_free(bytes: MidenBytes) {
+    if !bytes.data.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(bytes.data, bytes.len)));
+    }
+}
+
+// ERRORS
+// ================================================================================================
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Returns a UTF-8 description of the last error which occurred on the calling thread, or an
+/// empty buffer if the last call succeeded.
+///
+/// The returned buffer must be released via [miden_bytes_free()].
+#[no_mangle]
+pub extern "C" fn miden_last_error_message() -> MidenBytes {
+    LAST_ERROR.with(|error| match error.borrow().as_ref() {
+        Some(message) => message.as_bytes().to_vec().into(),
+        None => MidenBytes::empty(),
+    })
+}
+
+/// An error raised by one of the exported functions.
+#[derive(Debug)]
+struct FfiError {
+    status: MidenStatus,
+    message: String,
+}
+
+impl FfiError {
+    fn new(status: MidenStatus, message: impl ToString) -> Self {
+        Self { status, message: message.to_string() }
+    }
+
+    fn invalid_input(message: impl ToString) -> Self {
+        Self::new(MidenStatus::InvalidInput, message)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Runs the provided closure, recording its error (if any) as the last error of the calling
+/// thread, and converting panics into [MidenStatus::Panic] so that they do not unwind across the
+/// FFI boundary.
+fn ffi_call<F: FnOnce() -> Result<(), FfiError>>(f: F) -> MidenStatus {
+    let error = match panic::catch_unwind(panic::AssertUnwindSafe(f)) {
+        Ok(Ok(())) => None,
+        Ok(Err(error)) => Some(error),
+        Err(_) => Some(FfiError::new(MidenStatus::Panic, "the library panicked")),
+    };
+
+    let status = error.as_ref().map_or(MidenStatus::Ok, |error| error.status);
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = error.map(|error| error.message));
+    status
+}
+
+/// Returns the slice described by the provided pointer and length.
+///
+/// # Safety
+/// Unless `data` is null, it must point to `len` readable bytes which remain valid for the
+/// lifetime of the returned slice.
+unsafe fn bytes<'a>(data: *const u8, len: usize) -> Result<&'a [u8], FfiError> {
+    if data.is_null() {
+        return Err(FfiError::new(MidenStatus::NullPointer, "input buffer is null"));
+    }
+    Ok(slice::from_raw_parts(data, len))
+}
+
+/// Returns the slice described by the provided pointer and length, or None if the pointer is null.
+///
+/// # Safety
+/// Same as for [bytes()].
+unsafe fn optional_bytes<'a>(data: *const u8, len: usize) -> Option<&'a [u8]> {
+    (!data.is_null()).then(|| slice::from_raw_parts(data, len))
+}
+
+/// Returns a reference to the object behind the provided handle.
+///
+/// # Safety
+/// Unless `handle` is null, it must point to a live object created by the library.
+unsafe fn handle<'a, T>(handle: *const T) -> Result<&'a T, FfiError> {
+    handle
+        .as_ref()
+        .ok_or_else(|| FfiError::new(MidenStatus::NullPointer, "handle is null"))
+}
+
+/// Writes the provided value into the output pointer.
+///
+/// # Safety
+/// Unless `out` is null, it must be valid for writes.
+unsafe fn write_out<T>(out: *mut T, value: T) -> Result<(), FfiError> {
+    if out.is_null() {
+        return Err(FfiError::new(MidenStatus::NullPointer, "output pointer is null"));
+    }
+    out.write(value);
+    Ok(())
+}
+
+/// Moves the provided object to the heap and writes the resulting handle into the output pointer.
+///
+/// # Safety
+/// Unless `out` is null, it must be valid for writes.
+unsafe fn write_handle<T>(out: *mut *mut T, value: T) -> Result<(), FfiError> {
+    if out.is_null() {
+        return Err(FfiError::new(MidenStatus::NullPointer, "output pointer is null"));
+    }
+    out.write(Box::into_raw(Box::new(value)));
+    Ok(())
+}
+
+/// Releases the object behind the provided handle.
+///
+/// # Safety
+/// Unless `handle` is null, it must have been created by the library and must not have been
+/// released before.
+unsafe fn free_handle<T>(handle: *mut T) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
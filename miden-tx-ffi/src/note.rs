@@ -0,0 +1,102 @@
+use miden_objects::{
+    notes::{Note, NoteAssets, NoteMetadata, NoteRecipient},
+    utils::serde::{Deserializable, Serializable},
+};
+
+use crate::{
+    bytes, ffi_call, free_handle, handle, write_handle, write_out, FfiError, MidenBytes,
+    MidenStatus,
+};
+
+// NOTE
+// ================================================================================================
+
+/// Opaque handle to a [Note].
+pub struct MidenNote(pub(crate) Note);
+
+/// Builds a note from its serialized assets, metadata, and recipient, and writes a handle to it
+/// into `out`.
+///
+/// # Safety
+/// Each of `assets`, `metadata`, and `recipient` must point to the specified number of readable
+/// bytes, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_note_new(
+    assets: *const u8,
+    assets_len: usize,
+    metadata: *const u8,
+    metadata_len: usize,
+    recipient: *const u8,
+    recipient_len: usize,
+    out: *mut *mut MidenNote,
+) -> MidenStatus {
+    ffi_call(|| {
+        let assets = NoteAssets::read_from_bytes(bytes(assets, assets_len)?)
+            .map_err(|err| FfiError::invalid_input(format!("invalid note assets: {err}")))?;
+        let metadata = NoteMetadata::read_from_bytes(bytes(metadata, metadata_len)?)
+            .map_err(|err| FfiError::invalid_input(format!("invalid note metadata: {err}")))?;
+        let recipient = NoteRecipient::read_from_bytes(bytes(recipient, recipient_len)?)
+            .map_err(|err| FfiError::invalid_input(format!("invalid note recipient: {err}")))?;
+
+        write_handle(out, MidenNote(Note::new(assets, metadata, recipient)))
+    })
+}
+
+/// Deserializes a note from the provided bytes and writes a handle to it into `out`.
+///
+/// # Safety
+/// `data` must point to `len` readable bytes, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_note_from_bytes(
+    data: *const u8,
+    len: usize,
+    out: *mut *mut MidenNote,
+) -> MidenStatus {
+    ffi_call(|| {
+        let note = Note::read_from_bytes(bytes(data, len)?)
+            .map_err(|err| FfiError::invalid_input(format!("invalid note: {err}")))?;
+        write_handle(out, MidenNote(note))
+    })
+}
+
+/// Serializes the note behind the provided handle into `out`.
+///
+/// # Safety
+/// `note` must be a live note handle, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_note_to_bytes(
+    note: *const MidenNote,
+    out: *mut MidenBytes,
+) -> MidenStatus {
+    ffi_call(|| write_out(out, handle(note)?.0.to_bytes().into()))
+}
+
+/// Writes the ID of the note behind the provided handle into `out`.
+///
+/// # Safety
+/// `note` must be a live note handle, and `out` must be valid for writes of 32 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_note_id(note: *const MidenNote, out: *mut [u8; 32]) -> MidenStatus {
+    ffi_call(|| write_out(out, handle(note)?.0.id().inner().as_bytes()))
+}
+
+/// Writes the nullifier of the note behind the provided handle into `out`.
+///
+/// # Safety
+/// `note` must be a live note handle, and `out` must be valid for writes of 32 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_note_nullifier(
+    note: *const MidenNote,
+    out: *mut [u8; 32],
+) -> MidenStatus {
+    ffi_call(|| write_out(out, handle(note)?.0.nullifier().inner().as_bytes()))
+}
+
+/// Releases the note behind the provided handle.
+///
+/// # Safety
+/// `note` must be null or a live note handle; the handle must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn miden_note_free(note: *mut MidenNote) {
+    free_handle(note)
+}
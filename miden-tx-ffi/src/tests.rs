@@ -0,0 +1,237 @@
+use std::{ptr, slice};
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    accounts::{Account, AccountDelta},
+    notes::Note,
+    transaction::{InputNote, OutputNote, OutputNotes, TransactionInputs},
+    utils::serde::{Deserializable, Serializable},
+    MIN_PROOF_SECURITY_LEVEL,
+};
+use mock::mock::{
+    account::{mock_account_code, MockAccountType},
+    notes::{mock_notes, AssetPreservationStatus},
+    transaction::mock_inputs,
+};
+
+use super::*;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn account_round_trip() {
+    let account = mock_tx_inputs().account().clone();
+    let bytes = account.to_bytes();
+
+    let mut handle = ptr::null_mut();
+    let status = unsafe { miden_account_from_bytes(bytes.as_ptr(), bytes.len(), &mut handle) };
+    assert_eq!(status, MidenStatus::Ok);
+
+    let mut account_id = 0;
+    assert_eq!(unsafe { miden_account_id(handle, &mut account_id) }, MidenStatus::Ok);
+    assert_eq!(account_id, u64::from(account.id()));
+
+    let mut account_hash = [0; 32];
+    assert_eq!(unsafe { miden_account_hash(handle, &mut account_hash) }, MidenStatus::Ok);
+    assert_eq!(account_hash, account.hash().as_bytes());
+
+    let mut out = MidenBytes::empty();
+    assert_eq!(unsafe { miden_account_to_bytes(handle, &mut out) }, MidenStatus::Ok);
+    let deserialized = Account::read_from_bytes(&unsafe { take_bytes(out) }).unwrap();
+    assert_eq!(deserialized.hash(), account.hash());
+
+    unsafe { miden_account_free(handle) };
+}
+
+#[test]
+fn note_from_parts() {
+    let note = mock_note();
+    let assets = note.assets().to_bytes();
+    let metadata = note.metadata().to_bytes();
+    let recipient = note.recipient().to_bytes();
+
+    let mut handle = ptr::null_mut();
+    let status = unsafe {
+        miden_note_new(
+            assets.as_ptr(),
+            assets.len(),
+            metadata.as_ptr(),
+            metadata.len(),
+            recipient.as_ptr(),
+            recipient.len(),
+            &mut handle,
+        )
+    };
+    assert_eq!(status, MidenStatus::Ok);
+
+    let mut note_id = [0; 32];
+    assert_eq!(unsafe { miden_note_id(handle, &mut note_id) }, MidenStatus::Ok);
+    assert_eq!(note_id, note.id().inner().as_bytes());
+
+    let mut nullifier = [0; 32];
+    assert_eq!(unsafe { miden_note_nullifier(handle, &mut nullifier) }, MidenStatus::Ok);
+    assert_eq!(nullifier, note.nullifier().inner().as_bytes());
+
+    let mut out = MidenBytes::empty();
+    assert_eq!(unsafe { miden_note_to_bytes(handle, &mut out) }, MidenStatus::Ok);
+    assert_eq!(Note::read_from_bytes(unsafe { take_bytes(out) }.as_slice()).unwrap(), note);
+
+    unsafe { miden_note_free(handle) };
+}
+
+#[test]
+fn invalid_inputs_are_reported() {
+    let mut handle = ptr::null_mut();
+
+    let status = unsafe { miden_note_from_bytes(ptr::null(), 0, &mut handle) };
+    assert_eq!(status, MidenStatus::NullPointer);
+    assert!(handle.is_null());
+
+    let bytes = [1, 2, 3];
+    let status = unsafe { miden_note_from_bytes(bytes.as_ptr(), bytes.len(), &mut handle) };
+    assert_eq!(status, MidenStatus::InvalidInput);
+    assert!(handle.is_null());
+
+    let message = String::from_utf8(unsafe { take_bytes(miden_last_error_message()) }).unwrap();
+    assert!(message.starts_with("invalid note"));
+
+    // a successful call clears the last error
+    let note = mock_note().to_bytes();
+    let status = unsafe { miden_note_from_bytes(note.as_ptr(), note.len(), &mut handle) };
+    assert_eq!(status, MidenStatus::Ok);
+    assert!(miden_last_error_message().data.is_null());
+
+    unsafe { miden_note_free(handle) };
+}
+
+#[test]
+fn execute_transaction() {
+    let executed_transaction = execute(&mock_tx_inputs());
+
+    let mut out = MidenBytes::empty();
+    let status =
+        unsafe { miden_executed_transaction_account_delta(executed_transaction, &mut out) };
+    assert_eq!(status, MidenStatus::Ok);
+    assert!(AccountDelta::read_from_bytes(&unsafe { take_bytes(out) }).is_ok());
+
+    let mut out = MidenBytes::empty();
+    let status = unsafe { miden_executed_transaction_output_notes(executed_transaction, &mut out) };
+    assert_eq!(status, MidenStatus::Ok);
+    let output_notes = OutputNotes::read_from_bytes(&unsafe { take_bytes(out) }).unwrap();
+    assert_eq!(output_notes.num_notes(), mock_expected_output_notes().len());
+
+    unsafe { miden_executed_transaction_free(executed_transaction) };
+}
+
+#[test]
+fn prove_and_verify_transaction() {
+    let executed_transaction = execute(&mock_tx_inputs());
+
+    let mut proven_transaction = ptr::null_mut();
+    let status = unsafe { miden_prove_transaction(executed_transaction, &mut proven_transaction) };
+    assert_eq!(status, MidenStatus::Ok);
+
+    let mut executed_id = [0; 32];
+    let mut proven_id = [0; 32];
+    unsafe {
+        miden_executed_transaction_id(executed_transaction, &mut executed_id);
+        miden_proven_transaction_id(proven_transaction, &mut proven_id);
+    }
+    assert_eq!(executed_id, proven_id);
+
+    // the proof survives a round trip through its serialized form
+    let mut out = MidenBytes::empty();
+    assert_eq!(
+        unsafe { miden_proven_transaction_to_bytes(proven_transaction, &mut out) },
+        MidenStatus::Ok
+    );
+    let bytes = unsafe { take_bytes(out) };
+    let mut deserialized = ptr::null_mut();
+    let status = unsafe {
+        miden_proven_transaction_from_bytes(bytes.as_ptr(), bytes.len(), &mut deserialized)
+    };
+    assert_eq!(status, MidenStatus::Ok);
+
+    let status = unsafe { miden_verify_transaction(deserialized, MIN_PROOF_SECURITY_LEVEL) };
+    assert_eq!(status, MidenStatus::Ok);
+
+    unsafe {
+        miden_executed_transaction_free(executed_transaction);
+        miden_proven_transaction_free(proven_transaction);
+        miden_proven_transaction_free(deserialized);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn mock_tx_inputs() -> TransactionInputs {
+    mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved).0
+}
+
+/// Returns the notes created by the transaction built from [mock_tx_inputs()].
+fn mock_expected_output_notes() -> Vec<Note> {
+    // note scripts call account procedures, and so the account code must be compiled first
+    let assembler = TransactionKernel::assembler();
+    mock_account_code(&assembler);
+
+    let (_, output_notes) = mock_notes(&assembler, &AssetPreservationStatus::Preserved);
+    output_notes
+        .into_iter()
+        .filter_map(|note| match note {
+            OutputNote::Full(note) => Some(note),
+            OutputNote::Partial(_) | OutputNote::Header(_) => None,
+        })
+        .collect()
+}
+
+fn mock_note() -> Note {
+    mock_tx_inputs()
+        .input_notes()
+        .iter()
+        .map(InputNote::note)
+        .next()
+        .unwrap()
+        .clone()
+}
+
+/// Executes a transaction against the provided inputs via the FFI and returns its handle.
+fn execute(tx_inputs: &TransactionInputs) -> *mut MidenExecutedTransaction {
+    let tx_inputs = tx_inputs.to_bytes();
+    let expected_output_notes =
+        mock_expected_output_notes().into_iter().map(MidenNote).collect::<Vec<_>>();
+    let expected_output_notes = expected_output_notes
+        .iter()
+        .map(|note| note as *const MidenNote)
+        .collect::<Vec<_>>();
+
+    let mut executed_transaction = ptr::null_mut();
+    let status = unsafe {
+        miden_execute_transaction(
+            tx_inputs.as_ptr(),
+            tx_inputs.len(),
+            ptr::null(),
+            0,
+            expected_output_notes.as_ptr(),
+            expected_output_notes.len(),
+            ptr::null(),
+            0,
+            &mut executed_transaction,
+        )
+    };
+    let message = unsafe { take_bytes(miden_last_error_message()) };
+    assert_eq!(status, MidenStatus::Ok, "{}", String::from_utf8_lossy(&message));
+
+    executed_transaction
+}
+
+/// Copies the contents of a buffer returned by the library and releases it.
+unsafe fn take_bytes(bytes: MidenBytes) -> Vec<u8> {
+    let result = match bytes.data.is_null() {
+        true => Vec::new(),
+        false => slice::from_raw_parts(bytes.data, bytes.len).to_vec(),
+    };
+    miden_bytes_free(bytes);
+    result
+}
@@ -0,0 +1,323 @@
+use std::{rc::Rc, slice};
+
+use miden_objects::{
+    accounts::{Account, AccountId, AuthSecretKey},
+    assembly::{ModuleAst, ProgramAst},
+    notes::NoteId,
+    transaction::{
+        ChainMmr, ExecutedTransaction, InputNote, ProvenTransaction, TransactionArgs,
+        TransactionInputs,
+    },
+    utils::serde::{Deserializable, Serializable},
+    BlockHeader, Word,
+};
+use miden_tx::{
+    BasicAuthenticator, DataStore, DataStoreError, ProvingOptions, ScriptTarget,
+    TransactionExecutor, TransactionProver, TransactionVerifier,
+};
+
+use crate::{
+    bytes, ffi_call, free_handle, handle, optional_bytes, write_handle, write_out, FfiError,
+    MidenBytes, MidenNote, MidenStatus,
+};
+
+// EXECUTED TRANSACTION
+// ================================================================================================
+
+/// Opaque handle to an [ExecutedTransaction].
+pub struct MidenExecutedTransaction(pub(crate) ExecutedTransaction);
+
+/// Executes a transaction against the provided inputs and writes a handle to the executed
+/// transaction into `out`.
+///
+/// - `tx_inputs` must hold serialized [TransactionInputs]; all input notes contained in the inputs
+///   are consumed by the transaction.
+/// - `tx_script`, if not null, must hold the UTF-8 source code of a transaction script, which is
+///   compiled against the interface of the executing account.
+/// - `expected_output_notes`, if not null, must point to an array of note handles holding the
+///   details of the notes expected to be created by the transaction. Details of all public output
+///   notes must be provided.
+/// - `auth_secret_key`, if not null, must hold a serialized [AuthSecretKey] used to sign the
+///   transaction.
+///
+/// # Safety
+/// Each non-null input buffer must point to the specified number of readable bytes, each non-null
+/// array must point to the specified number of live handles, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_execute_transaction(
+    tx_inputs: *const u8,
+    tx_inputs_len: usize,
+    tx_script: *const u8,
+    tx_script_len: usize,
+    expected_output_notes: *const *const MidenNote,
+    num_expected_output_notes: usize,
+    auth_secret_key: *const u8,
+    auth_secret_key_len: usize,
+    out: *mut *mut MidenExecutedTransaction,
+) -> MidenStatus {
+    ffi_call(|| {
+        let tx_inputs = TransactionInputs::read_from_bytes(bytes(tx_inputs, tx_inputs_len)?)
+            .map_err(|err| FfiError::invalid_input(format!("invalid transaction inputs: {err}")))?;
+        let tx_script = optional_bytes(tx_script, tx_script_len)
+            .map(|source| {
+                let source = std::str::from_utf8(source).map_err(|err| {
+                    FfiError::invalid_input(format!("transaction script is not UTF-8: {err}"))
+                })?;
+                ProgramAst::parse(source).map_err(|err| {
+                    FfiError::invalid_input(format!("invalid transaction script: {err}"))
+                })
+            })
+            .transpose()?;
+        let expected_output_notes = match expected_output_notes.is_null() {
+            true => &[],
+            false => slice::from_raw_parts(expected_output_notes, num_expected_output_notes),
+        };
+        let authenticator = optional_bytes(auth_secret_key, auth_secret_key_len)
+            .map(|key| {
+                let key = AuthSecretKey::read_from_bytes(key)
+                    .map_err(|err| FfiError::invalid_input(format!("invalid secret key: {err}")))?;
+                let pub_key: Word = match &key {
+                    AuthSecretKey::RpoFalcon512(secret_key) => secret_key.public_key().into(),
+                };
+                Ok::<_, FfiError>(Rc::new(BasicAuthenticator::new(&[(pub_key, key)])))
+            })
+            .transpose()?;
+
+        let account_id = tx_inputs.account().id();
+        let block_ref = tx_inputs.block_header().block_num();
+        let note_ids = tx_inputs.input_notes().iter().map(|note| note.id()).collect::<Vec<_>>();
+
+        let mut executor = TransactionExecutor::new(InputsDataStore(tx_inputs), authenticator);
+        executor.load_account(account_id).map_err(execution_failed)?;
+
+        let mut tx_args = match tx_script {
+            Some(tx_script) => {
+                let target = vec![ScriptTarget::AccountId(account_id)];
+                let tx_script = executor
+                    .compile_tx_script(tx_script, vec![], target)
+                    .map_err(execution_failed)?;
+                TransactionArgs::with_tx_script(tx_script)
+            },
+            None => TransactionArgs::default(),
+        };
+        for &note in expected_output_notes {
+            tx_args.add_expected_output_note(&handle(note)?.0);
+        }
+
+        let executed_transaction = executor
+            .execute_transaction(account_id, block_ref, &note_ids, tx_args)
+            .map_err(execution_failed)?;
+
+        write_handle(out, MidenExecutedTransaction(executed_transaction))
+    })
+}
+
+/// Writes the ID of the executed transaction behind the provided handle into `out`.
+///
+/// # Safety
+/// `tx` must be a live executed transaction handle, and `out` must be valid for writes of 32
+/// bytes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_executed_transaction_id(
+    tx: *const MidenExecutedTransaction,
+    out: *mut [u8; 32],
+) -> MidenStatus {
+    ffi_call(|| write_out(out, handle(tx)?.0.id().as_bytes()))
+}
+
+/// Serializes the account delta of the executed transaction behind the provided handle into
+/// `out`.
+///
+/// # Safety
+/// `tx` must be a live executed transaction handle, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_executed_transaction_account_delta(
+    tx: *const MidenExecutedTransaction,
+    out: *mut MidenBytes,
+) -> MidenStatus {
+    ffi_call(|| write_out(out, handle(tx)?.0.account_delta().to_bytes().into()))
+}
+
+/// Serializes the output notes of the executed transaction behind the provided handle into `out`.
+///
+/// # Safety
+/// `tx` must be a live executed transaction handle, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_executed_transaction_output_notes(
+    tx: *const MidenExecutedTransaction,
+    out: *mut MidenBytes,
+) -> MidenStatus {
+    ffi_call(|| write_out(out, handle(tx)?.0.output_notes().to_bytes().into()))
+}
+
+/// Releases the executed transaction behind the provided handle.
+///
+/// # Safety
+/// `tx` must be null or a live executed transaction handle; the handle must not be used
+/// afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn miden_executed_transaction_free(tx: *mut MidenExecutedTransaction) {
+    free_handle(tx)
+}
+
+// PROVEN TRANSACTION
+// ================================================================================================
+
+/// Opaque handle to a [ProvenTransaction].
+pub struct MidenProvenTransaction(pub(crate) ProvenTransaction);
+
+/// Proves the executed transaction behind the provided handle using the default proving options,
+/// and writes a handle to the proven transaction into `out`.
+///
+/// # Safety
+/// `tx` must be a live executed transaction handle, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_prove_transaction(
+    tx: *const MidenExecutedTransaction,
+    out: *mut *mut MidenProvenTransaction,
+) -> MidenStatus {
+    ffi_call(|| {
+        let executed_transaction = handle(tx)?.0.clone();
+        let proven_transaction = TransactionProver::new(ProvingOptions::default())
+            .prove_transaction(executed_transaction)
+            .map_err(|err| FfiError::new(MidenStatus::ProvingFailed, format!("{err:?}")))?;
+
+        write_handle(out, MidenProvenTransaction(proven_transaction))
+    })
+}
+
+/// Verifies the proof of the proven transaction behind the provided handle against the specified
+/// minimum security level.
+///
+/// Returns [MidenStatus::VerificationFailed] if the proof is invalid or does not reach the
+/// requested security level.
+///
+/// # Safety
+/// `tx` must be a live proven transaction handle.
+#[no_mangle]
+pub unsafe extern "C" fn miden_verify_transaction(
+    tx: *const MidenProvenTransaction,
+    security_level: u32,
+) -> MidenStatus {
+    ffi_call(|| {
+        let proven_transaction = handle(tx)?.0.clone();
+        TransactionVerifier::new(security_level)
+            .verify(proven_transaction)
+            .map_err(|err| FfiError::new(MidenStatus::VerificationFailed, format!("{err:?}")))
+    })
+}
+
+/// Deserializes a proven transaction from the provided bytes and writes a handle to it into
+/// `out`.
+///
+/// # Safety
+/// `data` must point to `len` readable bytes, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_proven_transaction_from_bytes(
+    data: *const u8,
+    len: usize,
+    out: *mut *mut MidenProvenTransaction,
+) -> MidenStatus {
+    ffi_call(|| {
+        let proven_transaction = ProvenTransaction::read_from_bytes(bytes(data, len)?)
+            .map_err(|err| FfiError::invalid_input(format!("invalid proven transaction: {err}")))?;
+        write_handle(out, MidenProvenTransaction(proven_transaction))
+    })
+}
+
+/// Serializes the proven transaction behind the provided handle into `out`.
+///
+/// # Safety
+/// `tx` must be a live proven transaction handle, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_proven_transaction_to_bytes(
+    tx: *const MidenProvenTransaction,
+    out: *mut MidenBytes,
+) -> MidenStatus {
+    ffi_call(|| write_out(out, handle(tx)?.0.to_bytes().into()))
+}
+
+/// Writes the ID of the proven transaction behind the provided handle into `out`.
+///
+/// # Safety
+/// `tx` must be a live proven transaction handle, and `out` must be valid for writes of 32 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_proven_transaction_id(
+    tx: *const MidenProvenTransaction,
+    out: *mut [u8; 32],
+) -> MidenStatus {
+    ffi_call(|| write_out(out, handle(tx)?.0.id().as_bytes()))
+}
+
+/// Releases the proven transaction behind the provided handle.
+///
+/// # Safety
+/// `tx` must be null or a live proven transaction handle; the handle must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn miden_proven_transaction_free(tx: *mut MidenProvenTransaction) {
+    free_handle(tx)
+}
+
+// DATA STORE
+// ================================================================================================
+
+/// A data store serving the inputs of a single transaction.
+struct InputsDataStore(TransactionInputs);
+
+impl DataStore for InputsDataStore {
+    fn get_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(Account, Option<Word>), DataStoreError> {
+        if account_id != self.0.account().id() {
+            return Err(DataStoreError::AccountNotFound(account_id));
+        }
+
+        Ok((self.0.account().clone(), self.0.account_seed()))
+    }
+
+    fn get_block_header(&self, block_num: u32) -> Result<BlockHeader, DataStoreError> {
+        if block_num != self.0.block_header().block_num() {
+            return Err(DataStoreError::BlockNotFound(block_num));
+        }
+
+        Ok(*self.0.block_header())
+    }
+
+    fn get_chain_mmr(&self, block_ref: u32, _blocks: &[u32]) -> Result<ChainMmr, DataStoreError> {
+        if block_ref != self.0.block_header().block_num() {
+            return Err(DataStoreError::BlockNotFound(block_ref));
+        }
+
+        Ok(self.0.block_chain().clone())
+    }
+
+    fn get_notes(&self, note_ids: &[NoteId]) -> Result<Vec<InputNote>, DataStoreError> {
+        note_ids
+            .iter()
+            .map(|&note_id| {
+                self.0
+                    .input_notes()
+                    .iter()
+                    .find(|note| note.id() == note_id)
+                    .cloned()
+                    .ok_or(DataStoreError::NoteNotFound(note_id))
+            })
+            .collect()
+    }
+
+    fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError> {
+        if account_id != self.0.account().id() {
+            return Err(DataStoreError::AccountNotFound(account_id));
+        }
+
+        Ok(self.0.account().code().module().clone())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn execution_failed<E: core::fmt::Debug>(err: E) -> FfiError {
+    FfiError::new(MidenStatus::ExecutionFailed, format!("{err:?}"))
+}
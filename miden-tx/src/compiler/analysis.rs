@@ -0,0 +1,183 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use miden_objects::{
+    assembly::{Assembler, AssemblyContext, ProgramAst},
+    vm::CodeBlock,
+    Digest,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Paths of the procedures of the Miden library which are recognized by the script analysis.
+const KNOWN_PROCEDURES: [(&str, KnownProcedure); 11] = [
+    ("miden::contracts::wallets::basic::receive_asset", KnownProcedure::ReceiveAsset),
+    ("miden::contracts::wallets::basic::send_asset", KnownProcedure::SendAsset),
+    ("miden::contracts::auth::session::send_asset", KnownProcedure::SendAsset),
+    (
+        "miden::contracts::wallets::basic::move_asset_to_note",
+        KnownProcedure::SendAsset,
+    ),
+    ("miden::contracts::auth::session::move_asset_to_note", KnownProcedure::SendAsset),
+    (
+        "miden::contracts::faucets::basic_fungible::distribute",
+        KnownProcedure::MintAsset,
+    ),
+    (
+        "miden::contracts::faucets::basic_non_fungible::distribute",
+        KnownProcedure::MintAsset,
+    ),
+    (
+        "miden::contracts::faucets::multi_fungible::distribute",
+        KnownProcedure::MintAsset,
+    ),
+    ("miden::contracts::faucets::basic_fungible::burn", KnownProcedure::BurnAsset),
+    ("miden::contracts::faucets::basic_non_fungible::burn", KnownProcedure::BurnAsset),
+    ("miden::contracts::faucets::multi_fungible::burn", KnownProcedure::BurnAsset),
+];
+
+// KNOWN PROCEDURE
+// ================================================================================================
+
+/// A procedure of the standard account interfaces provided by the Miden library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnownProcedure {
+    /// Adds an asset to the vault of a basic wallet.
+    ReceiveAsset,
+    /// Removes an asset from the vault of a basic wallet and sends it in a note.
+    ///
+    /// This includes moving an asset into a note created by the wallet.
+    SendAsset,
+    /// Mints an asset by a faucet and sends it in a note.
+    MintAsset,
+    /// Burns an asset by a faucet.
+    BurnAsset,
+}
+
+// SCRIPT INTENT
+// ================================================================================================
+
+/// A summary of the account procedures a script may invoke.
+///
+/// The summary is obtained by static analysis of the MAST of the script, and thus, it contains the
+/// targets of all `call` instructions reachable in any execution branch of the script, regardless
+/// of whether they are invoked when the script is executed. Each target is classified against the
+/// standard account interfaces of the Miden library.
+///
+/// The targets of `dyncall` instructions and the procedures hidden behind proxy blocks cannot be
+/// determined statically; [ScriptIntent::is_complete()] returns false if the script contains any
+/// of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptIntent {
+    calls: Vec<(Digest, Option<KnownProcedure>)>,
+    is_complete: bool,
+}
+
+impl ScriptIntent {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the roots of the procedures called by the script in the order of their first
+    /// occurrence in the script, each paired with its classification if it is a known procedure.
+    pub fn calls(&self) -> &[(Digest, Option<KnownProcedure>)] {
+        &self.calls
+    }
+
+    /// Returns the known procedures called by the script.
+    pub fn known_procedures(&self) -> impl Iterator<Item = KnownProcedure> + '_ {
+        self.calls.iter().filter_map(|(_, procedure)| *procedure)
+    }
+
+    /// Returns the roots of the called procedures which are not a part of the standard account
+    /// interfaces.
+    pub fn unknown_procedures(&self) -> impl Iterator<Item = Digest> + '_ {
+        self.calls
+            .iter()
+            .filter(|(_, procedure)| procedure.is_none())
+            .map(|(root, _)| *root)
+    }
+
+    /// Returns true if the script may invoke the specified known procedure.
+    pub fn calls_procedure(&self, procedure: KnownProcedure) -> bool {
+        self.known_procedures().any(|known| known == procedure)
+    }
+
+    /// Returns true if the script may add assets to the vault of the account.
+    pub fn receives_assets(&self) -> bool {
+        self.calls_procedure(KnownProcedure::ReceiveAsset)
+    }
+
+    /// Returns true if the script may remove assets from the vault of the account.
+    pub fn sends_assets(&self) -> bool {
+        self.calls_procedure(KnownProcedure::SendAsset)
+    }
+
+    /// Returns true if all procedures invoked by the script could be determined.
+    pub fn is_complete(&self) -> bool {
+        self.is_complete
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a summary of the account procedures which may be called by the provided code block.
+pub(super) fn analyze_code_block(
+    code_block: &CodeBlock,
+    known_procedures: &BTreeMap<Digest, KnownProcedure>,
+) -> ScriptIntent {
+    let mut intent = ScriptIntent { calls: Vec::new(), is_complete: true };
+    recursively_collect_calls(code_block, known_procedures, &mut intent);
+    intent
+}
+
+/// Returns the MAST roots of the known procedures of the Miden library.
+pub(super) fn get_known_procedures(assembler: &Assembler) -> BTreeMap<Digest, KnownProcedure> {
+    KNOWN_PROCEDURES
+        .iter()
+        .map(|(path, procedure)| {
+            let (module, name) = path.rsplit_once("::").expect("procedure path is valid");
+            let program_ast =
+                ProgramAst::parse(&format!("use.{module}->lib begin call.lib::{name} end"))
+                    .expect("known procedure program is well formed");
+            let code_block = assembler
+                .compile_in_context(
+                    &program_ast,
+                    &mut AssemblyContext::for_program(Some(&program_ast)),
+                )
+                .expect("known procedure program is well formed");
+
+            let (root, _) = analyze_code_block(&code_block, &BTreeMap::new()).calls[0];
+            (root, *procedure)
+        })
+        .collect()
+}
+
+/// Recursively traverses the provided code block and accumulates the targets of calls.
+fn recursively_collect_calls(
+    code_block: &CodeBlock,
+    known_procedures: &BTreeMap<Digest, KnownProcedure>,
+    intent: &mut ScriptIntent,
+) {
+    match code_block {
+        CodeBlock::Join(block) => {
+            recursively_collect_calls(block.first(), known_procedures, intent);
+            recursively_collect_calls(block.second(), known_procedures, intent);
+        },
+        CodeBlock::Split(block) => {
+            recursively_collect_calls(block.on_true(), known_procedures, intent);
+            recursively_collect_calls(block.on_false(), known_procedures, intent);
+        },
+        CodeBlock::Loop(block) => {
+            recursively_collect_calls(block.body(), known_procedures, intent);
+        },
+        CodeBlock::Call(block) => {
+            let root = block.fn_hash();
+            if !block.is_syscall() && !intent.calls.iter().any(|(target, _)| *target == root) {
+                intent.calls.push((root, known_procedures.get(&root).copied()));
+            }
+        },
+        CodeBlock::Span(_) => {},
+        CodeBlock::Proxy(_) | CodeBlock::Dyn(_) => intent.is_complete = false,
+    }
+}
@@ -1,8 +1,9 @@
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 
 use miden_objects::{
-    assembly::{Assembler, AssemblyContext, ModuleAst, ProgramAst},
-    transaction::{InputNotes, TransactionScript},
+    assembly::{Assembler, AssemblyContext, Library, LibraryNamespace, ModuleAst, ProgramAst},
+    transaction::{InputNotes, KernelVersion, TransactionScript},
+    vm::ProgramInfo,
     Felt, NoteError, TransactionScriptError, Word,
 };
 
@@ -11,9 +12,22 @@ use super::{
     TransactionKernel,
 };
 
+mod analysis;
+pub use analysis::{KnownProcedure, ScriptIntent};
+
+mod script_builder;
+pub use script_builder::TransactionScriptBuilder;
+
 #[cfg(test)]
 mod tests;
 
+// CONSTANTS
+// ================================================================================================
+
+/// Namespaces of the libraries loaded into the transaction kernel assembler (i.e., miden-lib and
+/// the Miden standard library), which can not be used by additional libraries.
+const RESERVED_LIBRARY_NAMESPACES: [&str; 2] = ["miden", "std"];
+
 // TRANSACTION COMPILER
 // ================================================================================================
 
@@ -25,10 +39,18 @@ mod tests;
 ///
 /// In addition to transaction compilation, transaction compiler provides methods which can be
 /// used to compile Miden account code and note scripts.
+///
+/// Besides miden-lib and the Miden standard library, additional MASM libraries can be loaded into
+/// the compiler via [TransactionCompiler::with_library()]; modules of these libraries can then be
+/// imported by note scripts and transaction scripts.
 pub struct TransactionCompiler {
     assembler: Assembler,
+    libraries: Vec<LibraryNamespace>,
     account_procedures: BTreeMap<AccountId, Vec<Digest>>,
+    account_codes: BTreeMap<Digest, AccountCode>,
     kernel_main: CodeBlock,
+    kernel_version: KernelVersion,
+    note_args_proc_root: Digest,
 }
 
 impl TransactionCompiler {
@@ -44,10 +66,21 @@ impl TransactionCompiler {
             .compile_in_context(&main_ast, &mut AssemblyContext::for_program(Some(&main_ast)))
             .expect("main is well formed");
 
+        let kernel_version = TransactionKernel::version_from_program_info(&ProgramInfo::new(
+            kernel_main.hash(),
+            assembler.kernel().clone(),
+        ));
+
+        let note_args_proc_root = get_note_args_proc_root(&assembler);
+
         TransactionCompiler {
             assembler,
+            libraries: Vec::new(),
             account_procedures: BTreeMap::default(),
+            account_codes: BTreeMap::default(),
             kernel_main,
+            kernel_version,
+            note_args_proc_root,
         }
     }
 
@@ -61,22 +94,122 @@ impl TransactionCompiler {
         self
     }
 
+    /// Loads the provided MASM library into the [TransactionCompiler].
+    ///
+    /// Modules of the library can then be imported by note scripts and transaction scripts (e.g.,
+    /// `use.my_lib::my_module`); procedures invoked from these modules are included in the
+    /// compiled transaction programs.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The namespace of the library is reserved by miden-lib or the Miden standard library, or
+    ///   a library with the same namespace was already loaded.
+    /// - The library depends on a library which is not loaded into the compiler.
+    /// - Any of the library modules is not well-formed, or its path collides with the path of an
+    ///   already loaded module.
+    pub fn with_library<L: Library>(
+        mut self,
+        library: &L,
+    ) -> Result<Self, TransactionCompilerError> {
+        let namespace = library.root_ns();
+        if RESERVED_LIBRARY_NAMESPACES.contains(&namespace.as_ref())
+            || self.libraries.contains(namespace)
+        {
+            return Err(TransactionCompilerError::LibraryNamespaceConflict(namespace.to_string()));
+        }
+
+        if let Some(dependency) = library.dependencies().iter().find(|dependency| {
+            !RESERVED_LIBRARY_NAMESPACES.contains(&dependency.as_ref())
+                && !self.libraries.contains(dependency)
+        }) {
+            return Err(TransactionCompilerError::LibraryDependencyNotFound {
+                library: namespace.to_string(),
+                dependency: dependency.to_string(),
+            });
+        }
+
+        self.assembler = self
+            .assembler
+            .with_library(library)
+            .map_err(TransactionCompilerError::LoadLibraryFailed)?;
+        self.libraries.push(namespace.clone());
+
+        Ok(self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the version of the transaction kernel the transactions are compiled against.
+    pub fn kernel_version(&self) -> KernelVersion {
+        self.kernel_version
+    }
+
+    /// Returns the namespaces of the additional libraries loaded into this compiler, in the
+    /// order in which they were loaded.
+    pub fn libraries(&self) -> &[LibraryNamespace] {
+        &self.libraries
+    }
+
     // ACCOUNT CODE AND NOTE SCRIPT COMPILERS
     // --------------------------------------------------------------------------------------------
 
     /// Compiles the provided module into [AccountCode] and associates the resulting procedures
     /// with the specified account ID.
+    ///
+    /// If the same module has already been compiled by this compiler (either via this method or
+    /// via [TransactionCompiler::preload_account_code()]), the cached [AccountCode] is used
+    /// instead of assembling the module again.
     pub fn load_account(
         &mut self,
         account_id: AccountId,
         account_code: ModuleAst,
     ) -> Result<AccountCode, TransactionCompilerError> {
-        let account_code = AccountCode::new(account_code, &self.assembler)
-            .map_err(TransactionCompilerError::LoadAccountFailed)?;
+        let account_code =
+            match self.account_codes.values().find(|code| code.module() == &account_code) {
+                Some(code) => code.clone(),
+                None => {
+                    let code = AccountCode::new(account_code, &self.assembler)
+                        .map_err(TransactionCompilerError::LoadAccountFailed)?;
+                    self.account_codes.insert(code.root(), code.clone());
+                    code
+                },
+            };
         self.account_procedures.insert(account_id, account_code.procedures().to_vec());
         Ok(account_code)
     }
 
+    /// Compiles the provided module into [AccountCode] and caches the result under the specified
+    /// code root, without associating it with any account.
+    ///
+    /// Accounts whose code is built from the same module can then be loaded via
+    /// [TransactionCompiler::load_account()] without assembling the module again. If code for the
+    /// specified root has already been cached, the cached [AccountCode] is returned and the
+    /// provided module is ignored.
+    ///
+    /// **Note**: the code root is expected to be a commitment to the code compiled from the
+    /// provided module (possibly with some of its procedures requiring authentication), but this
+    /// is not checked.
+    pub fn preload_account_code(
+        &mut self,
+        code_root: Digest,
+        module: ModuleAst,
+    ) -> Result<AccountCode, TransactionCompilerError> {
+        if let Some(code) = self.account_codes.get(&code_root) {
+            return Ok(code.clone());
+        }
+
+        let code = AccountCode::new(module, &self.assembler)
+            .map_err(TransactionCompilerError::LoadAccountFailed)?;
+        self.account_codes.insert(code_root, code.clone());
+        Ok(code)
+    }
+
+    /// Returns the [AccountCode] cached under the specified code root, if any.
+    pub fn account_code(&self, code_root: &Digest) -> Option<&AccountCode> {
+        self.account_codes.get(code_root)
+    }
+
     /// Loads the provided account interface (vector of procedure digests) into this compiler.
     /// Returns the old account interface if it previously existed.
     pub fn load_account_interface(
@@ -112,6 +245,46 @@ impl TransactionCompiler {
         Ok(note_script)
     }
 
+    /// Returns true if the provided note script requires note args to be provided when the note
+    /// is consumed.
+    ///
+    /// A note script declares that it requires note args by accessing them via the
+    /// `miden::note::get_args` procedure.
+    pub fn note_script_requires_args(
+        &self,
+        note_script: &NoteScript,
+    ) -> Result<bool, TransactionCompilerError> {
+        let code_block = self
+            .assembler
+            .compile_in_context(note_script.code(), &mut AssemblyContext::for_program(None))
+            .map_err(TransactionCompilerError::CompileNoteScriptFailed)?;
+
+        Ok(collect_syscall_targets(&code_block).contains(&self.note_args_proc_root))
+    }
+
+    /// Returns a summary of the account procedures which may be invoked by the provided note
+    /// script, with the procedures of the standard account interfaces (e.g., receiving and sending
+    /// assets by basic wallets) classified as [KnownProcedure]s.
+    ///
+    /// The summary can be presented to users before consuming a note with an unknown script. The
+    /// procedures called by the script do not need to be loaded into this compiler.
+    pub fn analyze_note_script(
+        &self,
+        note_script: &NoteScript,
+    ) -> Result<ScriptIntent, TransactionCompilerError> {
+        // the code of the called procedures is not needed to determine their roots
+        let mut context = AssemblyContext::for_program(None).with_phantom_calls(true);
+        let code_block = self
+            .assembler
+            .compile_in_context(note_script.code(), &mut context)
+            .map_err(TransactionCompilerError::CompileNoteScriptFailed)?;
+
+        Ok(analysis::analyze_code_block(
+            &code_block,
+            &analysis::get_known_procedures(&self.assembler),
+        ))
+    }
+
     /// Constructs a [TransactionScript] by compiling the provided source code and checking the
     /// compatibility of the resulting program with the target account interfaces.
     pub fn compile_tx_script<T>(
@@ -147,6 +320,14 @@ impl TransactionCompiler {
     /// the specified account. Returns the compiled transaction program.
     ///
     /// The account is assumed to have been previously loaded into this compiler.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "info",
+            skip_all,
+            fields(account_id = %account_id, num_notes = notes.num_notes())
+        )
+    )]
     pub fn compile_transaction(
         &self,
         account_id: AccountId,
@@ -210,12 +391,59 @@ impl TransactionCompiler {
         Ok(program)
     }
 
+    // ACCOUNT VIEW CALL BUILDER
+    // --------------------------------------------------------------------------------------------
+    /// Compiles a program which calls the specified procedure of the specified account in a
+    /// read-only context. Returns the compiled program.
+    ///
+    /// Instead of the full transaction prologue, the program only loads the account data into the
+    /// root context, and neither the note processing nor the epilogue are executed.
+    ///
+    /// The account is assumed to have been previously loaded into this compiler.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account interface of the specified account has not been loaded into this compiler.
+    /// - The specified procedure is not a part of the account interface.
+    pub fn compile_account_view_call(
+        &self,
+        account_id: AccountId,
+        proc_root: Digest,
+    ) -> Result<Program, TransactionCompilerError> {
+        let account_interface = self
+            .account_procedures
+            .get(&account_id)
+            .ok_or(TransactionCompilerError::AccountInterfaceNotFound(account_id))?;
+        if !account_interface.contains(&proc_root) {
+            return Err(TransactionCompilerError::AccountProcedureNotFound(proc_root));
+        }
+
+        let view_call_ast = ProgramAst::parse(&format!(
+            "
+            use.miden::kernels::tx::prologue
+
+            begin
+                exec.prologue::prepare_account_view
+                call.{proc_root}
+            end
+            "
+        ))
+        .map_err(|err| TransactionCompilerError::CompileAccountViewCallFailed(err.into()))?;
+
+        self.assembler
+            .compile_ast(&view_call_ast)
+            .map_err(TransactionCompilerError::CompileAccountViewCallFailed)
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
     /// Compiles the provided notes into [CodeBlock]s (programs) and verifies that each note is
     /// compatible with the target account interfaces. Returns a vector of the compiled note
     /// programs.
+    ///
+    /// Notes sharing the same script are compiled only once, as compiling the local procedures of
+    /// a script more than once within the same assembly context fails.
     fn compile_notes(
         &self,
         target_account_interface: &[Digest],
@@ -223,9 +451,15 @@ impl TransactionCompiler {
         assembly_context: &mut AssemblyContext,
     ) -> Result<Vec<CodeBlock>, TransactionCompilerError> {
         let mut note_programs = Vec::new();
+        let mut compiled_scripts = Vec::new();
 
         // Create and verify note programs. Note programs are verified against the target account.
         for recorded_note in notes.iter() {
+            let note_script = recorded_note.note().script();
+            if compiled_scripts.contains(&note_script) {
+                continue;
+            }
+
             let note_program = self
                 .assembler
                 .compile_in_context(recorded_note.note().script().code(), assembly_context)
@@ -236,6 +470,7 @@ impl TransactionCompiler {
                 ScriptType::NoteScript,
             )?;
             note_programs.push(note_program);
+            compiled_scripts.push(note_script);
         }
 
         Ok(note_programs)
@@ -370,6 +605,52 @@ fn recursively_collect_call_branches(code_block: &CodeBlock, branches: &mut Vec<
     }
 }
 
+/// Returns the MAST root of the kernel procedure providing note args to note scripts.
+fn get_note_args_proc_root(assembler: &Assembler) -> Digest {
+    let program_ast = ProgramAst::parse("use.miden::note begin exec.note::get_args end")
+        .expect("note args program is well formed");
+    let code_block = assembler
+        .compile_in_context(&program_ast, &mut AssemblyContext::for_program(Some(&program_ast)))
+        .expect("note args program is well formed");
+
+    collect_syscall_targets(&code_block)
+        .first()
+        .copied()
+        .expect("note args are accessed via a syscall")
+}
+
+/// Collects the targets of all syscalls invoked by the provided code block.
+fn collect_syscall_targets(code_block: &CodeBlock) -> Vec<Digest> {
+    let mut targets = Vec::new();
+    recursively_collect_syscall_targets(code_block, &mut targets);
+    targets
+}
+
+/// Recursively traverses the provided code block and accumulates the targets of syscalls.
+fn recursively_collect_syscall_targets(code_block: &CodeBlock, targets: &mut Vec<Digest>) {
+    match code_block {
+        CodeBlock::Join(block) => {
+            recursively_collect_syscall_targets(block.first(), targets);
+            recursively_collect_syscall_targets(block.second(), targets);
+        },
+        CodeBlock::Split(block) => {
+            recursively_collect_syscall_targets(block.on_true(), targets);
+            recursively_collect_syscall_targets(block.on_false(), targets);
+        },
+        CodeBlock::Loop(block) => {
+            recursively_collect_syscall_targets(block.body(), targets);
+        },
+        CodeBlock::Call(block) => {
+            if block.is_syscall() {
+                targets.push(block.fn_hash());
+            }
+        },
+        CodeBlock::Span(_) => {},
+        CodeBlock::Proxy(_) => {},
+        CodeBlock::Dyn(_) => {},
+    }
+}
+
 // SCRIPT TARGET
 // ================================================================================================
 
@@ -0,0 +1,361 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use miden_lib::AuthScheme;
+use miden_objects::{
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault},
+    notes::{Note, NoteTag, NoteType, PartialNote},
+    Digest, Word, MAX_OUTPUT_NOTES_PER_TX,
+};
+
+use crate::TransactionCompilerError;
+
+// CONSTANTS
+// ================================================================================================
+
+const AUTH_LIBRARY: &str = "use.miden::contracts::auth::basic->auth_tx";
+const SESSION_LIBRARY: &str = "use.miden::contracts::auth::session";
+const WALLET_LIBRARY: &str = "use.miden::contracts::wallets::basic->wallet";
+const FAUCET_LIBRARY: &str = "use.miden::contracts::faucets::basic_fungible->faucet";
+
+// TRANSACTION SCRIPT BUILDER
+// ================================================================================================
+
+/// A builder for transaction scripts which invoke the procedures of the standard wallet and faucet
+/// accounts.
+///
+/// The builder assembles the MASM source of the script from typed parameters, taking care of the
+/// stack layout expected by each procedure. The calls are executed in the order in which they
+/// were added to the builder, followed by the authentication procedure if an authentication
+/// scheme was provided.
+///
+/// For wallets using [AuthScheme::RpoFalcon512Session], assets are sent via the `send_asset`
+/// procedure of the session module, and thus, the authentication scheme must be provided for the
+/// script to match the interface of the wallet.
+///
+/// The resulting [ProgramAst] can be compiled into a transaction script via
+/// [TransactionCompiler::compile_tx_script()](super::TransactionCompiler::compile_tx_script).
+#[derive(Debug, Default, Clone)]
+pub struct TransactionScriptBuilder {
+    calls: Vec<ScriptCall>,
+    auth: Option<ScriptAuth>,
+}
+
+impl TransactionScriptBuilder {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [TransactionScriptBuilder] without any calls and without authentication.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // BUILDER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Authenticates the transaction using the specified authentication scheme at the end of the
+    /// script.
+    ///
+    /// For [AuthScheme::RpoFalcon512Session], the transaction is authenticated by the main key.
+    pub fn with_auth(mut self, auth_scheme: &AuthScheme) -> Self {
+        self.auth = Some(match auth_scheme {
+            AuthScheme::RpoFalcon512 { .. } => ScriptAuth::RpoFalcon512,
+            AuthScheme::RpoFalcon512Session { .. } => {
+                ScriptAuth::RpoFalcon512Session { use_session_key: false }
+            },
+        });
+        self
+    }
+
+    /// Authenticates the transaction using the session key of a wallet created with
+    /// [AuthScheme::RpoFalcon512Session] at the end of the script.
+    pub fn with_session_key_auth(mut self) -> Self {
+        self.auth = Some(ScriptAuth::RpoFalcon512Session { use_session_key: true });
+        self
+    }
+
+    /// Adds a call to the `send_asset` procedure of the basic wallet, which creates a note with
+    /// the specified asset, tag, and type for the specified recipient.
+    pub fn send_asset(
+        mut self,
+        asset: Asset,
+        recipient: Digest,
+        tag: NoteTag,
+        note_type: NoteType,
+    ) -> Self {
+        self.calls.push(ScriptCall::SendNote {
+            assets: vec![asset],
+            recipient,
+            tag,
+            note_type,
+        });
+        self
+    }
+
+    /// Adds a call to the `send_asset` procedure of the basic wallet for each of the provided
+    /// assets, which creates a separate note for every asset.
+    pub fn send_assets(
+        self,
+        assets: impl IntoIterator<Item = Asset>,
+        recipient: Digest,
+        tag: NoteTag,
+        note_type: NoteType,
+    ) -> Self {
+        assets
+            .into_iter()
+            .fold(self, |builder, asset| builder.send_asset(asset, recipient, tag, note_type))
+    }
+
+    /// Adds a call to the `send_asset` procedure of the basic wallet, which creates the provided
+    /// note for a recipient known only by its digest.
+    ///
+    /// # Panics
+    /// Panics if the note does not contain exactly one asset.
+    pub fn send_to_recipient(self, note: &PartialNote) -> Self {
+        assert_eq!(note.assets().num_assets(), 1, "note must contain exactly one asset");
+        let asset = *note.assets().iter().next().expect("note contains one asset");
+        let metadata = note.metadata();
+
+        self.send_asset(asset, note.recipient_digest(), metadata.tag(), metadata.note_type())
+    }
+
+    /// Adds the calls creating each of the provided notes: a call to the `send_asset` procedure of
+    /// the basic wallet with the first asset of the note, followed by a call to the
+    /// `move_asset_to_note` procedure for each of its remaining assets.
+    ///
+    /// Before adding the calls, checks that all notes sent by the script can be created in a single
+    /// transaction by an account holding the assets of the provided vault, i.e., that the number of
+    /// output notes does not exceed [MAX_OUTPUT_NOTES_PER_TX] and that the vault contains all
+    /// assets sent by the script.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of notes created by the script would exceed [MAX_OUTPUT_NOTES_PER_TX].
+    /// - Any of the provided notes holds no assets.
+    /// - The vault does not contain all assets sent by the script.
+    pub fn send_notes(
+        mut self,
+        notes: &[Note],
+        vault: &AssetVault,
+    ) -> Result<Self, TransactionCompilerError> {
+        let num_notes = self.num_output_notes() + notes.len();
+        if num_notes > MAX_OUTPUT_NOTES_PER_TX {
+            return Err(TransactionCompilerError::TooManyOutputNotes(num_notes));
+        }
+        if let Some(note) = notes.iter().find(|note| note.assets().num_assets() == 0) {
+            return Err(TransactionCompilerError::OutputNoteWithoutAssets(note.id()));
+        }
+
+        self.calls.extend(notes.iter().map(|note| ScriptCall::SendNote {
+            assets: note.assets().iter().copied().collect(),
+            recipient: note.recipient().digest(),
+            tag: note.metadata().tag(),
+            note_type: note.metadata().note_type(),
+        }));
+
+        let sent_assets = self.calls.iter().flat_map(|call| match call {
+            ScriptCall::SendNote { assets, .. } => assets.as_slice(),
+            _ => &[],
+        });
+        vault
+            .clone()
+            .remove_assets(sent_assets.copied())
+            .map_err(TransactionCompilerError::InsufficientVaultAssets)?;
+
+        Ok(self)
+    }
+
+    /// Adds a call to the `distribute` procedure of the basic fungible faucet, which mints the
+    /// specified amount of the faucet's asset and sends it to the specified recipient in a note
+    /// with the specified tag and type.
+    pub fn distribute(
+        mut self,
+        amount: u64,
+        recipient: Digest,
+        tag: NoteTag,
+        note_type: NoteType,
+    ) -> Self {
+        self.calls.push(ScriptCall::Distribute { amount, recipient, tag, note_type });
+        self
+    }
+
+    /// Adds a call to the `burn` procedure of the basic fungible faucet, which burns the
+    /// specified asset.
+    ///
+    /// The asset must be a part of the assets consumed by the transaction and must have been
+    /// issued by the faucet executing the transaction.
+    pub fn burn(mut self, asset: Asset) -> Self {
+        self.calls.push(ScriptCall::Burn { asset });
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of notes created by the calls added to the builder.
+    pub fn num_output_notes(&self) -> usize {
+        self.calls
+            .iter()
+            .filter(|call| {
+                matches!(call, ScriptCall::SendNote { .. } | ScriptCall::Distribute { .. })
+            })
+            .count()
+    }
+
+    // SCRIPT BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the MASM source code of the transaction script.
+    pub fn source(&self) -> String {
+        let uses_session = matches!(self.auth, Some(ScriptAuth::RpoFalcon512Session { .. }));
+        let sends_assets =
+            self.calls.iter().any(|call| matches!(call, ScriptCall::SendNote { .. }));
+
+        let mut imports = Vec::new();
+        if uses_session {
+            imports.push(SESSION_LIBRARY);
+        } else if self.auth.is_some() {
+            imports.push(AUTH_LIBRARY);
+        }
+        if sends_assets && !uses_session {
+            imports.push(WALLET_LIBRARY);
+        }
+        if self.calls.iter().any(|call| !matches!(call, ScriptCall::SendNote { .. })) {
+            imports.push(FAUCET_LIBRARY);
+        }
+
+        let wallet = if uses_session { "session" } else { "wallet" };
+        let mut body: Vec<String> = self.calls.iter().map(|call| call.to_masm(wallet)).collect();
+        if let Some(auth) = self.auth {
+            body.push(auth.to_masm());
+        }
+        if body.is_empty() {
+            // an empty program body is not valid MASM
+            body.push(String::from("push.0 drop"));
+        }
+
+        format!(
+            "{imports}\n\nbegin\n    {body}\nend\n",
+            imports = imports.join("\n"),
+            body = body.join("\n\n    ")
+        )
+    }
+
+    /// Returns the [ProgramAst] of the transaction script.
+    pub fn build(&self) -> ProgramAst {
+        ProgramAst::parse(&self.source()).expect("generated transaction script is valid")
+    }
+}
+
+// SCRIPT AUTH
+// ================================================================================================
+
+/// The authentication procedure invoked at the end of a transaction script.
+#[derive(Debug, Clone, Copy)]
+enum ScriptAuth {
+    RpoFalcon512,
+    RpoFalcon512Session { use_session_key: bool },
+}
+
+impl ScriptAuth {
+    /// Returns the MASM code invoking the authentication procedure.
+    fn to_masm(self) -> String {
+        match self {
+            ScriptAuth::RpoFalcon512 => String::from("call.auth_tx::auth_tx_rpo_falcon512"),
+            ScriptAuth::RpoFalcon512Session { use_session_key } => format!(
+                "push.{use_session_key}
+    call.session::auth_tx_rpo_falcon512_session
+    drop",
+                use_session_key = use_session_key as u8,
+            ),
+        }
+    }
+}
+
+// SCRIPT CALL
+// ================================================================================================
+
+/// A single procedure invocation in a transaction script.
+#[derive(Debug, Clone)]
+enum ScriptCall {
+    SendNote {
+        assets: Vec<Asset>,
+        recipient: Digest,
+        tag: NoteTag,
+        note_type: NoteType,
+    },
+    Distribute {
+        amount: u64,
+        recipient: Digest,
+        tag: NoteTag,
+        note_type: NoteType,
+    },
+    Burn {
+        asset: Asset,
+    },
+}
+
+impl ScriptCall {
+    /// Returns the MASM code invoking the procedure, which leaves the stack in the same state as
+    /// it was before the invocation.
+    ///
+    /// The `send_asset` and `move_asset_to_note` procedures are invoked from the module imported
+    /// under the `wallet` alias. The note pointer returned by `send_asset` stays on top of the
+    /// stack while the remaining assets are moved to the note.
+    fn to_masm(&self, wallet: &str) -> String {
+        match self {
+            ScriptCall::SendNote { assets, recipient, tag, note_type } => {
+                let (asset, remaining_assets) =
+                    assets.split_first().expect("notes sent by the script hold assets");
+                let move_assets = remaining_assets.iter().fold(String::new(), |masm, asset| {
+                    masm + &format!(
+                        "
+    push.{asset}
+    call.{wallet}::move_asset_to_note
+    dropw",
+                        asset = prepare_word(&(*asset).into()),
+                    )
+                });
+
+                format!(
+                    "push.{recipient}
+    push.{note_type}
+    push.{tag}
+    push.{asset}
+    call.{wallet}::send_asset{move_assets}
+    dropw dropw drop drop",
+                    recipient = prepare_word(&(*recipient).into()),
+                    note_type = *note_type as u8,
+                    asset = prepare_word(&(*asset).into()),
+                )
+            },
+            ScriptCall::Distribute { amount, recipient, tag, note_type } => format!(
+                "push.{recipient}
+    push.{note_type}
+    push.{tag}
+    push.{amount}
+    call.faucet::distribute
+    dropw drop drop drop",
+                recipient = prepare_word(&(*recipient).into()),
+                note_type = *note_type as u8,
+            ),
+            ScriptCall::Burn { asset } => format!(
+                "push.{asset}
+    call.faucet::burn
+    dropw",
+                asset = prepare_word(&(*asset).into()),
+            ),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Formats the provided word as the immediate value of a MASM `push` instruction.
+fn prepare_word(word: &Word) -> String {
+    word.iter().map(|x| x.as_int().to_string()).collect::<Vec<_>>().join(".")
+}
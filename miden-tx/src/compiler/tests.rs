@@ -1,20 +1,30 @@
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 
+use miden_lib::{
+    notes::{create_p2id_note, create_swap_note},
+    AuthScheme,
+};
 use miden_objects::{
     accounts::account_id::testing::{
         ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
         ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
         ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN_2, ACCOUNT_ID_SENDER,
     },
+    assembly::{LibraryNamespace, LibraryPath, MaslLibrary, Module, Version},
     assets::{Asset, FungibleAsset},
+    crypto::{dsa::rpo_falcon512::PublicKey, rand::RpoRandomCoin},
     notes::{
-        Note, NoteAssets, NoteInclusionProof, NoteInputs, NoteMetadata, NoteRecipient, NoteType,
+        Note, NoteAssets, NoteInclusionProof, NoteInputs, NoteMetadata, NoteRecipient, NoteScript,
+        NoteTag, NoteType,
     },
     transaction::{InputNote, InputNotes},
-    Felt, Word, ZERO,
+    Digest, Felt, Word, ZERO,
 };
 
-use super::{AccountId, ModuleAst, ProgramAst, ScriptTarget, TransactionCompiler};
+use super::{
+    AccountId, KnownProcedure, ModuleAst, ProgramAst, ScriptTarget, TransactionCompiler,
+    TransactionCompilerError, TransactionScriptBuilder,
+};
 
 // CONSTANTS
 // ================================================================================================
@@ -66,6 +76,27 @@ fn test_load_account() {
     }
 }
 
+#[test]
+fn test_preload_account_code() {
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+    let account_code = tx_compiler
+        .preload_account_code(Digest::default(), account_code_ast.clone())
+        .unwrap();
+    assert_eq!(tx_compiler.account_code(&Digest::default()), Some(&account_code));
+
+    // once preloaded, code for the same root is not compiled again
+    let other_code_ast = ModuleAst::parse("export.foo push.1 drop end").unwrap();
+    let cached = tx_compiler.preload_account_code(Digest::default(), other_code_ast).unwrap();
+    assert_eq!(cached, account_code);
+
+    // loading an account with the same module uses the cached code
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let loaded = tx_compiler.load_account(account_id, account_code_ast).unwrap();
+    assert_eq!(loaded, account_code);
+}
+
 #[test]
 fn test_compile_valid_note_script() {
     let test_cases = [
@@ -135,6 +166,165 @@ fn test_compile_valid_note_script() {
     }
 }
 
+#[test]
+fn test_analyze_note_script() {
+    let tx_compiler = TransactionCompiler::new();
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let rng = RpoRandomCoin::new([ZERO; 4]);
+
+    // P2ID notes only add assets to the vault of the account
+    let p2id_note =
+        create_p2id_note(sender, target, vec![asset], NoteType::Public, ZERO, rng).unwrap();
+    let intent = tx_compiler.analyze_note_script(p2id_note.script()).unwrap();
+    assert!(intent.is_complete());
+    assert_eq!(intent.known_procedures().collect::<Vec<_>>(), [KnownProcedure::ReceiveAsset]);
+    assert!(intent.receives_assets() && !intent.sends_assets());
+
+    // SWAP notes add the offered asset and send the requested asset
+    let requested_asset: Asset =
+        FungibleAsset::new(AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap(), 50)
+            .unwrap()
+            .into();
+    let (swap_note, _) =
+        create_swap_note(sender, asset, requested_asset, NoteType::Public, ZERO, rng).unwrap();
+    let intent = tx_compiler.analyze_note_script(swap_note.script()).unwrap();
+    assert!(intent.receives_assets() && intent.sends_assets());
+    assert_eq!(intent.unknown_procedures().count(), 0);
+
+    // calls of procedures outside of the standard interfaces are reported in all branches, while
+    // syscalls are ignored
+    let note_script_ast = ProgramAst::parse(&format!(
+        "
+        use.miden::note
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            exec.note::get_inputs drop drop
+            if.true
+                call.{ACCT_PROC_1}
+            else
+                call.wallet::receive_asset
+                call.{ACCT_PROC_1}
+            end
+        end"
+    ))
+    .unwrap();
+    let note_script = NoteScript::from_parts(note_script_ast, Digest::default());
+    let intent = tx_compiler.analyze_note_script(&note_script).unwrap();
+    assert_eq!(intent.calls().len(), 2);
+    assert_eq!(
+        intent.unknown_procedures().collect::<Vec<_>>(),
+        [Digest::try_from(ACCT_PROC_1).unwrap()]
+    );
+    assert!(intent.receives_assets());
+}
+
+#[test]
+fn test_compile_note_script_with_library() {
+    let note_script_src = "
+        use.my_lib::math
+
+        begin
+            push.1.2
+            exec.math::add_two
+            drop
+        end
+    ";
+
+    // the note script can not be compiled without the library
+    let tx_compiler = TransactionCompiler::new();
+    let note_script_ast = ProgramAst::parse(note_script_src).unwrap();
+    assert!(tx_compiler.compile_note_script(note_script_ast, vec![]).is_err());
+
+    let tx_compiler =
+        TransactionCompiler::new().with_library(&mock_library("my_lib", &[])).unwrap();
+    assert_eq!(tx_compiler.libraries(), &[LibraryNamespace::new("my_lib").unwrap()]);
+    let note_script_ast = ProgramAst::parse(note_script_src).unwrap();
+    assert!(tx_compiler.compile_note_script(note_script_ast, vec![]).is_ok());
+}
+
+#[test]
+fn test_load_invalid_library() {
+    // namespaces of miden-lib and of the standard library are reserved
+    for namespace in ["miden", "std"] {
+        let result = TransactionCompiler::new().with_library(&mock_library(namespace, &[]));
+        assert!(matches!(
+            result,
+            Err(TransactionCompilerError::LibraryNamespaceConflict(name)) if name == namespace
+        ));
+    }
+
+    // the same namespace can not be loaded twice
+    let result = TransactionCompiler::new()
+        .with_library(&mock_library("my_lib", &[]))
+        .unwrap()
+        .with_library(&mock_library("my_lib", &[]));
+    assert!(matches!(result, Err(TransactionCompilerError::LibraryNamespaceConflict(_))));
+
+    // dependencies must be loaded before the library
+    let result = TransactionCompiler::new().with_library(&mock_library("my_lib", &["other_lib"]));
+    assert!(matches!(
+        result,
+        Err(TransactionCompilerError::LibraryDependencyNotFound { library, dependency })
+            if library == "my_lib" && dependency == "other_lib"
+    ));
+    let tx_compiler = TransactionCompiler::new()
+        .with_library(&mock_library("other_lib", &[]))
+        .unwrap()
+        .with_library(&mock_library("my_lib", &["other_lib", "std"]))
+        .unwrap();
+    assert_eq!(tx_compiler.libraries().len(), 2);
+}
+
+#[test]
+fn test_transaction_compilation_with_shared_note_script() {
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+    let _account_code = tx_compiler.load_account(account_id, account_code_ast).unwrap();
+
+    // a note script with a local procedure, shared by several notes
+    let note_program_ast = ProgramAst::parse(
+        format!("proc.local_procedure push.1 drop end begin exec.local_procedure call.{ACCT_PROC_1} drop end")
+            .as_str(),
+    )
+    .unwrap();
+    let note_script = tx_compiler
+        .compile_note_script(note_program_ast, vec![ScriptTarget::AccountId(account_id)])
+        .unwrap();
+
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let mock_inclusion_proof = NoteInclusionProof::new(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        0,
+        Default::default(),
+    )
+    .unwrap();
+    let notes = (0..3_u64)
+        .map(|idx| {
+            let serial_num: Word = [Felt::new(idx), Felt::new(2), Felt::new(3), Felt::new(4)];
+            let vault = NoteAssets::new(vec![]).unwrap();
+            let metadata = NoteMetadata::new(sender, NoteType::Public, 0.into(), ZERO).unwrap();
+            let inputs = NoteInputs::new(vec![]).unwrap();
+            let recipient = NoteRecipient::new(serial_num, note_script.clone(), inputs);
+            InputNote::authenticated(
+                Note::new(vault, metadata, recipient),
+                mock_inclusion_proof.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let notes = InputNotes::new(notes).unwrap();
+
+    let res = tx_compiler.compile_transaction(account_id, &notes, None);
+    assert!(res.is_ok());
+}
+
 fn mock_consumed_notes(
     tx_compiler: &mut TransactionCompiler,
     target_account: AccountId,
@@ -195,7 +385,7 @@ fn test_transaction_compilation_succeeds() {
     .unwrap();
     let notes = notes
         .into_iter()
-        .map(|note| InputNote::new(note, mock_inclusion_proof.clone()))
+        .map(|note| InputNote::authenticated(note, mock_inclusion_proof.clone()))
         .collect::<Vec<_>>();
 
     let notes = InputNotes::new(notes).unwrap();
@@ -207,6 +397,86 @@ fn test_transaction_compilation_succeeds() {
     assert!(res.is_ok());
 }
 
+#[test]
+fn test_transaction_script_builder() {
+    let compiler = TransactionCompiler::new();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let recipient = Digest::from([ZERO, Felt::new(1), Felt::new(2), Felt::new(3)]);
+    let tag = NoteTag::from(7);
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new([ZERO; 4]) };
+
+    let tx_script_ast = TransactionScriptBuilder::new()
+        .send_asset(asset, recipient, tag, NoteType::OffChain)
+        .with_auth(&auth_scheme)
+        .build();
+    let tx_script = compiler.compile_tx_script(tx_script_ast, vec![], vec![]).unwrap();
+
+    let asset_word: Word = asset.into();
+    let expected_script_ast = ProgramAst::parse(&format!(
+        "
+        use.miden::contracts::auth::basic->auth_tx
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.0.1.2.3
+            push.{note_type}
+            push.7
+            push.{asset}
+            call.wallet::send_asset
+            dropw dropw drop drop
+
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+        note_type = NoteType::OffChain as u8,
+        asset = asset_word.iter().map(|x| x.as_int().to_string()).collect::<Vec<_>>().join("."),
+    ))
+    .unwrap();
+    let expected_script = compiler.compile_tx_script(expected_script_ast, vec![], vec![]).unwrap();
+    assert_eq!(tx_script.hash(), expected_script.hash());
+
+    // wallets using session keys send assets via the session module
+    let tx_script_ast = TransactionScriptBuilder::new()
+        .send_asset(asset, recipient, tag, NoteType::OffChain)
+        .with_session_key_auth()
+        .build();
+    let tx_script = compiler.compile_tx_script(tx_script_ast, vec![], vec![]).unwrap();
+
+    let expected_script_ast = ProgramAst::parse(&format!(
+        "
+        use.miden::contracts::auth::session
+
+        begin
+            push.0.1.2.3
+            push.{note_type}
+            push.7
+            push.{asset}
+            call.session::send_asset
+            dropw dropw drop drop
+
+            push.1
+            call.session::auth_tx_rpo_falcon512_session
+            drop
+        end
+        ",
+        note_type = NoteType::OffChain as u8,
+        asset = asset_word.iter().map(|x| x.as_int().to_string()).collect::<Vec<_>>().join("."),
+    ))
+    .unwrap();
+    let expected_script = compiler.compile_tx_script(expected_script_ast, vec![], vec![]).unwrap();
+    assert_eq!(tx_script.hash(), expected_script.hash());
+
+    // faucet calls and empty scripts are compiled as well
+    let tx_script_ast = TransactionScriptBuilder::new()
+        .distribute(100, recipient, tag, NoteType::Public)
+        .burn(asset)
+        .build();
+    assert!(compiler.compile_tx_script(tx_script_ast, vec![], vec![]).is_ok());
+    let empty_script_ast = TransactionScriptBuilder::new().build();
+    assert!(compiler.compile_tx_script(empty_script_ast, vec![], vec![]).is_ok());
+}
+
 // HELPERS
 // ================================================================================================
 
@@ -216,3 +486,24 @@ fn hex_to_bytes(hex: &str) -> Vec<u8> {
         .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
         .collect::<Vec<_>>()
 }
+
+/// Returns a library with the specified namespace and dependencies, consisting of a single `math`
+/// module which exports an `add_two` procedure.
+fn mock_library(namespace: &str, dependencies: &[&str]) -> MaslLibrary {
+    let namespace = LibraryNamespace::new(namespace).unwrap();
+    let path = LibraryPath::new(format!("{}::math", namespace.as_ref())).unwrap();
+    let ast = ModuleAst::parse("export.add_two push.2 add end").unwrap();
+    let dependencies = dependencies
+        .iter()
+        .map(|dependency| LibraryNamespace::new(dependency).unwrap())
+        .collect();
+
+    MaslLibrary::new(
+        namespace,
+        Version::default(),
+        false,
+        vec![Module::new(path, ast)],
+        dependencies,
+    )
+    .unwrap()
+}
@@ -0,0 +1,332 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cell::RefCell, fmt};
+
+use miden_air::trace::{
+    decoder::{HASHER_STATE_RANGE, OP_BITS_RANGE},
+    DECODER_TRACE_OFFSET,
+};
+use miden_lib::{transaction::TransactionKernel, MidenLib};
+use miden_objects::{
+    assembly::{Assembler, AssemblyContext, Library, LibraryPath, ModuleAst},
+    vm::{CodeBlock, Program},
+    Digest,
+};
+use miden_stdlib::StdLibrary;
+use vm_processor::{ExecutionTrace, Operation};
+use winter_prover::Trace;
+
+// KERNEL COVERAGE
+// ================================================================================================
+
+/// Records which procedures of the transaction kernel are executed across a set of transactions.
+///
+/// Procedures are identified by their MAST roots, which are matched against the roots of the code
+/// blocks executed by the VM, as recorded in the decoder section of the execution trace. Since
+/// `exec` inlines the code block of the invoked procedure, every execution of a procedure shows up
+/// as an executed code block with the MAST root of the procedure, whichever way it is invoked.
+///
+/// Only the procedures exported by the kernel modules and by the kernel API are tracked, as the
+/// assembler does not expose the MAST roots of internal procedures. Procedures with identical
+/// bodies share a MAST root, and thus are reported as executed together.
+///
+/// The assembler merges consecutive basic blocks, so a procedure consisting of a single basic block
+/// loses its MAST root when it is inlined next to other basic blocks. Procedures whose MAST roots
+/// do not appear anywhere in the MAST of the kernel can never be observed and are reported as
+/// untraceable (see [KernelProcedure::is_traceable]); for the remaining procedures, executions of
+/// inlined copies which were merged into a surrounding basic block are not counted.
+///
+/// Executions are recorded through a shared reference, so that the coverage can be shared by
+/// several executors; see [TransactionExecutor::with_kernel_coverage()](crate::TransactionExecutor::with_kernel_coverage).
+pub struct KernelCoverage {
+    procedures: Vec<KernelProcedure>,
+    hits: RefCell<BTreeMap<Digest, u64>>,
+}
+
+impl KernelCoverage {
+    /// Returns a new [KernelCoverage] tracking all exported procedures of the transaction kernel,
+    /// none of which has been executed yet.
+    ///
+    /// # Panics
+    /// Panics if the transaction kernel source is not well-formed.
+    pub fn new() -> Self {
+        // the kernel API must be compiled under the kernel path, and thus by an assembler which
+        // does not have a kernel yet
+        let kernel = ModuleAst::parse(TransactionKernel::kernel()).expect("kernel is well formed");
+        let kernel_assembler = Assembler::default()
+            .with_library(&MidenLib::default())
+            .expect("failed to load miden-lib")
+            .with_library(&StdLibrary::default())
+            .expect("failed to load std-lib");
+        let mut procedures = kernel_procedures(
+            &kernel_assembler,
+            &kernel,
+            &LibraryPath::kernel_path(),
+            "asm/kernels/transaction/api.masm".to_string(),
+        );
+
+        // the kernel modules may have already been compiled under their paths while resolving the
+        // imports of other modules, and so they are compiled as anonymous modules
+        let assembler = TransactionKernel::assembler();
+        for module in MidenLib::default().modules() {
+            let path = module.path.path();
+            if path.starts_with("miden::kernels::") {
+                let file = format!("asm/{}.masm", path.replace("::", "/"));
+                procedures.extend(kernel_procedures(&assembler, &module.ast, &module.path, file));
+            }
+        }
+
+        // a procedure is traceable only if its MAST root survived as a code block of the kernel
+        // MAST, which consists of the kernel program and of the kernel API procedures
+        let main_ast = TransactionKernel::main().expect("main is well formed");
+        let kernel_main = assembler.compile_ast(&main_ast).expect("main is well formed");
+        let mut kernel_api = String::from("begin\n");
+        for procedure in kernel.procs().iter().filter(|procedure| procedure.is_export) {
+            kernel_api.push_str(&format!("    syscall.{}\n", procedure.name));
+        }
+        kernel_api.push_str("end");
+        let kernel_api = assembler.compile(kernel_api).expect("kernel API is well formed");
+        let mut block_roots = code_block_roots(&kernel_main);
+        block_roots.extend(code_block_roots(&kernel_api));
+        for procedure in procedures.iter_mut() {
+            procedure.is_traceable = block_roots.contains(&procedure.mast_root);
+        }
+
+        procedures.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+        let hits = procedures.iter().map(|procedure| (procedure.mast_root, 0)).collect();
+
+        Self { procedures, hits: RefCell::new(hits) }
+    }
+
+    /// Returns the tracked procedures of the transaction kernel.
+    pub fn procedures(&self) -> &[KernelProcedure] {
+        &self.procedures
+    }
+
+    /// Records the kernel procedures executed in the provided execution trace.
+    ///
+    /// A procedure is counted once per execution; e.g., a procedure invoked for each input note of
+    /// a transaction is counted once per note.
+    pub fn record(&self, trace: &ExecutionTrace) {
+        let mut hits = self.hits.borrow_mut();
+        let main_trace = trace.main_segment();
+        let op_bits_offset = DECODER_TRACE_OFFSET + OP_BITS_RANGE.start;
+        let hasher_offset = DECODER_TRACE_OFFSET + HASHER_STATE_RANGE.start;
+
+        for row in 0..trace.trace_len_summary().main_trace_len() {
+            // the operation bits are stored starting with the least significant bit
+            let op_code = (0..OP_BITS_RANGE.len()).fold(0_u8, |op_code, bit| {
+                op_code | ((main_trace.get(op_bits_offset + bit, row).as_int() as u8) << bit)
+            });
+            if op_code != Operation::End.op_code() {
+                continue;
+            }
+
+            // when a code block ends, the first word of the hasher state holds its MAST root
+            let block_root =
+                Digest::new(core::array::from_fn(|i| main_trace.get(hasher_offset + i, row)));
+            if let Some(block_hits) = hits.get_mut(&block_root) {
+                *block_hits += 1;
+            }
+        }
+    }
+
+    /// Returns the report of the executions recorded so far.
+    pub fn report(&self) -> KernelCoverageReport {
+        let hits = self.hits.borrow();
+        let procedures = self
+            .procedures
+            .iter()
+            .map(|procedure| ProcedureCoverage {
+                procedure: procedure.clone(),
+                hits: hits[&procedure.mast_root],
+            })
+            .collect();
+
+        KernelCoverageReport { procedures }
+    }
+}
+
+impl Default for KernelCoverage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// KERNEL PROCEDURE
+// ================================================================================================
+
+/// A procedure exported by a module of the transaction kernel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KernelProcedure {
+    /// Path of the module defining the procedure (e.g., `miden::kernels::tx::note`), or `#sys`
+    /// for the procedures of the kernel API.
+    pub module: String,
+    /// Name of the procedure.
+    pub name: String,
+    /// Path of the MASM file defining the procedure relative to the root of the `miden-lib` crate
+    /// (e.g., `asm/miden/kernels/tx/note.masm`).
+    pub file: String,
+    /// Line of the procedure definition, starting at 1, or 0 if not known.
+    pub line: u32,
+    /// MAST root of the procedure.
+    pub mast_root: Digest,
+    /// Whether the executions of the procedure can be observed, i.e., whether the MAST root of the
+    /// procedure is the root of a code block of the kernel MAST.
+    pub is_traceable: bool,
+}
+
+// KERNEL COVERAGE REPORT
+// ================================================================================================
+
+/// Number of executions of a procedure of the transaction kernel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcedureCoverage {
+    pub procedure: KernelProcedure,
+    pub hits: u64,
+}
+
+/// Coverage of the procedures of the transaction kernel, as recorded by [KernelCoverage].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KernelCoverageReport {
+    procedures: Vec<ProcedureCoverage>,
+}
+
+impl KernelCoverageReport {
+    /// Returns the coverage of all tracked procedures, in the order of their files and lines.
+    pub fn procedures(&self) -> &[ProcedureCoverage] {
+        &self.procedures
+    }
+
+    /// Returns the coverage of the procedure with the specified name in the specified module, or
+    /// `None` if no such procedure is tracked.
+    pub fn get(&self, module: &str, name: &str) -> Option<&ProcedureCoverage> {
+        self.procedures
+            .iter()
+            .find(|coverage| coverage.procedure.module == module && coverage.procedure.name == name)
+    }
+
+    /// Returns the traceable procedures which were never executed.
+    pub fn uncovered(&self) -> impl Iterator<Item = &KernelProcedure> {
+        self.procedures
+            .iter()
+            .filter(|coverage| coverage.procedure.is_traceable && coverage.hits == 0)
+            .map(|coverage| &coverage.procedure)
+    }
+
+    /// Returns the procedures whose executions can not be observed.
+    pub fn untraceable(&self) -> impl Iterator<Item = &KernelProcedure> {
+        self.procedures
+            .iter()
+            .filter(|coverage| !coverage.procedure.is_traceable)
+            .map(|coverage| &coverage.procedure)
+    }
+
+    /// Returns the number of traceable procedures.
+    pub fn num_traceable(&self) -> usize {
+        self.procedures
+            .iter()
+            .filter(|coverage| coverage.procedure.is_traceable)
+            .count()
+    }
+
+    /// Returns the number of procedures which were executed at least once.
+    pub fn num_covered(&self) -> usize {
+        self.procedures.iter().filter(|coverage| coverage.hits > 0).count()
+    }
+}
+
+impl fmt::Display for KernelCoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "kernel coverage: {} of {} traceable procedures executed ({} untraceable)",
+            self.num_covered(),
+            self.num_traceable(),
+            self.procedures.len() - self.num_traceable()
+        )?;
+
+        let mut files = BTreeMap::<&str, (usize, usize)>::new();
+        for coverage in self.procedures.iter().filter(|coverage| coverage.procedure.is_traceable) {
+            let (covered, total) = files.entry(&coverage.procedure.file).or_default();
+            *covered += usize::from(coverage.hits > 0);
+            *total += 1;
+        }
+        for (file, (covered, total)) in files {
+            writeln!(f, "  {file}: {covered}/{total}")?;
+        }
+
+        for procedure in self.uncovered() {
+            writeln!(
+                f,
+                "  not executed: {} ({}:{})",
+                procedure.name, procedure.file, procedure.line
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the procedures exported by the provided kernel module, with their MAST roots computed
+/// by the provided assembler.
+///
+/// # Panics
+/// Panics if the module can not be compiled.
+fn kernel_procedures(
+    assembler: &Assembler,
+    module: &ModuleAst,
+    module_path: &LibraryPath,
+    file: String,
+) -> Vec<KernelProcedure> {
+    let mast_roots = if module_path.is_kernel_path() {
+        let mut context = AssemblyContext::for_module(true);
+        assembler.compile_module(module, Some(module_path), &mut context)
+    } else {
+        assembler.compile_module(module, None, &mut AssemblyContext::for_module(false))
+    }
+    .expect("kernel module is well formed");
+
+    // the MAST roots of re-exported procedures precede the roots of the exported procedures
+    let exports = module.procs().iter().filter(|procedure| procedure.is_export);
+    mast_roots
+        .into_iter()
+        .skip(module.reexported_procs().len())
+        .zip(exports)
+        .map(|(mast_root, procedure)| KernelProcedure {
+            module: module_path.path().to_string(),
+            name: procedure.name.to_string(),
+            file: file.clone(),
+            line: procedure.start.line(),
+            mast_root,
+            is_traceable: false,
+        })
+        .collect()
+}
+
+/// Returns the roots of all code blocks in the MAST of the provided program, including the code
+/// blocks of the procedures invoked via `call` and `syscall`.
+fn code_block_roots(program: &Program) -> BTreeSet<Digest> {
+    let mut roots = BTreeSet::new();
+    let mut blocks = vec![program.root()];
+    while let Some(block) = blocks.pop() {
+        if !roots.insert(block.hash()) {
+            continue;
+        }
+        match block {
+            CodeBlock::Join(block) => blocks.extend([block.first(), block.second()]),
+            CodeBlock::Split(block) => blocks.extend([block.on_true(), block.on_false()]),
+            CodeBlock::Loop(block) => blocks.push(block.body()),
+            CodeBlock::Call(block) => blocks.extend(program.cb_table().get(block.fn_hash())),
+            CodeBlock::Span(_) | CodeBlock::Dyn(_) | CodeBlock::Proxy(_) => (),
+        }
+    }
+
+    roots
+}
@@ -1,13 +1,16 @@
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use core::fmt::{self, Display};
 
+use miden_lib::transaction::TransactionKernelError;
 use miden_objects::{
-    assembly::AssemblyError, notes::NoteId, Felt, NoteError, ProvenTransactionError,
-    TransactionInputError, TransactionOutputError,
+    assembly::AssemblyError, notes::NoteId, transaction::KernelVersion, AccountDeltaError,
+    AdviceInputsError, AssetVaultError, Felt, NoteError, ProvenTransactionError,
+    TransactionArgsError, TransactionInputError, TransactionOutputError,
 };
 use miden_verifier::VerificationError;
+use vm_processor::InputError;
 
-use super::{AccountError, AccountId, Digest, ExecutionError};
+use super::{AccountError, AccountId, Digest, ExecutionError, NoteSkipReason};
 
 // TRANSACTION COMPILER ERROR
 // ================================================================================================
@@ -15,19 +18,69 @@ use super::{AccountError, AccountId, Digest, ExecutionError};
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionCompilerError {
     AccountInterfaceNotFound(AccountId),
+    AccountProcedureNotFound(Digest),
     BuildCodeBlockTableFailed(AssemblyError),
+    CompileAccountViewCallFailed(AssemblyError),
     CompileNoteScriptFailed(AssemblyError),
     CompileTxScriptFailed(AssemblyError),
+    InsufficientVaultAssets(AssetVaultError),
+    LibraryDependencyNotFound { library: String, dependency: String },
+    LibraryNamespaceConflict(String),
     LoadAccountFailed(AccountError),
+    LoadLibraryFailed(AssemblyError),
     NoteIncompatibleWithAccountInterface(Digest),
     NoteScriptError(NoteError),
     NoTransactionDriver,
+    OutputNoteWithoutAssets(NoteId),
+    TooManyOutputNotes(usize),
     TxScriptIncompatibleWithAccountInterface(Digest),
 }
 
 impl fmt::Display for TransactionCompilerError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::AccountInterfaceNotFound(account_id) => {
+                write!(f, "Interface of account {account_id} not found")
+            },
+            Self::AccountProcedureNotFound(root) => {
+                write!(f, "Account procedure with root {root} not found")
+            },
+            Self::BuildCodeBlockTableFailed(err) => {
+                write!(f, "Failed to build the code block table: {err}")
+            },
+            Self::CompileAccountViewCallFailed(err) => {
+                write!(f, "Failed to compile account view call: {err}")
+            },
+            Self::CompileNoteScriptFailed(err) => write!(f, "Failed to compile note script: {err}"),
+            Self::CompileTxScriptFailed(err) => {
+                write!(f, "Failed to compile transaction script: {err}")
+            },
+            Self::InsufficientVaultAssets(err) => {
+                write!(f, "Account vault holds insufficient assets: {err}")
+            },
+            Self::LibraryDependencyNotFound { library, dependency } => {
+                write!(f, "Dependency {dependency} of library {library} not found")
+            },
+            Self::LibraryNamespaceConflict(namespace) => {
+                write!(f, "Library namespace {namespace} is already in use")
+            },
+            Self::LoadAccountFailed(err) => write!(f, "Failed to load account: {err}"),
+            Self::LoadLibraryFailed(err) => write!(f, "Failed to load library: {err}"),
+            Self::NoteIncompatibleWithAccountInterface(root) => {
+                write!(f, "Note script {root} is incompatible with the account interface")
+            },
+            Self::NoteScriptError(err) => write!(f, "Invalid note script: {err}"),
+            Self::NoTransactionDriver => write!(f, "Transaction has neither notes nor a script"),
+            Self::OutputNoteWithoutAssets(note_id) => {
+                write!(f, "Output note {note_id} carries no assets")
+            },
+            Self::TooManyOutputNotes(num_notes) => {
+                write!(f, "{num_notes} output notes exceed the maximum number of output notes")
+            },
+            Self::TxScriptIncompatibleWithAccountInterface(root) => {
+                write!(f, "Transaction script {root} is incompatible with the account interface")
+            },
+        }
     }
 }
 
@@ -37,11 +90,23 @@ impl std::error::Error for TransactionCompilerError {}
 // TRANSACTION EXECUTOR ERROR
 // ================================================================================================
 
+/// Errors which can occur while executing a transaction.
+///
+/// Every variant has a stable numeric code in the `1000` range, see
+/// [TransactionExecutorError::error_code()].
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum TransactionExecutorError {
+    AdviceInputsTooLarge {
+        size: usize,
+        limit: usize,
+    },
+    CheckOutputNotesFailed(TransactionKernelError),
+    CompileAccountViewCallFailed(TransactionCompilerError),
     CompileNoteScriptFailed(TransactionCompilerError),
     CompileTransactionScriptFailed(TransactionCompilerError),
     CompileTransactionFailed(TransactionCompilerError),
+    ExecuteAccountViewCallFailed(ExecutionError),
     ExecuteTransactionProgramFailed(ExecutionError),
     FetchAccountCodeFailed(DataStoreError),
     FetchTransactionInputsFailed(DataStoreError),
@@ -53,28 +118,189 @@ pub enum TransactionExecutorError {
         expected: Option<Felt>,
         actual: Option<Felt>,
     },
+    InvalidAccountSalts(AccountId),
+    InvalidAccountViewInputs(InputError),
+    InvalidAdviceInputs(AdviceInputsError),
+    InvalidFaucetReservedSlot(AccountId),
+    InvalidTransactionArgs(TransactionArgsError),
+    InvalidTransactionInputs(TransactionInputError),
     InvalidTransactionOutput(TransactionOutputError),
     LoadAccountFailed(TransactionCompilerError),
+    LoadLibraryFailed(TransactionCompilerError),
+    NewAccountNonceNotIncremented(AccountId),
+    NewAccountVaultNotEmpty(AccountId),
+    NoClaimableNotes(Vec<(NoteId, NoteSkipReason)>),
+    UnsupportedKernelVersion(KernelVersion),
+}
+
+impl TransactionExecutorError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes are stable across releases: the code of a variant never changes and the codes of
+    /// removed variants are never reused.
+    pub fn error_code(&self) -> u32 {
+        match self {
+            Self::AdviceInputsTooLarge { .. } => 1000,
+            Self::CompileAccountViewCallFailed(_) => 1001,
+            Self::CompileNoteScriptFailed(_) => 1002,
+            Self::CompileTransactionScriptFailed(_) => 1003,
+            Self::CompileTransactionFailed(_) => 1004,
+            Self::ExecuteAccountViewCallFailed(_) => 1005,
+            Self::ExecuteTransactionProgramFailed(_) => 1006,
+            Self::FetchAccountCodeFailed(_) => 1007,
+            Self::FetchTransactionInputsFailed(_) => 1008,
+            Self::InconsistentAccountId { .. } => 1009,
+            Self::InconsistentAccountNonceDelta { .. } => 1010,
+            Self::InvalidAccountSalts(_) => 1011,
+            Self::InvalidAccountViewInputs(_) => 1012,
+            Self::InvalidAdviceInputs(_) => 1013,
+            Self::InvalidFaucetReservedSlot(_) => 1014,
+            Self::InvalidTransactionArgs(_) => 1015,
+            Self::InvalidTransactionInputs(_) => 1016,
+            Self::InvalidTransactionOutput(_) => 1017,
+            Self::LoadAccountFailed(_) => 1018,
+            Self::LoadLibraryFailed(_) => 1019,
+            Self::NewAccountNonceNotIncremented(_) => 1020,
+            Self::NewAccountVaultNotEmpty(_) => 1021,
+            Self::NoClaimableNotes(_) => 1022,
+            Self::UnsupportedKernelVersion(_) => 1023,
+            Self::CheckOutputNotesFailed(_) => 1024,
+        }
+    }
 }
 
 impl fmt::Display for TransactionExecutorError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::AdviceInputsTooLarge { size, limit } => {
+                write!(f, "Advice inputs take {size} elements, but at most {limit} are allowed")
+            },
+            Self::CheckOutputNotesFailed(err) => write!(f, "Invalid output notes: {err}"),
+            Self::CompileAccountViewCallFailed(err) => {
+                write!(f, "Failed to compile account view call: {err}")
+            },
+            Self::CompileNoteScriptFailed(err) => write!(f, "Failed to compile note script: {err}"),
+            Self::CompileTransactionScriptFailed(err) => {
+                write!(f, "Failed to compile transaction script: {err}")
+            },
+            Self::CompileTransactionFailed(err) => {
+                write!(f, "Failed to compile transaction: {err}")
+            },
+            Self::ExecuteAccountViewCallFailed(err) => {
+                write!(f, "Failed to execute account view call: {err}")
+            },
+            Self::ExecuteTransactionProgramFailed(err) => {
+                write!(f, "Failed to execute transaction program: {err}")
+            },
+            Self::FetchAccountCodeFailed(err) => write!(f, "Failed to fetch account code: {err}"),
+            Self::FetchTransactionInputsFailed(err) => {
+                write!(f, "Failed to fetch transaction inputs: {err}")
+            },
+            Self::InconsistentAccountId { input_id, output_id } => {
+                write!(
+                    f,
+                    "Transaction outputs account {output_id}, but was executed against {input_id}"
+                )
+            },
+            Self::InconsistentAccountNonceDelta { expected, actual } => {
+                write!(f, "Account nonce delta is {actual:?}, but {expected:?} was expected")
+            },
+            Self::InvalidAccountSalts(account_id) => {
+                write!(f, "Account salts are invalid for account {account_id}")
+            },
+            Self::InvalidAccountViewInputs(err) => {
+                write!(f, "Invalid account view call inputs: {err}")
+            },
+            Self::InvalidAdviceInputs(err) => write!(f, "Invalid advice inputs: {err}"),
+            Self::InvalidFaucetReservedSlot(account_id) => {
+                write!(f, "Reserved storage slot of faucet {account_id} is invalid")
+            },
+            Self::InvalidTransactionArgs(err) => write!(f, "Invalid transaction arguments: {err}"),
+            Self::InvalidTransactionInputs(err) => write!(f, "Invalid transaction inputs: {err}"),
+            Self::InvalidTransactionOutput(err) => write!(f, "Invalid transaction output: {err}"),
+            Self::LoadAccountFailed(err) => write!(f, "Failed to load account: {err}"),
+            Self::LoadLibraryFailed(err) => write!(f, "Failed to load library: {err}"),
+            Self::NewAccountNonceNotIncremented(account_id) => {
+                write!(f, "Nonce of new account {account_id} was not incremented")
+            },
+            Self::NewAccountVaultNotEmpty(account_id) => {
+                write!(f, "Vault of new account {account_id} is not empty")
+            },
+            Self::NoClaimableNotes(skipped) => {
+                write!(f, "None of the input notes can be consumed:")?;
+                for (note_id, reason) in skipped {
+                    write!(f, " note {note_id} ({reason:?});")?;
+                }
+                Ok(())
+            },
+            Self::UnsupportedKernelVersion(version) => {
+                write!(f, "Kernel version {version} is not supported")
+            },
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for TransactionExecutorError {}
+impl std::error::Error for TransactionExecutorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::CheckOutputNotesFailed(err) => Some(err),
+            Self::CompileAccountViewCallFailed(err)
+            | Self::CompileNoteScriptFailed(err)
+            | Self::CompileTransactionScriptFailed(err)
+            | Self::CompileTransactionFailed(err)
+            | Self::LoadAccountFailed(err)
+            | Self::LoadLibraryFailed(err) => Some(err),
+            Self::ExecuteAccountViewCallFailed(err)
+            | Self::ExecuteTransactionProgramFailed(err) => Some(err),
+            Self::FetchAccountCodeFailed(err) | Self::FetchTransactionInputsFailed(err) => {
+                Some(err)
+            },
+            Self::InvalidAccountViewInputs(err) => Some(err),
+            Self::InvalidAdviceInputs(err) => Some(err),
+            Self::InvalidTransactionArgs(err) => Some(err),
+            Self::InvalidTransactionInputs(err) => Some(err),
+            Self::InvalidTransactionOutput(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 // TRANSACTION PROVER ERROR
 // ================================================================================================
 
+/// Errors which can occur while proving a transaction.
+///
+/// Every variant has a stable numeric code in the `2000` range, see
+/// [TransactionProverError::error_code()].
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum TransactionProverError {
     ProveTransactionProgramFailed(ExecutionError),
     InvalidAccountDelta(AccountError),
+    InvalidAccountVaultDelta(AccountDeltaError),
     InvalidTransactionOutput(TransactionOutputError),
+    ProtocolLimitsExceeded(ProvenTransactionError),
     ProvenTransactionError(ProvenTransactionError),
+    UnsupportedKernelRoot(Digest),
+}
+
+impl TransactionProverError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes are stable across releases: the code of a variant never changes and the codes of
+    /// removed variants are never reused.
+    pub fn error_code(&self) -> u32 {
+        match self {
+            Self::ProveTransactionProgramFailed(_) => 2000,
+            Self::InvalidAccountDelta(_) => 2001,
+            Self::InvalidAccountVaultDelta(_) => 2002,
+            Self::InvalidTransactionOutput(_) => 2003,
+            Self::ProtocolLimitsExceeded(_) => 2004,
+            Self::ProvenTransactionError(_) => 2005,
+            Self::UnsupportedKernelRoot(_) => 2006,
+        }
+    }
 }
 
 impl Display for TransactionProverError {
@@ -86,18 +312,38 @@ impl Display for TransactionProverError {
             TransactionProverError::InvalidAccountDelta(account_error) => {
                 write!(f, "Applying account delta failed: {}", account_error)
             },
+            TransactionProverError::InvalidAccountVaultDelta(inner) => {
+                write!(f, "Account vault delta is invalid for the initial account vault: {}", inner)
+            },
             TransactionProverError::InvalidTransactionOutput(inner) => {
                 write!(f, "Transaction ouptut invalid: {}", inner)
             },
+            TransactionProverError::ProtocolLimitsExceeded(inner) => {
+                write!(f, "Proven transaction exceeds protocol limits: {}", inner)
+            },
             TransactionProverError::ProvenTransactionError(inner) => {
                 write!(f, "Building proven transaction error: {}", inner)
             },
+            TransactionProverError::UnsupportedKernelRoot(root) => {
+                write!(f, "Transaction program was compiled against unsupported kernel {}", root)
+            },
         }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for TransactionProverError {}
+impl std::error::Error for TransactionProverError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ProveTransactionProgramFailed(err) => Some(err),
+            Self::InvalidAccountDelta(err) => Some(err),
+            Self::InvalidAccountVaultDelta(err) => Some(err),
+            Self::InvalidTransactionOutput(err) => Some(err),
+            Self::ProtocolLimitsExceeded(err) | Self::ProvenTransactionError(err) => Some(err),
+            Self::UnsupportedKernelRoot(_) => None,
+        }
+    }
+}
 
 // TRANSACTION VERIFIER ERROR
 // ================================================================================================
@@ -105,12 +351,31 @@ impl std::error::Error for TransactionProverError {}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionVerifierError {
     TransactionVerificationFailed(VerificationError),
-    InsufficientProofSecurityLevel(u32, u32),
+    InsufficientProofSecurityLevel { actual: u32, required: u32 },
+    KernelVersionMismatch(KernelVersion),
+    ProtocolLimitsExceeded(ProvenTransactionError),
+    UnsupportedKernelVersion(KernelVersion),
 }
 
 impl fmt::Display for TransactionVerifierError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::TransactionVerificationFailed(err) => {
+                write!(f, "Transaction verification failed: {err}")
+            },
+            Self::InsufficientProofSecurityLevel { actual, required } => {
+                write!(f, "Proof has security level {actual}, but at least {required} is required")
+            },
+            Self::KernelVersionMismatch(version) => {
+                write!(f, "Program info does not match kernel version {version}")
+            },
+            Self::ProtocolLimitsExceeded(err) => {
+                write!(f, "Transaction exceeds protocol limits: {err}")
+            },
+            Self::UnsupportedKernelVersion(version) => {
+                write!(f, "Kernel version {version} is not supported")
+            },
+        }
     }
 }
 
@@ -120,7 +385,12 @@ impl std::error::Error for TransactionVerifierError {}
 // DATA STORE ERROR
 // ================================================================================================
 
+/// Errors which can occur while fetching data from a [DataStore](crate::DataStore).
+///
+/// Every variant has a stable numeric code in the `3000` range, see
+/// [DataStoreError::error_code()].
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum DataStoreError {
     AccountNotFound(AccountId),
     BlockNotFound(u32),
@@ -130,25 +400,78 @@ pub enum DataStoreError {
     NoteNotFound(NoteId),
 }
 
+impl DataStoreError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes are stable across releases: the code of a variant never changes and the codes of
+    /// removed variants are never reused.
+    pub fn error_code(&self) -> u32 {
+        match self {
+            Self::AccountNotFound(_) => 3000,
+            Self::BlockNotFound(_) => 3001,
+            Self::InvalidTransactionInput(_) => 3002,
+            Self::InternalError(_) => 3003,
+            Self::NoteAlreadyConsumed(_) => 3004,
+            Self::NoteNotFound(_) => 3005,
+        }
+    }
+}
+
 impl fmt::Display for DataStoreError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::AccountNotFound(account_id) => write!(f, "Account {account_id} not found"),
+            Self::BlockNotFound(block_num) => write!(f, "Block {block_num} not found"),
+            Self::InvalidTransactionInput(err) => write!(f, "Invalid transaction input: {err}"),
+            Self::InternalError(msg) => write!(f, "Data store internal error: {msg}"),
+            Self::NoteAlreadyConsumed(note_id) => {
+                write!(f, "Note {note_id} was already consumed")
+            },
+            Self::NoteNotFound(note_id) => write!(f, "Note {note_id} not found"),
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for DataStoreError {}
+impl std::error::Error for DataStoreError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidTransactionInput(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 // AUTHENTICATION ERROR
 // ================================================================================================
 
+/// Errors which can occur while signing a transaction with a
+/// [TransactionAuthenticator](crate::TransactionAuthenticator).
+///
+/// Every variant has a stable numeric code in the `4000` range, see
+/// [AuthenticationError::error_code()].
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum AuthenticationError {
     InternalError(String),
     RejectedSignature(String),
     UnknownKey(String),
 }
 
+impl AuthenticationError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes are stable across releases: the code of a variant never changes and the codes of
+    /// removed variants are never reused.
+    pub fn error_code(&self) -> u32 {
+        match self {
+            Self::InternalError(_) => 4000,
+            Self::RejectedSignature(_) => 4001,
+            Self::UnknownKey(_) => 4002,
+        }
+    }
+}
+
 impl fmt::Display for AuthenticationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -179,7 +502,7 @@ const ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED: u32 = 131078;
 const ERR_SWAP_WRONG_NUMBER_OF_INPUTS: u32 = 131079;
 const ERR_SWAP_WRONG_NUMBER_OF_ASSETS: u32 = 131080;
 const ERR_NONCE_DID_NOT_INCREASE: u32 = 131081;
-const ERR_EPILOGUE_ASSETS_DONT_ADD_UP: u32 = 131082;
+pub(crate) const ERR_EPILOGUE_ASSETS_DONT_ADD_UP: u32 = 131082;
 const ERR_PROLOGUE_GLOBAL_INPUTS_MISMATCH: u32 = 131083;
 const ERR_PROLOGUE_ACCT_STORAGE_MISMATCH: u32 = 131084;
 const ERR_PROLOGUE_ACCT_STORAGE_ARITY_TOO_HIGH: u32 = 131085;
@@ -221,7 +544,7 @@ const ERR_VAULT_FUNGIBLE_AMOUNT_UNDERFLOW: u32 = 131120;
 const ERR_VAULT_REMOVE_FUNGIBLE_ASSET_MISMATCH: u32 = 131121;
 const ERR_VAULT_NON_FUNGIBLE_MISSING_ASSET: u32 = 131122;
 const ERR_FUNGIBLE_ASSET_FORMAT_POSITION_ONE_MUST_BE_ZERO: u32 = 131123;
-const ERR_FUNGIBLE_ASSET_FORMAT_POSITION_TWO_MUST_BE_ZERO: u32 = 131124;
+const ERR_FUNGIBLE_ASSET_FORMAT_POSITION_TWO_MUST_BE_U32: u32 = 131124;
 const ERR_FUNGIBLE_ASSET_FORMAT_POSITION_THREE_MUST_BE_FUNGIBLE_FAUCET_ID: u32 = 131125;
 const ERR_FUNGIBLE_ASSET_FORMAT_POSITION_ZERO_MUST_BE_WITHIN_LIMITS: u32 = 131126;
 const ERR_NON_FUNGIBLE_ASSET_FORMAT_POSITION_ONE_MUST_BE_FUNGIBLE_FAUCET_ID: u32 = 131127;
@@ -240,8 +563,53 @@ const ERR_ASSET_NOT_NON_FUNGIBLE_ID: u32 = 131139;
 const ERR_INVALID_NOTE_TYPE: u32 = 131140;
 const ERR_NOTE_INVALID_TAG_PREFIX_FOR_TYPE: u32 = 131141;
 const ERR_NOTE_TAG_MUST_BE_U32: u32 = 131142;
+const ERR_ASSET_INVALID_SUB_TOKEN_ID: u32 = 131143;
+const ERR_MULTI_FUNGIBLE_UNKNOWN_SUB_TOKEN: u32 = 131144;
+const ERR_MULTI_FUNGIBLE_MAX_SUPPLY_OVERFLOW: u32 = 131145;
+const ERR_MULTI_FUNGIBLE_BURN_OVER_SUPPLY: u32 = 131146;
+const ERR_BASIC_FUNGIBLE_NO_MIGRATION_SUCCESSOR: u32 = 131147;
+const ERR_BASIC_FUNGIBLE_INVALID_VOUCHER_SENDER: u32 = 131148;
+const ERR_MIGRATE_WRONG_NUMBER_OF_INPUTS: u32 = 131149;
+const ERR_MIGRATE_WRONG_NUMBER_OF_ASSETS: u32 = 131150;
+const ERR_MIGRATION_VOUCHER_WRONG_NUMBER_OF_INPUTS: u32 = 131151;
+const ERR_NOTE_FUNGIBLE_MAX_AMOUNT_EXCEEDED: u32 = 131152;
+const ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS: u32 = 131153;
+const ERR_ACCOUNT_PROC_REQUIRES_AUTH: u32 = 131154;
+const ERR_NOTE_INVALID_ARGS: u32 = 131155;
+const ERR_SESSION_KEY_NOT_SET: u32 = 131156;
+const ERR_SESSION_KEY_EXPIRED: u32 = 131157;
+const ERR_SESSION_KEY_ACTION_NOT_ALLOWED: u32 = 131158;
+const ERR_RECOVERY_APPROVAL_WRONG_NUMBER_OF_INPUTS: u32 = 131159;
+const ERR_RECOVERY_APPROVAL_TARGET_ACCT_MISMATCH: u32 = 131160;
+const ERR_RECOVERY_INVALID_PUB_KEY: u32 = 131161;
+const ERR_RECOVERY_SENDER_NOT_GUARDIAN: u32 = 131162;
+const ERR_RECOVERY_ALREADY_APPROVED: u32 = 131163;
+const ERR_RECOVERY_NOT_INITIATED: u32 = 131164;
+const ERR_RECOVERY_NOT_ENOUGH_APPROVALS: u32 = 131165;
+const ERR_RECOVERY_TIMELOCK_NOT_ELAPSED: u32 = 131166;
+const ERR_AUCTION_WRONG_NUMBER_OF_INPUTS: u32 = 131167;
+const ERR_AUCTION_WRONG_NUMBER_OF_ASSETS: u32 = 131168;
+const ERR_AUCTION_BID_TOO_LOW: u32 = 131169;
+const ERR_AUCTION_INVALID_CLAIMANT: u32 = 131170;
+const ERR_CROWDFUND_WRONG_NUMBER_OF_INPUTS: u32 = 131171;
+const ERR_CROWDFUND_WRONG_NUMBER_OF_ASSETS: u32 = 131172;
+const ERR_CROWDFUND_INVALID_CONTRIBUTION: u32 = 131173;
+const ERR_CROWDFUND_TOO_MANY_CONTRIBUTIONS: u32 = 131174;
+const ERR_CROWDFUND_TARGET_REACHED: u32 = 131175;
+const ERR_PROLOGUE_NEW_ACCT_INIT_SALT_NOT_EMPTY: u32 = 131176;
+const ERR_PROLOGUE_SEED_DIGEST_DIFFICULTY_TOO_HIGH: u32 = 131177;
+const ERR_ARRAY_INDEX_OUT_OF_BOUNDS: u32 = 131178;
+const ERR_BASIC_WALLET_ASSET_REJECTED: u32 = 131179;
+const ERR_RECOVERY_ACCOUNT_STATE_CHANGED: u32 = 131180;
+const ERR_RECOVERY_INIT_ACCT_DATA_MISMATCH: u32 = 131181;
+const ERR_MIGRATION_VOUCHER_TARGET_ACCT_MISMATCH: u32 = 131182;
+const ERR_NOTE_INVALID_SCRIPT_ROOT: u32 = 131183;
+const ERR_BASIC_FUNGIBLE_INVALID_VOUCHER_SCRIPT: u32 = 131184;
+const ERR_BASIC_WALLET_SPENDING_LIMIT_EXCEEDED: u32 = 131185;
+const ERR_BASIC_WALLET_NONCE_ALREADY_INCREMENTED: u32 = 131186;
+const ERR_AUCTION_LOT_IS_BID_ASSET: u32 = 131187;
 
-pub const KERNEL_ERRORS: [(u32, &str); 71] = [
+pub const KERNEL_ERRORS: [(u32, &str); 116] = [
     (ERR_FAUCET_RESERVED_DATA_SLOT, "For faucets, storage slot 254 is reserved and can not be used with set_account_item procedure"),
     (ERR_ACCT_MUST_BE_A_FAUCET, "Procedure can only be called from faucet accounts"),
     (ERR_P2ID_WRONG_NUMBER_OF_INPUTS, "P2ID scripts expect exactly 1 note input"),
@@ -270,7 +638,7 @@ pub const KERNEL_ERRORS: [(u32, &str); 71] = [
     (ERR_PROLOGUE_ACCT_ID_MISMATCH, "Provided account ids via global inputs and advice provider do not match"),
     (ERR_PROLOGUE_NOTE_MMR_DIGEST_MISMATCH, "Reference block MMR and note's authentication MMR must match"),
     (ERR_PROLOGUE_NOTE_TOO_MANY_INPUTS, "Number of note inputs exceeded the maximum limit of 128"),
-    (ERR_PROLOGUE_NOTE_TOO_MANY_ASSETS, "Number of note assets exceeded the maximum limit of 256"),
+    (ERR_PROLOGUE_NOTE_TOO_MANY_ASSETS, "Number of note assets exceeded the maximum limit of 255"),
     (ERR_PROLOGUE_NOTE_CONSUMED_ASSETS_MISMATCH, "Provided info about assets of an input do not match its commitment"),
     (ERR_PROLOGUE_TOO_MANY_INPUT_NOTES, "Number of input notes exceeded the kernel's maximum limit of 1023"),
     (ERR_PROLOGUE_INPUT_NOTES_NULLIFIER_COMMITMENT_MISMATCH, "Cannot compute matching nullifier commitment using the provided input note data"),
@@ -294,7 +662,7 @@ pub const KERNEL_ERRORS: [(u32, &str); 71] = [
     (ERR_VAULT_REMOVE_FUNGIBLE_ASSET_MISMATCH, "Removing the asset from the account vault failed, something is wrong with the current value before the update"),
     (ERR_VAULT_NON_FUNGIBLE_MISSING_ASSET, "Removing inexistent non-fungible asset"),
     (ERR_FUNGIBLE_ASSET_FORMAT_POSITION_ONE_MUST_BE_ZERO, "Malformed fungible asset; ASSET[1] must be 0"),
-    (ERR_FUNGIBLE_ASSET_FORMAT_POSITION_TWO_MUST_BE_ZERO, "Malformed fungible asset; ASSET[2] must be 0"),
+    (ERR_FUNGIBLE_ASSET_FORMAT_POSITION_TWO_MUST_BE_U32, "Malformed fungible asset; ASSET[2] must be a valid u32 sub-token id"),
     (ERR_FUNGIBLE_ASSET_FORMAT_POSITION_THREE_MUST_BE_FUNGIBLE_FAUCET_ID, "Malformed fungible asset; ASSET[3] must be a valide fungible faucet id"),
     (ERR_FUNGIBLE_ASSET_FORMAT_POSITION_ZERO_MUST_BE_WITHIN_LIMITS, "Malformed fungible asset; ASSET[0] exceeds the maximum allowed amount"),
     (ERR_NON_FUNGIBLE_ASSET_FORMAT_POSITION_ONE_MUST_BE_FUNGIBLE_FAUCET_ID, "Malformed non-fungible asset; ASSET[1] is not a valid non-fungible faucet id"),
@@ -313,4 +681,117 @@ pub const KERNEL_ERRORS: [(u32, &str); 71] = [
     (ERR_INVALID_NOTE_TYPE, "Invalid note type"),
     (ERR_NOTE_INVALID_TAG_PREFIX_FOR_TYPE, "The note's tag failed the most significant validation"),
     (ERR_NOTE_TAG_MUST_BE_U32, "The note's tag high bits must be set to 0"),
+    (ERR_ASSET_INVALID_SUB_TOKEN_ID, "Can not build the fungible asset because the sub-token id is not a valid u32 value"),
+    (ERR_MULTI_FUNGIBLE_UNKNOWN_SUB_TOKEN, "The sub-token is not registered in the faucet"),
+    (ERR_MULTI_FUNGIBLE_MAX_SUPPLY_OVERFLOW, "Distribute would cause the max supply of the sub-token to be exceeded"),
+    (ERR_MULTI_FUNGIBLE_BURN_OVER_SUPPLY, "Burn would cause the issued supply of the sub-token to become negative"),
+    (ERR_BASIC_FUNGIBLE_NO_MIGRATION_SUCCESSOR, "The faucet has no successor to migrate its tokens to"),
+    (ERR_BASIC_FUNGIBLE_INVALID_VOUCHER_SENDER, "The migration voucher was not issued by the predecessor of the faucet"),
+    (ERR_MIGRATE_WRONG_NUMBER_OF_INPUTS, "MIGRATE script expects exactly 12 note inputs"),
+    (ERR_MIGRATE_WRONG_NUMBER_OF_ASSETS, "MIGRATE script requires exactly 1 note asset"),
+    (ERR_MIGRATION_VOUCHER_WRONG_NUMBER_OF_INPUTS, "MIGRATION_VOUCHER script expects exactly 8 note inputs"),
+    (ERR_NOTE_FUNGIBLE_MAX_AMOUNT_EXCEEDED, "Adding a fungible asset to a note cannot exceed the max_amount of 9223372036854775807"),
+    (ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS, "Non-fungible asset that already exists in the note cannot be added again"),
+    (ERR_ACCOUNT_PROC_REQUIRES_AUTH, "Account procedure requiring authentication cannot be invoked while a note is being executed"),
+    (ERR_NOTE_INVALID_ARGS, "Trying to access note args from incorrect context"),
+    (ERR_SESSION_KEY_NOT_SET, "The transaction is authenticated by a session key but no session key is registered"),
+    (ERR_SESSION_KEY_EXPIRED, "The session key authenticating the transaction has expired"),
+    (ERR_SESSION_KEY_ACTION_NOT_ALLOWED, "The transaction exceeds the spending cap of the session, sends a non-fungible asset or manages sessions, and can only be authenticated by the main key"),
+    (ERR_RECOVERY_APPROVAL_WRONG_NUMBER_OF_INPUTS, "RECOVERY_APPROVAL script expects exactly 5 note inputs"),
+    (ERR_RECOVERY_APPROVAL_TARGET_ACCT_MISMATCH, "RECOVERY_APPROVAL's target account and transaction account do not match"),
+    (ERR_RECOVERY_INVALID_PUB_KEY, "The public key approved for recovery must not be empty"),
+    (ERR_RECOVERY_SENDER_NOT_GUARDIAN, "The recovery approval was not sent by a guardian of the account"),
+    (ERR_RECOVERY_ALREADY_APPROVED, "The guardian has already approved the pending recovery"),
+    (ERR_RECOVERY_NOT_INITIATED, "No recovery of the account is pending"),
+    (ERR_RECOVERY_NOT_ENOUGH_APPROVALS, "The pending recovery has not been approved by enough guardians"),
+    (ERR_RECOVERY_TIMELOCK_NOT_ELAPSED, "The timelock of the pending recovery has not elapsed"),
+    (ERR_AUCTION_WRONG_NUMBER_OF_INPUTS, "AUCTION script expects exactly 12 note inputs"),
+    (ERR_AUCTION_WRONG_NUMBER_OF_ASSETS, "AUCTION script requires the lot and, once a bid was placed, the highest bid as note assets"),
+    (ERR_AUCTION_BID_TOO_LOW, "The bid does not reach the reserve price or does not exceed the highest bid"),
+    (ERR_AUCTION_INVALID_CLAIMANT, "After the auction ended, it can only be settled by the seller or the highest bidder"),
+    (ERR_CROWDFUND_WRONG_NUMBER_OF_INPUTS, "CROWDFUND script expects 12 note inputs followed by 4 note inputs per contribution"),
+    (ERR_CROWDFUND_WRONG_NUMBER_OF_ASSETS, "CROWDFUND script requires the contributed funds as the only note asset once a contribution was made"),
+    (ERR_CROWDFUND_INVALID_CONTRIBUTION, "The amount of a contribution must not be zero nor below the minimum contribution"),
+    (ERR_CROWDFUND_TOO_MANY_CONTRIBUTIONS, "The campaign holds the maximum number of contributions"),
+    (ERR_CROWDFUND_TARGET_REACHED, "The funds of a campaign which exceed its target can only be claimed by the beneficiary"),
+    (ERR_PROLOGUE_NEW_ACCT_INIT_SALT_NOT_EMPTY, "The state commitment of a new account cannot be salted as it was not recorded on-chain"),
+    (ERR_PROLOGUE_SEED_DIGEST_DIFFICULTY_TOO_HIGH, "The proof-of-work difficulty of new account seeds exceeds the maximum of 31 trailing zeros"),
+    (ERR_ARRAY_INDEX_OUT_OF_BOUNDS, "The index of the array element is not smaller than the length of the array"),
+    (ERR_BASIC_WALLET_ASSET_REJECTED, "The faucet of the received asset is rejected by the asset filter of the wallet"),
+    (ERR_RECOVERY_ACCOUNT_STATE_CHANGED, "The account state was changed other than by the recovery in a transaction updating the recovery"),
+    (ERR_RECOVERY_INIT_ACCT_DATA_MISMATCH, "The initial account data provided via the advice map does not match the initial account hash"),
+    (ERR_MIGRATION_VOUCHER_TARGET_ACCT_MISMATCH, "MIGRATION_VOUCHER's successor faucet and transaction account do not match"),
+    (ERR_NOTE_INVALID_SCRIPT_ROOT, "Trying to access note script root from incorrect context"),
+    (ERR_BASIC_FUNGIBLE_INVALID_VOUCHER_SCRIPT, "The migration voucher is not a MIGRATION_VOUCHER note"),
+    (ERR_BASIC_WALLET_SPENDING_LIMIT_EXCEEDED, "The amount of the sent asset exceeds the remaining spending limit of the current period for its faucet"),
+    (ERR_BASIC_WALLET_NONCE_ALREADY_INCREMENTED, "The wallet state cannot be changed after the nonce of the account has been incremented"),
+    (ERR_AUCTION_LOT_IS_BID_ASSET, "The lot cannot be bid on as it is the asset accepted as bids"),
 ];
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString};
+    use std::error::Error;
+
+    use miden_objects::accounts::account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN;
+
+    use super::*;
+
+    #[test]
+    fn error_codes_and_sources() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+
+        let data_store_error = DataStoreError::AccountNotFound(account_id);
+        assert_eq!(data_store_error.error_code(), 3000);
+        assert!(data_store_error.source().is_none());
+
+        let executor_error =
+            TransactionExecutorError::FetchTransactionInputsFailed(data_store_error.clone());
+        assert_eq!(executor_error.error_code(), 1008);
+        let source = executor_error.source().unwrap().downcast_ref::<DataStoreError>();
+        assert_eq!(source, Some(&data_store_error));
+
+        let executor_error = TransactionExecutorError::NewAccountVaultNotEmpty(account_id);
+        assert_eq!(executor_error.error_code(), 1021);
+        assert!(executor_error.source().is_none());
+
+        let kernel_error =
+            TransactionKernelError::DuplicateOutputNote(NoteId::from(Digest::default()));
+        let executor_error = TransactionExecutorError::CheckOutputNotesFailed(kernel_error.clone());
+        assert_eq!(executor_error.error_code(), 1024);
+        let source = executor_error.source().unwrap().downcast_ref::<TransactionKernelError>();
+        assert_eq!(source, Some(&kernel_error));
+
+        let prover_error = TransactionProverError::UnsupportedKernelRoot(Digest::default());
+        assert_eq!(prover_error.error_code(), 2006);
+        assert!(prover_error.source().is_none());
+
+        let auth_error = AuthenticationError::UnknownKey("key".into());
+        assert_eq!(auth_error.error_code(), 4002);
+    }
+
+    #[test]
+    fn error_messages() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+
+        let executor_error = TransactionExecutorError::FetchTransactionInputsFailed(
+            DataStoreError::AccountNotFound(account_id),
+        );
+        assert_eq!(
+            executor_error.to_string(),
+            format!("Failed to fetch transaction inputs: Account {account_id} not found")
+        );
+
+        let verifier_error =
+            TransactionVerifierError::InsufficientProofSecurityLevel { actual: 90, required: 96 };
+        assert_eq!(
+            verifier_error.to_string(),
+            "Proof has security level 90, but at least 96 is required"
+        );
+    }
+}
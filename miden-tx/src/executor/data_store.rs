@@ -1,7 +1,13 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
 use miden_objects::{
-    accounts::AccountId, assembly::ModuleAst, notes::NoteId, transaction::TransactionInputs,
+    accounts::{Account, AccountId},
+    assembly::ModuleAst,
+    notes::NoteId,
+    transaction::{ChainMmr, InputNote, InputNotes, TransactionInputs},
+    BlockHeader, Word,
 };
-use winter_maybe_async::maybe_async;
+use winter_maybe_async::{maybe_async, maybe_await};
 
 use crate::DataStoreError;
 
@@ -10,7 +16,49 @@ use crate::DataStoreError;
 
 /// The [DataStore] trait defines the interface that transaction objects use to fetch data
 /// required for transaction execution.
+///
+/// The account, block, chain and note data is fetched via separate methods, which are combined
+/// into [TransactionInputs] by [DataStore::get_transaction_inputs()]. This allows data stores to
+/// cache the data which does not change between consecutive transactions, e.g., the chain MMR,
+/// and to fetch only the data which did.
 pub trait DataStore {
+    /// Returns the account with the specified ID, together with the seed of the account if the
+    /// account is new.
+    ///
+    /// # Errors
+    /// Returns an error if the account could not be found in the data store.
+    #[maybe_async]
+    fn get_account(&self, account_id: AccountId)
+        -> Result<(Account, Option<Word>), DataStoreError>;
+
+    /// Returns the header of the block with the specified number.
+    ///
+    /// # Errors
+    /// Returns an error if the block could not be found in the data store.
+    #[maybe_async]
+    fn get_block_header(&self, block_num: u32) -> Result<BlockHeader, DataStoreError>;
+
+    /// Returns the chain MMR as of the block with number `block_ref`, i.e., the MMR of all blocks
+    /// preceding this block, which tracks the headers of all specified blocks.
+    ///
+    /// All specified blocks precede the reference block.
+    ///
+    /// # Errors
+    /// Returns an error if any of the blocks could not be found in the data store.
+    #[maybe_async]
+    fn get_chain_mmr(&self, block_ref: u32, blocks: &[u32]) -> Result<ChainMmr, DataStoreError>;
+
+    /// Returns the notes with the specified IDs, in the order of the IDs.
+    ///
+    /// The inclusion proofs of authenticated notes must be valid in the context of the block
+    /// referenced by the transaction.
+    ///
+    /// # Errors
+    /// Returns an error if any of the notes could not be found in the data store or was already
+    /// consumed.
+    #[maybe_async]
+    fn get_notes(&self, note_ids: &[NoteId]) -> Result<Vec<InputNote>, DataStoreError>;
+
     /// Returns account, chain, and input note data required to execute a transaction against
     /// the account with the specified ID and consuming the set of specified input notes.
     ///
@@ -18,6 +66,11 @@ pub trait DataStore {
     /// recorded in the chain. In general, it is recommended that bock_ref corresponds to the
     /// latest block available in the data store.
     ///
+    /// By default, the inputs are combined from the data returned by the other methods of this
+    /// trait, where the chain MMR tracks the blocks in which the authenticated input notes were
+    /// created and, for new accounts, the anchor block of the account ID. Data stores which
+    /// describe the account vault via a partial vault need to override this method.
+    ///
     /// # Errors
     /// Returns an error if:
     /// - The account with the specified ID could not be found in the data store.
@@ -32,7 +85,28 @@ pub trait DataStore {
         account_id: AccountId,
         block_ref: u32,
         notes: &[NoteId],
-    ) -> Result<TransactionInputs, DataStoreError>;
+    ) -> Result<TransactionInputs, DataStoreError> {
+        let (account, account_seed) = maybe_await!(self.get_account(account_id))?;
+        let block_header = maybe_await!(self.get_block_header(block_ref))?;
+        let input_notes = maybe_await!(self.get_notes(notes))?;
+
+        // the reference block is not tracked by the chain MMR as it is not part of it
+        let mut blocks = input_notes
+            .iter()
+            .filter_map(|note| note.origin().map(|origin| origin.block_num))
+            .collect::<BTreeSet<_>>();
+        if let (true, Some(epoch)) = (account.is_new(), account_id.anchor_epoch()) {
+            blocks.insert(BlockHeader::epoch_block_num(epoch));
+        }
+        blocks.remove(&block_ref);
+        let blocks = blocks.into_iter().collect::<Vec<_>>();
+        let block_chain = maybe_await!(self.get_chain_mmr(block_ref, &blocks))?;
+
+        let input_notes =
+            InputNotes::new(input_notes).map_err(DataStoreError::InvalidTransactionInput)?;
+        TransactionInputs::new(account, account_seed, block_header, block_chain, input_notes)
+            .map_err(DataStoreError::InvalidTransactionInput)
+    }
 
     /// Returns the account code [ModuleAst] associated with the specified [AccountId].
     #[maybe_async]
@@ -1,25 +1,37 @@
-use alloc::{rc::Rc, vec::Vec};
+use alloc::{collections::BTreeMap, rc::Rc, vec::Vec};
+use core::cell::RefCell;
 
-use miden_lib::transaction::{ToTransactionKernelInputs, TransactionKernel};
+use miden_lib::transaction::{memory::FAUCET_STORAGE_DATA_SLOT, TransactionKernel};
 use miden_objects::{
-    assembly::ProgramAst,
-    transaction::{TransactionArgs, TransactionInputs, TransactionScript},
-    vm::{Program, StackOutputs},
-    Felt, Word, ZERO,
+    accounts::{Account, AccountType, NetworkParams, StorageMap, StorageSlotType},
+    assembly::{Library, ModuleAst, ProgramAst},
+    block::BlockHeader,
+    transaction::{
+        AdviceInputsBuilder, ChainMmr, InputNote, InputNotes, KernelVersion, TransactionArgs,
+        TransactionInputs, TransactionScript,
+    },
+    vm::{Program, StackInputs, StackOutputs},
+    Felt, Word, EMPTY_WORD, ZERO,
 };
-use vm_processor::ExecutionOptions;
+use vm_processor::{ExecutionOptions, MemAdviceProvider};
 use winter_maybe_async::{maybe_async, maybe_await};
 
 use super::{
     AccountCode, AccountId, Digest, ExecutedTransaction, NoteId, NoteScript, PreparedTransaction,
-    RecAdviceProvider, ScriptTarget, TransactionCompiler, TransactionExecutorError,
-    TransactionHost,
+    RecAdviceProvider, ScriptTarget, TransactionCompiler, TransactionCompilerError,
+    TransactionExecutorError, TransactionHost,
+};
+use crate::{
+    host::{AccountProcedureIndexMap, AccountViewHost, DebugSink, TransactionAuthenticator},
+    KernelCoverage, NoteSkipReason,
 };
-use crate::host::TransactionAuthenticator;
 
 mod data_store;
 pub use data_store::DataStore;
 
+mod note_screener;
+use note_screener::NoteScreener;
+
 // TRANSACTION EXECUTOR
 // ================================================================================================
 
@@ -41,6 +53,11 @@ pub struct TransactionExecutor<D, A> {
     authenticator: Option<Rc<A>>,
     compiler: TransactionCompiler,
     exec_options: ExecutionOptions,
+    advice_size_limit: Option<usize>,
+    network_params: NetworkParams,
+    debug_sink: Option<Rc<dyn DebugSink>>,
+    kernel_coverage: Option<Rc<KernelCoverage>>,
+    proc_index_maps: RefCell<BTreeMap<Digest, Rc<AccountProcedureIndexMap>>>,
 }
 
 impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
@@ -54,7 +71,57 @@ impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
             authenticator,
             compiler: TransactionCompiler::new(),
             exec_options: ExecutionOptions::default(),
+            advice_size_limit: None,
+            network_params: NetworkParams::default(),
+            debug_sink: None,
+            kernel_coverage: None,
+            proc_index_maps: RefCell::default(),
+        }
+    }
+
+    /// Creates a new [TransactionExecutor] instance which executes transactions only if the version
+    /// of its transaction kernel is in the provided set of supported kernel versions.
+    ///
+    /// This allows a network which has rolled its kernel forward to reject executors built
+    /// against kernels it no longer accepts, before any transaction is executed.
+    ///
+    /// # Errors
+    /// Returns an error if the version of the transaction kernel used by the executor is not in
+    /// the provided set.
+    pub fn with_supported_kernels<I>(
+        data_store: D,
+        authenticator: Option<Rc<A>>,
+        supported_versions: I,
+    ) -> Result<Self, TransactionExecutorError>
+    where
+        I: IntoIterator<Item = KernelVersion>,
+    {
+        let executor = Self::new(data_store, authenticator);
+        let kernel_version = executor.kernel_version();
+        if !supported_versions.into_iter().any(|version| version == kernel_version) {
+            return Err(TransactionExecutorError::UnsupportedKernelVersion(kernel_version));
         }
+
+        Ok(executor)
+    }
+
+    /// Loads the provided MASM library into the [TransactionExecutor].
+    ///
+    /// Modules of the library can then be imported by note scripts and transaction scripts
+    /// compiled and executed by this executor. See [TransactionCompiler::with_library()] for
+    /// details.
+    ///
+    /// # Errors
+    /// Returns an error if the library can not be loaded into the transaction compiler.
+    pub fn with_library<L: Library>(
+        mut self,
+        library: &L,
+    ) -> Result<Self, TransactionExecutorError> {
+        self.compiler = self
+            .compiler
+            .with_library(library)
+            .map_err(TransactionExecutorError::LoadLibraryFailed)?;
+        Ok(self)
     }
 
     /// Puts the [TransactionExecutor] into debug mode.
@@ -81,16 +148,72 @@ impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
         self
     }
 
+    /// Puts the [TransactionExecutor] into debug mode and routes the output of the `debug`
+    /// instructions to the provided sink.
+    ///
+    /// The output is reported together with the ID of the note being executed, if any. Only
+    /// instructions in code compiled by this executor (e.g., transaction scripts compiled via
+    /// [TransactionExecutor::compile_tx_script()]) and in note scripts assembled in debug mode are
+    /// retained; note scripts assembled without debug mode do not contain `debug` instructions.
+    pub fn with_debug_sink(self, debug_sink: Rc<dyn DebugSink>) -> Self {
+        let mut executor = self.with_debug_mode(true);
+        executor.debug_sink = Some(debug_sink);
+        executor
+    }
+
+    /// Records the kernel procedures executed by the transactions of this executor in the provided
+    /// [KernelCoverage].
+    ///
+    /// Only successfully executed transactions are recorded. The same coverage can be provided to
+    /// several executors to accumulate the coverage of a whole test suite.
+    pub fn with_kernel_coverage(mut self, kernel_coverage: Rc<KernelCoverage>) -> Self {
+        self.kernel_coverage = Some(kernel_coverage);
+        self
+    }
+
     /// Enables tracing for the created instance of [TransactionExecutor].
     ///
     /// When tracing is enabled, the executor will receive tracing events as various stages of the
     /// transaction kernel complete. This enables collecting basic stats about how long different
     /// stages of transaction execution take.
+    ///
+    /// This is independent of the `tracing` feature: the spans covering the stages of the
+    /// transaction kernel are emitted only if both are enabled.
     pub fn with_tracing(mut self) -> Self {
         self.exec_options = self.exec_options.with_tracing();
         self
     }
 
+    /// Sets the maximum size (in bytes) of the advice data of the transactions executed by the
+    /// created instance of [TransactionExecutor].
+    ///
+    /// The size of the advice data is estimated before a transaction is executed (see
+    /// [AdviceInputsSize::num_bytes()](miden_objects::transaction::AdviceInputsSize::num_bytes)),
+    /// and transactions exceeding the limit are rejected without being executed. By default, the
+    /// size of the advice data is not limited.
+    pub fn with_advice_size_limit(mut self, num_bytes: usize) -> Self {
+        self.advice_size_limit = Some(num_bytes);
+        self
+    }
+
+    /// Sets the [NetworkParams] of the network the transactions executed by the created instance
+    /// of [TransactionExecutor] are meant for. By default, [NetworkParams::MAINNET] is used.
+    ///
+    /// The parameters are public inputs of the transaction kernel, and thus the executed
+    /// transactions must be proven and verified with the same parameters.
+    pub fn with_network_params(mut self, network_params: NetworkParams) -> Self {
+        self.network_params = network_params;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the version of the transaction kernel used to execute transactions.
+    pub fn kernel_version(&self) -> KernelVersion {
+        self.compiler.kernel_version()
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -115,6 +238,55 @@ impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
             .map_err(TransactionExecutorError::LoadAccountFailed)
     }
 
+    /// Compiles the code of the provided account and loads the compiled code into the internal
+    /// cache.
+    ///
+    /// Unlike [TransactionExecutor::load_account()], the account code is taken from the provided
+    /// account rather than fetched from the [DataStore]. This is used for accounts which have not
+    /// been created yet, and thus are not known to the [DataStore].
+    ///
+    /// # Errors:
+    /// Returns an error if the account code fails to be loaded into the compiler.
+    pub fn load_new_account(
+        &mut self,
+        account: &Account,
+    ) -> Result<AccountCode, TransactionExecutorError> {
+        self.compiler
+            .load_account(account.id(), account.code().module().clone())
+            .map_err(TransactionExecutorError::LoadAccountFailed)
+    }
+
+    /// Compiles the provided account code module and caches the compiled code under the specified
+    /// code root.
+    ///
+    /// Accounts whose code was built from the same module can then be loaded via
+    /// [TransactionExecutor::load_account()] without assembling the module again. The map of
+    /// account procedures used by the transaction host is cached by code root as well, so that
+    /// repeated transactions against accounts with the same code do not need to rebuild it from
+    /// the advice data.
+    ///
+    /// # Errors:
+    /// Returns an error if the account code fails to be compiled.
+    pub fn preload_account_code(
+        &mut self,
+        code_root: Digest,
+        module: ModuleAst,
+    ) -> Result<AccountCode, TransactionExecutorError> {
+        let account_code = self
+            .compiler
+            .preload_account_code(code_root, module)
+            .map_err(TransactionExecutorError::LoadAccountFailed)?;
+
+        // the procedure map can be derived from the compiled code only if none of the procedures
+        // require authentication (i.e., the compiled code commits to the provided root); otherwise
+        // it is built from the account code on the first transaction against the account
+        if account_code.root() == code_root {
+            self.procedure_index_map(&account_code);
+        }
+
+        Ok(account_code)
+    }
+
     /// Loads the provided account interface (vector of procedure digests) into the compiler.
     ///
     /// Returns the old interface for the specified account ID if it previously existed.
@@ -159,6 +331,79 @@ impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
             .map_err(TransactionExecutorError::CompileTransactionScriptFailed)
     }
 
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that the transaction described by the provided inputs and arguments would pass the
+    /// transaction kernel prologue, without compiling or executing the transaction.
+    ///
+    /// This performs in Rust the checks which the prologue enforces on the transaction inputs:
+    /// - The chain MMR, the account seed, and the authenticated input notes are consistent with
+    ///   the referenced block (see [TransactionInputs::validate()]). The limits on the number of
+    ///   input notes, and on the number of inputs and assets of each note, are enforced by the
+    ///   types of the transaction inputs.
+    /// - The seed of a new account satisfies the proof-of-work difficulty of the network
+    ///   parameters of this executor.
+    /// - A new account has an empty vault and, for faucets, a correctly initialized reserved
+    ///   faucet storage slot.
+    /// - The initial account salt is not set for a new account.
+    ///
+    /// Additionally, this checks the conditions which this executor checks before executing a
+    /// transaction, i.e., that note args are provided for all notes which require them, that
+    /// account salts are set only for off-chain accounts, that the advice map of the transaction
+    /// arguments does not conflict with the transaction inputs, and that the size of the advice
+    /// data does not exceed the limit configured for this executor.
+    ///
+    /// Passing these checks does not guarantee that the transaction executes successfully, as
+    /// note scripts, the transaction script, and account procedures can still fail.
+    ///
+    /// Checking note args requires the note scripts to be compiled, and thus the code of the
+    /// account must have been loaded into the executor, as it must be for executing the
+    /// transaction.
+    ///
+    /// # Errors:
+    /// Returns an error if any of the above checks fails.
+    pub fn validate_inputs(
+        &self,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+    ) -> Result<(), TransactionExecutorError> {
+        tx_inputs
+            .validate()
+            .and_then(|_| tx_inputs.validate_account_seed(&self.network_params))
+            .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
+
+        let account = tx_inputs.account();
+        if account.is_new() {
+            if !account.vault().is_empty() {
+                return Err(TransactionExecutorError::NewAccountVaultNotEmpty(account.id()));
+            }
+
+            let reserved_slot_valid = match account.account_type() {
+                AccountType::FungibleFaucet => {
+                    account.storage().get_item(FAUCET_STORAGE_DATA_SLOT) == Digest::default()
+                        && account.storage().layout()[FAUCET_STORAGE_DATA_SLOT as usize]
+                            == StorageSlotType::Value { value_arity: 0 }
+                },
+                AccountType::NonFungibleFaucet => {
+                    account.storage().get_item(FAUCET_STORAGE_DATA_SLOT) == StorageMap::new().root()
+                        && account.storage().layout()[FAUCET_STORAGE_DATA_SLOT as usize]
+                            == StorageSlotType::Map { value_arity: 0 }
+                },
+                _ => true,
+            };
+            if !reserved_slot_valid {
+                return Err(TransactionExecutorError::InvalidFaucetReservedSlot(account.id()));
+            }
+        }
+
+        validate_account_salts(account, tx_args)?;
+        self.validate_note_args(tx_inputs.input_notes(), tx_args)?;
+        self.build_advice_inputs(tx_inputs, tx_args)?;
+
+        Ok(())
+    }
+
     // TRANSACTION EXECUTION
     // --------------------------------------------------------------------------------------------
 
@@ -172,9 +417,20 @@ impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
     /// # Errors:
     /// Returns an error if:
     /// - If required data can not be fetched from the [DataStore].
+    /// - If note args are missing for any of the notes which require them.
     /// - If the transaction program can not be compiled.
+    /// - If the advice map of the transaction arguments conflicts with the transaction inputs.
+    /// - If the size of the advice data exceeds the limit configured for this executor.
     /// - If the transaction program can not be executed.
     #[maybe_async]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "info",
+            skip_all,
+            fields(account_id = %account_id, block_ref = block_ref, num_notes = notes.len())
+        )
+    )]
     pub fn execute_transaction(
         &self,
         account_id: AccountId,
@@ -185,31 +441,197 @@ impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
         let transaction =
             maybe_await!(self.prepare_transaction(account_id, block_ref, notes, tx_args))?;
 
-        let (stack_inputs, advice_inputs) = transaction.get_kernel_inputs();
-        let advice_recorder: RecAdviceProvider = advice_inputs.into();
-        let mut host = TransactionHost::new(
-            transaction.account().into(),
-            advice_recorder,
-            self.authenticator.clone(),
-        );
+        self.execute_prepared_transaction(transaction)
+    }
 
-        let result = vm_processor::execute(
-            transaction.program(),
-            stack_inputs,
-            &mut host,
-            self.exec_options,
-        )
-        .map_err(TransactionExecutorError::ExecuteTransactionProgramFailed)?;
+    /// Executes a transaction consuming all notes out of the provided candidate notes which can
+    /// be consumed by the specified account, and returns the [ExecutedTransaction] together with
+    /// the notes which were skipped and the reasons for skipping them.
+    ///
+    /// The candidate notes are screened by static checks against the account before the
+    /// transaction is compiled:
+    /// - P2ID notes must target the account. P2IDR notes must target the account, or be reclaimed
+    ///   by their sender once the recall height is reached by the referenced block.
+    /// - Note scripts must not call procedures which are not exposed by the account.
+    /// - Note args must be provided for all notes whose scripts require them.
+    ///
+    /// Notes passing these checks can still fail during execution, in which case the whole
+    /// transaction fails.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If none of the candidate notes can be consumed by the account.
+    /// - If the transaction program can not be compiled or executed.
+    #[maybe_async]
+    pub fn execute_claimable_notes(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<(ExecutedTransaction, Vec<(NoteId, NoteSkipReason)>), TransactionExecutorError>
+    {
+        let tx_inputs =
+            maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
+                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
 
-        let (tx_program, tx_inputs, tx_args) = transaction.into_parts();
+        let screener = NoteScreener::new().map_err(|err| {
+            TransactionExecutorError::CompileNoteScriptFailed(
+                TransactionCompilerError::NoteScriptError(err),
+            )
+        })?;
+        // the partial vault is discarded by `into_parts()`, and thus, needs to be kept separately
+        let partial_vault = tx_inputs.partial_vault().cloned();
+        let (account, account_seed, block_header, block_chain, input_notes) =
+            tx_inputs.into_parts();
+
+        let mut claimable_notes = Vec::new();
+        let mut skipped_notes = Vec::new();
+        for input_note in input_notes.into_vec() {
+            let note = input_note.note();
+            match screener.check_note(&self.compiler, &account, block_ref, note, &tx_args) {
+                Ok(()) => claimable_notes.push(input_note),
+                Err(reason) => skipped_notes.push((note.id(), reason)),
+            }
+        }
+        if claimable_notes.is_empty() {
+            return Err(TransactionExecutorError::NoClaimableNotes(skipped_notes));
+        }
 
-        build_executed_transaction(
-            tx_program,
-            tx_args,
-            tx_inputs,
-            result.stack_outputs().clone(),
-            host,
+        let input_notes = InputNotes::new(claimable_notes)
+            .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
+        let tx_inputs = match partial_vault {
+            Some(partial_vault) => TransactionInputs::with_partial_vault(
+                account,
+                partial_vault,
+                account_seed,
+                block_header,
+                block_chain,
+                input_notes,
+            ),
+            None => TransactionInputs::new(
+                account,
+                account_seed,
+                block_header,
+                block_chain,
+                input_notes,
+            ),
+        }
+        .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
+
+        let transaction = self.compile_transaction_inputs(tx_inputs, tx_args)?;
+        let executed_transaction = self.execute_prepared_transaction(transaction)?;
+
+        Ok((executed_transaction, skipped_notes))
+    }
+
+    /// Executes a transaction which creates the provided new account and returns an
+    /// [ExecutedTransaction].
+    ///
+    /// Unlike [TransactionExecutor::execute_transaction()], the transaction inputs are not fetched
+    /// from the [DataStore] (which does not know about accounts which have not been created yet),
+    /// but are built from the provided data as follows:
+    /// - The code of the account is loaded into the executor.
+    /// - The account seed is included into the transaction inputs so that the transaction kernel
+    ///   can verify that the ID of the account was derived from it.
+    /// - If a funding note is provided, it is consumed by the transaction. The funding note is the
+    ///   only way to put assets into the vault of the new account.
+    ///
+    /// The account must be new (i.e., its nonce must be zero) and its vault must be empty. The
+    /// provided transaction arguments must make sure that the nonce of the account is incremented
+    /// (e.g., by executing a transaction script which invokes the authentication procedure of the
+    /// account), as an account is considered to be created only once its nonce is non-zero. Since
+    /// account deltas consisting only of a nonce update are not valid, the transaction must also
+    /// modify the storage or the vault of the account (e.g., by consuming the funding note). A
+    /// transaction script invoking procedures of the new account can be compiled only after the
+    /// code of the account was loaded via [TransactionExecutor::load_new_account()].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the vault of the account is not empty.
+    /// - If the account is not new, or the account seed is invalid for the account.
+    /// - If the funding note is not consistent with the provided block header and chain MMR.
+    /// - If the account code fails to be loaded into the compiler.
+    /// - If the transaction can not be compiled or executed.
+    /// - If the nonce of the account was not incremented by the transaction.
+    pub fn create_account_transaction(
+        &mut self,
+        account: Account,
+        account_seed: Word,
+        block_header: BlockHeader,
+        block_chain: ChainMmr,
+        funding_note: Option<InputNote>,
+        tx_args: TransactionArgs,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let account_id = account.id();
+        if !account.vault().is_empty() {
+            return Err(TransactionExecutorError::NewAccountVaultNotEmpty(account_id));
+        }
+
+        let input_notes = InputNotes::new(funding_note.into_iter().collect())
+            .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
+        let tx_inputs = TransactionInputs::new(
+            account,
+            Some(account_seed),
+            block_header,
+            block_chain,
+            input_notes,
         )
+        .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
+
+        self.load_new_account(tx_inputs.account())?;
+
+        let transaction = self.compile_transaction_inputs(tx_inputs, tx_args)?;
+        let executed_transaction = self.execute_prepared_transaction(transaction)?;
+
+        if executed_transaction.final_account().nonce() == ZERO {
+            return Err(TransactionExecutorError::NewAccountNonceNotIncremented(account_id));
+        }
+
+        Ok(executed_transaction)
+    }
+
+    // ACCOUNT VIEW CALLS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the specified procedure of the provided account in a read-only context and
+    /// returns the resulting stack outputs.
+    ///
+    /// The procedure is executed with the account data (storage, vault, and code) provided via
+    /// the advice provider, and the provided inputs placed on the operand stack such that the
+    /// first input is the top of the stack. Neither the transaction prologue nor the epilogue are
+    /// executed, and no transaction is created; thus, the nonce of the account is not incremented.
+    ///
+    /// The account code is assumed to have been previously loaded into the executor.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the view call program can not be compiled, e.g., because the procedure is not a part
+    ///   of the account interface.
+    /// - If more than 16 inputs were provided.
+    /// - If the procedure can not be executed, e.g., because it attempts to modify the account
+    ///   state.
+    pub fn execute_account_view_call(
+        &self,
+        account: &Account,
+        proc_root: Digest,
+        inputs: &[Felt],
+    ) -> Result<StackOutputs, TransactionExecutorError> {
+        let program = self
+            .compiler
+            .compile_account_view_call(account.id(), proc_root)
+            .map_err(TransactionExecutorError::CompileAccountViewCallFailed)?;
+
+        let stack_inputs = StackInputs::new(inputs.iter().rev().copied().collect())
+            .map_err(TransactionExecutorError::InvalidAccountViewInputs)?;
+        let advice_inputs = TransactionKernel::build_account_view_advice_inputs(account);
+        let mut host = AccountViewHost::new(MemAdviceProvider::from(advice_inputs));
+
+        let result = vm_processor::execute(&program, stack_inputs, &mut host, self.exec_options)
+            .map_err(TransactionExecutorError::ExecuteAccountViewCallFailed)?;
+
+        Ok(result.stack_outputs().clone())
     }
 
     // HELPER METHODS
@@ -222,8 +644,17 @@ impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
     /// # Errors:
     /// Returns an error if:
     /// - If required data can not be fetched from the [DataStore].
+    /// - If note args are missing for any of the notes which require them.
     /// - If the transaction can not be compiled.
     #[maybe_async]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "info",
+            skip_all,
+            fields(account_id = %account_id, block_ref = block_ref, num_notes = notes.len())
+        )
+    )]
     pub fn prepare_transaction(
         &self,
         account_id: AccountId,
@@ -235,10 +666,30 @@ impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
             maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
                 .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
 
+        self.compile_transaction_inputs(tx_inputs, tx_args)
+    }
+
+    /// Compiles the transaction described by the provided inputs and arguments into an executable
+    /// program using the [TransactionCompiler], and returns a [PreparedTransaction].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If account salts are set for an on-chain account, or an initial account salt is set for a
+    ///   new account.
+    /// - If note args are missing for any of the notes which require them.
+    /// - If the transaction can not be compiled.
+    fn compile_transaction_inputs(
+        &self,
+        tx_inputs: TransactionInputs,
+        tx_args: TransactionArgs,
+    ) -> Result<PreparedTransaction, TransactionExecutorError> {
+        validate_account_salts(tx_inputs.account(), &tx_args)?;
+        self.validate_note_args(tx_inputs.input_notes(), &tx_args)?;
+
         let tx_program = self
             .compiler
             .compile_transaction(
-                account_id,
+                tx_inputs.account().id(),
                 tx_inputs.input_notes(),
                 tx_args.tx_script().map(|x| x.code()),
             )
@@ -246,11 +697,155 @@ impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
 
         Ok(PreparedTransaction::new(tx_program, tx_inputs, tx_args))
     }
+
+    /// Executes the provided [PreparedTransaction] and returns an [ExecutedTransaction].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the advice map of the transaction arguments conflicts with the transaction inputs.
+    /// - If the size of the advice data exceeds the limit configured for this executor.
+    /// - If the transaction program can not be executed.
+    fn execute_prepared_transaction(
+        &self,
+        transaction: PreparedTransaction,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let stack_inputs = TransactionKernel::build_input_stack(
+            transaction.account().id(),
+            transaction
+                .tx_inputs()
+                .account_init_commitment(transaction.tx_args().init_account_salt()),
+            transaction.input_notes().commitment(),
+            transaction.block_header().hash(),
+            &self.network_params,
+        );
+
+        let advice_inputs =
+            self.build_advice_inputs(transaction.tx_inputs(), transaction.tx_args())?;
+        let advice_recorder: RecAdviceProvider = advice_inputs.build().into();
+        let mut host = TransactionHost::with_procedure_index_map(
+            transaction.tx_inputs().account_stub(),
+            advice_recorder,
+            self.authenticator.clone(),
+            self.procedure_index_map(transaction.account().code()),
+        )
+        .with_input_notes(transaction.input_notes());
+        if let Some(debug_sink) = &self.debug_sink {
+            host = host.with_debug_sink(debug_sink.clone());
+        }
+
+        let result = vm_processor::execute(
+            transaction.program(),
+            stack_inputs,
+            &mut host,
+            self.exec_options,
+        )
+        .map_err(TransactionExecutorError::ExecuteTransactionProgramFailed)?;
+
+        host.check_output_notes()
+            .map_err(TransactionExecutorError::CheckOutputNotesFailed)?;
+
+        if let Some(kernel_coverage) = &self.kernel_coverage {
+            kernel_coverage.record(&result);
+        }
+
+        let (tx_program, tx_inputs, tx_args) = transaction.into_parts();
+
+        build_executed_transaction(
+            tx_program,
+            tx_args,
+            tx_inputs,
+            result.stack_outputs().clone(),
+            host,
+        )
+    }
+
+    /// Builds the advice inputs for the transaction described by the provided inputs and
+    /// arguments.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the advice map of the transaction arguments conflicts with the transaction inputs.
+    /// - If the size of the advice data exceeds the limit configured for this executor.
+    fn build_advice_inputs(
+        &self,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+    ) -> Result<AdviceInputsBuilder, TransactionExecutorError> {
+        let advice_inputs = TransactionKernel::build_advice_inputs(tx_inputs, tx_args)
+            .map_err(TransactionExecutorError::InvalidAdviceInputs)?;
+        if let Some(limit) = self.advice_size_limit {
+            let size = advice_inputs.size_estimate().num_bytes();
+            if size > limit {
+                return Err(TransactionExecutorError::AdviceInputsTooLarge { size, limit });
+            }
+        }
+
+        Ok(advice_inputs)
+    }
+
+    /// Returns the map of procedures of the provided account code, building it and caching it
+    /// under the code root if it has not been cached yet.
+    fn procedure_index_map(&self, account_code: &AccountCode) -> Rc<AccountProcedureIndexMap> {
+        self.proc_index_maps
+            .borrow_mut()
+            .entry(account_code.root())
+            .or_insert_with(|| Rc::new(AccountProcedureIndexMap::from_account_code(account_code)))
+            .clone()
+    }
+
+    /// Checks that the provided transaction arguments contain note args for all input notes
+    /// whose scripts require them.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If any of the note scripts can not be compiled.
+    /// - If note args are missing for a note which requires them.
+    fn validate_note_args(
+        &self,
+        notes: &InputNotes,
+        tx_args: &TransactionArgs,
+    ) -> Result<(), TransactionExecutorError> {
+        let mut notes_requiring_args = Vec::new();
+        for input_note in notes.iter() {
+            let note = input_note.note();
+            if self
+                .compiler
+                .note_script_requires_args(note.script())
+                .map_err(TransactionExecutorError::CompileNoteScriptFailed)?
+            {
+                notes_requiring_args.push(note.id());
+            }
+        }
+
+        tx_args
+            .validate_note_args(notes_requiring_args)
+            .map_err(TransactionExecutorError::InvalidTransactionArgs)
+    }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Checks that the account salts of the provided transaction arguments can be used with the
+/// provided account, i.e., that salts are set only for off-chain accounts, and that the initial
+/// account salt is not set for a new account, as its state commitment was not recorded on-chain.
+fn validate_account_salts(
+    account: &Account,
+    tx_args: &TransactionArgs,
+) -> Result<(), TransactionExecutorError> {
+    let is_salted = |salt: Option<Word>| salt.is_some_and(|salt| salt != EMPTY_WORD);
+    let init_salted = is_salted(tx_args.init_account_salt());
+    let final_salted = is_salted(tx_args.final_account_salt());
+
+    if (account.id().is_on_chain() && (init_salted || final_salted))
+        || (account.is_new() && init_salted)
+    {
+        return Err(TransactionExecutorError::InvalidAccountSalts(account.id()));
+    }
+
+    Ok(())
+}
+
 /// Creates a new [ExecutedTransaction] from the provided data.
 fn build_executed_transaction<A: TransactionAuthenticator>(
     program: Program,
@@ -263,9 +858,13 @@ fn build_executed_transaction<A: TransactionAuthenticator>(
 
     let (mut advice_witness, _, map, _store) = advice_recorder.finalize();
 
-    let tx_outputs =
-        TransactionKernel::from_transaction_parts(&stack_outputs, &map.into(), output_notes)
-            .map_err(TransactionExecutorError::InvalidTransactionOutput)?;
+    let tx_outputs = TransactionKernel::from_transaction_parts(
+        &stack_outputs,
+        &map.into(),
+        output_notes,
+        tx_args.final_account_salt(),
+    )
+    .map_err(TransactionExecutorError::InvalidTransactionOutput)?;
     let final_account = &tx_outputs.account;
 
     let initial_account = tx_inputs.account();
@@ -0,0 +1,67 @@
+use miden_objects::{accounts::Account, notes::Note, transaction::TransactionArgs, NoteError};
+
+use crate::{Consumable, NoteConsumptionChecker, NoteSkipReason, TransactionCompiler};
+
+// NOTE SCREENER
+// ================================================================================================
+
+/// Statically checks whether notes can be consumed by an account in a transaction, without
+/// executing them.
+///
+/// The checks do not guarantee that a note is consumed successfully, as its script can still fail
+/// during execution; they only rule out the notes which would certainly fail.
+pub(super) struct NoteScreener {
+    checker: NoteConsumptionChecker,
+}
+
+impl NoteScreener {
+    /// Returns a new [NoteScreener] recognizing the standard note scripts of the Miden library.
+    pub fn new() -> Result<Self, NoteError> {
+        Ok(Self { checker: NoteConsumptionChecker::new()? })
+    }
+
+    /// Checks whether the provided note can be consumed by the account in a transaction against
+    /// the block with the specified number.
+    ///
+    /// Notes with standard scripts are checked by the [NoteConsumptionChecker], while notes with
+    /// other scripts are not rejected for their scripts being unknown. For all notes, the script
+    /// must not call procedures which are not exposed by the account, and the note args must be
+    /// provided if the script requires them.
+    pub fn check_note(
+        &self,
+        compiler: &TransactionCompiler,
+        account: &Account,
+        block_num: u32,
+        note: &Note,
+        tx_args: &TransactionArgs,
+    ) -> Result<(), NoteSkipReason> {
+        match self.checker.check(note, account.id(), block_num) {
+            Consumable::Now | Consumable::Never(NoteSkipReason::UnknownScript(_)) => (),
+            Consumable::After(recall_height) => {
+                return Err(NoteSkipReason::RecallHeightNotReached(recall_height))
+            },
+            Consumable::Never(reason) => return Err(reason),
+        }
+
+        let intent = compiler
+            .analyze_note_script(note.script())
+            .map_err(NoteSkipReason::InvalidScript)?;
+        if let Some(proc_root) = intent
+            .calls()
+            .iter()
+            .map(|(root, _)| *root)
+            .find(|root| !account.code().has_procedure(*root))
+        {
+            return Err(NoteSkipReason::ProcedureNotFound(proc_root));
+        }
+
+        let requires_args = compiler
+            .note_script_requires_args(note.script())
+            .map_err(NoteSkipReason::InvalidScript)?;
+        if requires_args && tx_args.get_note_args(note.id()).is_none() {
+            return Err(NoteSkipReason::MissingNoteArgs);
+        }
+
+        Ok(())
+    }
+}
@@ -64,6 +64,11 @@ impl AccountDeltaTracker {
         &mut self.vault
     }
 
+    /// Returns a reference to the vault tracker.
+    pub fn vault(&self) -> &AccountVaultDeltaTracker {
+        &self.vault
+    }
+
     /// Get the storage tracker
     pub fn storage_tracker(&mut self) -> &mut AccountStorageDeltaTracker {
         &mut self.storage
@@ -78,11 +83,12 @@ impl AccountDeltaTracker {
 ///
 /// The delta tracker is composed of:
 /// - A map which records the latest states for the updated storage slots.
-/// - A map which records the latest states for the updates storage maps
+/// - A map which records, for every updated key of the updated storage maps, the value of the key
+///   before the transaction and the latest value of the key.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct AccountStorageDeltaTracker {
     slot_updates: BTreeMap<u8, Word>,
-    maps_updates: BTreeMap<u8, Vec<(Word, Word)>>,
+    maps_updates: BTreeMap<u8, BTreeMap<Digest, (Word, Word)>>,
 }
 
 impl AccountStorageDeltaTracker {
@@ -101,19 +107,27 @@ impl AccountStorageDeltaTracker {
             }
         }
 
-        for (idx, map_deltas) in self.maps_updates {
+        for (idx, map_updates) in self.maps_updates {
             let mut updated_leafs = Vec::new();
             let mut cleared_leafs = Vec::new();
 
-            for map_delta in map_deltas {
-                if map_delta.1 == EMPTY_WORD {
-                    cleared_leafs.push(map_delta.0);
+            for (key, (old_value, new_value)) in map_updates {
+                // keys which were set back to their initial values are not part of the delta
+                if new_value == old_value {
+                    continue;
+                }
+
+                if new_value == EMPTY_WORD {
+                    cleared_leafs.push(key.into());
                 } else {
-                    updated_leafs.push(map_delta);
+                    updated_leafs.push((key.into(), new_value));
                 }
             }
-            let storage_map_delta = StorageMapDelta::from(cleared_leafs, updated_leafs);
-            updated_maps.push((idx, storage_map_delta));
+
+            if !cleared_leafs.is_empty() || !updated_leafs.is_empty() {
+                let storage_map_delta = StorageMapDelta::from(cleared_leafs, updated_leafs);
+                updated_maps.push((idx, storage_map_delta));
+            }
         }
 
         AccountStorageDelta {
@@ -128,9 +142,23 @@ impl AccountStorageDeltaTracker {
         self.slot_updates.insert(slot_index, new_slot_value);
     }
 
-    /// Tracks a slot change
-    pub fn maps_update(&mut self, slot_index: u8, key: [Felt; 4], new_value: [Felt; 4]) {
-        self.maps_updates.entry(slot_index).or_default().push((key, new_value));
+    /// Tracks a storage map change.
+    ///
+    /// The old value is recorded only for the first update of a key, so that the value of the key
+    /// before the transaction is retained.
+    pub fn maps_update(
+        &mut self,
+        slot_index: u8,
+        key: [Felt; 4],
+        old_value: [Felt; 4],
+        new_value: [Felt; 4],
+    ) {
+        self.maps_updates
+            .entry(slot_index)
+            .or_default()
+            .entry(key.into())
+            .and_modify(|(_, value)| *value = new_value)
+            .or_insert((old_value, new_value));
     }
 }
 
@@ -142,18 +170,32 @@ impl AccountStorageDeltaTracker {
 ///
 /// The delta tracker is composed of two maps:
 /// - Fungible asset map: tracks changes to the vault's fungible assets, where the key is the
-///   faucet ID of the asset, and the value is the amount of the asset being added or removed from
+///   faucet ID and the sub-token ID of the asset, and the value is the amount of the asset being added or removed from
 ///   the vault (positive value for added assets, negative value for removed assets).
 /// - Non-fungible asset map: tracks changes to the vault's non-fungible assets, where the key is
 ///   the non-fungible asset, and the value is either 1 or -1 depending on whether the asset is
 ///   being added or removed from the vault.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct AccountVaultDeltaTracker {
-    fungible_assets: BTreeMap<AccountId, i128>,
+    fungible_assets: BTreeMap<(AccountId, u32), i128>,
     non_fungible_assets: BTreeMap<Digest, i8>,
 }
 
 impl AccountVaultDeltaTracker {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the changes to the fungible assets of the vault, keyed by the faucet ID and the
+    /// sub-token ID of the asset.
+    pub fn fungible_assets(&self) -> &BTreeMap<(AccountId, u32), i128> {
+        &self.fungible_assets
+    }
+
+    /// Returns the changes to the non-fungible assets of the vault, keyed by the asset.
+    pub fn non_fungible_assets(&self) -> &BTreeMap<Digest, i8> {
+        &self.non_fungible_assets
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -162,7 +204,7 @@ impl AccountVaultDeltaTracker {
             Asset::Fungible(asset) => {
                 update_asset_delta(
                     &mut self.fungible_assets,
-                    asset.faucet_id(),
+                    (asset.faucet_id(), asset.sub_token_id()),
                     asset.amount() as i128,
                 );
             },
@@ -178,7 +220,7 @@ impl AccountVaultDeltaTracker {
             Asset::Fungible(asset) => {
                 update_asset_delta(
                     &mut self.fungible_assets,
-                    asset.faucet_id(),
+                    (asset.faucet_id(), asset.sub_token_id()),
                     -(asset.amount() as i128),
                 );
             },
@@ -198,19 +240,21 @@ impl AccountVaultDeltaTracker {
         let mut removed_assets = Vec::new();
 
         // process fungible assets
-        for (faucet_id, amount) in self.fungible_assets {
+        for ((faucet_id, sub_token_id), amount) in self.fungible_assets {
             if amount > 0 {
                 added_assets.push(Asset::Fungible(
-                    FungibleAsset::new(
+                    FungibleAsset::with_sub_token(
                         AccountId::new_unchecked(faucet_id.into()),
+                        sub_token_id,
                         amount.unsigned_abs() as u64,
                     )
                     .expect("fungible asset is well formed"),
                 ));
             } else {
                 removed_assets.push(Asset::Fungible(
-                    FungibleAsset::new(
+                    FungibleAsset::with_sub_token(
                         AccountId::new_unchecked(faucet_id.into()),
+                        sub_token_id,
                         amount.unsigned_abs() as u64,
                     )
                     .expect("fungible asset is well formed"),
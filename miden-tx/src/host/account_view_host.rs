@@ -0,0 +1,72 @@
+use alloc::{collections::BTreeMap, string::ToString};
+
+use miden_lib::transaction::TransactionEvent;
+use vm_processor::{
+    AdviceExtractor, AdviceInjector, AdviceProvider, ExecutionError, Host, HostResponse,
+    ProcessState,
+};
+
+use super::assertion_failed;
+use crate::KERNEL_ERRORS;
+
+// ACCOUNT VIEW HOST
+// ================================================================================================
+
+/// Account view host is responsible for handling [Host] requests made by an account procedure
+/// executed in a read-only context.
+///
+/// The host only serves advice requests. All transaction kernel events are rejected, which makes
+/// any attempt to modify the account state (e.g., updating the storage, the vault, or the nonce)
+/// fail the execution.
+pub struct AccountViewHost<A> {
+    /// Advice provider which is used to provide the account data to the executed procedure.
+    adv_provider: A,
+
+    /// Contains mappings from error codes to the related error messages
+    error_messages: BTreeMap<u32, &'static str>,
+}
+
+impl<A: AdviceProvider> AccountViewHost<A> {
+    /// Returns a new [AccountViewHost] instance with the provided [AdviceProvider].
+    pub fn new(adv_provider: A) -> Self {
+        Self {
+            adv_provider,
+            error_messages: BTreeMap::from(KERNEL_ERRORS),
+        }
+    }
+}
+
+impl<A: AdviceProvider> Host for AccountViewHost<A> {
+    fn get_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        extractor: AdviceExtractor,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.adv_provider.get_advice(process, &extractor)
+    }
+
+    fn set_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        injector: AdviceInjector,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.adv_provider.set_advice(process, &injector)
+    }
+
+    fn on_event<S: ProcessState>(
+        &mut self,
+        _process: &S,
+        event_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        let event = TransactionEvent::try_from(event_id)
+            .map_err(|err| ExecutionError::EventError(err.to_string()))?;
+
+        Err(ExecutionError::EventError(format!(
+            "{event} event cannot be emitted from an account view call"
+        )))
+    }
+
+    fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
+        assertion_failed(process, &self.error_messages, err_code)
+    }
+}
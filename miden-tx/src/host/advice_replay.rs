@@ -0,0 +1,401 @@
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    cell::{Cell, RefCell},
+};
+
+use miden_objects::{
+    accounts::AccountStub,
+    vm::{Program, SignatureKind},
+    Digest,
+};
+use vm_processor::{
+    crypto::{MerklePath, MerkleStore},
+    AdviceInputs, AdviceProvider, AdviceSource, ExecutionError, ExecutionOptions, Felt,
+    MemAdviceProvider, ProcessState, StackInputs, Word,
+};
+
+use super::TransactionHost;
+
+// ADVICE REPLAY
+// ================================================================================================
+
+/// Re-executes a transaction program and checks that every response of the advice provider
+/// matches the advice data recorded while the transaction was originally executed.
+///
+/// The program is executed against the provided kernel inputs (e.g., as returned by
+/// `get_kernel_inputs()` of a [TransactionWitness](miden_objects::transaction::TransactionWitness)),
+/// while `recording` is the advice witness recorded by the [RecAdviceProvider] during the
+/// original execution (see [ReplayAdviceProvider] for details).
+///
+/// Returns:
+/// - `Ok(None)` if the program was executed successfully and all advice responses matched the
+///   recording.
+/// - `Ok(Some(divergence))` if an advice response did not match the recording; this is returned
+///   regardless of whether the execution failed afterwards.
+/// - `Err(err)` if the execution failed, but all advice responses before the failure matched the
+///   recording.
+///
+/// [RecAdviceProvider]: vm_processor::RecAdviceProvider
+pub fn replay_transaction(
+    program: &Program,
+    account: AccountStub,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+    recording: AdviceInputs,
+) -> Result<Option<AdviceDivergence>, ExecutionError> {
+    let adv_provider = ReplayAdviceProvider::new(advice_inputs, recording);
+    let mut host: TransactionHost<_, ()> = TransactionHost::new(account, adv_provider, None);
+
+    let result =
+        vm_processor::execute(program, stack_inputs, &mut host, ExecutionOptions::default());
+
+    let (adv_provider, ..) = host.into_parts();
+    match (adv_provider.into_divergence(), result) {
+        (Some(divergence), _) => Ok(Some(divergence)),
+        (None, Ok(_)) => Ok(None),
+        (None, Err(err)) => Err(err),
+    }
+}
+
+// REPLAY ADVICE PROVIDER
+// ================================================================================================
+
+/// An [AdviceProvider] which serves advice from the provided advice inputs while checking that
+/// every response matches the response of an advice provider instantiated from recorded advice
+/// data.
+///
+/// The recorded advice data is expected to be the advice witness produced by a
+/// [RecAdviceProvider](vm_processor::RecAdviceProvider), which contains the initial advice stack
+/// and the advice map entries and Merkle store nodes which were read during the recorded
+/// execution. All requests modifying the advice data (e.g., pushing onto the advice stack or
+/// inserting into the advice map) are applied to both providers, such that a response diverges
+/// only if the served advice data differs from the recorded data, or if data which was not read
+/// during the recorded execution is requested.
+///
+/// Only the first divergence is kept; the responses served after it are always taken from the
+/// provided advice inputs. Merkle store subsets are not checked.
+pub struct ReplayAdviceProvider {
+    actual: MemAdviceProvider,
+    expected: MemAdviceProvider,
+    num_requests: Cell<usize>,
+    divergence: RefCell<Option<AdviceDivergence>>,
+}
+
+impl ReplayAdviceProvider {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ReplayAdviceProvider] serving advice from `advice_inputs` and checking the
+    /// responses against `recording`.
+    pub fn new(advice_inputs: AdviceInputs, recording: AdviceInputs) -> Self {
+        Self {
+            actual: advice_inputs.into(),
+            expected: recording.into(),
+            num_requests: Cell::new(0),
+            divergence: RefCell::new(None),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of advice requests served so far.
+    pub fn num_requests(&self) -> usize {
+        self.num_requests.get()
+    }
+
+    /// Returns the first advice response which did not match the recording, if any.
+    pub fn divergence(&self) -> Option<AdviceDivergence> {
+        self.divergence.borrow().clone()
+    }
+
+    /// Consumes `self` and returns the first advice response which did not match the recording,
+    /// if any.
+    pub fn into_divergence(self) -> Option<AdviceDivergence> {
+        self.divergence.into_inner()
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Compares the responses to the specified request, and records a divergence if they do not
+    /// match and no divergence has been recorded yet.
+    fn check(
+        &self,
+        request: AdviceRequest,
+        expected: Result<Vec<Felt>, ExecutionError>,
+        actual: Result<Vec<Felt>, ExecutionError>,
+    ) {
+        let request_idx = self.num_requests.get();
+        self.num_requests.set(request_idx + 1);
+
+        let mut divergence = self.divergence.borrow_mut();
+        if divergence.is_none() && expected != actual {
+            *divergence = Some(AdviceDivergence { request_idx, request, expected, actual });
+        }
+    }
+}
+
+impl AdviceProvider for ReplayAdviceProvider {
+    // ADVICE STACK
+    // --------------------------------------------------------------------------------------------
+
+    fn pop_stack<S: ProcessState>(&mut self, process: &S) -> Result<Felt, ExecutionError> {
+        let expected = self.expected.pop_stack(process);
+        let actual = self.actual.pop_stack(process);
+        self.check(
+            AdviceRequest::PopStack,
+            expected.map(|value| vec![value]),
+            actual.clone().map(|value| vec![value]),
+        );
+        actual
+    }
+
+    fn pop_stack_word<S: ProcessState>(&mut self, process: &S) -> Result<Word, ExecutionError> {
+        let expected = self.expected.pop_stack_word(process);
+        let actual = self.actual.pop_stack_word(process);
+        self.check(
+            AdviceRequest::PopStackWord,
+            expected.map(|word| word.to_vec()),
+            actual.clone().map(|word| word.to_vec()),
+        );
+        actual
+    }
+
+    fn pop_stack_dword<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<[Word; 2], ExecutionError> {
+        let expected = self.expected.pop_stack_dword(process);
+        let actual = self.actual.pop_stack_dword(process);
+        self.check(
+            AdviceRequest::PopStackDword,
+            expected.map(|words| words.concat()),
+            actual.clone().map(|words| words.concat()),
+        );
+        actual
+    }
+
+    fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
+        // values pushed from the advice map are checked against the recorded advice map
+        if let AdviceSource::Map { key, .. } = source {
+            let key = Digest::from(key);
+            self.check(
+                AdviceRequest::PushStack(source),
+                mapped_values(&self.expected, &key),
+                mapped_values(&self.actual, &key),
+            );
+        } else {
+            self.check(AdviceRequest::PushStack(source), Ok(Vec::new()), Ok(Vec::new()));
+        }
+
+        // the stacks are updated independently, such that subsequent pops are checked as well
+        let _ = self.expected.push_stack(source);
+        self.actual.push_stack(source)
+    }
+
+    // ADVICE MAP
+    // --------------------------------------------------------------------------------------------
+
+    fn get_mapped_values(&self, key: &Digest) -> Option<&[Felt]> {
+        self.check(
+            AdviceRequest::GetMappedValues(*key),
+            mapped_values(&self.expected, key),
+            mapped_values(&self.actual, key),
+        );
+        self.actual.get_mapped_values(key)
+    }
+
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
+        self.expected.insert_into_map(key, values.clone())?;
+        self.actual.insert_into_map(key, values)
+    }
+
+    fn get_signature(
+        &self,
+        kind: SignatureKind,
+        pub_key: Word,
+        msg: Word,
+    ) -> Result<Vec<Felt>, ExecutionError> {
+        let expected = self.expected.get_signature(kind, pub_key, msg);
+        let actual = self.actual.get_signature(kind, pub_key, msg);
+        self.check(AdviceRequest::GetSignature { pub_key, msg }, expected, actual.clone());
+        actual
+    }
+
+    // MERKLE STORE
+    // --------------------------------------------------------------------------------------------
+
+    fn get_tree_node(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<Word, ExecutionError> {
+        let expected = self.expected.get_tree_node(root, depth, index);
+        let actual = self.actual.get_tree_node(root, depth, index);
+        self.check(
+            AdviceRequest::GetTreeNode { root, depth: *depth, index: *index },
+            expected.map(|node| node.to_vec()),
+            actual.clone().map(|node| node.to_vec()),
+        );
+        actual
+    }
+
+    fn get_merkle_path(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<MerklePath, ExecutionError> {
+        let expected = self.expected.get_merkle_path(root, depth, index);
+        let actual = self.actual.get_merkle_path(root, depth, index);
+        self.check(
+            AdviceRequest::GetMerklePath { root, depth: *depth, index: *index },
+            expected.map(|path| path_to_elements(&path)),
+            actual.as_ref().map(path_to_elements).map_err(Clone::clone),
+        );
+        actual
+    }
+
+    fn get_leaf_depth(
+        &self,
+        root: Word,
+        tree_depth: &Felt,
+        index: &Felt,
+    ) -> Result<u8, ExecutionError> {
+        let expected = self.expected.get_leaf_depth(root, tree_depth, index);
+        let actual = self.actual.get_leaf_depth(root, tree_depth, index);
+        self.check(
+            AdviceRequest::GetLeafDepth {
+                root,
+                tree_depth: *tree_depth,
+                index: *index,
+            },
+            expected.map(|depth| vec![Felt::from(depth)]),
+            actual.clone().map(|depth| vec![Felt::from(depth)]),
+        );
+        actual
+    }
+
+    fn update_merkle_node(
+        &mut self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+        value: Word,
+    ) -> Result<(MerklePath, Word), ExecutionError> {
+        let expected = self.expected.update_merkle_node(root, depth, index, value);
+        let actual = self.actual.update_merkle_node(root, depth, index, value);
+        self.check(
+            AdviceRequest::UpdateMerkleNode {
+                root,
+                depth: *depth,
+                index: *index,
+                value,
+            },
+            expected.map(|(path, root)| [path_to_elements(&path), root.to_vec()].concat()),
+            actual
+                .as_ref()
+                .map(|(path, root)| [path_to_elements(path), root.to_vec()].concat())
+                .map_err(Clone::clone),
+        );
+        actual
+    }
+
+    fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
+        let expected = self.expected.merge_roots(lhs, rhs);
+        let actual = self.actual.merge_roots(lhs, rhs);
+        self.check(
+            AdviceRequest::MergeRoots { lhs, rhs },
+            expected.map(|root| root.to_vec()),
+            actual.clone().map(|root| root.to_vec()),
+        );
+        actual
+    }
+
+    fn get_store_subset<I, R>(&self, roots: I) -> MerkleStore
+    where
+        I: Iterator<Item = R>,
+        R: Borrow<Digest>,
+    {
+        self.actual.get_store_subset(roots)
+    }
+}
+
+// ADVICE DIVERGENCE
+// ================================================================================================
+
+/// An advice request served by the [ReplayAdviceProvider].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdviceRequest {
+    PopStack,
+    PopStackWord,
+    PopStackDword,
+    PushStack(AdviceSource),
+    GetMappedValues(Digest),
+    GetSignature {
+        pub_key: Word,
+        msg: Word,
+    },
+    GetTreeNode {
+        root: Word,
+        depth: Felt,
+        index: Felt,
+    },
+    GetMerklePath {
+        root: Word,
+        depth: Felt,
+        index: Felt,
+    },
+    GetLeafDepth {
+        root: Word,
+        tree_depth: Felt,
+        index: Felt,
+    },
+    UpdateMerkleNode {
+        root: Word,
+        depth: Felt,
+        index: Felt,
+        value: Word,
+    },
+    MergeRoots {
+        lhs: Word,
+        rhs: Word,
+    },
+}
+
+/// The first advice response of a replayed execution which did not match the recording.
+///
+/// Responses are represented as the elements returned by the advice provider (e.g., the nodes of
+/// a Merkle path followed by the root for [AdviceRequest::UpdateMerkleNode]), or as the error
+/// returned by the advice provider if the request failed. Responses to requests which return no
+/// data (e.g., pushing a value onto the advice stack) are empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdviceDivergence {
+    /// Index of the request among all advice requests of the execution, starting at 0.
+    pub request_idx: usize,
+    /// The diverging request.
+    pub request: AdviceRequest,
+    /// The response according to the recording.
+    pub expected: Result<Vec<Felt>, ExecutionError>,
+    /// The response served during the replay.
+    pub actual: Result<Vec<Felt>, ExecutionError>,
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the values mapped to the specified key by the provided advice provider.
+fn mapped_values(provider: &MemAdviceProvider, key: &Digest) -> Result<Vec<Felt>, ExecutionError> {
+    provider
+        .get_mapped_values(key)
+        .map(|values| values.to_vec())
+        .ok_or(ExecutionError::AdviceMapKeyNotFound(key.into()))
+}
+
+/// Returns the elements of the nodes of the provided Merkle path.
+fn path_to_elements(path: &MerklePath) -> Vec<Felt> {
+    path.iter().flat_map(|node| node.as_elements()).copied().collect()
+}
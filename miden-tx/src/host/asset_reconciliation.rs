@@ -0,0 +1,158 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::fmt;
+
+use miden_objects::{
+    accounts::AccountId,
+    assets::{Asset, NonFungibleAsset},
+    Digest,
+};
+
+use super::account_delta_tracker::AccountVaultDeltaTracker;
+
+// ASSET RECONCILIATION
+// ================================================================================================
+
+/// Reconciliation of the assets entering a transaction with the assets leaving it.
+///
+/// Assets enter a transaction via the input notes and leave it via the output notes, and the
+/// difference between the two must be absorbed by the account vault. The reconciliation lists the
+/// assets for which this does not hold, i.e., the assets which make the transaction kernel fail
+/// the asset preservation check of the epilogue.
+///
+/// Assets issued by the account executing the transaction are not reconciled if the account is a
+/// faucet, as faucets can mint and burn their own assets.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetReconciliation {
+    imbalances: Vec<AssetImbalance>,
+}
+
+/// An asset for which the assets entering a transaction differ from the assets leaving it.
+///
+/// A positive amount means that more of the asset entered the transaction via the input notes and
+/// the initial account vault than left it via the output notes and the final account vault, i.e.,
+/// that the asset was lost. A negative amount means that the asset was created out of nothing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetImbalance {
+    Fungible {
+        faucet_id: AccountId,
+        sub_token_id: u32,
+        amount: i128,
+    },
+    NonFungible {
+        asset: NonFungibleAsset,
+        amount: i64,
+    },
+}
+
+impl AssetReconciliation {
+    /// Returns a new [AssetReconciliation] of the provided assets of the input and output notes and
+    /// the changes to the vault of the account with the specified ID.
+    pub(super) fn new(
+        account_id: AccountId,
+        input_note_assets: impl IntoIterator<Item = Asset>,
+        output_note_assets: impl IntoIterator<Item = Asset>,
+        vault_delta: &AccountVaultDeltaTracker,
+    ) -> Self {
+        let mut fungible_assets = BTreeMap::<(AccountId, u32), i128>::new();
+        let mut non_fungible_assets = BTreeMap::<Digest, i64>::new();
+
+        let mut update = |asset: Asset, sign: i128| match asset {
+            Asset::Fungible(asset) => {
+                *fungible_assets.entry((asset.faucet_id(), asset.sub_token_id())).or_default() +=
+                    sign * asset.amount() as i128;
+            },
+            Asset::NonFungible(asset) => {
+                *non_fungible_assets.entry(asset.vault_key().into()).or_default() += sign as i64;
+            },
+        };
+        input_note_assets.into_iter().for_each(|asset| update(asset, 1));
+        output_note_assets.into_iter().for_each(|asset| update(asset, -1));
+
+        for (&key, &amount) in vault_delta.fungible_assets() {
+            *fungible_assets.entry(key).or_default() -= amount;
+        }
+        for (&key, &amount) in vault_delta.non_fungible_assets() {
+            *non_fungible_assets.entry(key).or_default() -= amount as i64;
+        }
+
+        let is_own_asset = |faucet_id: AccountId| account_id.is_faucet() && faucet_id == account_id;
+
+        let fungible_imbalances = fungible_assets
+            .into_iter()
+            .filter(|&((faucet_id, _), amount)| amount != 0 && !is_own_asset(faucet_id))
+            .map(|((faucet_id, sub_token_id), amount)| AssetImbalance::Fungible {
+                faucet_id,
+                sub_token_id,
+                amount,
+            });
+        let non_fungible_imbalances = non_fungible_assets
+            .into_iter()
+            .filter(|&(_, amount)| amount != 0)
+            // SAFETY: the keys are the vault keys of well formed non-fungible assets, which are
+            // the assets themselves
+            .map(|(key, amount)| AssetImbalance::NonFungible {
+                asset: unsafe { NonFungibleAsset::new_unchecked(*key) },
+                amount,
+            })
+            .filter(|imbalance| !is_own_asset(imbalance.faucet_id()));
+
+        Self {
+            imbalances: fungible_imbalances.chain(non_fungible_imbalances).collect(),
+        }
+    }
+
+    /// Returns true if the assets entering the transaction match the assets leaving it.
+    pub fn is_balanced(&self) -> bool {
+        self.imbalances.is_empty()
+    }
+
+    /// Returns the assets for which the assets entering the transaction differ from the assets
+    /// leaving it.
+    pub fn imbalances(&self) -> &[AssetImbalance] {
+        &self.imbalances
+    }
+}
+
+impl fmt::Display for AssetReconciliation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_balanced() {
+            return write!(f, "all assets are accounted for");
+        }
+
+        for (i, imbalance) in self.imbalances.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{imbalance}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl AssetImbalance {
+    /// Returns the ID of the faucet which issued the asset.
+    pub fn faucet_id(&self) -> AccountId {
+        match self {
+            Self::Fungible { faucet_id, .. } => *faucet_id,
+            Self::NonFungible { asset, .. } => asset.faucet_id(),
+        }
+    }
+}
+
+impl fmt::Display for AssetImbalance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (amount, asset) = match self {
+            Self::Fungible { amount, .. } => (*amount, "fungible asset"),
+            Self::NonFungible { amount, .. } => (*amount as i128, "non-fungible asset"),
+        };
+        let outcome = if amount > 0 { "lost" } else { "created" };
+        write!(f, "{} of {asset} of faucet {}", amount.unsigned_abs(), self.faucet_id())?;
+        if let Self::Fungible { sub_token_id, .. } = self {
+            if *sub_token_id != 0 {
+                write!(f, " (sub-token {sub_token_id})")?;
+            }
+        }
+        write!(f, " {outcome}")
+    }
+}
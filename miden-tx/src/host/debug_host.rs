@@ -0,0 +1,329 @@
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, ops::Range};
+
+use miden_lib::transaction::{
+    kernel_memory::KernelMemoryReader, memory::MemoryAddress, TransactionTrace,
+};
+use miden_objects::{notes::NoteId, Word};
+use vm_processor::{
+    AdviceExtractor, AdviceInjector, ExecutionError, Felt, Host, HostResponse, ProcessState,
+};
+
+// DEBUG TRANSACTION HOST
+// ================================================================================================
+
+/// A [Host] wrapper which collects diagnostics about the execution of a transaction.
+///
+/// The debug host forwards all requests to the wrapped host, while keeping track of the stage of
+/// the transaction kernel and of the consumed note being executed. When a request handled by the
+/// wrapped host fails (including failed assertions), the state of the process is captured in
+/// [TransactionDiagnostics], which consist of the current stage and note, the operand stack, and
+/// the configured ranges of the kernel memory.
+///
+/// The state of the process is also captured whenever a `trace` instruction with one of the
+/// configured breakpoint IDs is executed. Breakpoint IDs which do not correspond to transaction
+/// kernel traces are not forwarded to the wrapped host, and thus can be placed in note and
+/// transaction scripts (e.g., `trace.1000`) without failing the execution.
+///
+/// Note that the stage and note tracking as well as breakpoints rely on trace events, and thus
+/// require tracing to be enabled in the execution options.
+pub struct DebugTransactionHost<H> {
+    inner: H,
+    memory_ranges: Vec<Range<MemoryAddress>>,
+    breakpoints: BTreeSet<u32>,
+    stage: Option<TransactionStage>,
+    note_id: Option<NoteId>,
+    breakpoint_hits: Vec<TransactionDiagnostics>,
+    failure: Option<TransactionDiagnostics>,
+}
+
+impl<H: Host> DebugTransactionHost<H> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [DebugTransactionHost] wrapping the provided host.
+    ///
+    /// By default, no kernel memory is captured and no breakpoints are set.
+    pub fn new(inner: H) -> Self {
+        Self {
+            inner,
+            memory_ranges: Vec::new(),
+            breakpoints: BTreeSet::new(),
+            stage: None,
+            note_id: None,
+            breakpoint_hits: Vec::new(),
+            failure: None,
+        }
+    }
+
+    /// Adds the specified range of the kernel memory to the captured diagnostics.
+    pub fn with_memory_range(mut self, range: Range<MemoryAddress>) -> Self {
+        self.memory_ranges.push(range);
+        self
+    }
+
+    /// Sets a breakpoint on the `trace` instructions with the specified ID.
+    pub fn with_breakpoint(mut self, trace_id: u32) -> Self {
+        self.breakpoints.insert(trace_id);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the wrapped host.
+    pub fn inner(&self) -> &H {
+        &self.inner
+    }
+
+    /// Consumes `self` and returns the wrapped host.
+    pub fn into_inner(self) -> H {
+        self.inner
+    }
+
+    /// Returns the diagnostics captured at each breakpoint hit, in the order of execution.
+    pub fn breakpoint_hits(&self) -> &[TransactionDiagnostics] {
+        &self.breakpoint_hits
+    }
+
+    /// Returns the diagnostics captured when a request handled by the wrapped host failed, or
+    /// `None` if no such request failed.
+    pub fn failure(&self) -> Option<&TransactionDiagnostics> {
+        self.failure.as_ref()
+    }
+
+    /// Returns the diagnostics for the provided execution error.
+    ///
+    /// If the error was caused by a request handled by the wrapped host, the diagnostics captured
+    /// at that point are returned. Otherwise, the error was raised by the VM itself, which does not
+    /// expose the state of the process to the host; in this case, only the last known stage and
+    /// note are reported.
+    pub fn failure_diagnostics(&self, err: &ExecutionError) -> TransactionDiagnostics {
+        match &self.failure {
+            Some(failure) => failure.clone(),
+            None => TransactionDiagnostics {
+                cause: DiagnosticsCause::Error(err.to_string()),
+                clk: None,
+                stage: self.stage,
+                note_id: self.note_id,
+                stack: Vec::new(),
+                memory: Vec::new(),
+            },
+        }
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Captures the current state of the process.
+    fn capture<S: ProcessState>(
+        &self,
+        process: &S,
+        cause: DiagnosticsCause,
+    ) -> TransactionDiagnostics {
+        let kernel_memory = KernelMemoryReader::new(process);
+        let memory = self
+            .memory_ranges
+            .iter()
+            .flat_map(|range| range.clone())
+            .filter_map(|address| kernel_memory.read_word(address).map(|word| (address, word)))
+            .collect();
+
+        TransactionDiagnostics {
+            cause,
+            clk: Some(process.clk()),
+            stage: self.stage,
+            note_id: self.note_id,
+            stack: process.get_stack_state(),
+            memory,
+        }
+    }
+
+    /// Captures the state of the process if the provided result is an error, unless a failure has
+    /// already been captured.
+    fn capture_failure<S: ProcessState, T>(
+        &mut self,
+        process: &S,
+        result: Result<T, ExecutionError>,
+    ) -> Result<T, ExecutionError> {
+        if let Err(err) = &result {
+            if self.failure.is_none() {
+                self.failure =
+                    Some(self.capture(process, DiagnosticsCause::Error(err.to_string())));
+            }
+        }
+        result
+    }
+
+    /// Updates the current stage and note according to the provided kernel trace.
+    fn update_stage<S: ProcessState>(&mut self, process: &S, trace: TransactionTrace) {
+        use TransactionTrace::*;
+        match trace {
+            PrologueStart => self.stage = Some(TransactionStage::Prologue),
+            NotesProcessingStart | NoteExecutionEnd => {
+                self.stage = Some(TransactionStage::NotesProcessing);
+                self.note_id = None;
+            },
+            NoteExecutionStart => {
+                self.stage = Some(TransactionStage::NoteExecution);
+                self.note_id =
+                    KernelMemoryReader::new(process).current_consumed_note_id().ok().flatten();
+            },
+            TxScriptProcessingStart => self.stage = Some(TransactionStage::TxScriptProcessing),
+            EpilogueStart => self.stage = Some(TransactionStage::Epilogue),
+            PrologueEnd | NotesProcessingEnd | TxScriptProcessingEnd | EpilogueEnd => {
+                self.stage = None
+            },
+        }
+    }
+}
+
+impl<H: Host> Host for DebugTransactionHost<H> {
+    fn get_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        extractor: AdviceExtractor,
+    ) -> Result<HostResponse, ExecutionError> {
+        let result = self.inner.get_advice(process, extractor);
+        self.capture_failure(process, result)
+    }
+
+    fn set_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        injector: AdviceInjector,
+    ) -> Result<HostResponse, ExecutionError> {
+        let result = self.inner.set_advice(process, injector);
+        self.capture_failure(process, result)
+    }
+
+    fn on_event<S: ProcessState>(
+        &mut self,
+        process: &S,
+        event_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        let result = self.inner.on_event(process, event_id);
+        self.capture_failure(process, result)
+    }
+
+    fn on_trace<S: ProcessState>(
+        &mut self,
+        process: &S,
+        trace_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        let kernel_trace = TransactionTrace::try_from(trace_id).ok();
+        if let Some(trace) = &kernel_trace {
+            self.update_stage(process, trace.clone());
+        }
+
+        if self.breakpoints.contains(&trace_id) {
+            let hit = self.capture(process, DiagnosticsCause::Breakpoint(trace_id));
+            self.breakpoint_hits.push(hit);
+
+            // user breakpoints are not known to the wrapped host
+            if kernel_trace.is_none() {
+                return Ok(HostResponse::None);
+            }
+        }
+
+        let result = self.inner.on_trace(process, trace_id);
+        self.capture_failure(process, result)
+    }
+
+    fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
+        let err = self.inner.on_assert_failed(process, err_code);
+        if self.failure.is_none() {
+            self.failure = Some(self.capture(process, DiagnosticsCause::Error(err.to_string())));
+        }
+        err
+    }
+}
+
+// TRANSACTION DIAGNOSTICS
+// ================================================================================================
+
+/// Stages of the transaction kernel, as reported by kernel traces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionStage {
+    Prologue,
+    NotesProcessing,
+    NoteExecution,
+    TxScriptProcessing,
+    Epilogue,
+}
+
+impl fmt::Display for TransactionStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransactionStage::Prologue => write!(f, "prologue"),
+            TransactionStage::NotesProcessing => write!(f, "notes processing"),
+            TransactionStage::NoteExecution => write!(f, "note execution"),
+            TransactionStage::TxScriptProcessing => write!(f, "transaction script processing"),
+            TransactionStage::Epilogue => write!(f, "epilogue"),
+        }
+    }
+}
+
+/// The reason for which [TransactionDiagnostics] were captured.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiagnosticsCause {
+    /// A breakpoint with the specified trace ID was hit.
+    Breakpoint(u32),
+    /// The execution failed with the specified error.
+    Error(String),
+}
+
+/// State of a transaction execution captured by the [DebugTransactionHost].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionDiagnostics {
+    /// The reason for which the diagnostics were captured.
+    pub cause: DiagnosticsCause,
+    /// The clock cycle at which the diagnostics were captured, if known.
+    pub clk: Option<u32>,
+    /// The stage of the transaction kernel, or `None` if the execution was outside of any stage.
+    pub stage: Option<TransactionStage>,
+    /// The ID of the consumed note being executed, if any.
+    pub note_id: Option<NoteId>,
+    /// The operand stack, with the top of the stack first.
+    pub stack: Vec<Felt>,
+    /// The initialized words within the configured ranges of the kernel memory.
+    pub memory: Vec<(MemoryAddress, Word)>,
+}
+
+impl fmt::Display for TransactionDiagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.cause {
+            DiagnosticsCause::Breakpoint(trace_id) => writeln!(f, "breakpoint {trace_id} hit")?,
+            DiagnosticsCause::Error(err) => writeln!(f, "execution failed: {err}")?,
+        }
+        if let Some(clk) = self.clk {
+            writeln!(f, "  clock cycle: {clk}")?;
+        }
+        match self.stage {
+            Some(stage) => writeln!(f, "  stage: {stage}")?,
+            None => writeln!(f, "  stage: -")?,
+        }
+        if let Some(note_id) = self.note_id {
+            writeln!(f, "  note: {}", note_id.to_hex())?;
+        }
+        if !self.stack.is_empty() {
+            let stack = self.stack.iter().map(|felt| felt.as_int().to_string()).collect::<Vec<_>>();
+            writeln!(f, "  stack: [{}]", stack.join(", "))?;
+        }
+        for (address, word) in self.memory.iter() {
+            writeln!(
+                f,
+                "  memory[{address}]: [{}, {}, {}, {}]",
+                word[0].as_int(),
+                word[1].as_int(),
+                word[2].as_int(),
+                word[3].as_int()
+            )?;
+        }
+        Ok(())
+    }
+}
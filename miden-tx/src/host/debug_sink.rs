@@ -0,0 +1,111 @@
+use alloc::{string::ToString, vec::Vec};
+use core::fmt;
+
+use miden_objects::{notes::NoteId, vm::DebugOptions, Word};
+use vm_processor::{Felt, ProcessState};
+
+// DEBUG SINK
+// ================================================================================================
+
+/// A receiver of the output of the `debug` instructions executed by a transaction.
+///
+/// `debug` instructions are retained only in code assembled in debug mode, and are executed only
+/// when debugging is enabled in the execution options; see
+/// [TransactionExecutor::with_debug_sink()](crate::TransactionExecutor::with_debug_sink).
+///
+/// The sink is invoked with a shared reference, and so implementations which accumulate the output
+/// need to rely on interior mutability (e.g., a `RefCell`).
+pub trait DebugSink {
+    /// Handles the output of a `debug` instruction.
+    fn on_debug(&self, output: &DebugOutput);
+}
+
+// DEBUG OUTPUT
+// ================================================================================================
+
+/// State of a transaction execution captured by a `debug` instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugOutput {
+    /// The options of the `debug` instruction, e.g., `debug.stack.4`.
+    pub options: DebugOptions,
+    /// The clock cycle at which the instruction was executed.
+    pub clk: u32,
+    /// The ID of the consumed note being executed, or `None` if the instruction was executed
+    /// outside of a note script, e.g., in the transaction script.
+    pub note_id: Option<NoteId>,
+    /// The requested items of the operand stack, with the top of the stack first.
+    pub stack: Vec<Felt>,
+    /// The requested initialized words of the memory of the current context, together with their
+    /// addresses. For procedure locals, the address is the index of the local.
+    pub memory: Vec<(u64, Word)>,
+}
+
+impl DebugOutput {
+    /// Returns the state of the provided process requested by the specified debug options.
+    pub(super) fn capture<S: ProcessState>(
+        process: &S,
+        options: DebugOptions,
+        note_id: Option<NoteId>,
+    ) -> Self {
+        let ctx = process.ctx();
+        let mut stack = Vec::new();
+        let mut memory = Vec::new();
+
+        match options {
+            DebugOptions::StackAll => stack = process.get_stack_state(),
+            DebugOptions::StackTop(n) => {
+                stack = process.get_stack_state();
+                stack.truncate(n as usize);
+            },
+            DebugOptions::MemAll => memory = process.get_mem_state(ctx),
+            DebugOptions::MemInterval(start, end) => {
+                memory = (start..=end)
+                    .filter_map(|addr| process.get_mem_value(ctx, addr).map(|w| (addr as u64, w)))
+                    .collect();
+            },
+            DebugOptions::LocalInterval(start, end, num_locals) => {
+                // locals are stored just below the free memory pointer of the procedure
+                let locals_offset = process.fmp() - num_locals as u64 + 1;
+                memory = (start..=end.min(num_locals.saturating_sub(1)))
+                    .filter_map(|index| {
+                        let addr = (locals_offset + index as u64) as u32;
+                        process.get_mem_value(ctx, addr).map(|word| (index as u64, word))
+                    })
+                    .collect();
+            },
+        }
+
+        Self {
+            options,
+            clk: process.clk(),
+            note_id,
+            stack,
+            memory,
+        }
+    }
+}
+
+impl fmt::Display for DebugOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "debug.{} at clock cycle {}", self.options, self.clk)?;
+        match self.note_id {
+            Some(note_id) => writeln!(f, " (note {})", note_id.to_hex())?,
+            None => writeln!(f)?,
+        }
+        if !self.stack.is_empty() {
+            let stack = self.stack.iter().map(|felt| felt.as_int().to_string()).collect::<Vec<_>>();
+            writeln!(f, "  stack: [{}]", stack.join(", "))?;
+        }
+        for (address, word) in self.memory.iter() {
+            writeln!(
+                f,
+                "  memory[{address}]: [{}, {}, {}, {}]",
+                word[0].as_int(),
+                word[1].as_int(),
+                word[2].as_int(),
+                word[3].as_int()
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -1,15 +1,21 @@
-use alloc::{collections::BTreeMap, rc::Rc, string::ToString, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    rc::Rc,
+    string::ToString,
+    vec::Vec,
+};
 
 use miden_lib::transaction::{
-    memory::{MemoryAddress, ACCT_STORAGE_ROOT_PTR, CURRENT_CONSUMED_NOTE_PTR},
+    assertion_locations, kernel_memory::KernelMemoryReader, memory::MemoryAddress,
     TransactionEvent, TransactionKernelError, TransactionTrace,
 };
 use miden_objects::{
     accounts::{AccountDelta, AccountId, AccountStorage, AccountStub},
     assets::Asset,
     notes::{NoteId, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType},
-    transaction::OutputNote,
-    Digest, Hasher,
+    transaction::{InputNotes, OutputNote, StorageChange, TransactionSummary, VaultChange},
+    vm::DebugOptions,
+    Digest, Hasher, Word, WORD_SIZE,
 };
 use vm_processor::{
     crypto::NodeIndex, AdviceExtractor, AdviceInjector, AdviceProvider, AdviceSource, ContextId,
@@ -20,18 +26,37 @@ mod account_delta_tracker;
 use account_delta_tracker::AccountDeltaTracker;
 
 mod account_procs;
-use account_procs::AccountProcedureIndexMap;
+pub(crate) use account_procs::AccountProcedureIndexMap;
+
+mod account_view_host;
+pub use account_view_host::AccountViewHost;
+
+mod advice_replay;
+pub use advice_replay::{
+    replay_transaction, AdviceDivergence, AdviceRequest, ReplayAdviceProvider,
+};
+
+mod asset_reconciliation;
+pub use asset_reconciliation::{AssetImbalance, AssetReconciliation};
+
+mod debug_host;
+pub use debug_host::{
+    DebugTransactionHost, DiagnosticsCause, TransactionDiagnostics, TransactionStage,
+};
+
+mod debug_sink;
+pub use debug_sink::{DebugOutput, DebugSink};
 
 mod note_builder;
 use note_builder::OutputNoteBuilder;
 
 mod tx_authenticator;
-pub use tx_authenticator::{BasicAuthenticator, TransactionAuthenticator};
+pub use tx_authenticator::{get_falcon_signature, BasicAuthenticator, TransactionAuthenticator};
 
 mod tx_progress;
 pub use tx_progress::TransactionProgress;
 
-use crate::KERNEL_ERRORS;
+use crate::{error::ERR_EPILOGUE_ASSETS_DONT_ADD_UP, KERNEL_ERRORS};
 
 // CONSTANTS
 // ================================================================================================
@@ -47,11 +72,27 @@ pub struct TransactionHost<A, T> {
     /// runtime.
     adv_provider: A,
 
+    /// The ID of the account against which the transaction is executed.
+    account_id: AccountId,
+
     /// Accumulates the state changes notified via events.
     account_delta: AccountDeltaTracker,
 
+    /// The assets of the notes consumed by the transaction, if known to the host.
+    input_note_assets: Option<Vec<Asset>>,
+
+    /// The slot index, key and new value of the storage map update which is currently being
+    /// executed. Recorded before the update and consumed once the old value is known.
+    pending_map_update: Option<(u8, Word, Word)>,
+
+    /// The changes made to the account vault, in the order in which the kernel made them.
+    vault_changes: Vec<VaultChange>,
+
+    /// The changes made to the account storage, in the order in which the kernel made them.
+    storage_changes: Vec<StorageChange>,
+
     /// A map for the account's procedures.
-    acct_procedure_index_map: AccountProcedureIndexMap,
+    acct_procedure_index_map: Rc<AccountProcedureIndexMap>,
 
     /// The list of notes created while executing a transaction stored as note_ptr |-> note_builder
     /// map.
@@ -60,6 +101,10 @@ pub struct TransactionHost<A, T> {
     /// Provides a way to get a signature for a message into a transaction
     authenticator: Option<Rc<T>>,
 
+    /// Receives the output of the `debug` instructions; if not set, the output is printed to the
+    /// standard output when the `std` feature is enabled.
+    debug_sink: Option<Rc<dyn DebugSink>>,
+
     /// Contains the information about the number of cycles for each of the transaction execution
     /// stages.
     tx_progress: TransactionProgress,
@@ -69,25 +114,74 @@ pub struct TransactionHost<A, T> {
 
     /// Contains mappings from error codes to the related error messages
     error_messages: BTreeMap<u32, &'static str>,
+
+    /// Spans of the currently executing transaction stages, together with the cycles at which the
+    /// stages started.
+    #[cfg(feature = "tracing")]
+    stage_spans: Vec<(tracing::span::EnteredSpan, u32)>,
 }
 
 impl<A: AdviceProvider, T: TransactionAuthenticator> TransactionHost<A, T> {
     /// Returns a new [TransactionHost] instance with the provided [AdviceProvider].
     pub fn new(account: AccountStub, adv_provider: A, authenticator: Option<Rc<T>>) -> Self {
         let proc_index_map = AccountProcedureIndexMap::new(account.code_root(), &adv_provider);
+        Self::with_procedure_index_map(
+            account,
+            adv_provider,
+            authenticator,
+            Rc::new(proc_index_map),
+        )
+    }
+
+    /// Returns a new [TransactionHost] instance with the provided [AdviceProvider] and a
+    /// previously built map of the account's procedures.
+    ///
+    /// Unlike [TransactionHost::new()], the procedure map is not derived from the data in the
+    /// advice provider.
+    pub(crate) fn with_procedure_index_map(
+        account: AccountStub,
+        adv_provider: A,
+        authenticator: Option<Rc<T>>,
+        proc_index_map: Rc<AccountProcedureIndexMap>,
+    ) -> Self {
         let kernel_assertion_errors = BTreeMap::from(KERNEL_ERRORS);
         Self {
             adv_provider,
+            account_id: account.id(),
             account_delta: AccountDeltaTracker::new(&account),
+            input_note_assets: None,
+            pending_map_update: None,
+            vault_changes: Vec::new(),
+            storage_changes: Vec::new(),
             acct_procedure_index_map: proc_index_map,
             output_notes: BTreeMap::default(),
             authenticator,
+            debug_sink: None,
             tx_progress: TransactionProgress::default(),
             generated_signatures: BTreeMap::new(),
             error_messages: kernel_assertion_errors,
+            #[cfg(feature = "tracing")]
+            stage_spans: Vec::new(),
         }
     }
 
+    /// Provides the host with the notes consumed by the transaction.
+    ///
+    /// The notes are used to reconcile the assets of the transaction when the kernel reports that
+    /// the assets do not add up.
+    pub fn with_input_notes(mut self, input_notes: &InputNotes) -> Self {
+        let assets = input_notes.iter().flat_map(|note| note.note().assets().iter().copied());
+        self.input_note_assets = Some(assets.collect());
+        self
+    }
+
+    /// Routes the output of the `debug` instructions executed by the transaction to the provided
+    /// sink, together with the note being executed.
+    pub fn with_debug_sink(mut self, debug_sink: Rc<dyn DebugSink>) -> Self {
+        self.debug_sink = Some(debug_sink);
+        self
+    }
+
     /// Consumes `self` and returns the advice provider and account vault delta.
     pub fn into_parts(self) -> (A, AccountDelta, Vec<OutputNote>, BTreeMap<Digest, Vec<Felt>>) {
         let output_notes = self.output_notes.into_values().map(|builder| builder.build()).collect();
@@ -100,6 +194,41 @@ impl<A: AdviceProvider, T: TransactionAuthenticator> TransactionHost<A, T> {
         )
     }
 
+    /// Checks that the notes created by the transaction are unique.
+    ///
+    /// Notes can receive assets at any point of the transaction, and so this check is meaningful
+    /// only once the execution of the transaction has completed.
+    ///
+    /// # Errors
+    /// Returns an error if two of the created notes have the same recipient and assets, and thus
+    /// the same ID.
+    pub fn check_output_notes(&self) -> Result<(), TransactionKernelError> {
+        let mut note_ids = BTreeSet::new();
+        for note in self.output_notes.values() {
+            if !note_ids.insert(note.id()) {
+                return Err(TransactionKernelError::DuplicateOutputNote(note.id()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the reconciliation of the assets of the input notes with the assets of the output
+    /// notes and the changes to the account vault made so far, or `None` if the input notes were
+    /// not provided via [TransactionHost::with_input_notes()].
+    pub fn asset_reconciliation(&self) -> Option<AssetReconciliation> {
+        let input_note_assets = self.input_note_assets.as_ref()?;
+        let output_note_assets =
+            self.output_notes.values().flat_map(|note| note.assets().iter().copied());
+
+        Some(AssetReconciliation::new(
+            self.account_id,
+            input_note_assets.iter().copied(),
+            output_note_assets,
+            self.account_delta.vault(),
+        ))
+    }
+
     /// Returns a reference to the `tx_progress` field of the [`TransactionHost`].
     pub fn tx_progress(&self) -> &TransactionProgress {
         &self.tx_progress
@@ -186,7 +315,10 @@ impl<A: AdviceProvider, T: TransactionAuthenticator> TransactionHost<A, T> {
         &mut self,
         process: &S,
     ) -> Result<(), TransactionKernelError> {
-        let proc_idx = self.acct_procedure_index_map.get_proc_index(process)?;
+        let (proc_idx, requires_auth) = self.acct_procedure_index_map.get_proc_index(process)?;
+        self.adv_provider
+            .push_stack(AdviceSource::Value(Felt::from(requires_auth)))
+            .expect("failed to push value onto advice stack");
         self.adv_provider
             .push_stack(AdviceSource::Value(proc_idx.into()))
             .expect("failed to push value onto advice stack");
@@ -212,9 +344,10 @@ impl<A: AdviceProvider, T: TransactionAuthenticator> TransactionHost<A, T> {
         &mut self,
         process: &S,
     ) -> Result<(), TransactionKernelError> {
-        let storage_root = process
-            .get_mem_value(ContextId::root(), ACCT_STORAGE_ROOT_PTR)
-            .expect("no storage root");
+        let storage_root: Word = KernelMemoryReader::new(process)
+            .account_storage_root()
+            .expect("no storage root")
+            .into();
 
         // get slot index from the stack and make sure it is valid
         let slot_index = process.get_stack_item(0);
@@ -241,9 +374,13 @@ impl<A: AdviceProvider, T: TransactionAuthenticator> TransactionHost<A, T> {
                 )
             })?;
 
+        // the kernel commits to every update, including the ones which leave the value unchanged
+        let slot_index = slot_index.as_int() as u8;
+        self.storage_changes
+            .push(StorageChange::Item { index: slot_index, value: new_slot_value });
+
         // update the delta tracker only if the current and new values are different
         if current_slot_value != new_slot_value {
-            let slot_index = slot_index.as_int() as u8;
             self.account_delta.storage_tracker().slot_update(slot_index, new_slot_value);
         }
 
@@ -251,7 +388,7 @@ impl<A: AdviceProvider, T: TransactionAuthenticator> TransactionHost<A, T> {
     }
 
     /// Extracts information from the process state about the storage map being updated and
-    /// records the latest values of this storage map.
+    /// keeps it until the update is completed.
     pub fn on_account_storage_set_map_item<S: ProcessState>(
         &mut self,
         process: &S,
@@ -279,9 +416,41 @@ impl<A: AdviceProvider, T: TransactionAuthenticator> TransactionHost<A, T> {
         ];
 
         let slot_index = slot_index.as_int() as u8;
-        self.account_delta
-            .storage_tracker()
-            .maps_update(slot_index, new_map_key, new_map_value);
+        self.storage_changes.push(StorageChange::MapItem {
+            index: slot_index,
+            key: new_map_key,
+            value: new_map_value,
+        });
+        self.pending_map_update = Some((slot_index, new_map_key, new_map_value));
+
+        Ok(())
+    }
+
+    /// Extracts the previous value of the updated storage map item from the process state and
+    /// records the storage map update together with the previous value.
+    pub fn on_account_storage_after_set_map_item<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<(), TransactionKernelError> {
+        let (slot_index, map_key, new_map_value) = self
+            .pending_map_update
+            .take()
+            .ok_or(TransactionKernelError::MissingStorageMapUpdate)?;
+
+        // get the VALUE which was stored under the KEY before the update
+        let old_map_value = [
+            process.get_stack_item(3),
+            process.get_stack_item(2),
+            process.get_stack_item(1),
+            process.get_stack_item(0),
+        ];
+
+        self.account_delta.storage_tracker().maps_update(
+            slot_index,
+            map_key,
+            old_map_value,
+            new_map_value,
+        );
 
         Ok(())
     }
@@ -300,6 +469,7 @@ impl<A: AdviceProvider, T: TransactionAuthenticator> TransactionHost<A, T> {
             .try_into()
             .map_err(TransactionKernelError::MalformedAssetOnAccountVaultUpdate)?;
 
+        self.vault_changes.push(VaultChange::Added(asset));
         self.account_delta.vault_tracker().add_asset(asset);
         Ok(())
     }
@@ -315,6 +485,7 @@ impl<A: AdviceProvider, T: TransactionAuthenticator> TransactionHost<A, T> {
             .try_into()
             .map_err(TransactionKernelError::MalformedAssetOnAccountVaultUpdate)?;
 
+        self.vault_changes.push(VaultChange::Removed(asset));
         self.account_delta.vault_tracker().remove_asset(asset);
         Ok(())
     }
@@ -335,18 +506,34 @@ impl<A: AdviceProvider, T: TransactionAuthenticator> TransactionHost<A, T> {
         let msg = process.get_stack_word(1);
         let signature_key = Hasher::merge(&[pub_key.into(), msg.into()]);
 
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "signature_request",
+            pub_key = %Digest::from(pub_key),
+            message = %Digest::from(msg),
+        )
+        .entered();
+
         let signature = if let Some(signature) = self.adv_provider.get_mapped_values(&signature_key)
         {
             signature.to_vec()
         } else {
-            let account_delta = self.account_delta.clone().into_delta();
+            #[cfg(feature = "tracing")]
+            tracing::debug!("requesting signature from the authenticator");
+
+            let nonce = KernelMemoryReader::new(process).account_nonce().unwrap_or_default();
+            let tx_summary = self.build_tx_summary(msg, nonce).ok_or(
+                ExecutionError::FailedSignatureGeneration(
+                    "Message is not a commitment to a transaction summary",
+                ),
+            )?;
 
             let signature: Vec<Felt> = match &self.authenticator {
                 None => Err(ExecutionError::FailedSignatureGeneration(
                     "No authenticator assigned to transaction host",
                 )),
                 Some(authenticator) => {
-                    authenticator.get_signature(pub_key, msg, &account_delta).map_err(|_| {
+                    authenticator.get_signature(pub_key, msg, &tx_summary).map_err(|_| {
                         ExecutionError::FailedSignatureGeneration("Error generating signature")
                     })
                 },
@@ -366,6 +553,82 @@ impl<A: AdviceProvider, T: TransactionAuthenticator> TransactionHost<A, T> {
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the summary of the transaction to which the provided message commits, or None if
+    /// the message is not a commitment to a transaction summary.
+    ///
+    /// The notes hashes are taken from the preimages of the message which were inserted into the
+    /// advice map by the authentication procedure (see `get_tx_summary_commitment` in
+    /// `miden::contracts::auth::basic`), and the account changes from the ones recorded by this
+    /// host, together with the provided current nonce of the account.
+    fn build_tx_summary(&self, msg: Word, nonce: Felt) -> Option<TransactionSummary> {
+        let [output_notes_hash, notes_and_delta] = self.get_hash_preimage(msg.into())?;
+        let [input_notes_hash, _] = self.get_hash_preimage(notes_and_delta)?;
+
+        let tx_summary = TransactionSummary::new(
+            self.vault_changes.clone(),
+            self.storage_changes.clone(),
+            nonce,
+            input_notes_hash,
+            output_notes_hash,
+        );
+        (tx_summary.to_commitment() == msg.into()).then_some(tx_summary)
+    }
+
+    /// Returns the two digests which were merged into the provided hash, if they were inserted
+    /// into the advice map under this hash.
+    fn get_hash_preimage(&self, hash: Digest) -> Option<[Digest; 2]> {
+        let values = self.adv_provider.get_mapped_values(&hash)?;
+        if values.len() != 2 * WORD_SIZE {
+            return None;
+        }
+        let left: Word = values[..WORD_SIZE].try_into().ok()?;
+        let right: Word = values[WORD_SIZE..].try_into().ok()?;
+        Some([left.into(), right.into()])
+    }
+
+    /// Enters a span when a stage of the transaction kernel starts, and exits it when the stage
+    /// ends, recording the number of cycles spent in the stage.
+    ///
+    /// Note execution spans are keyed by the ID of the executed note, which must have been
+    /// recorded in the transaction progress beforehand.
+    #[cfg(feature = "tracing")]
+    fn trace_stage(&mut self, event: TransactionTrace, cycle: u32) {
+        use tracing::{debug_span, field::Empty};
+        use TransactionTrace::*;
+
+        let span = match event {
+            PrologueStart => debug_span!("prologue", start_cycle = cycle, cycles = Empty),
+            NotesProcessingStart => {
+                debug_span!("notes_processing", start_cycle = cycle, cycles = Empty)
+            },
+            NoteExecutionStart => {
+                let note_id = self.tx_progress.note_execution().last().map(|(note_id, _)| note_id);
+                debug_span!(
+                    "note_execution",
+                    note_id = note_id.map(NoteId::to_hex),
+                    start_cycle = cycle,
+                    cycles = Empty,
+                )
+            },
+            TxScriptProcessingStart => {
+                debug_span!("tx_script_processing", start_cycle = cycle, cycles = Empty)
+            },
+            EpilogueStart => debug_span!("epilogue", start_cycle = cycle, cycles = Empty),
+            PrologueEnd
+            | NotesProcessingEnd
+            | NoteExecutionEnd
+            | TxScriptProcessingEnd
+            | EpilogueEnd => {
+                if let Some((span, start_cycle)) = self.stage_spans.pop() {
+                    span.record("cycles", cycle.saturating_sub(start_cycle));
+                }
+                return;
+            },
+        };
+
+        self.stage_spans.push((span.entered(), cycle));
+    }
+
     /// Returns the ID of the currently executing input note, or None if the note execution hasn't
     /// started yet or has already ended.
     ///
@@ -373,24 +636,9 @@ impl<A: AdviceProvider, T: TransactionAuthenticator> TransactionHost<A, T> {
     /// Returns an error if the address of the currently executing input note is invalid (e.g.,
     /// greater than `u32::MAX`).
     fn get_current_note_id<S: ProcessState>(process: &S) -> Result<Option<NoteId>, ExecutionError> {
-        // get the word where note address is stored
-        let note_address_word = process.get_mem_value(process.ctx(), CURRENT_CONSUMED_NOTE_PTR);
-        // get the note address in `Felt` from or return `None` if the address hasn't been accessed
-        // previously.
-        let note_address_felt = match note_address_word {
-            Some(w) => w[0],
-            None => return Ok(None),
-        };
-        // get the note address
-        let note_address: u32 = note_address_felt
-            .try_into()
-            .map_err(|_| ExecutionError::MemoryAddressOutOfBounds(note_address_felt.as_int()))?;
-        // if `note_address` == 0 note execution has ended and there is no valid note address
-        if note_address == 0 {
-            Ok(None)
-        } else {
-            Ok(process.get_mem_value(process.ctx(), note_address).map(NoteId::from))
-        }
+        KernelMemoryReader::new(process)
+            .current_consumed_note_id()
+            .map_err(|err| ExecutionError::EventError(err.to_string()))
     }
 }
 
@@ -443,6 +691,9 @@ impl<A: AdviceProvider, T: TransactionAuthenticator> Host for TransactionHost<A,
                 self.on_account_storage_set_map_item(process)
             },
             TransactionEvent::NoteAddAsset => self.on_note_add_asset(process),
+            TransactionEvent::AccountStorageAfterSetMapItem => {
+                self.on_account_storage_after_set_map_item(process)
+            },
         }
         .map_err(|err| ExecutionError::EventError(err.to_string()))?;
 
@@ -475,18 +726,77 @@ impl<A: AdviceProvider, T: TransactionAuthenticator> Host for TransactionHost<A,
             EpilogueEnd => self.tx_progress.end_epilogue(process.clk()),
         }
 
+        #[cfg(feature = "tracing")]
+        self.trace_stage(event, process.clk());
+
+        Ok(HostResponse::None)
+    }
+
+    fn on_debug<S: ProcessState>(
+        &mut self,
+        process: &S,
+        options: &DebugOptions,
+    ) -> Result<HostResponse, ExecutionError> {
+        // a debug instruction never fails the execution, and so a malformed note pointer is
+        // reported as the instruction being executed outside of a note
+        let note_id = Self::get_current_note_id(process).ok().flatten();
+        let output = DebugOutput::capture(process, *options, note_id);
+        match &self.debug_sink {
+            Some(debug_sink) => debug_sink.on_debug(&output),
+            #[cfg(feature = "std")]
+            None => std::print!("{output}"),
+            #[cfg(not(feature = "std"))]
+            None => (),
+        }
+
         Ok(HostResponse::None)
     }
 
     fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
-        let err_msg = self
-            .error_messages
-            .get(&err_code)
-            .map_or("Unknown error".to_string(), |msg| msg.to_string());
-        ExecutionError::FailedAssertion {
-            clk: process.clk(),
-            err_code,
-            err_msg: Some(err_msg),
+        let mut err = assertion_failed(process, &self.error_messages, err_code);
+
+        // name the assets which do not add up, as the kernel only compares the vault roots
+        if err_code == ERR_EPILOGUE_ASSETS_DONT_ADD_UP {
+            if let (
+                Some(reconciliation),
+                ExecutionError::FailedAssertion { err_msg: Some(err_msg), .. },
+            ) = (self.asset_reconciliation(), &mut err)
+            {
+                err_msg.push_str(&format!(": {reconciliation}"));
+            }
         }
+
+        err
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the error for a failed assertion with the specified error code.
+///
+/// The error message consists of the message associated with the error code in `error_messages`,
+/// followed by the locations of the assertions using this error code in the MASM sources of the
+/// transaction kernel and its libraries (e.g., `asm/kernels/transaction/lib/note.masm:42`).
+pub(crate) fn assertion_failed<S: ProcessState>(
+    process: &S,
+    error_messages: &BTreeMap<u32, &'static str>,
+    err_code: u32,
+) -> ExecutionError {
+    let mut err_msg = error_messages
+        .get(&err_code)
+        .map_or("Unknown error".to_string(), |msg| msg.to_string());
+
+    let locations = assertion_locations(err_code)
+        .map(|location| location.to_string())
+        .collect::<Vec<_>>();
+    if !locations.is_empty() {
+        err_msg.push_str(&format!(" (at {})", locations.join(", ")));
+    }
+
+    ExecutionError::FailedAssertion {
+        clk: process.clk(),
+        err_code,
+        err_msg: Some(err_msg),
     }
 }
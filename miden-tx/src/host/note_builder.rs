@@ -1,6 +1,6 @@
 use miden_objects::{
     assets::Asset,
-    notes::{Note, NoteAssets, NoteHeader, NoteId},
+    notes::{Note, NoteAssets, NoteId, PartialNote},
 };
 
 use super::{Digest, NoteMetadata, NoteRecipient, OutputNote, TransactionKernelError};
@@ -49,6 +49,16 @@ impl OutputNoteBuilder {
         }
     }
 
+    /// Returns the ID of the note with the assets added so far.
+    pub fn id(&self) -> NoteId {
+        NoteId::new(self.recipient_digest, self.assets.commitment())
+    }
+
+    /// Returns the assets added to the note so far.
+    pub fn assets(&self) -> &NoteAssets {
+        &self.assets
+    }
+
     /// Adds the specified asset to the note.
     ///
     /// # Errors
@@ -67,6 +77,9 @@ impl OutputNoteBuilder {
     }
 
     /// Converts this builder to an [OutputNote].
+    ///
+    /// If the recipient details are not known, i.e., the note was created from a recipient digest
+    /// only, the builder is converted into an [OutputNote::Partial].
     pub fn build(self) -> OutputNote {
         match self.recipient {
             Some(recipient) => {
@@ -74,9 +87,8 @@ impl OutputNoteBuilder {
                 OutputNote::Full(note)
             },
             None => {
-                let note_id = NoteId::new(self.recipient_digest, self.assets.commitment());
-                let header = NoteHeader::new(note_id, self.metadata);
-                OutputNote::Header(header)
+                let note = PartialNote::new(self.metadata, self.recipient_digest, self.assets);
+                OutputNote::Partial(note)
             },
         }
     }
@@ -2,8 +2,9 @@ use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 use core::cell::RefCell;
 
 use miden_objects::{
-    accounts::{AccountDelta, AuthSecretKey},
+    accounts::AuthSecretKey,
     crypto::dsa::rpo_falcon512::{self, Polynomial},
+    transaction::TransactionSummary,
 };
 use rand::Rng;
 use vm_processor::{Digest, Felt, Word};
@@ -26,16 +27,17 @@ pub trait TransactionAuthenticator {
     /// injector.
     ///
     /// - `pub_key`: The public key used for signature generation.
-    /// - `message`: The message to sign, usually a commitment to the transaction data.
-    /// - `account_delta`: An informational parameter describing the changes made to
-    ///   the account up to the point of calling `get_signature()`. This allows the
-    ///   authenticator to review any alterations to the account prior to signing.
-    ///   It should not be directly used in the signature computation.
+    /// - `message`: The message to sign, which is the commitment to `tx_summary`.
+    /// - `tx_summary`: The summary of the transaction to which the message commits, including
+    ///   the changes made to the account up to the point of calling `get_signature()`. This
+    ///   allows the authenticator to review the transaction prior to signing, and to verify
+    ///   that the message commits to it via [TransactionSummary::to_commitment()]. It should not
+    ///   be directly used in the signature computation.
     fn get_signature(
         &self,
         pub_key: Word,
         message: Word,
-        account_delta: &AccountDelta,
+        tx_summary: &TransactionSummary,
     ) -> Result<Vec<Felt>, AuthenticationError>;
 }
 
@@ -50,15 +52,26 @@ pub struct BasicAuthenticator<R> {
     rng: RefCell<R>,
 }
 
-impl<R: Rng> BasicAuthenticator<R> {
-    #[cfg(feature = "std")]
-    pub fn new(keys: &[(Word, AuthSecretKey)]) -> BasicAuthenticator<rand::rngs::StdRng> {
+#[cfg(feature = "std")]
+impl BasicAuthenticator<rand::rngs::StdRng> {
+    /// Returns a new [BasicAuthenticator] for the provided keys, seeded from the OS entropy source.
+    ///
+    /// This constructor is available only with the `std` feature; in `no_std` environments (e.g.,
+    /// wasm32), use [BasicAuthenticator::new_with_rng()] with an RNG seeded by the host instead.
+    pub fn new(keys: &[(Word, AuthSecretKey)]) -> Self {
         use rand::{rngs::StdRng, SeedableRng};
 
-        let rng = StdRng::from_entropy();
-        BasicAuthenticator::<StdRng>::new_with_rng(keys, rng)
+        Self::new_with_rng(keys, StdRng::from_entropy())
     }
+}
 
+impl<R: Rng> BasicAuthenticator<R> {
+    /// Returns a new [BasicAuthenticator] for the provided keys, using the provided RNG to
+    /// generate signatures.
+    ///
+    /// Any [FeltRng](miden_objects::crypto::rand::FeltRng) can be used as the RNG; in particular,
+    /// tests and benchmarks which need reproducible signatures can use the `DeterministicRng`
+    /// exported by the `testing` module of `miden-objects`.
     pub fn new_with_rng(keys: &[(Word, AuthSecretKey)], rng: R) -> Self {
         let mut key_map = BTreeMap::new();
         for (word, secret_key) in keys {
@@ -83,9 +96,9 @@ impl<R: Rng> TransactionAuthenticator for BasicAuthenticator<R> {
         &self,
         pub_key: Word,
         message: Word,
-        account_delta: &AccountDelta,
+        tx_summary: &TransactionSummary,
     ) -> Result<Vec<Felt>, AuthenticationError> {
-        let _ = account_delta;
+        let _ = tx_summary;
         let mut rng = self.rng.borrow_mut();
 
         match self.keys.get(&pub_key.into()) {
@@ -155,7 +168,7 @@ impl TransactionAuthenticator for () {
         &self,
         _pub_key: Word,
         _message: Word,
-        _account_delta: &AccountDelta,
+        _tx_summary: &TransactionSummary,
     ) -> Result<Vec<Felt>, AuthenticationError> {
         Err(AuthenticationError::RejectedSignature(
             "Default authenticator cannot provide signatures".to_string(),
@@ -1,4 +1,6 @@
+use alloc::string::String;
 pub use alloc::vec::Vec;
+use core::fmt::Write;
 
 use miden_objects::notes::NoteId;
 
@@ -84,6 +86,90 @@ impl TransactionProgress {
     pub fn end_epilogue(&mut self, cycle: u32) {
         self.epilogue.set_end(cycle);
     }
+
+    // EXPORTERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the transaction stages in the Chrome trace-event JSON format, which can be loaded
+    /// into profiling UIs such as `chrome://tracing` or Perfetto.
+    ///
+    /// Each completed stage is exported as a complete (`"ph": "X"`) event on a single thread, with
+    /// note executions nested inside the notes processing stage and carrying the ID of the
+    /// executed note in their arguments. Timestamps and durations are expressed in VM cycles,
+    /// which the UIs display as microseconds.
+    pub fn to_chrome_trace(&self) -> String {
+        let mut events = Vec::new();
+        for (stage, note_id, interval) in self.stages() {
+            let (Some(start), Some(len)) = (interval.start(), interval.len()) else {
+                continue;
+            };
+
+            let mut event = format!(
+                "{{\"name\":\"{stage}\",\"cat\":\"transaction\",\"ph\":\"X\",\
+                \"ts\":{start},\"dur\":{len},\"pid\":0,\"tid\":0"
+            );
+            if let Some(note_id) = note_id {
+                write!(event, ",\"args\":{{\"note_id\":\"{}\"}}", note_id.to_hex())
+                    .expect("writing to a string must not fail");
+            }
+            event.push('}');
+            events.push(event);
+        }
+
+        format!("{{\"traceEvents\":[{}]}}", events.join(","))
+    }
+
+    /// Returns the transaction stages in the folded-stack format, which can be rendered by
+    /// flamegraph tools such as `inferno` or `flamegraph.pl`.
+    ///
+    /// Each line contains a `;`-separated stack of stages followed by the number of cycles spent in
+    /// the innermost stage itself; the cycles of note executions are thus excluded from the notes
+    /// processing stage. Note executions are keyed by the IDs of the executed notes, e.g.
+    /// `notes_processing;note_execution:0x1234... 1500`.
+    pub fn to_folded_stacks(&self) -> String {
+        let notes_cycles: u32 =
+            self.note_execution.iter().filter_map(|(_, interval)| interval.len()).sum();
+
+        let mut folded = String::new();
+        for (stage, note_id, interval) in self.stages() {
+            let Some(mut cycles) = interval.len() else {
+                continue;
+            };
+
+            match note_id {
+                Some(note_id) => {
+                    write!(folded, "notes_processing;{stage}:{}", note_id.to_hex())
+                        .expect("writing to a string must not fail");
+                },
+                None => {
+                    if stage == "notes_processing" {
+                        cycles = cycles.saturating_sub(notes_cycles);
+                    }
+                    folded.push_str(stage);
+                },
+            }
+            writeln!(folded, " {cycles}").expect("writing to a string must not fail");
+        }
+
+        folded
+    }
+
+    /// Returns the name, the ID of the executed note (for note executions), and the cycle
+    /// interval of each stage, in the order in which the stages start.
+    fn stages(&self) -> Vec<(&'static str, Option<&NoteId>, &CycleInterval)> {
+        let mut stages = vec![
+            ("prologue", None, &self.prologue),
+            ("notes_processing", None, &self.notes_processing),
+        ];
+        stages.extend(
+            self.note_execution
+                .iter()
+                .map(|(note_id, interval)| ("note_execution", Some(note_id), interval)),
+        );
+        stages.push(("tx_script_processing", None, &self.tx_script_processing));
+        stages.push(("epilogue", None, &self.epilogue));
+        stages
+    }
 }
 
 /// Stores the cycles corresponding to the start and the end of an interval.
@@ -106,6 +192,16 @@ impl CycleInterval {
         self.end = Some(e);
     }
 
+    /// Returns the cycle at which the interval starts, if it has started.
+    pub fn start(&self) -> Option<u32> {
+        self.start
+    }
+
+    /// Returns the cycle at which the interval ends, if it has ended.
+    pub fn end(&self) -> Option<u32> {
+        self.end
+    }
+
     /// Calculate the length of the interval
     pub fn len(&self) -> Option<u32> {
         if let Some(start) = self.start {
@@ -118,3 +214,53 @@ impl CycleInterval {
         None
     }
 }
+
+#[cfg(test)]
+mod test {
+    use miden_objects::{notes::NoteId, Felt, ZERO};
+
+    use super::TransactionProgress;
+
+    fn tx_progress(note_id: NoteId) -> TransactionProgress {
+        let mut tx_progress = TransactionProgress::default();
+        tx_progress.start_prologue(1);
+        tx_progress.end_prologue(100);
+        tx_progress.start_notes_processing(101);
+        tx_progress.start_note_execution(110, note_id);
+        tx_progress.end_note_execution(150);
+        tx_progress.end_notes_processing(160);
+        tx_progress.start_tx_script_processing(161);
+        tx_progress.end_tx_script_processing(170);
+        tx_progress.start_epilogue(171);
+        tx_progress
+    }
+
+    #[test]
+    fn export_folded_stacks() {
+        let note_id = NoteId::from([Felt::new(1), ZERO, ZERO, ZERO]);
+        let folded = tx_progress(note_id).to_folded_stacks();
+
+        // the epilogue has not ended, and thus is not exported
+        let expected = format!(
+            "prologue 99\nnotes_processing 19\nnotes_processing;note_execution:{} 40\n\
+            tx_script_processing 9\n",
+            note_id.to_hex()
+        );
+        assert_eq!(folded, expected);
+    }
+
+    #[test]
+    fn export_chrome_trace() {
+        let note_id = NoteId::from([Felt::new(1), ZERO, ZERO, ZERO]);
+        let trace = tx_progress(note_id).to_chrome_trace();
+
+        assert!(trace.starts_with("{\"traceEvents\":[{\"name\":\"prologue\""));
+        assert!(trace.contains(&format!(
+            "{{\"name\":\"note_execution\",\"cat\":\"transaction\",\"ph\":\"X\",\"ts\":110,\
+            \"dur\":40,\"pid\":0,\"tid\":0,\"args\":{{\"note_id\":\"{}\"}}}}",
+            note_id.to_hex()
+        )));
+        assert!(!trace.contains("epilogue"));
+        assert_eq!(trace.matches("\"ph\":\"X\"").count(), 4);
+    }
+}
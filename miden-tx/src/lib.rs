@@ -18,14 +18,24 @@ use miden_objects::{
 use vm_processor::{ExecutionError, RecAdviceProvider};
 
 mod compiler;
-pub use compiler::{ScriptTarget, TransactionCompiler};
+pub use compiler::{
+    KnownProcedure, ScriptIntent, ScriptTarget, TransactionCompiler, TransactionScriptBuilder,
+};
+
+mod coverage;
+pub use coverage::{KernelCoverage, KernelCoverageReport, KernelProcedure, ProcedureCoverage};
 
 mod executor;
 pub use executor::{DataStore, TransactionExecutor};
 
+mod note_checker;
+pub use note_checker::{Consumable, NoteConsumptionChecker, NoteSkipReason};
+
 pub mod host;
 pub use host::{
-    BasicAuthenticator, TransactionAuthenticator, TransactionHost, TransactionProgress,
+    replay_transaction, AccountViewHost, AdviceDivergence, AssetImbalance, AssetReconciliation,
+    BasicAuthenticator, DebugOutput, DebugSink, DebugTransactionHost, ReplayAdviceProvider,
+    TransactionAuthenticator, TransactionDiagnostics, TransactionHost, TransactionProgress,
 };
 
 mod prover;
@@ -40,6 +50,9 @@ pub use error::{
     TransactionProverError, TransactionVerifierError, KERNEL_ERRORS,
 };
 
+#[cfg(any(feature = "testing", test))]
+pub mod testing;
+
 #[cfg(test)]
 mod tests;
 
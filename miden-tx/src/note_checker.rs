@@ -0,0 +1,216 @@
+use alloc::collections::BTreeMap;
+
+use miden_lib::notes::utils::well_known_note_scripts;
+use miden_objects::{accounts::AccountId, notes::Note, Digest, NoteError};
+
+use crate::TransactionCompilerError;
+
+// CONSUMABLE
+// ================================================================================================
+
+/// Describes whether and when a note can be consumed by an account, as determined by
+/// [NoteConsumptionChecker::check()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Consumable {
+    /// The note can be consumed by the account now.
+    Now,
+    /// The note can be consumed by the account in transactions against the specified block or
+    /// any later block.
+    After(u32),
+    /// The note can never be consumed by the account for the specified reason.
+    Never(NoteSkipReason),
+}
+
+// NOTE SKIP REASON
+// ================================================================================================
+
+/// The reason for which a note cannot be consumed by an account.
+///
+/// This is also the reason for which a candidate note is not consumed by
+/// [TransactionExecutor::execute_claimable_notes()](crate::TransactionExecutor::execute_claimable_notes).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoteSkipReason {
+    /// The note is a P2ID or P2IDR note which can be consumed only by the specified account.
+    WrongTarget(AccountId),
+    /// The note is a P2IDR note which can be reclaimed by its sender only from the specified
+    /// block on.
+    RecallHeightNotReached(u32),
+    /// The inputs of the note are not valid for its standard script.
+    InvalidInputs,
+    /// The root of the note script is not the root of any standard note script.
+    UnknownScript(Digest),
+    /// The note script calls the specified procedure, which is not exposed by the account.
+    ProcedureNotFound(Digest),
+    /// The note script requires note args, which were not provided.
+    MissingNoteArgs,
+    /// The note script could not be compiled.
+    InvalidScript(TransactionCompilerError),
+}
+
+// NOTE CONSUMPTION CHECKER
+// ================================================================================================
+
+/// Statically checks whether notes with standard scripts can be consumed by an account, e.g., to
+/// show the notes a wallet can claim in its inbox.
+///
+/// The checker recognizes the note scripts of the Miden library by their roots. For P2ID and
+/// P2IDR notes, the inputs of the note are checked against the account and the reclaim height;
+/// notes with other standard scripts are assumed to be consumable by any account.
+///
+/// The checks do not execute the note scripts, and thus, do not guarantee that a note is consumed
+/// successfully, e.g., if the account does not expose the procedures called by the note script.
+#[derive(Debug, Clone)]
+pub struct NoteConsumptionChecker {
+    standard_scripts: BTreeMap<Digest, &'static str>,
+}
+
+impl NoteConsumptionChecker {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NoteConsumptionChecker] recognizing the standard note scripts of the Miden
+    /// library.
+    ///
+    /// # Errors
+    /// Returns an error if deserialization or compilation of the standard note scripts fails.
+    pub fn new() -> Result<Self, NoteError> {
+        Ok(Self {
+            standard_scripts: well_known_note_scripts()?,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the name of the standard script of the provided note, or None if the note script
+    /// is not a standard note script.
+    pub fn script_name(&self, note: &Note) -> Option<&'static str> {
+        self.standard_scripts.get(&note.script().hash()).copied()
+    }
+
+    /// Determines whether the provided note can be consumed by the specified account in a
+    /// transaction against the block with the specified number.
+    pub fn check(&self, note: &Note, account_id: AccountId, block_num: u32) -> Consumable {
+        let result = match self.script_name(note) {
+            Some("P2ID") => check_p2id_inputs(note, account_id),
+            Some("P2IDR") => check_p2idr_inputs(note, account_id),
+            Some(_) => Ok(None),
+            None => Err(NoteSkipReason::UnknownScript(note.script().hash())),
+        };
+
+        match result {
+            Ok(Some(recall_height)) if block_num < recall_height => {
+                Consumable::After(recall_height)
+            },
+            Ok(_) => Consumable::Now,
+            Err(reason) => Consumable::Never(reason),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that the P2ID note targets the specified account.
+fn check_p2id_inputs(note: &Note, account_id: AccountId) -> Result<Option<u32>, NoteSkipReason> {
+    let [target] = note.inputs().values() else {
+        return Err(NoteSkipReason::InvalidInputs);
+    };
+    let target = AccountId::try_from(*target).map_err(|_| NoteSkipReason::InvalidInputs)?;
+
+    if target != account_id {
+        return Err(NoteSkipReason::WrongTarget(target));
+    }
+    Ok(None)
+}
+
+/// Checks that the P2IDR note targets the specified account, or that it was sent by the account.
+///
+/// Returns the recall height if the note can be consumed by the account only after reclaiming
+/// it becomes possible.
+fn check_p2idr_inputs(note: &Note, account_id: AccountId) -> Result<Option<u32>, NoteSkipReason> {
+    let [target, recall_height] = note.inputs().values() else {
+        return Err(NoteSkipReason::InvalidInputs);
+    };
+    let target = AccountId::try_from(*target).map_err(|_| NoteSkipReason::InvalidInputs)?;
+    let recall_height =
+        u32::try_from(recall_height.as_int()).map_err(|_| NoteSkipReason::InvalidInputs)?;
+
+    if target == account_id {
+        Ok(None)
+    } else if note.metadata().sender() == account_id {
+        Ok(Some(recall_height))
+    } else {
+        Err(NoteSkipReason::WrongTarget(target))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_lib::notes::{create_p2id_note, create_p2idr_note, create_swap_note};
+    use miden_objects::{
+        accounts::account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        assets::{Asset, FungibleAsset},
+        crypto::rand::RpoRandomCoin,
+        notes::NoteType,
+        Felt, ZERO,
+    };
+
+    use super::*;
+
+    fn asset(faucet_id: u64) -> Asset {
+        FungibleAsset::new(AccountId::try_from(faucet_id).unwrap(), 100).unwrap().into()
+    }
+
+    fn rng() -> RpoRandomCoin {
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)])
+    }
+
+    #[test]
+    fn check_standard_notes() {
+        let checker = NoteConsumptionChecker::new().unwrap();
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        let assets = vec![asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
+
+        let p2id = create_p2id_note(sender, target, assets.clone(), NoteType::Public, ZERO, rng())
+            .unwrap();
+        assert_eq!(checker.script_name(&p2id), Some("P2ID"));
+        assert_eq!(checker.check(&p2id, target, 0), Consumable::Now);
+        assert_eq!(
+            checker.check(&p2id, sender, 0),
+            Consumable::Never(NoteSkipReason::WrongTarget(target))
+        );
+
+        // the target can consume a P2IDR note at any time, the sender after the recall height
+        let p2idr =
+            create_p2idr_note(sender, target, assets, NoteType::Public, ZERO, 10, rng()).unwrap();
+        assert_eq!(checker.check(&p2idr, target, 0), Consumable::Now);
+        assert_eq!(checker.check(&p2idr, sender, 9), Consumable::After(10));
+        assert_eq!(checker.check(&p2idr, sender, 10), Consumable::Now);
+        let other = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        assert_eq!(
+            checker.check(&p2idr, other, 10),
+            Consumable::Never(NoteSkipReason::WrongTarget(target))
+        );
+
+        // other standard notes can be consumed by any account
+        let (swap, _) = create_swap_note(
+            sender,
+            asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN),
+            asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1),
+            NoteType::Public,
+            ZERO,
+            rng(),
+        )
+        .unwrap();
+        assert_eq!(checker.check(&swap, target, 0), Consumable::Now);
+    }
+}
@@ -2,11 +2,13 @@ use alloc::vec::Vec;
 
 use miden_lib::transaction::{ToTransactionKernelInputs, TransactionKernel};
 use miden_objects::{
-    accounts::delta::AccountUpdateDetails,
-    notes::Nullifier,
+    accounts::{delta::AccountUpdateDetails, salt_account_hash, NetworkParams},
     transaction::{
-        InputNotes, OutputNote, ProvenTransaction, ProvenTransactionBuilder, TransactionWitness,
+        InputNoteCommitment, InputNotes, KernelVersion, OutputNote, ProtocolLimits,
+        ProvenTransaction, ProvenTransactionBuilder, TransactionWitness,
     },
+    vm::ProgramInfo,
+    EMPTY_WORD,
 };
 use miden_prover::prove;
 pub use miden_prover::ProvingOptions;
@@ -18,8 +20,17 @@ use super::{TransactionHost, TransactionProverError};
 ///
 /// Transaction prover exposes the `prove_transaction` method which takes a [TransactionWitness],
 /// or anything that can be converted into a [TransactionWitness], and returns a [ProvenTransaction].
+///
+/// The resulting [ProvenTransaction] records the version of the transaction kernel it was proven
+/// with. Only transactions compiled against the transaction kernel of this prover can be proven.
+///
+/// Transactions are proven with the [NetworkParams] of the prover, which must match the parameters
+/// the transactions were executed with.
 pub struct TransactionProver {
     proof_options: ProvingOptions,
+    kernel_version: KernelVersion,
+    protocol_limits: Option<ProtocolLimits>,
+    network_params: NetworkParams,
 }
 
 impl TransactionProver {
@@ -27,7 +38,37 @@ impl TransactionProver {
     // --------------------------------------------------------------------------------------------
     /// Creates a new [TransactionProver] instance.
     pub fn new(proof_options: ProvingOptions) -> Self {
-        Self { proof_options }
+        Self {
+            proof_options,
+            kernel_version: TransactionKernel::version(),
+            protocol_limits: None,
+            network_params: NetworkParams::default(),
+        }
+    }
+
+    /// Puts the [ProtocolLimits] in place for the transactions proven by the returned instance of
+    /// [TransactionProver].
+    ///
+    /// Transactions whose output notes or account update exceed the limits are rejected. By
+    /// default, the size of the data published by a transaction is not limited.
+    pub fn with_protocol_limits(mut self, limits: ProtocolLimits) -> Self {
+        self.protocol_limits = Some(limits);
+        self
+    }
+
+    /// Sets the [NetworkParams] the transactions proven by the returned instance of
+    /// [TransactionProver] are proven with. By default, [NetworkParams::MAINNET] is used.
+    pub fn with_network_params(mut self, network_params: NetworkParams) -> Self {
+        self.network_params = network_params;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the version of the transaction kernel the transactions are proven with.
+    pub fn kernel_version(&self) -> KernelVersion {
+        self.kernel_version
     }
 
     // TRANSACTION PROVER
@@ -37,23 +78,46 @@ impl TransactionProver {
     ///
     /// # Errors
     /// - If the consumed note data in the transaction witness is corrupt.
+    /// - If the transaction program was not compiled against the transaction kernel of this
+    ///   prover.
     /// - If the transaction program cannot be proven.
     /// - If the transaction result is corrupt.
+    /// - If the account vault delta cannot be applied to the initial account vault.
+    /// - If the proven transaction exceeds the protocol limits of this prover.
     pub fn prove_transaction<T: Into<TransactionWitness>>(
         &self,
         transaction: T,
     ) -> Result<ProvenTransaction, TransactionProverError> {
         let tx_witness: TransactionWitness = transaction.into();
 
-        let input_notes: InputNotes<Nullifier> = tx_witness.tx_inputs().input_notes().into();
+        let input_notes: InputNotes<InputNoteCommitment> =
+            tx_witness.tx_inputs().input_notes().into();
         let account_id = tx_witness.account().id();
         let block_hash = tx_witness.block_header().hash();
 
+        let program_info =
+            ProgramInfo::new(tx_witness.program().hash(), tx_witness.program().kernel().clone());
+        if !self.kernel_version.matches(&program_info) {
+            return Err(TransactionProverError::UnsupportedKernelRoot(
+                KernelVersion::compute_root(&program_info),
+            ));
+        }
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "prove_transaction",
+            account_id = %account_id,
+            block_num = tx_witness.block_header().block_num(),
+            num_notes = input_notes.num_notes(),
+        )
+        .entered();
+
         // execute and prove
-        let (stack_inputs, advice_inputs) = tx_witness.get_kernel_inputs();
+        let (stack_inputs, advice_inputs) =
+            tx_witness.get_kernel_inputs_with_params(&self.network_params);
         let advice_provider: MemAdviceProvider = advice_inputs.into();
         let mut host: TransactionHost<_, ()> =
-            TransactionHost::new(tx_witness.account().into(), advice_provider, None);
+            TransactionHost::new(tx_witness.tx_inputs().account_stub(), advice_provider, None);
         let (stack_outputs, proof) =
             prove(tx_witness.program(), stack_inputs, &mut host, self.proof_options.clone())
                 .map_err(TransactionProverError::ProveTransactionProgramFailed)?;
@@ -61,18 +125,32 @@ impl TransactionProver {
         // extract transaction outputs and process transaction data
         let (advice_provider, account_delta, output_notes, _signatures) = host.into_parts();
         let (_, map, _) = advice_provider.into_parts();
-        let tx_outputs =
-            TransactionKernel::from_transaction_parts(&stack_outputs, &map.into(), output_notes)
-                .map_err(TransactionProverError::InvalidTransactionOutput)?;
+        let final_account_salt = tx_witness.tx_args().final_account_salt();
+        let tx_outputs = TransactionKernel::from_transaction_parts(
+            &stack_outputs,
+            &map.into(),
+            output_notes,
+            final_account_salt,
+        )
+        .map_err(TransactionProverError::InvalidTransactionOutput)?;
 
         // erase private note information (convert private full notes to just headers)
         let output_notes: Vec<_> = tx_outputs.output_notes.iter().map(OutputNote::shrink).collect();
 
+        // make sure the vault delta is consistent with the initial state of the account vault
+        account_delta
+            .vault()
+            .validate_against(tx_witness.account().vault())
+            .map_err(TransactionProverError::InvalidAccountVaultDelta)?;
+
         let builder = ProvenTransactionBuilder::new(
             account_id,
-            tx_witness.account().init_hash(),
-            tx_outputs.account.hash(),
+            tx_witness
+                .tx_inputs()
+                .account_init_commitment(tx_witness.tx_args().init_account_salt()),
+            salt_account_hash(tx_outputs.account.hash(), final_account_salt),
             block_hash,
+            self.kernel_version,
             proof,
         )
         .add_input_notes(input_notes)
@@ -93,9 +171,21 @@ impl TransactionProver {
 
                 builder.account_update_details(account_update_details)
             },
-            false => builder,
+            false => match final_account_salt.filter(|salt| *salt != EMPTY_WORD) {
+                Some(salt) => {
+                    builder.account_update_details(AccountUpdateDetails::PrivateSalted(salt))
+                },
+                None => builder,
+            },
         };
 
-        builder.build().map_err(TransactionProverError::ProvenTransactionError)
+        let proven_tx = builder.build().map_err(TransactionProverError::ProvenTransactionError)?;
+        if let Some(limits) = self.protocol_limits {
+            limits
+                .validate(&proven_tx)
+                .map_err(TransactionProverError::ProtocolLimitsExceeded)?;
+        }
+
+        Ok(proven_tx)
     }
 }
@@ -0,0 +1,2 @@
+mod workflow;
+pub use workflow::{TransactionWorkflow, WorkflowOutcome};
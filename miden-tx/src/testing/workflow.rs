@@ -0,0 +1,165 @@
+use miden_objects::{
+    accounts::{delta::AccountUpdateDetails, Account, AccountId},
+    notes::NoteId,
+    transaction::{ExecutedTransaction, ProvenTransaction, TransactionArgs},
+};
+use winter_maybe_async::{maybe_async, maybe_await};
+
+use crate::{
+    DataStore, ProvingOptions, TransactionAuthenticator, TransactionExecutor, TransactionProver,
+    TransactionVerifier,
+};
+
+// TRANSACTION WORKFLOW
+// ================================================================================================
+
+/// Test harness which takes a transaction through all stages of its lifecycle: execution,
+/// proving, verification, and application of the resulting delta to the initial account state.
+///
+/// After each stage, the harness asserts that the commitments produced by the stage match the
+/// ones produced by the preceding stages, and thus panics on the first stage that diverges.
+pub struct TransactionWorkflow {
+    prover: TransactionProver,
+    verifier: TransactionVerifier,
+}
+
+impl TransactionWorkflow {
+    /// Returns a new [TransactionWorkflow] which proves transactions with the default proving
+    /// options and verifies them at the specified security level.
+    pub fn new(proof_security_level: u32) -> Self {
+        Self::with_prover(
+            TransactionProver::new(ProvingOptions::default()),
+            TransactionVerifier::new(proof_security_level),
+        )
+    }
+
+    /// Returns a new [TransactionWorkflow] which uses the provided prover and verifier.
+    pub fn with_prover(prover: TransactionProver, verifier: TransactionVerifier) -> Self {
+        Self { prover, verifier }
+    }
+
+    /// Executes the specified transaction with the provided executor and takes the resulting
+    /// transaction through the remaining stages of the workflow.
+    ///
+    /// # Panics
+    /// Panics if the transaction cannot be executed, or if any of the stages of the workflow fails
+    /// or produces commitments inconsistent with the preceding stages.
+    #[maybe_async]
+    pub fn execute<D: DataStore, A: TransactionAuthenticator>(
+        &self,
+        executor: &TransactionExecutor<D, A>,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> WorkflowOutcome {
+        let executed_transaction =
+            maybe_await!(executor.execute_transaction(account_id, block_ref, notes, tx_args))
+                .expect("failed to execute the transaction");
+        assert_eq!(executed_transaction.account_id(), account_id, "executed account mismatch");
+        assert_eq!(
+            executed_transaction.block_header().block_num(),
+            block_ref,
+            "executed reference block mismatch"
+        );
+
+        self.run(executed_transaction)
+    }
+
+    /// Proves and verifies the provided executed transaction, and applies its account delta to
+    /// the initial state of the account.
+    ///
+    /// # Panics
+    /// Panics if any of the stages of the workflow fails or produces commitments inconsistent
+    /// with the preceding stages.
+    pub fn run(&self, executed_transaction: ExecutedTransaction) -> WorkflowOutcome {
+        // prove the transaction and check that the proven transaction commits to the same data
+        // as the executed one
+        let proven_transaction = self
+            .prover
+            .prove_transaction(executed_transaction.clone())
+            .expect("failed to prove the transaction");
+
+        assert_eq!(proven_transaction.id(), executed_transaction.id(), "transaction ID mismatch");
+        assert_eq!(
+            proven_transaction.account_id(),
+            executed_transaction.account_id(),
+            "proven account mismatch"
+        );
+        assert_eq!(
+            proven_transaction.block_ref(),
+            executed_transaction.block_header().hash(),
+            "proven reference block mismatch"
+        );
+        assert_eq!(
+            proven_transaction.account_update().init_state_hash(),
+            executed_transaction.initial_account().hash(),
+            "proven initial account state mismatch"
+        );
+        assert_eq!(
+            proven_transaction.account_update().final_state_hash(),
+            executed_transaction.final_account_commitment(),
+            "proven final account state mismatch"
+        );
+        assert_eq!(
+            proven_transaction.input_notes().commitment(),
+            executed_transaction.input_notes().commitment(),
+            "proven input notes mismatch"
+        );
+        assert_eq!(
+            proven_transaction.output_notes().commitment(),
+            executed_transaction.output_notes().commitment(),
+            "proven output notes mismatch"
+        );
+
+        // verify the proof
+        self.verifier
+            .verify(proven_transaction.clone())
+            .expect("failed to verify the transaction");
+
+        // apply the delta to the initial account state and check that the result matches the
+        // final account state of the transaction
+        let mut final_account = executed_transaction.initial_account().clone();
+        final_account
+            .apply_delta(executed_transaction.account_delta())
+            .expect("failed to apply the account delta");
+        assert_eq!(
+            final_account.hash(),
+            executed_transaction.final_account().hash(),
+            "final account state mismatch"
+        );
+
+        // public account updates must allow reconstructing the same final account state
+        match proven_transaction.account_update().details() {
+            AccountUpdateDetails::New(account) => {
+                assert_eq!(account.hash(), final_account.hash(), "published account mismatch")
+            },
+            AccountUpdateDetails::Delta(delta) => assert_eq!(
+                delta,
+                executed_transaction.account_delta(),
+                "published account delta mismatch"
+            ),
+            AccountUpdateDetails::Private | AccountUpdateDetails::PrivateSalted(_) => (),
+        }
+
+        WorkflowOutcome {
+            executed_transaction,
+            proven_transaction,
+            final_account,
+        }
+    }
+}
+
+// WORKFLOW OUTCOME
+// ================================================================================================
+
+/// Results of all stages of a [TransactionWorkflow].
+#[derive(Debug, Clone)]
+pub struct WorkflowOutcome {
+    /// The transaction as executed by the executor.
+    pub executed_transaction: ExecutedTransaction,
+    /// The transaction as proven by the prover; the transaction was successfully verified.
+    pub proven_transaction: ProvenTransaction,
+    /// The state of the account after the account delta of the transaction was applied to it.
+    pub final_account: Account,
+}
@@ -1,36 +1,49 @@
-use alloc::vec::Vec;
+use alloc::{rc::Rc, string::ToString, vec::Vec};
+use core::cell::RefCell;
 
-use miden_lib::transaction::{ToTransactionKernelInputs, TransactionKernel};
+use miden_lib::transaction::{
+    kernel_memory::GLOBAL_INPUTS_SECTION, memory::ACCT_ID_PTR, ToTransactionKernelInputs,
+    TransactionKernel, TransactionKernelError,
+};
 use miden_objects::{
     accounts::{
         account_id::testing::{
             ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
-            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
             ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
         },
-        Account, AccountCode,
+        Account, AccountCode, NetworkParams, StorageMapDelta,
+    },
+    assembly::{
+        Assembler, LibraryNamespace, LibraryPath, MaslLibrary, Module, ModuleAst, ProgramAst,
+        Version,
     },
-    assembly::{Assembler, ModuleAst, ProgramAst},
-    assets::{Asset, FungibleAsset},
+    assets::{Asset, AssetVault, FungibleAsset, PartialVault},
     block::BlockHeader,
     notes::{
         Note, NoteAssets, NoteExecutionHint, NoteHeader, NoteId, NoteInputs, NoteMetadata,
         NoteRecipient, NoteScript, NoteTag, NoteType,
     },
     transaction::{
-        ChainMmr, InputNote, InputNotes, ProvenTransaction, TransactionArgs, TransactionWitness,
+        ChainMmr, InputNote, InputNotes, KernelVersion, PreparedTransaction, ProtocolLimits,
+        ProvenTransaction, TransactionArgs, TransactionWitness,
     },
-    Felt, Word, ZERO,
+    vm::DebugOptions,
+    AdviceInputsError, Felt, Word, ZERO,
 };
 use miden_prover::ProvingOptions;
 use mock::{
-    constants::{non_fungible_asset, FUNGIBLE_ASSET_AMOUNT, MIN_PROOF_SECURITY_LEVEL},
+    constants::{
+        non_fungible_asset, CONSUMED_ASSET_2_AMOUNT, CONSUMED_ASSET_3_AMOUNT,
+        FUNGIBLE_ASSET_AMOUNT, MIN_PROOF_SECURITY_LEVEL,
+    },
     mock::{
         account::{
+            generate_account_seed, mock_account_code, mock_account_storage, AccountSeedType,
             MockAccountType, ACCOUNT_ADD_ASSET_TO_NOTE_MAST_ROOT, ACCOUNT_CREATE_NOTE_MAST_ROOT,
             ACCOUNT_INCR_NONCE_MAST_ROOT, ACCOUNT_REMOVE_ASSET_MAST_ROOT,
             ACCOUNT_SET_CODE_MAST_ROOT, ACCOUNT_SET_ITEM_MAST_ROOT, ACCOUNT_SET_MAP_ITEM_MAST_ROOT,
-            STORAGE_INDEX_0, STORAGE_INDEX_2,
+            STORAGE_INDEX_0, STORAGE_INDEX_2, STORAGE_LEAVES_2, STORAGE_VALUE_0,
         },
         notes::AssetPreservationStatus,
         transaction::mock_inputs,
@@ -39,13 +52,19 @@ use mock::{
 };
 use vm_processor::{
     utils::{Deserializable, Serializable},
-    Digest, MemAdviceProvider,
+    AdviceInputs, AdviceSource, Digest, ExecutionError, ExecutionOptions, MemAdviceProvider,
 };
-use winter_maybe_async::maybe_async;
+use winter_maybe_async::{maybe_async, maybe_await};
 
 use super::{
-    AccountId, DataStore, DataStoreError, TransactionExecutor, TransactionHost, TransactionInputs,
-    TransactionProver, TransactionVerifier,
+    error::ERR_EPILOGUE_ASSETS_DONT_ADD_UP,
+    host::{
+        AccountProcedureIndexMap, AdviceRequest, AssetImbalance, DebugOutput, DebugSink,
+        DiagnosticsCause, TransactionStage,
+    },
+    replay_transaction, AccountId, DataStore, DataStoreError, DebugTransactionHost, KernelCoverage,
+    TransactionCompilerError, TransactionExecutor, TransactionExecutorError, TransactionHost,
+    TransactionInputs, TransactionProver, TransactionVerifier, TransactionVerifierError,
 };
 
 // TESTS
@@ -84,6 +103,102 @@ fn transaction_executor_witness() {
         result.stack_outputs(),
         &map.into(),
         output_notes,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(executed_transaction.final_account().hash(), tx_outputs.account.hash());
+    assert_eq!(executed_transaction.output_notes(), &tx_outputs.output_notes);
+}
+
+#[test]
+fn transaction_executor_preloaded_account_code() {
+    let data_store = MockDataStore::default();
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None);
+
+    let account_code = data_store.account.code();
+    let preloaded = executor
+        .preload_account_code(account_code.root(), account_code.module().clone())
+        .unwrap();
+    assert_eq!(preloaded.procedures(), account_code.procedures());
+
+    // loading the account reuses the preloaded code
+    let account_id = data_store.account.id();
+    assert_eq!(executor.load_account(account_id).unwrap(), preloaded);
+
+    // repeated transactions against the account produce the same result
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let executed = [0, 1].map(|_| {
+        executor
+            .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args().clone())
+            .unwrap()
+    });
+    assert_eq!(executed[0].final_account().hash(), executed[1].final_account().hash());
+
+    // the procedure map derived from the account code matches the one derived from advice data
+    let tx_inputs = data_store.get_transaction_inputs(account_id, block_ref, &note_ids).unwrap();
+    let advice_inputs =
+        TransactionKernel::build_advice_inputs(&tx_inputs, data_store.tx_args()).unwrap();
+    let advice_provider = MemAdviceProvider::from(advice_inputs.build());
+    assert_eq!(
+        AccountProcedureIndexMap::from_account_code(account_code),
+        AccountProcedureIndexMap::new(account_code.root(), &advice_provider)
+    );
+}
+
+#[test]
+fn transaction_executor_minimized_witness() {
+    let data_store = MockDataStore::default();
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None);
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // add advice data which is never accessed during execution
+    let unused_key = Digest::from([Felt::new(7), ZERO, ZERO, ZERO]);
+    let mut tx_args = data_store.tx_args().clone();
+    tx_args.extend_advice_map([(unused_key, vec![Felt::new(7); 8])]).unwrap();
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+    let tx_witness: TransactionWitness = executed_transaction.clone().into();
+    assert!(!tx_witness.is_minimal());
+    let (_, advice_inputs) = tx_witness.get_kernel_inputs();
+    assert!(advice_inputs.mapped_values(&unused_key).is_some());
+    let full_advice_size = advice_data_size(advice_inputs);
+
+    let tx_witness = tx_witness.minimize();
+    assert!(tx_witness.is_minimal());
+    assert!(tx_witness.tx_args().advice_map().get(&unused_key).is_none());
+
+    // the minimized witness contains only the accessed advice data, which is smaller than the
+    // advice data of the full witness by at least the unused advice map entry
+    let (stack_inputs, advice_inputs) = tx_witness.get_kernel_inputs();
+    assert!(advice_inputs.mapped_values(&unused_key).is_none());
+    let minimized_advice_size = advice_data_size(advice_inputs.clone());
+    assert!(minimized_advice_size + 8 <= full_advice_size);
+
+    // use the minimized witness to execute the transaction again
+    let mem_advice_provider: MemAdviceProvider = advice_inputs.into();
+    let mut host: TransactionHost<MemAdviceProvider, ()> =
+        TransactionHost::new(tx_witness.account().into(), mem_advice_provider, None);
+    let result =
+        vm_processor::execute(tx_witness.program(), stack_inputs, &mut host, Default::default())
+            .unwrap();
+
+    let (advice_provider, _, output_notes, _signatures) = host.into_parts();
+    let (_, map, _) = advice_provider.into_parts();
+    let tx_outputs = TransactionKernel::from_transaction_parts(
+        result.stack_outputs(),
+        &map.into(),
+        output_notes,
+        None,
     )
     .unwrap();
 
@@ -91,6 +206,58 @@ fn transaction_executor_witness() {
     assert_eq!(executed_transaction.output_notes(), &tx_outputs.output_notes);
 }
 
+#[test]
+fn replay_transaction_detects_divergence() {
+    let data_store = MockDataStore::default();
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None);
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args().clone())
+        .unwrap();
+    let tx_witness: TransactionWitness = executed_transaction.into();
+    let (stack_inputs, advice_inputs) = tx_witness.get_kernel_inputs();
+
+    // replaying against the recorded advice witness does not diverge
+    let divergence = replay_transaction(
+        tx_witness.program(),
+        tx_witness.account().into(),
+        stack_inputs.clone(),
+        advice_inputs.clone(),
+        tx_witness.advice_witness().clone(),
+    )
+    .unwrap();
+    assert_eq!(divergence, None);
+
+    // replaying against a recording with different note data diverges at the first read of it
+    let notes_commitment = tx_witness.input_notes().commitment();
+    let recorded_note_data =
+        tx_witness.advice_witness().mapped_values(&notes_commitment).unwrap().to_vec();
+    let recording = tx_witness.advice_witness().clone().with_map([(notes_commitment, vec![ZERO])]);
+
+    let divergence = replay_transaction(
+        tx_witness.program(),
+        tx_witness.account().into(),
+        stack_inputs,
+        advice_inputs,
+        recording,
+    )
+    .unwrap()
+    .unwrap();
+    assert!(matches!(
+        divergence.request,
+        AdviceRequest::PushStack(AdviceSource::Map { key, .. }) if key == Word::from(notes_commitment)
+    ));
+    assert_eq!(divergence.expected, Ok(vec![ZERO]));
+    assert_eq!(divergence.actual, Ok(recorded_note_data));
+}
+
 #[test]
 fn executed_transaction_account_delta() {
     let data_store = MockDataStore::new(AssetPreservationStatus::PreservedWithAccountVaultDelta);
@@ -348,6 +515,73 @@ fn executed_transaction_account_delta() {
     );
 }
 
+#[test]
+fn executed_transaction_storage_map_delta() {
+    let data_store = MockDataStore::new(AssetPreservationStatus::PreservedWithAccountVaultDelta);
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None);
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let new_map_key = [Felt::new(14), Felt::new(15), Felt::new(16), Felt::new(17)];
+    let new_map_value_1 = [Felt::new(18), Felt::new(19), Felt::new(20), Felt::new(21)];
+    let new_map_value_2 = [Felt::new(22), Felt::new(23), Felt::new(24), Felt::new(25)];
+    let (reverted_map_key, reverted_map_value) = STORAGE_LEAVES_2[0];
+    let (cleared_map_key, _) = STORAGE_LEAVES_2[1];
+
+    let tx_script = format!(
+        "\
+        proc.set_map_item
+            call.{ACCOUNT_SET_MAP_ITEM_MAST_ROOT} dropw dropw dropw
+            # => []
+        end
+
+        begin
+            # a new key is set twice, only the last value is part of the delta
+            push.{NEW_MAP_VALUE_1} push.{NEW_MAP_KEY} push.{STORAGE_INDEX_2} exec.set_map_item
+            push.{NEW_MAP_VALUE_2} push.{NEW_MAP_KEY} push.{STORAGE_INDEX_2} exec.set_map_item
+
+            # an existing key is set back to its initial value, which is not part of the delta
+            push.{NEW_MAP_VALUE_1} push.{REVERTED_MAP_KEY} push.{STORAGE_INDEX_2} exec.set_map_item
+            push.{REVERTED_MAP_VALUE} push.{REVERTED_MAP_KEY} push.{STORAGE_INDEX_2}
+            exec.set_map_item
+
+            # an existing key is cleared
+            padw push.{CLEARED_MAP_KEY} push.{STORAGE_INDEX_2} exec.set_map_item
+
+            push.1 call.{ACCOUNT_INCR_NONCE_MAST_ROOT} drop
+        end
+    ",
+        NEW_MAP_KEY = prepare_word(&new_map_key),
+        NEW_MAP_VALUE_1 = prepare_word(&new_map_value_1),
+        NEW_MAP_VALUE_2 = prepare_word(&new_map_value_2),
+        REVERTED_MAP_KEY = prepare_word(&reverted_map_key.into()),
+        REVERTED_MAP_VALUE = prepare_word(&reverted_map_value),
+        CLEARED_MAP_KEY = prepare_word(&cleared_map_key.into()),
+    );
+    let tx_script_code = ProgramAst::parse(&tx_script).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+
+    let expected_map_delta =
+        StorageMapDelta::from(vec![cleared_map_key.into()], vec![(new_map_key, new_map_value_2)]);
+    assert_eq!(
+        executed_transaction.account_delta().storage().updated_maps,
+        vec![(STORAGE_INDEX_2, expected_map_delta)]
+    );
+
+    // applying the delta to the initial account results in the final account storage
+    let mut account = executed_transaction.initial_account().clone();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(account.storage().root(), executed_transaction.final_account().storage_root());
+}
+
 #[test]
 fn executed_transaction_output_notes() {
     let data_store = MockDataStore::new(AssetPreservationStatus::PreservedWithAccountVaultDelta);
@@ -537,6 +771,151 @@ fn executed_transaction_output_notes() {
     assert_eq!(NoteHeader::from(created_note), NoteHeader::new(note_id, *note_metadata));
 }
 
+#[test]
+fn executed_transaction_duplicate_output_notes() {
+    let data_store = MockDataStore::new(AssetPreservationStatus::PreservedWithAccountVaultDelta);
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None);
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let removed_asset = Asset::Fungible(
+        FungibleAsset::new(
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().expect("id is valid"),
+            FUNGIBLE_ASSET_AMOUNT / 2,
+        )
+        .expect("asset is valid"),
+    );
+    let tag = NoteTag::from_account_id(
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN.try_into().unwrap(),
+        NoteExecutionHint::Local,
+    )
+    .unwrap();
+
+    // both notes are created with the same recipient, metadata, and assets
+    let tx_script = format!(
+        "\
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.0.1.2.3            # recipient
+            push.{NOTETYPE}         # note_type
+            push.{tag}              # tag
+            push.{REMOVED_ASSET}    # asset
+            call.wallet::send_asset dropw dropw dropw dropw
+
+            push.0.1.2.3            # recipient
+            push.{NOTETYPE}         # note_type
+            push.{tag}              # tag
+            push.{REMOVED_ASSET}    # asset
+            call.wallet::send_asset dropw dropw dropw dropw
+
+            push.1 call.{ACCOUNT_INCR_NONCE_MAST_ROOT} drop
+        end
+    ",
+        REMOVED_ASSET = prepare_word(&Word::from(removed_asset)),
+        NOTETYPE = NoteType::OffChain as u8,
+    );
+    let tx_script_code = ProgramAst::parse(&tx_script).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let recipient = Digest::from([Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)]);
+    let note_id =
+        NoteId::new(recipient, NoteAssets::new(vec![removed_asset]).unwrap().commitment());
+    assert_eq!(
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args),
+        Err(TransactionExecutorError::CheckOutputNotesFailed(
+            TransactionKernelError::DuplicateOutputNote(note_id)
+        ))
+    );
+}
+
+#[test]
+fn executed_transaction_asset_reconciliation() {
+    let data_store = MockDataStore::new(AssetPreservationStatus::TooManyFungibleInput);
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None);
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the last input note neither adds its assets to the account vault nor to an output note
+    let err = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap_err();
+    let TransactionExecutorError::ExecuteTransactionProgramFailed(
+        ExecutionError::FailedAssertion { err_code, err_msg: Some(err_msg), .. },
+    ) = err
+    else {
+        panic!("unexpected error: {err:?}");
+    };
+
+    let lost_assets = [
+        (ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, CONSUMED_ASSET_2_AMOUNT),
+        (ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3, CONSUMED_ASSET_3_AMOUNT),
+    ]
+    .map(|(faucet_id, amount)| {
+        AssetImbalance::Fungible {
+            faucet_id: faucet_id.try_into().unwrap(),
+            sub_token_id: 0,
+            amount: amount.into(),
+        }
+        .to_string()
+    });
+    assert_eq!(err_code, ERR_EPILOGUE_ASSETS_DONT_ADD_UP);
+    assert!(err_msg.ends_with(&format!(": {}", lost_assets.join("; "))), "{err_msg}");
+}
+
+#[test]
+fn transaction_executor_kernel_coverage() {
+    let data_store = MockDataStore::default();
+    let kernel_coverage = Rc::new(KernelCoverage::new());
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None)
+            .with_kernel_coverage(kernel_coverage.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap();
+
+    let report = kernel_coverage.report();
+    let hits = |module: &str, name: &str| report.get(module, name).unwrap().hits;
+    assert_eq!(hits("miden::kernels::tx::prologue", "prepare_transaction"), 1);
+    assert_eq!(hits("miden::kernels::tx::epilogue", "finalize_transaction"), 1);
+    assert_eq!(
+        hits("#sys", "create_note"),
+        executed_transaction.output_notes().num_notes() as u64
+    );
+    assert_eq!(hits("miden::kernels::tx::faucet", "mint"), 0);
+    assert!(report.uncovered().any(|procedure| procedure.name == "mint"));
+    assert!(report.num_covered() < report.num_traceable());
+
+    // untraceable procedures are not reported as uncovered
+    assert!(report.untraceable().all(|procedure| report.uncovered().all(|p| p != procedure)));
+
+    // executions of subsequent transactions accumulate
+    executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args)
+        .unwrap();
+    let report = kernel_coverage.report();
+    assert_eq!(
+        report.get("miden::kernels::tx::epilogue", "finalize_transaction").unwrap().hits,
+        2
+    );
+}
+
 #[test]
 fn prove_witness_and_verify() {
     let data_store = MockDataStore::default();
@@ -559,12 +938,71 @@ fn prove_witness_and_verify() {
     let proven_transaction = prover.prove_transaction(executed_transaction).unwrap();
 
     assert_eq!(proven_transaction.id(), executed_transaction_id);
+    assert_eq!(proven_transaction.kernel_version(), executor.kernel_version());
 
     let serialised_transaction = proven_transaction.to_bytes();
     let proven_transaction = ProvenTransaction::read_from_bytes(&serialised_transaction).unwrap();
 
-    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
-    assert!(verifier.verify(proven_transaction).is_ok());
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL)
+        .with_protocol_limits(ProtocolLimits::default());
+    assert!(verifier.verify(proven_transaction.clone()).is_ok());
+    assert_eq!(
+        proven_transaction.check_stateless(&verifier, &ProtocolLimits::default()),
+        Ok(())
+    );
+
+    // a verifier with tighter protocol limits rejects the transaction
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL)
+        .with_protocol_limits(ProtocolLimits::new(0, 0));
+    assert!(matches!(
+        verifier.verify(proven_transaction.clone()),
+        Err(TransactionVerifierError::ProtocolLimitsExceeded(_))
+    ));
+
+    // a verifier which supports only a different kernel rejects the transaction
+    let program_info = TransactionKernel::program_info();
+    let other_version = KernelVersion::new(0, 3, 0, KernelVersion::compute_root(&program_info));
+    let verifier = TransactionVerifier::with_supported_kernels(
+        MIN_PROOF_SECURITY_LEVEL,
+        [(other_version, program_info)],
+    )
+    .unwrap();
+    assert_eq!(
+        verifier.verify(proven_transaction),
+        Err(TransactionVerifierError::UnsupportedKernelVersion(executor.kernel_version()))
+    );
+}
+
+#[test]
+fn transaction_kernel_version() {
+    let kernel_version = TransactionKernel::version();
+    let (major, minor, patch) = TransactionKernel::SEMVER;
+    assert_eq!(
+        (kernel_version.major(), kernel_version.minor(), kernel_version.patch()),
+        (major, minor, patch)
+    );
+
+    let data_store = MockDataStore::default();
+    let executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::with_supported_kernels(data_store.clone(), None, [kernel_version])
+            .unwrap();
+    assert_eq!(executor.kernel_version(), kernel_version);
+
+    // the executor can not be used with a network which does not support its kernel
+    let other_version = KernelVersion::new(major, minor, patch, Digest::default());
+    let result =
+        TransactionExecutor::<_, ()>::with_supported_kernels(data_store, None, [other_version]);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::UnsupportedKernelVersion(version)) if version == kernel_version
+    ));
+
+    // kernel versions must match the program info of the kernel
+    let result = TransactionVerifier::with_supported_kernels(
+        MIN_PROOF_SECURITY_LEVEL,
+        [(other_version, TransactionKernel::program_info())],
+    );
+    assert!(matches!(result, Err(TransactionVerifierError::KernelVersionMismatch(_))));
 }
 
 // TEST TRANSACTION SCRIPT
@@ -621,6 +1059,594 @@ fn test_tx_script() {
     );
 }
 
+#[test]
+fn test_tx_script_debug_sink() {
+    #[derive(Default)]
+    struct CollectingSink(RefCell<Vec<DebugOutput>>);
+
+    impl DebugSink for CollectingSink {
+        fn on_debug(&self, output: &DebugOutput) {
+            self.0.borrow_mut().push(output.clone());
+        }
+    }
+
+    let data_store = MockDataStore::default();
+    let debug_sink = Rc::new(CollectingSink::default());
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None).with_debug_sink(debug_sink.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_source = "
+    begin
+        push.1.2.3.4 debug.stack.4
+        mem_storew.1000 dropw debug.mem.1000.1001
+    end
+";
+    let tx_script_code = ProgramAst::parse(tx_script_source).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+
+    executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+
+    // the debug instructions are executed by the transaction script, outside of any note
+    let outputs = debug_sink.0.take();
+    assert_eq!(outputs.len(), 2);
+    assert_eq!(outputs[0].options, DebugOptions::StackTop(4));
+    assert_eq!(outputs[0].note_id, None);
+    assert_eq!(outputs[0].stack, [4, 3, 2, 1].map(Felt::new));
+    assert!(outputs[0].memory.is_empty());
+    assert_eq!(outputs[1].options, DebugOptions::MemInterval(1000, 1001));
+    assert!(outputs[1].stack.is_empty());
+    assert_eq!(outputs[1].memory, [(1000, [1, 2, 3, 4].map(Felt::new))]);
+}
+
+#[test]
+fn test_tx_script_with_library() {
+    let library = MaslLibrary::new(
+        LibraryNamespace::new("my_lib").unwrap(),
+        Version::default(),
+        false,
+        vec![Module::new(
+            LibraryPath::new("my_lib::math").unwrap(),
+            ModuleAst::parse("export.add_two push.2 add end").unwrap(),
+        )],
+        vec![],
+    )
+    .unwrap();
+
+    let data_store = MockDataStore::default();
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None)
+            .with_library(&library)
+            .unwrap();
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_source = "
+    use.my_lib::math
+
+    begin
+        push.5 exec.math::add_two
+        push.7 assert_eq
+    end
+    ";
+    let tx_script_code = ProgramAst::parse(tx_script_source).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args);
+
+    assert!(
+        executed_transaction.is_ok(),
+        "Transaction execution failed {:?}",
+        executed_transaction,
+    );
+}
+
+#[test]
+fn transaction_executor_partial_vault() {
+    let data_store = MockDataStore::default();
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None);
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let expected = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args().clone())
+        .unwrap();
+
+    // execute the same transaction providing only the vault leaves touched by the transaction
+    let partial_data_store = MockDataStore {
+        use_partial_vault: true,
+        ..data_store.clone()
+    };
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(partial_data_store, None);
+    executor.load_account(account_id).unwrap();
+
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args().clone())
+        .unwrap();
+
+    assert!(executed_transaction.initial_account().vault().is_empty());
+    assert_eq!(executed_transaction.id(), expected.id());
+    assert_eq!(executed_transaction.final_account().hash(), expected.final_account().hash());
+    assert_eq!(executed_transaction.account_delta(), expected.account_delta());
+}
+
+#[test]
+fn execute_claimable_notes_with_partial_vault() {
+    let data_store = MockDataStore::default();
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None);
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let (expected, expected_skipped) = executor
+        .execute_claimable_notes(account_id, block_ref, &note_ids, data_store.tx_args().clone())
+        .unwrap();
+
+    // the partial vault must be kept when the inputs are rebuilt for the claimable notes
+    let partial_data_store = MockDataStore {
+        use_partial_vault: true,
+        ..data_store.clone()
+    };
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(partial_data_store, None);
+    executor.load_account(account_id).unwrap();
+
+    let (executed_transaction, skipped) = executor
+        .execute_claimable_notes(account_id, block_ref, &note_ids, data_store.tx_args().clone())
+        .unwrap();
+
+    assert!(executed_transaction.tx_inputs().partial_vault().is_some());
+    assert!(executed_transaction.initial_account().vault().is_empty());
+    assert_eq!(skipped, expected_skipped);
+    assert_eq!(executed_transaction.id(), expected.id());
+    assert_eq!(executed_transaction.final_account().hash(), expected.final_account().hash());
+}
+
+#[test]
+fn transaction_executor_advice_size_limit() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_inputs = data_store.get_transaction_inputs(account_id, block_ref, &note_ids).unwrap();
+    let advice_size = TransactionKernel::build_advice_inputs(&tx_inputs, data_store.tx_args())
+        .unwrap()
+        .size_estimate()
+        .num_bytes();
+
+    // transactions with advice data exceeding the limit are rejected
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None).with_advice_size_limit(advice_size - 1);
+    executor.load_account(account_id).unwrap();
+
+    let result = executor.execute_transaction(
+        account_id,
+        block_ref,
+        &note_ids,
+        data_store.tx_args().clone(),
+    );
+    assert_eq!(
+        result.unwrap_err(),
+        TransactionExecutorError::AdviceInputsTooLarge {
+            size: advice_size,
+            limit: advice_size - 1
+        }
+    );
+
+    // advice map entries conflicting with the transaction inputs are rejected
+    let note_inputs_hash = data_store.notes[0].note().inputs().commitment();
+    let mut tx_args = data_store.tx_args().clone();
+    tx_args.extend_advice_map([(note_inputs_hash, vec![ZERO])]).unwrap();
+
+    let result = executor.execute_transaction(account_id, block_ref, &note_ids, tx_args);
+    assert_eq!(
+        result.unwrap_err(),
+        TransactionExecutorError::InvalidAdviceInputs(AdviceInputsError::MapKeyConflict(
+            note_inputs_hash
+        ))
+    );
+
+    // transactions with advice data within the limit are executed
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None).with_advice_size_limit(advice_size);
+    executor.load_account(account_id).unwrap();
+
+    let result = executor.execute_transaction(
+        account_id,
+        block_ref,
+        &note_ids,
+        data_store.tx_args().clone(),
+    );
+    assert!(result.is_ok(), "Transaction execution failed {:?}", result);
+}
+
+#[test]
+fn transaction_executor_validate_inputs() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_inputs = data_store.get_transaction_inputs(account_id, block_ref, &note_ids).unwrap();
+    let advice_size = TransactionKernel::build_advice_inputs(&tx_inputs, data_store.tx_args())
+        .unwrap()
+        .size_estimate()
+        .num_bytes();
+
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None).with_advice_size_limit(advice_size);
+    executor.load_account(account_id).unwrap();
+    assert_eq!(executor.validate_inputs(&tx_inputs, data_store.tx_args()), Ok(()));
+
+    // advice map entries conflicting with the transaction inputs are rejected
+    let note_inputs_hash = data_store.notes[0].note().inputs().commitment();
+    let mut tx_args = data_store.tx_args().clone();
+    tx_args.extend_advice_map([(note_inputs_hash, vec![ZERO])]).unwrap();
+    assert_eq!(
+        executor.validate_inputs(&tx_inputs, &tx_args),
+        Err(TransactionExecutorError::InvalidAdviceInputs(
+            AdviceInputsError::MapKeyConflict(note_inputs_hash)
+        ))
+    );
+
+    // advice data exceeding the limit of the executor is rejected
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None).with_advice_size_limit(advice_size - 1);
+    executor.load_account(account_id).unwrap();
+    assert_eq!(
+        executor.validate_inputs(&tx_inputs, data_store.tx_args()),
+        Err(TransactionExecutorError::AdviceInputsTooLarge {
+            size: advice_size,
+            limit: advice_size - 1
+        })
+    );
+}
+
+/// Returns the number of field elements in the provided advice inputs, counting every Merkle store
+/// node as four elements.
+fn advice_data_size(advice_inputs: AdviceInputs) -> usize {
+    let (stack, map, store) = MemAdviceProvider::from(advice_inputs).into_parts();
+    stack.len() + map.values().map(Vec::len).sum::<usize>() + 4 * store.num_internal_nodes()
+}
+
+// TEST ACCOUNT VIEW CALLS
+// ================================================================================================
+
+const VIEW_ACCOUNT_CODE: &str = "
+    use.miden::account
+
+    export.get_item
+        exec.account::get_item
+        # => [VALUE, 0, 0, 0, ...]
+
+        movup.4 drop movup.4 drop movup.4 drop
+        # => [VALUE, ...]
+    end
+
+    export.get_balance
+        exec.account::get_balance
+        # => [balance, ...]
+    end
+
+    export.incr_nonce
+        push.1 exec.account::incr_nonce
+        # => [...]
+    end
+";
+
+#[cfg_attr(not(feature = "testing"), ignore)]
+#[test]
+fn create_account_transaction() {
+    let data_store = MockDataStore::default();
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None)
+            .with_network_params(NetworkParams::testing());
+
+    let assembler = TransactionKernel::assembler();
+    let (account_id, account_seed) =
+        generate_account_seed(AccountSeedType::RegularAccountUpdatableCodeOffChain);
+    let account = Account::new(
+        account_id,
+        AssetVault::default(),
+        mock_account_storage(),
+        mock_account_code(&assembler),
+        ZERO,
+    );
+    executor.load_new_account(&account).unwrap();
+
+    let tx_script_source = format!(
+        "
+    begin
+        push.0.0.0 push.{value} push.{STORAGE_INDEX_0}
+        call.{ACCOUNT_SET_ITEM_MAST_ROOT}
+        dropw dropw
+
+        push.1
+        call.{ACCOUNT_INCR_NONCE_MAST_ROOT}
+        drop
+    end
+    ",
+        value = prepare_word(&[Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)])
+    );
+    let tx_script_code = ProgramAst::parse(&tx_script_source).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .create_account_transaction(
+            account.clone(),
+            account_seed,
+            data_store.block_header,
+            data_store.block_chain.clone(),
+            None,
+            tx_args,
+        )
+        .unwrap();
+
+    assert_eq!(executed_transaction.account_id(), account_id);
+    assert!(executed_transaction.initial_account().is_new());
+    assert_eq!(executed_transaction.tx_inputs().account_seed(), Some(account_seed));
+    assert_eq!(executed_transaction.final_account().nonce(), Felt::new(1));
+
+    // a transaction which does not modify the account does not create it
+    let tx_script_code = ProgramAst::parse("begin push.1 drop end").unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let result = executor.create_account_transaction(
+        account.clone(),
+        account_seed,
+        data_store.block_header,
+        data_store.block_chain.clone(),
+        None,
+        TransactionArgs::with_tx_script(tx_script),
+    );
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::NewAccountNonceNotIncremented(id)) if id == account_id
+    ));
+
+    // a new account can receive assets only via the funding note
+    let account = Account::new(
+        account_id,
+        data_store.account.vault().clone(),
+        account.storage().clone(),
+        account.code().clone(),
+        ZERO,
+    );
+    let result = executor.create_account_transaction(
+        account.clone(),
+        account_seed,
+        data_store.block_header,
+        data_store.block_chain.clone(),
+        None,
+        TransactionArgs::default(),
+    );
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::NewAccountVaultNotEmpty(id)) if id == account_id
+    ));
+
+    // the same is detected without executing the transaction
+    let tx_inputs = TransactionInputs::new(
+        account,
+        Some(account_seed),
+        data_store.block_header,
+        data_store.block_chain.clone(),
+        InputNotes::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        executor.validate_inputs(&tx_inputs, &TransactionArgs::default()),
+        Err(TransactionExecutorError::NewAccountVaultNotEmpty(account_id))
+    );
+}
+
+#[test]
+fn account_view_call_reads_account_state() {
+    let (data_store, view_procs) = view_call_data_store();
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None);
+    let account = &data_store.account;
+    executor.load_account(account.id()).unwrap();
+
+    // read an item from the account storage
+    let outputs = executor
+        .execute_account_view_call(account, view_procs[0], &[Felt::from(STORAGE_INDEX_0)])
+        .unwrap();
+    let mut expected_value = STORAGE_VALUE_0;
+    expected_value.reverse();
+    assert_eq!(&outputs.stack()[..4], &expected_value);
+
+    // read the balance of a fungible asset from the account vault
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let outputs = executor
+        .execute_account_view_call(account, view_procs[1], &[faucet_id.into()])
+        .unwrap();
+    assert_eq!(outputs.stack()[0], Felt::new(FUNGIBLE_ASSET_AMOUNT));
+}
+
+#[test]
+fn account_view_call_rejects_state_changes() {
+    let (data_store, view_procs) = view_call_data_store();
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None);
+    let account = &data_store.account;
+    executor.load_account(account.id()).unwrap();
+
+    let result = executor.execute_account_view_call(account, view_procs[2], &[]);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::ExecuteAccountViewCallFailed(
+            ExecutionError::EventError(_)
+        ))
+    ));
+}
+
+#[test]
+fn account_view_call_unknown_procedure() {
+    let (data_store, _) = view_call_data_store();
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None);
+    let account = &data_store.account;
+    executor.load_account(account.id()).unwrap();
+
+    let proc_root = Digest::default();
+    let result = executor.execute_account_view_call(account, proc_root, &[]);
+    assert_eq!(
+        result,
+        Err(TransactionExecutorError::CompileAccountViewCallFailed(
+            TransactionCompilerError::AccountProcedureNotFound(proc_root)
+        ))
+    );
+}
+
+/// Returns a data store for the mock account with its code replaced by the view account code,
+/// together with the roots of the `get_item`, `get_balance`, and `incr_nonce` procedures.
+fn view_call_data_store() -> (MockDataStore, Vec<Digest>) {
+    let data_store = MockDataStore::default();
+    let code = AccountCode::new(
+        ModuleAst::parse(VIEW_ACCOUNT_CODE).unwrap(),
+        &TransactionKernel::assembler(),
+    )
+    .unwrap();
+    let view_procs = code.procedures().to_vec();
+
+    let account = Account::new(
+        data_store.account.id(),
+        data_store.account.vault().clone(),
+        data_store.account.storage().clone(),
+        code,
+        data_store.account.nonce(),
+    );
+
+    (MockDataStore { account, ..data_store }, view_procs)
+}
+
+// TEST DEBUG HOST
+// ================================================================================================
+
+#[test]
+fn debug_host_captures_breakpoints() {
+    let (transaction, mut host) = debug_transaction(
+        "
+        begin
+            push.5 trace.1000 drop
+        end
+        ",
+    );
+
+    vm_processor::execute(
+        transaction.program(),
+        transaction.get_kernel_inputs().0,
+        &mut host,
+        ExecutionOptions::default().with_tracing(),
+    )
+    .unwrap();
+
+    assert!(host.failure().is_none());
+    let hits = host.breakpoint_hits();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].cause, DiagnosticsCause::Breakpoint(1000));
+    assert_eq!(hits[0].stage, Some(TransactionStage::TxScriptProcessing));
+    assert_eq!(hits[0].note_id, None);
+    assert_eq!(hits[0].stack[0], Felt::new(5));
+}
+
+#[test]
+fn debug_host_captures_failure_diagnostics() {
+    let (transaction, mut host) = debug_transaction(
+        "
+        begin
+            push.7.0 assert
+        end
+        ",
+    );
+
+    let err = vm_processor::execute(
+        transaction.program(),
+        transaction.get_kernel_inputs().0,
+        &mut host,
+        ExecutionOptions::default().with_tracing(),
+    )
+    .err()
+    .unwrap();
+
+    let diagnostics = host.failure_diagnostics(&err);
+    assert!(matches!(diagnostics.cause, DiagnosticsCause::Error(_)));
+    assert!(diagnostics.clk.is_some());
+    assert_eq!(diagnostics.stage, Some(TransactionStage::TxScriptProcessing));
+    assert_eq!(&diagnostics.stack[..2], &[ZERO, Felt::new(7)]);
+
+    // the account ID is stored in the captured global inputs section
+    let account_id = transaction.account().id();
+    assert!(diagnostics
+        .memory
+        .iter()
+        .any(|(address, word)| *address == ACCT_ID_PTR && word[0] == account_id.into()));
+}
+
+/// Prepares a transaction running the provided transaction script against the mock account, and
+/// returns it together with a debug host capturing the global inputs section of the memory and
+/// breaking on trace 1000.
+fn debug_transaction(
+    tx_script: &str,
+) -> (
+    PreparedTransaction,
+    DebugTransactionHost<TransactionHost<MemAdviceProvider, ()>>,
+) {
+    let data_store = MockDataStore::default();
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(data_store.clone(), None);
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let tx_script_code = ProgramAst::parse(tx_script).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let transaction =
+        executor.prepare_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+
+    let (_, advice_inputs) = transaction.get_kernel_inputs();
+    let host = TransactionHost::new(
+        transaction.tx_inputs().account_stub(),
+        MemAdviceProvider::from(advice_inputs),
+        None,
+    );
+    let host = DebugTransactionHost::new(host)
+        .with_memory_range(GLOBAL_INPUTS_SECTION.start..GLOBAL_INPUTS_SECTION.end)
+        .with_breakpoint(1000);
+
+    (transaction, host)
+}
+
 // MOCK DATA STORE
 // ================================================================================================
 
@@ -631,6 +1657,7 @@ struct MockDataStore {
     pub block_chain: ChainMmr,
     pub notes: Vec<InputNote>,
     pub tx_args: TransactionArgs,
+    pub use_partial_vault: bool,
 }
 
 impl MockDataStore {
@@ -645,6 +1672,7 @@ impl MockDataStore {
             block_chain,
             notes: notes.into_vec(),
             tx_args,
+            use_partial_vault: false,
         }
     }
 
@@ -660,6 +1688,42 @@ impl Default for MockDataStore {
 }
 
 impl DataStore for MockDataStore {
+    #[maybe_async]
+    fn get_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(Account, Option<Word>), DataStoreError> {
+        assert_eq!(account_id, self.account.id());
+        Ok((self.account.clone(), None))
+    }
+
+    #[maybe_async]
+    fn get_block_header(&self, block_num: u32) -> Result<BlockHeader, DataStoreError> {
+        assert_eq!(block_num, self.block_header.block_num());
+        Ok(self.block_header)
+    }
+
+    #[maybe_async]
+    fn get_chain_mmr(&self, block_ref: u32, _blocks: &[u32]) -> Result<ChainMmr, DataStoreError> {
+        assert_eq!(block_ref, self.block_header.block_num());
+        Ok(self.block_chain.clone())
+    }
+
+    #[maybe_async]
+    fn get_notes(&self, note_ids: &[NoteId]) -> Result<Vec<InputNote>, DataStoreError> {
+        assert_eq!(note_ids.len(), self.notes.len());
+        note_ids
+            .iter()
+            .map(|&note_id| {
+                self.notes
+                    .iter()
+                    .find(|note| note.id() == note_id)
+                    .cloned()
+                    .ok_or(DataStoreError::NoteNotFound(note_id))
+            })
+            .collect()
+    }
+
     #[maybe_async]
     fn get_transaction_inputs(
         &self,
@@ -669,17 +1733,38 @@ impl DataStore for MockDataStore {
     ) -> Result<TransactionInputs, DataStoreError> {
         assert_eq!(account_id, self.account.id());
         assert_eq!(block_num, self.block_header.block_num());
-        assert_eq!(notes.len(), self.notes.len());
-
-        let notes = self
-            .notes
-            .iter()
-            .filter(|note| notes.contains(&note.id()))
-            .cloned()
-            .collect::<Vec<_>>();
+        let notes = maybe_await!(self.get_notes(notes))?;
+
+        if !self.use_partial_vault {
+            return Ok(TransactionInputs::new(
+                self.account.clone(),
+                None,
+                self.block_header,
+                self.block_chain.clone(),
+                InputNotes::new(notes).unwrap(),
+            )
+            .unwrap());
+        }
 
-        Ok(TransactionInputs::new(
-            self.account.clone(),
+        // track only the vault leaves for the assets in the account vault and in the input notes
+        let vault = self.account.vault();
+        let touched_keys = vault
+            .assets()
+            .chain(notes.iter().flat_map(|note| note.note().assets().iter().copied()))
+            .map(|asset| asset.vault_key());
+        let partial_vault = PartialVault::from_vault(vault, touched_keys);
+
+        let account = Account::new(
+            self.account.id(),
+            AssetVault::default(),
+            self.account.storage().clone(),
+            self.account.code().clone(),
+            self.account.nonce(),
+        );
+
+        Ok(TransactionInputs::with_partial_vault(
+            account,
+            partial_vault,
             None,
             self.block_header,
             self.block_chain.clone(),
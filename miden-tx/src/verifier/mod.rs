@@ -1,6 +1,13 @@
+use alloc::collections::BTreeMap;
+
 use miden_lib::transaction::TransactionKernel;
-use miden_objects::{transaction::ProvenTransaction, vm::ProgramInfo};
-use miden_verifier::verify;
+use miden_objects::{
+    accounts::NetworkParams,
+    transaction::{KernelVersion, ProtocolLimits, ProvenTransaction, TransactionProofVerifier},
+    vm::ProgramInfo,
+    TransactionValidityError,
+};
+use miden_verifier::{verify, VerificationError};
 
 use super::TransactionVerifierError;
 
@@ -9,57 +16,193 @@ use super::TransactionVerifierError;
 
 /// The [TransactionVerifier] is used to verify  [ProvenTransaction]s.
 ///
-/// The [TransactionVerifier] contains a [ProgramInfo] object which is associated with the
-/// transaction kernel program.  The `proof_security_level` specifies the minimum security
-/// level that the transaction proof must have in order to be considered valid.
+/// The [TransactionVerifier] contains a [ProgramInfo] object for each of the supported versions
+/// of the transaction kernel program. A transaction is verified against the kernel version
+/// recorded in the [ProvenTransaction], which allows verifying transactions proven with older
+/// kernels after a network has rolled its kernel forward. The `proof_security_level` specifies
+/// the minimum security level that the transaction proof must have in order to be considered
+/// valid.
+///
+/// Proofs are verified against the [NetworkParams] of the verifier, which must match the
+/// parameters the transactions were executed and proven with.
+///
+/// [TransactionVerifier] implements [TransactionProofVerifier], and thus can be used to check the
+/// validity of transactions via [ProvenTransaction::check_stateless()].
 pub struct TransactionVerifier {
-    tx_program_info: ProgramInfo,
+    kernels: BTreeMap<KernelVersion, ProgramInfo>,
     proof_security_level: u32,
+    protocol_limits: Option<ProtocolLimits>,
+    network_params: NetworkParams,
 }
 
 impl TransactionVerifier {
-    /// Returns a new [TransactionVerifier] instantiated with the specified security level.
+    /// Returns a new [TransactionVerifier] instantiated with the specified security level, which
+    /// supports only the current version of the transaction kernel.
     pub fn new(proof_security_level: u32) -> Self {
         let tx_program_info = TransactionKernel::program_info();
-        Self { tx_program_info, proof_security_level }
+        let kernel_version = TransactionKernel::version_from_program_info(&tx_program_info);
+        Self {
+            kernels: BTreeMap::from([(kernel_version, tx_program_info)]),
+            proof_security_level,
+            protocol_limits: None,
+            network_params: NetworkParams::default(),
+        }
     }
 
-    /// Verifies the provided [ProvenTransaction] against the transaction kernel.
+    /// Returns a new [TransactionVerifier] instantiated with the specified security level, which
+    /// supports the provided versions of the transaction kernel.
+    ///
+    /// Each kernel version must be provided together with the [ProgramInfo] of its kernel main
+    /// program, as returned by [TransactionKernel::program_info()] of the corresponding kernel.
+    ///
+    /// # Errors
+    /// Returns an error if the root of any of the kernel versions does not match the provided
+    /// [ProgramInfo].
+    pub fn with_supported_kernels<I>(
+        proof_security_level: u32,
+        kernels: I,
+    ) -> Result<Self, TransactionVerifierError>
+    where
+        I: IntoIterator<Item = (KernelVersion, ProgramInfo)>,
+    {
+        let kernels = kernels
+            .into_iter()
+            .map(|(version, program_info)| {
+                if version.matches(&program_info) {
+                    Ok((version, program_info))
+                } else {
+                    Err(TransactionVerifierError::KernelVersionMismatch(version))
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            kernels,
+            proof_security_level,
+            protocol_limits: None,
+            network_params: NetworkParams::default(),
+        })
+    }
+
+    /// Puts the [ProtocolLimits] in place for the transactions verified by the returned instance
+    /// of [TransactionVerifier].
+    ///
+    /// Transactions whose output notes or account update exceed the limits are rejected before
+    /// their proofs are verified. By default, the size of the data published by a transaction is
+    /// not limited.
+    pub fn with_protocol_limits(mut self, limits: ProtocolLimits) -> Self {
+        self.protocol_limits = Some(limits);
+        self
+    }
+
+    /// Sets the [NetworkParams] the transactions verified by the returned instance of
+    /// [TransactionVerifier] must have been proven with. By default, [NetworkParams::MAINNET] is
+    /// used.
+    pub fn with_network_params(mut self, network_params: NetworkParams) -> Self {
+        self.network_params = network_params;
+        self
+    }
+
+    /// Returns the versions of the transaction kernel supported by this verifier.
+    pub fn supported_kernels(&self) -> impl Iterator<Item = KernelVersion> + '_ {
+        self.kernels.keys().copied()
+    }
+
+    /// Verifies the provided [ProvenTransaction] against the transaction kernel it was proven
+    /// with.
+    ///
+    /// The proof is verified against the initial and final account state hashes of the account
+    /// update, which for off-chain accounts may be salted commitments to the account states (see
+    /// [AccountUpdateDetails::PrivateSalted](miden_objects::accounts::delta::AccountUpdateDetails)).
     ///
     /// # Errors
     /// Returns an error if:
+    /// - The transaction was proven with an unsupported version of the transaction kernel.
+    /// - The transaction exceeds the protocol limits of this verifier.
     /// - Transaction verification fails.
     /// - The security level of the verified proof is insufficient.
     pub fn verify(&self, transaction: ProvenTransaction) -> Result<(), TransactionVerifierError> {
-        // build stack inputs and outputs
-        let stack_inputs = TransactionKernel::build_input_stack(
-            transaction.account_id(),
-            transaction.account_update().init_state_hash(),
-            transaction.input_notes().commitment(),
-            transaction.block_ref(),
-        );
-        let stack_outputs = TransactionKernel::build_output_stack(
-            transaction.account_update().final_state_hash(),
-            transaction.output_notes().commitment(),
-        );
-
-        // verify transaction proof
-        let proof_security_level = verify(
-            self.tx_program_info.clone(),
-            stack_inputs,
-            stack_outputs,
-            transaction.proof().clone(),
-        )
-        .map_err(TransactionVerifierError::TransactionVerificationFailed)?;
+        let tx_program_info = self.kernels.get(&transaction.kernel_version()).ok_or(
+            TransactionVerifierError::UnsupportedKernelVersion(transaction.kernel_version()),
+        )?;
+
+        if let Some(limits) = self.protocol_limits {
+            limits
+                .validate(&transaction)
+                .map_err(TransactionVerifierError::ProtocolLimitsExceeded)?;
+        }
+
+        let proof_security_level =
+            verify_proof(tx_program_info, &transaction, &self.network_params)
+                .map_err(TransactionVerifierError::TransactionVerificationFailed)?;
 
         // check security level
         if proof_security_level < self.proof_security_level {
-            return Err(TransactionVerifierError::InsufficientProofSecurityLevel(
-                proof_security_level,
-                self.proof_security_level,
-            ));
+            return Err(TransactionVerifierError::InsufficientProofSecurityLevel {
+                actual: proof_security_level,
+                required: self.proof_security_level,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl TransactionProofVerifier for TransactionVerifier {
+    fn supports_kernel(&self, version: KernelVersion) -> bool {
+        self.kernels.contains_key(&version)
+    }
+
+    fn verify_proof(
+        &self,
+        transaction: &ProvenTransaction,
+    ) -> Result<(), TransactionValidityError> {
+        let tx_program_info = self.kernels.get(&transaction.kernel_version()).ok_or(
+            TransactionValidityError::UnsupportedKernelVersion(transaction.kernel_version()),
+        )?;
+
+        let proof_security_level = verify_proof(tx_program_info, transaction, &self.network_params)
+            .map_err(TransactionValidityError::ProofVerificationFailed)?;
+
+        if proof_security_level < self.proof_security_level {
+            return Err(TransactionValidityError::InsufficientProofSecurityLevel {
+                required: self.proof_security_level,
+                actual: proof_security_level,
+            });
         }
 
         Ok(())
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Verifies the proof of the provided transaction against the specified kernel program and network
+/// parameters, returning the security level of the proof.
+fn verify_proof(
+    tx_program_info: &ProgramInfo,
+    transaction: &ProvenTransaction,
+    network_params: &NetworkParams,
+) -> Result<u32, VerificationError> {
+    // build stack inputs and outputs
+    let stack_inputs = TransactionKernel::build_input_stack(
+        transaction.account_id(),
+        transaction.account_update().init_state_hash(),
+        transaction.input_notes().commitment(),
+        transaction.block_ref(),
+        network_params,
+    );
+    let stack_outputs = TransactionKernel::build_output_stack(
+        transaction.account_update().final_state_hash(),
+        transaction.output_notes().commitment(),
+    );
+
+    // verify transaction proof
+    verify(
+        tx_program_info.clone(),
+        stack_inputs,
+        stack_outputs,
+        transaction.proof().clone(),
+    )
+}
@@ -1,5 +1,9 @@
+mod mock_chain;
 mod scripts;
+mod tx_context;
 mod wallet;
+#[cfg(feature = "testing")]
+mod workflow;
 
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
@@ -13,17 +17,19 @@ use miden_objects::{
     notes::{
         Note, NoteAssets, NoteId, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteType,
     },
+    testing::DeterministicRng,
     transaction::{
-        ChainMmr, ExecutedTransaction, InputNote, InputNotes, OutputNote, ProvenTransaction,
-        TransactionArgs, TransactionInputs,
+        ChainMmr, ExecutedTransaction, InputNote, OutputNote, ProvenTransaction, TransactionArgs,
     },
     BlockHeader, Felt, Word, ZERO,
 };
 use miden_prover::ProvingOptions;
 use miden_tx::{
-    DataStore, DataStoreError, TransactionProver, TransactionVerifier, TransactionVerifierError,
+    host::TransactionAuthenticator, DataStore, DataStoreError, TransactionExecutor,
+    TransactionExecutorError, TransactionProver, TransactionVerifier, TransactionVerifierError,
 };
 use mock::{
+    builders::TransactionContext,
     constants::MIN_PROOF_SECURITY_LEVEL,
     mock::{
         account::{MockAccountType, DEFAULT_ACCOUNT_CODE},
@@ -32,7 +38,7 @@ use mock::{
     },
 };
 use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
-use vm_processor::utils::Deserializable;
+use vm_processor::{utils::Deserializable, ExecutionError};
 use winter_maybe_async::maybe_async;
 
 // MOCK DATA STORE
@@ -77,7 +83,7 @@ impl MockDataStore {
         );
         let output_notes = created_notes.into_iter().filter_map(|note| match note {
             OutputNote::Full(note) => Some(note),
-            OutputNote::Header(_) => None,
+            OutputNote::Partial(_) | OutputNote::Header(_) => None,
         });
         let mut tx_args = TransactionArgs::default();
         tx_args.extend_expected_output_notes(output_notes);
@@ -92,6 +98,20 @@ impl MockDataStore {
     }
 }
 
+impl<A> From<TransactionContext<A>> for MockDataStore {
+    fn from(tx_context: TransactionContext<A>) -> Self {
+        let (tx_inputs, tx_args) = tx_context.into_parts();
+        let (account, _, block_header, block_chain, notes) = tx_inputs.into_parts();
+        Self {
+            account,
+            block_header,
+            block_chain,
+            notes: notes.into_vec(),
+            tx_args,
+        }
+    }
+}
+
 impl Default for MockDataStore {
     fn default() -> Self {
         Self::new()
@@ -100,31 +120,39 @@ impl Default for MockDataStore {
 
 impl DataStore for MockDataStore {
     #[maybe_async]
-    fn get_transaction_inputs(
+    fn get_account(
         &self,
         account_id: AccountId,
-        block_num: u32,
-        notes: &[NoteId],
-    ) -> Result<TransactionInputs, DataStoreError> {
+    ) -> Result<(Account, Option<Word>), DataStoreError> {
         assert_eq!(account_id, self.account.id());
+        Ok((self.account.clone(), None))
+    }
+
+    #[maybe_async]
+    fn get_block_header(&self, block_num: u32) -> Result<BlockHeader, DataStoreError> {
         assert_eq!(block_num, self.block_header.block_num());
-        assert_eq!(notes.len(), self.notes.len());
+        Ok(self.block_header)
+    }
 
-        let notes = self
-            .notes
+    #[maybe_async]
+    fn get_chain_mmr(&self, block_ref: u32, _blocks: &[u32]) -> Result<ChainMmr, DataStoreError> {
+        assert_eq!(block_ref, self.block_header.block_num());
+        Ok(self.block_chain.clone())
+    }
+
+    #[maybe_async]
+    fn get_notes(&self, note_ids: &[NoteId]) -> Result<Vec<InputNote>, DataStoreError> {
+        assert_eq!(note_ids.len(), self.notes.len());
+        note_ids
             .iter()
-            .filter(|note| notes.contains(&note.id()))
-            .cloned()
-            .collect::<Vec<_>>();
-
-        Ok(TransactionInputs::new(
-            self.account.clone(),
-            None,
-            self.block_header,
-            self.block_chain.clone(),
-            InputNotes::new(notes).unwrap(),
-        )
-        .unwrap())
+            .map(|&note_id| {
+                self.notes
+                    .iter()
+                    .find(|note| note.id() == note_id)
+                    .cloned()
+                    .ok_or(DataStoreError::NoteNotFound(note_id))
+            })
+            .collect()
     }
 
     #[maybe_async]
@@ -137,6 +165,23 @@ impl DataStore for MockDataStore {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Executes the transaction described by the provided context, signing it with the context's
+/// authenticator, if any.
+#[cfg(test)]
+pub fn execute_tx_context<A: TransactionAuthenticator>(
+    tx_context: TransactionContext<A>,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let account_id = tx_context.tx_inputs().account().id();
+    let block_num = tx_context.block_header().block_num();
+    let note_ids = tx_context.input_note_ids();
+    let tx_args = tx_context.tx_args().clone();
+    let authenticator = tx_context.authenticator();
+
+    let mut executor = TransactionExecutor::new(MockDataStore::from(tx_context), authenticator);
+    executor.load_account(account_id)?;
+    executor.execute_transaction(account_id, block_num, &note_ids, tx_args)
+}
+
 #[cfg(test)]
 pub fn prove_and_verify_transaction(
     executed_transaction: ExecutedTransaction,
@@ -162,12 +207,11 @@ pub fn prove_and_verify_transaction(
 
 #[cfg(test)]
 pub fn get_new_pk_and_authenticator(
-) -> (Word, std::rc::Rc<miden_tx::host::BasicAuthenticator<rand::rngs::StdRng>>) {
+) -> (Word, std::rc::Rc<miden_tx::host::BasicAuthenticator<DeterministicRng>>) {
     use std::rc::Rc;
 
     use miden_objects::accounts::AuthSecretKey;
     use miden_tx::host::BasicAuthenticator;
-    use rand::rngs::StdRng;
 
     let seed = [0_u8; 32];
     let mut rng = ChaCha20Rng::from_seed(seed);
@@ -175,8 +219,10 @@ pub fn get_new_pk_and_authenticator(
     let sec_key = SecretKey::with_rng(&mut rng);
     let pub_key: Word = sec_key.public_key().into();
 
-    let authenticator =
-        BasicAuthenticator::<StdRng>::new(&[(pub_key, AuthSecretKey::RpoFalcon512(sec_key))]);
+    let authenticator = BasicAuthenticator::new_with_rng(
+        &[(pub_key, AuthSecretKey::RpoFalcon512(sec_key))],
+        DeterministicRng::default(),
+    );
 
     (pub_key, Rc::new(authenticator))
 }
@@ -226,3 +272,74 @@ pub fn get_note_with_fungible_asset_and_script(
 
     Note::new(vault, metadata, recipient)
 }
+
+/// Executes a transaction consuming the provided note against the account without a transaction
+/// script, i.e., consuming the note must not require authentication.
+#[cfg(test)]
+pub fn execute_note(
+    account: &Account,
+    note: Note,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![note]));
+
+    let (_, falcon_auth) = get_new_pk_and_authenticator();
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(falcon_auth));
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    executor.execute_transaction(account.id(), block_ref, &note_ids, data_store.tx_args.clone())
+}
+
+/// Executes a transaction running the provided transaction script against the account, signing it
+/// with the provided authenticator.
+#[cfg(test)]
+pub fn execute_tx_script(
+    account: &Account,
+    authenticator: std::rc::Rc<miden_tx::host::BasicAuthenticator<DeterministicRng>>,
+    tx_script_code: ProgramAst,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    execute_tx_script_with_inputs(account, authenticator, tx_script_code, vec![])
+}
+
+/// Executes a transaction running the provided transaction script with the specified script
+/// inputs against the account, signing it with the provided authenticator.
+#[cfg(test)]
+pub fn execute_tx_script_with_inputs(
+    account: &Account,
+    authenticator: std::rc::Rc<miden_tx::host::BasicAuthenticator<DeterministicRng>>,
+    tx_script_code: ProgramAst,
+    tx_script_inputs: Vec<(Word, Vec<Felt>)>,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(authenticator));
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor.compile_tx_script(tx_script_code, tx_script_inputs, vec![]).unwrap();
+
+    executor.execute_transaction(
+        account.id(),
+        block_ref,
+        &note_ids,
+        TransactionArgs::with_tx_script(tx_script),
+    )
+}
+
+/// Asserts that the transaction failed on an assertion with the specified error code.
+#[cfg(test)]
+pub fn assert_failed_with(
+    result: Result<ExecutedTransaction, TransactionExecutorError>,
+    expected_err_code: u32,
+) {
+    match result {
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::FailedAssertion { err_code, .. },
+        )) => assert_eq!(err_code, expected_err_code),
+        result => panic!("transaction should fail with error {expected_err_code}: {result:?}"),
+    }
+}
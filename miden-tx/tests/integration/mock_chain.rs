@@ -0,0 +1,144 @@
+use miden_lib::notes::create_p2id_note;
+use miden_objects::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        Account, AccountId,
+    },
+    assembly::{ModuleAst, ProgramAst},
+    assets::{Asset, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::{NoteId, NoteType},
+    testing::{MockChain, MockChainError},
+    transaction::{ChainMmr, InputNote, TransactionArgs},
+    BlockHeader, Felt, Word, ONE, ZERO,
+};
+use miden_tx::{DataStore, DataStoreError, TransactionExecutor};
+use mock::mock::account::DEFAULT_AUTH_SCRIPT;
+use winter_maybe_async::maybe_async;
+
+use crate::{get_account_with_default_account_code, get_new_pk_and_authenticator};
+
+// MOCK CHAIN DATA STORE
+// ================================================================================================
+
+struct MockChainDataStore(MockChain);
+
+impl DataStore for MockChainDataStore {
+    #[maybe_async]
+    fn get_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(Account, Option<Word>), DataStoreError> {
+        let account =
+            self.0.account(account_id).ok_or(DataStoreError::AccountNotFound(account_id))?;
+        Ok((account.clone(), self.0.account_seed(account_id)))
+    }
+
+    #[maybe_async]
+    fn get_block_header(&self, block_num: u32) -> Result<BlockHeader, DataStoreError> {
+        self.0
+            .block_header(block_num)
+            .copied()
+            .ok_or(DataStoreError::BlockNotFound(block_num))
+    }
+
+    #[maybe_async]
+    fn get_chain_mmr(&self, block_ref: u32, blocks: &[u32]) -> Result<ChainMmr, DataStoreError> {
+        if self.0.block_header(block_ref).is_none() {
+            return Err(DataStoreError::BlockNotFound(block_ref));
+        }
+        if let Some(&block_num) = blocks.iter().find(|&&block_num| block_num >= block_ref) {
+            return Err(DataStoreError::BlockNotFound(block_num));
+        }
+
+        Ok(self.0.chain_mmr(block_ref, blocks))
+    }
+
+    #[maybe_async]
+    fn get_notes(&self, note_ids: &[NoteId]) -> Result<Vec<InputNote>, DataStoreError> {
+        note_ids
+            .iter()
+            .map(|&note_id| {
+                let note = self.0.note(note_id).ok_or(DataStoreError::NoteNotFound(note_id))?;
+                if self.0.is_nullifier_consumed(note.note().nullifier()) {
+                    return Err(DataStoreError::NoteAlreadyConsumed(note_id));
+                }
+                Ok(note.clone())
+            })
+            .collect()
+    }
+
+    #[maybe_async]
+    fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError> {
+        self.0
+            .account(account_id)
+            .map(|account| account.code().module().clone())
+            .ok_or(DataStoreError::AccountNotFound(account_id))
+    }
+}
+
+// MOCK CHAIN TESTS
+// ================================================================================================
+
+#[test]
+fn consume_notes_from_different_blocks() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let target_account = get_account_with_default_account_code(target_id, target_pub_key, None);
+
+    let notes = [1, 2].map(|seed| {
+        let asset: Asset = FungibleAsset::new(faucet_id, 100 * seed).unwrap().into();
+        let rng = RpoRandomCoin::new([Felt::new(seed), ONE, ONE, ONE]);
+        create_p2id_note(sender_id, target_id, vec![asset], NoteType::Public, ZERO, rng).unwrap()
+    });
+
+    // commit the account and each of the notes to a separate block
+    let mut chain = MockChain::new();
+    chain.add_account(target_account, None);
+    chain.add_note(notes[0].clone()).unwrap();
+    chain.seal_block();
+    chain.add_note(notes[1].clone()).unwrap();
+    chain.seal_block();
+    chain.seal_block();
+
+    // consume the notes in two consecutive transactions
+    for note in notes.iter() {
+        let block_ref = chain.latest_block_header().unwrap().block_num();
+        let mut executor =
+            TransactionExecutor::new(MockChainDataStore(chain.clone()), Some(falcon_auth.clone()));
+        executor.load_account(target_id).unwrap();
+
+        let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+        let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+        let executed_transaction = executor
+            .execute_transaction(
+                target_id,
+                block_ref,
+                &[note.id()],
+                TransactionArgs::with_tx_script(tx_script),
+            )
+            .unwrap();
+
+        chain.apply_executed_transaction(&executed_transaction).unwrap();
+        chain.seal_block();
+    }
+
+    // the account received the assets from both notes and the notes cannot be consumed again
+    let target_account = chain.account(target_id).unwrap();
+    assert_eq!(target_account.nonce(), Felt::new(3));
+    assert_eq!(target_account.vault().get_balance(faucet_id).unwrap(), 300);
+    assert_eq!(
+        chain.get_transaction_inputs(target_id, 4, &[notes[0].id()]),
+        Err(MockChainError::NoteAlreadyConsumed(notes[0].id()))
+    );
+    assert_eq!(
+        MockChainDataStore(chain).get_transaction_inputs(target_id, 4, &[notes[0].id()]),
+        Err(DataStoreError::NoteAlreadyConsumed(notes[0].id()))
+    );
+}
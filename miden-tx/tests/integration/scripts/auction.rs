@@ -0,0 +1,307 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use miden_lib::notes::{
+    build_auction_bid_args, create_auction_bid_notes, create_auction_note,
+    create_auction_settlement_note,
+};
+use miden_objects::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        Account, AccountId,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::{Note, NoteAssets, NoteExecutionHint, NoteTag},
+    testing::DeterministicRng,
+    transaction::{ExecutedTransaction, OutputNote, TransactionArgs},
+    Felt, Word, EMPTY_WORD,
+};
+use miden_tx::{host::BasicAuthenticator, TransactionExecutor, TransactionExecutorError};
+use mock::mock::account::DEFAULT_AUTH_SCRIPT;
+use vm_processor::AdviceMap;
+
+use crate::{
+    assert_failed_with, get_account_with_default_account_code, get_new_pk_and_authenticator,
+    MockDataStore,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Reference block of the transactions executed against the mock chain.
+const BLOCK_NUM: u32 = 4;
+
+const ERR_AUCTION_BID_TOO_LOW: u32 = 131169;
+const ERR_AUCTION_INVALID_CLAIMANT: u32 = 131170;
+const ERR_AUCTION_LOT_IS_BID_ASSET: u32 = 131187;
+
+// TESTS BIDDING
+// ================================================================================================
+
+#[test]
+fn auction_bids_are_escrowed_and_previous_bids_refunded() {
+    let auction = create_test_auction(BLOCK_NUM + 10);
+
+    // the first bidder escrows its bid in the re-emitted auction
+    let (first_bidder, first_bidder_auth) = get_bidder(first_bidder_id(), 80);
+    let first_bidder_tag = account_tag(first_bidder.id());
+    let (first_bid_auction, refund_note) =
+        create_auction_bid_notes(&auction, first_bidder.id(), 60, first_bidder_tag).unwrap();
+    assert!(refund_note.is_none());
+
+    let executed_transaction = execute_auction(
+        &first_bidder,
+        first_bidder_auth,
+        &auction,
+        build_auction_bid_args(60, first_bidder_tag),
+        &[first_bid_auction.clone()],
+    )
+    .unwrap();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(
+        executed_transaction.output_notes().get_note(0),
+        &OutputNote::Full(first_bid_auction.clone())
+    );
+    assert_eq!(final_balance(&first_bidder, &executed_transaction, bid_faucet_id()), 20);
+
+    // the second bidder outbids the first one, which is refunded
+    let (second_bidder, second_bidder_auth) = get_bidder(second_bidder_id(), 100);
+    let second_bidder_tag = account_tag(second_bidder.id());
+    let (second_bid_auction, refund_note) =
+        create_auction_bid_notes(&first_bid_auction, second_bidder.id(), 70, second_bidder_tag)
+            .unwrap();
+    let refund_note = refund_note.unwrap();
+    assert_eq!(refund_note.assets().iter().collect::<Vec<_>>(), [&bid(60)]);
+    assert_eq!(refund_note.metadata().tag(), first_bidder_tag);
+
+    let executed_transaction = execute_auction(
+        &second_bidder,
+        second_bidder_auth,
+        &first_bid_auction,
+        build_auction_bid_args(70, second_bidder_tag),
+        &[second_bid_auction.clone(), refund_note.clone()],
+    )
+    .unwrap();
+
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 2);
+    assert_eq!(output_notes.get_note(0), &OutputNote::Full(refund_note));
+    assert_eq!(output_notes.get_note(1), &OutputNote::Full(second_bid_auction));
+    assert_eq!(final_balance(&second_bidder, &executed_transaction, bid_faucet_id()), 30);
+}
+
+#[test]
+fn auction_rejects_bids_not_exceeding_the_highest_bid() {
+    let auction = create_test_auction(BLOCK_NUM + 10);
+    let (bidder, bidder_auth) = get_bidder(second_bidder_id(), 100);
+    let bidder_tag = account_tag(bidder.id());
+
+    // the first bid must reach the reserve price
+    let result = execute_auction(
+        &bidder,
+        bidder_auth.clone(),
+        &auction,
+        build_auction_bid_args(40, bidder_tag),
+        &[],
+    );
+    assert_failed_with(result, ERR_AUCTION_BID_TOO_LOW);
+
+    // subsequent bids must exceed the highest bid
+    let (first_bid_auction, _) =
+        create_auction_bid_notes(&auction, first_bidder_id(), 60, bidder_tag).unwrap();
+    let result = execute_auction(
+        &bidder,
+        bidder_auth,
+        &first_bid_auction,
+        build_auction_bid_args(60, bidder_tag),
+        &[],
+    );
+    assert_failed_with(result, ERR_AUCTION_BID_TOO_LOW);
+}
+
+#[test]
+fn auction_rejects_bids_on_lots_of_the_bid_asset() {
+    let rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    assert!(
+        create_auction_note(seller_id(), bid(100), bid_faucet_id(), 50, BLOCK_NUM + 10, rng)
+            .is_err()
+    );
+
+    // the lot would be merged with the bid in the re-emitted auction, so bids are rejected
+    let auction = create_test_auction(BLOCK_NUM + 10);
+    let auction = Note::new(
+        NoteAssets::new(vec![bid(100)]).unwrap(),
+        *auction.metadata(),
+        auction.recipient().clone(),
+    );
+    let (bidder, bidder_auth) = get_bidder(first_bidder_id(), 80);
+    let bidder_tag = account_tag(bidder.id());
+    let result = execute_auction(
+        &bidder,
+        bidder_auth,
+        &auction,
+        build_auction_bid_args(60, bidder_tag),
+        &[],
+    );
+    assert_failed_with(result, ERR_AUCTION_LOT_IS_BID_ASSET);
+}
+
+// TESTS SETTLEMENT
+// ================================================================================================
+
+#[test]
+fn auction_is_settled_by_highest_bidder() {
+    let auction = create_test_auction(BLOCK_NUM);
+    let (bidder, bidder_auth) = get_bidder(first_bidder_id(), 0);
+    let (ended_auction, _) =
+        create_auction_bid_notes(&auction, bidder.id(), 60, account_tag(bidder.id())).unwrap();
+
+    // the highest bidder receives the lot and pays the highest bid to the seller
+    let payment_note =
+        create_auction_settlement_note(&ended_auction, bidder.id()).unwrap().unwrap();
+    assert_eq!(payment_note.assets().iter().collect::<Vec<_>>(), [&bid(60)]);
+
+    let executed_transaction =
+        execute_auction(&bidder, bidder_auth, &ended_auction, EMPTY_WORD, &[payment_note.clone()])
+            .unwrap();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(executed_transaction.output_notes().get_note(0), &OutputNote::Full(payment_note));
+    assert_eq!(final_balance(&bidder, &executed_transaction, lot_faucet_id()), 100);
+}
+
+#[test]
+fn auction_is_settled_by_seller() {
+    let auction = create_test_auction(BLOCK_NUM);
+    let (ended_auction, _) =
+        create_auction_bid_notes(&auction, first_bidder_id(), 60, account_tag(first_bidder_id()))
+            .unwrap();
+
+    // the seller receives the highest bid and sends the lot to the highest bidder
+    let (seller, seller_auth) = get_bidder(seller_id(), 0);
+    let lot_note = create_auction_settlement_note(&ended_auction, seller.id()).unwrap().unwrap();
+    assert_eq!(lot_note.assets().iter().collect::<Vec<_>>(), [&lot()]);
+
+    let executed_transaction =
+        execute_auction(&seller, seller_auth, &ended_auction, EMPTY_WORD, &[lot_note.clone()])
+            .unwrap();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(executed_transaction.output_notes().get_note(0), &OutputNote::Full(lot_note));
+    assert_eq!(final_balance(&seller, &executed_transaction, bid_faucet_id()), 60);
+}
+
+#[test]
+fn auction_without_bids_is_reclaimed_by_seller() {
+    let auction = create_test_auction(BLOCK_NUM);
+
+    // other accounts cannot settle the auction
+    let (bidder, bidder_auth) = get_bidder(first_bidder_id(), 0);
+    assert!(create_auction_settlement_note(&auction, bidder.id()).is_err());
+    let result = execute_auction(&bidder, bidder_auth, &auction, EMPTY_WORD, &[]);
+    assert_failed_with(result, ERR_AUCTION_INVALID_CLAIMANT);
+
+    let (seller, seller_auth) = get_bidder(seller_id(), 0);
+    assert_eq!(create_auction_settlement_note(&auction, seller.id()), Ok(None));
+
+    let executed_transaction =
+        execute_auction(&seller, seller_auth, &auction, EMPTY_WORD, &[]).unwrap();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 0);
+    assert_eq!(final_balance(&seller, &executed_transaction, lot_faucet_id()), 100);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn seller_id() -> AccountId {
+    AccountId::try_from(ACCOUNT_ID_SENDER).unwrap()
+}
+
+fn first_bidder_id() -> AccountId {
+    AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap()
+}
+
+fn second_bidder_id() -> AccountId {
+    AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap()
+}
+
+fn lot_faucet_id() -> AccountId {
+    AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap()
+}
+
+fn bid_faucet_id() -> AccountId {
+    AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap()
+}
+
+fn lot() -> Asset {
+    FungibleAsset::new(lot_faucet_id(), 100).unwrap().into()
+}
+
+fn bid(amount: u64) -> Asset {
+    FungibleAsset::new(bid_faucet_id(), amount).unwrap().into()
+}
+
+fn account_tag(account_id: AccountId) -> NoteTag {
+    NoteTag::from_account_id(account_id, NoteExecutionHint::Local).unwrap()
+}
+
+/// Returns an auction of the lot with a reserve price of 50, ending at the specified block.
+fn create_test_auction(end_height: u32) -> Note {
+    let rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    create_auction_note(seller_id(), lot(), bid_faucet_id(), 50, end_height, rng).unwrap()
+}
+
+/// Returns a wallet holding the specified amount of the bid asset, and its authenticator.
+fn get_bidder(
+    account_id: AccountId,
+    amount: u64,
+) -> (Account, Rc<BasicAuthenticator<DeterministicRng>>) {
+    let (pub_key, authenticator) = get_new_pk_and_authenticator();
+    let assets = (amount > 0).then(|| bid(amount));
+    (
+        get_account_with_default_account_code(account_id, pub_key, assets),
+        authenticator,
+    )
+}
+
+/// Executes a transaction consuming the auction note against the provided account, which expects
+/// the specified notes to be created.
+fn execute_auction(
+    account: &Account,
+    authenticator: Rc<BasicAuthenticator<DeterministicRng>>,
+    auction: &Note,
+    note_args: Word,
+    expected_notes: &[Note],
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let data_store =
+        MockDataStore::with_existing(Some(account.clone()), Some(vec![auction.clone()]));
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(authenticator));
+    executor.load_account(account.id()).unwrap();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let note_args = BTreeMap::from([(auction.id(), note_args)]);
+    let mut tx_args = TransactionArgs::new(Some(tx_script), Some(note_args), AdviceMap::default());
+    tx_args.extend_expected_output_notes(expected_notes.to_vec());
+
+    assert_eq!(data_store.block_header.block_num(), BLOCK_NUM);
+    executor.execute_transaction(account.id(), BLOCK_NUM, &[auction.id()], tx_args)
+}
+
+/// Returns the balance of the specified faucet in the vault of the account after the transaction.
+fn final_balance(
+    account: &Account,
+    executed_transaction: &ExecutedTransaction,
+    faucet_id: AccountId,
+) -> u64 {
+    let mut account = account.clone();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+    account.vault().get_balance(faucet_id).unwrap()
+}
@@ -0,0 +1,324 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use miden_lib::notes::{
+    build_crowdfund_contribution_args, create_crowdfund_contribution_note, create_crowdfund_note,
+    create_crowdfund_refund_notes,
+};
+use miden_objects::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        Account, AccountId,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::{Note, NoteExecutionHint, NoteTag},
+    testing::DeterministicRng,
+    transaction::{ExecutedTransaction, OutputNote, TransactionArgs},
+    Felt, Word, EMPTY_WORD,
+};
+use miden_tx::{host::BasicAuthenticator, TransactionExecutor, TransactionExecutorError};
+use mock::mock::account::DEFAULT_AUTH_SCRIPT;
+use vm_processor::AdviceMap;
+
+use crate::{
+    assert_failed_with, get_account_with_default_account_code, get_new_pk_and_authenticator,
+    MockDataStore,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Reference block of the transactions executed against the mock chain.
+const BLOCK_NUM: u32 = 4;
+
+const ERR_CROWDFUND_INVALID_CONTRIBUTION: u32 = 131173;
+const ERR_CROWDFUND_TARGET_REACHED: u32 = 131175;
+
+// TESTS CONTRIBUTING
+// ================================================================================================
+
+#[test]
+fn crowdfund_contributions_accumulate_in_reemitted_notes() {
+    let crowdfund = create_test_crowdfund(BLOCK_NUM + 10);
+
+    let (first_contributor, first_contributor_auth) = get_wallet(first_contributor_id(), 80);
+    let first_contributor_tag = account_tag(first_contributor.id());
+    let first_contribution = create_crowdfund_contribution_note(
+        &crowdfund,
+        first_contributor.id(),
+        30,
+        first_contributor_tag,
+    )
+    .unwrap();
+    assert_eq!(first_contribution.assets().iter().collect::<Vec<_>>(), [&funds(30)]);
+
+    let executed_transaction = execute_crowdfund(
+        &first_contributor,
+        first_contributor_auth,
+        &crowdfund,
+        build_crowdfund_contribution_args(30, first_contributor_tag),
+        &[first_contribution.clone()],
+    )
+    .unwrap();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(
+        executed_transaction.output_notes().get_note(0),
+        &OutputNote::Full(first_contribution.clone())
+    );
+    assert_eq!(final_balance(&first_contributor, &executed_transaction), 50);
+
+    // the second contribution is added to the funds contributed so far
+    let (second_contributor, second_contributor_auth) = get_wallet(second_contributor_id(), 100);
+    let second_contributor_tag = account_tag(second_contributor.id());
+    let second_contribution = create_crowdfund_contribution_note(
+        &first_contribution,
+        second_contributor.id(),
+        40,
+        second_contributor_tag,
+    )
+    .unwrap();
+    assert_eq!(second_contribution.assets().iter().collect::<Vec<_>>(), [&funds(70)]);
+
+    let executed_transaction = execute_crowdfund(
+        &second_contributor,
+        second_contributor_auth,
+        &first_contribution,
+        build_crowdfund_contribution_args(40, second_contributor_tag),
+        &[second_contribution.clone()],
+    )
+    .unwrap();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(
+        executed_transaction.output_notes().get_note(0),
+        &OutputNote::Full(second_contribution)
+    );
+    assert_eq!(final_balance(&second_contributor, &executed_transaction), 60);
+}
+
+#[test]
+fn crowdfund_rejects_contributions_below_minimum() {
+    let crowdfund = create_test_crowdfund(BLOCK_NUM + 10);
+    let contributor_tag = account_tag(first_contributor_id());
+
+    for amount in [0, 5] {
+        assert!(create_crowdfund_contribution_note(
+            &crowdfund,
+            first_contributor_id(),
+            amount,
+            contributor_tag
+        )
+        .is_err());
+
+        let (contributor, contributor_auth) = get_wallet(first_contributor_id(), 80);
+        let result = execute_crowdfund(
+            &contributor,
+            contributor_auth,
+            &crowdfund,
+            build_crowdfund_contribution_args(amount, contributor_tag),
+            &[],
+        );
+        assert_failed_with(result, ERR_CROWDFUND_INVALID_CONTRIBUTION);
+    }
+}
+
+// TESTS RELEASING FUNDS
+// ================================================================================================
+
+#[test]
+fn crowdfund_is_claimed_by_beneficiary_once_target_is_exceeded() {
+    // the funds can be claimed before the deadline once they exceed the target
+    let crowdfund = contribute(create_test_crowdfund(BLOCK_NUM + 10), &[30, 30]);
+
+    let (beneficiary, beneficiary_auth) = get_wallet(beneficiary_id(), 0);
+    assert!(create_crowdfund_refund_notes(&crowdfund, beneficiary.id()).is_err());
+
+    let executed_transaction =
+        execute_crowdfund(&beneficiary, beneficiary_auth, &crowdfund, EMPTY_WORD, &[]).unwrap();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 0);
+    assert_eq!(final_balance(&beneficiary, &executed_transaction), 60);
+
+    // after the deadline, other accounts cannot consume a campaign which exceeded its target
+    let crowdfund = contribute(create_test_crowdfund(BLOCK_NUM), &[30, 30]);
+    let (contributor, contributor_auth) = get_wallet(first_contributor_id(), 0);
+    let result = execute_crowdfund(&contributor, contributor_auth, &crowdfund, EMPTY_WORD, &[]);
+    assert_failed_with(result, ERR_CROWDFUND_TARGET_REACHED);
+}
+
+#[test]
+fn crowdfund_is_refunded_if_target_is_only_reached() {
+    // funds equal to the target cannot be claimed by the beneficiary, whose consumption is taken
+    // as a contribution of zero before the deadline
+    let crowdfund = contribute(create_test_crowdfund(BLOCK_NUM + 10), &[30, 20]);
+    let (beneficiary, beneficiary_auth) = get_wallet(beneficiary_id(), 0);
+    let result = execute_crowdfund(&beneficiary, beneficiary_auth, &crowdfund, EMPTY_WORD, &[]);
+    assert_failed_with(result, ERR_CROWDFUND_INVALID_CONTRIBUTION);
+
+    // after the deadline, the contributions are refunded
+    let crowdfund = contribute(create_test_crowdfund(BLOCK_NUM), &[30, 20]);
+    let (account, authenticator) = get_wallet(beneficiary_id(), 0);
+    let refund_notes = create_crowdfund_refund_notes(&crowdfund, account.id()).unwrap();
+    assert_eq!(refund_notes.len(), 2);
+
+    let executed_transaction = execute_crowdfund_with_script(
+        &account,
+        authenticator,
+        &crowdfund,
+        EMPTY_WORD,
+        &refund_notes,
+        None,
+    )
+    .unwrap();
+    assert_eq!(executed_transaction.output_notes().num_notes(), 2);
+}
+
+#[test]
+fn crowdfund_is_refunded_if_target_is_not_exceeded() {
+    let crowdfund = contribute(create_test_crowdfund(BLOCK_NUM), &[20, 10]);
+
+    // any account can refund the contributions of a failed campaign
+    let (account, authenticator) = get_wallet(beneficiary_id(), 0);
+    let refund_notes = create_crowdfund_refund_notes(&crowdfund, account.id()).unwrap();
+    assert_eq!(refund_notes.len(), 2);
+    assert_eq!(refund_notes[0].assets().iter().collect::<Vec<_>>(), [&funds(20)]);
+    assert_eq!(refund_notes[1].assets().iter().collect::<Vec<_>>(), [&funds(10)]);
+    assert_eq!(refund_notes[1].metadata().tag(), account_tag(second_contributor_id()));
+
+    // the vault of the refunding account is unchanged, so its nonce is not incremented
+    let executed_transaction = execute_crowdfund_with_script(
+        &account,
+        authenticator,
+        &crowdfund,
+        EMPTY_WORD,
+        &refund_notes,
+        None,
+    )
+    .unwrap();
+
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 2);
+    assert_eq!(output_notes.get_note(0), &OutputNote::Full(refund_notes[0].clone()));
+    assert_eq!(output_notes.get_note(1), &OutputNote::Full(refund_notes[1].clone()));
+    assert!(executed_transaction.account_delta().is_empty());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn beneficiary_id() -> AccountId {
+    AccountId::try_from(ACCOUNT_ID_SENDER).unwrap()
+}
+
+fn first_contributor_id() -> AccountId {
+    AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap()
+}
+
+fn second_contributor_id() -> AccountId {
+    AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap()
+}
+
+fn faucet_id() -> AccountId {
+    AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap()
+}
+
+fn funds(amount: u64) -> Asset {
+    FungibleAsset::new(faucet_id(), amount).unwrap().into()
+}
+
+fn account_tag(account_id: AccountId) -> NoteTag {
+    NoteTag::from_account_id(account_id, NoteExecutionHint::Local).unwrap()
+}
+
+/// Returns a campaign with a target of 50 and a minimum contribution of 10, ending at the
+/// specified block.
+fn create_test_crowdfund(deadline: u32) -> Note {
+    let rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    create_crowdfund_note(beneficiary_id(), beneficiary_id(), faucet_id(), 50, 10, deadline, rng)
+        .unwrap()
+}
+
+/// Returns the campaign after the first and the second contributor alternately contributed the
+/// specified amounts to it.
+fn contribute(crowdfund: Note, amounts: &[u64]) -> Note {
+    let contributors = [first_contributor_id(), second_contributor_id()];
+    amounts
+        .iter()
+        .zip(contributors.iter().cycle())
+        .fold(crowdfund, |crowdfund, (&amount, &id)| {
+            create_crowdfund_contribution_note(&crowdfund, id, amount, account_tag(id)).unwrap()
+        })
+}
+
+/// Returns a wallet holding the specified amount of the contributed asset, and its authenticator.
+fn get_wallet(
+    account_id: AccountId,
+    amount: u64,
+) -> (Account, Rc<BasicAuthenticator<DeterministicRng>>) {
+    let (pub_key, authenticator) = get_new_pk_and_authenticator();
+    let assets = (amount > 0).then(|| funds(amount));
+    (
+        get_account_with_default_account_code(account_id, pub_key, assets),
+        authenticator,
+    )
+}
+
+/// Executes a transaction consuming the crowdfund note against the provided account, which
+/// expects the specified notes to be created.
+fn execute_crowdfund(
+    account: &Account,
+    authenticator: Rc<BasicAuthenticator<DeterministicRng>>,
+    crowdfund: &Note,
+    note_args: Word,
+    expected_notes: &[Note],
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    execute_crowdfund_with_script(
+        account,
+        authenticator,
+        crowdfund,
+        note_args,
+        expected_notes,
+        Some(DEFAULT_AUTH_SCRIPT),
+    )
+}
+
+/// Executes a transaction consuming the crowdfund note against the provided account with the
+/// specified transaction script, which expects the specified notes to be created.
+fn execute_crowdfund_with_script(
+    account: &Account,
+    authenticator: Rc<BasicAuthenticator<DeterministicRng>>,
+    crowdfund: &Note,
+    note_args: Word,
+    expected_notes: &[Note],
+    tx_script: Option<&str>,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let data_store =
+        MockDataStore::with_existing(Some(account.clone()), Some(vec![crowdfund.clone()]));
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(authenticator));
+    executor.load_account(account.id()).unwrap();
+
+    let tx_script = tx_script.map(|tx_script| {
+        let tx_script_code = ProgramAst::parse(tx_script).unwrap();
+        executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap()
+    });
+    let note_args = BTreeMap::from([(crowdfund.id(), note_args)]);
+    let mut tx_args = TransactionArgs::new(tx_script, Some(note_args), AdviceMap::default());
+    tx_args.extend_expected_output_notes(expected_notes.to_vec());
+
+    assert_eq!(data_store.block_header.block_num(), BLOCK_NUM);
+    executor.execute_transaction(account.id(), BLOCK_NUM, &[crowdfund.id()], tx_args)
+}
+
+/// Returns the balance of the contributed asset in the vault of the account after the
+/// transaction.
+fn final_balance(account: &Account, executed_transaction: &ExecutedTransaction) -> u64 {
+    let mut account = account.clone();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+    account.vault().get_balance(faucet_id()).unwrap()
+}
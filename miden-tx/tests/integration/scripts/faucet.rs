@@ -1,25 +1,41 @@
 extern crate alloc;
 
 use miden_lib::{
-    accounts::faucets::create_basic_fungible_faucet,
+    accounts::faucets::{
+        create_basic_fungible_faucet, create_multi_fungible_faucet, create_nft_faucet,
+        get_faucet_metadata, get_fungible_faucet_state, get_nft_issued_count,
+        get_nft_royalty_recipient, get_nft_token, get_sub_token_metadata, get_sub_token_supply,
+        mint_nft, MULTI_FAUCET_SUPPLY_SLOT, NFT_FAUCET_ISSUED_COUNT_SLOT, NFT_FAUCET_ROYALTY_SLOT,
+        NFT_FAUCET_TOKENS_SLOT,
+    },
     transaction::{memory::FAUCET_STORAGE_DATA_SLOT, TransactionKernel},
     AuthScheme,
 };
 use miden_objects::{
     accounts::{
-        account_id::testing::ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, Account, AccountCode, AccountId,
-        AccountStorage, AccountStorageType, SlotItem, StorageSlot,
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+            ACCOUNT_ID_SENDER,
+        },
+        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, SlotItem, StorageMap,
+        StorageSlot,
     },
     assembly::{ModuleAst, ProgramAst},
-    assets::{Asset, AssetVault, FungibleAsset, TokenSymbol},
-    crypto::dsa::rpo_falcon512::SecretKey,
-    notes::{NoteAssets, NoteId, NoteMetadata, NoteTag, NoteType},
+    assets::{
+        Asset, AssetVault, FaucetMetadata, FungibleAsset, NonFungibleAssetDetails, TokenSymbol,
+    },
+    crypto::{dsa::rpo_falcon512::SecretKey, merkle::Smt},
+    notes::{
+        Note, NoteAssets, NoteId, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag,
+        NoteType,
+    },
     transaction::TransactionArgs,
-    Felt, Word, ZERO,
+    AccountError, Digest, Felt, Word, ZERO,
 };
-use miden_tx::TransactionExecutor;
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
 use mock::utils::prepare_word;
 use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use vm_processor::ExecutionError;
 
 use crate::{
     get_new_pk_and_authenticator, get_note_with_fungible_asset_and_script,
@@ -266,18 +282,623 @@ fn faucet_contract_creation() {
 
     assert!(faucet_account.is_faucet());
 
+    // check that the metadata can be decoded from the faucet storage
+    let metadata = get_faucet_metadata(&faucet_account).unwrap();
+    assert_eq!(metadata.symbol(), token_symbol);
+    assert_eq!(metadata.decimals(), decimals);
+    assert_eq!(metadata.max_supply(), max_supply.as_int());
+
+    let state = get_fungible_faucet_state(&faucet_account).unwrap();
+    assert_eq!(state.total_issuance(), 0);
+    assert_eq!(state.max_supply(), max_supply.as_int());
+
     let exp_faucet_account_code_src =
         include_str!("../../../../miden-lib/asm/miden/contracts/faucets/basic_fungible.masm");
     let exp_faucet_account_code_ast = ModuleAst::parse(exp_faucet_account_code_src).unwrap();
     let account_assembler = TransactionKernel::assembler();
 
     let exp_faucet_account_code =
-        AccountCode::new(exp_faucet_account_code_ast.clone(), &account_assembler).unwrap();
+        AccountCode::new(exp_faucet_account_code_ast.clone(), &account_assembler)
+            .unwrap()
+            .with_auth_procedures(&[get_set_migration_successor_root()])
+            .unwrap();
 
     assert_eq!(faucet_account.code(), &exp_faucet_account_code);
+    assert!(faucet_account.code().requires_auth(get_set_migration_successor_root()));
+}
+
+// TESTS MULTI-ASSET FUNGIBLE FAUCET
+// ================================================================================================
+
+#[test]
+fn multi_faucet_contract_mint_sub_token_succeeds() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let faucet_account = get_multi_faucet_account(faucet_pub_key, None);
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(falcon_auth.clone()));
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let tag = NoteTag::for_local_use_case(0, 0).unwrap();
+    let note_type = NoteType::OffChain;
+    let amount = 100;
+    let sub_token_id = 7;
+
+    let tx_script = executor
+        .compile_tx_script(
+            multi_faucet_distribute_script(recipient, tag, amount, sub_token_id),
+            vec![],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(faucet_account.id(), block_ref, &note_ids, tx_args)
+        .unwrap();
+
+    // the created note carries an asset of the requested sub-token
+    let fungible_asset: Asset =
+        FungibleAsset::with_sub_token(faucet_account.id(), sub_token_id, amount)
+            .unwrap()
+            .into();
+    let assets = NoteAssets::new(vec![fungible_asset]).unwrap();
+    let created_note = executed_transaction.output_notes().get_note(0).clone();
+    assert_eq!(created_note.id(), NoteId::new(recipient.into(), assets.commitment()));
+    assert_eq!(
+        created_note.metadata(),
+        &NoteMetadata::new(faucet_account.id(), note_type, tag, ZERO).unwrap()
+    );
+
+    // the issued supply of the sub-token was updated
+    let supply_delta = executed_transaction
+        .account_delta()
+        .storage()
+        .updated_maps
+        .iter()
+        .find(|(slot, _)| *slot == MULTI_FAUCET_SUPPLY_SLOT)
+        .map(|(_, delta)| delta.clone())
+        .unwrap();
+    assert_eq!(
+        supply_delta.updated_leaves,
+        vec![(sub_token_map_key(sub_token_id).into(), [Felt::new(amount), ZERO, ZERO, ZERO])]
+    );
+}
+
+#[test]
+fn multi_faucet_contract_mint_sub_token_fails_exceeds_max_supply() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    // sub-token 7 has a max supply of 200, of which 150 were already issued
+    let faucet_account = get_multi_faucet_account(faucet_pub_key, Some((7, 150)));
+
+    let data_store = MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(falcon_auth.clone()));
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let tag = NoteTag::for_local_use_case(0, 0).unwrap();
+
+    // minting 100 tokens of sub-token 7 exceeds its max supply
+    let tx_script = executor
+        .compile_tx_script(multi_faucet_distribute_script(recipient, tag, 100, 7), vec![], vec![])
+        .unwrap();
+    let result = executor.execute_transaction(
+        faucet_account.id(),
+        block_ref,
+        &note_ids,
+        TransactionArgs::with_tx_script(tx_script),
+    );
+    match result {
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::FailedAssertion { err_code, .. },
+        )) => assert_eq!(err_code, 131145),
+        result => {
+            panic!(
+                "transaction should fail with ERR_MULTI_FUNGIBLE_MAX_SUPPLY_OVERFLOW: {result:?}"
+            )
+        },
+    }
+
+    // minting a sub-token which is not registered in the faucet fails as well
+    let tx_script = executor
+        .compile_tx_script(multi_faucet_distribute_script(recipient, tag, 10, 8), vec![], vec![])
+        .unwrap();
+    let result = executor.execute_transaction(
+        faucet_account.id(),
+        block_ref,
+        &note_ids,
+        TransactionArgs::with_tx_script(tx_script),
+    );
+    match result {
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::FailedAssertion { err_code, .. },
+        )) => assert_eq!(err_code, 131144),
+        result => {
+            panic!("transaction should fail with ERR_MULTI_FUNGIBLE_UNKNOWN_SUB_TOKEN: {result:?}")
+        },
+    }
+}
+
+#[test]
+fn multi_faucet_contract_burn_sub_token_succeeds() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let faucet_account = get_multi_faucet_account(faucet_pub_key, Some((7, 150)));
+
+    let fungible_asset = FungibleAsset::with_sub_token(faucet_account.id(), 7, 100).unwrap();
+
+    // need to create a note with the fungible asset to be burned
+    let note_script = ProgramAst::parse(
+        "
+        use.miden::contracts::faucets::multi_fungible->faucet_contract
+        use.miden::note
+
+        # burn the asset
+        begin
+            dropw
+            exec.note::get_assets drop
+            mem_loadw
+            call.faucet_contract::burn
+        end
+        ",
+    )
+    .unwrap();
+
+    let note = get_note_with_fungible_asset_and_script(fungible_asset, note_script);
+
+    let data_store =
+        MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![note.clone()]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(falcon_auth.clone()));
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction = executor
+        .execute_transaction(faucet_account.id(), block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap();
+
+    // check that the account burned the asset and decreased the supply of the sub-token
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+    let supply_delta = executed_transaction
+        .account_delta()
+        .storage()
+        .updated_maps
+        .iter()
+        .find(|(slot, _)| *slot == MULTI_FAUCET_SUPPLY_SLOT)
+        .map(|(_, delta)| delta.clone())
+        .unwrap();
+    assert_eq!(
+        supply_delta.updated_leaves,
+        vec![(sub_token_map_key(7).into(), [Felt::new(50), ZERO, ZERO, ZERO])]
+    );
+}
+
+#[test]
+fn multi_faucet_contract_creation() {
+    let mut rng = ChaCha20Rng::from_seed([0_u8; 32]);
+    let pub_key = SecretKey::with_rng(&mut rng).public_key();
+
+    let gold = FaucetMetadata::new(TokenSymbol::new("GOLD").unwrap(), 2, 1000).unwrap();
+    let silver = FaucetMetadata::new(TokenSymbol::new("SILVER").unwrap(), 4, 5000).unwrap();
+
+    let (faucet_account, _) = create_multi_fungible_faucet(
+        [1; 32],
+        &[(1, gold), (2, silver)],
+        AccountStorageType::OffChain,
+        AuthScheme::RpoFalcon512 { pub_key },
+    )
+    .unwrap();
+
+    assert!(faucet_account.is_faucet());
+    assert_eq!(get_sub_token_metadata(&faucet_account, 1).unwrap(), gold);
+    assert_eq!(get_sub_token_metadata(&faucet_account, 2).unwrap(), silver);
+    assert!(get_sub_token_metadata(&faucet_account, 3).is_err());
+    assert_eq!(get_sub_token_supply(&faucet_account, 1).unwrap(), 0);
+
+    // the same sub-token can not be registered twice
+    let result = create_multi_fungible_faucet(
+        [1; 32],
+        &[(1, gold), (1, silver)],
+        AccountStorageType::OffChain,
+        AuthScheme::RpoFalcon512 { pub_key },
+    );
+    assert!(matches!(result, Err(AccountError::FungibleFaucetInvalidMetadata(_))));
+}
+
+// TESTS NON-FUNGIBLE FAUCET
+// ================================================================================================
+
+#[test]
+fn nft_faucet_contract_mint_succeeds() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let faucet_account = get_nft_faucet_account(faucet_pub_key, 3);
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(falcon_auth.clone()));
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let tag = NoteTag::for_local_use_case(0, 0).unwrap();
+    let note_type = NoteType::OffChain;
+
+    let details = NonFungibleAssetDetails::new(faucet_account.id(), vec![1, 2, 3, 4]).unwrap();
+    let (data_hash, nft) = mint_nft(&details).unwrap();
+
+    let tx_script_code = ProgramAst::parse(
+        format!(
+            "
+            use.miden::contracts::faucets::basic_non_fungible->faucet
+            use.miden::contracts::auth::basic->auth_tx
+
+            begin
+
+                push.{recipient}
+                push.{note_type}
+                push.{tag}
+                push.{data_hash}
+                call.faucet::distribute
+
+                call.auth_tx::auth_tx_rpo_falcon512
+                dropw dropw drop drop
+
+            end
+            ",
+            note_type = note_type as u8,
+            recipient = prepare_word(&recipient),
+            tag = u32::from(tag),
+            data_hash = prepare_word(&data_hash),
+        )
+        .as_str(),
+    )
+    .unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(faucet_account.id(), block_ref, &note_ids, tx_args)
+        .unwrap();
+
+    // the created note carries the asset returned by `mint_nft`
+    let assets = NoteAssets::new(vec![nft.into()]).unwrap();
+    let created_note = executed_transaction.output_notes().get_note(0).clone();
+    assert_eq!(created_note.id(), NoteId::new(recipient.into(), assets.commitment()));
+    assert_eq!(
+        created_note.metadata(),
+        &NoteMetadata::new(faucet_account.id(), note_type, tag, ZERO).unwrap()
+    );
+
+    // three tokens were issued before, so the token was recorded under id 3 and the number of issued tokens was incremented
+    let storage_delta = executed_transaction.account_delta().storage();
+    assert!(storage_delta
+        .updated_items
+        .contains(&(NFT_FAUCET_ISSUED_COUNT_SLOT, [Felt::new(4), ZERO, ZERO, ZERO])));
+    let tokens_delta = storage_delta
+        .updated_maps
+        .iter()
+        .find(|(slot, _)| *slot == NFT_FAUCET_TOKENS_SLOT)
+        .map(|(_, delta)| delta.clone())
+        .unwrap();
+    assert_eq!(tokens_delta.updated_leaves, vec![(nft_token_map_key(3).into(), nft.into())]);
+}
+
+#[test]
+fn nft_faucet_contract_set_royalty_recipient_succeeds() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let faucet_account = get_nft_faucet_account(faucet_pub_key, 0);
+
+    let data_store = MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(falcon_auth.clone()));
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(
+        format!(
+            "
+            use.miden::contracts::faucets::basic_non_fungible->faucet
+            use.miden::contracts::auth::basic->auth_tx
+
+            begin
+                push.{ACCOUNT_ID_SENDER}
+                call.faucet::set_royalty_recipient
+
+                call.auth_tx::auth_tx_rpo_falcon512
+                drop
+            end
+            "
+        )
+        .as_str(),
+    )
+    .unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+
+    let executed_transaction = executor
+        .execute_transaction(
+            faucet_account.id(),
+            block_ref,
+            &note_ids,
+            TransactionArgs::with_tx_script(tx_script),
+        )
+        .unwrap();
+
+    assert_eq!(
+        executed_transaction.account_delta().storage().updated_items,
+        vec![(NFT_FAUCET_ROYALTY_SLOT, [Felt::new(ACCOUNT_ID_SENDER), ZERO, ZERO, ZERO])]
+    );
+}
+
+#[test]
+fn nft_faucet_contract_set_royalty_recipient_from_note_fails() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let faucet_account = get_nft_faucet_account(faucet_pub_key, 0);
+
+    // a note which tries to redirect the royalties of the faucet to its sender
+    let note_script_ast = ProgramAst::parse(
+        format!(
+            "
+            use.miden::contracts::faucets::basic_non_fungible->faucet
+
+            begin
+                push.{ACCOUNT_ID_SENDER}
+                call.faucet::set_royalty_recipient
+            end
+            "
+        )
+        .as_str(),
+    )
+    .unwrap();
+    let (note_script, _) =
+        NoteScript::new(note_script_ast, &TransactionKernel::assembler()).unwrap();
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let metadata = NoteMetadata::new(sender_id, NoteType::Public, 1.into(), ZERO).unwrap();
+    let recipient = NoteRecipient::new(
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+        note_script,
+        NoteInputs::new(vec![]).unwrap(),
+    );
+    let note = Note::new(NoteAssets::new(vec![]).unwrap(), metadata, recipient);
+
+    let data_store = MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(falcon_auth.clone()));
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let result = executor.execute_transaction(
+        faucet_account.id(),
+        block_ref,
+        &note_ids,
+        data_store.tx_args.clone(),
+    );
+    match result {
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::FailedAssertion { err_code, .. },
+        )) => assert_eq!(err_code, 131154),
+        result => panic!("transaction should fail with ERR_ACCOUNT_PROC_REQUIRES_AUTH: {result:?}"),
+    }
+}
+
+#[test]
+fn nft_faucet_contract_creation() {
+    let mut rng = ChaCha20Rng::from_seed([0_u8; 32]);
+    let pub_key = SecretKey::with_rng(&mut rng).public_key();
+    let royalty_recipient = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let (faucet_account, _) = create_nft_faucet(
+        [1; 32],
+        Some(royalty_recipient),
+        AccountStorageType::OffChain,
+        AuthScheme::RpoFalcon512 { pub_key },
+    )
+    .unwrap();
+
+    assert!(faucet_account.is_faucet());
+    assert_eq!(get_nft_issued_count(&faucet_account).unwrap(), 0);
+    assert_eq!(get_nft_token(&faucet_account, 0).unwrap(), None);
+    assert_eq!(get_nft_royalty_recipient(&faucet_account).unwrap(), Some(royalty_recipient));
+    assert!(faucet_account.code().requires_auth(get_set_royalty_recipient_root()));
+
+    // the helpers only work for non-fungible faucets
+    let fungible_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    assert!(mint_nft(&NonFungibleAssetDetails::new(faucet_account.id(), vec![1]).unwrap()).is_ok());
+    assert!(NonFungibleAssetDetails::new(fungible_faucet_id, vec![1]).is_err());
+
+    let (fungible_faucet, _) = create_basic_fungible_faucet(
+        [1; 32],
+        TokenSymbol::new("POL").unwrap(),
+        2,
+        Felt::new(100),
+        AccountStorageType::OffChain,
+        AuthScheme::RpoFalcon512 { pub_key },
+    )
+    .unwrap();
+    assert!(get_nft_issued_count(&fungible_faucet).is_err());
+}
+
+fn get_multi_faucet_account(public_key: Word, issued: Option<(u32, u64)>) -> Account {
+    let faucet_account_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let faucet_account_code_src =
+        include_str!("../../../../miden-lib/asm/miden/contracts/faucets/multi_fungible.masm");
+    let faucet_account_code_ast = ModuleAst::parse(faucet_account_code_src).unwrap();
+    let account_assembler = TransactionKernel::assembler();
+
+    let faucet_account_code =
+        AccountCode::new(faucet_account_code_ast.clone(), &account_assembler).unwrap();
+
+    // sub-token 7 has a max supply of 200
+    let metadata = FaucetMetadata::new(TokenSymbol::new("GOLD").unwrap(), 2, 200).unwrap();
+    let metadata_map = StorageMap::with_entries([(sub_token_map_key(7), metadata.into())]).unwrap();
+    let supply_map = StorageMap::with_entries(issued.map(|(sub_token_id, supply)| {
+        (sub_token_map_key(sub_token_id), [Felt::new(supply), ZERO, ZERO, ZERO])
+    }))
+    .unwrap();
+
+    let mut faucet_account_storage = AccountStorage::new(
+        vec![
+            SlotItem {
+                index: 0,
+                slot: StorageSlot::new_value(public_key),
+            },
+            SlotItem {
+                index: 1,
+                slot: StorageSlot::new_map(metadata_map.root().into()),
+            },
+            SlotItem {
+                index: 2,
+                slot: StorageSlot::new_map(supply_map.root().into()),
+            },
+        ],
+        vec![metadata_map, supply_map],
+    )
+    .unwrap();
+
+    if let Some((_, supply)) = issued {
+        faucet_account_storage
+            .set_item(FAUCET_STORAGE_DATA_SLOT, [ZERO, ZERO, ZERO, Felt::new(supply)])
+            .unwrap();
+    }
+
+    Account::new(
+        faucet_account_id,
+        AssetVault::new(&[]).unwrap(),
+        faucet_account_storage,
+        faucet_account_code,
+        Felt::new(1),
+    )
+}
+
+fn multi_faucet_distribute_script(
+    recipient: Word,
+    tag: NoteTag,
+    amount: u64,
+    sub_token_id: u32,
+) -> ProgramAst {
+    ProgramAst::parse(
+        format!(
+            "
+            use.miden::contracts::faucets::multi_fungible->faucet
+            use.miden::contracts::auth::basic->auth_tx
+
+            begin
+
+                push.{recipient}
+                push.{note_type}
+                push.{tag}
+                push.{sub_token_id}
+                push.{amount}
+                call.faucet::distribute
+
+                call.auth_tx::auth_tx_rpo_falcon512
+                dropw dropw
+
+            end
+            ",
+            note_type = NoteType::OffChain as u8,
+            recipient = prepare_word(&recipient),
+            tag = u32::from(tag),
+        )
+        .as_str(),
+    )
+    .unwrap()
+}
+
+fn get_set_migration_successor_root() -> Digest {
+    let module = ModuleAst::parse(
+        "
+        use.miden::contracts::faucets::basic_fungible->faucet
+        export.faucet::set_migration_successor
+        ",
+    )
+    .unwrap();
+    AccountCode::new(module, &TransactionKernel::assembler()).unwrap().procedures()[0]
+}
+
+fn sub_token_map_key(sub_token_id: u32) -> Digest {
+    Digest::from([ZERO, ZERO, ZERO, Felt::from(sub_token_id)])
+}
+
+fn get_nft_faucet_account(public_key: Word, issued_count: u64) -> Account {
+    let faucet_account_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let faucet_account_code_src =
+        include_str!("../../../../miden-lib/asm/miden/contracts/faucets/basic_non_fungible.masm");
+    let faucet_account_code_ast = ModuleAst::parse(faucet_account_code_src).unwrap();
+    let account_assembler = TransactionKernel::assembler();
+
+    let faucet_account_code = AccountCode::new(faucet_account_code_ast.clone(), &account_assembler)
+        .unwrap()
+        .with_auth_procedures(&[get_set_royalty_recipient_root()])
+        .unwrap();
+
+    let tokens_map = StorageMap::new();
+    let faucet_account_storage = AccountStorage::new(
+        vec![
+            SlotItem {
+                index: 0,
+                slot: StorageSlot::new_value(public_key),
+            },
+            SlotItem {
+                index: NFT_FAUCET_ISSUED_COUNT_SLOT,
+                slot: StorageSlot::new_value([Felt::new(issued_count), ZERO, ZERO, ZERO]),
+            },
+            SlotItem {
+                index: NFT_FAUCET_TOKENS_SLOT,
+                slot: StorageSlot::new_map(tokens_map.root().into()),
+            },
+            SlotItem {
+                index: FAUCET_STORAGE_DATA_SLOT,
+                slot: StorageSlot::new_map(*Smt::default().root()),
+            },
+        ],
+        vec![tokens_map],
+    )
+    .unwrap();
+
+    Account::new(
+        faucet_account_id,
+        AssetVault::new(&[]).unwrap(),
+        faucet_account_storage,
+        faucet_account_code,
+        Felt::new(1),
+    )
+}
+
+fn get_set_royalty_recipient_root() -> Digest {
+    let module = ModuleAst::parse(
+        "
+        use.miden::contracts::faucets::basic_non_fungible->faucet
+        export.faucet::set_royalty_recipient
+        ",
+    )
+    .unwrap();
+    AccountCode::new(module, &TransactionKernel::assembler()).unwrap().procedures()[0]
+}
+
+fn nft_token_map_key(token_id: u64) -> Digest {
+    Digest::from([ZERO, ZERO, ZERO, Felt::new(token_id)])
 }
 
-fn get_faucet_account_with_max_supply_and_total_issuance(
+pub fn get_faucet_account_with_max_supply_and_total_issuance(
     public_key: Word,
     max_supply: u64,
     total_issuance: Option<u64>,
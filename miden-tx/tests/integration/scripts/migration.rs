@@ -0,0 +1,322 @@
+use miden_lib::{
+    accounts::faucets::{
+        FAUCET_METADATA_SLOT, FAUCET_MIGRATION_SLOT, FAUCET_VOUCHER_SCRIPT_ROOT_SLOT,
+    },
+    notes::{
+        create_migrate_note, create_migration_voucher_note, utils::migration_voucher_script_root,
+    },
+    transaction::{memory::FAUCET_STORAGE_DATA_SLOT, TransactionKernel},
+};
+use miden_objects::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, ACCOUNT_ID_SENDER,
+        },
+        Account, AccountCode, AccountId, AccountStorage, SlotItem, StorageSlot,
+    },
+    assembly::{ModuleAst, ProgramAst},
+    assets::{AssetVault, FaucetMetadata, FungibleAsset, TokenSymbol},
+    crypto::rand::RpoRandomCoin,
+    notes::{
+        Note, NoteAssets, NoteExecutionHint, NoteId, NoteInputs, NoteMetadata, NoteRecipient,
+        NoteScript, NoteTag, NoteType,
+    },
+    Digest, Felt, Word, ZERO,
+};
+
+use crate::{assert_failed_with, execute_note, execute_tx_script, get_new_pk_and_authenticator};
+
+// TESTS BURN FOR MIGRATION
+// ================================================================================================
+
+#[test]
+fn migrate_note_burns_asset_and_creates_voucher() {
+    let old_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let new_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let old_faucet =
+        get_faucet_account(old_faucet_id, None, Some(new_faucet_id), 150, Word::default());
+
+    // the holder migrates 100 of the 150 tokens issued by the old faucet
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let asset = FungibleAsset::new(old_faucet_id, 100).unwrap();
+    let (migrate_note, voucher) =
+        create_migrate_note(sender_id, asset, new_faucet_id, NoteType::OffChain, rng()).unwrap();
+
+    let executed_transaction = execute_note(&old_faucet, migrate_note).unwrap();
+
+    // the burned tokens are removed from the total issuance of the old faucet
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+    assert!(executed_transaction
+        .account_delta()
+        .storage()
+        .updated_items
+        .contains(&(FAUCET_STORAGE_DATA_SLOT, [ZERO, ZERO, ZERO, Felt::new(50)])));
+
+    // the faucet created the voucher note for the new faucet
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    let created_note = executed_transaction.output_notes().get_note(0);
+    assert_eq!(created_note.id(), voucher.id());
+    assert_eq!(created_note.metadata(), voucher.metadata());
+}
+
+#[test]
+fn migrate_note_fails_without_successor() {
+    let old_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let new_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let old_faucet = get_faucet_account(old_faucet_id, None, None, 150, Word::default());
+
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let asset = FungibleAsset::new(old_faucet_id, 100).unwrap();
+    let (migrate_note, _) =
+        create_migrate_note(sender_id, asset, new_faucet_id, NoteType::OffChain, rng()).unwrap();
+
+    assert!(execute_note(&old_faucet, migrate_note).is_err());
+}
+
+// TESTS REDEEM MIGRATION VOUCHER
+// ================================================================================================
+
+#[test]
+fn migration_voucher_mints_replacement_asset() {
+    let old_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let new_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let new_faucet =
+        get_faucet_account(new_faucet_id, Some(old_faucet_id), None, 0, Word::default());
+
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let asset = FungibleAsset::new(old_faucet_id, 100).unwrap();
+    let (_, voucher) =
+        create_migrate_note(sender_id, asset, new_faucet_id, NoteType::OffChain, rng()).unwrap();
+
+    let executed_transaction = execute_note(&new_faucet, voucher.clone()).unwrap();
+
+    // the new faucet issued exactly the amount burned by the old faucet
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+    assert!(executed_transaction
+        .account_delta()
+        .storage()
+        .updated_items
+        .contains(&(FAUCET_STORAGE_DATA_SLOT, [ZERO, ZERO, ZERO, Felt::new(100)])));
+
+    // the replacement asset is sent to the holder in the recipient committed to by the voucher
+    let replacement_recipient: Word = voucher.inputs().values()[4..8].try_into().unwrap();
+    let replacement_assets =
+        NoteAssets::new(vec![FungibleAsset::new(new_faucet_id, 100).unwrap().into()]).unwrap();
+    let created_note = executed_transaction.output_notes().get_note(0);
+    assert_eq!(
+        created_note.id(),
+        NoteId::new(replacement_recipient.into(), replacement_assets.commitment())
+    );
+    assert_eq!(
+        created_note.metadata().tag(),
+        NoteTag::from_account_id(sender_id, NoteExecutionHint::Local).unwrap()
+    );
+}
+
+#[test]
+fn migration_voucher_fails_for_unknown_sender() {
+    let old_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    let new_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let new_faucet =
+        get_faucet_account(new_faucet_id, Some(old_faucet_id), None, 0, Word::default());
+
+    // a voucher which was not issued by the predecessor of the new faucet
+    let voucher = create_migration_voucher_note(
+        other_faucet_id,
+        new_faucet_id,
+        100,
+        NoteTag::for_local_use_case(0, 0).unwrap(),
+        Digest::default(),
+        NoteType::OffChain,
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+    )
+    .unwrap();
+
+    assert!(execute_note(&new_faucet, voucher).is_err());
+}
+
+#[test]
+fn migration_voucher_fails_for_other_note_script() {
+    let old_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let new_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let new_faucet =
+        get_faucet_account(new_faucet_id, Some(old_faucet_id), None, 0, Word::default());
+
+    // a note sent by the predecessor which redeems an amount not backed by any burned assets
+    let note_script_ast = ProgramAst::parse(
+        "
+        use.miden::contracts::faucets::basic_fungible->faucet
+
+        begin
+            padw push.2 push.0 push.1000
+            call.faucet::redeem_migration_voucher
+        end
+        ",
+    )
+    .unwrap();
+    let (note_script, _) =
+        NoteScript::new(note_script_ast, &TransactionKernel::assembler()).unwrap();
+    let metadata = NoteMetadata::new(old_faucet_id, NoteType::Public, 1.into(), ZERO).unwrap();
+    let recipient = NoteRecipient::new(
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+        note_script,
+        NoteInputs::new(vec![]).unwrap(),
+    );
+    let note = Note::new(NoteAssets::new(vec![]).unwrap(), metadata, recipient);
+
+    // ERR_BASIC_FUNGIBLE_INVALID_VOUCHER_SCRIPT
+    assert_failed_with(execute_note(&new_faucet, note), 131184);
+}
+
+// TESTS MIGRATION CONFIGURATION
+// ================================================================================================
+
+#[test]
+fn set_migration_successor_keeps_predecessor() {
+    let old_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let new_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let predecessor_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let old_faucet =
+        get_faucet_account(old_faucet_id, Some(predecessor_id), None, 150, faucet_pub_key);
+
+    let tx_script_code = ProgramAst::parse(
+        format!(
+            "
+            use.miden::contracts::faucets::basic_fungible->faucet
+            use.miden::contracts::auth::basic->auth_tx
+
+            begin
+                push.{new_faucet_id}
+                call.faucet::set_migration_successor
+
+                call.auth_tx::auth_tx_rpo_falcon512
+                drop
+            end
+            "
+        )
+        .as_str(),
+    )
+    .unwrap();
+
+    let executed_transaction = execute_tx_script(&old_faucet, falcon_auth, tx_script_code).unwrap();
+
+    assert_eq!(
+        executed_transaction.account_delta().storage().updated_items,
+        vec![(FAUCET_MIGRATION_SLOT, [new_faucet_id.into(), predecessor_id.into(), ZERO, ZERO])]
+    );
+}
+
+#[test]
+fn set_migration_successor_from_note_fails() {
+    let old_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+    let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    let old_faucet = get_faucet_account(old_faucet_id, None, None, 150, Word::default());
+
+    // a note which tries to redirect the migrations of the faucet to another faucet
+    let note_script_ast = ProgramAst::parse(
+        format!(
+            "
+            use.miden::contracts::faucets::basic_fungible->faucet
+
+            begin
+                push.{other_faucet_id}
+                call.faucet::set_migration_successor
+            end
+            "
+        )
+        .as_str(),
+    )
+    .unwrap();
+    let (note_script, _) =
+        NoteScript::new(note_script_ast, &TransactionKernel::assembler()).unwrap();
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let metadata = NoteMetadata::new(sender_id, NoteType::Public, 1.into(), ZERO).unwrap();
+    let recipient = NoteRecipient::new(
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+        note_script,
+        NoteInputs::new(vec![]).unwrap(),
+    );
+    let note = Note::new(NoteAssets::new(vec![]).unwrap(), metadata, recipient);
+
+    // ERR_ACCOUNT_PROC_REQUIRES_AUTH
+    assert_failed_with(execute_note(&old_faucet, note), 131154);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn rng() -> RpoRandomCoin {
+    RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)])
+}
+
+fn get_faucet_account(
+    faucet_id: AccountId,
+    predecessor: Option<AccountId>,
+    successor: Option<AccountId>,
+    total_issuance: u64,
+    public_key: Word,
+) -> Account {
+    let faucet_account_code_src =
+        include_str!("../../../../miden-lib/asm/miden/contracts/faucets/basic_fungible.masm");
+    let faucet_account_code_ast = ModuleAst::parse(faucet_account_code_src).unwrap();
+    let account_assembler = TransactionKernel::assembler();
+
+    // set_migration_successor requires authentication as in faucets built by miden-lib
+    let set_successor_module = ModuleAst::parse(
+        "
+        use.miden::contracts::faucets::basic_fungible->faucet
+        export.faucet::set_migration_successor
+        ",
+    )
+    .unwrap();
+    let set_successor_root =
+        AccountCode::new(set_successor_module, &account_assembler).unwrap().procedures()[0];
+    let faucet_account_code = AccountCode::new(faucet_account_code_ast.clone(), &account_assembler)
+        .unwrap()
+        .with_auth_procedures(&[set_successor_root])
+        .unwrap();
+
+    let metadata = FaucetMetadata::new(TokenSymbol::new("POL").unwrap(), 2, 200).unwrap();
+    let migration_data = [
+        successor.map(Felt::from).unwrap_or(ZERO),
+        predecessor.map(Felt::from).unwrap_or(ZERO),
+        ZERO,
+        ZERO,
+    ];
+    let mut faucet_account_storage = AccountStorage::new(
+        vec![
+            SlotItem {
+                index: 0,
+                slot: StorageSlot::new_value(public_key),
+            },
+            SlotItem {
+                index: FAUCET_METADATA_SLOT,
+                slot: StorageSlot::new_value(metadata.into()),
+            },
+            SlotItem {
+                index: FAUCET_MIGRATION_SLOT,
+                slot: StorageSlot::new_value(migration_data),
+            },
+            SlotItem {
+                index: FAUCET_VOUCHER_SCRIPT_ROOT_SLOT,
+                slot: StorageSlot::new_value(migration_voucher_script_root().unwrap().into()),
+            },
+        ],
+        vec![],
+    )
+    .unwrap();
+    faucet_account_storage
+        .set_item(FAUCET_STORAGE_DATA_SLOT, [ZERO, ZERO, ZERO, Felt::new(total_issuance)])
+        .unwrap();
+
+    Account::new(
+        faucet_id,
+        AssetVault::new(&[]).unwrap(),
+        faucet_account_storage,
+        faucet_account_code,
+        Felt::new(1),
+    )
+}
@@ -1,4 +1,9 @@
-mod faucet;
+mod auction;
+mod crowdfund;
+pub mod faucet;
+mod migration;
+mod oracle;
 mod p2id;
 mod p2idr;
+mod recovery;
 mod swap;
@@ -0,0 +1,228 @@
+use std::rc::Rc;
+
+use miden_lib::{
+    accounts::oracles::{
+        build_publish_data_script, create_basic_oracle, get_data_feed_value, DataFeedUpdate,
+        ORACLE_DATA_SLOT, ORACLE_PUBLISHED_BLOCKS_SLOT,
+    },
+    AuthScheme,
+};
+use miden_objects::{
+    accounts::{Account, AccountStorage, SlotItem, StorageMap, StorageSlot},
+    assembly::ProgramAst,
+    assets::AssetVault,
+    crypto::dsa::rpo_falcon512::{PublicKey, SecretKey},
+    testing::DeterministicRng,
+    AccountError, Digest, Felt, Word, ONE, ZERO,
+};
+use miden_tx::{
+    host::{get_falcon_signature, BasicAuthenticator},
+    TransactionExecutorError,
+};
+use mock::utils::prepare_word;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+use crate::{execute_tx_script_with_inputs, get_new_pk_and_authenticator};
+
+// TESTS BASIC ORACLE
+// ================================================================================================
+
+#[test]
+fn publish_data_updates_feeds() {
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let mut oracle = get_oracle(pub_key, &[]);
+
+    let btc_price = [Felt::new(67_000), Felt::new(2), ZERO, ZERO];
+    let eth_price = [Felt::new(3_500), Felt::new(2), ZERO, ZERO];
+    let updates = [
+        DataFeedUpdate::new(ONE, [Felt::new(66_000), Felt::new(2), ZERO, ZERO]),
+        DataFeedUpdate::new(Felt::new(2), eth_price),
+        DataFeedUpdate::new(ONE, btc_price),
+    ];
+
+    let signatures = sign_updates(&updates);
+    let executed_transaction = execute_tx_script_with_inputs(
+        &oracle,
+        falcon_auth,
+        build_publish_data_script(&updates),
+        signatures,
+    )
+    .unwrap();
+    oracle.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    // the last update of a feed is retained
+    let btc_value = get_data_feed_value(&oracle, ONE).unwrap().unwrap();
+    assert_eq!(btc_value.data(), btc_price);
+    assert_eq!(btc_value.published_block(), 4);
+    let eth_value = get_data_feed_value(&oracle, Felt::new(2)).unwrap().unwrap();
+    assert_eq!(eth_value.data(), eth_price);
+    assert_eq!(eth_value.published_block(), 4);
+    assert_eq!(get_data_feed_value(&oracle, Felt::new(3)).unwrap(), None);
+}
+
+#[test]
+fn publish_data_requires_signed_data() {
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let oracle = get_oracle(pub_key, &[]);
+
+    let signed_update = DataFeedUpdate::new(ONE, [Felt::new(66_000), Felt::new(2), ZERO, ZERO]);
+    let forged_update = DataFeedUpdate::new(ONE, [Felt::new(1), Felt::new(2), ZERO, ZERO]);
+
+    // a signature over a different data word does not authorize the forged update
+    let (_, signature) = sign_updates(&[signed_update]).remove(0);
+    let signatures = vec![(forged_update.signature_key(pub_key).into(), signature)];
+    let result = execute_tx_script_with_inputs(
+        &oracle,
+        falcon_auth.clone(),
+        build_publish_data_script(&[forged_update]),
+        signatures,
+    );
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(_))
+    ));
+
+    // the authenticator of the transaction does not sign the data words
+    let result = execute_tx_script_with_inputs(
+        &oracle,
+        falcon_auth,
+        build_publish_data_script(&[forged_update]),
+        vec![],
+    );
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(_))
+    ));
+}
+
+#[test]
+fn get_data_returns_published_data() {
+    let (pub_key, _) = get_new_pk_and_authenticator();
+    let price = [Felt::new(67_000), Felt::new(2), ZERO, ZERO];
+    let oracle = get_oracle(pub_key, &[(ONE, price, 3)]);
+
+    // reading the data feeds does not require authentication
+    let tx_script = ProgramAst::parse(
+        format!(
+            "
+        use.miden::contracts::oracles::basic->oracle
+
+        begin
+            push.1
+            call.oracle::get_data
+            push.{price} assert_eqw
+            push.3 assert_eq
+
+            push.2
+            call.oracle::get_data
+            padw assert_eqw
+            push.0 assert_eq
+        end
+        ",
+            price = prepare_word(&price),
+        )
+        .as_str(),
+    )
+    .unwrap();
+
+    let authenticator = BasicAuthenticator::new_with_rng(&[], DeterministicRng::default());
+    let executed_transaction =
+        execute_tx_script_with_inputs(&oracle, Rc::new(authenticator), tx_script, vec![]).unwrap();
+    assert_eq!(executed_transaction.account_delta().nonce(), None);
+}
+
+#[test]
+fn oracle_creation() {
+    let (pub_key, _) = get_new_pk_and_authenticator();
+    let (oracle, _) =
+        create_basic_oracle([3; 32], AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) })
+            .unwrap();
+
+    assert!(oracle.is_on_chain());
+    assert_eq!(oracle.storage().get_item(0), Digest::from(pub_key));
+    assert_eq!(oracle.code().procedures().len(), 3);
+    assert_eq!(oracle.code().auth_procedures().len(), 1);
+
+    // the data feed procedures carry descriptive info
+    let mut procedure_names = oracle
+        .code()
+        .procedures()
+        .iter()
+        .filter_map(|root| oracle.code().procedure_info(*root))
+        .map(|info| info.name())
+        .collect::<Vec<_>>();
+    procedure_names.sort();
+    assert_eq!(procedure_names, ["get_data", "publish_data"]);
+    assert_eq!(get_data_feed_value(&oracle, ONE).unwrap(), None);
+
+    let result = create_basic_oracle(
+        [3; 32],
+        AuthScheme::RpoFalcon512Session { pub_key: PublicKey::new(pub_key) },
+    );
+    assert!(matches!(result, Err(AccountError::UnsupportedAuthScheme(_))));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an existing oracle with the specified data feeds, each provided as the feed id, the
+/// data word and the block at which it was published.
+fn get_oracle(pub_key: Word, feeds: &[(Felt, Word, u32)]) -> Account {
+    let (oracle, _) =
+        create_basic_oracle([3; 32], AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) })
+            .unwrap();
+
+    let feed_key = |feed_id| Digest::from([ZERO, ZERO, ZERO, feed_id]);
+    let data_map =
+        StorageMap::with_entries(feeds.iter().map(|&(feed_id, data, _)| (feed_key(feed_id), data)))
+            .unwrap();
+    let published_blocks_map =
+        StorageMap::with_entries(feeds.iter().map(|&(feed_id, _, block)| {
+            (feed_key(feed_id), [Felt::from(block), ZERO, ZERO, ZERO])
+        }))
+        .unwrap();
+
+    let account_storage = AccountStorage::new(
+        vec![
+            SlotItem {
+                index: 0,
+                slot: StorageSlot::new_value(pub_key),
+            },
+            SlotItem {
+                index: ORACLE_DATA_SLOT,
+                slot: StorageSlot::new_map(data_map.root().into()),
+            },
+            SlotItem {
+                index: ORACLE_PUBLISHED_BLOCKS_SLOT,
+                slot: StorageSlot::new_map(published_blocks_map.root().into()),
+            },
+        ],
+        vec![data_map, published_blocks_map],
+    )
+    .unwrap();
+
+    Account::new(
+        oracle.id(),
+        AssetVault::new(&[]).unwrap(),
+        account_storage,
+        oracle.code().clone(),
+        ONE,
+    )
+}
+
+/// Returns the signatures of the key returned by [get_new_pk_and_authenticator()] over the
+/// provided updates, keyed as expected by `publish_data`.
+fn sign_updates(updates: &[DataFeedUpdate]) -> Vec<(Word, Vec<Felt>)> {
+    let sec_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([0_u8; 32]));
+    let pub_key: Word = sec_key.public_key().into();
+    let mut rng = DeterministicRng::default();
+
+    updates
+        .iter()
+        .map(|update| {
+            let signature =
+                get_falcon_signature(&sec_key, update.message().into(), &mut rng).unwrap();
+            (update.signature_key(pub_key).into(), signature)
+        })
+        .collect()
+}
@@ -1,4 +1,7 @@
-use miden_lib::{notes::create_p2id_note, transaction::TransactionKernel};
+use miden_lib::{
+    notes::{create_p2id_note, create_p2idr_note},
+    transaction::TransactionKernel,
+};
 use miden_objects::{
     accounts::{
         account_id::testing::{
@@ -14,9 +17,9 @@ use miden_objects::{
     crypto::rand::RpoRandomCoin,
     notes::{NoteScript, NoteType},
     transaction::TransactionArgs,
-    Felt,
+    Felt, ZERO,
 };
-use miden_tx::TransactionExecutor;
+use miden_tx::{NoteSkipReason, TransactionExecutor, TransactionExecutorError};
 use mock::mock::account::DEFAULT_AUTH_SCRIPT;
 
 use crate::{
@@ -50,6 +53,7 @@ fn prove_p2id_script() {
         target_account_id,
         vec![fungible_asset],
         NoteType::Public,
+        ZERO,
         RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
     )
     .unwrap();
@@ -153,6 +157,7 @@ fn p2id_script_multiple_assets() {
         target_account_id,
         vec![fungible_asset_1, fungible_asset_2],
         NoteType::Public,
+        ZERO,
         RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
     )
     .unwrap();
@@ -227,6 +232,115 @@ fn p2id_script_multiple_assets() {
     assert!(executed_transaction_2.is_err());
 }
 
+/// We test consuming all claimable notes out of a set of candidate notes. Only the notes which the
+/// account can consume are included into the transaction, the others are reported as skipped.
+#[test]
+fn consume_claimable_p2id_notes() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let other_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let rng =
+        |seed| RpoRandomCoin::new([Felt::new(seed), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let claimable_p2id = create_p2id_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
+        rng(1),
+    )
+    .unwrap();
+    let foreign_p2id = create_p2id_note(
+        sender_account_id,
+        other_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
+        rng(2),
+    )
+    .unwrap();
+    // the target account sent this note, but cannot reclaim it yet
+    let unrecallable_p2idr = create_p2idr_note(
+        target_account_id,
+        other_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
+        100,
+        rng(3),
+    )
+    .unwrap();
+    let second_p2id = create_p2id_note(
+        other_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
+        rng(4),
+    )
+    .unwrap();
+    let notes = vec![claimable_p2id, foreign_p2id, unrecallable_p2idr, second_p2id];
+
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(notes.clone()));
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(falcon_auth.clone()));
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script.clone());
+
+    let (executed_transaction, skipped_notes) = executor
+        .execute_claimable_notes(target_account_id, block_ref, &note_ids, tx_args)
+        .unwrap();
+
+    let consumed_notes = executed_transaction
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+    assert_eq!(consumed_notes, [note_ids[0], note_ids[3]]);
+    assert_eq!(
+        skipped_notes,
+        [
+            (note_ids[1], NoteSkipReason::WrongTarget(other_account_id)),
+            (note_ids[2], NoteSkipReason::RecallHeightNotReached(100)),
+        ]
+    );
+
+    let mut target_account_after = target_account.clone();
+    target_account_after.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(target_account_after.vault().get_balance(faucet_id).unwrap(), 200);
+
+    // a transaction is not executed if none of the notes can be consumed
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(notes[1..3].to_vec()));
+    let mut executor = TransactionExecutor::new(data_store, Some(falcon_auth));
+    executor.load_account(target_account_id).unwrap();
+    let result = executor.execute_claimable_notes(
+        target_account_id,
+        block_ref,
+        &note_ids[1..3],
+        TransactionArgs::with_tx_script(tx_script),
+    );
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::NoClaimableNotes(skipped_notes)) if skipped_notes.len() == 2
+    ));
+}
+
 #[test]
 fn test_note_script_to_from_felt() {
     let assembler = TransactionKernel::assembler();
@@ -11,15 +11,18 @@ use miden_objects::{
     assembly::ProgramAst,
     assets::{Asset, AssetVault, FungibleAsset},
     crypto::rand::RpoRandomCoin,
-    notes::NoteType,
+    notes::{Note, NoteType},
     transaction::TransactionArgs,
-    Felt,
+    Felt, ZERO,
 };
 use miden_tx::TransactionExecutor;
-use mock::mock::account::DEFAULT_AUTH_SCRIPT;
+use mock::{builders::TransactionContextBuilder, mock::account::DEFAULT_AUTH_SCRIPT};
 
 use crate::{get_account_with_default_account_code, get_new_pk_and_authenticator, MockDataStore};
 
+/// Number of the block referenced by the transactions consuming the notes.
+const BLOCK_NUM: u32 = 1000;
+
 // P2IDR TESTS
 // ===============================================================================================
 // We want to test the Pay to ID Reclaim script, which is a script that allows the user
@@ -54,9 +57,9 @@ fn p2idr_script() {
 
     // --------------------------------------------------------------------------------------------
     // Create notes
-    // Create the reclaim block height (Note: Current block height is 4)
-    let reclaim_block_height_in_time = 5_u32;
-    let reclaim_block_height_reclaimable = 3_u32;
+    // Create the reclaim block height
+    let reclaim_block_height_in_time = BLOCK_NUM + 1;
+    let reclaim_block_height_reclaimable = BLOCK_NUM - 1;
 
     // Create the notes with the P2IDR script
     // Create the note_in_time
@@ -65,6 +68,7 @@ fn p2idr_script() {
         target_account_id,
         vec![fungible_asset],
         NoteType::Public,
+        ZERO,
         reclaim_block_height_in_time,
         RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
     )
@@ -76,6 +80,7 @@ fn p2idr_script() {
         target_account_id,
         vec![fungible_asset],
         NoteType::Public,
+        ZERO,
         reclaim_block_height_reclaimable,
         RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
     )
@@ -83,16 +88,13 @@ fn p2idr_script() {
 
     // --------------------------------------------------------------------------------------------
     //  We have two cases:
-    //  Case "in time": block height is 4, reclaim block height is 5. Only the target account can consume the note.
-    //  Case "reclaimable": block height is 4, reclaim block height is 3. Target and sender account can consume the note.
+    //  Case "in time": reclaim block height is after the current block height. Only the target account can consume the note.
+    //  Case "reclaimable": reclaim block height is before the current block height. Target and sender account can consume the note.
     //  The malicious account should never be able to consume the note.
     // --------------------------------------------------------------------------------------------
     // CONSTRUCT AND EXECUTE TX (Case "in time" - Target Account Execution Success)
     // --------------------------------------------------------------------------------------------
-    let data_store_1 = MockDataStore::with_existing(
-        Some(target_account.clone()),
-        Some(vec![note_in_time.clone()]),
-    );
+    let data_store_1 = data_store(&target_account, &note_in_time);
     let mut executor_1 =
         TransactionExecutor::new(data_store_1.clone(), Some(target_falcon_auth.clone()));
 
@@ -123,10 +125,7 @@ fn p2idr_script() {
 
     // CONSTRUCT AND EXECUTE TX (Case "in time" - Sender Account Execution Failure)
     // --------------------------------------------------------------------------------------------
-    let data_store_2 = MockDataStore::with_existing(
-        Some(sender_account.clone()),
-        Some(vec![note_in_time.clone()]),
-    );
+    let data_store_2 = data_store(&sender_account, &note_in_time);
     let mut executor_2 =
         TransactionExecutor::new(data_store_2.clone(), Some(sender_falcon_auth.clone()));
     executor_2.load_account(sender_account_id).unwrap();
@@ -151,10 +150,7 @@ fn p2idr_script() {
 
     // CONSTRUCT AND EXECUTE TX (Case "in time" - Malicious Target Account Failure)
     // --------------------------------------------------------------------------------------------
-    let data_store_3 = MockDataStore::with_existing(
-        Some(malicious_account.clone()),
-        Some(vec![note_in_time.clone()]),
-    );
+    let data_store_3 = data_store(&malicious_account, &note_in_time);
     let mut executor_3 =
         TransactionExecutor::new(data_store_3.clone(), Some(malicious_falcon_auth.clone()));
     executor_3.load_account(malicious_account_id).unwrap();
@@ -178,10 +174,7 @@ fn p2idr_script() {
 
     // CONSTRUCT AND EXECUTE TX (Case "reclaimable" - Execution Target Account Success)
     // --------------------------------------------------------------------------------------------
-    let data_store_4 = MockDataStore::with_existing(
-        Some(target_account.clone()),
-        Some(vec![note_reclaimable.clone()]),
-    );
+    let data_store_4 = data_store(&target_account, &note_reclaimable);
     let mut executor_4 = TransactionExecutor::new(data_store_4.clone(), Some(target_falcon_auth));
     executor_4.load_account(target_account_id).unwrap();
 
@@ -210,10 +203,7 @@ fn p2idr_script() {
 
     // CONSTRUCT AND EXECUTE TX (Case "too late" - Execution Sender Account Success)
     // --------------------------------------------------------------------------------------------
-    let data_store_5 = MockDataStore::with_existing(
-        Some(sender_account.clone()),
-        Some(vec![note_reclaimable.clone()]),
-    );
+    let data_store_5 = data_store(&sender_account, &note_reclaimable);
     let mut executor_5 = TransactionExecutor::new(data_store_5.clone(), Some(sender_falcon_auth));
 
     executor_5.load_account(sender_account_id).unwrap();
@@ -242,10 +232,7 @@ fn p2idr_script() {
 
     // CONSTRUCT AND EXECUTE TX (Case "too late" - Malicious Account Failure)
     // --------------------------------------------------------------------------------------------
-    let data_store_6 = MockDataStore::with_existing(
-        Some(malicious_account.clone()),
-        Some(vec![note_reclaimable.clone()]),
-    );
+    let data_store_6 = data_store(&malicious_account, &note_reclaimable);
     let mut executor_6 =
         TransactionExecutor::new(data_store_6.clone(), Some(malicious_falcon_auth));
 
@@ -266,3 +253,18 @@ fn p2idr_script() {
     // Sixth transaction should not work (malicious account can never consume), we expect an error
     assert!(executed_transaction_6.is_err())
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a data store for a transaction executed by the specified account against block
+/// [BLOCK_NUM] and consuming a note created 10 blocks earlier.
+fn data_store(account: &Account, note: &Note) -> MockDataStore {
+    TransactionContextBuilder::new(account.clone())
+        .input_notes([note.clone()])
+        .block_num(BLOCK_NUM)
+        .input_notes_block_num(BLOCK_NUM - 10)
+        .build()
+        .unwrap()
+        .into()
+}
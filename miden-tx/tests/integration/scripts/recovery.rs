@@ -0,0 +1,490 @@
+use std::rc::Rc;
+
+use miden_lib::{
+    accounts::wallets::{
+        build_cancel_recovery_script, build_create_session_script, build_finalize_recovery_script,
+        create_basic_wallet_with_recovery, get_pending_recovery, SessionKey, WALLET_GUARDIANS_SLOT,
+        WALLET_PENDING_RECOVERY_KEY_SLOT, WALLET_RECOVERY_CONFIG_SLOT, WALLET_RECOVERY_STATE_SLOT,
+    },
+    notes::create_recovery_approval_note,
+    AuthScheme,
+};
+use miden_objects::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_OFF_CHAIN_SENDER,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        Account, AccountId, AccountStorage, AccountStorageType, AccountType, AuthSecretKey,
+        SlotItem, StorageSlot,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::{
+        dsa::rpo_falcon512::{PublicKey, SecretKey},
+        rand::RpoRandomCoin,
+    },
+    notes::{Note, NoteTag, NoteType},
+    testing::DeterministicRng,
+    transaction::{ExecutedTransaction, TransactionArgs},
+    AccountError, Felt, Word, ONE, ZERO,
+};
+use miden_tx::{host::BasicAuthenticator, TransactionExecutor, TransactionExecutorError};
+use mock::utils::prepare_word;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+use crate::{
+    assert_failed_with, execute_note, execute_tx_script, get_new_pk_and_authenticator,
+    MockDataStore,
+};
+
+// TESTS RECOVERY APPROVAL
+// ================================================================================================
+
+#[test]
+fn recovery_approvals_initiate_recovery() {
+    let (guardian_1, guardian_2) = get_guardians();
+    let (main_key, _) = get_new_pk_and_authenticator();
+    let new_key = get_new_key();
+    let mut wallet = get_wallet_with_recovery(main_key, None);
+
+    // the first approval initiates the recovery at the reference block of the transaction
+    let approval =
+        create_recovery_approval_note(guardian_1, wallet.id(), new_key, NoteType::OffChain, rng(1))
+            .unwrap();
+    let executed_transaction = execute_note(&wallet, approval).unwrap();
+    wallet.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    let pending_recovery = get_pending_recovery(&wallet).unwrap();
+    assert_eq!(pending_recovery.new_pub_key(), new_key);
+    assert_eq!(pending_recovery.initiated_block(), 4);
+    assert_eq!(pending_recovery.num_approvals(), 1);
+
+    // the same guardian cannot approve the recovery again
+    let approval =
+        create_recovery_approval_note(guardian_1, wallet.id(), new_key, NoteType::OffChain, rng(2))
+            .unwrap();
+    assert_failed_with(execute_note(&wallet, approval), 131163);
+
+    // but another guardian can
+    let approval =
+        create_recovery_approval_note(guardian_2, wallet.id(), new_key, NoteType::OffChain, rng(3))
+            .unwrap();
+    let executed_transaction = execute_note(&wallet, approval).unwrap();
+    wallet.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    let pending_recovery = get_pending_recovery(&wallet).unwrap();
+    assert_eq!(pending_recovery.new_pub_key(), new_key);
+    assert_eq!(pending_recovery.initiated_block(), 4);
+    assert_eq!(pending_recovery.num_approvals(), 2);
+}
+
+#[test]
+fn recovery_approval_from_unknown_sender_fails() {
+    let (main_key, _) = get_new_pk_and_authenticator();
+    let wallet = get_wallet_with_recovery(main_key, None);
+
+    let sender = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let approval = create_recovery_approval_note(
+        sender,
+        wallet.id(),
+        get_new_key(),
+        NoteType::OffChain,
+        rng(1),
+    )
+    .unwrap();
+    assert_failed_with(execute_note(&wallet, approval), 131162);
+}
+
+// TESTS RECOVERY FINALIZATION
+// ================================================================================================
+
+#[test]
+fn finalize_recovery_replaces_main_key() {
+    let (main_key, _) = get_new_pk_and_authenticator();
+    let new_key = get_new_key();
+    let mut wallet = get_wallet_with_recovery(main_key, Some((new_key, 0, 2)));
+
+    let executed_transaction =
+        execute_tx_script(&wallet, get_empty_authenticator(), build_finalize_recovery_script())
+            .unwrap();
+    wallet.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    assert_eq!(wallet.storage().get_item(0), Word::from(new_key).into());
+    assert_eq!(get_pending_recovery(&wallet), None);
+}
+
+#[test]
+fn finalize_recovery_before_timelock_fails() {
+    let (main_key, _) = get_new_pk_and_authenticator();
+    let wallet = get_wallet_with_recovery(main_key, Some((get_new_key(), 1, 2)));
+
+    let result =
+        execute_tx_script(&wallet, get_empty_authenticator(), build_finalize_recovery_script());
+    assert_failed_with(result, 131166);
+}
+
+#[test]
+fn finalize_recovery_without_enough_approvals_fails() {
+    let (main_key, _) = get_new_pk_and_authenticator();
+    let wallet = get_wallet_with_recovery(main_key, Some((get_new_key(), 0, 1)));
+
+    let result =
+        execute_tx_script(&wallet, get_empty_authenticator(), build_finalize_recovery_script());
+    assert_failed_with(result, 131165);
+}
+
+#[test]
+fn finalize_recovery_after_sending_asset_fails() {
+    let (main_key, _) = get_new_pk_and_authenticator();
+    let asset = get_asset();
+    let wallet = with_asset(get_wallet_with_recovery(main_key, Some((get_new_key(), 0, 2))), asset);
+
+    // the finalization of the recovery must not authorize draining the vault of the wallet
+    let tx_script_code = ProgramAst::parse(&format!(
+        "
+    use.miden::contracts::wallets::basic->wallet
+    use.miden::contracts::auth::recovery
+
+    begin
+        {send_asset}
+
+        call.recovery::finalize_recovery
+    end
+    ",
+        send_asset = send_asset_code(asset),
+    ))
+    .unwrap();
+
+    assert_failed_with(
+        execute_tx_script(&wallet, get_empty_authenticator(), tx_script_code),
+        131180,
+    );
+}
+
+#[test]
+fn send_asset_after_recovery_finalization_fails() {
+    let (main_key, _) = get_new_pk_and_authenticator();
+    let asset = get_asset();
+    let wallet = with_asset(get_wallet_with_recovery(main_key, Some((get_new_key(), 0, 2))), asset);
+
+    // the vault of the wallet cannot be drained once the finalization incremented the nonce
+    let tx_script_code = ProgramAst::parse(&format!(
+        "
+    use.miden::contracts::wallets::basic->wallet
+    use.miden::contracts::auth::recovery
+
+    begin
+        call.recovery::finalize_recovery
+
+        {send_asset}
+    end
+    ",
+        send_asset = send_asset_code(asset),
+    ))
+    .unwrap();
+
+    assert_failed_with(
+        execute_tx_script(&wallet, get_empty_authenticator(), tx_script_code),
+        131186,
+    );
+}
+
+#[test]
+fn send_asset_after_recovery_approval_fails() {
+    let (guardian_1, _) = get_guardians();
+    let (main_key, _) = get_new_pk_and_authenticator();
+    let asset = get_asset();
+    let wallet = with_asset(get_wallet_with_recovery(main_key, None), asset);
+
+    // the vault of the wallet cannot be drained once the approval incremented the nonce
+    let approval = create_recovery_approval_note(
+        guardian_1,
+        wallet.id(),
+        get_new_key(),
+        NoteType::OffChain,
+        rng(1),
+    )
+    .unwrap();
+    let tx_script_code = ProgramAst::parse(&format!(
+        "
+    use.miden::contracts::wallets::basic->wallet
+
+    begin
+        {send_asset}
+    end
+    ",
+        send_asset = send_asset_code(asset),
+    ))
+    .unwrap();
+
+    assert_failed_with(execute_note_with_tx_script(&wallet, approval, tx_script_code), 131186);
+}
+
+#[test]
+fn cancel_recovery_with_main_key() {
+    let (main_key, falcon_auth) = get_new_pk_and_authenticator();
+    let mut wallet = get_wallet_with_recovery(main_key, Some((get_new_key(), 0, 2)));
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(main_key) };
+    let executed_transaction =
+        execute_tx_script(&wallet, falcon_auth, build_cancel_recovery_script(&auth_scheme))
+            .unwrap();
+    wallet.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    assert_eq!(get_pending_recovery(&wallet), None);
+    assert_failed_with(
+        execute_tx_script(&wallet, get_empty_authenticator(), build_finalize_recovery_script()),
+        131164,
+    );
+}
+
+#[test]
+fn cancel_recovery_with_session_key_fails() {
+    let main_sec_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([0_u8; 32]));
+    let session_sec_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([2_u8; 32]));
+    let main_key = main_sec_key.public_key();
+    let session = SessionKey::new(session_sec_key.public_key(), 100, 100).unwrap();
+    let authenticator = Rc::new(BasicAuthenticator::new_with_rng(
+        &[
+            (main_key.into(), AuthSecretKey::RpoFalcon512(main_sec_key)),
+            (session.pub_key().into(), AuthSecretKey::RpoFalcon512(session_sec_key)),
+        ],
+        DeterministicRng::default(),
+    ));
+
+    let mut wallet = get_wallet_with_recovery_and_auth(
+        AuthScheme::RpoFalcon512Session { pub_key: main_key },
+        Some((get_new_key(), 0, 2)),
+    );
+    let executed_transaction =
+        execute_tx_script(&wallet, authenticator.clone(), build_create_session_script(&session))
+            .unwrap();
+    wallet.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    // a session key cannot cancel the recovery of the main key
+    let tx_script_code = ProgramAst::parse(
+        "
+    use.miden::contracts::auth::session
+
+    begin
+        call.session::cancel_recovery
+
+        push.1
+        call.session::auth_tx_rpo_falcon512_session
+        drop
+    end
+    ",
+    )
+    .unwrap();
+    let result = execute_tx_script(&wallet, authenticator.clone(), tx_script_code);
+    assert_failed_with(result, 131158);
+
+    let auth_scheme = AuthScheme::RpoFalcon512Session { pub_key: main_key };
+    let executed_transaction =
+        execute_tx_script(&wallet, authenticator, build_cancel_recovery_script(&auth_scheme))
+            .unwrap();
+    wallet.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(get_pending_recovery(&wallet), None);
+}
+
+// TESTS WALLET CREATION
+// ================================================================================================
+
+#[test]
+fn wallet_with_recovery_creation() {
+    let (guardian_1, guardian_2) = get_guardians();
+    let (main_key, _) = get_new_pk_and_authenticator();
+    let create_wallet = |guardians: &[AccountId], threshold| {
+        create_basic_wallet_with_recovery(
+            [2; 32],
+            AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(main_key) },
+            AccountType::RegularAccountImmutableCode,
+            AccountStorageType::OffChain,
+            guardians,
+            threshold,
+            4,
+        )
+    };
+
+    let (wallet, _) = create_wallet(&[guardian_1, guardian_2], 2).unwrap();
+    assert_eq!(wallet.code().procedures().len(), 9);
+    assert_eq!(wallet.code().auth_procedures().len(), 3);
+    assert_eq!(
+        wallet.storage().get_item(WALLET_RECOVERY_CONFIG_SLOT),
+        [Felt::new(2), Felt::new(4), ZERO, ZERO].into()
+    );
+    assert_eq!(
+        wallet.storage().maps()[0].root(),
+        wallet.storage().get_item(WALLET_GUARDIANS_SLOT)
+    );
+    assert_eq!(get_pending_recovery(&wallet), None);
+
+    assert!(matches!(
+        create_wallet(&[], 1),
+        Err(AccountError::WalletInvalidRecoveryConfig(_))
+    ));
+    assert!(matches!(
+        create_wallet(&[guardian_1, guardian_1], 1),
+        Err(AccountError::WalletInvalidRecoveryConfig(_))
+    ));
+    assert!(matches!(
+        create_wallet(&[guardian_1, guardian_2], 0),
+        Err(AccountError::WalletInvalidRecoveryConfig(_))
+    ));
+    assert!(matches!(
+        create_wallet(&[guardian_1, guardian_2], 3),
+        Err(AccountError::WalletInvalidRecoveryConfig(_))
+    ));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn rng(seed: u64) -> RpoRandomCoin {
+    RpoRandomCoin::new([Felt::new(seed), ZERO, ZERO, ZERO])
+}
+
+fn get_guardians() -> (AccountId, AccountId) {
+    (
+        AccountId::try_from(ACCOUNT_ID_SENDER).unwrap(),
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap(),
+    )
+}
+
+fn get_new_key() -> PublicKey {
+    SecretKey::with_rng(&mut ChaCha20Rng::from_seed([1_u8; 32])).public_key()
+}
+
+fn get_asset() -> Asset {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    FungibleAsset::new(faucet_id, 100).unwrap().into()
+}
+
+/// Returns the provided wallet holding the specified asset.
+fn with_asset(wallet: Account, asset: Asset) -> Account {
+    Account::new(
+        wallet.id(),
+        AssetVault::new(&[asset]).unwrap(),
+        wallet.storage().clone(),
+        wallet.code().clone(),
+        ONE,
+    )
+}
+
+/// Returns the code of a transaction script which sends the specified asset via the basic wallet
+/// imported as `wallet`.
+fn send_asset_code(asset: Asset) -> String {
+    format!(
+        "push.{recipient}
+        push.{note_type}
+        push.{tag}
+        push.{asset}
+        call.wallet::send_asset
+        drop drop dropw dropw",
+        recipient = prepare_word(&[ZERO, ONE, Felt::new(2), Felt::new(3)]),
+        note_type = NoteType::OffChain as u8,
+        tag = NoteTag::for_local_use_case(0, 0).unwrap(),
+        asset = prepare_word(&asset.into()),
+    )
+}
+
+/// Returns a wallet guarded by the accounts returned by [get_guardians()] with a threshold of 2
+/// and a timelock of 4 blocks, optionally with a pending recovery of the specified key which was
+/// initiated at the specified block and approved by the specified number of guardians.
+fn get_wallet_with_recovery(main_key: Word, pending: Option<(PublicKey, u32, u32)>) -> Account {
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(main_key) };
+    get_wallet_with_recovery_and_auth(auth_scheme, pending)
+}
+
+/// Returns a wallet as described in [get_wallet_with_recovery()], which uses the specified
+/// authentication scheme.
+fn get_wallet_with_recovery_and_auth(
+    auth_scheme: AuthScheme,
+    pending: Option<(PublicKey, u32, u32)>,
+) -> Account {
+    let main_key: Word = match &auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } | AuthScheme::RpoFalcon512Session { pub_key } => {
+            (*pub_key).into()
+        },
+    };
+    let (guardian_1, guardian_2) = get_guardians();
+    let (wallet, _) = create_basic_wallet_with_recovery(
+        [2; 32],
+        auth_scheme,
+        AccountType::RegularAccountImmutableCode,
+        AccountStorageType::OffChain,
+        &[guardian_1, guardian_2],
+        2,
+        4,
+    )
+    .unwrap();
+
+    let guardians_map = wallet.storage().maps()[0].clone();
+    let mut slots = vec![
+        SlotItem {
+            index: 0,
+            slot: StorageSlot::new_value(main_key),
+        },
+        SlotItem {
+            index: WALLET_GUARDIANS_SLOT,
+            slot: StorageSlot::new_map(guardians_map.root().into()),
+        },
+        SlotItem {
+            index: WALLET_RECOVERY_CONFIG_SLOT,
+            slot: StorageSlot::new_value(
+                wallet.storage().get_item(WALLET_RECOVERY_CONFIG_SLOT).into(),
+            ),
+        },
+    ];
+    if let Some((new_key, initiated_block, num_approvals)) = pending {
+        slots.push(SlotItem {
+            index: WALLET_PENDING_RECOVERY_KEY_SLOT,
+            slot: StorageSlot::new_value(new_key.into()),
+        });
+        slots.push(SlotItem {
+            index: WALLET_RECOVERY_STATE_SLOT,
+            slot: StorageSlot::new_value([
+                Felt::from(initiated_block),
+                Felt::from(num_approvals),
+                ONE,
+                ZERO,
+            ]),
+        });
+    }
+    let account_storage = AccountStorage::new(slots, vec![guardians_map]).unwrap();
+
+    Account::new(
+        wallet.id(),
+        AssetVault::new(&[]).unwrap(),
+        account_storage,
+        wallet.code().clone(),
+        ONE,
+    )
+}
+
+fn execute_note_with_tx_script(
+    wallet: &Account,
+    note: Note,
+    tx_script_code: ProgramAst,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let data_store = MockDataStore::with_existing(Some(wallet.clone()), Some(vec![note]));
+
+    let mut executor =
+        TransactionExecutor::new(data_store.clone(), Some(get_empty_authenticator()));
+    executor.load_account(wallet.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let mut tx_args = data_store.tx_args.clone();
+    tx_args.merge(TransactionArgs::with_tx_script(tx_script)).unwrap();
+
+    executor.execute_transaction(wallet.id(), block_ref, &note_ids, tx_args)
+}
+
+/// Returns an authenticator without keys, for transactions which do not require authentication.
+fn get_empty_authenticator() -> Rc<BasicAuthenticator<DeterministicRng>> {
+    Rc::new(BasicAuthenticator::new_with_rng(&[], DeterministicRng::default()))
+}
@@ -53,6 +53,7 @@ fn prove_swap_script() {
         offered_asset,
         requested_asset,
         NoteType::Public,
+        ZERO,
         RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
     )
     .unwrap();
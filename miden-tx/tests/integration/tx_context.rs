@@ -0,0 +1,103 @@
+use miden_lib::{notes::create_p2id_note, transaction::TransactionKernel};
+use miden_objects::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        AccountId,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::NoteType,
+    transaction::TransactionScript,
+    Digest, Felt, ZERO,
+};
+use mock::{builders::TransactionContextBuilder, mock::account::DEFAULT_AUTH_SCRIPT};
+
+use crate::{
+    execute_tx_context, get_account_with_default_account_code, get_new_pk_and_authenticator,
+};
+
+// TRANSACTION CONTEXT TESTS
+// ================================================================================================
+
+#[test]
+fn transaction_context_block_chain() {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (pub_key, _) = get_new_pk_and_authenticator();
+    let account = get_account_with_default_account_code(account_id, pub_key, None);
+    let prev_hash = Digest::new([1_u32.into(), 2_u32.into(), 3_u32.into(), 4_u32.into()]);
+
+    let tx_context = TransactionContextBuilder::new(account)
+        .block_num(20)
+        .timestamp(1_000_000)
+        .prev_hash(prev_hash)
+        .build()
+        .unwrap();
+
+    let block_header = tx_context.block_header();
+    assert_eq!(block_header.block_num(), 20);
+    assert_eq!(block_header.timestamp(), 1_000_000);
+    assert_eq!(tx_context.tx_inputs().block_chain().chain_length(), 20);
+
+    let blocks = tx_context.blocks();
+    assert_eq!(blocks.len(), 21);
+    assert_eq!(blocks[0].prev_hash(), prev_hash);
+    for (block_num, pair) in blocks.windows(2).enumerate() {
+        assert_eq!(pair[0].block_num(), block_num as u32);
+        assert_eq!(pair[1].prev_hash(), pair[0].hash());
+        assert_eq!(pair[1].timestamp(), pair[0].timestamp() + 10);
+    }
+}
+
+#[test]
+fn transaction_context_with_authenticator() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, authenticator) = get_new_pk_and_authenticator();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let note = create_p2id_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    let (tx_script, _) = TransactionScript::new(
+        ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap(),
+        vec![],
+        &TransactionKernel::assembler(),
+    )
+    .unwrap();
+
+    let tx_context = TransactionContextBuilder::new(target_account.clone())
+        .block_num(4)
+        .input_notes(vec![note])
+        .tx_script(tx_script)
+        .authenticator(authenticator)
+        .build()
+        .unwrap();
+
+    let executed_transaction = execute_tx_context(tx_context).unwrap();
+
+    // the note asset was added to the account vault and the nonce was incremented
+    let final_account = executed_transaction.final_account();
+    assert_eq!(final_account.nonce(), target_account.nonce() + Felt::new(1));
+    assert!(executed_transaction
+        .account_delta()
+        .vault()
+        .added_assets
+        .contains(&fungible_asset));
+}
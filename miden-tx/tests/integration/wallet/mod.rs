@@ -1,28 +1,77 @@
-use miden_lib::{accounts::wallets::create_basic_wallet, AuthScheme};
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use miden_lib::{
+    accounts::{
+        faucets::create_basic_fungible_faucet,
+        wallets::{
+            build_create_session_script, build_revoke_session_script,
+            build_set_asset_filter_entry_script, build_set_spending_limit_script,
+            create_basic_wallet, create_basic_wallet_with_asset_filter,
+            create_basic_wallet_with_spending_limits, get_asset_filter_mode, get_session_key,
+            get_spending_limit, is_asset_accepted, AssetFilterMode, SessionKey, SpendingLimit,
+            WALLET_ASSET_FILTER_SLOT, WALLET_SESSION_INFO_SLOT, WALLET_SESSION_KEY_SLOT,
+            WALLET_SPENDING_LIMITS_SLOT,
+        },
+    },
+    notes::{create_batch_p2id_notes, create_note_to_recipient, create_p2id_note},
+    transaction::TransactionKernel,
+    AuthScheme,
+};
 use miden_objects::{
     accounts::{
         account_id::testing::{
-            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_OFF_CHAIN_SENDER,
-            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_OFF_CHAIN_SENDER,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
         },
-        Account, AccountId, AccountStorage, SlotItem, StorageSlot,
+        delta::AccountUpdateDetails,
+        salt_account_hash, Account, AccountCode, AccountId, AccountStorage, AccountStorageType,
+        AccountType, AuthSecretKey, SlotItem, StorageMap, StorageSlot,
+    },
+    assembly::{ModuleAst, ProgramAst},
+    assets::{
+        Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails, TokenSymbol,
+    },
+    crypto::{
+        dsa::rpo_falcon512::{PublicKey, SecretKey},
+        rand::RpoRandomCoin,
+    },
+    notes::{
+        Note, NoteAssets, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
     },
-    assembly::ProgramAst,
-    assets::{Asset, AssetVault, FungibleAsset},
-    crypto::dsa::rpo_falcon512::SecretKey,
-    notes::{NoteTag, NoteType},
-    transaction::TransactionArgs,
-    Felt, Word, ONE, ZERO,
+    testing::DeterministicRng,
+    transaction::{
+        ExecutedTransaction, OutputNote, TransactionArgs, TransactionSummary, VaultChange,
+    },
+    vm::AdviceMap,
+    AccountError, Digest, Felt, TransactionArgsError, Word, ONE, ZERO,
+};
+use miden_prover::ProvingOptions;
+use miden_tx::{
+    host::{BasicAuthenticator, TransactionAuthenticator},
+    AuthenticationError, TransactionCompilerError, TransactionExecutor, TransactionExecutorError,
+    TransactionProver, TransactionScriptBuilder, TransactionVerifier,
+};
+use mock::{
+    constants::MIN_PROOF_SECURITY_LEVEL,
+    mock::account::{DEFAULT_ACCOUNT_CODE, DEFAULT_AUTH_SCRIPT},
+    utils::prepare_word,
 };
-use miden_tx::TransactionExecutor;
-use mock::{mock::account::DEFAULT_AUTH_SCRIPT, utils::prepare_word};
 use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use vm_processor::ExecutionError;
 
 use crate::{
-    get_account_with_default_account_code, get_new_pk_and_authenticator,
-    get_note_with_fungible_asset_and_script, prove_and_verify_transaction, MockDataStore,
+    assert_failed_with, execute_tx_script, get_account_with_default_account_code,
+    get_new_pk_and_authenticator, get_note_with_fungible_asset_and_script,
+    prove_and_verify_transaction, MockDataStore,
 };
 
+// CONSTANTS
+// ================================================================================================
+
+const ERR_BASIC_WALLET_ASSET_REJECTED: u32 = 131179;
+
 #[test]
 // Testing the basic Miden wallet - receiving an asset
 fn prove_receive_asset_via_wallet() {
@@ -105,6 +154,152 @@ fn prove_receive_asset_via_wallet() {
     assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
 }
 
+#[test]
+fn prove_receive_asset_with_salted_account_commitments() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, target_falcon_auth) = get_new_pk_and_authenticator();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let rng = RpoRandomCoin::new([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let note = create_p2id_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
+        rng,
+    )
+    .unwrap();
+
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note.clone()]));
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(target_falcon_auth));
+    executor.load_account(target_account_id).unwrap();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let mut tx_args = TransactionArgs::with_tx_script(tx_script);
+    let init_salt = [ONE, ZERO, ZERO, ZERO];
+    let final_salt = [Felt::new(2), ZERO, ZERO, ZERO];
+    tx_args.set_account_salts(Some(init_salt), Some(final_salt));
+
+    let block_ref = data_store.block_header.block_num();
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &[note.id()], tx_args)
+        .unwrap();
+
+    let init_commitment = salt_account_hash(target_account.hash(), Some(init_salt));
+    let final_commitment =
+        salt_account_hash(executed_transaction.final_account().hash(), Some(final_salt));
+    assert_eq!(executed_transaction.final_account_commitment(), final_commitment);
+
+    // the proven transaction records the salted commitments and the final salt
+    let prover = TransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove_transaction(executed_transaction.clone()).unwrap();
+    assert_eq!(proven_transaction.id(), executed_transaction.id());
+
+    let account_update = proven_transaction.account_update();
+    assert_eq!(account_update.init_state_hash(), init_commitment);
+    assert_eq!(account_update.final_state_hash(), final_commitment);
+    assert_eq!(account_update.details(), &AccountUpdateDetails::PrivateSalted(final_salt));
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    assert!(verifier.verify(proven_transaction).is_ok());
+}
+
+#[test]
+fn salted_account_commitments_require_off_chain_account() {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let account = get_account_with_default_account_code(account_id, pub_key, None);
+
+    let data_store = MockDataStore::with_existing(Some(account), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(falcon_auth));
+    executor.load_account(account_id).unwrap();
+
+    let mut tx_args = TransactionArgs::default();
+    tx_args.set_account_salts(None, Some([ONE, ZERO, ZERO, ZERO]));
+
+    let block_ref = data_store.block_header.block_num();
+    let result = executor.execute_transaction(account_id, block_ref, &[], tx_args);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::InvalidAccountSalts(id)) if id == account_id
+    ));
+}
+
+#[test]
+fn receive_asset_with_required_note_args() {
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1 = FungibleAsset::new(faucet_id_1, 100).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, target_falcon_auth) = get_new_pk_and_authenticator();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    // the note can only be consumed if the note args are set to [1, 2, 3, 4]
+    let note_script_ast = ProgramAst::parse(
+        "
+    use.miden::note
+    use.miden::contracts::wallets::basic->wallet
+
+    begin
+        dropw
+        exec.note::get_args
+        push.1.2.3.4 assert_eqw
+
+        exec.note::get_assets drop
+        mem_loadw
+        call.wallet::receive_asset
+        dropw
+    end
+    ",
+    )
+    .unwrap();
+    let note = get_note_with_fungible_asset_and_script(fungible_asset_1, note_script_ast);
+
+    let data_store = MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note]));
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(target_falcon_auth));
+    executor.load_account(target_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_id = data_store.notes[0].id();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+
+    // executing the transaction without note args fails
+    let tx_args = TransactionArgs::with_tx_script(tx_script.clone());
+    let result = executor.execute_transaction(target_account.id(), block_ref, &[note_id], tx_args);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::InvalidTransactionArgs(
+            TransactionArgsError::MissingNoteArgs(id)
+        )) if id == note_id
+    ));
+
+    // executing the transaction with note args succeeds
+    let note_args = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+    let tx_args = TransactionArgs::new(
+        Some(tx_script),
+        Some(BTreeMap::from([(note_id, note_args)])),
+        AdviceMap::default(),
+    );
+    let executed_transaction = executor
+        .execute_transaction(target_account.id(), block_ref, &[note_id], tx_args)
+        .unwrap();
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+}
+
 #[test]
 /// Testing the basic Miden wallet - sending an asset
 fn prove_send_asset_via_wallet() {
@@ -191,12 +386,251 @@ fn prove_send_asset_via_wallet() {
     assert_eq!(executed_transaction.final_account().hash(), sender_account_after.hash());
 }
 
+#[test]
+fn send_asset_via_wallet_with_script_builder() {
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1: Asset = FungibleAsset::new(faucet_id_1, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let (sender_pub_key, sender_falcon_auth) = get_new_pk_and_authenticator();
+    let sender_account = get_account_with_default_account_code(
+        sender_account_id,
+        sender_pub_key,
+        fungible_asset_1.into(),
+    );
+
+    let data_store = MockDataStore::with_existing(Some(sender_account.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(sender_falcon_auth));
+    executor.load_account(sender_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let recipient = Digest::from([ZERO, ONE, Felt::new(2), Felt::new(3)]);
+    let tag = NoteTag::for_local_use_case(0, 0).unwrap();
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(sender_pub_key) };
+
+    let tx_script_code = TransactionScriptBuilder::new()
+        .send_asset(fungible_asset_1, recipient, tag, NoteType::OffChain)
+        .with_auth(&auth_scheme)
+        .build();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(sender_account.id(), block_ref, &[], tx_args)
+        .unwrap();
+
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 1);
+    let output_note = output_notes.get_note(0);
+    assert_eq!(output_note.metadata().tag(), tag);
+    assert_eq!(output_note.metadata().sender(), sender_account_id);
+    assert_eq!(
+        executed_transaction.final_account().vault_root(),
+        AssetVault::default().commitment()
+    );
+
+    let summary = executed_transaction.summary();
+    assert!(summary.inflows().is_empty());
+    assert_eq!(summary.outflows(), [fungible_asset_1]);
+    assert_eq!((summary.init_nonce(), summary.final_nonce()), (ONE, Felt::new(2)));
+    let created_note = &summary.created_notes()[0];
+    assert_eq!(created_note.id(), output_note.id());
+    assert_eq!(created_note.note_type(), NoteType::OffChain);
+    assert_eq!(created_note.recipient(), Some(recipient));
+    assert_eq!(created_note.assets(), Some([fungible_asset_1].as_slice()));
+}
+
+#[test]
+fn send_asset_to_recipient_digest() {
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1: Asset = FungibleAsset::new(faucet_id_1, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let (sender_pub_key, sender_falcon_auth) = get_new_pk_and_authenticator();
+    let sender_account = get_account_with_default_account_code(
+        sender_account_id,
+        sender_pub_key,
+        fungible_asset_1.into(),
+    );
+
+    let data_store = MockDataStore::with_existing(Some(sender_account.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(sender_falcon_auth));
+    executor.load_account(sender_account.id()).unwrap();
+
+    // the recipient is communicated only as a digest
+    let recipient = Digest::from([ZERO, ONE, Felt::new(2), Felt::new(3)]);
+    let tag = NoteTag::for_local_use_case(0, 0).unwrap();
+    let note = create_note_to_recipient(
+        sender_account_id,
+        recipient,
+        fungible_asset_1,
+        tag,
+        NoteType::OffChain,
+    )
+    .unwrap();
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(sender_pub_key) };
+    let tx_script_code = TransactionScriptBuilder::new()
+        .send_to_recipient(&note)
+        .with_auth(&auth_scheme)
+        .build();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let block_ref = data_store.block_header.block_num();
+    let executed_transaction = executor
+        .execute_transaction(sender_account.id(), block_ref, &[], tx_args)
+        .unwrap();
+
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 1);
+    assert_eq!(output_notes.get_note(0), &OutputNote::Partial(note));
+}
+
+#[test]
+fn signed_message_commits_to_tx_summary() {
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1: Asset = FungibleAsset::new(faucet_id_1, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let (sender_pub_key, sender_falcon_auth) = get_new_pk_and_authenticator();
+    let sender_account = get_account_with_default_account_code(
+        sender_account_id,
+        sender_pub_key,
+        fungible_asset_1.into(),
+    );
+    let authenticator = Rc::new(RecordingAuthenticator {
+        inner: sender_falcon_auth,
+        requests: RefCell::new(Vec::new()),
+    });
+
+    let data_store = MockDataStore::with_existing(Some(sender_account.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(authenticator.clone()));
+    executor.load_account(sender_account.id()).unwrap();
+
+    let recipient = Digest::from([ZERO, ONE, Felt::new(2), Felt::new(3)]);
+    let tag = NoteTag::for_local_use_case(0, 0).unwrap();
+    let note = create_note_to_recipient(
+        sender_account_id,
+        recipient,
+        fungible_asset_1,
+        tag,
+        NoteType::OffChain,
+    )
+    .unwrap();
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(sender_pub_key) };
+    let tx_script_code = TransactionScriptBuilder::new()
+        .send_to_recipient(&note)
+        .with_auth(&auth_scheme)
+        .build();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let block_ref = data_store.block_header.block_num();
+    let executed_transaction = executor
+        .execute_transaction(sender_account.id(), block_ref, &[], tx_args)
+        .unwrap();
+
+    // the signed message commits to the changes made by the transaction
+    let requests = authenticator.requests.borrow();
+    let [(message, tx_summary)] = requests.as_slice() else {
+        panic!("expected a single signature request");
+    };
+    assert_eq!(tx_summary.to_commitment(), Digest::from(*message));
+    assert_eq!(tx_summary.vault_changes(), &[VaultChange::Removed(fungible_asset_1)]);
+    assert!(tx_summary.storage_changes().is_empty());
+    assert_eq!(tx_summary.nonce(), executed_transaction.final_account().nonce());
+    assert_eq!(tx_summary.input_notes_hash(), executed_transaction.input_notes().commitment());
+    assert_eq!(tx_summary.output_notes_hash(), executed_transaction.output_notes().commitment());
+    assert!(tx_summary.matches_account_delta(executed_transaction.account_delta()));
+}
+
+#[test]
+fn send_batch_payout_with_script_builder() {
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    let fungible_asset =
+        |faucet_id, amount| -> Asset { FungibleAsset::new(faucet_id, amount).unwrap().into() };
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let (sender_pub_key, sender_falcon_auth) = get_new_pk_and_authenticator();
+    let sender_account =
+        get_account_with_default_account_code(sender_account_id, sender_pub_key, None);
+    let sender_vault =
+        AssetVault::new(&[fungible_asset(faucet_id_1, 100), fungible_asset(faucet_id_2, 50)])
+            .unwrap();
+    let sender_account = Account::new(
+        sender_account_id,
+        sender_vault.clone(),
+        sender_account.storage().clone(),
+        sender_account.code().clone(),
+        sender_account.nonce(),
+    );
+
+    // the first payout holds two assets, which are sent in the same note
+    let payouts = vec![
+        (
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap(),
+            vec![fungible_asset(faucet_id_1, 30), fungible_asset(faucet_id_2, 20)],
+        ),
+        (
+            AccountId::try_from(ACCOUNT_ID_SENDER).unwrap(),
+            vec![fungible_asset(faucet_id_1, 70)],
+        ),
+        (
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap(),
+            vec![fungible_asset(faucet_id_2, 30)],
+        ),
+    ];
+    let rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let notes =
+        create_batch_p2id_notes(sender_account_id, payouts, NoteType::OffChain, rng).unwrap();
+
+    // the payouts must not exceed the assets of the sender
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(sender_pub_key) };
+    let result = TransactionScriptBuilder::new()
+        .send_asset(
+            fungible_asset(faucet_id_2, 1),
+            Digest::default(),
+            notes[0].metadata().tag(),
+            NoteType::OffChain,
+        )
+        .send_notes(&notes, &sender_vault);
+    assert!(matches!(result, Err(TransactionCompilerError::InsufficientVaultAssets(_))));
+
+    let data_store = MockDataStore::with_existing(Some(sender_account.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(sender_falcon_auth));
+    executor.load_account(sender_account.id()).unwrap();
+
+    let tx_script_code = TransactionScriptBuilder::new()
+        .send_notes(&notes, &sender_vault)
+        .unwrap()
+        .with_auth(&auth_scheme)
+        .build();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let block_ref = data_store.block_header.block_num();
+    let executed_transaction = executor
+        .execute_transaction(sender_account.id(), block_ref, &[], tx_args)
+        .unwrap();
+
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), notes.len());
+    for (index, note) in notes.iter().enumerate() {
+        assert_eq!(output_notes.get_note(index).id(), note.id());
+    }
+    assert_eq!(
+        executed_transaction.final_account().vault_root(),
+        AssetVault::default().commitment()
+    );
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[test]
 fn wallet_creation() {
-    use miden_objects::accounts::{
-        account_id::testing::ACCOUNT_ID_SENDER, AccountStorageType, AccountType,
-    };
+    use miden_objects::accounts::{AccountStorageType, AccountType};
 
     // we need a Falcon Public Key to create the wallet account
     let seed = [0_u8; 32];
@@ -223,6 +657,12 @@ fn wallet_creation() {
     let expected_code_root =
         get_account_with_default_account_code(sender_account_id, pub_key.into(), None)
             .code()
+            .clone()
+            .with_auth_procedures(&[
+                get_set_spending_limit_root(),
+                get_set_asset_filter_entry_root(),
+            ])
+            .unwrap()
             .root();
 
     assert!(wallet.is_regular_account());
@@ -230,3 +670,935 @@ fn wallet_creation() {
     let pub_key_word: Word = pub_key.into();
     assert_eq!(wallet.storage().get_item(0).as_elements(), pub_key_word);
 }
+
+// TESTS SPENDING LIMITS
+// ================================================================================================
+
+#[test]
+fn send_asset_within_spending_limit() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let wallet = get_wallet_with_spending_limits(pub_key, 150, &[(faucet_id, [100, 10, 0, 0])]);
+
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let executed_transaction = send_asset(&wallet, falcon_auth, asset).unwrap();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(executed_transaction.account_delta().vault().removed_assets, vec![asset]);
+
+    // the sent amount is recorded in the current period, which started at block 0
+    let storage_delta = executed_transaction.account_delta().storage();
+    assert_eq!(
+        storage_delta.updated_maps[0].1.updated_leaves,
+        vec![(
+            [ZERO, ZERO, ZERO, faucet_id.into()],
+            [Felt::new(100), Felt::new(10), Felt::new(100), ZERO]
+        )]
+    );
+}
+
+#[test]
+fn send_asset_over_spending_limit_fails() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let wallet = get_wallet_with_spending_limits(pub_key, 150, &[(faucet_id, [100, 10, 0, 0])]);
+
+    let asset: Asset = FungibleAsset::new(faucet_id, 101).unwrap().into();
+    assert_failed_with(send_asset(&wallet, falcon_auth, asset), 131185);
+}
+
+#[test]
+fn send_assets_over_spending_limit_in_one_transaction_fails() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let wallet = get_wallet_with_spending_limits(pub_key, 150, &[(faucet_id, [100, 10, 0, 0])]);
+
+    // each of the assets is within the limit, but not their sum
+    let asset: Asset = FungibleAsset::new(faucet_id, 60).unwrap().into();
+    let tx_script = TransactionScriptBuilder::new()
+        .send_assets(
+            [asset, asset],
+            Digest::from([ZERO, ONE, Felt::new(2), Felt::new(3)]),
+            NoteTag::for_local_use_case(0, 0).unwrap(),
+            NoteType::OffChain,
+        )
+        .with_auth(&AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) })
+        .build();
+
+    assert_failed_with(execute_tx_script(&wallet, falcon_auth, tx_script), 131185);
+}
+
+#[test]
+fn send_asset_over_remaining_spending_limit_of_period_fails() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+
+    // 60 of the limit were already sent in the period which started at block 1 and ends after
+    // block 10, while the transaction is executed against block 4
+    let wallet = get_wallet_with_spending_limits(pub_key, 150, &[(faucet_id, [100, 10, 60, 1])]);
+
+    let asset: Asset = FungibleAsset::new(faucet_id, 41).unwrap().into();
+    assert_failed_with(send_asset(&wallet, falcon_auth.clone(), asset), 131185);
+
+    let asset: Asset = FungibleAsset::new(faucet_id, 40).unwrap().into();
+    assert!(send_asset(&wallet, falcon_auth, asset).is_ok());
+}
+
+#[test]
+fn spending_limit_resets_after_period() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+
+    // the whole limit was sent in the period which started at block 1 and ended after block 2
+    let wallet = get_wallet_with_spending_limits(pub_key, 150, &[(faucet_id, [100, 2, 100, 1])]);
+
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let executed_transaction = send_asset(&wallet, falcon_auth, asset).unwrap();
+
+    // a new period starts at the reference block of the transaction
+    let storage_delta = executed_transaction.account_delta().storage();
+    assert_eq!(
+        storage_delta.updated_maps[0].1.updated_leaves,
+        vec![(
+            [ZERO, ZERO, ZERO, faucet_id.into()],
+            [Felt::new(100), Felt::new(2), Felt::new(100), Felt::new(4)]
+        )]
+    );
+}
+
+#[test]
+fn send_asset_of_faucet_without_spending_limit() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let wallet =
+        get_wallet_with_spending_limits(pub_key, 150, &[(other_faucet_id, [100, 10, 0, 0])]);
+
+    let asset: Asset = FungibleAsset::new(faucet_id, 150).unwrap().into();
+    assert!(send_asset(&wallet, falcon_auth, asset).is_ok());
+}
+
+#[test]
+fn set_spending_limit_updates_storage_map() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let wallet = get_wallet_with_spending_limits(pub_key, 150, &[(faucet_id, [100, 10, 60, 1])]);
+
+    let limit = SpendingLimit::new(200, 20).unwrap();
+    let tx_script_code = build_set_spending_limit_script(
+        faucet_id,
+        Some(limit),
+        &AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) },
+    );
+    let executed_transaction = execute_tx_script(&wallet, falcon_auth, tx_script_code).unwrap();
+
+    // the amount sent in the current period is kept
+    let storage_delta = executed_transaction.account_delta().storage();
+    assert_eq!(storage_delta.updated_maps.len(), 1);
+    assert_eq!(storage_delta.updated_maps[0].0, WALLET_SPENDING_LIMITS_SLOT);
+    assert_eq!(
+        storage_delta.updated_maps[0].1.updated_leaves,
+        vec![(
+            [ZERO, ZERO, ZERO, faucet_id.into()],
+            [Felt::new(200), Felt::new(20), Felt::new(60), ONE]
+        )]
+    );
+}
+
+#[test]
+fn set_spending_limit_after_authentication_fails() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let wallet = get_wallet_with_spending_limits(pub_key, 150, &[(faucet_id, [100, 10, 0, 0])]);
+
+    // the signature of the transaction would not cover a limit raised after the authentication
+    let tx_script_code = ProgramAst::parse(
+        format!(
+            "
+        use.miden::contracts::auth::basic->auth_tx
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            call.auth_tx::auth_tx_rpo_falcon512
+
+            push.10 push.200 push.{faucet_id}
+            call.wallet::set_spending_limit
+            drop drop drop
+        end
+        "
+        )
+        .as_str(),
+    )
+    .unwrap();
+
+    assert_failed_with(execute_tx_script(&wallet, falcon_auth, tx_script_code), 131186);
+}
+
+#[test]
+fn wallet_with_spending_limits_creation() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    let mut rng = ChaCha20Rng::from_seed([0_u8; 32]);
+    let pub_key = SecretKey::with_rng(&mut rng).public_key();
+    let account_type = AccountType::RegularAccountImmutableCode;
+    let storage_type = AccountStorageType::OffChain;
+    let limit = SpendingLimit::new(100, 10).unwrap();
+
+    let (wallet, _) = create_basic_wallet_with_spending_limits(
+        [1; 32],
+        AuthScheme::RpoFalcon512 { pub_key },
+        account_type,
+        storage_type,
+        &[(faucet_id, limit)],
+    )
+    .unwrap();
+
+    assert_eq!(get_spending_limit(&wallet, faucet_id), Ok(Some(limit)));
+    assert_eq!(get_spending_limit(&wallet, other_faucet_id), Ok(None));
+
+    // wallets without spending limits do not restrict any faucet
+    let (wallet, _) = create_basic_wallet(
+        [1; 32],
+        AuthScheme::RpoFalcon512 { pub_key },
+        account_type,
+        storage_type,
+    )
+    .unwrap();
+    assert_eq!(get_spending_limit(&wallet, faucet_id), Ok(None));
+
+    // only positive limits over non-empty periods can be created
+    for (amount, period) in [(0, 10), (FungibleAsset::MAX_AMOUNT + 1, 10), (100, 0)] {
+        assert!(matches!(
+            SpendingLimit::new(amount, period),
+            Err(AccountError::WalletInvalidSpendingLimit(_))
+        ));
+    }
+
+    // only limits for distinct fungible faucets can be set
+    let non_fungible_faucet_id =
+        AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    for limits in [
+        vec![(non_fungible_faucet_id, limit)],
+        vec![(faucet_id, limit), (faucet_id, limit)],
+    ] {
+        assert!(matches!(
+            create_basic_wallet_with_spending_limits(
+                [1; 32],
+                AuthScheme::RpoFalcon512 { pub_key },
+                account_type,
+                storage_type,
+                &limits
+            ),
+            Err(AccountError::WalletInvalidSpendingLimit(_))
+        ));
+    }
+}
+
+// TESTS ASSET FILTER
+// ================================================================================================
+
+#[test]
+fn receive_asset_with_allowlist() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let wallet = get_wallet_with_asset_filter(pub_key, AssetFilterMode::Allowlist, &[faucet_id]);
+
+    let asset: Asset = FungibleAsset::new(faucet_id, 10).unwrap().into();
+    let executed_transaction =
+        execute_notes(&wallet, falcon_auth.clone(), vec![get_receive_asset_note(asset)]).unwrap();
+    assert_eq!(executed_transaction.account_delta().vault().added_assets, vec![asset]);
+
+    // assets of faucets which are not listed are rejected
+    let asset: Asset = FungibleAsset::new(other_faucet_id, 10).unwrap().into();
+    let result = execute_notes(&wallet, falcon_auth, vec![get_receive_asset_note(asset)]);
+    assert_failed_with(result, ERR_BASIC_WALLET_ASSET_REJECTED);
+}
+
+#[test]
+fn receive_asset_with_denylist() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let non_fungible_faucet_id =
+        AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let wallet =
+        get_wallet_with_asset_filter(pub_key, AssetFilterMode::Denylist, &[non_fungible_faucet_id]);
+
+    let asset: Asset = FungibleAsset::new(faucet_id, 10).unwrap().into();
+    let executed_transaction =
+        execute_notes(&wallet, falcon_auth.clone(), vec![get_receive_asset_note(asset)]).unwrap();
+    assert_eq!(executed_transaction.account_delta().vault().added_assets, vec![asset]);
+
+    // assets of listed faucets are rejected
+    let details = NonFungibleAssetDetails::new(non_fungible_faucet_id, vec![1, 2, 3]).unwrap();
+    let asset: Asset = NonFungibleAsset::new(&details).unwrap().into();
+    let result = execute_notes(&wallet, falcon_auth, vec![get_receive_asset_note(asset)]);
+    assert_failed_with(result, ERR_BASIC_WALLET_ASSET_REJECTED);
+}
+
+#[test]
+fn set_asset_filter_entry_updates_storage_map() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let mut wallet = get_wallet_with_asset_filter(pub_key, AssetFilterMode::Denylist, &[]);
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) };
+    assert_eq!(is_asset_accepted(&wallet, faucet_id), Ok(true));
+
+    let tx_script_code = build_set_asset_filter_entry_script(faucet_id, true, &auth_scheme);
+    let executed_transaction =
+        execute_tx_script(&wallet, falcon_auth.clone(), tx_script_code).unwrap();
+
+    let storage_delta = executed_transaction.account_delta().storage();
+    assert_eq!(storage_delta.updated_maps.len(), 1);
+    assert_eq!(storage_delta.updated_maps[0].0, WALLET_ASSET_FILTER_SLOT);
+    assert_eq!(
+        storage_delta.updated_maps[0].1.updated_leaves,
+        vec![([ZERO, ZERO, ZERO, faucet_id.into()], [ONE, ZERO, ZERO, ZERO])]
+    );
+
+    wallet.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(is_asset_accepted(&wallet, faucet_id), Ok(false));
+
+    // the faucet can be removed from the denylist again
+    let tx_script_code = build_set_asset_filter_entry_script(faucet_id, false, &auth_scheme);
+    let executed_transaction = execute_tx_script(&wallet, falcon_auth, tx_script_code).unwrap();
+
+    wallet.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(is_asset_accepted(&wallet, faucet_id), Ok(true));
+}
+
+#[test]
+fn wallet_with_asset_filter_creation() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    let mut rng = ChaCha20Rng::from_seed([0_u8; 32]);
+    let pub_key = SecretKey::with_rng(&mut rng).public_key();
+    let account_type = AccountType::RegularAccountImmutableCode;
+    let storage_type = AccountStorageType::OffChain;
+
+    let (wallet, _) = create_basic_wallet_with_asset_filter(
+        [1; 32],
+        AuthScheme::RpoFalcon512 { pub_key },
+        account_type,
+        storage_type,
+        AssetFilterMode::Allowlist,
+        &[faucet_id],
+    )
+    .unwrap();
+
+    assert_eq!(get_asset_filter_mode(&wallet), Some(AssetFilterMode::Allowlist));
+    assert_eq!(is_asset_accepted(&wallet, faucet_id), Ok(true));
+    assert_eq!(is_asset_accepted(&wallet, other_faucet_id), Ok(false));
+    assert!(wallet.code().requires_auth(get_set_asset_filter_entry_root()));
+
+    // wallets without an asset filter accept assets of all faucets
+    let (wallet, _) = create_basic_wallet(
+        [1; 32],
+        AuthScheme::RpoFalcon512 { pub_key },
+        account_type,
+        storage_type,
+    )
+    .unwrap();
+    assert_eq!(get_asset_filter_mode(&wallet), None);
+    assert_eq!(is_asset_accepted(&wallet, other_faucet_id), Ok(true));
+
+    // only distinct faucets can be listed
+    let regular_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    for faucet_ids in [vec![regular_account_id], vec![faucet_id, faucet_id]] {
+        assert!(matches!(
+            create_basic_wallet_with_asset_filter(
+                [1; 32],
+                AuthScheme::RpoFalcon512 { pub_key },
+                account_type,
+                storage_type,
+                AssetFilterMode::Denylist,
+                &faucet_ids
+            ),
+            Err(AccountError::WalletInvalidAssetFilter(_))
+        ));
+    }
+}
+
+// TESTS SESSION KEYS
+// ================================================================================================
+
+#[test]
+fn create_and_revoke_session() {
+    let (main_key, session_key, authenticator) = get_session_keys_and_authenticator();
+    let mut wallet = get_wallet_with_session_keys(main_key, None, 150);
+
+    let session = SessionKey::new(session_key, 100, 50).unwrap();
+    let executed_transaction =
+        execute_tx_script(&wallet, authenticator.clone(), build_create_session_script(&session))
+            .unwrap();
+
+    wallet.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(get_session_key(&wallet), Some(session));
+
+    // the session can be revoked by the main key as well
+    let executed_transaction =
+        execute_tx_script(&wallet, authenticator, build_revoke_session_script()).unwrap();
+
+    let storage_delta = executed_transaction.account_delta().storage();
+    assert_eq!(
+        storage_delta.cleared_items,
+        vec![WALLET_SESSION_KEY_SLOT, WALLET_SESSION_INFO_SLOT]
+    );
+    wallet.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(get_session_key(&wallet), None);
+}
+
+#[test]
+fn create_session_with_session_key_fails() {
+    let (main_key, session_key, authenticator) = get_session_keys_and_authenticator();
+    let session = SessionKey::new(session_key, 100, 50).unwrap();
+    let wallet = get_wallet_with_session_keys(main_key, Some(session), 150);
+
+    // a session key cannot extend its own session
+    let tx_script = ProgramAst::parse(
+        format!(
+            "
+        use.miden::contracts::auth::session
+
+        begin
+            push.150.1000
+            push.{session_key}
+            call.session::create_session
+            dropw drop drop
+
+            push.1
+            call.session::auth_tx_rpo_falcon512_session
+            drop
+        end
+        ",
+            session_key = prepare_word(&session_key.into()),
+        )
+        .as_str(),
+    )
+    .unwrap();
+    let result = execute_tx_script(&wallet, authenticator, tx_script);
+    assert_failed_with(result, 131158);
+}
+
+#[test]
+fn send_asset_with_session_key() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (main_key, session_key, authenticator) = get_session_keys_and_authenticator();
+    let session = SessionKey::new(session_key, 100, 100).unwrap();
+    let wallet = get_wallet_with_session_keys(main_key, Some(session), 150);
+
+    let asset: Asset = FungibleAsset::new(faucet_id, 60).unwrap().into();
+    let tx_script = send_asset_script(asset).with_session_key_auth().build();
+    let executed_transaction = execute_tx_script(&wallet, authenticator, tx_script).unwrap();
+
+    // the amount sent is deducted from the spending cap of the session
+    assert_eq!(executed_transaction.account_delta().vault().removed_assets, vec![asset]);
+    assert_eq!(
+        executed_transaction.account_delta().storage().updated_items,
+        vec![(WALLET_SESSION_INFO_SLOT, [Felt::new(100), Felt::new(40), ZERO, ZERO])]
+    );
+}
+
+#[test]
+fn send_asset_over_session_spending_cap_fails() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (main_key, session_key, authenticator) = get_session_keys_and_authenticator();
+    let session = SessionKey::new(session_key, 100, 100).unwrap();
+    let wallet = get_wallet_with_session_keys(main_key, Some(session), 150);
+
+    let asset: Asset = FungibleAsset::new(faucet_id, 101).unwrap().into();
+    let tx_script = send_asset_script(asset).with_session_key_auth().build();
+    assert_failed_with(execute_tx_script(&wallet, authenticator, tx_script), 131158);
+}
+
+#[test]
+fn send_asset_with_expired_session_key_fails() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (main_key, session_key, authenticator) = get_session_keys_and_authenticator();
+    let wallet = get_wallet_with_session_keys(main_key, None, 150);
+
+    // the session expires one block before the reference block of the transaction
+    let block_num = MockDataStore::with_existing(Some(wallet), None).block_header.block_num();
+    let session = SessionKey::new(session_key, block_num - 1, 100).unwrap();
+    let wallet = get_wallet_with_session_keys(main_key, Some(session), 150);
+
+    let asset: Asset = FungibleAsset::new(faucet_id, 10).unwrap().into();
+    let tx_script = send_asset_script(asset).with_session_key_auth().build();
+    assert_failed_with(execute_tx_script(&wallet, authenticator, tx_script), 131157);
+}
+
+#[test]
+fn send_asset_with_main_key_ignores_session_spending_cap() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (main_key, session_key, authenticator) = get_session_keys_and_authenticator();
+    let session = SessionKey::new(session_key, 100, 100).unwrap();
+    let wallet = get_wallet_with_session_keys(main_key, Some(session), 150);
+
+    let asset: Asset = FungibleAsset::new(faucet_id, 150).unwrap().into();
+    let tx_script = send_asset_script(asset)
+        .with_auth(&AuthScheme::RpoFalcon512Session { pub_key: PublicKey::new(main_key) })
+        .build();
+    let executed_transaction = execute_tx_script(&wallet, authenticator, tx_script).unwrap();
+
+    // the session info is restored by the authentication procedure, so the spending cap of the
+    // session is unchanged
+    let storage_delta = executed_transaction.account_delta().storage();
+    assert_eq!(
+        storage_delta.updated_items,
+        vec![(WALLET_SESSION_INFO_SLOT, [Felt::new(100), Felt::new(100), ZERO, ZERO])]
+    );
+    assert!(storage_delta.cleared_items.is_empty());
+}
+
+#[test]
+fn set_spending_limit_with_main_key_of_session_wallet() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (main_key, session_key, authenticator) = get_session_keys_and_authenticator();
+    let session = SessionKey::new(session_key, 100, 100).unwrap();
+    let wallet = get_wallet_with_session_keys(main_key, Some(session), 150);
+
+    let limit = SpendingLimit::new(200, 20).unwrap();
+    let tx_script = build_set_spending_limit_script(
+        faucet_id,
+        Some(limit),
+        &AuthScheme::RpoFalcon512Session { pub_key: PublicKey::new(main_key) },
+    );
+    let executed_transaction = execute_tx_script(&wallet, authenticator, tx_script).unwrap();
+
+    let storage_delta = executed_transaction.account_delta().storage();
+    assert_eq!(
+        storage_delta.updated_maps[0].1.updated_leaves,
+        vec![(
+            [ZERO, ZERO, ZERO, faucet_id.into()],
+            [Felt::new(200), Felt::new(20), ZERO, ZERO]
+        )]
+    );
+}
+
+#[test]
+fn set_spending_limit_with_session_key_fails() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (main_key, session_key, authenticator) = get_session_keys_and_authenticator();
+    let session = SessionKey::new(session_key, 100, 100).unwrap();
+    let wallet = get_wallet_with_session_keys(main_key, Some(session), 150);
+
+    // a session key cannot raise the spending limits of the wallet
+    let tx_script = ProgramAst::parse(
+        format!(
+            "
+        use.miden::contracts::auth::session
+
+        begin
+            push.10.1000.{faucet_id}
+            call.session::set_spending_limit
+            drop drop drop
+
+            push.1
+            call.session::auth_tx_rpo_falcon512_session
+            drop
+        end
+        ",
+            faucet_id = u64::from(faucet_id),
+        )
+        .as_str(),
+    )
+    .unwrap();
+    let result = execute_tx_script(&wallet, authenticator, tx_script);
+    assert_failed_with(result, 131158);
+}
+
+#[test]
+fn wallet_with_session_keys_creation() {
+    let (main_key, session_key, _) = get_session_keys_and_authenticator();
+    let auth_scheme = AuthScheme::RpoFalcon512Session { pub_key: PublicKey::new(main_key) };
+    let (wallet, _) = create_basic_wallet(
+        [1; 32],
+        auth_scheme,
+        AccountType::RegularAccountImmutableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+
+    assert_eq!(wallet.storage().get_item(0).as_elements(), main_key);
+    assert_eq!(get_session_key(&wallet), None);
+    assert_eq!(wallet.code().procedures().len(), 8);
+    assert_eq!(wallet.code().auth_procedures().len(), 4);
+
+    // the spending cap must be a valid amount of a fungible asset
+    assert!(matches!(
+        SessionKey::new(session_key, 100, FungibleAsset::MAX_AMOUNT + 1),
+        Err(AccountError::WalletInvalidSessionKey(_))
+    ));
+
+    // faucets do not support session keys
+    assert!(matches!(
+        create_basic_fungible_faucet(
+            [1; 32],
+            TokenSymbol::new("POL").unwrap(),
+            2,
+            Felt::new(1000),
+            AccountStorageType::OffChain,
+            AuthScheme::RpoFalcon512Session { pub_key: PublicKey::new(main_key) },
+        ),
+        Err(AccountError::UnsupportedAuthScheme(_))
+    ));
+}
+
+// TESTS PROCEDURE AUTHENTICATION
+// ================================================================================================
+
+#[test]
+fn set_spending_limit_from_note_fails() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let wallet = get_wallet_with_spending_limits(pub_key, 150, &[(faucet_id, [100, 10, 0, 0])]);
+    assert!(wallet.code().requires_auth(get_set_spending_limit_root()));
+
+    // a note which tries to raise the spending limit of the wallet
+    let note_script_ast = ProgramAst::parse(
+        format!(
+            "
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.200 push.{faucet_id}
+            call.wallet::set_spending_limit
+        end
+        "
+        )
+        .as_str(),
+    )
+    .unwrap();
+    let note = get_note_with_fungible_asset_and_script(
+        FungibleAsset::new(faucet_id, 10).unwrap(),
+        note_script_ast,
+    );
+
+    let result = execute_notes(&wallet, falcon_auth, vec![note]);
+    let Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+        ExecutionError::FailedAssertion {
+            err_code: 131154, err_msg: Some(err_msg), ..
+        },
+    )) = result
+    else {
+        panic!("transaction should fail with ERR_ACCOUNT_PROC_REQUIRES_AUTH");
+    };
+
+    // the error message points to the failed assertion in the account kernel module
+    assert!(err_msg.contains("(at asm/miden/kernels/tx/account.masm:"), "{err_msg}");
+}
+
+#[test]
+fn receive_asset_from_note_with_auth_procedures() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let wallet = get_wallet_with_spending_limits(pub_key, 150, &[(faucet_id, [100, 10, 0, 0])]);
+
+    // procedures which do not require authentication can still be invoked from notes
+    let note_script_ast = ProgramAst::parse(
+        "
+        use.miden::note
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            dropw
+            exec.note::get_assets drop
+            mem_loadw
+            call.wallet::receive_asset
+            dropw
+        end
+        ",
+    )
+    .unwrap();
+    let asset = FungibleAsset::new(faucet_id, 10).unwrap();
+    let note = get_note_with_fungible_asset_and_script(asset, note_script_ast);
+
+    let executed_transaction = execute_notes(&wallet, falcon_auth, vec![note]).unwrap();
+    assert_eq!(executed_transaction.account_delta().vault().added_assets, vec![asset.into()]);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn send_asset(
+    wallet: &Account,
+    falcon_auth: Rc<BasicAuthenticator<DeterministicRng>>,
+    asset: Asset,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let data_store = MockDataStore::with_existing(Some(wallet.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(falcon_auth));
+    executor.load_account(wallet.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let tag = NoteTag::for_local_use_case(0, 0).unwrap();
+    let note_type = NoteType::OffChain;
+
+    let tx_script_code = ProgramAst::parse(
+        format!(
+            "
+        use.miden::contracts::auth::basic->auth_tx
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.{recipient}
+            push.{note_type}
+            push.{tag}
+            push.{asset}
+            call.wallet::send_asset
+            drop drop dropw dropw
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+            recipient = prepare_word(&recipient),
+            note_type = note_type as u8,
+            asset = prepare_word(&asset.into())
+        )
+        .as_str(),
+    )
+    .unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+
+    executor.execute_transaction(
+        wallet.id(),
+        block_ref,
+        &note_ids,
+        TransactionArgs::with_tx_script(tx_script),
+    )
+}
+
+/// Returns a wallet storing the provided spending limit info, given as
+/// `[limit, period, spent, period_start]`, for each of the specified faucets.
+fn get_wallet_with_spending_limits(
+    public_key: Word,
+    balance: u64,
+    limits: &[(AccountId, [u64; 4])],
+) -> Account {
+    let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let account_code_ast = ModuleAst::parse(DEFAULT_ACCOUNT_CODE).unwrap();
+    let account_code = AccountCode::new(account_code_ast, &TransactionKernel::assembler())
+        .unwrap()
+        .with_auth_procedures(&[get_set_spending_limit_root()])
+        .unwrap();
+
+    let limits_map = StorageMap::with_entries(limits.iter().map(|&(faucet_id, info)| {
+        (Digest::from([ZERO, ZERO, ZERO, faucet_id.into()]), info.map(Felt::new))
+    }))
+    .unwrap();
+    let account_storage = AccountStorage::new(
+        vec![
+            SlotItem {
+                index: 0,
+                slot: StorageSlot::new_value(public_key),
+            },
+            SlotItem {
+                index: WALLET_SPENDING_LIMITS_SLOT,
+                slot: StorageSlot::new_map(limits_map.root().into()),
+            },
+        ],
+        vec![limits_map],
+    )
+    .unwrap();
+
+    // the wallet holds the specified balance of a single fungible faucet
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let account_vault =
+        AssetVault::new(&[FungibleAsset::new(faucet_id, balance).unwrap().into()]).unwrap();
+
+    Account::new(account_id, account_vault, account_storage, account_code, Felt::new(1))
+}
+
+fn execute_notes(
+    wallet: &Account,
+    falcon_auth: Rc<BasicAuthenticator<DeterministicRng>>,
+    notes: Vec<Note>,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let data_store = MockDataStore::with_existing(Some(wallet.clone()), Some(notes));
+
+    let mut executor = TransactionExecutor::new(data_store.clone(), Some(falcon_auth));
+    executor.load_account(wallet.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+
+    executor.execute_transaction(
+        wallet.id(),
+        block_ref,
+        &note_ids,
+        TransactionArgs::with_tx_script(tx_script),
+    )
+}
+
+fn send_asset_script(asset: Asset) -> TransactionScriptBuilder {
+    let recipient = Digest::from([ZERO, ONE, Felt::new(2), Felt::new(3)]);
+    let tag = NoteTag::for_local_use_case(0, 0).unwrap();
+    TransactionScriptBuilder::new().send_asset(asset, recipient, tag, NoteType::OffChain)
+}
+
+/// Returns the main key and the session key of a wallet, and an authenticator holding both keys.
+fn get_session_keys_and_authenticator(
+) -> (Word, PublicKey, Rc<BasicAuthenticator<DeterministicRng>>) {
+    let main_sec_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([0_u8; 32]));
+    let session_sec_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([1_u8; 32]));
+    let main_key: Word = main_sec_key.public_key().into();
+    let session_key = session_sec_key.public_key();
+
+    let authenticator = BasicAuthenticator::new_with_rng(
+        &[
+            (main_key, AuthSecretKey::RpoFalcon512(main_sec_key)),
+            (session_key.into(), AuthSecretKey::RpoFalcon512(session_sec_key)),
+        ],
+        DeterministicRng::default(),
+    );
+
+    (main_key, session_key, Rc::new(authenticator))
+}
+
+fn get_wallet_with_session_keys(
+    main_key: Word,
+    session: Option<SessionKey>,
+    balance: u64,
+) -> Account {
+    let (wallet, _) = create_basic_wallet(
+        [1; 32],
+        AuthScheme::RpoFalcon512Session { pub_key: PublicKey::new(main_key) },
+        AccountType::RegularAccountImmutableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+
+    let mut slots = vec![SlotItem {
+        index: 0,
+        slot: StorageSlot::new_value(main_key),
+    }];
+    if let Some(session) = session {
+        slots.push(SlotItem {
+            index: WALLET_SESSION_KEY_SLOT,
+            slot: StorageSlot::new_value(session.pub_key().into()),
+        });
+        slots.push(SlotItem {
+            index: WALLET_SESSION_INFO_SLOT,
+            slot: StorageSlot::new_value([
+                Felt::from(session.expiry_block()),
+                Felt::new(session.spending_cap()),
+                ZERO,
+                ZERO,
+            ]),
+        });
+    }
+
+    // the wallet stores an empty map of spending limits, so that limits can be set
+    let limits_map = StorageMap::new();
+    slots.push(SlotItem {
+        index: WALLET_SPENDING_LIMITS_SLOT,
+        slot: StorageSlot::new_map(limits_map.root().into()),
+    });
+    let account_storage = AccountStorage::new(slots, vec![limits_map]).unwrap();
+
+    // the wallet holds the specified balance of a single fungible faucet
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let account_vault =
+        AssetVault::new(&[FungibleAsset::new(faucet_id, balance).unwrap().into()]).unwrap();
+
+    Account::new(wallet.id(), account_vault, account_storage, wallet.code().clone(), ONE)
+}
+
+fn get_wallet_with_asset_filter(
+    public_key: Word,
+    mode: AssetFilterMode,
+    faucet_ids: &[AccountId],
+) -> Account {
+    let (wallet, _) = create_basic_wallet_with_asset_filter(
+        [1; 32],
+        AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(public_key) },
+        AccountType::RegularAccountImmutableCode,
+        AccountStorageType::OffChain,
+        mode,
+        faucet_ids,
+    )
+    .unwrap();
+
+    Account::new(
+        wallet.id(),
+        AssetVault::default(),
+        wallet.storage().clone(),
+        wallet.code().clone(),
+        ONE,
+    )
+}
+
+/// Returns a note holding the provided asset whose script adds the asset to the consuming wallet.
+fn get_receive_asset_note(asset: Asset) -> Note {
+    let note_script_ast = ProgramAst::parse(
+        "
+        use.miden::note
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            dropw
+            exec.note::get_assets drop
+            mem_loadw
+            call.wallet::receive_asset
+            dropw
+        end
+        ",
+    )
+    .unwrap();
+    let (note_script, _) =
+        NoteScript::new(note_script_ast, &TransactionKernel::assembler()).unwrap();
+
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let vault = NoteAssets::new(vec![asset]).unwrap();
+    let metadata = NoteMetadata::new(sender_id, NoteType::Public, 1.into(), ZERO).unwrap();
+    let recipient = NoteRecipient::new(serial_num, note_script, NoteInputs::new(vec![]).unwrap());
+
+    Note::new(vault, metadata, recipient)
+}
+
+fn get_set_asset_filter_entry_root() -> Digest {
+    let module = ModuleAst::parse(
+        "
+        use.miden::contracts::wallets::basic->basic_wallet
+        export.basic_wallet::set_asset_filter_entry
+        ",
+    )
+    .unwrap();
+    AccountCode::new(module, &TransactionKernel::assembler()).unwrap().procedures()[0]
+}
+
+fn get_set_spending_limit_root() -> Digest {
+    let module = ModuleAst::parse(
+        "
+        use.miden::contracts::wallets::basic->basic_wallet
+        export.basic_wallet::set_spending_limit
+        ",
+    )
+    .unwrap();
+    AccountCode::new(module, &TransactionKernel::assembler()).unwrap().procedures()[0]
+}
+
+/// An authenticator which records the requested signatures before signing with the inner
+/// authenticator.
+struct RecordingAuthenticator {
+    inner: Rc<BasicAuthenticator<DeterministicRng>>,
+    requests: RefCell<Vec<(Word, TransactionSummary)>>,
+}
+
+impl TransactionAuthenticator for RecordingAuthenticator {
+    fn get_signature(
+        &self,
+        pub_key: Word,
+        message: Word,
+        tx_summary: &TransactionSummary,
+    ) -> Result<Vec<Felt>, AuthenticationError> {
+        self.requests.borrow_mut().push((message, tx_summary.clone()));
+        self.inner.get_signature(pub_key, message, tx_summary)
+    }
+}
@@ -0,0 +1,205 @@
+use miden_lib::{
+    notes::{create_p2id_note, create_swap_note},
+    transaction::{memory::FAUCET_STORAGE_DATA_SLOT, TransactionKernel},
+};
+use miden_objects::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        AccountId,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    crypto::rand::RpoRandomCoin,
+    notes::{NoteTag, NoteType},
+    transaction::TransactionScript,
+    Felt, ZERO,
+};
+use miden_tx::{
+    testing::{TransactionWorkflow, WorkflowOutcome},
+    TransactionAuthenticator, TransactionExecutor,
+};
+use mock::{
+    builders::{TransactionContext, TransactionContextBuilder},
+    constants::MIN_PROOF_SECURITY_LEVEL,
+    mock::account::DEFAULT_AUTH_SCRIPT,
+    utils::prepare_word,
+};
+
+use crate::{
+    get_account_with_default_account_code, get_new_pk_and_authenticator,
+    scripts::faucet::get_faucet_account_with_max_supply_and_total_issuance, MockDataStore,
+};
+
+// WORKFLOW TESTS
+// ================================================================================================
+
+#[test]
+fn prove_wallet_workflow() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, authenticator) = get_new_pk_and_authenticator();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let note = create_p2id_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    let tx_context = TransactionContextBuilder::new(target_account)
+        .block_num(4)
+        .input_notes(vec![note])
+        .tx_script(compile_tx_script(DEFAULT_AUTH_SCRIPT))
+        .authenticator(authenticator)
+        .build()
+        .unwrap();
+
+    let outcome = run_workflow(tx_context);
+
+    assert_eq!(outcome.final_account.nonce(), Felt::new(2));
+    assert_eq!(outcome.final_account.vault().get_balance(faucet_id), Ok(100));
+    assert_eq!(outcome.proven_transaction.input_notes().num_notes(), 1);
+    assert_eq!(outcome.proven_transaction.output_notes().num_notes(), 0);
+}
+
+#[test]
+fn prove_faucet_workflow() {
+    let (faucet_pub_key, authenticator) = get_new_pk_and_authenticator();
+    let faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, Some(50));
+    let faucet_id = faucet_account.id();
+
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let tag = NoteTag::for_local_use_case(0, 0).unwrap();
+    let tx_script = format!(
+        "
+        use.miden::contracts::faucets::basic_fungible->faucet
+        use.miden::contracts::auth::basic->auth_tx
+
+        begin
+            push.{recipient}
+            push.{note_type}
+            push.{tag}
+            push.{amount}
+            call.faucet::distribute
+
+            call.auth_tx::auth_tx_rpo_falcon512
+            dropw dropw
+        end
+        ",
+        recipient = prepare_word(&recipient),
+        note_type = NoteType::OffChain as u8,
+        tag = u32::from(tag),
+        amount = 100,
+    );
+
+    let tx_context = TransactionContextBuilder::new(faucet_account)
+        .tx_script(compile_tx_script(&tx_script))
+        .authenticator(authenticator)
+        .build()
+        .unwrap();
+
+    let outcome = run_workflow(tx_context);
+
+    // the total issuance of the faucet was increased by the minted amount
+    let issuance = outcome.final_account.storage().get_item(FAUCET_STORAGE_DATA_SLOT);
+    assert_eq!(issuance[3], Felt::new(150));
+    assert_eq!(outcome.final_account.nonce(), Felt::new(2));
+
+    let created_note = outcome.executed_transaction.output_notes().get_note(0);
+    let minted_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    assert_eq!(created_note.assets().unwrap().iter().collect::<Vec<_>>(), vec![&minted_asset]);
+    assert_eq!(outcome.proven_transaction.output_notes().num_notes(), 1);
+}
+
+#[test]
+fn prove_swap_workflow() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let offered_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let requested_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(faucet_id_2, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, authenticator) = get_new_pk_and_authenticator();
+    let target_account = get_account_with_default_account_code(
+        target_account_id,
+        target_pub_key,
+        Some(requested_asset),
+    );
+
+    let (note, payback_note) = create_swap_note(
+        sender_account_id,
+        offered_asset,
+        requested_asset,
+        NoteType::Public,
+        ZERO,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    let tx_context = TransactionContextBuilder::new(target_account)
+        .block_num(4)
+        .input_notes(vec![note])
+        .tx_script(compile_tx_script(DEFAULT_AUTH_SCRIPT))
+        .authenticator(authenticator)
+        .build()
+        .unwrap();
+
+    let outcome = run_workflow(tx_context);
+
+    // the offered asset was received and the requested asset was sent back to the sender
+    let vault = outcome.final_account.vault();
+    assert_eq!(vault.get_balance(faucet_id), Ok(100));
+    assert!(!vault.has_non_fungible_asset(requested_asset).unwrap());
+
+    let created_note = outcome.executed_transaction.output_notes().get_note(0);
+    assert_eq!(created_note.recipient_digest(), Some(payback_note.recipient().digest()));
+    assert_eq!(outcome.proven_transaction.output_notes().num_notes(), 1);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn compile_tx_script(source: &str) -> TransactionScript {
+    let (tx_script, _) = TransactionScript::new(
+        ProgramAst::parse(source).unwrap(),
+        vec![],
+        &TransactionKernel::assembler(),
+    )
+    .unwrap();
+    tx_script
+}
+
+fn run_workflow<A: TransactionAuthenticator>(tx_context: TransactionContext<A>) -> WorkflowOutcome {
+    let account_id = tx_context.tx_inputs().account().id();
+    let block_num = tx_context.block_header().block_num();
+    let note_ids = tx_context.input_note_ids();
+    let tx_args = tx_context.tx_args().clone();
+    let authenticator = tx_context.authenticator();
+
+    let mut executor = TransactionExecutor::new(MockDataStore::from(tx_context), authenticator);
+    executor.load_account(account_id).unwrap();
+
+    TransactionWorkflow::new(MIN_PROOF_SECURITY_LEVEL)
+        .execute(&executor, account_id, block_num, &note_ids, tx_args)
+}
@@ -9,6 +9,7 @@ mod error;
 mod fungible_asset;
 mod nonfungible_asset;
 mod note;
+mod transaction_context;
 
 // RE-EXPORTS
 // ================================================================================================
@@ -19,6 +20,7 @@ pub use error::AccountBuilderError;
 pub use fungible_asset::FungibleAssetBuilder;
 pub use nonfungible_asset::{NonFungibleAssetBuilder, NonFungibleAssetDetailsBuilder};
 pub use note::NoteBuilder;
+pub use transaction_context::{TransactionContext, TransactionContextBuilder};
 
 pub fn str_to_account_code(source: &str) -> Result<AccountCode, AccountError> {
     let assembler = TransactionKernel::assembler();
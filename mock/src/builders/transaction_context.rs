@@ -0,0 +1,299 @@
+use alloc::{collections::BTreeMap, rc::Rc, vec::Vec};
+
+use miden_objects::{
+    accounts::Account,
+    block::{AccountTree, BlockNoteIndex, BlockNoteTree, NoteBatch},
+    crypto::merkle::{Mmr, PartialMmr},
+    notes::{Note, NoteId},
+    transaction::{
+        ChainMmr, InputNote, InputNotes, OutputNote, TransactionArgs, TransactionInputs,
+        TransactionScript,
+    },
+    BlockHeader, Digest, TransactionInputError, Word, MAX_NOTES_PER_BATCH,
+};
+
+/// Default timestamp of the reference block.
+const DEFAULT_TIMESTAMP: u32 = 1693348223;
+
+/// Number of seconds between two consecutive blocks.
+const TIMESTAMP_STEP: u32 = 10;
+
+// TRANSACTION CONTEXT
+// ================================================================================================
+
+/// Inputs and arguments of a mock transaction, together with the chain of blocks the transaction
+/// inputs were derived from and the authenticator used to sign the transaction, if any.
+#[derive(Debug, Clone)]
+pub struct TransactionContext<A = ()> {
+    tx_inputs: TransactionInputs,
+    tx_args: TransactionArgs,
+    blocks: Vec<BlockHeader>,
+    authenticator: Option<Rc<A>>,
+}
+
+impl<A> TransactionContext<A> {
+    /// Returns the inputs of the transaction.
+    pub fn tx_inputs(&self) -> &TransactionInputs {
+        &self.tx_inputs
+    }
+
+    /// Returns the arguments of the transaction.
+    pub fn tx_args(&self) -> &TransactionArgs {
+        &self.tx_args
+    }
+
+    /// Returns the header of the block referenced by the transaction.
+    pub fn block_header(&self) -> &BlockHeader {
+        self.tx_inputs.block_header()
+    }
+
+    /// Returns the headers of all blocks in the chain, ordered by block number; the last header
+    /// is the header of the reference block.
+    pub fn blocks(&self) -> &[BlockHeader] {
+        &self.blocks
+    }
+
+    /// Returns the authenticator of the transaction, if one was set.
+    pub fn authenticator(&self) -> Option<Rc<A>> {
+        self.authenticator.clone()
+    }
+
+    /// Returns the IDs of the input notes of the transaction.
+    pub fn input_note_ids(&self) -> Vec<NoteId> {
+        self.tx_inputs.input_notes().iter().map(|note| note.id()).collect()
+    }
+
+    /// Consumes this context and returns the inputs and arguments of the transaction.
+    pub fn into_parts(self) -> (TransactionInputs, TransactionArgs) {
+        (self.tx_inputs, self.tx_args)
+    }
+}
+
+// TRANSACTION CONTEXT BUILDER
+// ================================================================================================
+
+/// Builder for a [TransactionContext].
+///
+/// The builder generates a chain of blocks ending with the reference block of the transaction:
+/// each block commits to the hash of the preceding block and to the MMR of all preceding blocks,
+/// and the chain MMR of the transaction inputs tracks the block in which the input notes were
+/// created. By default, the reference block is the genesis block, and the input notes are created
+/// in the block preceding the reference block (or in the reference block itself, if it is the
+/// genesis block).
+///
+/// An authenticator can be attached to the context so that transactions requiring signatures
+/// can be executed directly from it.
+#[derive(Debug, Clone)]
+pub struct TransactionContextBuilder<A = ()> {
+    account: Account,
+    account_seed: Option<Word>,
+    input_notes: Vec<Note>,
+    tx_args: TransactionArgs,
+    block_num: u32,
+    timestamp: u32,
+    prev_hash: Digest,
+    input_notes_block_num: Option<u32>,
+    authenticator: Option<Rc<A>>,
+}
+
+impl TransactionContextBuilder {
+    pub fn new(account: Account) -> Self {
+        Self {
+            account,
+            account_seed: None,
+            input_notes: Vec::new(),
+            tx_args: TransactionArgs::default(),
+            block_num: 0,
+            timestamp: DEFAULT_TIMESTAMP,
+            prev_hash: Digest::default(),
+            input_notes_block_num: None,
+            authenticator: None,
+        }
+    }
+}
+
+impl<A> TransactionContextBuilder<A> {
+    /// Sets the authenticator used to sign the transaction, replacing any previously set one.
+    pub fn authenticator<B>(self, authenticator: Rc<B>) -> TransactionContextBuilder<B> {
+        TransactionContextBuilder {
+            account: self.account,
+            account_seed: self.account_seed,
+            input_notes: self.input_notes,
+            tx_args: self.tx_args,
+            block_num: self.block_num,
+            timestamp: self.timestamp,
+            prev_hash: self.prev_hash,
+            input_notes_block_num: self.input_notes_block_num,
+            authenticator: Some(authenticator),
+        }
+    }
+
+    /// Sets the seed of the account; the seed must be provided for new accounts.
+    pub fn account_seed(mut self, account_seed: Word) -> Self {
+        self.account_seed = Some(account_seed);
+        self
+    }
+
+    pub fn input_notes<I: IntoIterator<Item = Note>>(mut self, notes: I) -> Self {
+        self.input_notes.extend(notes);
+        self
+    }
+
+    pub fn expected_output_notes<I: IntoIterator<Item = Note>>(mut self, notes: I) -> Self {
+        self.tx_args.extend_expected_output_notes(notes);
+        self
+    }
+
+    /// Sets the arguments of the transaction, replacing any previously set script, note
+    /// arguments or expected output notes.
+    pub fn tx_args(mut self, tx_args: TransactionArgs) -> Self {
+        self.tx_args = tx_args;
+        self
+    }
+
+    /// Sets the script of the transaction.
+    ///
+    /// # Panics
+    /// Panics if a different transaction script has already been set.
+    pub fn tx_script(mut self, tx_script: TransactionScript) -> Self {
+        self.tx_args
+            .merge(TransactionArgs::with_tx_script(tx_script))
+            .expect("conflicting transaction script");
+        self
+    }
+
+    /// Adds the provided note arguments to the arguments of the transaction.
+    ///
+    /// # Panics
+    /// Panics if different arguments have already been set for any of the notes.
+    pub fn note_args(mut self, note_args: BTreeMap<NoteId, Word>) -> Self {
+        self.tx_args.extend_note_args(note_args).expect("conflicting note arguments");
+        self
+    }
+
+    /// Sets the number of the reference block; this is also the number of ancestor blocks
+    /// generated for the chain.
+    pub fn block_num(mut self, block_num: u32) -> Self {
+        self.block_num = block_num;
+        self
+    }
+
+    /// Sets the timestamp of the reference block; ancestor blocks are spaced 10 seconds apart.
+    pub fn timestamp(mut self, timestamp: u32) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Sets the hash of the block preceding the oldest generated block; defaults to the zero
+    /// digest, in which case the oldest generated block is the genesis block.
+    pub fn prev_hash(mut self, prev_hash: Digest) -> Self {
+        self.prev_hash = prev_hash;
+        self
+    }
+
+    /// Sets the number of the block in which the input notes were created.
+    pub fn input_notes_block_num(mut self, block_num: u32) -> Self {
+        self.input_notes_block_num = Some(block_num);
+        self
+    }
+
+    /// Builds the [TransactionContext].
+    ///
+    /// # Errors
+    /// Returns an error if the resulting transaction inputs are invalid.
+    ///
+    /// # Panics
+    /// Panics if the input notes are created after the reference block or if the timestamp of the
+    /// reference block is too small to accommodate all ancestor blocks.
+    pub fn build(self) -> Result<TransactionContext<A>, TransactionInputError> {
+        let notes_block_num =
+            self.input_notes_block_num.unwrap_or(self.block_num.saturating_sub(1));
+        assert!(
+            notes_block_num <= self.block_num,
+            "input notes created after the reference block"
+        );
+
+        let note_batches = self
+            .input_notes
+            .chunks(MAX_NOTES_PER_BATCH)
+            .map(|batch| batch.iter().cloned().map(OutputNote::Full).collect())
+            .collect::<Vec<NoteBatch>>();
+        let note_tree =
+            BlockNoteTree::from_note_batches(&note_batches).expect("too many input notes");
+
+        let mut account_tree = AccountTree::new();
+        if !self.account.is_new() {
+            account_tree.insert(self.account.id(), self.account.hash());
+        }
+
+        // build the chain of blocks; the account and the input notes are committed to by all
+        // blocks and by the note block respectively
+        let mut mmr = Mmr::default();
+        let mut blocks = Vec::with_capacity(self.block_num as usize + 1);
+        for block_num in 0..=self.block_num {
+            let prev_hash = blocks.last().map_or(self.prev_hash, BlockHeader::hash);
+            let note_root = match block_num == notes_block_num {
+                true => note_tree.root(),
+                false => BlockNoteTree::default().root(),
+            };
+            let timestamp = self
+                .timestamp
+                .checked_sub((self.block_num - block_num) * TIMESTAMP_STEP)
+                .expect("timestamp of the reference block is too small");
+
+            let header = BlockHeader::new(
+                0,
+                prev_hash,
+                block_num,
+                mmr.peaks(mmr.forest()).expect("forest is valid").hash_peaks(),
+                account_tree.root(),
+                Digest::default(),
+                note_root,
+                Digest::default(),
+                Digest::default(),
+                timestamp,
+            );
+            mmr.add(header.hash());
+            blocks.push(header);
+        }
+
+        // build the chain MMR as of the reference block, tracking the note block if needed
+        let forest = self.block_num as usize;
+        let mut partial_mmr = PartialMmr::from_peaks(mmr.peaks(forest).expect("forest is valid"));
+        let mut tracked_blocks = Vec::new();
+        if !self.input_notes.is_empty() && notes_block_num < self.block_num {
+            let pos = notes_block_num as usize;
+            let path = mmr.open(pos, forest).expect("note block is in the MMR").merkle_path;
+            partial_mmr.track(pos, blocks[pos].hash(), &path).expect("path is valid");
+            tracked_blocks.push(blocks[pos]);
+        }
+        let block_chain = ChainMmr::new(partial_mmr, tracked_blocks).expect("blocks are tracked");
+
+        let notes_block = blocks[notes_block_num as usize];
+        let mut input_notes = Vec::with_capacity(self.input_notes.len());
+        for (batch_idx, batch) in self.input_notes.chunks(MAX_NOTES_PER_BATCH).enumerate() {
+            for (note_idx_in_batch, note) in batch.iter().enumerate() {
+                let index = BlockNoteIndex::new(batch_idx, note_idx_in_batch);
+                let proof = note_tree
+                    .get_note_inclusion_proof(&notes_block, index)
+                    .expect("note is in the tree");
+                input_notes.push(InputNote::authenticated(note.clone(), proof));
+            }
+        }
+
+        let tx_inputs = TransactionInputs::new(
+            self.account,
+            self.account_seed,
+            *blocks.last().expect("chain contains the reference block"),
+            block_chain,
+            InputNotes::new(input_notes)?,
+        )?;
+
+        Ok(TransactionContext {
+            tx_inputs,
+            tx_args: self.tx_args,
+            blocks,
+            authenticator: self.authenticator,
+        })
+    }
+}
@@ -16,6 +16,7 @@ use std::{
 
 use miden_lib::transaction::{memory, ToTransactionKernelInputs, TransactionKernel};
 use miden_objects::{
+    accounts::NetworkParams,
     notes::NoteAssets,
     transaction::{OutputNotes, PreparedTransaction},
 };
@@ -49,7 +50,8 @@ fn load_file_with_code(imports: &str, code: &str, assembly_file: PathBuf) -> Str
     complete_code.replace("export", "proc")
 }
 
-/// Inject `code` along side the specified file and run it
+/// Inject `code` along side the specified file and run it on a network with
+/// [NetworkParams::testing()]
 pub fn run_tx(tx: &PreparedTransaction) -> Result<Process<MockHost>, ExecutionError> {
     run_tx_with_inputs(tx, AdviceInputs::default())
 }
@@ -57,9 +59,18 @@ pub fn run_tx(tx: &PreparedTransaction) -> Result<Process<MockHost>, ExecutionEr
 pub fn run_tx_with_inputs(
     tx: &PreparedTransaction,
     inputs: AdviceInputs,
+) -> Result<Process<MockHost>, ExecutionError> {
+    run_tx_with_network_params(tx, inputs, &NetworkParams::testing())
+}
+
+/// Runs the transaction with the kernel inputs of a network with the provided parameters
+pub fn run_tx_with_network_params(
+    tx: &PreparedTransaction,
+    inputs: AdviceInputs,
+    network_params: &NetworkParams,
 ) -> Result<Process<MockHost>, ExecutionError> {
     let program = tx.program().clone();
-    let (stack_inputs, mut advice_inputs) = tx.get_kernel_inputs();
+    let (stack_inputs, mut advice_inputs) = tx.get_kernel_inputs_with_params(network_params);
     advice_inputs.extend(inputs);
     let host = MockHost::new(tx.account().into(), advice_inputs);
     let mut process = Process::new_debug(program.kernel().clone(), stack_inputs, host);
@@ -122,9 +133,7 @@ pub fn run_within_host<H: Host>(
 
 // TEST HELPERS
 // ================================================================================================
-pub fn consumed_note_data_ptr(note_idx: u32) -> memory::MemoryAddress {
-    memory::CONSUMED_NOTE_DATA_SECTION_OFFSET + note_idx * memory::NOTE_MEM_SIZE
-}
+pub use miden_lib::transaction::kernel_memory::consumed_note_data_ptr;
 
 #[cfg(feature = "std")]
 pub fn prepare_transaction(
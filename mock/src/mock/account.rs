@@ -1,122 +1,43 @@
 use miden_lib::transaction::memory::FAUCET_STORAGE_DATA_SLOT;
+pub use miden_objects::testing::accounts::{
+    account_storage as mock_account_storage, storage_item_0, storage_item_1, storage_item_2,
+    storage_map_2, STORAGE_INDEX_0, STORAGE_INDEX_1, STORAGE_INDEX_2, STORAGE_LEAVES_2,
+    STORAGE_VALUE_0, STORAGE_VALUE_1,
+};
 use miden_objects::{
     accounts::{
         account_id::testing::{
-            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
-            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
             ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
             ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
         },
         get_account_seed_single, Account, AccountCode, AccountId, AccountStorage,
-        AccountStorageType, AccountType, SlotItem, StorageMap, StorageSlot,
+        AccountStorageType, AccountType, SlotItem, StorageSlot,
     },
     assembly::{Assembler, ModuleAst},
-    assets::{Asset, AssetVault, FungibleAsset},
-    crypto::{hash::rpo::RpoDigest, merkle::Smt},
+    assets::AssetVault,
+    crypto::merkle::Smt,
+    testing::accounts::account_vault,
     Felt, FieldElement, Word, ZERO,
 };
 
 use crate::{
-    constants::{
-        non_fungible_asset, non_fungible_asset_2, FUNGIBLE_ASSET_AMOUNT,
-        FUNGIBLE_FAUCET_INITIAL_BALANCE,
-    },
+    constants::{non_fungible_asset_2, FUNGIBLE_FAUCET_INITIAL_BALANCE},
     TransactionKernel,
 };
 
-// ACCOUNT STORAGE
-// ================================================================================================
-
-pub const STORAGE_INDEX_0: u8 = 20;
-pub const STORAGE_VALUE_0: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
-pub const STORAGE_INDEX_1: u8 = 30;
-pub const STORAGE_VALUE_1: Word = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
-
-pub const STORAGE_INDEX_2: u8 = 40;
-pub const STORAGE_LEAVES_2: [(RpoDigest, Word); 2] = [
-    (
-        RpoDigest::new([Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)]),
-        [Felt::new(1_u64), Felt::new(2_u64), Felt::new(3_u64), Felt::new(4_u64)],
-    ),
-    (
-        RpoDigest::new([Felt::new(105), Felt::new(106), Felt::new(107), Felt::new(108)]),
-        [Felt::new(5_u64), Felt::new(6_u64), Felt::new(7_u64), Felt::new(8_u64)],
-    ),
-];
-
-pub fn storage_item_0() -> SlotItem {
-    SlotItem {
-        index: STORAGE_INDEX_0,
-        slot: StorageSlot::new_value(STORAGE_VALUE_0),
-    }
-}
-
-pub fn storage_item_1() -> SlotItem {
-    SlotItem {
-        index: STORAGE_INDEX_1,
-        slot: StorageSlot::new_value(STORAGE_VALUE_1),
-    }
-}
-
-pub fn storage_map_2() -> StorageMap {
-    StorageMap::with_entries(STORAGE_LEAVES_2).unwrap()
-}
-
-pub fn storage_item_2() -> SlotItem {
-    SlotItem {
-        index: STORAGE_INDEX_2,
-        slot: StorageSlot::new_map(Word::from(storage_map_2().root())),
-    }
-}
-
-/// Creates an [AssetVault] with 4 assets.
-///
-/// The ids of the assets added to the vault are defined by the following constants:
-///
-/// - ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN
-/// - ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1
-/// - ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2
-/// - ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN
-///
-fn mock_account_vault() -> AssetVault {
-    let faucet_id: AccountId = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap();
-    let fungible_asset =
-        Asset::Fungible(FungibleAsset::new(faucet_id, FUNGIBLE_ASSET_AMOUNT).unwrap());
-
-    let faucet_id_1: AccountId = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1.try_into().unwrap();
-    let fungible_asset_1 =
-        Asset::Fungible(FungibleAsset::new(faucet_id_1, FUNGIBLE_ASSET_AMOUNT).unwrap());
-
-    let faucet_id_2: AccountId = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2.try_into().unwrap();
-    let fungible_asset_2 =
-        Asset::Fungible(FungibleAsset::new(faucet_id_2, FUNGIBLE_ASSET_AMOUNT).unwrap());
-
-    let non_fungible_asset = non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN);
-    AssetVault::new(&[fungible_asset, fungible_asset_1, fungible_asset_2, non_fungible_asset])
-        .unwrap()
-}
-
-pub fn mock_account_storage() -> AccountStorage {
-    // create account storage
-    AccountStorage::new(
-        vec![storage_item_0(), storage_item_1(), storage_item_2()],
-        vec![storage_map_2()],
-    )
-    .unwrap()
-}
-
 // The MAST root of the default account's interface. Use these constants to interact with the
 // account's procedures.
 const MASTS: [&str; 11] = [
-    "0x74de7e94e5afc71e608f590c139ac51f446fc694da83f93d968b019d1d2b7306",
-    "0xf5f4a93b873d3dc236539d5566d245ac5e5bc6be75fc5af4235a52fe091077ae",
-    "0xd765111e22479256e87a57eaf3a27479d19cc876c9a715ee6c262e0a0d47a2ac",
-    "0x17b326d5403115afccc0727efa72bd929bfdc7bbf284c7c28a7aadade5d4cc9d",
-    "0x6682a0e0f4e49820e5c547f1b60a82cb326a56c972999e36bf6d45459393ac87",
-    "0x73c14f65d2bab6f52eafc4397e104b3ab22a470f6b5cbc86d4aa4d3978c8b7d4",
-    "0x49fee714925e6b287136494465184a84495cedb35fce3ab3a13f68ad48751596",
-    "0xfe4b6f0a485393583f5b6de9edca2f133f3e7ad0c3e631eadd0d18e89bfdbfe0",
-    "0x976ff83372d5e5f4618927de2f64ebc14cd0a2c651ddded4ba0485973aa03caa",
+    "0xf61b30d34c0e42ee7ab69f1683afc58b3c9bde1ee377a04993753edbc06e0f77",
+    "0xdcb296d24cc00533c40e3cf829eef3c711a5b3441475f090296cce6a45aeee3c",
+    "0x17356ef69b78bc27f4c008e72292a6c71fdf6f4fd57354348eb4c79b89610a89",
+    "0xc575e1a748203cbc09e09469f8d919f2023cf9213d58cb255d817de3f9899185",
+    "0xe7e97de91b4a2347901d0ab01c68bd705fbcc79182a0695501a9aaa2743bf500",
+    "0xf6346e0079c1da9d9175d9ee6252af479500fd8d34565cbca9df3cfaacad5437",
+    "0xe564f2d70bac4d409622dab32ffe08e853930b5479e698199934ba3d550c5517",
+    "0x031bf269756924f1326f62eba6ec219c850370bdb866a8e667af1e295bec579f",
+    "0x2baf96d13074ab7a596615847d108ff03d9cda8dc8e27e16b4fb859cf471b1a9",
     "0xff06b90f849c4b262cbfbea67042c4ea017ea0e9c558848a951d44b23370bec5",
     "0x8ef0092134469a1330e3c468f57c7f085ce611645d09cc7516c786fefc71d794",
 ];
@@ -140,6 +61,9 @@ pub const DEFAULT_ACCOUNT_CODE: &str = "
 
     export.basic_wallet::receive_asset
     export.basic_wallet::send_asset
+    export.basic_wallet::move_asset_to_note
+    export.basic_wallet::set_spending_limit
+    export.basic_wallet::set_asset_filter_entry
     export.basic_eoa::auth_tx_rpo_falcon512
 ";
 
@@ -290,7 +214,7 @@ pub fn mock_new_account(assembler: &Assembler) -> Account {
 
 pub fn mock_account(account_id: u64, nonce: Felt, account_code: AccountCode) -> Account {
     let account_storage = mock_account_storage();
-    let account_vault = mock_account_vault();
+    let account_vault = account_vault();
     let account_id = AccountId::try_from(account_id).unwrap();
     Account::new(account_id, account_vault, account_storage, account_code, nonce)
 }
@@ -4,10 +4,11 @@ use core::fmt;
 use miden_objects::{
     accounts::{Account, AccountId, AccountStorageType, AccountType, SlotItem},
     assets::Asset,
+    block::AccountTree,
     crypto::merkle::{LeafIndex, Mmr, PartialMmr, SimpleSmt, Smt},
     notes::{Note, NoteInclusionProof},
     transaction::{ChainMmr, InputNote},
-    BlockHeader, Digest, Word, ACCOUNT_TREE_DEPTH, NOTE_TREE_DEPTH, ZERO,
+    BlockHeader, Digest, Word, NOTE_TREE_DEPTH, ZERO,
 };
 use rand::{Rng, SeedableRng};
 
@@ -104,7 +105,7 @@ impl<R: Rng> Objects<R> {
             .enumerate()
             .map(|(index, note)| {
                 let auth_index = LeafIndex::new(index as u64).expect("index bigger than 2**20");
-                InputNote::new(
+                InputNote::authenticated(
                     note.clone(),
                     NoteInclusionProof::new(
                         header.block_num(),
@@ -134,7 +135,7 @@ pub struct MockChain<R> {
     nullifiers: Smt,
 
     /// Tree containing the latest hash of each account.
-    accounts: SimpleSmt<ACCOUNT_TREE_DEPTH>,
+    accounts: AccountTree,
 
     /// RNG used to seed builders.
     ///
@@ -193,7 +194,7 @@ impl<R: Rng + SeedableRng> MockChain<R> {
             chain: Mmr::default(),
             blocks: vec![],
             nullifiers: Smt::default(),
-            accounts: SimpleSmt::<ACCOUNT_TREE_DEPTH>::new().expect("depth too big for SimpleSmt"),
+            accounts: AccountTree::new(),
             rng,
             account_id_builder,
             objects: Objects::new(),
@@ -431,10 +432,10 @@ impl<R: Rng + SeedableRng> MockChain<R> {
         let block_num: u32 = self.blocks.len().try_into().expect("usize to u32 failed");
 
         for (account, _seed) in self.pending_objects.accounts.iter() {
-            self.accounts.insert(account.id().into(), account.hash().into());
+            self.accounts.insert(account.id(), account.hash());
         }
         for (account, _seed) in self.objects.accounts.iter() {
-            self.accounts.insert(account.id().into(), account.hash().into());
+            self.accounts.insert(account.id(), account.hash());
         }
 
         // TODO:
@@ -621,7 +622,7 @@ pub fn mock_chain_data(consumed_notes: Vec<Note>) -> (ChainMmr, Vec<InputNote>)
             let block_header = &block_chain[index];
             let auth_index = LeafIndex::new(index as u64).unwrap();
 
-            InputNote::new(
+            InputNote::authenticated(
                 note,
                 NoteInclusionProof::new(
                     block_header.block_num(),
@@ -8,19 +8,32 @@ use super::{AdviceProvider, Digest, NodeIndex, ProcessState};
 // ACCOUNT PROCEDURE INDEX MAP
 // ================================================================================================
 
-/// A map of proc_root |-> proc_index for all known procedures of an account interface.
-pub struct AccountProcedureIndexMap(BTreeMap<Digest, u8>);
+/// A map of proc_root |-> (proc_index, requires_auth) for all known procedures of an account
+/// interface.
+pub struct AccountProcedureIndexMap(BTreeMap<Digest, (u8, bool)>);
 
 impl AccountProcedureIndexMap {
     /// Returns a new [AccountProcedureIndexMap] instantiated with account procedures present in
     /// the provided advice provider.
     ///
     /// This function assumes that the account procedure tree (or a part thereof) is loaded into the
-    /// Merkle store of the provided advice provider.
+    /// Merkle store of the provided advice provider, and that the procedures requiring
+    /// authentication are loaded into its advice map under the account code root.
     pub fn new<A: AdviceProvider>(account_code_root: Digest, adv_provider: &A) -> Self {
         // get the Merkle store with the procedure tree from the advice provider
         let proc_store = adv_provider.get_store_subset([account_code_root].iter());
 
+        // get the leaves of the procedures requiring authentication from the advice provider
+        let auth_procs: BTreeMap<Digest, Digest> = adv_provider
+            .get_mapped_values(&account_code_root)
+            .unwrap_or(&[])
+            .chunks_exact(4)
+            .map(|root| {
+                let root = Digest::new([root[0], root[1], root[2], root[3]]);
+                (AccountCode::auth_procedure_leaf(root), root)
+            })
+            .collect();
+
         // iterate over all possible procedure indexes
         let mut result = BTreeMap::new();
         for i in 0..AccountCode::MAX_NUM_PROCEDURES {
@@ -28,19 +41,23 @@ impl AccountProcedureIndexMap {
                 .expect("procedure tree index is valid");
             // if the node at the current index does not exist, skip it and try the next node;this
             // situation is valid if not all account procedures are loaded into the advice provider
-            if let Ok(proc_root) = proc_store.get_node(account_code_root, index) {
+            if let Ok(leaf) = proc_store.get_node(account_code_root, index) {
                 // if we got an empty digest, this means we got to the end of the procedure list
-                if proc_root == Digest::default() {
+                if leaf == Digest::default() {
                     break;
                 }
-                result.insert(proc_root, i as u8);
+                match auth_procs.get(&leaf) {
+                    Some(proc_root) => result.insert(*proc_root, (i as u8, true)),
+                    None => result.insert(leaf, (i as u8, false)),
+                };
             }
         }
         Self(result)
     }
 
     /// Returns index of the procedure whose root is currently at the top of the operand stack in
-    /// the provided process.
+    /// the provided process, together with a flag indicating whether the procedure requires
+    /// authentication.
     ///
     /// # Errors
     /// Returns an error if the procedure at the top of the operand stack is not present in this
@@ -48,12 +65,12 @@ impl AccountProcedureIndexMap {
     pub fn get_proc_index<S: ProcessState>(
         &self,
         process: &S,
-    ) -> Result<u8, TransactionKernelError> {
+    ) -> Result<(u8, bool), TransactionKernelError> {
         let proc_root = process.get_stack_word(0).into();
         // mock account method for testing from root context
         // TODO: figure out if we can get rid of this
         if proc_root == Digest::default() {
-            return Ok(255);
+            return Ok((255, false));
         }
         self.0
             .get(&proc_root)
@@ -7,7 +7,7 @@ use miden_objects::{
 };
 use vm_processor::{
     crypto::NodeIndex, AdviceExtractor, AdviceInjector, AdviceInputs, AdviceProvider, AdviceSource,
-    ContextId, ExecutionError, Host, HostResponse, MemAdviceProvider, ProcessState,
+    ContextId, ExecutionError, Felt, Host, HostResponse, MemAdviceProvider, ProcessState,
 };
 
 mod account_procs;
@@ -48,10 +48,11 @@ impl MockHost {
         &mut self,
         process: &S,
     ) -> Result<(), ExecutionError> {
-        let proc_idx = self
+        let (proc_idx, requires_auth) = self
             .acct_procedure_index_map
             .get_proc_index(process)
             .map_err(|err| ExecutionError::EventError(err.to_string()))?;
+        self.adv_provider.push_stack(AdviceSource::Value(Felt::from(requires_auth)))?;
         self.adv_provider.push_stack(AdviceSource::Value(proc_idx.into()))?;
         Ok(())
     }
@@ -0,0 +1,34 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use miden_objects::{
+    accounts::{delta::AccountUpdateDetails, testing::build_account},
+    utils::{
+        compression::{read_compressed, write_compressed, MAX_PAYLOAD_SIZE},
+        serde::{Serializable, SliceReader},
+    },
+    Felt, ONE, ZERO,
+};
+
+fn compress_account_update(c: &mut Criterion) {
+    let storage_items = (0..250).map(|i| [Felt::new(i), ONE, ZERO, ZERO]).collect();
+    let account = build_account(vec![], ONE, storage_items, None);
+    let payload = AccountUpdateDetails::New(account).to_bytes();
+
+    let mut compressed = Vec::new();
+    write_compressed(&mut compressed, &payload);
+
+    c.bench_function("Compress new account update details", |bench| {
+        bench.iter_batched(
+            Vec::new,
+            |mut target| write_compressed(&mut target, &payload),
+            BatchSize::SmallInput,
+        )
+    });
+
+    c.bench_function("Decompress new account update details", |bench| {
+        bench
+            .iter(|| read_compressed(&mut SliceReader::new(&compressed), MAX_PAYLOAD_SIZE).unwrap())
+    });
+}
+
+criterion_group!(compression, compress_account_update);
+criterion_main!(compression);
@@ -0,0 +1,81 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use miden_objects::{
+    accounts::{
+        account_id::testing::ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, delta::AccountUpdateDetails,
+        testing::build_account, AccountId, AccountStorage, AccountStorageRef, StorageMap,
+    },
+    assets::{Asset, NonFungibleAsset, NonFungibleAssetDetails},
+    notes::{NoteAssets, NoteAssetsRef},
+    transaction::TxAccountUpdate,
+    utils::{
+        compression::{read_compressed, write_compressed},
+        serde::{Deserializable, Serializable, SliceReader},
+    },
+    Digest, Felt, MAX_ACCOUNT_UPDATE_SIZE, ONE, ZERO,
+};
+
+// Proven transactions are ingested by nodes in parts: the account update and the output notes
+// make up most of the size of a proven transaction, and are benchmarked separately here since the
+// dummy STARK proof available in tests can not be deserialized as a part of a proven transaction.
+
+fn ingest_account_update(c: &mut Criterion) {
+    // the storage map is placed into slot 100
+    let storage_items = (0..100).map(|i| [Felt::new(i), ONE, ZERO, ZERO]).collect();
+    let mut storage_map = StorageMap::new();
+    for i in 0..1000 {
+        storage_map.insert([Felt::new(i), ZERO, ZERO, ZERO].into(), [ONE, ONE, ZERO, ZERO]);
+    }
+    let account = build_account(vec![], ONE, storage_items, Some(storage_map));
+
+    let account_update = TxAccountUpdate::new(
+        account.id(),
+        Digest::default(),
+        account.hash(),
+        AccountUpdateDetails::New(account.clone()),
+    );
+    let mut compressed = Vec::new();
+    write_compressed(&mut compressed, &account_update.to_bytes());
+
+    c.bench_function("Ingest new account update", |bench| {
+        bench.iter(|| {
+            let payload =
+                read_compressed(&mut SliceReader::new(&compressed), MAX_ACCOUNT_UPDATE_SIZE)
+                    .unwrap();
+            TxAccountUpdate::read_from_bytes(&payload).unwrap()
+        })
+    });
+
+    let storage_bytes = account.storage().to_bytes();
+    let mut group = c.benchmark_group("Account storage commitment");
+    group.bench_function("owned", |bench| {
+        bench.iter(|| AccountStorage::read_from_bytes(&storage_bytes).unwrap().root())
+    });
+    group.bench_function("borrowed", |bench| {
+        bench.iter(|| AccountStorageRef::read_from_bytes(&storage_bytes).unwrap().root())
+    });
+    group.finish();
+}
+
+fn ingest_note_assets(c: &mut Criterion) {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let assets = (0..NoteAssets::MAX_NUM_ASSETS as u32)
+        .map(|i| {
+            let details =
+                NonFungibleAssetDetails::new(faucet_id, i.to_le_bytes().to_vec()).unwrap();
+            Asset::NonFungible(NonFungibleAsset::new(&details).unwrap())
+        })
+        .collect();
+    let assets_bytes = NoteAssets::new(assets).unwrap().to_bytes();
+
+    let mut group = c.benchmark_group("Note assets commitment");
+    group.bench_function("owned", |bench| {
+        bench.iter(|| NoteAssets::read_from_bytes(&assets_bytes).unwrap().commitment())
+    });
+    group.bench_function("borrowed", |bench| {
+        bench.iter(|| NoteAssetsRef::read_from_bytes(&assets_bytes).unwrap().commitment())
+    });
+    group.finish();
+}
+
+criterion_group!(ingestion, ingest_account_update, ingest_note_assets);
+criterion_main!(ingestion);
@@ -2,18 +2,22 @@ use alloc::{
     string::{String, ToString},
     vec::Vec,
 };
-use core::fmt;
+use core::{fmt, str::FromStr};
 
 use super::{
-    get_account_seed, AccountError, ByteReader, Deserializable, DeserializationError, Digest, Felt,
-    Hasher, Serializable, Word, ZERO,
+    get_account_seed, get_account_seed_with_params, get_anchored_account_seed, grind_account_seed,
+    AccountError, ByteReader, Deserializable, DeserializationError, Digest, Felt, GrindOptions,
+    Hasher, NetworkParams, Serializable, Word, ZERO,
+};
+use crate::{
+    block::BlockHeader, crypto::merkle::LeafIndex, utils::hex_to_bytes, ACCOUNT_TREE_DEPTH,
 };
-use crate::{crypto::merkle::LeafIndex, utils::hex_to_bytes, ACCOUNT_TREE_DEPTH};
 
 // CONSTANTS
 // ================================================================================================
 
-// The higher two bits of the most significant nibble determines the account type
+// The higher two bits of the most significant nibble determines the account storage type, or
+// whether the account ID is anchored
 pub const ACCOUNT_STORAGE_MASK_SHIFT: u64 = 62;
 pub const ACCOUNT_STORAGE_MASK: u64 = 0b11 << ACCOUNT_STORAGE_MASK_SHIFT;
 
@@ -22,6 +26,17 @@ pub const ACCOUNT_TYPE_MASK_SHIFT: u64 = 60;
 pub const ACCOUNT_TYPE_MASK: u64 = 0b11 << ACCOUNT_TYPE_MASK_SHIFT;
 pub const ACCOUNT_ISFAUCET_MASK: u64 = 0b10 << ACCOUNT_TYPE_MASK_SHIFT;
 
+// For anchored account IDs, the bit following the most significant nibble determines the account
+// storage type
+pub const ACCOUNT_ANCHORED_STORAGE_MASK_SHIFT: u64 = 59;
+pub const ACCOUNT_ANCHORED_STORAGE_MASK: u64 = 0b1 << ACCOUNT_ANCHORED_STORAGE_MASK_SHIFT;
+
+// For anchored account IDs, the next 16 bits encode the epoch of the anchor block, and the
+// remaining 43 bits are taken from the seed digest
+pub const ACCOUNT_EPOCH_MASK_SHIFT: u64 = 43;
+pub const ACCOUNT_EPOCH_MASK: u64 = 0xffff << ACCOUNT_EPOCH_MASK_SHIFT;
+const ACCOUNT_ANCHORED_DIGEST_MASK: u64 = (1 << ACCOUNT_EPOCH_MASK_SHIFT) - 1;
+
 // ACCOUNT TYPES
 // ================================================================================================
 
@@ -61,12 +76,51 @@ impl From<u64> for AccountType {
     }
 }
 
+impl AccountType {
+    /// Returns the label of this account type used in labeled account IDs (see
+    /// [ParsedAccountId]).
+    pub const fn label(&self) -> &'static str {
+        match self {
+            AccountType::FungibleFaucet => "fungible-faucet",
+            AccountType::NonFungibleFaucet => "non-fungible-faucet",
+            AccountType::RegularAccountImmutableCode => "regular-immutable-code",
+            AccountType::RegularAccountUpdatableCode => "regular-updatable-code",
+        }
+    }
+}
+
+impl fmt::Display for AccountType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl FromStr for AccountType {
+    type Err = AccountError;
+
+    /// Parses an account type from its label.
+    fn from_str(label: &str) -> Result<Self, Self::Err> {
+        [
+            AccountType::FungibleFaucet,
+            AccountType::NonFungibleFaucet,
+            AccountType::RegularAccountImmutableCode,
+            AccountType::RegularAccountUpdatableCode,
+        ]
+        .into_iter()
+        .find(|account_type| account_type.label() == label)
+        .ok_or_else(|| AccountError::AccountIdInvalidLabel(label.to_string()))
+    }
+}
+
 // ACCOUNT STORAGE TYPES
 // ================================================================================================
 
 pub const ON_CHAIN: u64 = 0b00;
 pub const OFF_CHAIN: u64 = 0b10;
 
+// The value of the storage bits of anchored account IDs, whose storage type is encoded separately
+pub const ANCHORED: u64 = 0b01;
+
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u64)]
@@ -75,6 +129,108 @@ pub enum AccountStorageType {
     OffChain = OFF_CHAIN,
 }
 
+impl AccountStorageType {
+    /// Returns the label of this storage type used in labeled account IDs (see
+    /// [ParsedAccountId]).
+    pub const fn label(&self) -> &'static str {
+        match self {
+            AccountStorageType::OnChain => "onchain",
+            AccountStorageType::OffChain => "offchain",
+        }
+    }
+}
+
+impl fmt::Display for AccountStorageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl FromStr for AccountStorageType {
+    type Err = AccountError;
+
+    /// Parses a storage type from its label.
+    fn from_str(label: &str) -> Result<Self, Self::Err> {
+        match label {
+            "onchain" => Ok(AccountStorageType::OnChain),
+            "offchain" => Ok(AccountStorageType::OffChain),
+            _ => Err(AccountError::AccountIdInvalidLabel(label.to_string())),
+        }
+    }
+}
+
+// ACCOUNT ID VERSIONS
+// ================================================================================================
+
+/// Version of an account ID, which determines how the ID was derived from its seed.
+///
+/// Anchored IDs are marked by the [ANCHORED] value of the storage bits of the ID, which is not a
+/// valid storage type. Thus, all IDs derived before anchored IDs were introduced remain valid.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AccountIdVersion {
+    /// The ID is derived from a seed whose digest satisfies a proof-of-work requirement (see
+    /// [AccountId::new()]).
+    ProofOfWork = 0,
+    /// The ID is derived from a seed and an anchor block, and the seed digest additionally
+    /// satisfies a proof-of-work requirement (see [AccountId::new_anchored()]).
+    Anchored = 1,
+}
+
+// ACCOUNT ID ANCHOR
+// ================================================================================================
+
+/// The block to which an anchored account ID commits.
+///
+/// An anchor block must be the first block of an epoch. Since the hash of the anchor block is
+/// unknown before the block is produced, anchored account IDs cannot be precomputed before that.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountIdAnchor {
+    epoch: u16,
+    block_hash: Digest,
+}
+
+impl AccountIdAnchor {
+    /// Returns a new [AccountIdAnchor] for the first block of the specified epoch, whose hash is
+    /// `block_hash`.
+    pub fn new(epoch: u16, block_hash: Digest) -> Self {
+        Self { epoch, block_hash }
+    }
+
+    /// Returns the epoch of the anchor block.
+    pub fn epoch(&self) -> u16 {
+        self.epoch
+    }
+
+    /// Returns the number of the anchor block.
+    pub fn block_num(&self) -> u32 {
+        BlockHeader::epoch_block_num(self.epoch)
+    }
+
+    /// Returns the hash of the anchor block.
+    pub fn block_hash(&self) -> Digest {
+        self.block_hash
+    }
+}
+
+impl TryFrom<&BlockHeader> for AccountIdAnchor {
+    type Error = AccountError;
+
+    /// Returns the anchor for the provided block header.
+    ///
+    /// # Errors
+    /// Returns an error if the block is not the first block of an epoch.
+    fn try_from(block_header: &BlockHeader) -> Result<Self, Self::Error> {
+        let epoch = block_header.epoch();
+        if BlockHeader::epoch_block_num(epoch) != block_header.block_num() {
+            return Err(AccountError::AccountIdAnchorNotEpochBlock(block_header.block_num()));
+        }
+
+        Ok(Self::new(epoch, block_header.hash()))
+    }
+}
+
 // ACCOUNT ID
 // ================================================================================================
 
@@ -87,27 +243,16 @@ pub enum AccountStorageType {
 /// encode the account's storage type. The next two bits encode the account type. The values
 /// [FUNGIBLE_FAUCET], [NON_FUNGIBLE_FAUCET], [REGULAR_ACCOUNT_IMMUTABLE_CODE], and
 /// [REGULAR_ACCOUNT_UPDATABLE_CODE] encode the account's type.
+///
+/// For anchored IDs (see [AccountIdVersion]), the top two bits are set to [ANCHORED] instead. The
+/// bit following the account type encodes the storage type, being set for off-chain accounts, and
+/// the next 16 bits encode the epoch of the anchor block (see [AccountIdAnchor]).
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 pub struct AccountId(Felt);
 
 impl AccountId {
-    /// Specifies a minimum number of trailing zeros required in the last element of the seed digest.
-    ///
-    /// Note: The account id includes 4 bits of metadata, these bits determine the account type
-    /// (normal account, fungible token, non-fungible token), the storage type (on/off chain), and
-    /// for the normal accounts if the code is updatable or not. These metadata bits are also
-    /// checked by the PoW and add to the total work defined below.
-    #[cfg(not(any(feature = "testing", test)))]
-    pub const REGULAR_ACCOUNT_SEED_DIGEST_MIN_TRAILING_ZEROS: u32 = 23;
-    #[cfg(not(any(feature = "testing", test)))]
-    pub const FAUCET_SEED_DIGEST_MIN_TRAILING_ZEROS: u32 = 31;
-    #[cfg(any(feature = "testing", test))]
-    pub const REGULAR_ACCOUNT_SEED_DIGEST_MIN_TRAILING_ZEROS: u32 = 5;
-    #[cfg(any(feature = "testing", test))]
-    pub const FAUCET_SEED_DIGEST_MIN_TRAILING_ZEROS: u32 = 6;
-
     /// Specifies a minimum number of ones for a valid account ID.
     pub const MIN_ACCOUNT_ONES: u32 = 5;
 
@@ -118,24 +263,127 @@ impl AccountId {
     ///
     /// The account ID is computed by hashing the seed, code root and storage root and using 1
     /// element of the resulting digest to form the ID. Specifically we take element 0. We also
-    /// require that the last element of the seed digest has at least `23` trailing zeros if it
-    /// is a regular account, or `31` trailing zeros if it is a faucet account.
+    /// require that the last element of the seed digest has at least the number of trailing zeros
+    /// required by [NetworkParams::MAINNET] for the account type, i.e., `23` trailing zeros if it
+    /// is a regular account, or `31` trailing zeros if it is a faucet account. If the `testing`
+    /// feature is enabled, the difficulty of [NetworkParams::testing()] is required instead.
     ///
     /// The seed digest is computed using a sequential hash over
     /// hash(SEED, CODE_ROOT, STORAGE_ROOT, ZERO).  This takes two permutations.
     ///
+    /// The resulting ID is of version [AccountIdVersion::ProofOfWork].
+    ///
     /// # Errors
     /// Returns an error if the resulting account ID does not comply with account ID rules:
-    /// - the metadata embedded in the ID (i.e., the first 4 bits) is valid.
+    /// - the metadata embedded in the ID (i.e., the first 4 bits) is valid, and the ID is not
+    ///   marked as anchored.
     /// - the ID has at least `5` ones.
-    /// - the last element of the seed digest has at least `23` trailing zeros for regular
-    ///   accounts.
-    /// - the last element of the seed digest has at least `31` trailing zeros for faucet accounts.
+    /// - the last element of the seed digest has at least the number of trailing zeros required
+    ///   for the account type, as described above.
     pub fn new(seed: Word, code_root: Digest, storage_root: Digest) -> Result<Self, AccountError> {
+        Self::new_with_params(seed, code_root, storage_root, &NetworkParams::account_seed_default())
+    }
+
+    /// Returns a new account ID derived from the specified seed, code root and storage root,
+    /// requiring the proof-of-work difficulty of the network with the provided parameters.
+    ///
+    /// See [AccountId::new()] for details.
+    ///
+    /// # Errors
+    /// Returns an error if the resulting account ID does not comply with account ID rules, where
+    /// the number of trailing zeros required of the seed digest is specified by `params`.
+    pub fn new_with_params(
+        seed: Word,
+        code_root: Digest,
+        storage_root: Digest,
+        params: &NetworkParams,
+    ) -> Result<Self, AccountError> {
         let seed_digest = compute_digest(seed, code_root, storage_root);
 
-        Self::validate_seed_digest(&seed_digest)?;
-        seed_digest[0].try_into()
+        Self::validate_seed_digest(&seed_digest, seed_digest[0].as_int().into(), params)?;
+        let account_id = Self::try_from(seed_digest[0])?;
+        if account_id.version() != AccountIdVersion::ProofOfWork {
+            return Err(AccountError::AccountIdVersionMismatch {
+                expected: AccountIdVersion::ProofOfWork,
+                actual: account_id.version(),
+            });
+        }
+
+        Ok(account_id)
+    }
+
+    /// Returns a new anchored account ID derived from the specified seed, anchor, code root and
+    /// storage root.
+    ///
+    /// Unlike for [AccountId::new()], the ID additionally commits to the hash of the anchor block,
+    /// which prevents the ID from being computed before the anchor block was produced.
+    ///
+    /// The seed digest is computed using a sequential hash over
+    /// hash(SEED, CODE_ROOT, STORAGE_ROOT, ANCHOR_BLOCK_HASH). The resulting ID is of version
+    /// [AccountIdVersion::Anchored] and consists of the specified account metadata, the epoch of
+    /// the anchor, and the 43 least significant bits of the first element of the seed digest. As
+    /// for [AccountId::new()], the last element of the seed digest must have at least the number
+    /// of trailing zeros required for the account type, so that finding a seed for a given ID takes
+    /// about `2^66` hashes for regular accounts on [NetworkParams::MAINNET].
+    ///
+    /// # Errors
+    /// Returns an error if the resulting account ID does not comply with account ID rules:
+    /// - the ID has at least `5` ones.
+    /// - the ID is a valid field element.
+    /// - the last element of the seed digest has at least the number of trailing zeros required
+    ///   for the account type.
+    pub fn new_anchored(
+        seed: Word,
+        anchor: AccountIdAnchor,
+        account_type: AccountType,
+        storage_type: AccountStorageType,
+        code_root: Digest,
+        storage_root: Digest,
+    ) -> Result<Self, AccountError> {
+        Self::new_anchored_with_params(
+            seed,
+            anchor,
+            account_type,
+            storage_type,
+            code_root,
+            storage_root,
+            &NetworkParams::account_seed_default(),
+        )
+    }
+
+    /// Returns a new anchored account ID derived from the specified seed, anchor, code root and
+    /// storage root, requiring the proof-of-work difficulty of the network with the provided
+    /// parameters.
+    ///
+    /// See [AccountId::new_anchored()] for details.
+    ///
+    /// # Errors
+    /// Returns an error if the resulting account ID does not comply with account ID rules, where
+    /// the number of trailing zeros required of the seed digest is specified by `params`.
+    pub fn new_anchored_with_params(
+        seed: Word,
+        anchor: AccountIdAnchor,
+        account_type: AccountType,
+        storage_type: AccountStorageType,
+        code_root: Digest,
+        storage_root: Digest,
+        params: &NetworkParams,
+    ) -> Result<Self, AccountError> {
+        let seed_digest =
+            compute_anchored_digest(seed, code_root, storage_root, anchor.block_hash());
+        Self::validate_seed_digest(&seed_digest, account_type, params)?;
+
+        let storage_bit = match storage_type {
+            AccountStorageType::OnChain => 0,
+            AccountStorageType::OffChain => ACCOUNT_ANCHORED_STORAGE_MASK,
+        };
+        let account_id = (ANCHORED << ACCOUNT_STORAGE_MASK_SHIFT)
+            | ((account_type as u64) << ACCOUNT_TYPE_MASK_SHIFT)
+            | storage_bit
+            | ((anchor.epoch() as u64) << ACCOUNT_EPOCH_MASK_SHIFT)
+            | (seed_digest[0].as_int() & ACCOUNT_ANCHORED_DIGEST_MASK);
+
+        Self::try_from(account_id)
     }
 
     /// Creates a new [AccountId] without checking its validity.
@@ -191,6 +439,10 @@ impl AccountId {
         match bits {
             ON_CHAIN => AccountStorageType::OnChain,
             OFF_CHAIN => AccountStorageType::OffChain,
+            ANCHORED if self.0.as_int() & ACCOUNT_ANCHORED_STORAGE_MASK == 0 => {
+                AccountStorageType::OnChain
+            },
+            ANCHORED => AccountStorageType::OffChain,
             _ => panic!("Account with invalid storage bits created"),
         }
     }
@@ -200,6 +452,32 @@ impl AccountId {
         self.storage_type() == AccountStorageType::OnChain
     }
 
+    /// Returns the version of this account ID.
+    pub fn version(&self) -> AccountIdVersion {
+        if (self.0.as_int() & ACCOUNT_STORAGE_MASK) >> ACCOUNT_STORAGE_MASK_SHIFT == ANCHORED {
+            AccountIdVersion::Anchored
+        } else {
+            AccountIdVersion::ProofOfWork
+        }
+    }
+
+    /// Returns the epoch of the anchor block for anchored account IDs, or None for account IDs
+    /// derived via proof-of-work.
+    pub fn anchor_epoch(&self) -> Option<u16> {
+        match self.version() {
+            AccountIdVersion::ProofOfWork => None,
+            AccountIdVersion::Anchored => {
+                Some(((self.0.as_int() & ACCOUNT_EPOCH_MASK) >> ACCOUNT_EPOCH_MASK_SHIFT) as u16)
+            },
+        }
+    }
+
+    /// Returns the number of the anchor block for anchored account IDs, or None for account IDs
+    /// derived via proof-of-work.
+    pub fn anchor_block_num(&self) -> Option<u32> {
+        self.anchor_epoch().map(BlockHeader::epoch_block_num)
+    }
+
     /// Finds and returns a seed suitable for creating an account ID for the specified account type
     /// using the provided initial seed as a starting point.
     pub fn get_account_seed(
@@ -212,6 +490,81 @@ impl AccountId {
         get_account_seed(init_seed, account_type, storage_type, code_root, storage_root)
     }
 
+    /// Finds and returns a seed suitable for creating an account ID for the specified account type
+    /// on the network with the provided parameters, using the provided initial seed as a starting
+    /// point.
+    pub fn get_account_seed_with_params(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        storage_type: AccountStorageType,
+        code_root: Digest,
+        storage_root: Digest,
+        params: &NetworkParams,
+    ) -> Result<Word, AccountError> {
+        get_account_seed_with_params(
+            init_seed,
+            account_type,
+            storage_type,
+            code_root,
+            storage_root,
+            params,
+        )
+    }
+
+    /// Finds and returns a seed suitable for creating an anchored account ID for the specified
+    /// anchor, account type and storage type on the network with the provided parameters, using
+    /// the provided initial seed as a starting point.
+    pub fn get_anchored_account_seed(
+        init_seed: [u8; 32],
+        anchor: AccountIdAnchor,
+        account_type: AccountType,
+        storage_type: AccountStorageType,
+        code_root: Digest,
+        storage_root: Digest,
+        params: &NetworkParams,
+    ) -> Result<Word, AccountError> {
+        get_anchored_account_seed(
+            init_seed,
+            anchor,
+            account_type,
+            storage_type,
+            code_root,
+            storage_root,
+            params,
+        )
+    }
+
+    /// Finds and returns a seed suitable for creating an account ID for the specified account type
+    /// and storage type, such that the ID additionally satisfies the provided predicate (e.g., its
+    /// hex encoding starts with a given prefix).
+    ///
+    /// See [grind_account_seed()] for details.
+    #[allow(clippy::too_many_arguments)]
+    pub fn grind_with_predicate<P>(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        storage_type: AccountStorageType,
+        code_root: Digest,
+        storage_root: Digest,
+        predicate: P,
+        parallelism: usize,
+        options: &GrindOptions,
+    ) -> Result<Word, AccountError>
+    where
+        P: Fn(AccountId) -> bool + Sync,
+    {
+        grind_account_seed(
+            init_seed,
+            account_type,
+            storage_type,
+            code_root,
+            storage_root,
+            predicate,
+            parallelism,
+            options,
+        )
+    }
+
     /// Creates an Account Id from a hex string. Assumes the string starts with "0x" and
     /// that the hexadecimal characters are big-endian encoded.
     pub fn from_hex(hex_value: &str) -> Result<AccountId, AccountError> {
@@ -230,20 +583,24 @@ impl AccountId {
         format!("0x{:016x}", self.0.as_int())
     }
 
+    /// Returns the hex-encoded ID prefixed by the labels of its account type and storage type,
+    /// e.g., `fungible-faucet/onchain/0x...` (see [ParsedAccountId]).
+    pub fn to_labeled_string(&self) -> String {
+        ParsedAccountId::from(*self).to_string()
+    }
+
     // UTILITY METHODS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns an error if:
-    /// - There are fewer then:
-    ///   - 24 trailing ZEROs in the last element of the seed digest for regular accounts.
-    ///   - 32 trailing ZEROs in the last element of the seed digest for faucet accounts.
-    pub(super) fn validate_seed_digest(digest: &Digest) -> Result<(), AccountError> {
+    /// Returns an error if there are fewer trailing ZEROs in the last element of the seed digest
+    /// than required by the provided network parameters for the specified account type.
+    pub(super) fn validate_seed_digest(
+        digest: &Digest,
+        account_type: AccountType,
+        params: &NetworkParams,
+    ) -> Result<(), AccountError> {
         // check the id satisfies the proof-of-work requirement.
-        let required_zeros = if is_regular_account(digest[0].as_int()) {
-            Self::REGULAR_ACCOUNT_SEED_DIGEST_MIN_TRAILING_ZEROS
-        } else {
-            Self::FAUCET_SEED_DIGEST_MIN_TRAILING_ZEROS
-        };
+        let required_zeros = params.seed_digest_min_trailing_zeros(account_type);
 
         let trailing_zeros = digest_pow(*digest);
         if required_zeros > trailing_zeros {
@@ -275,6 +632,102 @@ impl fmt::Display for AccountId {
     }
 }
 
+impl FromStr for AccountId {
+    type Err = AccountError;
+
+    /// Parses an account ID from either its hex encoding or its labeled form (see
+    /// [ParsedAccountId]).
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value.parse::<ParsedAccountId>().map(|parsed| parsed.id())
+    }
+}
+
+// PARSED ACCOUNT ID
+// ================================================================================================
+
+/// An [AccountId] together with the metadata decoded from it.
+///
+/// A parsed account ID is displayed in its labeled form, which prefixes the hex-encoded ID with
+/// the labels of its account type and storage type, e.g., `fungible-faucet/onchain/0x...`.
+///
+/// When parsed from a string, both the labeled form and the plain hex encoding of an ID are
+/// accepted. The labels of the labeled form must match the metadata encoded in the ID.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParsedAccountId {
+    id: AccountId,
+    account_type: AccountType,
+    storage_type: AccountStorageType,
+}
+
+impl ParsedAccountId {
+    /// Returns the account ID.
+    pub fn id(&self) -> AccountId {
+        self.id
+    }
+
+    /// Returns the account type decoded from the ID.
+    pub fn account_type(&self) -> AccountType {
+        self.account_type
+    }
+
+    /// Returns the storage type decoded from the ID.
+    pub fn storage_type(&self) -> AccountStorageType {
+        self.storage_type
+    }
+}
+
+impl From<AccountId> for ParsedAccountId {
+    fn from(id: AccountId) -> Self {
+        Self {
+            id,
+            account_type: id.account_type(),
+            storage_type: id.storage_type(),
+        }
+    }
+}
+
+impl fmt::Display for ParsedAccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.account_type, self.storage_type, self.id)
+    }
+}
+
+impl FromStr for ParsedAccountId {
+    type Err = AccountError;
+
+    /// Parses an account ID from either its hex encoding (e.g., `0x...`) or its labeled form
+    /// (e.g., `fungible-faucet/onchain/0x...`).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The hex-encoded ID is not a valid account ID.
+    /// - The labeled form does not consist of an account type label, a storage type label and a
+    ///   hex-encoded ID separated by `/`, or contains unknown labels.
+    /// - The labels do not match the account type or the storage type encoded in the ID.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.split('/');
+        let (labels, hex) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(hex), None, None, None) => (None, hex),
+            (Some(account_type), Some(storage_type), Some(hex), None) => {
+                (Some((account_type.parse()?, storage_type.parse()?)), hex)
+            },
+            _ => return Err(AccountError::AccountIdInvalidLabel(value.to_string())),
+        };
+
+        let parsed = Self::from(AccountId::from_hex(hex)?);
+        if let Some((account_type, storage_type)) = labels {
+            if (account_type, storage_type) != (parsed.account_type, parsed.storage_type) {
+                return Err(AccountError::AccountIdLabelMismatch {
+                    expected: format!("{}/{}", parsed.account_type, parsed.storage_type),
+                    actual: format!("{account_type}/{storage_type}"),
+                });
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
 // CONVERSIONS FROM ACCOUNT ID
 // ================================================================================================
 
@@ -327,7 +780,7 @@ impl TryFrom<Felt> for AccountId {
 
         let bits = (int_value & ACCOUNT_STORAGE_MASK) >> ACCOUNT_STORAGE_MASK_SHIFT;
         match bits {
-            ON_CHAIN | OFF_CHAIN => (),
+            ON_CHAIN | OFF_CHAIN | ANCHORED => (),
             _ => return Err(AccountError::InvalidAccountStorageType),
         };
 
@@ -388,6 +841,22 @@ pub(super) fn compute_digest(seed: Word, code_root: Digest, storage_root: Digest
     Hasher::hash_elements(&elements)
 }
 
+/// Returns the digest of two hashing permutations over the seed, code root, storage root and
+/// anchor block hash.
+pub(super) fn compute_anchored_digest(
+    seed: Word,
+    code_root: Digest,
+    storage_root: Digest,
+    anchor_block_hash: Digest,
+) -> Digest {
+    let mut elements = Vec::with_capacity(16);
+    elements.extend(seed);
+    elements.extend(*code_root);
+    elements.extend(*storage_root);
+    elements.extend(*anchor_block_hash);
+    Hasher::hash_elements(&elements)
+}
+
 /// Given a [Digest] returns its proof-of-work.
 pub(super) fn digest_pow(digest: Digest) -> u32 {
     digest.as_elements()[3].as_int().trailing_zeros()
@@ -498,13 +967,18 @@ pub mod testing {
 // ================================================================================================
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+
     use miden_crypto::utils::{Deserializable, Serializable};
 
     use super::{
-        testing::*, AccountId, AccountStorageType, AccountType, ACCOUNT_ISFAUCET_MASK,
+        compute_digest, digest_pow, testing::*, AccountError, AccountId, AccountIdAnchor,
+        AccountIdVersion, AccountStorageType, AccountType, Digest, Felt, NetworkParams,
+        ParsedAccountId, ACCOUNT_ISFAUCET_MASK, ACCOUNT_STORAGE_MASK_SHIFT,
         ACCOUNT_TYPE_MASK_SHIFT, FUNGIBLE_FAUCET, NON_FUNGIBLE_FAUCET,
         REGULAR_ACCOUNT_IMMUTABLE_CODE, REGULAR_ACCOUNT_UPDATABLE_CODE,
     };
+    use crate::block::BlockHeaderBuilder;
 
     #[test]
     fn test_account_id() {
@@ -537,6 +1011,91 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_account_id_labeled_string() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let labeled = account_id.to_labeled_string();
+        assert_eq!(labeled, format!("fungible-faucet/onchain/{}", account_id.to_hex()));
+
+        let parsed: ParsedAccountId = labeled.parse().unwrap();
+        assert_eq!(parsed.id(), account_id);
+        assert_eq!(parsed.account_type(), AccountType::FungibleFaucet);
+        assert_eq!(parsed.storage_type(), AccountStorageType::OnChain);
+        assert_eq!(parsed.to_string(), labeled);
+
+        // both the hex and the labeled forms can be parsed
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        assert_eq!(account_id.to_hex().parse::<AccountId>(), Ok(account_id));
+        assert_eq!(account_id.to_labeled_string().parse::<AccountId>(), Ok(account_id));
+        assert!(account_id.to_labeled_string().starts_with("regular-updatable-code/offchain/"));
+
+        // the labels must be known and match the ID metadata
+        let hex = account_id.to_hex();
+        assert_eq!(
+            format!("regular-immutable-code/offchain/{hex}").parse::<AccountId>(),
+            Err(AccountError::AccountIdLabelMismatch {
+                expected: "regular-updatable-code/offchain".to_string(),
+                actual: "regular-immutable-code/offchain".to_string(),
+            })
+        );
+        assert_eq!(
+            format!("regular-updatable-code/public/{hex}").parse::<AccountId>(),
+            Err(AccountError::AccountIdInvalidLabel("public".to_string()))
+        );
+        assert!(format!("offchain/{hex}").parse::<AccountId>().is_err());
+    }
+
+    #[test]
+    fn test_account_id_grind_with_predicate() {
+        use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+        use super::GrindOptions;
+
+        let code_root = Digest::default();
+        let storage_root = Digest::default();
+        let params = NetworkParams::new(4, 4).unwrap();
+        let attempts = AtomicU64::new(0);
+        let progress = |count| attempts.store(count, Ordering::Relaxed);
+        let options = GrindOptions::new().with_network_params(params).with_progress(1, &progress);
+
+        // the last hex digit of the ID is constrained by the predicate
+        let seed = AccountId::grind_with_predicate(
+            [3; 32],
+            AccountType::FungibleFaucet,
+            AccountStorageType::OffChain,
+            code_root,
+            storage_root,
+            |account_id| account_id.to_hex().ends_with('7'),
+            2,
+            &options,
+        )
+        .unwrap();
+        let account_id =
+            AccountId::new_with_params(seed, code_root, storage_root, &params).unwrap();
+        assert_eq!(account_id.account_type(), AccountType::FungibleFaucet);
+        assert_eq!(account_id.storage_type(), AccountStorageType::OffChain);
+        assert!(account_id.to_hex().ends_with('7'));
+        assert!(attempts.load(Ordering::Relaxed) > 0);
+
+        // a cancelled search returns an error
+        let cancel = AtomicBool::new(true);
+        let options = GrindOptions::new().with_network_params(params).with_cancellation(&cancel);
+        assert_eq!(
+            AccountId::grind_with_predicate(
+                [3; 32],
+                AccountType::FungibleFaucet,
+                AccountStorageType::OffChain,
+                code_root,
+                storage_root,
+                |_| false,
+                2,
+                &options,
+            ),
+            Err(AccountError::SeedGrindingCancelled)
+        );
+    }
+
     #[test]
     fn test_account_id_serde() {
         let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN)
@@ -556,6 +1115,50 @@ mod tests {
         assert_eq!(account_id, AccountId::read_from_bytes(&account_id.to_bytes()).unwrap());
     }
 
+    #[test]
+    fn test_account_id_with_network_params() {
+        let code_root = Digest::default();
+        let storage_root = Digest::default();
+        let params = NetworkParams::new(10, 12).unwrap();
+        let seed = AccountId::get_account_seed_with_params(
+            [7; 32],
+            AccountType::RegularAccountUpdatableCode,
+            AccountStorageType::OffChain,
+            code_root,
+            storage_root,
+            &params,
+        )
+        .unwrap();
+        let pow = digest_pow(compute_digest(seed, code_root, storage_root));
+        assert!(pow >= 10);
+
+        let account_id =
+            AccountId::new_with_params(seed, code_root, storage_root, &params).unwrap();
+        assert_eq!(account_id.account_type(), AccountType::RegularAccountUpdatableCode);
+
+        // the seed is valid on networks requiring at most its proof-of-work
+        let params = NetworkParams::new(pow, 0).unwrap();
+        assert_eq!(
+            AccountId::new_with_params(seed, code_root, storage_root, &params),
+            Ok(account_id)
+        );
+        assert_eq!(
+            AccountId::new_with_params(
+                seed,
+                code_root,
+                storage_root,
+                &NetworkParams::NO_PROOF_OF_WORK
+            ),
+            Ok(account_id)
+        );
+
+        let params = NetworkParams::new(pow + 1, 0).unwrap();
+        assert_eq!(
+            AccountId::new_with_params(seed, code_root, storage_root, &params),
+            Err(AccountError::SeedDigestTooFewTrailingZeros { expected: pow + 1, actual: pow })
+        );
+    }
+
     #[test]
     fn test_account_id_account_type() {
         let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN)
@@ -607,6 +1210,90 @@ mod tests {
         assert!(!account_id.is_on_chain());
     }
 
+    #[test]
+    fn test_account_id_anchored() {
+        let block_header = BlockHeaderBuilder::new().build();
+        let anchor = AccountIdAnchor::try_from(&block_header).unwrap();
+        let code_root = Digest::default();
+        let storage_root = Digest::default();
+        let params = NetworkParams::testing();
+
+        for account_type in [
+            AccountType::RegularAccountImmutableCode,
+            AccountType::RegularAccountUpdatableCode,
+            AccountType::NonFungibleFaucet,
+            AccountType::FungibleFaucet,
+        ] {
+            for storage_type in [AccountStorageType::OnChain, AccountStorageType::OffChain] {
+                let seed = AccountId::get_anchored_account_seed(
+                    [7; 32],
+                    anchor,
+                    account_type,
+                    storage_type,
+                    code_root,
+                    storage_root,
+                    &params,
+                )
+                .unwrap();
+                let account_id = AccountId::new_anchored(
+                    seed,
+                    anchor,
+                    account_type,
+                    storage_type,
+                    code_root,
+                    storage_root,
+                )
+                .unwrap();
+                assert_eq!(account_id.version(), AccountIdVersion::Anchored);
+                assert_eq!(account_id.account_type(), account_type);
+                assert_eq!(account_id.storage_type(), storage_type);
+                assert_eq!(account_id.anchor_epoch(), Some(0));
+                assert_eq!(account_id.anchor_block_num(), Some(0));
+                assert_eq!(AccountId::try_from(Felt::from(account_id)), Ok(account_id));
+
+                // the seed digest of an anchored id never satisfies the proof-of-work rules
+                assert!(AccountId::new(seed, code_root, storage_root)
+                    .map_or(true, |id| id != account_id));
+            }
+        }
+
+        // the seed digest of an anchored id must satisfy the proof-of-work requirement
+        let seed = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        assert!(matches!(
+            AccountId::new_anchored_with_params(
+                seed,
+                anchor,
+                AccountType::RegularAccountUpdatableCode,
+                AccountStorageType::OffChain,
+                code_root,
+                storage_root,
+                &NetworkParams::MAINNET,
+            ),
+            Err(AccountError::SeedDigestTooFewTrailingZeros { expected: 23, .. })
+        ));
+
+        // existing account ids are not anchored
+        let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN)
+            .expect("Valid account ID");
+        assert_eq!(account_id.version(), AccountIdVersion::ProofOfWork);
+        assert_eq!(account_id.anchor_epoch(), None);
+
+        // only the first block of an epoch can be used as an anchor
+        let block_header = BlockHeaderBuilder::new().block_num(1).build();
+        assert_eq!(
+            AccountIdAnchor::try_from(&block_header),
+            Err(AccountError::AccountIdAnchorNotEpochBlock(1))
+        );
+        let anchor = AccountIdAnchor::new(3, Digest::default());
+        assert_eq!(anchor.block_num(), 3 << 16);
+    }
+
+    #[test]
+    fn test_account_id_invalid_storage_bits() {
+        let id = (0b11 << ACCOUNT_STORAGE_MASK_SHIFT) | 0b1111_1111;
+        assert_eq!(AccountId::try_from(id), Err(AccountError::InvalidAccountStorageType));
+    }
+
     /// The following test ensure there is a bit available to identify an account as a faucet or
     /// normal.
     #[test]
@@ -1,10 +1,14 @@
-use alloc::vec::Vec;
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use assembly::ast::AstSerdeOptions;
 
 use super::{
     AccountError, Assembler, AssemblyContext, ByteReader, ByteWriter, Deserializable,
-    DeserializationError, Digest, ModuleAst, Serializable,
+    DeserializationError, Digest, Felt, Hasher, ModuleAst, Serializable, Word, ZERO,
 };
 use crate::crypto::merkle::SimpleSmt;
 
@@ -17,6 +21,10 @@ const MODULE_SERDE_OPTIONS: AstSerdeOptions = AstSerdeOptions::new(false);
 /// The depth of the Merkle tree that is used to commit to the account's public interface.
 pub const PROCEDURE_TREE_DEPTH: u8 = 8;
 
+/// The word which is merged with the MAST root of a procedure requiring authentication to build
+/// its leaf in the procedure tree.
+const AUTH_PROCEDURE_MARKER: Word = [Felt::new(1), ZERO, ZERO, ZERO];
+
 // ACCOUNT CODE
 // ================================================================================================
 
@@ -25,10 +33,22 @@ pub const PROCEDURE_TREE_DEPTH: u8 = 8;
 /// Account's public interface consists of a set of account procedures, each procedure being a Miden
 /// VM program. Thus, MAST root of each procedure commits to the underlying program. We commit to
 /// the entire account interface by building a simple Merkle tree out of all procedure MAST roots.
+///
+/// Some of the procedures can be marked as requiring authentication. Such procedures cannot be
+/// invoked while a note is being executed against the account, i.e., they can only be invoked
+/// from a transaction script. The leaf of such a procedure in the procedure tree is
+/// hash(PROC_ROOT, [1, 0, 0, 0]) instead of PROC_ROOT, which makes the procedure tree commit to
+/// the authentication requirements as well.
+///
+/// Procedures can also carry descriptive [AccountProcedureInfo]. The info is serialized with the
+/// account code but it is not committed to by the procedure tree, and thus, it cannot be trusted
+/// unless the account code is obtained from a trusted source.
 #[derive(Debug, Clone)]
 pub struct AccountCode {
     module: ModuleAst,
     procedures: Vec<Digest>,
+    auth_procedures: Vec<Digest>,
+    procedure_info: BTreeMap<Digest, AccountProcedureInfo>,
     procedure_tree: SimpleSmt<PROCEDURE_TREE_DEPTH>,
 }
 
@@ -68,9 +88,11 @@ impl AccountCode {
         }
 
         Ok(Self {
-            procedure_tree: build_procedure_tree(&procedures),
+            procedure_tree: build_procedure_tree(&procedures, &[]),
             module,
             procedures,
+            auth_procedures: Vec::new(),
+            procedure_info: BTreeMap::new(),
         })
     }
 
@@ -86,15 +108,71 @@ impl AccountCode {
         assert!(!procedures.is_empty(), "no account procedures");
         assert!(procedures.len() <= Self::MAX_NUM_PROCEDURES, "too many account procedures");
         Self {
-            procedure_tree: build_procedure_tree(&procedures),
+            procedure_tree: build_procedure_tree(&procedures, &[]),
             module,
             procedures,
+            auth_procedures: Vec::new(),
+            procedure_info: BTreeMap::new(),
+        }
+    }
+
+    /// Returns a new definition of an account's interface in which the procedures with the
+    /// specified roots require authentication, replacing any previously marked procedures.
+    ///
+    /// Procedures requiring authentication cannot be invoked while a note is being executed
+    /// against the account.
+    ///
+    /// # Errors
+    /// Returns an error if any of the specified procedures is not defined for this account.
+    pub fn with_auth_procedures(
+        mut self,
+        auth_procedures: &[Digest],
+    ) -> Result<Self, AccountError> {
+        if let Some(root) = auth_procedures.iter().find(|root| !self.has_procedure(**root)) {
+            return Err(AccountError::AccountCodeProcedureNotFound(*root));
+        }
+
+        self.auth_procedures = self
+            .procedures
+            .iter()
+            .filter(|root| auth_procedures.contains(root))
+            .copied()
+            .collect();
+        self.procedure_tree = build_procedure_tree(&self.procedures, &self.auth_procedures);
+
+        Ok(self)
+    }
+
+    /// Returns a new definition of an account's interface in which the procedure with the
+    /// specified root carries the provided info, replacing any previously provided info for it.
+    ///
+    /// The info is not committed to by the account code, and thus, it does not affect the
+    /// commitment to the account's public interface.
+    ///
+    /// # Errors
+    /// Returns an error if the specified procedure is not defined for this account.
+    pub fn with_procedure_info(
+        mut self,
+        root: Digest,
+        info: AccountProcedureInfo,
+    ) -> Result<Self, AccountError> {
+        if !self.has_procedure(root) {
+            return Err(AccountError::AccountCodeProcedureNotFound(root));
         }
+
+        self.procedure_info.insert(root, info);
+        Ok(self)
     }
 
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the info of the procedure with the specified root or None if no info was provided
+    /// for such procedure.
+    pub fn procedure_info(&self, root: Digest) -> Option<&AccountProcedureInfo> {
+        self.procedure_info.get(&root)
+    }
+
     /// Returns a commitment to an account's public interface.
     pub fn root(&self) -> Digest {
         self.procedure_tree().root()
@@ -110,6 +188,21 @@ impl AccountCode {
         &self.procedures
     }
 
+    /// Returns a reference to the digests of the account procedures requiring authentication.
+    pub fn auth_procedures(&self) -> &[Digest] {
+        &self.auth_procedures
+    }
+
+    /// Returns true if the procedure with the specified root requires authentication.
+    pub fn requires_auth(&self, root: Digest) -> bool {
+        self.auth_procedures.contains(&root)
+    }
+
+    /// Returns the leaf of a procedure requiring authentication in the procedure tree.
+    pub fn auth_procedure_leaf(root: Digest) -> Digest {
+        Hasher::merge(&[root, AUTH_PROCEDURE_MARKER.into()])
+    }
+
     /// Returns a reference to the procedure tree.
     pub fn procedure_tree(&self) -> &SimpleSmt<PROCEDURE_TREE_DEPTH> {
         &self.procedure_tree
@@ -146,7 +239,10 @@ impl AccountCode {
 impl PartialEq for AccountCode {
     fn eq(&self, other: &Self) -> bool {
         // TODO: consider checking equality based only on the set of procedures
-        self.module == other.module && self.procedures == other.procedures
+        self.module == other.module
+            && self.procedures == other.procedures
+            && self.auth_procedures == other.auth_procedures
+            && self.procedure_info == other.procedure_info
     }
 }
 
@@ -163,6 +259,9 @@ impl Serializable for AccountCode {
         // number as a single byte - but we do have to subtract 1 to store 256 as 255.
         target.write_u8((self.procedures.len() - 1) as u8);
         target.write_many(self.procedures());
+        target.write_u16(self.auth_procedures.len() as u16);
+        target.write_many(self.auth_procedures());
+        self.procedure_info.write_into(target);
     }
 }
 
@@ -172,15 +271,146 @@ impl Deserializable for AccountCode {
         let module = ModuleAst::read_from(source, MODULE_SERDE_OPTIONS)?;
         let num_procedures = (source.read_u8()? as usize) + 1;
         let procedures = source.read_many::<Digest>(num_procedures)?;
+        let num_auth_procedures = source.read_u16()? as usize;
+        let auth_procedures = source.read_many::<Digest>(num_auth_procedures)?;
+        let procedure_info = BTreeMap::<Digest, AccountProcedureInfo>::read_from(source)?;
+
+        procedure_info
+            .into_iter()
+            .fold(
+                Self::from_parts(module, procedures).with_auth_procedures(&auth_procedures),
+                |code, (root, info)| code?.with_procedure_info(root, info),
+            )
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+/// Account code is serialized as a byte array using the binary encoding of [AccountCode], since
+/// the module AST has no structured representation.
+#[cfg(feature = "serde")]
+impl serde::Serialize for AccountCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = self.to_bytes();
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AccountCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes: Vec<u8> = <Vec<u8> as serde::Deserialize>::deserialize(deserializer)?;
+        Self::read_from_bytes(&bytes).map_err(serde::de::Error::custom)
+    }
+}
+
+// ACCOUNT PROCEDURE INFO
+// ================================================================================================
 
-        Ok(Self::from_parts(module, procedures))
+/// Descriptive information about an account procedure.
+///
+/// The info consists of:
+/// - A human-readable name of the procedure.
+/// - The number of stack elements consumed and produced by the procedure, which cannot exceed 16
+///   since account procedures are invoked via `call`.
+/// - The index of the first storage slot the procedure may access, if any.
+///
+/// The info is meant to be displayed to users, e.g., to describe which procedures a note is going
+/// to invoke. It is not enforced by the VM.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountProcedureInfo {
+    name: String,
+    num_inputs: u8,
+    num_outputs: u8,
+    storage_offset: Option<u8>,
+}
+
+impl AccountProcedureInfo {
+    /// The maximum number of stack elements consumed or produced by an account procedure.
+    pub const MAX_NUM_STACK_ELEMENTS: u8 = 16;
+
+    /// Returns new [AccountProcedureInfo] instantiated from the provided parameters.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The name is empty.
+    /// - The number of inputs or outputs is greater than 16.
+    pub fn new(
+        name: impl Into<String>,
+        num_inputs: u8,
+        num_outputs: u8,
+        storage_offset: Option<u8>,
+    ) -> Result<Self, AccountError> {
+        let name = name.into();
+        if name.is_empty() {
+            return Err(AccountError::AccountCodeInvalidProcedureInfo(
+                "procedure name must not be empty".to_string(),
+            ));
+        }
+        if num_inputs > Self::MAX_NUM_STACK_ELEMENTS || num_outputs > Self::MAX_NUM_STACK_ELEMENTS {
+            return Err(AccountError::AccountCodeInvalidProcedureInfo(format!(
+                "procedure {name} has {num_inputs} inputs and {num_outputs} outputs, but at most {} are allowed",
+                Self::MAX_NUM_STACK_ELEMENTS
+            )));
+        }
+
+        Ok(Self {
+            name,
+            num_inputs,
+            num_outputs,
+            storage_offset,
+        })
+    }
+
+    /// Returns the name of the procedure.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the number of stack elements consumed by the procedure.
+    pub fn num_inputs(&self) -> u8 {
+        self.num_inputs
+    }
+
+    /// Returns the number of stack elements produced by the procedure.
+    pub fn num_outputs(&self) -> u8 {
+        self.num_outputs
+    }
+
+    /// Returns the index of the first storage slot the procedure may access, or None if the
+    /// procedure does not access account storage.
+    pub fn storage_offset(&self) -> Option<u8> {
+        self.storage_offset
+    }
+}
+
+impl Serializable for AccountProcedureInfo {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.name.write_into(target);
+        target.write_u8(self.num_inputs);
+        target.write_u8(self.num_outputs);
+        self.storage_offset.write_into(target);
+    }
+}
+
+impl Deserializable for AccountProcedureInfo {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let name = String::read_from(source)?;
+        let num_inputs = source.read_u8()?;
+        let num_outputs = source.read_u8()?;
+        let storage_offset = Option::<u8>::read_from(source)?;
+
+        Self::new(name, num_inputs, num_outputs, storage_offset)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
     }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
-fn build_procedure_tree(procedures: &[Digest]) -> SimpleSmt<PROCEDURE_TREE_DEPTH> {
+fn build_procedure_tree(
+    procedures: &[Digest],
+    auth_procedures: &[Digest],
+) -> SimpleSmt<PROCEDURE_TREE_DEPTH> {
     // order the procedure digests to achieve a reproducible tree
     let procedures = {
         let mut procedures = procedures.to_vec();
@@ -192,7 +422,14 @@ fn build_procedure_tree(procedures: &[Digest]) -> SimpleSmt<PROCEDURE_TREE_DEPTH
         procedures
             .iter()
             .enumerate()
-            .map(|(idx, p)| (idx as u64, p.into()))
+            .map(|(idx, p)| {
+                let leaf = if auth_procedures.contains(p) {
+                    AccountCode::auth_procedure_leaf(*p)
+                } else {
+                    *p
+                };
+                (idx as u64, leaf.into())
+            })
             .collect::<Vec<_>>(),
     )
     .expect("failed to build procedure tree")
@@ -228,7 +465,7 @@ pub mod testing {
 
 #[cfg(test)]
 mod tests {
-    use super::{testing::*, AccountCode, Deserializable, Serializable};
+    use super::{testing::*, AccountCode, AccountProcedureInfo, Deserializable, Serializable};
 
     #[test]
     fn test_serde() {
@@ -237,4 +474,49 @@ mod tests {
         let deserialized = AccountCode::read_from_bytes(&serialized).unwrap();
         assert_eq!(deserialized, code)
     }
+
+    #[test]
+    fn test_auth_procedures() {
+        let code = make_account_code();
+        let auth_proc = code.procedures()[0];
+        let auth_code = code.clone().with_auth_procedures(&[auth_proc]).unwrap();
+
+        // marking a procedure changes the commitment to the account interface
+        assert_ne!(auth_code.root(), code.root());
+        assert!(auth_code.requires_auth(auth_proc));
+        assert!(!auth_code.requires_auth(code.procedures()[1]));
+
+        // the authentication requirements are serialized with the account code
+        let deserialized = AccountCode::read_from_bytes(&auth_code.to_bytes()).unwrap();
+        assert_eq!(deserialized, auth_code);
+        assert_eq!(deserialized.root(), auth_code.root());
+
+        // only procedures of the account can be marked
+        let unknown_proc = AccountCode::auth_procedure_leaf(auth_proc);
+        assert!(code.with_auth_procedures(&[unknown_proc]).is_err());
+    }
+
+    #[test]
+    fn test_procedure_info() {
+        let code = make_account_code();
+        let foo = code.procedures()[0];
+        let info = AccountProcedureInfo::new("foo", 0, 1, Some(2)).unwrap();
+        let info_code = code.clone().with_procedure_info(foo, info.clone()).unwrap();
+
+        // the info does not change the commitment to the account interface
+        assert_eq!(info_code.root(), code.root());
+        assert_eq!(info_code.procedure_info(foo), Some(&info));
+        assert_eq!(info_code.procedure_info(code.procedures()[1]), None);
+
+        // the info is serialized with the account code
+        let deserialized = AccountCode::read_from_bytes(&info_code.to_bytes()).unwrap();
+        assert_eq!(deserialized, info_code);
+        assert_eq!(deserialized.procedure_info(foo), Some(&info));
+
+        // only procedures of the account can carry info, and the arity is limited to 16
+        let unknown_proc = AccountCode::auth_procedure_leaf(foo);
+        assert!(code.clone().with_procedure_info(unknown_proc, info).is_err());
+        assert!(AccountProcedureInfo::new("foo", 17, 0, None).is_err());
+        assert!(AccountProcedureInfo::new("", 0, 0, None).is_err());
+    }
 }
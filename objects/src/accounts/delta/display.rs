@@ -0,0 +1,270 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use super::{Account, AccountDelta, Asset, Word};
+use crate::{accounts::AccountId, Digest, EMPTY_WORD};
+
+// ACCOUNT DELTA RENDERING
+// ================================================================================================
+
+impl AccountDelta {
+    /// Renders this delta as a table of the changes it makes to the provided account, showing
+    /// the value of every changed entry before and after the delta is applied.
+    ///
+    /// The table lists the change of the nonce, the net change of the balance of every fungible
+    /// asset, the added and removed non-fungible assets, and the updated storage slots and
+    /// storage map entries. Slots holding the roots of updated storage maps are listed via the
+    /// updated map entries.
+    ///
+    /// The provided account is expected to be the account against which the delta was computed;
+    /// entries missing from the account are rendered as empty.
+    pub fn to_table(&self, account: &Account) -> String {
+        let rows = self.rows(Some(account));
+
+        let entry_width = rows.iter().map(|row| row.entry.len()).max().unwrap_or(0).max(5);
+        let before_width = rows
+            .iter()
+            .map(|row| row.before.as_deref().unwrap_or_default().len())
+            .max()
+            .unwrap_or(0)
+            .max(6);
+
+        let mut table = format!("{:entry_width$} | {:before_width$} | after\n", "entry", "before");
+        table.push_str(&format!("{:-<entry_width$}-+-{:-<before_width$}-+------\n", "", ""));
+        for row in rows {
+            table.push_str(&format!(
+                "{:entry_width$} | {:before_width$} | {}\n",
+                row.entry,
+                row.before.unwrap_or_default(),
+                row.after
+            ));
+        }
+        table
+    }
+
+    /// Returns the rows describing the changes made by this delta; the values before the changes
+    /// are computed only if the account is provided.
+    fn rows(&self, account: Option<&Account>) -> Vec<DeltaRow> {
+        let mut rows = Vec::new();
+
+        if let Some(nonce) = self.nonce {
+            rows.push(DeltaRow {
+                entry: "nonce".to_string(),
+                before: account.map(|account| account.nonce().to_string()),
+                after: nonce.to_string(),
+            });
+        }
+
+        // net flows of fungible assets, in the order in which the faucets appear in the delta
+        let mut flows: Vec<(AccountId, i128)> = Vec::new();
+        let vault = &self.vault;
+        let fungible_changes = vault
+            .added_assets
+            .iter()
+            .map(|asset| (asset, 1))
+            .chain(vault.removed_assets.iter().map(|asset| (asset, -1)));
+        for (asset, sign) in fungible_changes {
+            if let Asset::Fungible(asset) = asset {
+                let amount = sign * asset.amount() as i128;
+                match flows.iter_mut().find(|(faucet_id, _)| *faucet_id == asset.faucet_id()) {
+                    Some((_, flow)) => *flow += amount,
+                    None => flows.push((asset.faucet_id(), amount)),
+                }
+            }
+        }
+        for (faucet_id, flow) in flows {
+            let balance =
+                account.map(|account| account.vault().get_balance(faucet_id).unwrap_or(0));
+            let after = match balance {
+                Some(balance) => format!("{} ({flow:+})", balance as i128 + flow),
+                None => format!("{flow:+}"),
+            };
+            rows.push(DeltaRow {
+                entry: format!("fungible {faucet_id}"),
+                before: balance.map(|balance| balance.to_string()),
+                after,
+            });
+        }
+
+        for asset in vault.added_assets.iter().filter(|asset| !asset.is_fungible()) {
+            rows.push(DeltaRow {
+                entry: format!("non-fungible {}", asset.faucet_id()),
+                before: account.map(|_| "-".to_string()),
+                after: format!("added {}", word_to_string(&Word::from(*asset))),
+            });
+        }
+        for asset in vault.removed_assets.iter().filter(|asset| !asset.is_fungible()) {
+            rows.push(DeltaRow {
+                entry: format!("non-fungible {}", asset.faucet_id()),
+                before: account.map(|_| word_to_string(&Word::from(*asset))),
+                after: "removed".to_string(),
+            });
+        }
+
+        // storage slots, excluding the slots holding the roots of updated maps
+        let storage = &self.storage;
+        let mut slots = storage
+            .cleared_items
+            .iter()
+            .map(|&index| (index, EMPTY_WORD))
+            .chain(storage.updated_items.iter().copied())
+            .filter(|(index, _)| {
+                !storage.updated_maps.iter().any(|(map_index, _)| map_index == index)
+            })
+            .collect::<Vec<_>>();
+        slots.sort_by_key(|(index, _)| *index);
+        for (index, value) in slots {
+            rows.push(DeltaRow {
+                entry: format!("storage[{index}]"),
+                before: account
+                    .map(|account| word_to_string(&Word::from(account.storage().get_item(index)))),
+                after: word_to_string(&value),
+            });
+        }
+
+        for (index, map_delta) in storage.updated_maps.iter() {
+            let map = account.map(|account| {
+                let root = account.storage().get_item(*index);
+                account.storage().maps().iter().find(|map| map.root() == root)
+            });
+            let entries = map_delta
+                .cleared_leaves
+                .iter()
+                .map(|key| (*key, EMPTY_WORD))
+                .chain(map_delta.updated_leaves.iter().copied());
+            for (key, value) in entries {
+                let before = map.map(|map| match map {
+                    Some(map) => word_to_string(&map.get_value(&Digest::from(key))),
+                    None => word_to_string(&EMPTY_WORD),
+                });
+                rows.push(DeltaRow {
+                    entry: format!("storage[{index}][{}]", word_elements(&key)),
+                    before,
+                    after: word_to_string(&value),
+                });
+            }
+        }
+
+        rows
+    }
+}
+
+impl fmt::Display for AccountDelta {
+    /// Lists the changes made by this delta, one per line; see [AccountDelta::to_table()] for
+    /// a rendering which also shows the values before the changes.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() && self.nonce.is_none() {
+            return writeln!(f, "no changes");
+        }
+        for row in self.rows(None) {
+            writeln!(f, "{}: {}", row.entry, row.after)?;
+        }
+        Ok(())
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// A single change made by an account delta.
+struct DeltaRow {
+    entry: String,
+    before: Option<String>,
+    after: String,
+}
+
+fn word_to_string(word: &Word) -> String {
+    format!("[{}]", word_elements(word))
+}
+
+fn word_elements(word: &Word) -> String {
+    word.iter().map(|felt| felt.as_int().to_string()).collect::<Vec<_>>().join(", ")
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+                ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            },
+            AccountStorageDelta, AccountVaultDelta, StorageMapDelta,
+        },
+        assets::FungibleAsset,
+        testing::accounts::{
+            account_with_storage_map, non_fungible_asset, STORAGE_INDEX_0, STORAGE_INDEX_1,
+            STORAGE_INDEX_2, STORAGE_LEAVES_2, STORAGE_VALUE_1,
+        },
+        Felt,
+    };
+
+    fn fungible_asset(faucet_id: u64, amount: u64) -> Asset {
+        FungibleAsset::new(faucet_id.try_into().unwrap(), amount).unwrap().into()
+    }
+
+    #[test]
+    fn account_delta_rendering() {
+        let account = account_with_storage_map();
+        let map_key = Word::from(STORAGE_LEAVES_2[0].0);
+        let map_value = [Felt::new(9); 4];
+        let delta = AccountDelta {
+            storage: AccountStorageDelta {
+                cleared_items: vec![STORAGE_INDEX_1],
+                updated_items: vec![
+                    (STORAGE_INDEX_0, STORAGE_VALUE_1),
+                    (STORAGE_INDEX_2, [Felt::new(1); 4]),
+                ],
+                updated_maps: vec![(
+                    STORAGE_INDEX_2,
+                    StorageMapDelta::from(vec![], vec![(map_key, map_value)]),
+                )],
+            },
+            vault: AccountVaultDelta {
+                added_assets: vec![fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 50)],
+                removed_assets: vec![
+                    fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 30),
+                    non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN),
+                ],
+            },
+            nonce: Some(Felt::new(2)),
+        };
+
+        assert_eq!(
+            delta.to_string(),
+            "nonce: 2\n\
+            fungible 0x200000000000001f: +50\n\
+            fungible 0x200000000000002f: -30\n\
+            non-fungible 0x300000000000002f: removed\n\
+            storage[20]: [5, 6, 7, 8]\n\
+            storage[30]: [0, 0, 0, 0]\n\
+            storage[40][101, 102, 103, 104]: [9, 9, 9, 9]\n"
+        );
+
+        let table = delta.to_table(&account);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 9);
+        assert!(lines[0].starts_with("entry "));
+        let rows = lines[2..]
+            .iter()
+            .map(|line| line.split(" | ").map(str::trim).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(rows[0], ["nonce", "1", "2"]);
+        assert_eq!(rows[1], ["fungible 0x200000000000001f", "100", "150 (+50)"]);
+        assert_eq!(rows[2], ["fungible 0x200000000000002f", "100", "70 (-30)"]);
+        assert_eq!(rows[3][2], "removed");
+        assert_eq!(rows[4], ["storage[20]", "[1, 2, 3, 4]", "[5, 6, 7, 8]"]);
+        assert_eq!(rows[5], ["storage[30]", "[5, 6, 7, 8]", "[0, 0, 0, 0]"]);
+        assert_eq!(rows[6], ["storage[40][101, 102, 103, 104]", "[1, 2, 3, 4]", "[9, 9, 9, 9]"]);
+
+        assert_eq!(AccountDelta::default().to_string(), "no changes\n");
+    }
+}
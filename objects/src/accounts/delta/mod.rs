@@ -9,6 +9,8 @@ use crate::{assets::Asset, AccountDeltaError};
 mod builder;
 pub use builder::AccountStorageDeltaBuilder;
 
+mod display;
+
 mod storage;
 pub use storage::{AccountStorageDelta, StorageMapDelta};
 
@@ -97,12 +99,26 @@ pub enum AccountUpdateDetails {
 
     /// For existing accounts, only the delta is needed.
     Delta(AccountDelta),
+
+    /// Account is private and the commitment to its new state is salted with the specified salt
+    /// (see [salt_account_hash()](crate::accounts::salt_account_hash)). The salt is required to
+    /// execute the next transaction against the account.
+    PrivateSalted(Word),
 }
 
 impl AccountUpdateDetails {
     /// Returns `true` if the account update details are for private account.
     pub fn is_private(&self) -> bool {
-        matches!(self, Self::Private)
+        matches!(self, Self::Private | Self::PrivateSalted(_))
+    }
+
+    /// Returns the salt of the commitment to the new state of a private account, if the
+    /// commitment is salted.
+    pub fn account_salt(&self) -> Option<Word> {
+        match self {
+            Self::PrivateSalted(salt) => Some(*salt),
+            _ => None,
+        }
     }
 }
 
@@ -144,6 +160,10 @@ impl Serializable for AccountUpdateDetails {
                 2_u8.write_into(target);
                 delta.write_into(target);
             },
+            AccountUpdateDetails::PrivateSalted(salt) => {
+                3_u8.write_into(target);
+                salt.write_into(target);
+            },
         }
     }
 }
@@ -154,6 +174,7 @@ impl Deserializable for AccountUpdateDetails {
             0 => Ok(Self::Private),
             1 => Ok(Self::New(Account::read_from(source)?)),
             2 => Ok(Self::Delta(AccountDelta::read_from(source)?)),
+            3 => Ok(Self::PrivateSalted(Word::read_from(source)?)),
             v => Err(DeserializationError::InvalidValue(format!(
                 "Unknown variant {v} for AccountDetails"
             ))),
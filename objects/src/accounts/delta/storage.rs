@@ -47,6 +47,7 @@ impl AccountStorageDelta {
     /// - The number of cleared or updated items is greater than 255.
     /// - Any of cleared or updated items are at slot 255 (i.e., immutable slot).
     /// - Any of the cleared or updated items is referenced more than once (e.g., updated twice).
+    /// - There is more than one storage map delta for the same storage item.
     /// - There is a storage map delta without a corresponding storage item update.
     pub fn validate(&self) -> Result<(), AccountDeltaError> {
         let num_cleared_items = self.cleared_items.len();
@@ -91,10 +92,13 @@ impl AccountStorageDelta {
         }
 
         // make sure storage map deltas are valid
-        for (index, storage_map_delta) in self.updated_maps.iter() {
+        for (pos, (index, storage_map_delta)) in self.updated_maps.iter().enumerate() {
             if index > &MAX_MUTABLE_STORAGE_SLOT_IDX {
                 return Err(AccountDeltaError::ImmutableStorageSlot(*index as usize));
             }
+            if self.updated_maps[..pos].iter().any(|x| x.0 == *index) {
+                return Err(AccountDeltaError::DuplicateStorageItemUpdate(*index as usize));
+            }
             // for every storage map delta there should be one corresponding storage item update
             if !self.updated_items.iter().any(|(idx, _)| idx == index) {
                 return Err(AccountDeltaError::StorageMapDeltaWithoutStorageItemChange(
@@ -195,6 +199,21 @@ impl Deserializable for AccountStorageDelta {
         for _ in 0..num_updated_maps {
             let idx = source.read_u8()?;
             let value = StorageMapDelta::read_from(source)?;
+
+            // make sure index is valid
+            if idx > MAX_MUTABLE_STORAGE_SLOT_IDX {
+                return Err(DeserializationError::InvalidValue(
+                    "immutable storage map updated".to_string(),
+                ));
+            }
+
+            // make sure the same map hasn't been updated before
+            if updated_maps.iter().any(|x: &(u8, StorageMapDelta)| x.0 == idx) {
+                return Err(DeserializationError::InvalidValue(
+                    "storage map updated more than once".to_string(),
+                ));
+            }
+
             updated_maps.push((idx, value));
         }
 
@@ -284,9 +303,23 @@ impl Serializable for StorageMapDelta {
 
 impl Deserializable for StorageMapDelta {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let cleared_leaves = Vec::<_>::read_from(source)?;
-        let updated_leaves = Vec::<_>::read_from(source)?;
-        Ok(Self { cleared_leaves, updated_leaves })
+        // the leaves are read one by one (rather than via Vec::read_from()) so that memory is not
+        // pre-allocated based on the untrusted number of leaves
+        let num_cleared_leaves = source.read_usize()?;
+        let cleared_leaves = (0..num_cleared_leaves)
+            .map(|_| Word::read_from(source))
+            .collect::<Result<Vec<_>, _>>()?;
+        let num_updated_leaves = source.read_usize()?;
+        let updated_leaves = (0..num_updated_leaves)
+            .map(|_| <(Word, Word)>::read_from(source))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let delta = Self { cleared_leaves, updated_leaves };
+        delta
+            .validate()
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        Ok(delta)
     }
 }
 
@@ -360,6 +393,44 @@ mod tests {
 
         let bytes = delta.to_bytes();
         assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // valid map update
+        let map_delta = StorageMapDelta::from(
+            vec![[ONE, ZERO, ZERO, ZERO]],
+            vec![([ONE, ONE, ZERO, ZERO], [ONE, ONE, ONE, ONE])],
+        );
+        let delta = AccountStorageDelta::from_iters(
+            [],
+            [(4, [ONE, ONE, ONE, ONE])],
+            [(4, map_delta.clone())],
+        );
+        assert!(delta.validate().is_ok());
+
+        let bytes = delta.to_bytes();
+        assert_eq!(AccountStorageDelta::read_from_bytes(&bytes), Ok(delta));
+
+        // duplicate in updated maps
+        let delta = AccountStorageDelta::from_iters(
+            [],
+            [(4, [ONE, ONE, ONE, ONE])],
+            [(4, map_delta.clone()), (4, map_delta)],
+        );
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // duplicate leaf in a map update
+        let map_delta = StorageMapDelta::from(
+            vec![[ONE, ZERO, ZERO, ZERO]],
+            vec![([ONE, ZERO, ZERO, ZERO], [ONE, ONE, ONE, ONE])],
+        );
+        let delta =
+            AccountStorageDelta::from_iters([], [(4, [ONE, ONE, ONE, ONE])], [(4, map_delta)]);
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
     }
 
     #[test]
@@ -4,6 +4,7 @@ use super::{
     AccountDeltaError, Asset, ByteReader, ByteWriter, Deserializable, DeserializationError,
     Serializable,
 };
+use crate::assets::{AssetVault, FungibleAsset, NonFungibleAsset};
 
 // ACCOUNT VAULT DELTA
 // ================================================================================================
@@ -79,10 +80,139 @@ impl AccountVaultDelta {
         Ok(())
     }
 
+    /// Checks whether this vault delta is valid and can be applied to the provided vault.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - This vault delta is invalid (see [AccountVaultDelta::validate()]).
+    /// - A removed fungible asset exceeds the balance of this asset in the vault.
+    /// - A removed non-fungible asset is not in the vault.
+    /// - An added fungible asset would bring the balance of this asset in the vault above
+    ///   [FungibleAsset::MAX_AMOUNT].
+    /// - An added non-fungible asset is already in the vault.
+    pub fn validate_against(&self, vault: &AssetVault) -> Result<(), AccountDeltaError> {
+        self.validate()?;
+
+        for &asset in self.removed_assets.iter() {
+            match asset {
+                Asset::Fungible(fungible) => {
+                    let balance = fungible_balance(vault, &fungible);
+                    if balance < fungible.amount() {
+                        return Err(AccountDeltaError::InsufficientVaultBalance { asset, balance });
+                    }
+                },
+                Asset::NonFungible(non_fungible) => {
+                    if !vault.has_non_fungible(&non_fungible) {
+                        return Err(AccountDeltaError::NonFungibleAssetNotInVault(asset));
+                    }
+                },
+            }
+        }
+
+        for &asset in self.added_assets.iter() {
+            match asset {
+                Asset::Fungible(fungible) => {
+                    let amount =
+                        fungible_balance(vault, &fungible) as u128 + fungible.amount() as u128;
+                    if amount > FungibleAsset::MAX_AMOUNT as u128 {
+                        return Err(AccountDeltaError::AssetAmountTooBig(amount));
+                    }
+                },
+                Asset::NonFungible(non_fungible) => {
+                    if vault.has_non_fungible(&non_fungible) {
+                        return Err(AccountDeltaError::NonFungibleAssetAlreadyInVault(asset));
+                    }
+                },
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns true if this vault delta contains no updates.
     pub fn is_empty(&self) -> bool {
         self.added_assets.is_empty() && self.removed_assets.is_empty()
     }
+
+    // TRANSFORMATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a normalized version of this vault delta.
+    ///
+    /// In a normalized vault delta, every asset is referenced at most once: added or removed
+    /// fungible assets of the same token class are merged into a single asset carrying the net
+    /// change of the balance, and non-fungible assets which are both added and removed cancel
+    /// each other out. A normalized vault delta is always valid if it does not contain too many
+    /// assets.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The same non-fungible asset is added or removed more than once in a row.
+    /// - The net change of the balance of a fungible asset is greater than
+    ///   [FungibleAsset::MAX_AMOUNT].
+    pub fn normalize(&self) -> Result<Self, AccountDeltaError> {
+        // net balance changes of fungible assets and net counts of non-fungible assets, where
+        // added assets are counted positively and removed assets are counted negatively
+        let mut fungible_assets: Vec<(FungibleAsset, i128)> = Vec::new();
+        let mut non_fungible_assets: Vec<(NonFungibleAsset, i8)> = Vec::new();
+
+        let updates = self
+            .added_assets
+            .iter()
+            .map(|&asset| (asset, 1))
+            .chain(self.removed_assets.iter().map(|&asset| (asset, -1)));
+
+        for (asset, sign) in updates {
+            match asset {
+                Asset::Fungible(fungible) => {
+                    let amount = sign as i128 * fungible.amount() as i128;
+                    match fungible_assets.iter_mut().find(|(a, _)| a.is_same_token(&fungible)) {
+                        Some((_, net_amount)) => *net_amount += amount,
+                        None => fungible_assets.push((fungible, amount)),
+                    }
+                },
+                Asset::NonFungible(non_fungible) => {
+                    match non_fungible_assets.iter_mut().find(|(a, _)| *a == non_fungible) {
+                        Some((_, count)) if *count == sign => {
+                            return Err(AccountDeltaError::DuplicateVaultUpdate(asset));
+                        },
+                        Some((_, count)) => *count += sign,
+                        None => non_fungible_assets.push((non_fungible, sign)),
+                    }
+                },
+            }
+        }
+
+        let mut normalized = Self::default();
+        for (asset, net_amount) in fungible_assets {
+            if net_amount.unsigned_abs() > FungibleAsset::MAX_AMOUNT as u128 {
+                return Err(AccountDeltaError::AssetAmountTooBig(net_amount.unsigned_abs()));
+            }
+
+            let asset = FungibleAsset::with_sub_token(
+                asset.faucet_id(),
+                asset.sub_token_id(),
+                net_amount.unsigned_abs() as u64,
+            )
+            .expect("fungible asset is well formed");
+
+            match net_amount.signum() {
+                1 => normalized.added_assets.push(asset.into()),
+                -1 => normalized.removed_assets.push(asset.into()),
+                _ => (),
+            }
+        }
+
+        for (asset, count) in non_fungible_assets {
+            match count {
+                1 => normalized.added_assets.push(asset.into()),
+                -1 => normalized.removed_assets.push(asset.into()),
+                _ => (),
+            }
+        }
+
+        Ok(normalized)
+    }
 }
 
 impl Serializable for AccountVaultDelta {
@@ -137,6 +267,16 @@ impl Deserializable for AccountVaultDelta {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the balance of the token class of the provided fungible asset in the provided vault.
+fn fungible_balance(vault: &AssetVault, asset: &FungibleAsset) -> u64 {
+    vault
+        .sub_token_balance(asset.faucet_id(), asset.sub_token_id())
+        .expect("fungible asset is issued by a fungible faucet")
+}
+
 // TESTS
 // ================================================================================================
 
@@ -152,7 +292,8 @@ mod tests {
             testing::build_assets,
             AccountId,
         },
-        assets::{FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        assets::{AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        AccountDeltaError,
     };
 
     #[test]
@@ -222,6 +363,102 @@ mod tests {
         assert!(AccountVaultDelta::read_from_bytes(&bytes).is_err());
     }
 
+    #[test]
+    fn account_vault_delta_normalization() {
+        let ffid = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let nffid = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let fungible = |amount| Asset::from(FungibleAsset::new(ffid, amount).unwrap());
+        let sub_token =
+            |amount| Asset::from(FungibleAsset::with_sub_token(ffid, 1, amount).unwrap());
+        let non_fungible_1: Asset =
+            NonFungibleAsset::new(&NonFungibleAssetDetails::new(nffid, vec![1, 2, 3]).unwrap())
+                .unwrap()
+                .into();
+        let non_fungible_2: Asset =
+            NonFungibleAsset::new(&NonFungibleAssetDetails::new(nffid, vec![4, 5, 6]).unwrap())
+                .unwrap()
+                .into();
+
+        // fungible assets are merged and netted per token class, non-fungible assets which are
+        // both added and removed cancel each other out
+        let delta = AccountVaultDelta::from_iterators(
+            [fungible(10), sub_token(5), non_fungible_1, fungible(20)],
+            [fungible(40), sub_token(5), non_fungible_1, non_fungible_2],
+        );
+        assert!(delta.validate().is_err());
+
+        let normalized = delta.normalize().unwrap();
+        assert_eq!(
+            normalized,
+            AccountVaultDelta::from_iterators([], [fungible(10), non_fungible_2])
+        );
+        assert!(normalized.validate().is_ok());
+        assert_eq!(normalized.normalize().unwrap(), normalized);
+
+        // a non-fungible asset cannot be added twice in a row
+        let delta = AccountVaultDelta::from_iterators([non_fungible_1, non_fungible_1], []);
+        assert_eq!(delta.normalize(), Err(AccountDeltaError::DuplicateVaultUpdate(non_fungible_1)));
+
+        // the net change of a fungible asset must be a valid amount
+        let max_amount = FungibleAsset::MAX_AMOUNT;
+        let delta = AccountVaultDelta::from_iterators([fungible(max_amount), fungible(1)], []);
+        assert_eq!(
+            delta.normalize(),
+            Err(AccountDeltaError::AssetAmountTooBig(max_amount as u128 + 1))
+        );
+    }
+
+    #[test]
+    fn account_vault_delta_validation_against_vault() {
+        let ffid = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let nffid = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let fungible = |amount| Asset::from(FungibleAsset::new(ffid, amount).unwrap());
+        let non_fungible_1: Asset =
+            NonFungibleAsset::new(&NonFungibleAssetDetails::new(nffid, vec![1, 2, 3]).unwrap())
+                .unwrap()
+                .into();
+        let non_fungible_2: Asset =
+            NonFungibleAsset::new(&NonFungibleAssetDetails::new(nffid, vec![4, 5, 6]).unwrap())
+                .unwrap()
+                .into();
+
+        let vault = AssetVault::new(&[fungible(100), non_fungible_1]).unwrap();
+
+        let delta =
+            AccountVaultDelta::from_iterators([non_fungible_2], [fungible(100), non_fungible_1]);
+        assert!(delta.validate_against(&vault).is_ok());
+
+        // removing more than the balance of a fungible asset
+        let delta = AccountVaultDelta::from_iterators([], [fungible(101)]);
+        assert_eq!(
+            delta.validate_against(&vault),
+            Err(AccountDeltaError::InsufficientVaultBalance { asset: fungible(101), balance: 100 })
+        );
+
+        // removing a non-fungible asset which is not in the vault
+        let delta = AccountVaultDelta::from_iterators([], [non_fungible_2]);
+        assert_eq!(
+            delta.validate_against(&vault),
+            Err(AccountDeltaError::NonFungibleAssetNotInVault(non_fungible_2))
+        );
+
+        // adding a non-fungible asset which is already in the vault
+        let delta = AccountVaultDelta::from_iterators([non_fungible_1], []);
+        assert_eq!(
+            delta.validate_against(&vault),
+            Err(AccountDeltaError::NonFungibleAssetAlreadyInVault(non_fungible_1))
+        );
+
+        // adding a fungible asset beyond the maximum amount
+        let delta = AccountVaultDelta::from_iterators([fungible(FungibleAsset::MAX_AMOUNT)], []);
+        assert_eq!(
+            delta.validate_against(&vault),
+            Err(AccountDeltaError::AssetAmountTooBig(FungibleAsset::MAX_AMOUNT as u128 + 100))
+        );
+    }
+
     #[test]
     fn test_serde_account_vault() {
         let (asset_0, asset_1) = build_assets();
@@ -0,0 +1,69 @@
+use super::{AccountError, AccountStorage};
+use crate::assets::FaucetMetadata;
+
+// FUNGIBLE FAUCET STATE
+// ================================================================================================
+
+/// Supply information of a fungible faucet decoded from the storage of the faucet account.
+///
+/// The total issuance is read from the reserved faucet data slot
+/// ([AccountStorage::FAUCET_DATA_SLOT_INDEX]), while the maximum supply is read from the slot at
+/// which the faucet stores its [FaucetMetadata].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FungibleFaucetState {
+    total_issuance: u64,
+    max_supply: u64,
+}
+
+impl FungibleFaucetState {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [FungibleFaucetState] instantiated from the provided values.
+    ///
+    /// # Errors
+    /// Returns an error if the total issuance exceeds the maximum supply.
+    pub fn new(total_issuance: u64, max_supply: u64) -> Result<Self, AccountError> {
+        if total_issuance > max_supply {
+            return Err(AccountError::FaucetIssuanceExceedsMaxSupply {
+                issued: total_issuance,
+                max_supply,
+            });
+        }
+
+        Ok(Self { total_issuance, max_supply })
+    }
+
+    /// Decodes the state of a fungible faucet from the provided account storage, where the faucet
+    /// stores its [FaucetMetadata] at `metadata_slot`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The reserved faucet data slot does not hold a valid total issuance.
+    /// - The metadata slot does not hold valid faucet metadata.
+    /// - The total issuance exceeds the maximum supply.
+    pub fn from_storage(storage: &AccountStorage, metadata_slot: u8) -> Result<Self, AccountError> {
+        let total_issuance = storage.faucet_total_issuance()?;
+        let metadata = FaucetMetadata::try_from(*storage.get_item(metadata_slot))?;
+
+        Self::new(total_issuance, metadata.max_supply())
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the amount of tokens issued by the faucet so far.
+    pub fn total_issuance(&self) -> u64 {
+        self.total_issuance
+    }
+
+    /// Returns the maximum amount of tokens the faucet can issue.
+    pub fn max_supply(&self) -> u64 {
+        self.max_supply
+    }
+
+    /// Returns the amount of tokens the faucet can still issue.
+    pub fn remaining_supply(&self) -> u64 {
+        self.max_supply - self.total_issuance
+    }
+}
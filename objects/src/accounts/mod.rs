@@ -1,30 +1,44 @@
+use alloc::string::ToString;
+
 use crate::{
     assembly::{Assembler, AssemblyContext, ModuleAst},
-    assets::AssetVault,
+    assets::{Asset, AssetVault},
+    crypto::commitment::{CommitmentScheme, DefaultCommitmentScheme},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    AccountError, Digest, Felt, Hasher, Word, ZERO,
+    AccountError, Digest, Felt, Hasher, Word, EMPTY_WORD, ZERO,
 };
 
 pub mod account_id;
 pub use account_id::{
-    AccountId, AccountStorageType, AccountType, ACCOUNT_ISFAUCET_MASK, ACCOUNT_STORAGE_MASK_SHIFT,
-    ACCOUNT_TYPE_MASK_SHIFT,
+    AccountId, AccountIdAnchor, AccountIdVersion, AccountStorageType, AccountType, ParsedAccountId,
+    ACCOUNT_ISFAUCET_MASK, ACCOUNT_STORAGE_MASK_SHIFT, ACCOUNT_TYPE_MASK_SHIFT,
 };
 
 pub mod auth;
 pub use auth::AuthSecretKey;
 
 pub mod code;
-pub use code::AccountCode;
+pub use code::{AccountCode, AccountProcedureInfo};
 
 pub mod delta;
 pub use delta::{AccountDelta, AccountStorageDelta, AccountVaultDelta, StorageMapDelta};
 
+mod faucet_state;
+pub use faucet_state::FungibleFaucetState;
+
+mod network_params;
+pub use network_params::NetworkParams;
+
 mod seed;
-pub use seed::{get_account_seed, get_account_seed_single};
+pub use seed::{
+    get_account_seed, get_account_seed_single, get_account_seed_with_params,
+    get_account_seed_with_rng, get_anchored_account_seed, grind_account_seed, GrindOptions,
+};
 
 mod storage;
-pub use storage::{AccountStorage, SlotItem, StorageMap, StorageSlot, StorageSlotType};
+pub use storage::{
+    AccountStorage, AccountStorageRef, SlotItem, StorageMap, StorageSlot, StorageSlotType,
+};
 
 mod stub;
 pub use stub::AccountStub;
@@ -51,6 +65,7 @@ pub use data::AccountData;
 /// changed). Other components may be mutated throughout the lifetime of the account. However,
 /// account state can be changed only by invoking one of account interface methods.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Account {
     id: AccountId,
     vault: AssetVault,
@@ -157,6 +172,151 @@ impl Account {
         self.nonce == ZERO
     }
 
+    // FAUCET DATA
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the amount of tokens issued so far by this fungible faucet account.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - This account is not a fungible faucet.
+    /// - The reserved faucet storage slot does not hold a valid total issuance.
+    pub fn faucet_total_issuance(&self) -> Result<u64, AccountError> {
+        self.check_account_type(AccountType::FungibleFaucet)?;
+        self.storage.faucet_total_issuance()
+    }
+
+    /// Returns the root of the map of assets issued so far by this non-fungible faucet account.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - This account is not a non-fungible faucet.
+    /// - The reserved faucet storage slot is not a map slot.
+    pub fn faucet_issued_assets_root(&self) -> Result<Digest, AccountError> {
+        self.check_account_type(AccountType::NonFungibleFaucet)?;
+        self.storage.faucet_issued_assets_root()
+    }
+
+    /// Returns the supply information of this fungible faucet account, which stores its
+    /// [FaucetMetadata](crate::assets::FaucetMetadata) at `metadata_slot`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - This account is not a fungible faucet.
+    /// - The storage of this account cannot be decoded into a [FungibleFaucetState].
+    pub fn fungible_faucet_state(
+        &self,
+        metadata_slot: u8,
+    ) -> Result<FungibleFaucetState, AccountError> {
+        self.check_account_type(AccountType::FungibleFaucet)?;
+        FungibleFaucetState::from_storage(&self.storage, metadata_slot)
+    }
+
+    /// Checks that this account is of the expected type.
+    fn check_account_type(&self, expected: AccountType) -> Result<(), AccountError> {
+        let actual = self.account_type();
+        if actual != expected {
+            return Err(AccountError::AccountTypeMismatch { expected, actual });
+        }
+
+        Ok(())
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that the components of this account are consistent with each other.
+    ///
+    /// These checks are performed when an account is deserialized, so that malformed accounts are
+    /// rejected before being used in a transaction.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of procedures of the account code differs from the number of procedures
+    ///   exported by the module of the account code.
+    /// - A storage map is not referenced by any map slot of the account storage.
+    /// - For faucets, the reserved faucet storage slot is not of the type required by the faucet
+    ///   type, or, for fungible faucets, does not hold a valid total issuance.
+    /// - For fungible faucets, the vault holds more units of the asset of the faucet than the
+    ///   faucet has issued, or the amounts of the asset held by the vault overflow.
+    /// - For non-fungible faucets, the vault holds an asset of the faucet which is not recorded in
+    ///   the map of issued assets, if the map is part of the account storage.
+    pub fn validate(&self) -> Result<(), AccountError> {
+        // the code must consist of the procedures exported by its module
+        let module = self.code.module();
+        let num_exports = module.reexported_procs().len()
+            + module.procs().iter().filter(|proc| proc.is_export).count();
+        if num_exports != self.code.num_procedures() {
+            return Err(AccountError::AccountCodeProcedureCountMismatch {
+                expected: num_exports,
+                actual: self.code.num_procedures(),
+            });
+        }
+
+        // each storage map must back a map slot
+        for map in self.storage.maps() {
+            let is_referenced =
+                self.storage.layout().iter().enumerate().any(|(index, slot_type)| {
+                    matches!(slot_type, StorageSlotType::Map { .. })
+                        && self.storage.get_item(index as u8) == map.root()
+                });
+            if !is_referenced {
+                return Err(AccountError::StorageMapNotReferenced(map.root()));
+            }
+        }
+
+        match self.account_type() {
+            AccountType::FungibleFaucet => self.validate_fungible_faucet(),
+            AccountType::NonFungibleFaucet => self.validate_non_fungible_faucet(),
+            AccountType::RegularAccountImmutableCode | AccountType::RegularAccountUpdatableCode => {
+                Ok(())
+            },
+        }
+    }
+
+    /// Checks that the reserved slot of a fungible faucet holds its total issuance, and that the
+    /// vault does not hold more units of the asset of the faucet than it has issued.
+    fn validate_fungible_faucet(&self) -> Result<(), AccountError> {
+        let issued = self.storage.faucet_total_issuance()?;
+        let held = self
+            .vault
+            .assets()
+            .filter_map(|asset| match asset {
+                Asset::Fungible(asset) if asset.faucet_id() == self.id => Some(asset.amount()),
+                _ => None,
+            })
+            .try_fold(0_u64, |held, amount| held.checked_add(amount))
+            .ok_or(AccountError::FaucetAssetAmountOverflow)?;
+        if held > issued {
+            return Err(AccountError::FaucetAssetsExceedIssuance { held, issued });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the reserved slot of a non-fungible faucet is a map slot, and that the assets of
+    /// the faucet held by the vault are recorded in the map of issued assets, if the map is part of
+    /// the account storage.
+    fn validate_non_fungible_faucet(&self) -> Result<(), AccountError> {
+        let issued_root = self.storage.faucet_issued_assets_root()?;
+        let Some(issued_assets) = self.storage.maps().iter().find(|map| map.root() == issued_root)
+        else {
+            return Ok(());
+        };
+
+        for asset in self.vault.assets() {
+            if let Asset::NonFungible(nft) = asset {
+                if nft.faucet_id() == self.id
+                    && issued_assets.get_value(&Word::from(asset).into()) == EMPTY_WORD
+                {
+                    return Err(AccountError::FaucetAssetNotIssued(asset));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -172,13 +332,12 @@ impl Account {
     pub fn apply_delta(&mut self, delta: &AccountDelta) -> Result<(), AccountError> {
         // update vault; we don't check vault delta validity here because AccountDelta can contain
         // only valid vault deltas
-        for &asset in delta.vault().added_assets.iter() {
-            self.vault.add_asset(asset).map_err(AccountError::AssetVaultUpdateError)?;
-        }
-
-        for &asset in delta.vault().removed_assets.iter() {
-            self.vault.remove_asset(asset).map_err(AccountError::AssetVaultUpdateError)?;
-        }
+        self.vault
+            .add_assets(delta.vault().added_assets.iter().copied())
+            .map_err(AccountError::AssetVaultUpdateError)?;
+        self.vault
+            .remove_assets(delta.vault().removed_assets.iter().copied())
+            .map_err(AccountError::AssetVaultUpdateError)?;
 
         // update storage
         self.storage.apply_delta(delta.storage())?;
@@ -243,25 +402,12 @@ impl Deserializable for Account {
         let code = AccountCode::read_from(source)?;
         let nonce = Felt::read_from(source)?;
 
-        Ok(Self::new(id, vault, storage, code, nonce))
-    }
-}
+        let account = Self::new(id, vault, storage, code, nonce);
+        account
+            .validate()
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
 
-#[cfg(feature = "serde")]
-impl serde::Serialize for Account {
-    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let bytes = self.to_bytes();
-        serializer.serialize_bytes(&bytes)
-    }
-}
-
-#[cfg(feature = "serde")]
-impl<'de> serde::Deserialize<'de> for Account {
-    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        use alloc::vec::Vec;
-
-        let bytes: Vec<u8> = <Vec<u8> as serde::Deserialize>::deserialize(deserializer)?;
-        Self::read_from_bytes(&bytes).map_err(serde::de::Error::custom)
+        Ok(account)
     }
 }
 
@@ -285,7 +431,22 @@ pub fn hash_account(
     elements[4..8].copy_from_slice(&*vault_root);
     elements[8..12].copy_from_slice(&*storage_root);
     elements[12..].copy_from_slice(&*code_root);
-    Hasher::hash_elements(&elements)
+    DefaultCommitmentScheme::hash_elements(&elements)
+}
+
+/// Returns the commitment to the state of an account with the specified hash under the specified
+/// salt.
+///
+/// The commitment is computed as hash(account_hash, salt), or is the account hash itself if the
+/// salt is None or an empty word. Recording salted commitments on-chain for off-chain accounts
+/// prevents observers from linking accounts which are in identical states.
+pub fn salt_account_hash(account_hash: Digest, salt: Option<Word>) -> Digest {
+    match salt {
+        Some(salt) if salt != EMPTY_WORD => {
+            DefaultCommitmentScheme::merge(&[account_hash, salt.into()])
+        },
+        _ => account_hash,
+    }
 }
 
 // TESTING
@@ -401,6 +562,195 @@ mod tests {
         assert_eq!(deserialized, account);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_account() {
+        let init_nonce = Felt::new(1);
+        let (asset_0, asset_1) = build_assets();
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let storage_map = StorageMap::with_entries([(
+            Digest::new([Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)]),
+            word,
+        )])
+        .unwrap();
+        let account =
+            build_account(vec![asset_0, asset_1], init_nonce, vec![word], Some(storage_map));
+
+        let json = serde_json::to_string(&account).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["vault"].as_array().unwrap().len(), 2);
+        assert_eq!(value["storage"]["maps"][0].as_array().unwrap().len(), 1);
+
+        let deserialized: Account = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, account);
+    }
+
+    #[test]
+    fn account_validation() {
+        use crate::{
+            accounts::{AccountError, AccountStorage, SlotItem, StorageSlot},
+            assets::{Asset, AssetVault, FungibleAsset},
+            testing::accounts::{
+                account_with_storage_map, basic_wallet_on_chain, fungible_faucet,
+                non_fungible_asset_2, non_fungible_faucet,
+            },
+            ZERO,
+        };
+
+        for account in [
+            basic_wallet_on_chain(),
+            account_with_storage_map(),
+            fungible_faucet(100),
+            non_fungible_faucet(),
+        ] {
+            assert_eq!(account.validate(), Ok(()));
+        }
+
+        // a fungible faucet cannot hold more of its asset than it has issued
+        let faucet = fungible_faucet(100);
+        let asset: Asset = FungibleAsset::new(faucet.id(), 101).unwrap().into();
+        let account = Account::new(
+            faucet.id(),
+            AssetVault::new(&[asset]).unwrap(),
+            faucet.storage().clone(),
+            faucet.code().clone(),
+            Felt::new(1),
+        );
+        assert_eq!(
+            account.validate(),
+            Err(AccountError::FaucetAssetsExceedIssuance { held: 101, issued: 100 })
+        );
+        assert!(Account::read_from_bytes(&account.to_bytes()).is_err());
+
+        // a non-fungible faucet can hold only assets it has issued
+        let faucet = non_fungible_faucet();
+        let issued = non_fungible_asset_2(faucet.id().into());
+        let account = Account::new(
+            faucet.id(),
+            AssetVault::new(&[issued]).unwrap(),
+            faucet.storage().clone(),
+            faucet.code().clone(),
+            Felt::new(1),
+        );
+        assert_eq!(account.validate(), Ok(()));
+
+        let account = Account::new(
+            faucet.id(),
+            AssetVault::default(),
+            fungible_faucet(0).storage().clone(),
+            faucet.code().clone(),
+            Felt::new(1),
+        );
+        assert!(matches!(
+            account.validate(),
+            Err(AccountError::FaucetReservedSlotInvalidType { .. })
+        ));
+
+        // the reserved slot of a fungible faucet must hold the total issuance
+        let issuance = [Felt::new(1), ZERO, ZERO, Felt::new(100)];
+        let storage = AccountStorage::new(
+            vec![SlotItem {
+                index: AccountStorage::FAUCET_DATA_SLOT_INDEX,
+                slot: StorageSlot::new_value(issuance),
+            }],
+            vec![],
+        )
+        .unwrap();
+        let faucet = fungible_faucet(100);
+        let account = Account::new(
+            faucet.id(),
+            AssetVault::default(),
+            storage,
+            faucet.code().clone(),
+            Felt::new(1),
+        );
+        assert_eq!(account.validate(), Err(AccountError::FaucetReservedSlotInvalidValue(issuance)));
+
+        // storage maps must be referenced by a map slot
+        let map = StorageMap::with_entries([(Digest::default(), [Felt::new(1); 4])]).unwrap();
+        let storage = AccountStorage::new(
+            vec![SlotItem {
+                index: 0,
+                slot: StorageSlot::new_map(StorageMap::new().root().into()),
+            }],
+            vec![map.clone()],
+        )
+        .unwrap();
+        let wallet = basic_wallet_on_chain();
+        let account = Account::new(
+            wallet.id(),
+            AssetVault::default(),
+            storage,
+            wallet.code().clone(),
+            Felt::new(1),
+        );
+        assert_eq!(account.validate(), Err(AccountError::StorageMapNotReferenced(map.root())));
+    }
+
+    #[test]
+    fn faucet_data_accessors() {
+        use crate::{
+            accounts::{AccountError, AccountStorage, AccountType, SlotItem, StorageSlot},
+            assets::{AssetVault, FaucetMetadata, TokenSymbol},
+            testing::accounts::{basic_wallet_on_chain, fungible_faucet, non_fungible_faucet},
+            ZERO,
+        };
+
+        let faucet = fungible_faucet(100);
+        assert_eq!(faucet.faucet_total_issuance(), Ok(100));
+        assert_eq!(
+            faucet.faucet_issued_assets_root(),
+            Err(AccountError::AccountTypeMismatch {
+                expected: AccountType::NonFungibleFaucet,
+                actual: AccountType::FungibleFaucet,
+            })
+        );
+
+        let faucet = non_fungible_faucet();
+        let issued_root = faucet.storage().maps()[0].root();
+        assert_eq!(faucet.faucet_issued_assets_root(), Ok(issued_root));
+        assert!(faucet.faucet_total_issuance().is_err());
+        assert!(basic_wallet_on_chain().faucet_total_issuance().is_err());
+
+        // the state of a fungible faucet combines its total issuance with its token metadata
+        let metadata = FaucetMetadata::new(TokenSymbol::new("POL").unwrap(), 8, 1000).unwrap();
+        let build_faucet = |issuance: u64| {
+            let faucet = fungible_faucet(issuance);
+            let storage = AccountStorage::new(
+                vec![
+                    SlotItem {
+                        index: 1,
+                        slot: StorageSlot::new_value(metadata.into()),
+                    },
+                    SlotItem {
+                        index: AccountStorage::FAUCET_DATA_SLOT_INDEX,
+                        slot: StorageSlot::new_value([ZERO, ZERO, ZERO, Felt::new(issuance)]),
+                    },
+                ],
+                vec![],
+            )
+            .unwrap();
+            Account::new(
+                faucet.id(),
+                AssetVault::default(),
+                storage,
+                faucet.code().clone(),
+                Felt::new(1),
+            )
+        };
+
+        let state = build_faucet(100).fungible_faucet_state(1).unwrap();
+        assert_eq!(state.total_issuance(), 100);
+        assert_eq!(state.max_supply(), 1000);
+        assert_eq!(state.remaining_supply(), 900);
+
+        assert_eq!(
+            build_faucet(1001).fungible_faucet_state(1),
+            Err(AccountError::FaucetIssuanceExceedsMaxSupply { issued: 1001, max_supply: 1000 })
+        );
+        assert!(build_faucet(100).fungible_faucet_state(2).is_err());
+    }
+
     #[test]
     fn test_serde_account_delta() {
         let final_nonce = Felt::new(2);
@@ -0,0 +1,162 @@
+use super::{AccountError, AccountType};
+
+// NETWORK PARAMETERS
+// ================================================================================================
+
+/// Parameters of the account creation rules of a Miden network.
+///
+/// The parameters specify the proof-of-work difficulty of account IDs derived via proof-of-work,
+/// i.e., the minimum number of trailing zeros in the last element of the seed digest of a new
+/// account (see [AccountId::new_with_params()](super::AccountId::new_with_params)).
+///
+/// The difficulty is not fixed by the transaction kernel: it is provided to the kernel as a public
+/// input of every transaction, and thus it must be configured consistently for the executor, the
+/// prover and the verifier of a network. This allows development networks to lower the difficulty
+/// without rebuilding the kernel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetworkParams {
+    regular_account_seed_digest_min_trailing_zeros: u32,
+    faucet_seed_digest_min_trailing_zeros: u32,
+}
+
+impl NetworkParams {
+    /// The maximum number of trailing zeros which can be required of a seed digest.
+    ///
+    /// The transaction kernel checks the proof-of-work against the lower 32 bits of the last
+    /// element of the seed digest, using a modulus which must fit into 32 bits.
+    pub const MAX_SEED_DIGEST_MIN_TRAILING_ZEROS: u32 = 31;
+
+    /// Parameters of the production network.
+    ///
+    /// Note: The account id includes 4 bits of metadata, these bits determine the account type
+    /// (normal account, fungible token, non-fungible token), the storage type (on/off chain), and
+    /// for the normal accounts if the code is updatable or not. These metadata bits are also
+    /// checked by the PoW and add to the total work defined below.
+    pub const MAINNET: Self = Self {
+        regular_account_seed_digest_min_trailing_zeros: 23,
+        faucet_seed_digest_min_trailing_zeros: 31,
+    };
+
+    /// Parameters which do not require any proof-of-work, i.e., under which any seed is valid as
+    /// long as it derives the account ID.
+    pub const NO_PROOF_OF_WORK: Self = Self {
+        regular_account_seed_digest_min_trailing_zeros: 0,
+        faucet_seed_digest_min_trailing_zeros: 0,
+    };
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [NetworkParams] requiring the specified minimum numbers of trailing zeros in
+    /// the seed digests of regular accounts and of faucets.
+    ///
+    /// # Errors
+    /// Returns an error if any of the numbers exceeds [Self::MAX_SEED_DIGEST_MIN_TRAILING_ZEROS].
+    pub fn new(
+        regular_account_seed_digest_min_trailing_zeros: u32,
+        faucet_seed_digest_min_trailing_zeros: u32,
+    ) -> Result<Self, AccountError> {
+        for trailing_zeros in [
+            regular_account_seed_digest_min_trailing_zeros,
+            faucet_seed_digest_min_trailing_zeros,
+        ] {
+            if trailing_zeros > Self::MAX_SEED_DIGEST_MIN_TRAILING_ZEROS {
+                return Err(AccountError::SeedDigestDifficultyTooHigh {
+                    max: Self::MAX_SEED_DIGEST_MIN_TRAILING_ZEROS,
+                    actual: trailing_zeros,
+                });
+            }
+        }
+
+        Ok(Self {
+            regular_account_seed_digest_min_trailing_zeros,
+            faucet_seed_digest_min_trailing_zeros,
+        })
+    }
+
+    /// Returns parameters with a low proof-of-work difficulty, suitable for tests and development
+    /// networks.
+    pub const fn testing() -> Self {
+        Self {
+            regular_account_seed_digest_min_trailing_zeros: 5,
+            faucet_seed_digest_min_trailing_zeros: 6,
+        }
+    }
+
+    /// Returns the parameters whose difficulty is satisfied by the seeds found by the account
+    /// seed generators which are not provided with explicit parameters, and required by
+    /// [AccountId::new()](super::AccountId::new).
+    ///
+    /// These are [NetworkParams::MAINNET], unless the `testing` feature is enabled, in which case
+    /// they are [NetworkParams::testing()] so that test fixtures can derive account IDs cheaply.
+    pub(crate) const fn account_seed_default() -> Self {
+        #[cfg(not(any(feature = "testing", test)))]
+        return Self::MAINNET;
+        #[cfg(any(feature = "testing", test))]
+        return Self::testing();
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the minimum number of trailing zeros in the seed digest of a regular account.
+    pub fn regular_account_seed_digest_min_trailing_zeros(&self) -> u32 {
+        self.regular_account_seed_digest_min_trailing_zeros
+    }
+
+    /// Returns the minimum number of trailing zeros in the seed digest of a faucet.
+    pub fn faucet_seed_digest_min_trailing_zeros(&self) -> u32 {
+        self.faucet_seed_digest_min_trailing_zeros
+    }
+
+    /// Returns the minimum number of trailing zeros in the seed digest of an account of the
+    /// specified type.
+    pub fn seed_digest_min_trailing_zeros(&self, account_type: AccountType) -> u32 {
+        match account_type {
+            AccountType::RegularAccountImmutableCode | AccountType::RegularAccountUpdatableCode => {
+                self.regular_account_seed_digest_min_trailing_zeros
+            },
+            AccountType::FungibleFaucet | AccountType::NonFungibleFaucet => {
+                self.faucet_seed_digest_min_trailing_zeros
+            },
+        }
+    }
+}
+
+impl Default for NetworkParams {
+    /// Returns [NetworkParams::MAINNET].
+    fn default() -> Self {
+        Self::MAINNET
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountError, AccountType, NetworkParams};
+
+    #[test]
+    fn network_params_difficulty() {
+        let params = NetworkParams::new(0, 12).unwrap();
+        assert_eq!(
+            params.seed_digest_min_trailing_zeros(AccountType::RegularAccountUpdatableCode),
+            0
+        );
+        assert_eq!(params.seed_digest_min_trailing_zeros(AccountType::NonFungibleFaucet), 12);
+
+        assert_eq!(
+            NetworkParams::new(23, 32),
+            Err(AccountError::SeedDigestDifficultyTooHigh { max: 31, actual: 32 })
+        );
+        assert_eq!(
+            NetworkParams::new(
+                NetworkParams::MAINNET.regular_account_seed_digest_min_trailing_zeros(),
+                NetworkParams::MAINNET.faucet_seed_digest_min_trailing_zeros()
+            ),
+            Ok(NetworkParams::MAINNET)
+        );
+        assert_eq!(NetworkParams::default(), NetworkParams::MAINNET);
+    }
+}
@@ -1,4 +1,5 @@
 use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 #[cfg(feature = "concurrent")]
 use std::{
     sync::{
@@ -8,23 +9,50 @@ use std::{
     thread::{self, spawn},
 };
 
+use miden_crypto::rand::FeltRng;
+
 use super::{
-    account_id::compute_digest, AccountError, AccountId, AccountStorageType, AccountType, Digest,
-    Felt, Word,
+    account_id::{compute_anchored_digest, compute_digest},
+    AccountError, AccountId, AccountIdAnchor, AccountIdVersion, AccountStorageType, AccountType,
+    Digest, Felt, NetworkParams, Word,
 };
 
 // SEED GENERATORS
 // --------------------------------------------------------------------------------------------
 
 /// Finds and returns a seed suitable for creating an account ID for the specified account type
-/// using the provided initial seed as a starting point. Using multi-threading.
-#[cfg(feature = "concurrent")]
+/// using the provided initial seed as a starting point.
+///
+/// The seed satisfies the proof-of-work difficulty of [NetworkParams::MAINNET], or of
+/// [NetworkParams::testing()] if the `testing` feature is enabled.
 pub fn get_account_seed(
     init_seed: [u8; 32],
     account_type: AccountType,
     storage_type: AccountStorageType,
     code_root: Digest,
     storage_root: Digest,
+) -> Result<Word, AccountError> {
+    get_account_seed_with_params(
+        init_seed,
+        account_type,
+        storage_type,
+        code_root,
+        storage_root,
+        &NetworkParams::account_seed_default(),
+    )
+}
+
+/// Finds and returns a seed suitable for creating an account ID for the specified account type
+/// on the network with the provided parameters, using the provided initial seed as a starting
+/// point. Using multi-threading.
+#[cfg(feature = "concurrent")]
+pub fn get_account_seed_with_params(
+    init_seed: [u8; 32],
+    account_type: AccountType,
+    storage_type: AccountStorageType,
+    code_root: Digest,
+    storage_root: Digest,
+    params: &NetworkParams,
 ) -> Result<Word, AccountError> {
     let thread_count = thread::available_parallelism().map_or(1, |v| v.get());
 
@@ -36,6 +64,7 @@ pub fn get_account_seed(
         let stop = Arc::clone(&stop);
         let mut init_seed = init_seed;
         init_seed[0] = init_seed[0].wrapping_add(count as u8);
+        let params = *params;
         spawn(move || {
             get_account_seed_inner(
                 send,
@@ -45,6 +74,7 @@ pub fn get_account_seed(
                 storage_type,
                 code_root,
                 storage_root,
+                params,
             )
         });
     }
@@ -67,6 +97,7 @@ pub fn get_account_seed(
 }
 
 #[cfg(feature = "concurrent")]
+#[allow(clippy::too_many_arguments)]
 pub fn get_account_seed_inner(
     send: Sender<(Digest, Word)>,
     stop: Arc<RwLock<bool>>,
@@ -75,6 +106,7 @@ pub fn get_account_seed_inner(
     storage_type: AccountStorageType,
     code_root: Digest,
     storage_root: Digest,
+    params: NetworkParams,
 ) {
     let init_seed: Vec<[u8; 8]> =
         init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
@@ -102,10 +134,11 @@ pub fn get_account_seed_inner(
         }
 
         // check if the seed satisfies the specified account type
-        if AccountId::validate_seed_digest(&current_digest).is_ok() {
+        if AccountId::validate_seed_digest(&current_digest, account_type, &params).is_ok() {
             if let Ok(account_id) = AccountId::try_from(current_digest[0]) {
                 if account_id.account_type() == account_type
                     && account_id.storage_type() == storage_type
+                    && account_id.version() == AccountIdVersion::ProofOfWork
                 {
                     #[cfg(feature = "log")]
                     log.done(current_digest, current_seed, account_id);
@@ -120,25 +153,53 @@ pub fn get_account_seed_inner(
     }
 }
 
+/// Finds and returns a seed suitable for creating an account ID for the specified account type
+/// on the network with the provided parameters, using the provided initial seed as a starting
+/// point.
 #[cfg(not(feature = "concurrent"))]
-pub fn get_account_seed(
+pub fn get_account_seed_with_params(
     init_seed: [u8; 32],
     account_type: AccountType,
     storage_type: AccountStorageType,
     code_root: Digest,
     storage_root: Digest,
+    params: &NetworkParams,
 ) -> Result<Word, AccountError> {
-    get_account_seed_single(init_seed, account_type, storage_type, code_root, storage_root)
+    find_account_seed(init_seed, account_type, storage_type, code_root, storage_root, params)
 }
 
 /// Finds and returns a seed suitable for creating an account ID for the specified account type
 /// using the provided initial seed as a starting point. Using a single thread.
+///
+/// The seed satisfies the proof-of-work difficulty of [NetworkParams::MAINNET], or of
+/// [NetworkParams::testing()] if the `testing` feature is enabled.
 pub fn get_account_seed_single(
     init_seed: [u8; 32],
     account_type: AccountType,
     storage_type: AccountStorageType,
     code_root: Digest,
     storage_root: Digest,
+) -> Result<Word, AccountError> {
+    find_account_seed(
+        init_seed,
+        account_type,
+        storage_type,
+        code_root,
+        storage_root,
+        &NetworkParams::account_seed_default(),
+    )
+}
+
+/// Finds and returns a seed suitable for creating an account ID for the specified account type
+/// on the network with the provided parameters, using the provided initial seed as a starting
+/// point. Using a single thread.
+fn find_account_seed(
+    init_seed: [u8; 32],
+    account_type: AccountType,
+    storage_type: AccountStorageType,
+    code_root: Digest,
+    storage_root: Digest,
+    params: &NetworkParams,
 ) -> Result<Word, AccountError> {
     let init_seed: Vec<[u8; 8]> =
         init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
@@ -159,10 +220,11 @@ pub fn get_account_seed_single(
         log.iteration(current_digest, current_seed);
 
         // check if the seed satisfies the specified account type
-        if AccountId::validate_seed_digest(&current_digest).is_ok() {
+        if AccountId::validate_seed_digest(&current_digest, account_type, params).is_ok() {
             if let Ok(account_id) = AccountId::try_from(current_digest[0]) {
                 if account_id.account_type() == account_type
                     && account_id.storage_type() == storage_type
+                    && account_id.version() == AccountIdVersion::ProofOfWork
                 {
                     #[cfg(feature = "log")]
                     log.done(current_digest, current_seed, account_id);
@@ -176,6 +238,257 @@ pub fn get_account_seed_single(
     }
 }
 
+/// Finds and returns a seed suitable for creating an account ID for the specified account type
+/// using an initial seed drawn from the provided RNG as a starting point.
+///
+/// The search is always performed on a single thread, and thus the returned seed depends only on
+/// the state of the RNG; this makes the function suitable for reproducible tests and benchmarks.
+pub fn get_account_seed_with_rng<R: FeltRng>(
+    rng: &mut R,
+    account_type: AccountType,
+    storage_type: AccountStorageType,
+    code_root: Digest,
+    storage_root: Digest,
+) -> Result<Word, AccountError> {
+    let mut init_seed = [0_u8; 32];
+    for (bytes, element) in init_seed.chunks_exact_mut(8).zip(rng.draw_word()) {
+        bytes.copy_from_slice(&element.as_int().to_le_bytes());
+    }
+
+    get_account_seed_single(init_seed, account_type, storage_type, code_root, storage_root)
+}
+
+/// Finds and returns a seed suitable for creating an anchored account ID for the specified
+/// anchor, account type and storage type on the network with the provided parameters, using the
+/// provided initial seed as a starting point. Using a single thread.
+///
+/// See [AccountId::new_anchored()] for details on anchored account IDs.
+pub fn get_anchored_account_seed(
+    init_seed: [u8; 32],
+    anchor: AccountIdAnchor,
+    account_type: AccountType,
+    storage_type: AccountStorageType,
+    code_root: Digest,
+    storage_root: Digest,
+    params: &NetworkParams,
+) -> Result<Word, AccountError> {
+    let init_seed: Vec<[u8; 8]> =
+        init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
+    let mut current_seed: Word = [
+        Felt::new(u64::from_le_bytes(init_seed[0])),
+        Felt::new(u64::from_le_bytes(init_seed[1])),
+        Felt::new(u64::from_le_bytes(init_seed[2])),
+        Felt::new(u64::from_le_bytes(init_seed[3])),
+    ];
+
+    // loop until we have a seed whose digest satisfies the proof-of-work requirement, and which
+    // results in a valid account ID
+    loop {
+        let current_digest =
+            compute_anchored_digest(current_seed, code_root, storage_root, anchor.block_hash());
+        if AccountId::validate_seed_digest(&current_digest, account_type, params).is_ok()
+            && AccountId::new_anchored_with_params(
+                current_seed,
+                anchor,
+                account_type,
+                storage_type,
+                code_root,
+                storage_root,
+                params,
+            )
+            .is_ok()
+        {
+            return Ok(current_seed);
+        }
+        current_seed = current_digest.into();
+    }
+}
+
+// VANITY SEED GENERATORS
+// --------------------------------------------------------------------------------------------
+
+/// The number of seeds each search thread tries between checks for cancellation.
+const GRIND_CANCELLATION_CHECK_INTERVAL: u64 = 10_000;
+
+/// Options of a search for an account ID satisfying a predicate (see [grind_account_seed()]).
+///
+/// By default, the search requires the proof-of-work difficulty of the default [NetworkParams],
+/// does not report its progress, and cannot be cancelled.
+#[derive(Clone, Copy, Default)]
+pub struct GrindOptions<'a> {
+    network_params: NetworkParams,
+    progress: Option<(u64, &'a (dyn Fn(u64) + Sync))>,
+    cancel: Option<&'a AtomicBool>,
+}
+
+impl<'a> GrindOptions<'a> {
+    /// Returns new [GrindOptions] with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [NetworkParams] specifying the proof-of-work difficulty the found seed must
+    /// satisfy.
+    pub fn with_network_params(mut self, network_params: NetworkParams) -> Self {
+        self.network_params = network_params;
+        self
+    }
+
+    /// Sets a callback which is invoked with the total number of seeds tried so far, every time a
+    /// search thread has tried another `interval` seeds.
+    ///
+    /// # Panics
+    /// Panics if `interval` is zero.
+    pub fn with_progress(mut self, interval: u64, callback: &'a (dyn Fn(u64) + Sync)) -> Self {
+        assert!(interval > 0, "progress interval must be greater than zero");
+        self.progress = Some((interval, callback));
+        self
+    }
+
+    /// Sets a flag which cancels the search once it is set to true.
+    pub fn with_cancellation(mut self, cancel: &'a AtomicBool) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+}
+
+/// Finds and returns a seed suitable for creating an account ID for the specified account type
+/// and storage type, such that the ID additionally satisfies the provided predicate. This can be
+/// used to search for vanity account IDs, e.g., IDs whose hex encoding starts with a given
+/// prefix.
+///
+/// The search starts from the provided initial seed and is performed by `parallelism` threads if
+/// the `concurrent` feature is enabled; a `parallelism` of zero uses all available cores. Without
+/// the `concurrent` feature, the search is always performed on a single thread.
+///
+/// Every bit of the ID constrained by the predicate doubles the expected duration of the search.
+///
+/// # Errors
+/// Returns an error if the search was cancelled via [GrindOptions::with_cancellation()].
+#[allow(clippy::too_many_arguments)]
+pub fn grind_account_seed<P>(
+    init_seed: [u8; 32],
+    account_type: AccountType,
+    storage_type: AccountStorageType,
+    code_root: Digest,
+    storage_root: Digest,
+    predicate: P,
+    parallelism: usize,
+    options: &GrindOptions,
+) -> Result<Word, AccountError>
+where
+    P: Fn(AccountId) -> bool + Sync,
+{
+    let search = GrindSearch {
+        account_type,
+        storage_type,
+        code_root,
+        storage_root,
+        predicate: &predicate,
+        options,
+        attempts: AtomicU64::new(0),
+        done: AtomicBool::new(false),
+    };
+
+    #[cfg(feature = "concurrent")]
+    let seed = {
+        let thread_count = match parallelism {
+            0 => thread::available_parallelism().map_or(1, |v| v.get()),
+            parallelism => parallelism,
+        };
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..thread_count)
+                .map(|count| {
+                    let mut init_seed = init_seed;
+                    init_seed[0] = init_seed[0].wrapping_add(count as u8);
+                    let search = &search;
+                    scope.spawn(move || search.run(init_seed))
+                })
+                .collect();
+
+            // Safety: the search threads do not panic unless the predicate or callback does
+            handles.into_iter().filter_map(|handle| handle.join().unwrap()).next()
+        })
+    };
+
+    #[cfg(not(feature = "concurrent"))]
+    let seed = {
+        let _ = parallelism;
+        search.run(init_seed)
+    };
+
+    seed.ok_or(AccountError::SeedGrindingCancelled)
+}
+
+/// The state of a search for an account ID satisfying a predicate, shared by all search threads.
+struct GrindSearch<'a, P> {
+    account_type: AccountType,
+    storage_type: AccountStorageType,
+    code_root: Digest,
+    storage_root: Digest,
+    predicate: &'a P,
+    options: &'a GrindOptions<'a>,
+    attempts: AtomicU64,
+    done: AtomicBool,
+}
+
+impl<P: Fn(AccountId) -> bool> GrindSearch<'_, P> {
+    /// Searches for a seed starting from the provided initial seed, and returns None if the search
+    /// was cancelled or another thread found a seed first.
+    fn run(&self, init_seed: [u8; 32]) -> Option<Word> {
+        let init_seed: Vec<[u8; 8]> =
+            init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
+        let mut current_seed: Word = [
+            Felt::new(u64::from_le_bytes(init_seed[0])),
+            Felt::new(u64::from_le_bytes(init_seed[1])),
+            Felt::new(u64::from_le_bytes(init_seed[2])),
+            Felt::new(u64::from_le_bytes(init_seed[3])),
+        ];
+        let mut current_digest = compute_digest(current_seed, self.code_root, self.storage_root);
+
+        let mut count: u64 = 0;
+        loop {
+            count += 1;
+            if count % GRIND_CANCELLATION_CHECK_INTERVAL == 0 && self.is_stopped() {
+                return None;
+            }
+            if let Some((interval, callback)) = self.options.progress {
+                if count % interval == 0 {
+                    callback(self.attempts.fetch_add(interval, Ordering::Relaxed) + interval);
+                }
+            }
+
+            if AccountId::validate_seed_digest(
+                &current_digest,
+                self.account_type,
+                &self.options.network_params,
+            )
+            .is_ok()
+            {
+                if let Ok(account_id) = AccountId::try_from(current_digest[0]) {
+                    if account_id.account_type() == self.account_type
+                        && account_id.storage_type() == self.storage_type
+                        && account_id.version() == AccountIdVersion::ProofOfWork
+                        && (self.predicate)(account_id)
+                    {
+                        self.done.store(true, Ordering::Relaxed);
+                        return Some(current_seed);
+                    }
+                }
+            }
+            current_seed = current_digest.into();
+            current_digest = compute_digest(current_seed, self.code_root, self.storage_root);
+        }
+    }
+
+    /// Returns true if the search was cancelled or a seed was found.
+    fn is_stopped(&self) -> bool {
+        self.done.load(Ordering::Relaxed)
+            || self.options.cancel.is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+    }
+}
+
 #[cfg(feature = "log")]
 mod log {
     use alloc::string::String;
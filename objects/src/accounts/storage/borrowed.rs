@@ -0,0 +1,225 @@
+use alloc::string::ToString;
+
+use super::{
+    AccountError, AccountStorage, ByteReader, Deserializable, DeserializationError, Digest, Felt,
+    Hasher, StorageSlotType, Word, ZERO,
+};
+use crate::borrowed::BorrowedReader;
+
+// ACCOUNT STORAGE REF
+// ================================================================================================
+
+/// A borrowed view of serialized [AccountStorage].
+///
+/// The view references the serialized storage slots directly and skips over the storage maps,
+/// and thus, can be used to inspect account storage (e.g., to compute its commitment or read a few
+/// slots) without building the storage tree and the storage maps.
+///
+/// The slot data is validated when the view is created. Storage maps are validated only to the
+/// extent needed to skip over them; their entries are validated when the view is converted into
+/// [AccountStorage] via [AccountStorageRef::to_account_storage()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountStorageRef<'a> {
+    bytes: &'a [u8],
+    complex_types: &'a [u8],
+    filled_slots: &'a [u8],
+    num_maps: usize,
+}
+
+impl<'a> AccountStorageRef<'a> {
+    /// Number of bytes in a serialized slot type entry: slot index and slot type.
+    const SLOT_TYPE_ENTRY_SIZE: usize = 3;
+
+    /// Number of bytes in a serialized slot value entry: slot index and slot value.
+    const SLOT_VALUE_ENTRY_SIZE: usize = 33;
+
+    /// Number of bytes in a serialized storage map entry: key and value.
+    const MAP_ENTRY_SIZE: usize = 64;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a view of the [AccountStorage] serialized at the start of the provided bytes.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The bytes do not start with a valid encoding of account storage.
+    /// - A value is provided for the reserved layout commitment slot or more than once for the
+    ///   same slot.
+    /// - The number of storage maps is greater than the number of map slots.
+    pub fn read_from_bytes(bytes: &'a [u8]) -> Result<Self, DeserializationError> {
+        Self::read_borrowed(&mut BorrowedReader::new(bytes))
+    }
+
+    /// Returns a view of the [AccountStorage] serialized at the current position of the provided
+    /// reader.
+    pub(crate) fn read_borrowed(
+        source: &mut BorrowedReader<'a>,
+    ) -> Result<Self, DeserializationError> {
+        let start = source.position();
+
+        let num_complex_types = source.read_u8()? as usize;
+        let complex_types = source.read_borrowed(num_complex_types * Self::SLOT_TYPE_ENTRY_SIZE)?;
+        for entry in complex_types.chunks_exact(Self::SLOT_TYPE_ENTRY_SIZE) {
+            StorageSlotType::try_from(u16::from_le_bytes([entry[1], entry[2]]))
+                .map_err(DeserializationError::InvalidValue)?;
+        }
+
+        let num_filled_slots = source.read_u8()? as usize;
+        let filled_slots = source.read_borrowed(num_filled_slots * Self::SLOT_VALUE_ENTRY_SIZE)?;
+        let mut filled = [false; AccountStorage::NUM_STORAGE_SLOTS];
+        for entry in filled_slots.chunks_exact(Self::SLOT_VALUE_ENTRY_SIZE) {
+            let index = entry[0];
+            if index == AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX {
+                return Err(invalid_value(AccountError::StorageSlotIsReserved(index)));
+            }
+            if core::mem::replace(&mut filled[index as usize], true) {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "duplicate value for storage slot {index}"
+                )));
+            }
+            Word::read_from_bytes(&entry[1..])?;
+        }
+
+        let num_maps = source.read_u8()? as usize;
+        for _ in 0..num_maps {
+            let num_entries = source.read_usize()?;
+            let len = num_entries
+                .checked_mul(Self::MAP_ENTRY_SIZE)
+                .ok_or(DeserializationError::UnexpectedEOF)?;
+            source.read_borrowed(len)?;
+        }
+
+        let storage = Self {
+            bytes: source.consumed_since(start),
+            complex_types,
+            filled_slots,
+            num_maps,
+        };
+
+        let num_map_slots = storage
+            .layout()
+            .iter()
+            .filter(|slot_type| matches!(slot_type, StorageSlotType::Map { .. }))
+            .count();
+        if num_maps > num_map_slots {
+            return Err(invalid_value(AccountError::StorageMapToManyMaps {
+                expected: num_map_slots,
+                actual: num_maps,
+            }));
+        }
+
+        Ok(storage)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a commitment to the storage.
+    ///
+    /// The commitment is the same as the root of the corresponding [AccountStorage], and is
+    /// computed without allocating memory for the storage tree.
+    pub fn root(&self) -> Digest {
+        let mut nodes = [Digest::default(); AccountStorage::NUM_STORAGE_SLOTS];
+        for (index, value) in self.filled_slots() {
+            nodes[index as usize] = value.into();
+        }
+        nodes[AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX as usize] = self.layout_commitment();
+
+        // hash the leaves of the storage tree pairwise up to the root
+        let mut len = nodes.len();
+        while len > 1 {
+            for i in 0..len / 2 {
+                nodes[i] = Hasher::merge(&[nodes[2 * i], nodes[2 * i + 1]]);
+            }
+            len /= 2;
+        }
+
+        nodes[0]
+    }
+
+    /// Returns an item from the storage at the specified index.
+    ///
+    /// If the item is not present in the storage, [ZERO; 4] is returned.
+    pub fn get_item(&self, index: u8) -> Digest {
+        if index == AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX {
+            return self.layout_commitment();
+        }
+
+        self.filled_slots()
+            .find(|&(slot, _)| slot == index)
+            .map(|(_, value)| value.into())
+            .unwrap_or_default()
+    }
+
+    /// Returns the type of the storage slot at the specified index.
+    pub fn slot_type(&self, index: u8) -> StorageSlotType {
+        self.layout()[index as usize]
+    }
+
+    /// Returns a commitment to the storage layout.
+    pub fn layout_commitment(&self) -> Digest {
+        let mut elements = [ZERO; AccountStorage::NUM_STORAGE_SLOTS];
+        for (element, slot_type) in elements.iter_mut().zip(self.layout()) {
+            *element = Felt::from(slot_type);
+        }
+        Hasher::hash_elements(&elements)
+    }
+
+    /// Returns the number of storage maps.
+    pub fn num_maps(&self) -> usize {
+        self.num_maps
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns [AccountStorage] built from the data of this view.
+    ///
+    /// # Errors
+    /// Returns an error if any of the storage maps is invalid.
+    pub fn to_account_storage(&self) -> Result<AccountStorage, DeserializationError> {
+        AccountStorage::read_from_bytes(self.bytes)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the types of all storage slots.
+    ///
+    /// As for [AccountStorage], non-default slot types are retained only for filled slots, and if
+    /// several types are provided for the same slot, the last one is used.
+    fn layout(&self) -> [StorageSlotType; AccountStorage::NUM_STORAGE_SLOTS] {
+        let mut filled = [false; AccountStorage::NUM_STORAGE_SLOTS];
+        for (index, _) in self.filled_slots() {
+            filled[index as usize] = true;
+        }
+
+        let mut layout = [StorageSlotType::default(); AccountStorage::NUM_STORAGE_SLOTS];
+        for entry in self.complex_types.chunks_exact(Self::SLOT_TYPE_ENTRY_SIZE) {
+            if filled[entry[0] as usize] {
+                layout[entry[0] as usize] =
+                    StorageSlotType::try_from(u16::from_le_bytes([entry[1], entry[2]]))
+                        .expect("slot type was validated");
+            }
+        }
+        layout[AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX as usize] =
+            StorageSlotType::Value { value_arity: 64 };
+
+        layout
+    }
+
+    /// Returns an iterator over the indexes and values of all filled slots.
+    fn filled_slots(&self) -> impl Iterator<Item = (u8, Word)> + 'a {
+        self.filled_slots.chunks_exact(Self::SLOT_VALUE_ENTRY_SIZE).map(|entry| {
+            let value = Word::read_from_bytes(&entry[1..]).expect("slot value was validated");
+            (entry[0], value)
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn invalid_value(err: AccountError) -> DeserializationError {
+    DeserializationError::InvalidValue(err.to_string())
+}
@@ -1,3 +1,5 @@
+use alloc::{string::ToString, vec::Vec};
+
 use super::{
     AccountError, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, Word,
 };
@@ -12,7 +14,6 @@ use crate::crypto::{
 /// Account storage map is a Sparse Merkle Tree of depth 64. It can be used to store more data as
 /// there is in plain usage of the storage slots. The root of the SMT consumes one account storage
 /// slot.
-#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StorageMap {
     map: Smt,
@@ -110,8 +111,33 @@ impl Serializable for StorageMap {
 
 impl Deserializable for StorageMap {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let smt = Smt::read_from(source)?;
-        Ok(StorageMap { map: smt })
+        // the entries are read one by one (rather than via Smt::read_from()) so that memory is not
+        // pre-allocated based on the untrusted number of entries
+        let num_entries = source.read_usize()?;
+        let entries = (0..num_entries)
+            .map(|_| Ok((RpoDigest::read_from(source)?, Word::read_from(source)?)))
+            .collect::<Result<Vec<_>, DeserializationError>>()?;
+        let map = Smt::with_entries(entries)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        Ok(StorageMap { map })
+    }
+}
+
+/// A storage map is serialized as a list of its `(key, value)` entries.
+#[cfg(feature = "serde")]
+impl serde::Serialize for StorageMap {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.entries())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for StorageMap {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries =
+            <alloc::vec::Vec<(RpoDigest, Word)> as serde::Deserialize>::deserialize(deserializer)?;
+        Self::with_entries(entries).map_err(serde::de::Error::custom)
     }
 }
 
@@ -6,6 +6,7 @@ use super::{
 };
 use crate::{
     accounts::StorageMapDelta,
+    assets::FungibleAsset,
     crypto::merkle::{LeafIndex, NodeIndex, SimpleSmt},
 };
 
@@ -15,6 +16,9 @@ pub use slot::StorageSlotType;
 mod map;
 pub use map::StorageMap;
 
+mod borrowed;
+pub use borrowed::AccountStorageRef;
+
 // CONSTANTS
 // ================================================================================================
 
@@ -119,6 +123,10 @@ impl AccountStorage {
     /// The storage slot at which the layout commitment is stored.
     pub const SLOT_LAYOUT_COMMITMENT_INDEX: u8 = 255;
 
+    /// The storage slot reserved for faucet data: the total issuance for fungible faucets, and the
+    /// root of the map of issued assets for non-fungible faucets.
+    pub const FAUCET_DATA_SLOT_INDEX: u8 = 254;
+
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Returns a new instance of account storage initialized with the provided items.
@@ -214,6 +222,45 @@ impl AccountStorage {
         self.maps.iter_mut().find(|map| map.root() == target_root)
     }
 
+    /// Returns the total issuance of a fungible faucet stored in the reserved faucet data slot.
+    ///
+    /// The slot is expected to be a value slot holding `[0, 0, 0, total_issuance]`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The reserved faucet data slot is not a value slot with value arity 0.
+    /// - The slot value is not of the form `[0, 0, 0, total_issuance]` or the total issuance
+    ///   exceeds [FungibleAsset::MAX_AMOUNT].
+    pub fn faucet_total_issuance(&self) -> Result<u64, AccountError> {
+        let value = self.faucet_data(StorageSlotType::Value { value_arity: 0 })?;
+        let total_issuance = value[3].as_int();
+        if value[..3] != [ZERO; 3] || total_issuance > FungibleAsset::MAX_AMOUNT {
+            return Err(AccountError::FaucetReservedSlotInvalidValue(value));
+        }
+
+        Ok(total_issuance)
+    }
+
+    /// Returns the root of the map of issued assets of a non-fungible faucet stored in the
+    /// reserved faucet data slot.
+    ///
+    /// # Errors
+    /// Returns an error if the reserved faucet data slot is not a map slot with value arity 0.
+    pub fn faucet_issued_assets_root(&self) -> Result<Digest, AccountError> {
+        self.faucet_data(StorageSlotType::Map { value_arity: 0 }).map(Digest::from)
+    }
+
+    /// Returns the value of the reserved faucet data slot if the slot is of the expected type.
+    fn faucet_data(&self, expected: StorageSlotType) -> Result<Word, AccountError> {
+        let index = Self::FAUCET_DATA_SLOT_INDEX;
+        let actual = self.layout[index as usize];
+        if actual != expected {
+            return Err(AccountError::FaucetReservedSlotInvalidType { expected, actual });
+        }
+
+        Ok(self.get_item(index).into())
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -230,7 +277,19 @@ impl AccountStorage {
         // Map updates are applied first as we need to find the storage map by its old root
         // and every map updates always involves updating the root in the Storage slots as well.
         for &(slot_idx, ref map_delta) in delta.updated_maps.iter() {
-            self.set_map_item(slot_idx, map_delta.clone())?;
+            let new_map_root = self.set_map_item(slot_idx, map_delta.clone())?;
+
+            // the slot update which accompanies the map update must set the slot to the root of
+            // the updated map
+            if let Some(&(_, slot_value)) = delta.updated_items.iter().find(|x| x.0 == slot_idx) {
+                if Digest::from(slot_value) != new_map_root {
+                    return Err(AccountError::StorageMapRootMismatch {
+                        index: slot_idx,
+                        expected: new_map_root,
+                        actual: slot_value.into(),
+                    });
+                }
+            }
         }
 
         for &slot_idx in delta.cleared_items.iter() {
@@ -276,7 +335,9 @@ impl AccountStorage {
                     });
                 }
             },
-            slot_type => Err(AccountError::StorageSlotArrayNotSupportedYet(index, slot_type))?,
+            slot_type => {
+                Err(AccountError::StorageSlotArrayNotSupportedYet { slot: index, slot_type })?
+            },
         }
 
         // update the slot and return
@@ -285,30 +346,54 @@ impl AccountStorage {
         Ok(slot_value)
     }
 
-    /// Updates a storage map at the specified index.
+    /// Updates a storage map at the specified index and sets the slot at this index to the new
+    /// root of the map. Returns the new root of the map.
+    ///
+    /// If the map is shared with other storage slots (e.g., several slots hold an empty map), a
+    /// copy of the map is updated so that the other slots are not affected. If the slot holds the
+    /// root of an empty map for which no map was provided, a new empty map is created.
     ///
     /// # Errors
     /// Returns an error if:
     /// - The index specifies a reserved storage slot.
-    /// - The index is not u8.
+    /// - The slot at the specified index is not a map slot.
     /// - The map does not exist at the specified index.
     pub fn set_map_item(
         &mut self,
         index: u8,
         map_delta: StorageMapDelta,
-    ) -> Result<(), AccountError> {
+    ) -> Result<Digest, AccountError> {
         // layout commitment slot cannot be updated
         if index == Self::SLOT_LAYOUT_COMMITMENT_INDEX {
             return Err(AccountError::StorageSlotIsReserved(index));
         }
 
-        // load the storage map
-        let index = LeafIndex::new(index as u64).expect("index is u8 - index within range");
-        let old_map_root: Digest = self.slots.get_leaf(&index).into();
+        if !matches!(self.layout[index as usize], StorageSlotType::Map { .. }) {
+            return Err(AccountError::StorageSlotNotMap(index));
+        }
 
-        let storage_map = self
-            .find_storage_map_by_root(old_map_root)
-            .ok_or(AccountError::StorageMapNotFound { index: index.value() })?;
+        // load the storage map; if there are fewer maps with the same root than slots which
+        // refer to this root, the map is shared and we need to update a copy of it
+        let old_map_root = self.get_item(index);
+        let num_slots = (0..Self::SLOT_LAYOUT_COMMITMENT_INDEX)
+            .filter(|&idx| matches!(self.layout[idx as usize], StorageSlotType::Map { .. }))
+            .filter(|&idx| self.get_item(idx) == old_map_root)
+            .count();
+        let num_maps = self.maps.iter().filter(|map| map.root() == old_map_root).count();
+
+        let storage_map = match self.maps.iter().position(|map| map.root() == old_map_root) {
+            Some(pos) if num_maps >= num_slots => &mut self.maps[pos],
+            Some(pos) => {
+                let storage_map = self.maps[pos].clone();
+                self.maps.push(storage_map);
+                self.maps.last_mut().expect("storage map was just added")
+            },
+            None if old_map_root == StorageMap::new().root() => {
+                self.maps.push(StorageMap::new());
+                self.maps.last_mut().expect("storage map was just added")
+            },
+            None => return Err(AccountError::StorageMapNotFound { index: index as u64 }),
+        };
 
         // apply the updated leaves to the storage map
         for (key, value) in map_delta.updated_leaves.iter() {
@@ -320,7 +405,12 @@ impl AccountStorage {
             storage_map.insert(key.into(), [ZERO; 4]);
         }
 
-        Ok(())
+        // update the slot with the new root of the map
+        let new_map_root = storage_map.root();
+        let index = LeafIndex::new(index as u64).expect("index is u8 - index within range");
+        self.slots.insert(index, new_map_root.into());
+
+        Ok(new_map_root)
     }
 }
 
@@ -412,6 +502,52 @@ impl Deserializable for AccountStorage {
     }
 }
 
+/// Account storage is serialized as a struct with two fields:
+/// - `slots`: a list of [SlotItem]s for all slots which have a non-default type or a non-empty
+///   value, excluding the reserved layout commitment slot.
+/// - `maps`: a list of [StorageMap]s backing the map slots.
+#[cfg(feature = "serde")]
+impl serde::Serialize for AccountStorage {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let slots = self.layout[..Self::SLOT_LAYOUT_COMMITMENT_INDEX as usize]
+            .iter()
+            .enumerate()
+            .map(|(index, &slot_type)| SlotItem {
+                index: index as u8,
+                slot: StorageSlot {
+                    slot_type,
+                    value: *self.get_item(index as u8),
+                },
+            })
+            .filter(|item| {
+                !item.slot.slot_type.is_default()
+                    || item.slot.value != SimpleSmt::<STORAGE_TREE_DEPTH>::EMPTY_VALUE
+            })
+            .collect::<Vec<_>>();
+
+        let mut state = serializer.serialize_struct("AccountStorage", 2)?;
+        state.serialize_field("slots", &slots)?;
+        state.serialize_field("maps", &self.maps)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AccountStorage {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct AccountStorageParts {
+            slots: Vec<SlotItem>,
+            maps: Vec<StorageMap>,
+        }
+
+        let parts = AccountStorageParts::deserialize(deserializer)?;
+        Self::new(parts.slots, parts.maps).map_err(serde::de::Error::custom)
+    }
+}
+
 // TESTS
 // ================================================================================================
 
@@ -422,10 +558,10 @@ mod tests {
     use miden_crypto::hash::rpo::RpoDigest;
 
     use super::{
-        AccountStorage, Deserializable, Felt, Serializable, SlotItem, StorageMap, StorageSlot,
-        StorageSlotType, Word,
+        AccountStorage, AccountStorageDelta, AccountStorageRef, Deserializable, Felt, Serializable,
+        SlotItem, StorageMap, StorageMapDelta, StorageSlot, StorageSlotType, Word,
     };
-    use crate::{ONE, ZERO};
+    use crate::{AccountError, ONE, ZERO};
 
     #[test]
     fn account_storage_serialization() {
@@ -501,4 +637,137 @@ mod tests {
         let bytes = storage.to_bytes();
         assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
     }
+
+    #[test]
+    fn account_storage_ref() {
+        let mut storage_map = StorageMap::new();
+        storage_map.insert([ONE, ZERO, ZERO, ZERO].into(), [ONE, ONE, ZERO, ZERO]);
+        let storage = AccountStorage::new(
+            vec![
+                SlotItem {
+                    index: 0,
+                    slot: StorageSlot {
+                        slot_type: StorageSlotType::Value { value_arity: 1 },
+                        value: [ONE, ONE, ONE, ONE],
+                    },
+                },
+                SlotItem {
+                    index: 7,
+                    slot: StorageSlot::new_map(Word::from(storage_map.root())),
+                },
+            ],
+            vec![storage_map],
+        )
+        .unwrap();
+
+        // trailing bytes are not a part of the view
+        let mut bytes = storage.to_bytes();
+        let len = bytes.len();
+        bytes.push(1);
+
+        let storage_ref = AccountStorageRef::read_from_bytes(&bytes).unwrap();
+        assert_eq!(storage_ref.root(), storage.root());
+        assert_eq!(storage_ref.layout_commitment(), storage.layout_commitment());
+        assert_eq!(storage_ref.num_maps(), storage.maps().len());
+        for index in [0, 1, 7, 255] {
+            assert_eq!(storage_ref.get_item(index), storage.get_item(index));
+            assert_eq!(storage_ref.slot_type(index), storage.layout()[index as usize]);
+        }
+        assert_eq!(storage_ref.to_account_storage().unwrap(), storage);
+
+        // truncated storage maps are rejected
+        assert!(AccountStorageRef::read_from_bytes(&bytes[..len - 1]).is_err());
+    }
+
+    #[test]
+    fn apply_delta_to_shared_empty_maps() {
+        let empty_map_root = Word::from(StorageMap::new().root());
+        let mut storage = AccountStorage::new(
+            vec![
+                SlotItem {
+                    index: 0,
+                    slot: StorageSlot::new_map(empty_map_root),
+                },
+                SlotItem {
+                    index: 1,
+                    slot: StorageSlot::new_map(empty_map_root),
+                },
+            ],
+            vec![],
+        )
+        .unwrap();
+
+        let key = [ONE, ONE, ONE, ZERO];
+        let value = [ONE, ONE, ONE, ONE];
+        let mut expected_map = StorageMap::new();
+        expected_map.insert(key.into(), value);
+        let new_map_root = Word::from(expected_map.root());
+
+        // updating the map in slot 1 must not affect the map in slot 0
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(1, new_map_root)],
+            updated_maps: vec![(1, StorageMapDelta::from(vec![], vec![(key, value)]))],
+        };
+        storage.apply_delta(&delta).unwrap();
+
+        assert_eq!(Word::from(storage.get_item(0)), empty_map_root);
+        assert_eq!(Word::from(storage.get_item(1)), new_map_root);
+        assert_eq!(storage.maps(), &[expected_map.clone()]);
+
+        // clearing the leaf restores the empty map in slot 1, and leaves slot 0 untouched
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(1, empty_map_root)],
+            updated_maps: vec![(1, StorageMapDelta::from(vec![key], vec![]))],
+        };
+        storage.apply_delta(&delta).unwrap();
+
+        assert_eq!(Word::from(storage.get_item(0)), empty_map_root);
+        assert_eq!(Word::from(storage.get_item(1)), empty_map_root);
+
+        let bytes = storage.to_bytes();
+        assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn apply_delta_with_invalid_map_updates() {
+        let storage_map =
+            StorageMap::with_entries([([ONE, ZERO, ZERO, ZERO].into(), [ONE, ONE, ONE, ONE])])
+                .unwrap();
+        let storage = AccountStorage::new(
+            vec![
+                SlotItem {
+                    index: 0,
+                    slot: StorageSlot::new_value([ONE, ONE, ONE, ONE]),
+                },
+                SlotItem {
+                    index: 1,
+                    slot: StorageSlot::new_map(Word::from(storage_map.root())),
+                },
+            ],
+            vec![storage_map],
+        )
+        .unwrap();
+        let map_delta = StorageMapDelta::from(vec![[ONE, ZERO, ZERO, ZERO]], vec![]);
+
+        // map delta for a value slot
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(0, [ONE, ONE, ONE, ONE])],
+            updated_maps: vec![(0, map_delta.clone())],
+        };
+        assert_eq!(storage.clone().apply_delta(&delta), Err(AccountError::StorageSlotNotMap(0)));
+
+        // slot update does not match the root of the updated map
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(1, [ONE, ONE, ONE, ONE])],
+            updated_maps: vec![(1, map_delta)],
+        };
+        assert!(matches!(
+            storage.clone().apply_delta(&delta),
+            Err(AccountError::StorageMapRootMismatch { index: 1, .. })
+        ));
+    }
 }
@@ -0,0 +1,149 @@
+use alloc::string::ToString;
+
+use super::{FungibleAsset, TokenSymbol};
+use crate::{AccountError, Felt, Word, ZERO};
+
+// FAUCET METADATA
+// ================================================================================================
+
+/// Human-readable metadata of a token issued by a fungible faucet.
+///
+/// The metadata is stored in a single storage slot of the faucet account as
+/// `[max_supply, decimals, token_symbol, 0]`, where:
+/// - `max_supply` is the maximum amount of tokens the faucet can issue.
+/// - `decimals` is the number of decimal places used to display token amounts.
+/// - `token_symbol` is the [TokenSymbol] of the token encoded into a single field element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FaucetMetadata {
+    symbol: TokenSymbol,
+    decimals: u8,
+    max_supply: u64,
+}
+
+impl FaucetMetadata {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The maximum number of decimals a token can have.
+    pub const MAX_DECIMALS: u8 = 12;
+
+    /// The maximum supply a token can have.
+    pub const MAX_SUPPLY: u64 = FungibleAsset::MAX_AMOUNT;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [FaucetMetadata] instantiated from the provided parameters.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of decimals is greater than [FaucetMetadata::MAX_DECIMALS].
+    /// - The max supply is greater than [FaucetMetadata::MAX_SUPPLY].
+    pub fn new(symbol: TokenSymbol, decimals: u8, max_supply: u64) -> Result<Self, AccountError> {
+        if decimals > Self::MAX_DECIMALS {
+            return Err(AccountError::FungibleFaucetInvalidMetadata(
+                "Decimals must be less than 13".to_string(),
+            ));
+        } else if max_supply > Self::MAX_SUPPLY {
+            return Err(AccountError::FungibleFaucetInvalidMetadata(
+                "Max supply must be < 2^63".to_string(),
+            ));
+        }
+
+        Ok(Self { symbol, decimals, max_supply })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the symbol of the token.
+    pub fn symbol(&self) -> TokenSymbol {
+        self.symbol
+    }
+
+    /// Returns the number of decimals of the token.
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Returns the maximum supply of the token.
+    pub fn max_supply(&self) -> u64 {
+        self.max_supply
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<FaucetMetadata> for Word {
+    fn from(metadata: FaucetMetadata) -> Self {
+        // Note: data is stored as [a0, a1, a2, a3] but loaded onto the stack as
+        // [a3, a2, a1, a0, ...]
+        [
+            Felt::new(metadata.max_supply),
+            Felt::from(metadata.decimals),
+            metadata.symbol.into(),
+            ZERO,
+        ]
+    }
+}
+
+impl TryFrom<Word> for FaucetMetadata {
+    type Error = AccountError;
+
+    fn try_from(word: Word) -> Result<Self, Self::Error> {
+        let [max_supply, decimals, symbol, padding] = word;
+
+        if padding != ZERO {
+            return Err(AccountError::FungibleFaucetInvalidMetadata(
+                "Last element of the metadata word must be zero".to_string(),
+            ));
+        }
+
+        let symbol = TokenSymbol::try_from(symbol)
+            .map_err(|err| AccountError::FungibleFaucetInvalidMetadata(err.to_string()))?;
+        let decimals = u8::try_from(decimals.as_int()).map_err(|_| {
+            AccountError::FungibleFaucetInvalidMetadata("Decimals must fit into a u8".to_string())
+        })?;
+
+        Self::new(symbol, decimals, max_supply.as_int())
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{FaucetMetadata, TokenSymbol};
+    use crate::{Felt, Word, ONE, ZERO};
+
+    #[test]
+    fn test_faucet_metadata_word_roundtrip() {
+        let symbol = TokenSymbol::new("POL").unwrap();
+        let metadata = FaucetMetadata::new(symbol, 8, 1_000_000).unwrap();
+
+        let word: Word = metadata.into();
+        assert_eq!(word, [Felt::new(1_000_000), Felt::new(8), symbol.into(), ZERO]);
+        assert_eq!(FaucetMetadata::try_from(word).unwrap(), metadata);
+    }
+
+    #[test]
+    fn test_faucet_metadata_validation() {
+        let symbol = TokenSymbol::new("POL").unwrap();
+        assert!(FaucetMetadata::new(symbol, FaucetMetadata::MAX_DECIMALS + 1, 100).is_err());
+        assert!(FaucetMetadata::new(symbol, 2, FaucetMetadata::MAX_SUPPLY + 1).is_err());
+
+        // non-zero padding element
+        let word = [Felt::new(100), Felt::new(2), symbol.into(), ONE];
+        assert!(FaucetMetadata::try_from(word).is_err());
+
+        // decimals which do not fit into a u8
+        let word = [Felt::new(100), Felt::new(256), symbol.into(), ZERO];
+        assert!(FaucetMetadata::try_from(word).is_err());
+
+        // invalid token symbol
+        let word = [Felt::new(100), Felt::new(2), Felt::new(u64::MAX >> 1), ZERO];
+        assert!(FaucetMetadata::try_from(word).is_err());
+    }
+}
@@ -10,12 +10,19 @@ use super::{
 // ================================================================================================
 /// A fungible asset.
 ///
-/// A fungible asset consists of a faucet ID of the faucet which issued the asset as well as the
-/// asset amount. Asset amount is guaranteed to be 2^63 - 1 or smaller.
+/// A fungible asset consists of a faucet ID of the faucet which issued the asset, a sub-token ID,
+/// and the asset amount. Asset amount is guaranteed to be 2^63 - 1 or smaller.
+///
+/// The sub-token ID allows a single faucet to issue multiple distinct classes of fungible tokens;
+/// assets with different sub-token IDs are not interchangeable even if they were issued by the
+/// same faucet. Faucets which issue a single token class use sub-token ID 0.
+///
+/// The asset is encoded into a word as `[amount, 0, sub_token_id, faucet_id]`.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct FungibleAsset {
     faucet_id: AccountId,
+    sub_token_id: u32,
     amount: u64,
 }
 
@@ -34,7 +41,22 @@ impl FungibleAsset {
     /// - The faucet_id is not a valid fungible faucet ID.
     /// - The provided amount is greater than 2^63 - 1.
     pub fn new(faucet_id: AccountId, amount: u64) -> Result<Self, AssetError> {
-        let asset = Self { faucet_id, amount };
+        Self::with_sub_token(faucet_id, 0, amount)
+    }
+
+    /// Returns a fungible asset of the specified sub-token class instantiated with the provided
+    /// faucet ID and amount.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The faucet_id is not a valid fungible faucet ID.
+    /// - The provided amount is greater than 2^63 - 1.
+    pub fn with_sub_token(
+        faucet_id: AccountId,
+        sub_token_id: u32,
+        amount: u64,
+    ) -> Result<Self, AssetError> {
+        let asset = Self { faucet_id, sub_token_id, amount };
         asset.validate()?;
 
         Ok(asset)
@@ -44,6 +66,7 @@ impl FungibleAsset {
     pub(crate) fn new_unchecked(value: Word) -> FungibleAsset {
         FungibleAsset {
             faucet_id: AccountId::new_unchecked(value[3]),
+            sub_token_id: value[2].as_int() as u32,
             amount: value[0].as_int(),
         }
     }
@@ -56,6 +79,11 @@ impl FungibleAsset {
         self.faucet_id
     }
 
+    /// Returns the ID of the sub-token class of this asset.
+    pub fn sub_token_id(&self) -> u32 {
+        self.sub_token_id
+    }
+
     /// Returns the amount of this asset.
     pub fn amount(&self) -> u64 {
         self.amount
@@ -66,9 +94,16 @@ impl FungibleAsset {
         self.faucet_id == other.faucet_id
     }
 
+    /// Returns true if this and the other assets belong to the same token class, i.e., they were
+    /// issued from the same faucet and have the same sub-token ID.
+    pub fn is_same_token(&self, other: &Self) -> bool {
+        self.is_from_same_faucet(other) && self.sub_token_id == other.sub_token_id
+    }
+
     /// Returns the key which is used to store this asset in the account vault.
     pub fn vault_key(&self) -> Word {
         let mut key = Word::default();
+        key[2] = self.sub_token_id.into();
         key[3] = self.faucet_id.into();
         key
     }
@@ -81,6 +116,7 @@ impl FungibleAsset {
     /// # Errors
     /// Returns an error if:
     /// - The assets were not issued by the same faucet.
+    /// - The assets have different sub-token IDs.
     /// - The total value of assets is greater than or equal to 2^63.
     #[allow(clippy::should_implement_trait)]
     pub fn add(self, other: Self) -> Result<Self, AssetError> {
@@ -88,12 +124,19 @@ impl FungibleAsset {
             return Err(AssetError::inconsistent_faucet_ids(self.faucet_id, other.faucet_id));
         }
 
+        if self.sub_token_id != other.sub_token_id {
+            return Err(AssetError::inconsistent_sub_token_ids(
+                self.sub_token_id,
+                other.sub_token_id,
+            ));
+        }
+
         let amount = self.amount.checked_add(other.amount).expect("overflow!");
         if amount > Self::MAX_AMOUNT {
             return Err(AssetError::amount_too_big(amount));
         }
 
-        Ok(Self { faucet_id: self.faucet_id, amount })
+        Ok(Self { amount, ..self })
     }
 
     /// Subtracts the specified amount from this asset and returns the resulting asset.
@@ -104,9 +147,9 @@ impl FungibleAsset {
         self.amount = self
             .amount
             .checked_sub(amount)
-            .ok_or(AssetError::AssetAmountNotSufficient(self.amount, amount))?;
+            .ok_or(AssetError::asset_amount_not_sufficient(self.amount, amount))?;
 
-        Ok(FungibleAsset { faucet_id: self.faucet_id, amount })
+        Ok(FungibleAsset { amount, ..*self })
     }
 
     // HELPER FUNCTIONS
@@ -135,6 +178,7 @@ impl From<FungibleAsset> for Word {
     fn from(asset: FungibleAsset) -> Self {
         let mut result = Word::default();
         result[0] = Felt::new(asset.amount);
+        result[2] = asset.sub_token_id.into();
         result[3] = asset.faucet_id.into();
         debug_assert!(is_not_a_non_fungible_asset(result));
         result
@@ -146,6 +190,7 @@ impl From<FungibleAsset> for [u8; 32] {
         let mut result = [0_u8; 32];
         let id_bytes: [u8; 8] = asset.faucet_id.into();
         result[..8].copy_from_slice(&asset.amount.to_le_bytes());
+        result[16..20].copy_from_slice(&asset.sub_token_id.to_le_bytes());
         result[24..].copy_from_slice(&id_bytes);
         result
     }
@@ -161,13 +206,15 @@ impl TryFrom<Word> for FungibleAsset {
     type Error = AssetError;
 
     fn try_from(value: Word) -> Result<Self, Self::Error> {
-        if (value[1], value[2]) != (ZERO, ZERO) {
+        if value[1] != ZERO {
             return Err(AssetError::fungible_asset_invalid_word(value));
         }
+        let sub_token_id = u32::try_from(value[2].as_int())
+            .map_err(|_| AssetError::fungible_asset_invalid_word(value))?;
         let faucet_id = AccountId::try_from(value[3])
             .map_err(|e| AssetError::invalid_account_id(e.to_string()))?;
         let amount = value[0].as_int();
-        Self::new(faucet_id, amount)
+        Self::with_sub_token(faucet_id, sub_token_id, amount)
     }
 }
 
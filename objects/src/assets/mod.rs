@@ -6,6 +6,9 @@ use super::{
     AssetError, Felt, Hasher, Word, ZERO,
 };
 
+mod faucet_metadata;
+pub use faucet_metadata::FaucetMetadata;
+
 mod fungible;
 pub use fungible::FungibleAsset;
 
@@ -15,6 +18,9 @@ pub use nonfungible::{NonFungibleAsset, NonFungibleAssetDetails};
 mod token_symbol;
 pub use token_symbol::TokenSymbol;
 
+mod partial_vault;
+pub use partial_vault::PartialVault;
+
 mod vault;
 pub use vault::AssetVault;
 
@@ -81,12 +87,13 @@ impl Asset {
     /// Returns true if this asset is the same as the specified asset.
     ///
     /// Two assets are defined to be the same if:
-    /// - For fungible assets, if they were issued by the same faucet.
+    /// - For fungible assets, if they were issued by the same faucet and have the same sub-token
+    ///   ID.
     /// - For non-fungible assets, if the assets are identical.
     pub fn is_same(&self, other: &Self) -> bool {
         use Asset::*;
         match (self, other) {
-            (Fungible(l), Fungible(r)) => l.is_from_same_faucet(r),
+            (Fungible(l), Fungible(r)) => l.is_same_token(r),
             (NonFungible(l), NonFungible(r)) => l == r,
             _ => false,
         }
@@ -254,14 +261,17 @@ mod tests {
     };
 
     use super::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
-    use crate::accounts::{
-        account_id::testing::{
-            ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
-            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
-            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
-            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN_1,
+    use crate::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+                ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN_1,
+            },
+            AccountId,
         },
-        AccountId,
+        Felt, ONE, ZERO,
     };
 
     #[test]
@@ -318,4 +328,31 @@ mod tests {
             assert_eq!(non_fungible_asset, Asset::new_unchecked(Word::from(non_fungible_asset)));
         }
     }
+
+    #[test]
+    fn test_fungible_sub_token() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let gold = FungibleAsset::with_sub_token(account_id, 1, 10).unwrap();
+        let silver = FungibleAsset::with_sub_token(account_id, 2, 10).unwrap();
+
+        // the sub-token id is encoded into the asset word and the vault key
+        let word = Word::from(gold);
+        assert_eq!(word, [Felt::new(10), ZERO, ONE, account_id.into()]);
+        assert_eq!(gold.vault_key(), [ZERO, ZERO, ONE, account_id.into()]);
+        assert_eq!(FungibleAsset::try_from(word).unwrap(), gold);
+        assert_eq!(Asset::new_unchecked(word), gold.into());
+        let asset: Asset = gold.into();
+        assert_eq!(asset, Asset::read_from_bytes(&asset.to_bytes()).unwrap());
+
+        // assets of different sub-tokens are different tokens and can not be added together
+        assert!(!Asset::from(gold).is_same(&Asset::from(silver)));
+        assert!(gold.add(silver).is_err());
+        assert_eq!(gold.add(gold).unwrap().amount(), 20);
+
+        // the sub-token id must fit into a u32 and ASSET[1] must be ZERO
+        let word = [Felt::new(10), ZERO, Felt::new(1 << 32), account_id.into()];
+        assert!(FungibleAsset::try_from(word).is_err());
+        let word = [Felt::new(10), ONE, ZERO, account_id.into()];
+        assert!(FungibleAsset::try_from(word).is_err());
+    }
 }
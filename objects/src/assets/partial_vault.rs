@@ -0,0 +1,321 @@
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+
+use super::{
+    AccountId, AccountType, Asset, AssetVault, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, FungibleAsset, NonFungibleAsset, Serializable, ZERO,
+};
+use crate::{
+    crypto::merkle::{InnerNodeInfo, LeafIndex, Smt, SmtLeaf, SmtProof, SMT_DEPTH},
+    AssetVaultError, Digest, Word,
+};
+
+// PARTIAL VAULT
+// ================================================================================================
+
+/// A partial representation of an [AssetVault].
+///
+/// A partial vault tracks only a subset of the leaves of the vault's Sparse Merkle Tree together
+/// with the authentication paths of these leaves to the vault root. This makes it possible to
+/// execute a transaction against an account with a very large vault by providing only the parts
+/// of the vault which the transaction touches.
+///
+/// A leaf may be tracked even if it is empty; this is needed to prove that a given asset is not
+/// present in the vault (e.g., when an asset issued by a new faucet is added to the vault).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialVault {
+    root: Digest,
+    proofs: BTreeMap<u64, SmtProof>,
+}
+
+impl PartialVault {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [PartialVault] with the specified root, tracking the leaves of the provided
+    /// proofs.
+    ///
+    /// # Errors
+    /// Returns an error if any of the provided proofs does not resolve to the specified root.
+    pub fn new(
+        root: Digest,
+        proofs: impl IntoIterator<Item = SmtProof>,
+    ) -> Result<Self, AssetVaultError> {
+        let mut tracked = BTreeMap::new();
+        for proof in proofs {
+            let actual = proof.compute_root();
+            if actual != root {
+                return Err(AssetVaultError::PartialVaultRootMismatch { expected: root, actual });
+            }
+            tracked.insert(proof.leaf().index().value(), proof);
+        }
+
+        Ok(Self { root, proofs: tracked })
+    }
+
+    /// Returns a new [PartialVault] built from the provided vault and tracking the leaves for the
+    /// specified vault keys.
+    ///
+    /// Vault keys of the assets can be obtained via [Asset::vault_key()]. A key does not need to
+    /// correspond to an asset currently stored in the vault.
+    pub fn from_vault(vault: &AssetVault, vault_keys: impl IntoIterator<Item = Word>) -> Self {
+        let tree = vault.asset_tree();
+        let proofs = vault_keys
+            .into_iter()
+            .map(|key| {
+                let leaf_index = LeafIndex::<SMT_DEPTH>::from(key).value();
+                (leaf_index, tree.open(&key.into()))
+            })
+            .collect();
+
+        Self { root: vault.commitment(), proofs }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the vault described by this partial vault.
+    pub fn commitment(&self) -> Digest {
+        self.root
+    }
+
+    /// Returns the number of leaves tracked by this partial vault.
+    pub fn num_tracked_leaves(&self) -> usize {
+        self.proofs.len()
+    }
+
+    /// Returns true if the leaf containing the specified vault key is tracked by this partial
+    /// vault.
+    pub fn is_tracked(&self, vault_key: Word) -> bool {
+        self.proofs.contains_key(&LeafIndex::<SMT_DEPTH>::from(vault_key).value())
+    }
+
+    /// Returns the balance of the fungible asset issued by the specified faucet. If the vault
+    /// does not contain such an asset, 0 is returned.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The specified ID is not an ID of a fungible asset faucet.
+    /// - The leaf for the asset issued by the specified faucet is not tracked by this vault.
+    pub fn fungible_balance(&self, faucet_id: AccountId) -> Result<u64, AssetVaultError> {
+        self.sub_token_balance(faucet_id, 0)
+    }
+
+    /// Returns the balance of the fungible asset of the specified sub-token class issued by the
+    /// specified faucet. If the vault does not contain such an asset, 0 is returned.
+    ///
+    /// # Errors
+    /// Returns an error if the specified ID is not an ID of a fungible asset faucet.
+    /// Also returns an error if the leaf for the requested asset is not tracked by this vault.
+    pub fn sub_token_balance(
+        &self,
+        faucet_id: AccountId,
+        sub_token_id: u32,
+    ) -> Result<u64, AssetVaultError> {
+        if !matches!(faucet_id.account_type(), AccountType::FungibleFaucet) {
+            return Err(AssetVaultError::NotAFungibleFaucetId(faucet_id));
+        }
+
+        match self.get_value([ZERO, ZERO, sub_token_id.into(), faucet_id.into()])? {
+            asset if asset == Smt::EMPTY_VALUE => Ok(0),
+            asset => Ok(FungibleAsset::new_unchecked(asset).amount()),
+        }
+    }
+
+    /// Returns true if the specified non-fungible asset is stored in this vault.
+    ///
+    /// # Errors
+    /// Returns an error if the leaf for the specified asset is not tracked by this vault.
+    pub fn has_non_fungible(&self, asset: &NonFungibleAsset) -> Result<bool, AssetVaultError> {
+        Ok(self.get_value(asset.vault_key())? != Smt::EMPTY_VALUE)
+    }
+
+    /// Returns an iterator over the assets stored in the tracked leaves of this vault.
+    pub fn assets(&self) -> impl Iterator<Item = Asset> + '_ {
+        self.leaves()
+            .flat_map(|leaf| leaf.entries())
+            .map(|(_, value)| Asset::new_unchecked(*value))
+    }
+
+    /// Returns an iterator over the leaves tracked by this partial vault.
+    pub fn leaves(&self) -> impl Iterator<Item = &SmtLeaf> {
+        self.proofs.values().map(|proof| proof.leaf())
+    }
+
+    /// Returns an iterator over the inner nodes of the authentication paths of all leaves tracked
+    /// by this partial vault.
+    pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
+        self.proofs.iter().flat_map(|(&leaf_index, proof)| {
+            proof
+                .path()
+                .inner_nodes(leaf_index, proof.leaf().hash())
+                .expect("leaf index is valid for a path of the vault depth")
+        })
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the value stored under the specified key, or an error if the key's leaf is not
+    /// tracked.
+    fn get_value(&self, vault_key: Word) -> Result<Word, AssetVaultError> {
+        let key: Digest = vault_key.into();
+        self.proofs
+            .get(&LeafIndex::<SMT_DEPTH>::from(key).value())
+            .and_then(|proof| proof.get(&key))
+            .ok_or(AssetVaultError::PartialVaultKeyNotTracked(key))
+    }
+}
+
+impl From<&AssetVault> for PartialVault {
+    /// Returns a partial vault tracking all non-empty leaves of the provided vault.
+    fn from(vault: &AssetVault) -> Self {
+        Self::from_vault(vault, vault.assets().map(|asset| asset.vault_key()))
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for PartialVault {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.root.write_into(target);
+        target.write_usize(self.proofs.len());
+        target.write_many(self.proofs.values());
+    }
+}
+
+impl Deserializable for PartialVault {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let root = Digest::read_from(source)?;
+        let num_proofs = source.read_usize()?;
+        let proofs = (0..num_proofs)
+            .map(|_| SmtProof::read_from(source))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(root, proofs).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+/// A partial vault is serialized as a struct with two fields:
+/// - `root`: the root of the vault.
+/// - `proofs`: a list of `(path, leaf)` pairs, one for each tracked leaf of the vault.
+#[cfg(feature = "serde")]
+impl serde::Serialize for PartialVault {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use alloc::vec::Vec;
+
+        use serde::ser::SerializeStruct;
+
+        let proofs = self
+            .proofs
+            .values()
+            .map(|proof| (proof.path(), proof.leaf()))
+            .collect::<Vec<_>>();
+
+        let mut state = serializer.serialize_struct("PartialVault", 2)?;
+        state.serialize_field("root", &self.root)?;
+        state.serialize_field("proofs", &proofs)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PartialVault {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use alloc::vec::Vec;
+
+        use serde::de::Error;
+
+        use crate::crypto::merkle::MerklePath;
+
+        #[derive(serde::Deserialize)]
+        struct PartialVaultParts {
+            root: Digest,
+            proofs: Vec<(MerklePath, SmtLeaf)>,
+        }
+
+        let parts = PartialVaultParts::deserialize(deserializer)?;
+        let proofs = parts
+            .proofs
+            .into_iter()
+            .map(|(path, leaf)| SmtProof::new(path, leaf).map_err(D::Error::custom))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(parts.root, proofs).map_err(D::Error::custom)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{AssetVault, Deserializable, PartialVault, Serializable};
+    use crate::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+                ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            },
+            AccountId,
+        },
+        assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        crypto::merkle::Smt,
+        AssetVaultError, Digest,
+    };
+
+    #[test]
+    fn test_partial_vault_queries() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+        let nft_faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let fungible: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let nft = NonFungibleAsset::new(
+            &NonFungibleAssetDetails::new(nft_faucet_id, vec![1, 2, 3]).unwrap(),
+        )
+        .unwrap();
+        let vault = AssetVault::new(&[fungible, nft.into()]).unwrap();
+
+        // track the fungible asset and an empty leaf for a different faucet
+        let other_key = FungibleAsset::new(other_faucet_id, 1).unwrap().vault_key();
+        let partial = PartialVault::from_vault(&vault, [fungible.vault_key(), other_key]);
+
+        assert_eq!(partial.commitment(), vault.commitment());
+        assert_eq!(partial.num_tracked_leaves(), 2);
+        assert_eq!(partial.fungible_balance(faucet_id).unwrap(), 100);
+        assert_eq!(partial.fungible_balance(other_faucet_id).unwrap(), 0);
+        assert_eq!(partial.assets().collect::<Vec<_>>(), vec![fungible]);
+        assert!(!partial.is_tracked(nft.vault_key()));
+        assert_eq!(
+            partial.has_non_fungible(&nft),
+            Err(AssetVaultError::PartialVaultKeyNotTracked(nft.vault_key().into()))
+        );
+
+        let full = PartialVault::from(&vault);
+        assert!(full.has_non_fungible(&nft).unwrap());
+    }
+
+    #[test]
+    fn test_partial_vault_root_mismatch() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let fungible: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let vault = AssetVault::new(&[fungible]).unwrap();
+
+        let proof = vault.asset_tree().open(&fungible.vault_key().into());
+        let result = PartialVault::new(Smt::default().root(), [proof.clone()]);
+        assert_eq!(
+            result,
+            Err(AssetVaultError::PartialVaultRootMismatch {
+                expected: Smt::default().root(),
+                actual: vault.commitment(),
+            })
+        );
+
+        let partial = PartialVault::new(vault.commitment(), [proof]).unwrap();
+        let deserialized = PartialVault::read_from_bytes(&partial.to_bytes()).unwrap();
+        assert_eq!(partial, deserialized);
+        assert_ne!(partial.commitment(), Digest::default());
+    }
+}
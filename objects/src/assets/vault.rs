@@ -1,10 +1,10 @@
-use alloc::{string::ToString, vec::Vec};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 
 use super::{
     AccountId, AccountType, Asset, ByteReader, ByteWriter, Deserializable, DeserializationError,
     FungibleAsset, NonFungibleAsset, Serializable, ZERO,
 };
-use crate::{crypto::merkle::Smt, AssetVaultError, Digest};
+use crate::{crypto::merkle::Smt, AssetVaultError, Digest, Word};
 
 // ASSET VAULT
 // ================================================================================================
@@ -46,16 +46,32 @@ impl AssetVault {
         self.asset_tree.root()
     }
 
+    /// Returns the number of assets stored in this vault.
+    ///
+    /// The count is computed from the leaves of the underlying Sparse Merkle Tree, without
+    /// decoding the assets.
+    pub fn num_assets(&self) -> usize {
+        self.asset_tree.leaves().map(|(_, leaf)| leaf.num_entries() as usize).sum()
+    }
+
+    /// Returns true if the vault does not contain any assets.
+    pub fn is_empty(&self) -> bool {
+        self.asset_tree.leaves().next().is_none()
+    }
+
     /// Returns true if the specified non-fungible asset is stored in this vault.
-    pub fn has_non_fungible_asset(&self, asset: Asset) -> Result<bool, AssetVaultError> {
-        if asset.is_fungible() {
-            return Err(AssetVaultError::NotANonFungibleAsset(asset));
-        }
+    pub fn has_non_fungible(&self, asset: &NonFungibleAsset) -> bool {
+        self.asset_tree.get_value(&asset.vault_key().into()) != Smt::EMPTY_VALUE
+    }
 
-        // check if the asset is stored in the vault
-        match self.asset_tree.get_value(&asset.vault_key().into()) {
-            asset if asset == Smt::EMPTY_VALUE => Ok(false),
-            _ => Ok(true),
+    /// Returns true if the specified non-fungible asset is stored in this vault.
+    ///
+    /// # Errors
+    /// Returns an error if the specified asset is a fungible asset.
+    pub fn has_non_fungible_asset(&self, asset: Asset) -> Result<bool, AssetVaultError> {
+        match asset {
+            Asset::Fungible(_) => Err(AssetVaultError::NotANonFungibleAsset(asset)),
+            Asset::NonFungible(asset) => Ok(self.has_non_fungible(&asset)),
         }
     }
 
@@ -65,12 +81,40 @@ impl AssetVault {
     /// # Errors
     /// Returns an error if the specified ID is not an ID of a fungible asset faucet.
     pub fn get_balance(&self, faucet_id: AccountId) -> Result<u64, AssetVaultError> {
+        self.fungible_balance(faucet_id)
+    }
+
+    /// Returns the balance of the fungible asset issued by the specified faucet. If the vault
+    /// does not contain such an asset, 0 is returned.
+    ///
+    /// For faucets issuing multiple token classes, this is the balance of the sub-token with ID 0
+    /// (see [AssetVault::sub_token_balance()]).
+    ///
+    /// # Errors
+    /// Returns an error if the specified ID is not an ID of a fungible asset faucet.
+    pub fn fungible_balance(&self, faucet_id: AccountId) -> Result<u64, AssetVaultError> {
+        self.sub_token_balance(faucet_id, 0)
+    }
+
+    /// Returns the balance of the fungible asset of the specified sub-token class issued by the
+    /// specified faucet. If the vault does not contain such an asset, 0 is returned.
+    ///
+    /// # Errors
+    /// Returns an error if the specified ID is not an ID of a fungible asset faucet.
+    pub fn sub_token_balance(
+        &self,
+        faucet_id: AccountId,
+        sub_token_id: u32,
+    ) -> Result<u64, AssetVaultError> {
         if !matches!(faucet_id.account_type(), AccountType::FungibleFaucet) {
             return Err(AssetVaultError::NotAFungibleFaucetId(faucet_id));
         }
 
         // if the tree value is [0, 0, 0, 0], the asset is not stored in the vault
-        match self.asset_tree.get_value(&[ZERO, ZERO, ZERO, faucet_id.into()].into()) {
+        match self
+            .asset_tree
+            .get_value(&[ZERO, ZERO, sub_token_id.into(), faucet_id.into()].into())
+        {
             asset if asset == Smt::EMPTY_VALUE => Ok(0),
             asset => Ok(FungibleAsset::new_unchecked(asset).amount()),
         }
@@ -81,6 +125,34 @@ impl AssetVault {
         self.asset_tree.entries().map(|x| Asset::new_unchecked(x.1))
     }
 
+    /// Returns an iterator over at most `limit` assets stored in the vault, starting right after
+    /// the asset with the vault key specified by `cursor`.
+    ///
+    /// Assets are returned in the order of their vault keys, which is the same order in which
+    /// they are stored in the underlying Sparse Merkle Tree. To fetch the next page, the vault key
+    /// of the last returned asset (see [Asset::vault_key()]) should be passed as the cursor. If
+    /// `cursor` is None, iteration starts from the first asset in the vault.
+    ///
+    /// The cursor does not need to be a key of an asset currently stored in the vault; in this
+    /// case, iteration starts from the first asset with a vault key greater than the cursor.
+    pub fn iter_assets(
+        &self,
+        cursor: Option<Word>,
+        limit: usize,
+    ) -> impl Iterator<Item = Asset> + '_ {
+        let start = cursor.map(|key| vault_key_order(&key));
+
+        self.asset_tree
+            .leaves()
+            .skip_while(move |(leaf_index, _)| {
+                start.is_some_and(|start| leaf_index.value() < start[0])
+            })
+            .flat_map(|(_, leaf)| leaf.entries())
+            .filter(move |(key, _)| start.map_or(true, |start| vault_key_order(key) > start))
+            .take(limit)
+            .map(|(_, value)| Asset::new_unchecked(*value))
+    }
+
     /// Returns a reference to the Sparse Merkle Tree underling this asset vault.
     pub fn asset_tree(&self) -> &Smt {
         &self.asset_tree
@@ -145,6 +217,44 @@ impl AssetVault {
         Ok(asset)
     }
 
+    /// Adds all of the specified assets to the vault.
+    ///
+    /// Updates are first accumulated per vault key (e.g., multiple fungible assets issued by the
+    /// same faucet are combined into a single update) and only then applied to the underlying
+    /// Sparse Merkle Tree, so that the path to the root is recomputed once per affected key rather
+    /// than once per asset. If any of the assets cannot be added, the vault is left unchanged.
+    ///
+    /// # Errors
+    /// - If the total value of fungible assets issued by the same faucet is greater than or equal
+    ///   to 2^63.
+    /// - If the vault already contains one of the non-fungible assets, or the same non-fungible
+    ///   asset is provided more than once.
+    pub fn add_assets(
+        &mut self,
+        assets: impl IntoIterator<Item = Asset>,
+    ) -> Result<(), AssetVaultError> {
+        let mut updates = BTreeMap::new();
+        for asset in assets {
+            let key: Digest = asset.vault_key().into();
+            let current = self.staged_value(&updates, &key);
+            let new: Word = match asset {
+                Asset::Fungible(asset) if current == Smt::EMPTY_VALUE => asset.into(),
+                Asset::Fungible(asset) => FungibleAsset::new_unchecked(current)
+                    .add(asset)
+                    .map_err(AssetVaultError::AddFungibleAssetBalanceError)?
+                    .into(),
+                Asset::NonFungible(asset) if current == Smt::EMPTY_VALUE => asset.into(),
+                Asset::NonFungible(asset) => {
+                    return Err(AssetVaultError::DuplicateNonFungibleAsset(asset))
+                },
+            };
+            updates.insert(key, new);
+        }
+
+        self.apply_updates(updates);
+        Ok(())
+    }
+
     // REMOVE ASSET
     // --------------------------------------------------------------------------------------------
     /// Remove the specified asset from the vault.
@@ -218,6 +328,81 @@ impl AssetVault {
         // return the asset that was removed.
         Ok(asset)
     }
+
+    /// Removes all of the specified assets from the vault.
+    ///
+    /// As with [AssetVault::add_assets()], updates are accumulated per vault key and applied to
+    /// the underlying Sparse Merkle Tree in a single pass. If any of the assets cannot be removed,
+    /// the vault is left unchanged.
+    ///
+    /// # Errors
+    /// - One of the fungible assets is not found in the vault.
+    /// - The amount of a fungible asset in the vault is less than the total amount to be removed.
+    /// - One of the non-fungible assets is not found in the vault, or the same non-fungible asset
+    ///   is provided more than once.
+    pub fn remove_assets(
+        &mut self,
+        assets: impl IntoIterator<Item = Asset>,
+    ) -> Result<(), AssetVaultError> {
+        let mut updates = BTreeMap::new();
+        for asset in assets {
+            let key: Digest = asset.vault_key().into();
+            let current = self.staged_value(&updates, &key);
+            let new: Word = match asset {
+                Asset::Fungible(asset) if current == Smt::EMPTY_VALUE => {
+                    return Err(AssetVaultError::FungibleAssetNotFound(asset))
+                },
+                Asset::Fungible(asset) => {
+                    let mut current = FungibleAsset::new_unchecked(current);
+                    current
+                        .sub(asset.amount())
+                        .map_err(AssetVaultError::SubtractFungibleAssetBalanceError)?;
+                    match current.amount() {
+                        0 => Smt::EMPTY_VALUE,
+                        _ => current.into(),
+                    }
+                },
+                Asset::NonFungible(asset) if current == Smt::EMPTY_VALUE => {
+                    return Err(AssetVaultError::NonFungibleAssetNotFound(asset))
+                },
+                Asset::NonFungible(_) => Smt::EMPTY_VALUE,
+            };
+            updates.insert(key, new);
+        }
+
+        self.apply_updates(updates);
+        Ok(())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the value stored under the specified key, taking into account pending updates
+    /// which have not yet been applied to the asset tree.
+    fn staged_value(&self, updates: &BTreeMap<Digest, Word>, key: &Digest) -> Word {
+        updates.get(key).copied().unwrap_or_else(|| self.asset_tree.get_value(key))
+    }
+
+    /// Applies the accumulated updates to the asset tree, inserting each key exactly once.
+    ///
+    /// The version of the Sparse Merkle Tree currently in use does not support computing and
+    /// applying a set of mutations at once, and thus, each update still recomputes the path from
+    /// its leaf to the root.
+    fn apply_updates(&mut self, updates: BTreeMap<Digest, Word>) {
+        for (key, value) in updates {
+            self.asset_tree.insert(key, value);
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Maps a vault key to a value which orders vault keys in the same way as they are ordered in
+/// the underlying Sparse Merkle Tree: first by leaf index (the most significant element of the
+/// key) and then by the remaining elements of the key, in reverse order.
+fn vault_key_order(key: &Word) -> [u64; 4] {
+    [key[3].as_int(), key[2].as_int(), key[1].as_int(), key[0].as_int()]
 }
 
 // SERIALIZATION
@@ -225,21 +410,184 @@ impl AssetVault {
 
 impl Serializable for AssetVault {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        // TODO: determine total number of assets in the vault without allocating the vector
-        let assets = self.assets().collect::<Vec<_>>();
+        let num_assets = self.num_assets();
 
         // TODO: either enforce that number of assets in the vault is never greater than
         // u32::MAX or use variable-length encoding for the number of assets
-        assert!(assets.len() <= u32::MAX as usize, "too many assets in the vault");
-        target.write_u32(assets.len() as u32);
-        target.write_many(&assets);
+        assert!(num_assets <= u32::MAX as usize, "too many assets in the vault");
+        target.write_u32(num_assets as u32);
+        target.write_many(self.assets());
     }
 }
 
 impl Deserializable for AssetVault {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let num_assets = source.read_u32()? as usize;
-        let assets = source.read_many::<Asset>(num_assets)?;
+        let assets = (0..num_assets)
+            .map(|_| Asset::read_from(source))
+            .collect::<Result<Vec<_>, _>>()?;
         Self::new(&assets).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
     }
 }
+
+/// An asset vault is serialized as a list of the assets it contains.
+#[cfg(feature = "serde")]
+impl serde::Serialize for AssetVault {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.assets())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AssetVault {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let assets = <alloc::vec::Vec<Asset> as serde::Deserialize>::deserialize(deserializer)?;
+        Self::new(&assets).map_err(serde::de::Error::custom)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{AssetVault, Deserializable, Serializable};
+    use crate::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            },
+            AccountId,
+        },
+        assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    };
+
+    fn build_vault_assets() -> Vec<Asset> {
+        let mut assets: Vec<Asset> = [
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, id)| {
+            let faucet_id = AccountId::try_from(id).unwrap();
+            FungibleAsset::new(faucet_id, 100 * (i as u64 + 1)).unwrap().into()
+        })
+        .collect();
+
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        for data in 0..4u8 {
+            let details = NonFungibleAssetDetails::new(faucet_id, vec![data; 4]).unwrap();
+            assets.push(NonFungibleAsset::new(&details).unwrap().into());
+        }
+
+        assets
+    }
+
+    #[test]
+    fn test_vault_balance_queries() {
+        let assets = build_vault_assets();
+        let vault = AssetVault::new(&assets).unwrap();
+
+        assert_eq!(vault.num_assets(), assets.len());
+        assert!(!vault.is_empty());
+        assert!(AssetVault::default().is_empty());
+
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+        assert_eq!(vault.fungible_balance(faucet_id).unwrap(), 200);
+
+        let non_fungible_faucet_id =
+            AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        assert!(vault.fungible_balance(non_fungible_faucet_id).is_err());
+
+        let stored = assets[3].unwrap_non_fungible();
+        assert!(vault.has_non_fungible(&stored));
+
+        let details = NonFungibleAssetDetails::new(non_fungible_faucet_id, vec![9; 4]).unwrap();
+        let missing = NonFungibleAsset::new(&details).unwrap();
+        assert!(!vault.has_non_fungible(&missing));
+    }
+
+    #[test]
+    fn test_vault_pagination() {
+        let assets = build_vault_assets();
+        let vault = AssetVault::new(&assets).unwrap();
+
+        // walk the vault in pages of 3 assets
+        let mut paged = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page: Vec<Asset> = vault.iter_assets(cursor, 3).collect();
+            if page.is_empty() {
+                break;
+            }
+            assert!(page.len() <= 3);
+            cursor = page.last().map(|asset| asset.vault_key());
+            paged.extend(page);
+        }
+
+        assert_eq!(paged, vault.assets().collect::<Vec<_>>());
+        assert_eq!(vault.iter_assets(None, 0).count(), 0);
+    }
+
+    #[test]
+    fn test_vault_batch_updates() {
+        let assets = build_vault_assets();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let extra: Asset = FungibleAsset::new(faucet_id, 50).unwrap().into();
+
+        // batch additions match adding the assets one by one
+        let mut batched = AssetVault::default();
+        batched.add_assets(assets.iter().copied().chain([extra])).unwrap();
+        let mut sequential = AssetVault::default();
+        for &asset in assets.iter().chain([&extra]) {
+            sequential.add_asset(asset).unwrap();
+        }
+        assert_eq!(batched, sequential);
+        assert_eq!(batched.fungible_balance(faucet_id).unwrap(), 150);
+
+        // batch removals match removing the assets one by one
+        batched.remove_assets([extra, assets[0], assets[3]]).unwrap();
+        for asset in [extra, assets[0], assets[3]] {
+            sequential.remove_asset(asset).unwrap();
+        }
+        assert_eq!(batched, sequential);
+        assert_eq!(batched.fungible_balance(faucet_id).unwrap(), 0);
+        assert!(!batched.has_non_fungible_asset(assets[3]).unwrap());
+
+        // a failing batch leaves the vault unchanged
+        let before = batched.clone();
+        assert!(batched.add_assets([extra, assets[4]]).is_err());
+        assert!(batched.remove_assets([assets[1], assets[1], assets[1]]).is_err());
+        assert!(batched.remove_assets([assets[5], assets[5]]).is_err());
+        assert_eq!(batched, before);
+    }
+
+    #[test]
+    fn test_vault_serde() {
+        let vault = AssetVault::new(&build_vault_assets()).unwrap();
+        let deserialized = AssetVault::read_from_bytes(&vault.to_bytes()).unwrap();
+        assert_eq!(vault, deserialized);
+
+        // a large number of assets without the asset data is rejected
+        assert!(AssetVault::read_from_bytes(&u32::MAX.to_le_bytes()).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_vault_json() {
+        let vault = AssetVault::new(&build_vault_assets()).unwrap();
+        let json = serde_json::to_string(&vault).unwrap();
+        let deserialized: AssetVault = serde_json::from_str(&json).unwrap();
+        assert_eq!(vault, deserialized);
+
+        // duplicate assets are rejected
+        let asset = serde_json::to_value(vault.assets().next().unwrap()).unwrap();
+        let duplicates = serde_json::Value::Array(vec![asset.clone(), asset]);
+        assert!(serde_json::from_value::<AssetVault>(duplicates).is_err());
+    }
+}
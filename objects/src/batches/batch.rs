@@ -0,0 +1,266 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use super::BatchNoteTree;
+use crate::{
+    notes::{NoteHeader, NoteId},
+    transaction::{
+        build_input_notes_commitment, InputNoteCommitment, OutputNote, ProvenTransaction,
+        TransactionId,
+    },
+    BatchError, Digest, MAX_NOTES_PER_BATCH, MAX_TRANSACTIONS_PER_BATCH,
+};
+
+// TRANSACTION BATCH
+// ================================================================================================
+
+/// A batch of proven transactions, together with the notes consumed and created by the batch.
+///
+/// Notes which are created by a transaction of the batch and consumed as unauthenticated notes by
+/// another transaction of the same batch are erased: neither the note nor its nullifier is
+/// included in the notes of the batch, and thus such ephemeral notes never hit the chain. The
+/// headers of the erased notes are available via [TransactionBatch::erased_notes()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionBatch {
+    transactions: Vec<TransactionId>,
+    input_notes: Vec<InputNoteCommitment>,
+    input_notes_commitment: Digest,
+    output_notes: Vec<OutputNote>,
+    output_notes_tree: BatchNoteTree,
+    erased_notes: Vec<NoteHeader>,
+}
+
+impl TransactionBatch {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionBatch] built from the provided transactions.
+    ///
+    /// The input and output notes of the batch are the notes of the transactions in the order of
+    /// the transactions, with the notes created and consumed within the batch erased.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of transactions is greater than [MAX_TRANSACTIONS_PER_BATCH].
+    /// - A note is consumed by several transactions of the batch.
+    /// - A note is created by several transactions of the batch.
+    /// - An unauthenticated note consumed by a transaction has the same ID as a note created in
+    ///   the batch, but different metadata.
+    /// - The number of output notes remaining after erasure is greater than
+    ///   [MAX_NOTES_PER_BATCH].
+    pub fn new(transactions: &[ProvenTransaction]) -> Result<Self, BatchError> {
+        if transactions.len() > MAX_TRANSACTIONS_PER_BATCH {
+            return Err(BatchError::TooManyTransactions(transactions.len()));
+        }
+
+        let tx_ids = transactions.iter().map(ProvenTransaction::id).collect();
+        let input_notes =
+            transactions.iter().flat_map(|tx| tx.input_notes().iter().cloned()).collect();
+        let output_notes =
+            transactions.iter().flat_map(|tx| tx.output_notes().iter().cloned()).collect();
+
+        Self::from_notes(tx_ids, input_notes, output_notes)
+    }
+
+    /// Returns a new [TransactionBatch] consisting of the specified transactions and their
+    /// combined input and output notes, erasing the notes created and consumed within the batch.
+    fn from_notes(
+        transactions: Vec<TransactionId>,
+        input_notes: Vec<InputNoteCommitment>,
+        output_notes: Vec<OutputNote>,
+    ) -> Result<Self, BatchError> {
+        let mut nullifiers = BTreeSet::new();
+        for note in input_notes.iter() {
+            if !nullifiers.insert(note.nullifier()) {
+                return Err(BatchError::DuplicateInputNote(note.nullifier()));
+            }
+        }
+
+        let mut output_note_map = BTreeMap::new();
+        for note in output_notes.iter() {
+            if output_note_map.insert(note.id(), note).is_some() {
+                return Err(BatchError::DuplicateOutputNote(note.id()));
+            }
+        }
+
+        // match unauthenticated input notes against the output notes of the batch
+        let mut erased_notes = Vec::new();
+        let mut erased_note_ids: BTreeSet<NoteId> = BTreeSet::new();
+        for header in input_notes.iter().filter_map(InputNoteCommitment::header) {
+            if let Some(output_note) = output_note_map.get(&header.id()) {
+                if output_note.metadata() != header.metadata() {
+                    return Err(BatchError::UnauthenticatedNoteMismatch {
+                        id: header.id(),
+                        expected: *output_note.metadata(),
+                        actual: *header.metadata(),
+                    });
+                }
+                erased_note_ids.insert(header.id());
+                erased_notes.push(*header);
+            }
+        }
+
+        let is_erased = |header: Option<&NoteHeader>| {
+            header.is_some_and(|header| erased_note_ids.contains(&header.id()))
+        };
+        let input_notes: Vec<_> =
+            input_notes.into_iter().filter(|note| !is_erased(note.header())).collect();
+        let output_notes: Vec<_> = output_notes
+            .into_iter()
+            .filter(|note| !erased_note_ids.contains(&note.id()))
+            .collect();
+
+        if output_notes.len() > MAX_NOTES_PER_BATCH {
+            return Err(BatchError::TooManyNotes(output_notes.len()));
+        }
+
+        let input_notes_commitment = build_input_notes_commitment(&input_notes);
+        let output_notes_tree = BatchNoteTree::with_contiguous_leaves(
+            output_notes.iter().map(|note| (note.id(), note.metadata())),
+        )
+        .expect("number of output notes is within the capacity of the batch note tree");
+
+        Ok(Self {
+            transactions,
+            input_notes,
+            input_notes_commitment,
+            output_notes,
+            output_notes_tree,
+            erased_notes,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the IDs of the transactions in this batch.
+    pub fn transactions(&self) -> &[TransactionId] {
+        &self.transactions
+    }
+
+    /// Returns the notes consumed by this batch, excluding the erased notes.
+    pub fn input_notes(&self) -> &[InputNoteCommitment] {
+        &self.input_notes
+    }
+
+    /// Returns the commitment to the notes consumed by this batch.
+    ///
+    /// The commitment is computed in the same way as the input notes commitment of a transaction,
+    /// over the input notes of the batch.
+    pub fn input_notes_commitment(&self) -> Digest {
+        self.input_notes_commitment
+    }
+
+    /// Returns the notes created by this batch, excluding the erased notes.
+    pub fn output_notes(&self) -> &[OutputNote] {
+        &self.output_notes
+    }
+
+    /// Returns the root of the note tree of the notes created by this batch.
+    pub fn output_notes_root(&self) -> Digest {
+        self.output_notes_tree.root()
+    }
+
+    /// Returns the headers of the notes which were both created and consumed within this batch.
+    pub fn erased_notes(&self) -> &[NoteHeader] {
+        &self.erased_notes
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::TransactionBatch;
+    use crate::{
+        accounts::{account_id::testing::ACCOUNT_ID_SENDER, AccountId},
+        batches::BatchNoteTree,
+        notes::{NoteHeader, NoteId, NoteMetadata, NoteTag, NoteType, Nullifier},
+        transaction::{build_input_notes_commitment, InputNoteCommitment, OutputNote},
+        BatchError, Digest, Felt, ONE, ZERO,
+    };
+
+    fn mock_header(seed: u32, aux: Felt) -> NoteHeader {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let metadata =
+            NoteMetadata::new(sender, NoteType::Public, NoteTag::from(seed), aux).unwrap();
+        NoteHeader::new(NoteId::from(Digest::from([seed.into(), ZERO, ZERO, ZERO])), metadata)
+    }
+
+    fn mock_nullifier(seed: u32) -> Nullifier {
+        Nullifier::from(Digest::from([ZERO, seed.into(), ZERO, ZERO]))
+    }
+
+    #[test]
+    fn test_note_erasure() {
+        let ephemeral = mock_header(1, ZERO);
+        let created = mock_header(2, ZERO);
+        let consumed = InputNoteCommitment::new(mock_nullifier(3), None);
+        let unauthenticated =
+            InputNoteCommitment::new(mock_nullifier(4), Some(mock_header(4, ZERO)));
+
+        let input_notes = vec![
+            consumed.clone(),
+            InputNoteCommitment::new(mock_nullifier(1), Some(ephemeral)),
+            unauthenticated.clone(),
+        ];
+        let output_notes = vec![OutputNote::Header(ephemeral), OutputNote::Header(created)];
+
+        let batch = TransactionBatch::from_notes(Vec::new(), input_notes, output_notes).unwrap();
+
+        assert_eq!(batch.erased_notes(), &[ephemeral]);
+        assert_eq!(batch.input_notes(), &[consumed.clone(), unauthenticated.clone()]);
+        assert_eq!(batch.output_notes(), &[OutputNote::Header(created)]);
+
+        // the commitments are computed over the remaining notes only
+        assert_eq!(
+            batch.input_notes_commitment(),
+            build_input_notes_commitment(&[consumed, unauthenticated])
+        );
+        let tree =
+            BatchNoteTree::with_contiguous_leaves([(created.id(), created.metadata())]).unwrap();
+        assert_eq!(batch.output_notes_root(), tree.root());
+    }
+
+    #[test]
+    fn test_note_erasure_errors() {
+        let ephemeral = mock_header(1, ZERO);
+        let input_note = InputNoteCommitment::new(mock_nullifier(1), Some(ephemeral));
+
+        // an unauthenticated note must match the header of the created note
+        let mismatched = mock_header(1, ONE);
+        let result = TransactionBatch::from_notes(
+            Vec::new(),
+            vec![input_note.clone()],
+            vec![OutputNote::Header(mismatched)],
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            BatchError::UnauthenticatedNoteMismatch {
+                id: ephemeral.id(),
+                expected: *mismatched.metadata(),
+                actual: *ephemeral.metadata(),
+            }
+        );
+
+        // an ephemeral note cannot be consumed twice
+        let result = TransactionBatch::from_notes(
+            Vec::new(),
+            vec![input_note.clone(), input_note],
+            vec![OutputNote::Header(ephemeral)],
+        );
+        assert_eq!(result.unwrap_err(), BatchError::DuplicateInputNote(mock_nullifier(1)));
+
+        let result = TransactionBatch::from_notes(
+            Vec::new(),
+            Vec::new(),
+            vec![OutputNote::Header(ephemeral), OutputNote::Header(ephemeral)],
+        );
+        assert_eq!(result.unwrap_err(), BatchError::DuplicateOutputNote(ephemeral.id()));
+    }
+}
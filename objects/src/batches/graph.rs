@@ -0,0 +1,354 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use crate::{
+    accounts::AccountId,
+    notes::{NoteId, Nullifier},
+    transaction::{InputNoteCommitment, ProvenTransaction, TransactionId},
+    Digest, TransactionGraphError,
+};
+
+// TRANSACTION GRAPH
+// ================================================================================================
+
+/// A graph of the dependencies between a set of proven transactions.
+///
+/// A transaction depends on another transaction if:
+/// - Both transactions are executed against the same account, and the initial account state of
+///   the transaction is the final account state of the other transaction.
+/// - The transaction consumes an unauthenticated note created by the other transaction.
+///
+/// The graph is guaranteed to be acyclic and free of conflicts, and thus the transactions can be
+/// ordered so that every transaction follows the transactions it depends on (see
+/// [TransactionGraph::levels()]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionGraph {
+    dependencies: BTreeMap<TransactionId, BTreeSet<TransactionId>>,
+    levels: Vec<Vec<TransactionId>>,
+}
+
+impl TransactionGraph {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the dependency graph of the provided transactions.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - A transaction is provided more than once.
+    /// - A note is consumed by several transactions.
+    /// - A note is created by several transactions.
+    /// - Several transactions update the same state of an account.
+    /// - The dependencies between the transactions form a cycle.
+    pub fn new(transactions: &[ProvenTransaction]) -> Result<Self, TransactionGraphError> {
+        let mut dependencies = BTreeMap::new();
+        let mut nullifiers: BTreeMap<Nullifier, TransactionId> = BTreeMap::new();
+        let mut output_notes: BTreeMap<NoteId, TransactionId> = BTreeMap::new();
+        let mut account_updates: BTreeMap<(AccountId, Digest), TransactionId> = BTreeMap::new();
+
+        for tx in transactions {
+            if dependencies.insert(tx.id(), BTreeSet::new()).is_some() {
+                return Err(TransactionGraphError::DuplicateTransaction(tx.id()));
+            }
+
+            for note in tx.input_notes().iter() {
+                if nullifiers.insert(note.nullifier(), tx.id()).is_some() {
+                    return Err(TransactionGraphError::DuplicateInputNote(note.nullifier()));
+                }
+            }
+
+            for note in tx.output_notes().iter() {
+                if output_notes.insert(note.id(), tx.id()).is_some() {
+                    return Err(TransactionGraphError::DuplicateOutputNote(note.id()));
+                }
+            }
+
+            // transactions which do not change the account state cannot conflict with other
+            // transactions against the account
+            let update = tx.account_update();
+            if update.init_state_hash() != update.final_state_hash()
+                && account_updates
+                    .insert((tx.account_id(), update.init_state_hash()), tx.id())
+                    .is_some()
+            {
+                return Err(TransactionGraphError::ConflictingAccountUpdates {
+                    account_id: tx.account_id(),
+                    init_state_hash: update.init_state_hash(),
+                });
+            }
+        }
+
+        // map the account states to the transactions which produce them
+        let account_states: BTreeMap<(AccountId, Digest), TransactionId> = transactions
+            .iter()
+            .filter(|tx| {
+                tx.account_update().init_state_hash() != tx.account_update().final_state_hash()
+            })
+            .map(|tx| ((tx.account_id(), tx.account_update().final_state_hash()), tx.id()))
+            .collect();
+
+        for tx in transactions {
+            let tx_dependencies = dependencies.get_mut(&tx.id()).expect("transaction is in graph");
+
+            let init_state = (tx.account_id(), tx.account_update().init_state_hash());
+            if let Some(&dependency) = account_states.get(&init_state) {
+                if dependency != tx.id() {
+                    tx_dependencies.insert(dependency);
+                }
+            }
+
+            for header in tx.input_notes().iter().filter_map(InputNoteCommitment::header) {
+                if let Some(&dependency) = output_notes.get(&header.id()) {
+                    tx_dependencies.insert(dependency);
+                }
+            }
+        }
+
+        let levels = build_levels(&dependencies)?;
+
+        Ok(Self { dependencies, levels })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of transactions in this graph.
+    pub fn num_transactions(&self) -> usize {
+        self.dependencies.len()
+    }
+
+    /// Returns the IDs of the transactions on which the specified transaction directly depends, or
+    /// None if the transaction is not in this graph.
+    pub fn dependencies(&self, tx_id: &TransactionId) -> Option<&BTreeSet<TransactionId>> {
+        self.dependencies.get(tx_id)
+    }
+
+    /// Returns the transactions of this graph grouped into levels.
+    ///
+    /// Each transaction depends only on the transactions of the previous levels, and thus the
+    /// transactions of a level can be batched together once the transactions of the previous
+    /// levels were batched. The transactions of each level are sorted by their IDs.
+    pub fn levels(&self) -> &[Vec<TransactionId>] {
+        &self.levels
+    }
+
+    /// Returns the IDs of all transactions of this graph in topological order, i.e., every
+    /// transaction follows the transactions it depends on.
+    pub fn topological_order(&self) -> Vec<TransactionId> {
+        self.levels.iter().flatten().copied().collect()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Groups the transactions of the provided dependency graph into levels, such that each
+/// transaction depends only on the transactions of the previous levels.
+///
+/// # Errors
+/// Returns an error if the dependencies form a cycle.
+fn build_levels(
+    dependencies: &BTreeMap<TransactionId, BTreeSet<TransactionId>>,
+) -> Result<Vec<Vec<TransactionId>>, TransactionGraphError> {
+    let mut remaining = dependencies.clone();
+    let mut levels = Vec::new();
+
+    while !remaining.is_empty() {
+        let level: Vec<TransactionId> = remaining
+            .iter()
+            .filter(|(_, tx_dependencies)| tx_dependencies.is_empty())
+            .map(|(tx_id, _)| *tx_id)
+            .collect();
+
+        if level.is_empty() {
+            return Err(TransactionGraphError::DependencyCycle(remaining.into_keys().collect()));
+        }
+
+        for tx_id in level.iter() {
+            remaining.remove(tx_id);
+        }
+        for tx_dependencies in remaining.values_mut() {
+            level.iter().for_each(|tx_id| {
+                tx_dependencies.remove(tx_id);
+            });
+        }
+
+        levels.push(level);
+    }
+
+    Ok(levels)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use miden_verifier::ExecutionProof;
+    use winter_air::proof::StarkProof;
+
+    use super::TransactionGraph;
+    use crate::{
+        accounts::{
+            account_id::testing::{ACCOUNT_ID_OFF_CHAIN_SENDER, ACCOUNT_ID_SENDER},
+            AccountId,
+        },
+        notes::{NoteHeader, NoteId, NoteMetadata, NoteTag, NoteType, Nullifier},
+        transaction::{
+            InputNoteCommitment, KernelVersion, OutputNote, ProvenTransaction,
+            ProvenTransactionBuilder,
+        },
+        utils::serde::Deserializable,
+        Digest, Felt, TransactionGraphError, ZERO,
+    };
+
+    #[test]
+    fn transaction_graph_orders_dependent_transactions() {
+        let account_1 = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let account_2 = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+        // tx_2 updates the state produced by tx_1, and tx_3 consumes the note created by tx_2
+        let tx_1 = transaction(account_1, 1, 2, &[1], &[]);
+        let tx_2 = transaction(account_1, 2, 3, &[2], &[header(10)]);
+        let tx_3 = mock_tx(account_2, 1, 2, &[unauthenticated(10)], &[]);
+        let tx_4 = transaction(account_2, 5, 6, &[3], &[]);
+
+        let transactions = [tx_3.clone(), tx_4.clone(), tx_2.clone(), tx_1.clone()];
+        let graph = TransactionGraph::new(&transactions).unwrap();
+
+        assert_eq!(graph.num_transactions(), 4);
+        assert!(graph.dependencies(&tx_1.id()).unwrap().is_empty());
+        assert!(graph.dependencies(&tx_2.id()).unwrap().contains(&tx_1.id()));
+        assert!(graph.dependencies(&tx_3.id()).unwrap().contains(&tx_2.id()));
+
+        let mut first_level = vec![tx_1.id(), tx_4.id()];
+        first_level.sort();
+        assert_eq!(graph.levels(), [first_level, vec![tx_2.id()], vec![tx_3.id()]]);
+
+        let order = graph.topological_order();
+        let position = |tx: &ProvenTransaction| order.iter().position(|id| *id == tx.id());
+        assert!(position(&tx_1) < position(&tx_2));
+        assert!(position(&tx_2) < position(&tx_3));
+    }
+
+    #[test]
+    fn transaction_graph_detects_conflicts() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let other_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+        let tx = transaction(account_id, 1, 2, &[1], &[]);
+        assert_eq!(
+            TransactionGraph::new(&[tx.clone(), tx.clone()]),
+            Err(TransactionGraphError::DuplicateTransaction(tx.id()))
+        );
+
+        let other_tx = transaction(other_account_id, 3, 4, &[1], &[]);
+        assert_eq!(
+            TransactionGraph::new(&[tx.clone(), other_tx]),
+            Err(TransactionGraphError::DuplicateInputNote(nullifier(1)))
+        );
+
+        let tx_1 = transaction(account_id, 1, 2, &[1], &[header(10)]);
+        let tx_2 = transaction(other_account_id, 3, 4, &[2], &[header(10)]);
+        assert_eq!(
+            TransactionGraph::new(&[tx_1, tx_2]),
+            Err(TransactionGraphError::DuplicateOutputNote(header(10).id()))
+        );
+
+        let fork = transaction(account_id, 1, 3, &[2], &[]);
+        assert_eq!(
+            TransactionGraph::new(&[tx, fork]),
+            Err(TransactionGraphError::ConflictingAccountUpdates {
+                account_id,
+                init_state_hash: digest(1),
+            })
+        );
+    }
+
+    #[test]
+    fn transaction_graph_detects_cycles() {
+        let account_1 = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let account_2 = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+        // each transaction consumes the note created by the other transaction
+        let tx_1 =
+            mock_tx(account_1, 1, 2, &[unauthenticated(20)], &[OutputNote::Header(header(10))]);
+        let tx_2 =
+            mock_tx(account_2, 1, 2, &[unauthenticated(10)], &[OutputNote::Header(header(20))]);
+
+        let mut cycle = vec![tx_1.id(), tx_2.id()];
+        cycle.sort();
+        assert_eq!(
+            TransactionGraph::new(&[tx_1, tx_2]),
+            Err(TransactionGraphError::DependencyCycle(cycle))
+        );
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    fn transaction(
+        account_id: AccountId,
+        init: u64,
+        last: u64,
+        nullifiers: &[u64],
+        output_notes: &[NoteHeader],
+    ) -> ProvenTransaction {
+        let input_notes: Vec<_> = nullifiers
+            .iter()
+            .map(|seed| InputNoteCommitment::from(nullifier(*seed)))
+            .collect();
+        let output_notes: Vec<_> = output_notes.iter().copied().map(OutputNote::Header).collect();
+        mock_tx(account_id, init, last, &input_notes, &output_notes)
+    }
+
+    fn mock_tx(
+        account_id: AccountId,
+        init: u64,
+        last: u64,
+        input_notes: &[InputNoteCommitment],
+        output_notes: &[OutputNote],
+    ) -> ProvenTransaction {
+        // the hash function is deserialized from its byte encoding (BLAKE3-192)
+        let proof = ExecutionProof::new(
+            StarkProof::new_dummy(),
+            Deserializable::read_from_bytes(&[0]).unwrap(),
+        );
+        ProvenTransactionBuilder::new(
+            account_id,
+            digest(init),
+            digest(last),
+            Digest::default(),
+            KernelVersion::new(0, 1, 0, Digest::default()),
+            proof,
+        )
+        .add_input_notes(input_notes.iter().cloned())
+        .add_output_notes(output_notes.iter().cloned())
+        .build()
+        .unwrap()
+    }
+
+    fn digest(seed: u64) -> Digest {
+        Digest::from([Felt::new(seed), ZERO, ZERO, ZERO])
+    }
+
+    fn nullifier(seed: u64) -> Nullifier {
+        Nullifier::from(digest(seed))
+    }
+
+    fn header(seed: u64) -> NoteHeader {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let metadata = NoteMetadata::new(sender, NoteType::Public, NoteTag::from(0), ZERO).unwrap();
+        NoteHeader::new(NoteId::from(digest(seed)), metadata)
+    }
+
+    /// Returns the commitment to the unauthenticated note with the specified seed, whose nullifier
+    /// is derived from a different seed than the nullifiers of authenticated notes.
+    fn unauthenticated(seed: u64) -> InputNoteCommitment {
+        InputNoteCommitment::new(nullifier(seed + 1000), Some(header(seed)))
+    }
+}
@@ -1,2 +1,8 @@
+mod batch;
+pub use batch::TransactionBatch;
+
+mod graph;
+pub use graph::TransactionGraph;
+
 mod note_tree;
 pub use note_tree::BatchNoteTree;
@@ -0,0 +1,307 @@
+use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+
+use miden_crypto::merkle::{LeafIndex, MerklePath, SimpleSmt};
+
+use crate::{
+    accounts::AccountId,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    AccountTreeError, Digest, Felt, ACCOUNT_TREE_DEPTH, EMPTY_WORD,
+};
+
+// ACCOUNT TREE
+// ================================================================================================
+
+/// Wrapper over [SimpleSmt<ACCOUNT_TREE_DEPTH>] for the account tree of the chain.
+///
+/// The tree maps the ID of every account to the hash of the latest state of the account, with
+/// the account ID used as the leaf index. The IDs of accounts which are not in the tree map to
+/// the empty word, and thus recording the empty hash for an account removes it from the tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountTree(SimpleSmt<ACCOUNT_TREE_DEPTH>);
+
+impl AccountTree {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [AccountTree].
+    pub fn new() -> Self {
+        Self(SimpleSmt::new().expect("account tree depth is valid"))
+    }
+
+    /// Returns a new [AccountTree] recording the provided state hashes for the specified accounts.
+    ///
+    /// # Errors
+    /// Returns an error if any of the accounts is provided more than once.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (AccountId, Digest)>,
+    ) -> Result<Self, AccountTreeError> {
+        let mut tree = Self::new();
+        tree.apply_updates(entries)?;
+
+        Ok(tree)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of this tree.
+    pub fn root(&self) -> Digest {
+        self.0.root()
+    }
+
+    /// Returns the number of accounts recorded in this tree.
+    pub fn num_accounts(&self) -> usize {
+        self.0.num_leaves()
+    }
+
+    /// Returns the hash of the latest state of the specified account, or None if the account is
+    /// not in this tree.
+    pub fn get_account_hash(&self, account_id: AccountId) -> Option<Digest> {
+        let state_hash = self.0.get_leaf(&account_id.into());
+        (state_hash != EMPTY_WORD).then(|| state_hash.into())
+    }
+
+    /// Returns an iterator over the accounts recorded in this tree together with the hashes of
+    /// their latest states.
+    pub fn entries(&self) -> impl Iterator<Item = (AccountId, Digest)> + '_ {
+        self.0.leaves().map(|(index, state_hash)| {
+            (AccountId::new_unchecked(Felt::new(index)), Digest::from(*state_hash))
+        })
+    }
+
+    /// Returns a witness of the state of the specified account in this tree.
+    ///
+    /// For accounts which are not in this tree, the witness proves that the empty hash is
+    /// recorded for the account.
+    pub fn open(&self, account_id: AccountId) -> AccountWitness {
+        let opening = self.0.open(&account_id.into());
+        AccountWitness {
+            account_id,
+            state_hash: opening.value,
+            path: opening.path,
+        }
+    }
+
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records the specified hash as the latest state hash of the specified account, and returns
+    /// the previously recorded hash, or None if the account was not in this tree.
+    pub fn insert(&mut self, account_id: AccountId, state_hash: Digest) -> Option<Digest> {
+        let prev_hash = self.0.insert(account_id.into(), state_hash.into());
+        (prev_hash != EMPTY_WORD).then(|| prev_hash.into())
+    }
+
+    /// Records the provided state hashes for the specified accounts, e.g., the final states of the
+    /// accounts updated in a block.
+    ///
+    /// The updates are applied atomically: if an error is returned, this tree is not modified.
+    ///
+    /// # Errors
+    /// Returns an error if any of the accounts is updated more than once.
+    pub fn apply_updates(
+        &mut self,
+        updates: impl IntoIterator<Item = (AccountId, Digest)>,
+    ) -> Result<(), AccountTreeError> {
+        let updates: Vec<_> = updates.into_iter().collect();
+
+        let mut updated_accounts = BTreeSet::new();
+        for (account_id, _) in updates.iter() {
+            if !updated_accounts.insert(*account_id) {
+                return Err(AccountTreeError::DuplicateAccountUpdate(*account_id));
+            }
+        }
+
+        for (account_id, state_hash) in updates {
+            self.insert(account_id, state_hash);
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for AccountTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ACCOUNT WITNESS
+// ================================================================================================
+
+/// A Merkle proof of the state hash recorded for an account in an [AccountTree].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountWitness {
+    account_id: AccountId,
+    state_hash: Digest,
+    path: MerklePath,
+}
+
+impl AccountWitness {
+    /// Returns the ID of the account whose state is proven by this witness.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the state hash of the account according to this witness; the hash is empty if the
+    /// account is not in the tree.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+
+    /// Returns the Merkle path from the leaf of the account to the root of the tree.
+    pub fn path(&self) -> &MerklePath {
+        &self.path
+    }
+
+    /// Returns `true` if this witness proves that its state hash is recorded for the account in
+    /// the account tree with the specified root.
+    pub fn verify(&self, root: Digest) -> bool {
+        let leaf_index: LeafIndex<ACCOUNT_TREE_DEPTH> = self.account_id.into();
+        self.path.verify(leaf_index.value(), self.state_hash, &root)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AccountTree {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.num_accounts());
+        for (account_id, state_hash) in self.entries() {
+            account_id.write_into(target);
+            state_hash.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for AccountTree {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_entries = source.read_usize()?;
+        let mut entries = Vec::with_capacity(num_entries);
+        for _ in 0..num_entries {
+            let account_id = AccountId::read_from(source)?;
+            let state_hash = Digest::read_from(source)?;
+            entries.push((account_id, state_hash));
+        }
+
+        Self::with_entries(entries)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+impl Serializable for AccountWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        self.state_hash.write_into(target);
+        self.path.write_into(target);
+    }
+}
+
+impl Deserializable for AccountWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_id = AccountId::read_from(source)?;
+        let state_hash = Digest::read_from(source)?;
+        let path = MerklePath::read_from(source)?;
+
+        Ok(Self { account_id, state_hash, path })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{AccountTree, AccountWitness};
+    use crate::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_OFF_CHAIN_SENDER, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            },
+            AccountId,
+        },
+        utils::serde::{Deserializable, Serializable},
+        AccountTreeError, Digest, Felt, ZERO,
+    };
+
+    fn account_ids() -> (AccountId, AccountId) {
+        (
+            AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap(),
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap(),
+        )
+    }
+
+    fn state_hash(seed: u64) -> Digest {
+        Digest::from([Felt::new(seed), ZERO, ZERO, ZERO])
+    }
+
+    #[test]
+    fn account_tree_insert_and_open() {
+        let (account_a, account_b) = account_ids();
+        let mut tree = AccountTree::new();
+        let empty_root = tree.root();
+
+        assert_eq!(tree.insert(account_a, state_hash(1)), None);
+        assert_eq!(tree.insert(account_a, state_hash(2)), Some(state_hash(1)));
+        assert_eq!(tree.num_accounts(), 1);
+        assert_eq!(tree.get_account_hash(account_a), Some(state_hash(2)));
+        assert_eq!(tree.get_account_hash(account_b), None);
+
+        // witnesses prove the state hashes of the accounts in the tree
+        let witness = tree.open(account_a);
+        assert_eq!(witness.state_hash(), state_hash(2));
+        assert!(witness.verify(tree.root()));
+        assert!(!witness.verify(empty_root));
+
+        // and the empty hash of the accounts which are not in the tree
+        let witness = tree.open(account_b);
+        assert_eq!(witness.state_hash(), Digest::default());
+        assert!(witness.verify(tree.root()));
+
+        // recording the empty hash removes the account
+        tree.insert(account_a, Digest::default());
+        assert_eq!(tree.num_accounts(), 0);
+        assert_eq!(tree.root(), empty_root);
+    }
+
+    #[test]
+    fn account_tree_apply_updates() {
+        let (account_a, account_b) = account_ids();
+        let mut tree = AccountTree::with_entries([(account_a, state_hash(1))]).unwrap();
+
+        tree.apply_updates([(account_a, state_hash(2)), (account_b, state_hash(3))])
+            .unwrap();
+        assert_eq!(
+            tree,
+            AccountTree::with_entries([(account_b, state_hash(3)), (account_a, state_hash(2))])
+                .unwrap()
+        );
+
+        // duplicate updates are rejected without modifying the tree
+        let root = tree.root();
+        assert_eq!(
+            tree.apply_updates([(account_b, state_hash(4)), (account_b, state_hash(5))]),
+            Err(AccountTreeError::DuplicateAccountUpdate(account_b))
+        );
+        assert_eq!(tree.root(), root);
+    }
+
+    #[test]
+    fn account_tree_serialization() {
+        let (account_a, account_b) = account_ids();
+        let tree =
+            AccountTree::with_entries([(account_a, state_hash(1)), (account_b, state_hash(2))])
+                .unwrap();
+
+        let deserialized = AccountTree::read_from_bytes(&tree.to_bytes()).unwrap();
+        assert_eq!(deserialized, tree);
+        assert_eq!(deserialized.entries().collect::<Vec<_>>(), tree.entries().collect::<Vec<_>>());
+
+        let witness = tree.open(account_b);
+        let deserialized = AccountWitness::read_from_bytes(&witness.to_bytes()).unwrap();
+        assert_eq!(deserialized, witness);
+    }
+}
@@ -1,8 +1,10 @@
 use alloc::vec::Vec;
 
 use super::{Digest, Felt, Hasher, ZERO};
-use crate::utils::serde::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+use crate::{
+    crypto::merkle::{MmrPeaks, PartialMmr},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    BlockHeaderError, BLOCK_EPOCH_LENGTH_BITS, MAX_BLOCK_TIMESTAMP_DRIFT,
 };
 
 /// The header of a block. It contains metadata about the block, commitments to the current
@@ -155,6 +157,125 @@ impl BlockHeader {
         self.timestamp
     }
 
+    /// Returns the epoch to which this block belongs.
+    pub fn epoch(&self) -> u16 {
+        (self.block_num >> BLOCK_EPOCH_LENGTH_BITS) as u16
+    }
+
+    /// Returns the number of the first block of the specified epoch.
+    pub const fn epoch_block_num(epoch: u16) -> u32 {
+        (epoch as u32) << BLOCK_EPOCH_LENGTH_BITS
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Validates this block header against the header of the previous block.
+    ///
+    /// The following rules are checked:
+    /// - The block number is the block number of the previous block incremented by one.
+    /// - The previous block hash is the hash of the previous block header.
+    /// - The protocol version is not smaller than the version of the previous block.
+    /// - The timestamp is not smaller than the timestamp of the previous block.
+    ///
+    /// # Errors
+    /// Returns an error if any of the above rules is violated.
+    pub fn validate_against(&self, prev: &BlockHeader) -> Result<(), BlockHeaderError> {
+        let expected_block_num =
+            prev.block_num.checked_add(1).ok_or(BlockHeaderError::BlockNumMismatch {
+                expected: u32::MAX,
+                actual: self.block_num,
+            })?;
+        if self.block_num != expected_block_num {
+            return Err(BlockHeaderError::BlockNumMismatch {
+                expected: expected_block_num,
+                actual: self.block_num,
+            });
+        }
+
+        if self.prev_hash != prev.hash {
+            return Err(BlockHeaderError::PrevHashMismatch {
+                expected: prev.hash,
+                actual: self.prev_hash,
+            });
+        }
+
+        if self.version < prev.version {
+            return Err(BlockHeaderError::VersionDecreased {
+                prev: prev.version,
+                actual: self.version,
+            });
+        }
+
+        if self.timestamp < prev.timestamp {
+            return Err(BlockHeaderError::TimestampBeforePrevBlock {
+                prev: prev.timestamp,
+                actual: self.timestamp,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Validates that the chain root of this block header commits to the chain of the previous
+    /// block extended with the previous block.
+    ///
+    /// The provided peaks are expected to be the peaks of the chain MMR committed to by the
+    /// previous block, i.e., an MMR containing the hashes of all blocks before the previous
+    /// block.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of leaves of the provided peaks is not the block number of the previous block.
+    /// - The provided peaks do not hash to the chain root of the previous block.
+    /// - The peaks extended with the hash of the previous block do not hash to the chain root of
+    ///   this block.
+    pub fn validate_chain_root(
+        &self,
+        prev: &BlockHeader,
+        prev_chain_peaks: MmrPeaks,
+    ) -> Result<(), BlockHeaderError> {
+        if prev_chain_peaks.num_leaves() != prev.block_num as usize {
+            return Err(BlockHeaderError::ChainLengthMismatch {
+                expected: prev.block_num,
+                actual: prev_chain_peaks.num_leaves(),
+            });
+        }
+
+        if prev_chain_peaks.hash_peaks() != prev.chain_root {
+            return Err(BlockHeaderError::ChainRootMismatch {
+                expected: prev.chain_root,
+                actual: prev_chain_peaks.hash_peaks(),
+            });
+        }
+
+        let mut chain = PartialMmr::from_peaks(prev_chain_peaks);
+        chain.add(prev.hash, false);
+        let chain_root = chain.peaks().hash_peaks();
+        if self.chain_root != chain_root {
+            return Err(BlockHeaderError::ChainRootMismatch {
+                expected: chain_root,
+                actual: self.chain_root,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Validates that the timestamp of this block header is not ahead of the provided current
+    /// time (in seconds since UNIX epoch) by more than [MAX_BLOCK_TIMESTAMP_DRIFT] seconds.
+    ///
+    /// # Errors
+    /// Returns an error if the timestamp is too far in the future.
+    pub fn validate_timestamp(&self, now: u32) -> Result<(), BlockHeaderError> {
+        let max = now.saturating_add(MAX_BLOCK_TIMESTAMP_DRIFT);
+        if self.timestamp > max {
+            return Err(BlockHeaderError::TimestampTooFarInFuture { max, actual: self.timestamp });
+        }
+
+        Ok(())
+    }
+
     // HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -188,6 +309,139 @@ impl BlockHeader {
     }
 }
 
+// BLOCK HEADER BUILDER
+// ================================================================================================
+
+/// Builder for a [BlockHeader].
+///
+/// All fields which are not set explicitly default to zero values.
+#[derive(Debug, Clone, Default)]
+pub struct BlockHeaderBuilder {
+    version: u32,
+    prev_hash: Digest,
+    block_num: u32,
+    chain_root: Digest,
+    account_root: Digest,
+    nullifier_root: Digest,
+    note_root: Digest,
+    batch_root: Digest,
+    proof_hash: Digest,
+    timestamp: u32,
+}
+
+impl BlockHeaderBuilder {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [BlockHeaderBuilder] with all fields set to zero values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new [BlockHeaderBuilder] for the block following the specified block.
+    ///
+    /// The version, account root, nullifier root, and timestamp are copied from the previous
+    /// block, the previous block hash is set to the hash of the previous block, and the block
+    /// number is set to the block number of the previous block incremented by one. All other
+    /// fields are set to zero values.
+    pub fn child_of(prev: &BlockHeader) -> Self {
+        Self {
+            version: prev.version(),
+            prev_hash: prev.hash(),
+            block_num: prev.block_num() + 1,
+            account_root: prev.account_root(),
+            nullifier_root: prev.nullifier_root(),
+            timestamp: prev.timestamp(),
+            ..Self::default()
+        }
+    }
+
+    // FIELD SETTERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the protocol version.
+    pub fn version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets the hash of the previous block header.
+    pub fn prev_hash(mut self, prev_hash: Digest) -> Self {
+        self.prev_hash = prev_hash;
+        self
+    }
+
+    /// Sets the block number.
+    pub fn block_num(mut self, block_num: u32) -> Self {
+        self.block_num = block_num;
+        self
+    }
+
+    /// Sets the chain root.
+    pub fn chain_root(mut self, chain_root: Digest) -> Self {
+        self.chain_root = chain_root;
+        self
+    }
+
+    /// Sets the account database root.
+    pub fn account_root(mut self, account_root: Digest) -> Self {
+        self.account_root = account_root;
+        self
+    }
+
+    /// Sets the nullifier database root.
+    pub fn nullifier_root(mut self, nullifier_root: Digest) -> Self {
+        self.nullifier_root = nullifier_root;
+        self
+    }
+
+    /// Sets the note root.
+    pub fn note_root(mut self, note_root: Digest) -> Self {
+        self.note_root = note_root;
+        self
+    }
+
+    /// Sets the batch root.
+    pub fn batch_root(mut self, batch_root: Digest) -> Self {
+        self.batch_root = batch_root;
+        self
+    }
+
+    /// Sets the proof hash.
+    pub fn proof_hash(mut self, proof_hash: Digest) -> Self {
+        self.proof_hash = proof_hash;
+        self
+    }
+
+    /// Sets the timestamp, in seconds since UNIX epoch.
+    pub fn timestamp(mut self, timestamp: u32) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    // BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds the [BlockHeader].
+    pub fn build(self) -> BlockHeader {
+        BlockHeader::new(
+            self.version,
+            self.prev_hash,
+            self.block_num,
+            self.chain_root,
+            self.account_root,
+            self.nullifier_root,
+            self.note_root,
+            self.batch_root,
+            self.proof_hash,
+            self.timestamp,
+        )
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
 impl Serializable for BlockHeader {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.version.write_into(target);
@@ -233,13 +487,9 @@ impl Deserializable for BlockHeader {
 
 #[cfg(all(feature = "testing", not(target_family = "wasm")))]
 mod mock {
-    use alloc::vec::Vec;
-
     use winter_rand_utils as rand;
 
-    use crate::{
-        accounts::Account, crypto::merkle::SimpleSmt, BlockHeader, Digest, Felt, ACCOUNT_TREE_DEPTH,
-    };
+    use crate::{accounts::Account, block::AccountTree, BlockHeader, Digest};
 
     impl BlockHeader {
         pub fn mock(
@@ -248,18 +498,8 @@ mod mock {
             note_root: Option<Digest>,
             accts: &[Account],
         ) -> Self {
-            let acct_db = SimpleSmt::<ACCOUNT_TREE_DEPTH>::with_leaves(
-                accts
-                    .iter()
-                    .flat_map(|acct| {
-                        if acct.is_new() {
-                            None
-                        } else {
-                            let felt_id: Felt = acct.id().into();
-                            Some((felt_id.as_int(), *acct.hash()))
-                        }
-                    })
-                    .collect::<Vec<_>>(),
+            let acct_db = AccountTree::with_entries(
+                accts.iter().filter(|acct| !acct.is_new()).map(|acct| (acct.id(), acct.hash())),
             )
             .expect("failed to create account db");
 
@@ -292,6 +532,7 @@ mod tests {
     use winter_rand_utils::rand_array;
 
     use super::*;
+    use crate::crypto::merkle::Mmr;
 
     #[test]
     fn test_serde() {
@@ -302,4 +543,73 @@ mod tests {
 
         assert_eq!(deserialized, header);
     }
+
+    #[test]
+    fn test_validate_against() {
+        let mut chain = Mmr::new();
+        let genesis = BlockHeaderBuilder::new()
+            .chain_root(chain.peaks(0).unwrap().hash_peaks())
+            .timestamp(100)
+            .build();
+        chain.add(genesis.hash());
+
+        let block = BlockHeaderBuilder::child_of(&genesis)
+            .chain_root(chain.peaks(chain.forest()).unwrap().hash_peaks())
+            .timestamp(110)
+            .build();
+        assert_eq!(block.block_num(), 1);
+        assert_eq!(block.validate_against(&genesis), Ok(()));
+        assert_eq!(block.validate_chain_root(&genesis, Mmr::new().peaks(0).unwrap()), Ok(()));
+        assert_eq!(block.validate_timestamp(100), Ok(()));
+        assert_eq!(
+            block.validate_timestamp(10),
+            Err(BlockHeaderError::TimestampTooFarInFuture {
+                max: 10 + MAX_BLOCK_TIMESTAMP_DRIFT,
+                actual: 110
+            })
+        );
+
+        let invalid = BlockHeaderBuilder::child_of(&genesis).block_num(2).build();
+        assert_eq!(
+            invalid.validate_against(&genesis),
+            Err(BlockHeaderError::BlockNumMismatch { expected: 1, actual: 2 })
+        );
+
+        let invalid = BlockHeaderBuilder::child_of(&genesis).prev_hash(Digest::default()).build();
+        assert_eq!(
+            invalid.validate_against(&genesis),
+            Err(BlockHeaderError::PrevHashMismatch {
+                expected: genesis.hash(),
+                actual: Digest::default()
+            })
+        );
+
+        let invalid = BlockHeaderBuilder::child_of(&genesis).timestamp(99).build();
+        assert_eq!(
+            invalid.validate_against(&genesis),
+            Err(BlockHeaderError::TimestampBeforePrevBlock { prev: 100, actual: 99 })
+        );
+
+        // the chain root must commit to the previous block
+        let invalid = BlockHeaderBuilder::child_of(&genesis).build();
+        assert!(matches!(
+            invalid.validate_chain_root(&genesis, Mmr::new().peaks(0).unwrap()),
+            Err(BlockHeaderError::ChainRootMismatch { .. })
+        ));
+        assert_eq!(
+            block.validate_chain_root(&genesis, chain.peaks(chain.forest()).unwrap()),
+            Err(BlockHeaderError::ChainLengthMismatch { expected: 0, actual: 1 })
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json() {
+        let header =
+            BlockHeader::mock(0, Some(rand_array().into()), Some(rand_array().into()), &[]);
+        let json = serde_json::to_string(&header).unwrap();
+        let deserialized: BlockHeader = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, header);
+    }
 }
@@ -1,11 +1,15 @@
-use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+use alloc::{string::ToString, vec::Vec};
 
-use super::{Digest, Felt, Hasher, MAX_BATCHES_PER_BLOCK, MAX_NOTES_PER_BATCH, ZERO};
+use super::{Digest, Felt, Hasher, ZERO};
 
+mod account_tree;
+pub use account_tree::{AccountTree, AccountWitness};
 mod header;
-pub use header::BlockHeader;
+pub use header::{BlockHeader, BlockHeaderBuilder};
 mod note_tree;
 pub use note_tree::{BlockNoteIndex, BlockNoteTree};
+mod nullifier_tree;
+pub use nullifier_tree::{NullifierTree, NullifierWitness};
 
 use crate::{
     accounts::{delta::AccountUpdateDetails, AccountId},
@@ -100,20 +104,12 @@ impl Block {
     /// Each note is accompanied by a corresponding index specifying where the note is located
     /// in the blocks note tree.
     pub fn notes(&self) -> impl Iterator<Item = (BlockNoteIndex, &OutputNote)> {
-        self.created_notes.iter().enumerate().flat_map(|(batch_idx, notes)| {
-            notes.iter().enumerate().map(move |(note_idx_in_batch, note)| {
-                (BlockNoteIndex::new(batch_idx, note_idx_in_batch), note)
-            })
-        })
+        note_tree::batch_notes(&self.created_notes)
     }
 
     /// Returns a note tree containing all notes created in this block.
     pub fn build_note_tree(&self) -> BlockNoteTree {
-        let entries = self
-            .notes()
-            .map(|(note_index, note)| (note_index, note.id().into(), *note.metadata()));
-
-        BlockNoteTree::with_entries(entries)
+        BlockNoteTree::from_note_batches(&self.created_notes)
             .expect("Something went wrong: block is invalid, but passed or skipped validation")
     }
 
@@ -126,27 +122,7 @@ impl Block {
     // --------------------------------------------------------------------------------------------
 
     fn validate(&self) -> Result<(), BlockError> {
-        let batch_count = self.created_notes.len();
-        if batch_count > MAX_BATCHES_PER_BLOCK {
-            return Err(BlockError::TooManyTransactionBatches(batch_count));
-        }
-
-        for batch in self.created_notes.iter() {
-            if batch.len() > MAX_NOTES_PER_BATCH {
-                return Err(BlockError::TooManyNotesInBatch(batch.len()));
-            }
-        }
-
-        let mut notes = BTreeSet::new();
-        for batch in self.created_notes.iter() {
-            for note in batch.iter() {
-                if !notes.insert(note.id()) {
-                    return Err(BlockError::DuplicateNoteFound(note.id()));
-                }
-            }
-        }
-
-        Ok(())
+        note_tree::validate_note_batches(&self.created_notes)
     }
 }
 
@@ -191,7 +167,7 @@ pub struct BlockAccountUpdate {
 
     /// A set of changes which can be applied to the previous account state (i.e., the state as of
     /// the last block) to get the new account state. For private accounts, this is set to
-    /// [AccountUpdateDetails::Private].
+    /// [AccountUpdateDetails::Private] or [AccountUpdateDetails::PrivateSalted].
     details: AccountUpdateDetails,
 }
 
@@ -1,14 +1,17 @@
-use alloc::string::ToString;
+use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
 
 use miden_crypto::{
     hash::rpo::RpoDigest,
     merkle::{LeafIndex, MerkleError, MerklePath, SimpleSmt},
 };
 
+use super::{BlockHeader, NoteBatch};
 use crate::{
-    notes::NoteMetadata,
+    errors::BlockError,
+    notes::{NoteInclusionProof, NoteMetadata},
+    transaction::OutputNote,
     utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    BLOCK_OUTPUT_NOTES_TREE_DEPTH, MAX_NOTES_PER_BATCH,
+    Word, BLOCK_OUTPUT_NOTES_TREE_DEPTH, EMPTY_WORD, MAX_BATCHES_PER_BLOCK, MAX_NOTES_PER_BATCH,
 };
 
 /// Wrapper over [SimpleSmt<BLOCK_OUTPUT_NOTES_TREE_DEPTH>] for notes tree.
@@ -42,6 +45,26 @@ impl BlockNoteTree {
         SimpleSmt::with_leaves(interleaved).map(Self)
     }
 
+    /// Returns a new [BlockNoteTree] containing the notes of the provided batches.
+    ///
+    /// The notes are laid out in the order of the batches in the block and of the notes in the
+    /// batches, i.e., the note at position `j` of the batch at position `i` is stored at the index
+    /// `BlockNoteIndex::new(i, j)`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of batches exceeds [MAX_BATCHES_PER_BLOCK].
+    /// - The number of notes in any of the batches exceeds [MAX_NOTES_PER_BATCH].
+    /// - Any of the notes is contained in the batches more than once.
+    pub fn from_note_batches(batches: &[NoteBatch]) -> Result<Self, BlockError> {
+        validate_note_batches(batches)?;
+
+        let entries =
+            batch_notes(batches).map(|(index, note)| (index, note.id().inner(), *note.metadata()));
+
+        Ok(Self::with_entries(entries).expect("note indexes are unique and within tree capacity"))
+    }
+
     /// Returns the root of the tree
     pub fn root(&self) -> RpoDigest {
         self.0.root()
@@ -60,6 +83,47 @@ impl BlockNoteTree {
 
         Ok(note_path.into())
     }
+
+    /// Returns the inclusion proof of the note at the specified index, for the block with the
+    /// provided header.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The note root of the block header is not the root of this tree.
+    /// - There is no note at the specified index.
+    pub fn get_note_inclusion_proof(
+        &self,
+        block_header: &BlockHeader,
+        index: BlockNoteIndex,
+    ) -> Result<NoteInclusionProof, BlockError> {
+        if block_header.note_root() != self.root() {
+            return Err(BlockError::NoteRootMismatch {
+                expected: self.root(),
+                actual: block_header.note_root(),
+            });
+        }
+
+        // the index of a note beyond the capacity of its batch would alias a note of the next
+        // batch
+        if index.note_idx_in_batch() >= MAX_NOTES_PER_BATCH {
+            return Err(BlockError::NoteNotFound(index));
+        }
+        let note_path = match LeafIndex::new(index.leaf_index()) {
+            Ok(leaf_index) if self.0.get_leaf(&leaf_index) != EMPTY_WORD => {
+                self.get_note_path(index).expect("note index is valid")
+            },
+            _ => return Err(BlockError::NoteNotFound(index)),
+        };
+
+        Ok(NoteInclusionProof::new(
+            block_header.block_num(),
+            block_header.sub_hash(),
+            block_header.note_root(),
+            index.to_absolute_index(),
+            note_path,
+        )
+        .expect("note index is within the note tree"))
+    }
 }
 
 impl Default for BlockNoteTree {
@@ -101,6 +165,44 @@ impl BlockNoteIndex {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an iterator over the notes of the provided batches together with their indexes in the
+/// note tree of the block.
+pub(super) fn batch_notes(
+    batches: &[NoteBatch],
+) -> impl Iterator<Item = (BlockNoteIndex, &OutputNote)> {
+    batches.iter().enumerate().flat_map(|(batch_idx, notes)| {
+        notes.iter().enumerate().map(move |(note_idx_in_batch, note)| {
+            (BlockNoteIndex::new(batch_idx, note_idx_in_batch), note)
+        })
+    })
+}
+
+/// Checks that the provided batches of notes fit into the note tree of a block, and that no note
+/// is contained in the batches more than once.
+pub(super) fn validate_note_batches(batches: &[NoteBatch]) -> Result<(), BlockError> {
+    if batches.len() > MAX_BATCHES_PER_BLOCK {
+        return Err(BlockError::TooManyTransactionBatches(batches.len()));
+    }
+
+    for batch in batches.iter() {
+        if batch.len() > MAX_NOTES_PER_BATCH {
+            return Err(BlockError::TooManyNotesInBatch(batch.len()));
+        }
+    }
+
+    let mut notes = BTreeSet::new();
+    for (_, note) in batch_notes(batches) {
+        if !notes.insert(note.id()) {
+            return Err(BlockError::DuplicateNoteFound(note.id()));
+        }
+    }
+
+    Ok(())
+}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -114,7 +216,9 @@ impl Serializable for BlockNoteTree {
 impl Deserializable for BlockNoteTree {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let count = source.read_u32()?;
-        let leaves = source.read_many(count as usize)?;
+        let leaves = (0..count)
+            .map(|_| <(u64, Word)>::read_from(source))
+            .collect::<Result<Vec<_>, _>>()?;
 
         SimpleSmt::with_leaves(leaves)
             .map(Self)
@@ -124,13 +228,22 @@ impl Deserializable for BlockNoteTree {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use miden_crypto::{
         merkle::SimpleSmt,
         utils::{Deserializable, Serializable},
         Felt, ONE, ZERO,
     };
 
-    use super::BlockNoteTree;
+    use super::{BlockNoteIndex, BlockNoteTree};
+    use crate::{
+        accounts::{account_id::testing::ACCOUNT_ID_SENDER, AccountId},
+        block::{BlockHeader, NoteBatch},
+        notes::{NoteHeader, NoteId, NoteMetadata, NoteTag, NoteType},
+        transaction::OutputNote,
+        BlockError, Digest, MAX_NOTES_PER_BATCH,
+    };
 
     #[test]
     fn test_serialization() {
@@ -145,4 +258,83 @@ mod tests {
 
         assert_eq!(deserialized_tree, initial_tree);
     }
+
+    #[test]
+    fn test_note_inclusion_proofs() {
+        let batches: Vec<NoteBatch> = vec![vec![note(1), note(2)], vec![], vec![note(3)]];
+        let tree = BlockNoteTree::from_note_batches(&batches).unwrap();
+        let header = block_header(tree.root());
+
+        for (batch_idx, batch) in batches.iter().enumerate() {
+            for (note_idx_in_batch, note) in batch.iter().enumerate() {
+                let index = BlockNoteIndex::new(batch_idx, note_idx_in_batch);
+                let proof = tree.get_note_inclusion_proof(&header, index).unwrap();
+
+                assert_eq!(proof.origin().block_num, header.block_num());
+                assert_eq!(proof.origin().node_index.value(), index.to_absolute_index());
+                assert_eq!(proof.sub_hash(), header.sub_hash());
+                let auth_hash = NoteHeader::from(note).authentication_hash();
+                assert!(proof.note_path().verify(
+                    index.to_absolute_index(),
+                    auth_hash,
+                    &header.note_root()
+                ));
+            }
+        }
+
+        // indexes without notes have no inclusion proofs
+        for index in [
+            BlockNoteIndex::new(1, 0),
+            BlockNoteIndex::new(0, 2),
+            BlockNoteIndex::new(0, MAX_NOTES_PER_BATCH),
+        ] {
+            assert_eq!(
+                tree.get_note_inclusion_proof(&header, index),
+                Err(BlockError::NoteNotFound(index))
+            );
+        }
+
+        let other_header = block_header(Digest::default());
+        assert_eq!(
+            tree.get_note_inclusion_proof(&other_header, BlockNoteIndex::new(0, 0)),
+            Err(BlockError::NoteRootMismatch {
+                expected: tree.root(),
+                actual: Digest::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_duplicate_notes() {
+        let batches: Vec<NoteBatch> = vec![vec![note(1)], vec![note(2), note(1)]];
+        assert_eq!(
+            BlockNoteTree::from_note_batches(&batches),
+            Err(BlockError::DuplicateNoteFound(note(1).id()))
+        );
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    fn note(seed: u64) -> OutputNote {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let metadata = NoteMetadata::new(sender, NoteType::Public, NoteTag::from(0), ZERO).unwrap();
+        let note_id = NoteId::from(Digest::from([Felt::new(seed), ZERO, ZERO, ZERO]));
+        OutputNote::Header(NoteHeader::new(note_id, metadata))
+    }
+
+    fn block_header(note_root: Digest) -> BlockHeader {
+        BlockHeader::new(
+            0,
+            Digest::default(),
+            7,
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            note_root,
+            Digest::default(),
+            Digest::default(),
+            0,
+        )
+    }
 }
@@ -0,0 +1,295 @@
+use alloc::{string::ToString, vec::Vec};
+
+use miden_crypto::merkle::{Smt, SmtProof};
+
+use crate::{
+    notes::Nullifier,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Digest, Felt, NullifierTreeError, Word, EMPTY_WORD, ZERO,
+};
+
+// NULLIFIER TREE
+// ================================================================================================
+
+/// Wrapper over [Smt] for the nullifier tree of the chain.
+///
+/// The tree maps the nullifier of every consumed note to the number of the block in which the
+/// note was consumed, stored as the leaf value `[block_num, 0, 0, 0]`. The nullifiers of notes
+/// which were not consumed map to [Smt::EMPTY_VALUE], and thus no nullifier can be recorded in the
+/// genesis block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NullifierTree(Smt);
+
+impl NullifierTree {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [NullifierTree].
+    pub fn new() -> Self {
+        Self(Smt::new())
+    }
+
+    /// Returns a new [NullifierTree] recording the provided nullifiers as consumed in the
+    /// specified blocks.
+    ///
+    /// # Errors
+    /// Returns an error if any of the nullifiers is provided more than once, or is recorded in the
+    /// genesis block.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (Nullifier, u32)>,
+    ) -> Result<Self, NullifierTreeError> {
+        let mut tree = Self::new();
+        for (nullifier, block_num) in entries {
+            tree.insert(nullifier, block_num)?;
+        }
+
+        Ok(tree)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of this tree.
+    pub fn root(&self) -> Digest {
+        self.0.root()
+    }
+
+    /// Returns the number of nullifiers recorded in this tree.
+    pub fn num_nullifiers(&self) -> usize {
+        self.0.entries().count()
+    }
+
+    /// Returns `true` if the specified nullifier is recorded in this tree, i.e., if the
+    /// corresponding note was consumed.
+    pub fn contains(&self, nullifier: Nullifier) -> bool {
+        self.0.get_value(&nullifier.inner()) != Smt::EMPTY_VALUE
+    }
+
+    /// Returns the number of the block in which the note with the specified nullifier was
+    /// consumed, or None if the note was not consumed.
+    pub fn get_block_num(&self, nullifier: Nullifier) -> Option<u32> {
+        block_num_from_value(self.0.get_value(&nullifier.inner()))
+    }
+
+    /// Returns an iterator over the nullifiers recorded in this tree together with the numbers of
+    /// the blocks in which they were recorded.
+    pub fn entries(&self) -> impl Iterator<Item = (Nullifier, u32)> + '_ {
+        self.0.entries().map(|(nullifier, value)| {
+            let block_num = block_num_from_value(*value).expect("recorded value is not empty");
+            (Nullifier::from(*nullifier), block_num)
+        })
+    }
+
+    /// Returns a witness of the state of the specified nullifier in this tree, which proves
+    /// either the inclusion of the nullifier or its non-inclusion.
+    pub fn open(&self, nullifier: Nullifier) -> NullifierWitness {
+        NullifierWitness {
+            nullifier,
+            proof: self.0.open(&nullifier.inner()),
+        }
+    }
+
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records the specified nullifier as consumed in the specified block.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The nullifier is already recorded in this tree.
+    /// - The block is the genesis block.
+    pub fn insert(
+        &mut self,
+        nullifier: Nullifier,
+        block_num: u32,
+    ) -> Result<(), NullifierTreeError> {
+        if block_num == 0 {
+            return Err(NullifierTreeError::GenesisBlockNullifier(nullifier));
+        }
+        if self.contains(nullifier) {
+            return Err(NullifierTreeError::NullifierAlreadySpent(nullifier));
+        }
+
+        self.0.insert(nullifier.inner(), block_num_to_value(block_num));
+        Ok(())
+    }
+}
+
+// NULLIFIER WITNESS
+// ================================================================================================
+
+/// A Merkle proof of the state of a nullifier in a [NullifierTree].
+///
+/// The witness proves that the nullifier is recorded in a tree with a given root together with
+/// the number of the block in which it was recorded, or that the nullifier is not recorded in the
+/// tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NullifierWitness {
+    nullifier: Nullifier,
+    proof: SmtProof,
+}
+
+impl NullifierWitness {
+    /// Returns the nullifier whose state is proven by this witness.
+    pub fn nullifier(&self) -> Nullifier {
+        self.nullifier
+    }
+
+    /// Returns the underlying Merkle proof of this witness.
+    pub fn proof(&self) -> &SmtProof {
+        &self.proof
+    }
+
+    /// Returns the number of the block in which the nullifier was recorded according to this
+    /// witness, or None if the nullifier is not recorded.
+    pub fn block_num(&self) -> Option<u32> {
+        self.proof.get(&self.nullifier.inner()).and_then(block_num_from_value)
+    }
+
+    /// Returns `true` if this witness proves that the nullifier is recorded in the nullifier tree
+    /// with the specified root.
+    pub fn verify_inclusion(&self, root: Digest) -> bool {
+        self.block_num().is_some_and(|block_num| {
+            self.proof.verify_membership(
+                &self.nullifier.inner(),
+                &block_num_to_value(block_num),
+                &root,
+            )
+        })
+    }
+
+    /// Returns `true` if this witness proves that the nullifier is not recorded in the nullifier
+    /// tree with the specified root, i.e., that the corresponding note was not consumed.
+    pub fn verify_non_inclusion(&self, root: Digest) -> bool {
+        self.proof.verify_membership(&self.nullifier.inner(), &EMPTY_WORD, &root)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn block_num_to_value(block_num: u32) -> Word {
+    [Felt::from(block_num), ZERO, ZERO, ZERO]
+}
+
+fn block_num_from_value(value: Word) -> Option<u32> {
+    if value == Smt::EMPTY_VALUE {
+        None
+    } else {
+        Some(value[0].as_int() as u32)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NullifierTree {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let entries: Vec<_> = self.entries().collect();
+        target.write_usize(entries.len());
+        for (nullifier, block_num) in entries {
+            nullifier.write_into(target);
+            target.write_u32(block_num);
+        }
+    }
+}
+
+impl Deserializable for NullifierTree {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_entries = source.read_usize()?;
+        let mut entries = Vec::with_capacity(num_entries);
+        for _ in 0..num_entries {
+            let nullifier = Nullifier::read_from(source)?;
+            let block_num = source.read_u32()?;
+            entries.push((nullifier, block_num));
+        }
+
+        Self::with_entries(entries)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+impl Serializable for NullifierWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.nullifier.write_into(target);
+        self.proof.write_into(target);
+    }
+}
+
+impl Deserializable for NullifierWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let nullifier = Nullifier::read_from(source)?;
+        let proof = SmtProof::read_from(source)?;
+
+        Ok(Self { nullifier, proof })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{NullifierTree, NullifierWitness};
+    use crate::{
+        notes::Nullifier,
+        utils::serde::{Deserializable, Serializable},
+        Digest, Felt, NullifierTreeError, ZERO,
+    };
+
+    fn nullifier(seed: u64) -> Nullifier {
+        Nullifier::from(Digest::from([Felt::new(seed), ZERO, ZERO, Felt::new(seed)]))
+    }
+
+    #[test]
+    fn nullifier_tree_insert_and_open() {
+        let mut tree = NullifierTree::new();
+        let empty_root = tree.root();
+        tree.insert(nullifier(1), 5).unwrap();
+        tree.insert(nullifier(2), 7).unwrap();
+
+        assert_eq!(tree.num_nullifiers(), 2);
+        assert!(tree.contains(nullifier(1)));
+        assert!(!tree.contains(nullifier(3)));
+        assert_eq!(tree.get_block_num(nullifier(2)), Some(7));
+        assert_eq!(tree.get_block_num(nullifier(3)), None);
+        assert_ne!(tree.root(), empty_root);
+
+        assert_eq!(
+            tree.insert(nullifier(1), 8),
+            Err(NullifierTreeError::NullifierAlreadySpent(nullifier(1)))
+        );
+        assert_eq!(
+            tree.insert(nullifier(3), 0),
+            Err(NullifierTreeError::GenesisBlockNullifier(nullifier(3)))
+        );
+
+        // witnesses prove the inclusion of spent nullifiers
+        let witness = tree.open(nullifier(1));
+        assert_eq!(witness.block_num(), Some(5));
+        assert!(witness.verify_inclusion(tree.root()));
+        assert!(!witness.verify_non_inclusion(tree.root()));
+        assert!(!witness.verify_inclusion(empty_root));
+
+        // and the non-inclusion of unspent nullifiers
+        let witness = tree.open(nullifier(3));
+        assert_eq!(witness.block_num(), None);
+        assert!(witness.verify_non_inclusion(tree.root()));
+        assert!(!witness.verify_inclusion(tree.root()));
+    }
+
+    #[test]
+    fn nullifier_tree_serialization() {
+        let tree =
+            NullifierTree::with_entries([(nullifier(1), 1), (nullifier(2), 3), (nullifier(3), 3)])
+                .unwrap();
+
+        let deserialized = NullifierTree::read_from_bytes(&tree.to_bytes()).unwrap();
+        assert_eq!(deserialized, tree);
+        assert_eq!(deserialized.root(), tree.root());
+
+        let witness = tree.open(nullifier(2));
+        let deserialized = NullifierWitness::read_from_bytes(&witness.to_bytes()).unwrap();
+        assert_eq!(deserialized, witness);
+    }
+}
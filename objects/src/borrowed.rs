@@ -0,0 +1,82 @@
+//! Borrowed reading of serialized objects.
+//!
+//! Borrowed views of serialized objects (e.g., [NoteAssetsRef](crate::notes::NoteAssetsRef) and
+//! [AccountStorageRef](crate::accounts::AccountStorageRef)) keep references into the source bytes
+//! rather than copying the data into newly allocated structures. The [ByteReader] implementations
+//! provided by winter-utils return slices which borrow the reader itself, and thus, a dedicated
+//! reader is needed to hand out slices which live as long as the source bytes.
+
+use crate::utils::serde::{ByteReader, DeserializationError};
+
+// BORROWED READER
+// ================================================================================================
+
+/// A [ByteReader] over a byte slice which can return sub-slices borrowing the underlying bytes.
+pub(crate) struct BorrowedReader<'a> {
+    source: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BorrowedReader<'a> {
+    /// Returns a new [BorrowedReader] reading from the start of the provided slice.
+    pub fn new(source: &'a [u8]) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    /// Returns the next `len` bytes of the source and advances the reader past them.
+    ///
+    /// Unlike [ByteReader::read_slice()], the returned slice borrows the source bytes rather than
+    /// the reader.
+    pub fn read_borrowed(&mut self, len: usize) -> Result<&'a [u8], DeserializationError> {
+        self.check_eor(len)?;
+        let result = &self.source[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(result)
+    }
+
+    /// Returns the source bytes from the specified position up to the current position of the
+    /// reader.
+    pub fn consumed_since(&self, start: usize) -> &'a [u8] {
+        &self.source[start..self.pos]
+    }
+
+    /// Returns the current position of the reader.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+}
+
+impl<'a> ByteReader for BorrowedReader<'a> {
+    fn read_u8(&mut self) -> Result<u8, DeserializationError> {
+        self.check_eor(1)?;
+        let result = self.source[self.pos];
+        self.pos += 1;
+        Ok(result)
+    }
+
+    fn peek_u8(&self) -> Result<u8, DeserializationError> {
+        self.check_eor(1)?;
+        Ok(self.source[self.pos])
+    }
+
+    fn read_slice(&mut self, len: usize) -> Result<&[u8], DeserializationError> {
+        self.read_borrowed(len)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], DeserializationError> {
+        let mut result = [0_u8; N];
+        result.copy_from_slice(self.read_borrowed(N)?);
+        Ok(result)
+    }
+
+    fn check_eor(&self, num_bytes: usize) -> Result<(), DeserializationError> {
+        match self.pos.checked_add(num_bytes) {
+            Some(end) if end <= self.source.len() => Ok(()),
+            _ => Err(DeserializationError::UnexpectedEOF),
+        }
+    }
+
+    fn has_more_bytes(&self) -> bool {
+        self.pos < self.source.len()
+    }
+}
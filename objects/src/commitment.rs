@@ -0,0 +1,107 @@
+//! Commitment schemes used to compute protocol commitments.
+//!
+//! Note IDs, note recipients, nullifiers, and account hashes are computed via the
+//! [DefaultCommitmentScheme], which is [RpoCommitment] (i.e., the RPO hash function used
+//! throughout the protocol) unless the `rpx-commitments` feature is enabled, in which case
+//! [RpxCommitment] is used instead.
+//!
+//! The transaction kernel computes these commitments using RPO, and thus, transactions executed
+//! by the kernel can be verified against commitments computed by this crate only with the default
+//! scheme. Alternative schemes are intended for contexts in which the commitments are computed
+//! outside of the Miden VM (e.g., for interoperability proofs).
+
+use crate::{
+    crypto::hash::rpx::{Rpx256, RpxDigest},
+    Digest, Felt, Hasher,
+};
+
+// COMMITMENT SCHEME
+// ================================================================================================
+
+/// A hash function which can be used to compute protocol commitments.
+pub trait CommitmentScheme {
+    /// Returns a commitment to the provided sequence of field elements.
+    fn hash_elements(elements: &[Felt]) -> Digest;
+
+    /// Returns a commitment to the provided pair of digests.
+    fn merge(values: &[Digest; 2]) -> Digest;
+}
+
+/// The commitment scheme used to compute protocol commitments.
+#[cfg(not(feature = "rpx-commitments"))]
+pub type DefaultCommitmentScheme = RpoCommitment;
+
+/// The commitment scheme used to compute protocol commitments.
+#[cfg(feature = "rpx-commitments")]
+pub type DefaultCommitmentScheme = RpxCommitment;
+
+// RPO COMMITMENT
+// ================================================================================================
+
+/// Commitment scheme based on the RPO hash function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RpoCommitment;
+
+impl CommitmentScheme for RpoCommitment {
+    fn hash_elements(elements: &[Felt]) -> Digest {
+        Hasher::hash_elements(elements)
+    }
+
+    fn merge(values: &[Digest; 2]) -> Digest {
+        Hasher::merge(values)
+    }
+}
+
+// RPX COMMITMENT
+// ================================================================================================
+
+/// Commitment scheme based on the RPX hash function.
+///
+/// RPX digests are represented by the same field elements as RPO digests, and thus, commitments
+/// computed via this scheme are returned as [Digest]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RpxCommitment;
+
+impl CommitmentScheme for RpxCommitment {
+    fn hash_elements(elements: &[Felt]) -> Digest {
+        to_digest(Rpx256::hash_elements(elements))
+    }
+
+    fn merge(values: &[Digest; 2]) -> Digest {
+        let values = values.map(|value| RpxDigest::new(value.into()));
+        to_digest(Rpx256::merge(&values))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn to_digest(digest: RpxDigest) -> Digest {
+    Digest::new(digest.into())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{CommitmentScheme, DefaultCommitmentScheme, RpoCommitment, RpxCommitment};
+    use crate::{Digest, Felt, Hasher, ONE, ZERO};
+
+    #[test]
+    fn commitment_schemes() {
+        let elements = [ONE, ZERO, Felt::new(3), ONE];
+        let values = [Digest::new(elements), Digest::default()];
+
+        assert_eq!(RpoCommitment::hash_elements(&elements), Hasher::hash_elements(&elements));
+        assert_eq!(RpoCommitment::merge(&values), Hasher::merge(&values));
+
+        assert_ne!(RpxCommitment::hash_elements(&elements), Hasher::hash_elements(&elements));
+        assert_ne!(RpxCommitment::merge(&values), Hasher::merge(&values));
+
+        #[cfg(not(feature = "rpx-commitments"))]
+        assert_eq!(DefaultCommitmentScheme::merge(&values), RpoCommitment::merge(&values));
+        #[cfg(feature = "rpx-commitments")]
+        assert_eq!(DefaultCommitmentScheme::merge(&values), RpxCommitment::merge(&values));
+    }
+}
@@ -0,0 +1,186 @@
+//! Compression of serialized payloads.
+//!
+//! Serialized protocol objects contain long runs of zero bytes: field elements holding small
+//! values (e.g., nonces, asset amounts, storage values) are encoded as 8-byte little-endian
+//! integers, and empty words, unused asset key elements and padding are all zeros. The encoding
+//! implemented here replaces runs of zero bytes with their length, which shrinks such payloads
+//! considerably while leaving incompressible data (e.g., hashes) at almost its original size.
+//!
+//! A compressed payload consists of the length of the decompressed payload followed by a sequence
+//! of runs. Each run starts with a header `(len << 1) | is_literal`, where a literal run is
+//! followed by `len` bytes copied verbatim and a zero run stands for `len` zero bytes. The length
+//! and the run headers are encoded as variable-length integers.
+//!
+//! Since a few bytes of a zero run can expand into an arbitrary number of zero bytes, the reader
+//! must specify the maximum size of the payload it expects, and decompression fails as soon as
+//! the payload would exceed it.
+
+use alloc::{string::ToString, vec::Vec};
+
+use crate::utils::serde::{ByteReader, ByteWriter, DeserializationError};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum size of a payload which can be compressed (64 MiB).
+pub const MAX_PAYLOAD_SIZE: usize = 1 << 26;
+
+/// Minimum length of a zero run which is encoded separately; shorter runs are cheaper to encode
+/// as a part of a literal run.
+const MIN_ZERO_RUN_LEN: usize = 3;
+
+// COMPRESSION
+// ================================================================================================
+
+/// Writes the compressed encoding of the provided payload into the target.
+///
+/// # Panics
+/// Panics if the payload is larger than [MAX_PAYLOAD_SIZE].
+pub fn write_compressed<W: ByteWriter>(target: &mut W, payload: &[u8]) {
+    assert!(payload.len() <= MAX_PAYLOAD_SIZE, "payload too large to be compressed");
+    target.write_usize(payload.len());
+
+    let mut literal_start = 0;
+    let mut pos = 0;
+    while pos < payload.len() {
+        let zeros = payload[pos..].iter().take_while(|&&byte| byte == 0).count();
+        if zeros >= MIN_ZERO_RUN_LEN || (zeros > 0 && pos + zeros == payload.len()) {
+            write_literal_run(target, &payload[literal_start..pos]);
+            target.write_usize(zeros << 1);
+            pos += zeros;
+            literal_start = pos;
+        } else {
+            pos += zeros.max(1);
+        }
+    }
+    write_literal_run(target, &payload[literal_start..]);
+}
+
+/// Reads a payload compressed with [write_compressed()] from the source and returns the
+/// decompressed payload, which must not be larger than `max_len` bytes.
+///
+/// The declared length of the payload is not trusted: memory for the payload is allocated only as
+/// its runs are decoded, and no run may extend the payload beyond the declared length.
+///
+/// # Errors
+/// Returns an error if:
+/// - The declared length of the decompressed payload is larger than `max_len`.
+/// - The runs do not add up to the declared length of the decompressed payload.
+/// - The source does not contain enough bytes.
+pub fn read_compressed<R: ByteReader>(
+    source: &mut R,
+    max_len: usize,
+) -> Result<Vec<u8>, DeserializationError> {
+    let len = source.read_usize()?;
+    if len > max_len {
+        return Err(DeserializationError::InvalidValue(format!(
+            "compressed payload of {len} bytes exceeds the maximum of {max_len} bytes"
+        )));
+    }
+
+    let mut payload = Vec::new();
+    while payload.len() < len {
+        let header = source.read_usize()?;
+        let run_len = header >> 1;
+        if run_len == 0 || run_len > len - payload.len() {
+            return Err(DeserializationError::InvalidValue(
+                "invalid run in compressed payload".to_string(),
+            ));
+        }
+
+        if header & 1 == 1 {
+            payload.extend_from_slice(source.read_slice(run_len)?);
+        } else {
+            payload.resize(payload.len() + run_len, 0);
+        }
+    }
+
+    Ok(payload)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes a literal run containing the provided bytes, unless there are no bytes to write.
+fn write_literal_run<W: ByteWriter>(target: &mut W, bytes: &[u8]) {
+    if !bytes.is_empty() {
+        target.write_usize((bytes.len() << 1) | 1);
+        target.write_bytes(bytes);
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{read_compressed, write_compressed};
+    use crate::utils::serde::{ByteReader, ByteWriter, SliceReader};
+
+    fn roundtrip(payload: &[u8]) -> Vec<u8> {
+        let mut compressed = Vec::new();
+        write_compressed(&mut compressed, payload);
+
+        let mut reader = SliceReader::new(&compressed);
+        assert_eq!(read_compressed(&mut reader, payload.len()).unwrap(), payload);
+        assert!(!reader.has_more_bytes());
+
+        compressed
+    }
+
+    #[test]
+    fn compressed_payload_roundtrip() {
+        roundtrip(&[]);
+        roundtrip(&[0]);
+        roundtrip(&[0, 0]);
+        roundtrip(&[1, 0, 0]);
+        roundtrip(&[1, 2, 3]);
+        roundtrip(&[0, 0, 0, 1, 0, 2, 0, 0, 0, 0]);
+
+        // a word of small field elements compresses to a fraction of its size
+        let word: Vec<u8> = [1_u64, 2, 3, 4].iter().flat_map(|x| x.to_le_bytes()).collect();
+        assert!(roundtrip(&word).len() < word.len() / 2);
+
+        // incompressible data grows only by a few bytes
+        let random: Vec<u8> = (1..=255).collect();
+        assert!(roundtrip(&random).len() <= random.len() + 4);
+    }
+
+    #[test]
+    fn malformed_compressed_payload() {
+        // declared length exceeds the maximum size expected by the reader
+        let mut bytes = Vec::new();
+        bytes.write_usize(2048);
+        bytes.write_usize(2048 << 1);
+        assert!(read_compressed(&mut SliceReader::new(&bytes), 1024).is_err());
+        assert_eq!(read_compressed(&mut SliceReader::new(&bytes), 2048).unwrap(), [0; 2048]);
+
+        // zero runs expanding beyond the declared length
+        let mut bytes = Vec::new();
+        bytes.write_usize(1024);
+        bytes.write_usize(1000 << 1);
+        bytes.write_usize(1000 << 1);
+        assert!(read_compressed(&mut SliceReader::new(&bytes), 1024).is_err());
+
+        // zero run longer than the declared length
+        let mut bytes = Vec::new();
+        bytes.write_usize(2);
+        bytes.write_usize(3 << 1);
+        assert!(read_compressed(&mut SliceReader::new(&bytes), 1024).is_err());
+
+        // empty run
+        let mut bytes = Vec::new();
+        bytes.write_usize(2);
+        bytes.write_usize(1);
+        assert!(read_compressed(&mut SliceReader::new(&bytes), 1024).is_err());
+
+        // truncated literal run
+        let mut bytes = Vec::new();
+        bytes.write_usize(2);
+        bytes.write_usize((2 << 1) | 1);
+        bytes.write_u8(1);
+        assert!(read_compressed(&mut SliceReader::new(&bytes), 1024).is_err());
+    }
+}
@@ -5,7 +5,7 @@ pub const ACCOUNT_TREE_DEPTH: u8 = 64;
 pub const NOTE_TREE_DEPTH: u8 = 20;
 
 /// The maximum number of assets that can be stored in a single note.
-pub const MAX_ASSETS_PER_NOTE: usize = 256;
+pub const MAX_ASSETS_PER_NOTE: usize = 255;
 
 /// The maximum number of inputs that can accompany a single note.
 ///
@@ -19,6 +19,12 @@ pub const MAX_INPUT_NOTES_PER_TX: usize = 1023;
 /// The maximum number of new notes created by a single transaction.
 pub const MAX_OUTPUT_NOTES_PER_TX: usize = 4096;
 
+/// The default maximum size (in bytes) of the serialized payload of a single output note.
+pub const MAX_NOTE_PAYLOAD_SIZE: usize = 1 << 16;
+
+/// The default maximum size (in bytes) of the serialized account update of a single transaction.
+pub const MAX_ACCOUNT_UPDATE_SIZE: usize = 1 << 20;
+
 /// The minimum proof security level used by the Miden prover & verifier.
 pub const MIN_PROOF_SECURITY_LEVEL: u32 = 96;
 
@@ -59,5 +65,17 @@ pub const BLOCK_OUTPUT_NOTES_TREE_DEPTH: u8 =
 /// Maximum number of batches that can be inserted into a single block.
 pub const MAX_BATCHES_PER_BLOCK: usize = 2_usize.pow(BLOCK_OUTPUT_NOTES_BATCH_TREE_DEPTH as u32);
 
+/// The maximum number of seconds by which the timestamp of a block may be ahead of the current
+/// time of the node validating the block.
+pub const MAX_BLOCK_TIMESTAMP_DRIFT: u32 = 60;
+
+/// The number of bits by which a block number is shifted to obtain the epoch of the block, i.e.,
+/// each epoch spans `2^16` blocks.
+pub const BLOCK_EPOCH_LENGTH_BITS: u8 = 16;
+
+/// The default maximum number of blocks by which the reference block of a transaction may lag
+/// behind the chain tip for the transaction to be accepted.
+pub const MAX_REFERENCE_BLOCK_DEPTH: u32 = 256;
+
 /// The block height of the genesis block
 pub const GENESIS_BLOCK: u32 = 0;
@@ -2,13 +2,16 @@ use alloc::{string::String, vec::Vec};
 use core::fmt;
 
 use assembly::AssemblyError;
+use miden_verifier::VerificationError;
 use vm_processor::DeserializationError;
 
 use super::{
-    accounts::{AccountId, StorageSlotType},
+    accounts::{AccountId, AccountIdVersion, StorageSlotType},
     assets::{Asset, FungibleAsset, NonFungibleAsset},
+    block::BlockNoteIndex,
     crypto::{hash::rpo::RpoDigest, merkle::MerkleError},
-    notes::NoteId,
+    notes::{NoteId, NoteMetadata, Nullifier},
+    transaction::{KernelVersion, TransactionId},
     Digest, Word, MAX_BATCHES_PER_BLOCK, MAX_NOTES_PER_BATCH,
 };
 use crate::{accounts::AccountType, notes::NoteType};
@@ -19,24 +22,104 @@ use crate::{accounts::AccountType, notes::NoteType};
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AccountError {
     AccountCodeAssemblerError(AssemblyError),
+    AccountCodeInvalidProcedureInfo(String),
     AccountCodeNoProcedures,
-    AccountCodeTooManyProcedures { max: usize, actual: usize },
+    AccountCodeProcedureCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    AccountCodeProcedureNotFound(Digest),
+    AccountCodeTooManyProcedures {
+        max: usize,
+        actual: usize,
+    },
+    AccountIdAnchorNotEpochBlock(u32),
     AccountIdInvalidFieldElement(String),
-    AccountIdTooFewOnes(u32, u32),
+    AccountIdInvalidLabel(String),
+    AccountIdLabelMismatch {
+        expected: String,
+        actual: String,
+    },
+    AccountIdTooFewOnes {
+        expected: u32,
+        actual: u32,
+    },
+    AccountIdVersionMismatch {
+        expected: AccountIdVersion,
+        actual: AccountIdVersion,
+    },
+    AccountTypeMismatch {
+        expected: AccountType,
+        actual: AccountType,
+    },
     AssetVaultUpdateError(AssetVaultError),
     DuplicateStorageItems(MerkleError),
+    FaucetAssetAmountOverflow,
+    FaucetAssetNotIssued(Asset),
+    FaucetAssetsExceedIssuance {
+        held: u64,
+        issued: u64,
+    },
+    FaucetIssuanceExceedsMaxSupply {
+        issued: u64,
+        max_supply: u64,
+    },
+    FaucetReservedSlotInvalidType {
+        expected: StorageSlotType,
+        actual: StorageSlotType,
+    },
+    FaucetReservedSlotInvalidValue(Word),
     FungibleFaucetIdInvalidFirstBit,
     FungibleFaucetInvalidMetadata(String),
     HexParseError(String),
     InvalidAccountStorageType,
-    NonceNotMonotonicallyIncreasing { current: u64, new: u64 },
-    SeedDigestTooFewTrailingZeros { expected: u32, actual: u32 },
-    StorageSlotInvalidValueArity { slot: u8, expected: u8, actual: u8 },
+    NonFungibleFaucetInvalidData(String),
+    NonceNotMonotonicallyIncreasing {
+        current: u64,
+        new: u64,
+    },
+    SeedDigestDifficultyTooHigh {
+        max: u32,
+        actual: u32,
+    },
+    SeedDigestTooFewTrailingZeros {
+        expected: u32,
+        actual: u32,
+    },
+    SeedGrindingCancelled,
+    StorageSlotInvalidValueArity {
+        slot: u8,
+        expected: u8,
+        actual: u8,
+    },
     StorageSlotIsReserved(u8),
-    StorageSlotArrayNotSupportedYet(u8, StorageSlotType),
-    StorageMapToManyMaps { expected: usize, actual: usize },
-    StorageMapNotFound { index: u64 },
-    StubDataIncorrectLength(usize, usize),
+    StorageSlotNotMap(u8),
+    StorageSlotArrayNotSupportedYet {
+        slot: u8,
+        slot_type: StorageSlotType,
+    },
+    StorageMapToManyMaps {
+        expected: usize,
+        actual: usize,
+    },
+    StorageMapNotReferenced(Digest),
+    StorageMapNotFound {
+        index: u64,
+    },
+    StorageMapRootMismatch {
+        index: u8,
+        expected: Digest,
+        actual: Digest,
+    },
+    StubDataIncorrectLength {
+        actual: usize,
+        expected: usize,
+    },
+    UnsupportedAuthScheme(String),
+    WalletInvalidAssetFilter(String),
+    WalletInvalidRecoveryConfig(String),
+    WalletInvalidSessionKey(String),
+    WalletInvalidSpendingLimit(String),
 }
 
 impl AccountError {
@@ -45,7 +128,7 @@ impl AccountError {
     }
 
     pub fn account_id_too_few_ones(expected: u32, actual: u32) -> Self {
-        Self::AccountIdTooFewOnes(expected, actual)
+        Self::AccountIdTooFewOnes { expected, actual }
     }
 
     pub fn seed_digest_too_few_trailing_zeros(expected: u32, actual: u32) -> Self {
@@ -59,7 +142,144 @@ impl AccountError {
 
 impl fmt::Display for AccountError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::AccountCodeAssemblerError(err) => {
+                write!(f, "Failed to assemble account code: {err}")
+            },
+            Self::AccountCodeInvalidProcedureInfo(msg) => {
+                write!(f, "Invalid account procedure information: {msg}")
+            },
+            Self::AccountCodeNoProcedures => {
+                write!(f, "Account code must export at least one procedure")
+            },
+            Self::AccountCodeProcedureCountMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Account code has {actual} procedures, but its module exports {expected} procedures"
+                )
+            },
+            Self::AccountCodeProcedureNotFound(root) => {
+                write!(f, "Procedure with root {root} is not part of the account code")
+            },
+            Self::AccountCodeTooManyProcedures { max, actual } => {
+                write!(f, "Account code exports {actual} procedures, but at most {max} are allowed")
+            },
+            Self::AccountIdAnchorNotEpochBlock(block_num) => {
+                write!(f, "Account ID anchor block {block_num} is not the first block of an epoch")
+            },
+            Self::AccountIdInvalidFieldElement(msg) => {
+                write!(f, "Account ID is not a valid field element: {msg}")
+            },
+            Self::AccountIdInvalidLabel(label) => {
+                write!(f, "Invalid labeled account ID or label: {label}")
+            },
+            Self::AccountIdLabelMismatch { expected, actual } => {
+                write!(f, "Account ID labels {actual} do not match the ID metadata {expected}")
+            },
+            Self::AccountIdTooFewOnes { expected, actual } => {
+                write!(f, "Account ID contains {actual} ones, but at least {expected} are required")
+            },
+            Self::AccountIdVersionMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Account ID has version {actual:?}, but version {expected:?} was expected"
+                )
+            },
+            Self::AccountTypeMismatch { expected, actual } => {
+                write!(f, "Account is of type {actual}, but type {expected} was expected")
+            },
+            Self::AssetVaultUpdateError(err) => {
+                write!(f, "Failed to update the asset vault: {err}")
+            },
+            Self::DuplicateStorageItems(err) => write!(f, "Duplicate storage items: {err}"),
+            Self::FaucetAssetAmountOverflow => {
+                write!(f, "Amounts of the faucet asset held by the vault overflow u64")
+            },
+            Self::FaucetAssetNotIssued(asset) => {
+                write!(f, "Faucet holds asset {asset:?} which it has not issued")
+            },
+            Self::FaucetAssetsExceedIssuance { held, issued } => {
+                write!(f, "Faucet holds {held} units of its asset, but has issued only {issued}")
+            },
+            Self::FaucetIssuanceExceedsMaxSupply { issued, max_supply } => {
+                write!(
+                    f,
+                    "Faucet has issued {issued} tokens, exceeding its max supply {max_supply}"
+                )
+            },
+            Self::FaucetReservedSlotInvalidType { expected, actual } => {
+                write!(f, "Reserved faucet storage slot has type {actual:?}, expected {expected:?}")
+            },
+            Self::FaucetReservedSlotInvalidValue(value) => {
+                write!(f, "Reserved faucet storage slot holds invalid value {value:?}")
+            },
+            Self::FungibleFaucetIdInvalidFirstBit => {
+                write!(f, "The first bit of a fungible faucet ID must be set")
+            },
+            Self::FungibleFaucetInvalidMetadata(msg) => {
+                write!(f, "Invalid fungible faucet metadata: {msg}")
+            },
+            Self::HexParseError(msg) => write!(f, "Failed to parse hex string: {msg}"),
+            Self::InvalidAccountStorageType => write!(f, "Invalid account storage type"),
+            Self::NonFungibleFaucetInvalidData(msg) => {
+                write!(f, "Invalid non-fungible faucet data: {msg}")
+            },
+            Self::NonceNotMonotonicallyIncreasing { current, new } => {
+                write!(f, "Nonce {new} is not greater than the current nonce {current}")
+            },
+            Self::SeedDigestDifficultyTooHigh { max, actual } => {
+                write!(
+                    f,
+                    "Seed digest difficulty of {actual} trailing zeros exceeds the maximum of {max}"
+                )
+            },
+            Self::SeedDigestTooFewTrailingZeros { expected, actual } => {
+                write!(
+                    f,
+                    "Seed digest has {actual} trailing zeros, but at least {expected} are required"
+                )
+            },
+            Self::SeedGrindingCancelled => write!(f, "Account seed search was cancelled"),
+            Self::StorageSlotInvalidValueArity { slot, expected, actual } => {
+                write!(f, "Value in storage slot {slot} has arity {actual}, expected {expected}")
+            },
+            Self::StorageSlotIsReserved(slot) => write!(f, "Storage slot {slot} is reserved"),
+            Self::StorageSlotNotMap(slot) => write!(f, "Storage slot {slot} does not hold a map"),
+            Self::StorageSlotArrayNotSupportedYet { slot, slot_type } => {
+                write!(f, "Storage slot {slot} has unsupported array type {slot_type:?}")
+            },
+            Self::StorageMapToManyMaps { expected, actual } => {
+                write!(f, "Account storage holds {actual} maps, but at most {expected} are allowed")
+            },
+            Self::StorageMapNotReferenced(root) => {
+                write!(f, "Storage map with root {root} is not referenced by any storage slot")
+            },
+            Self::StorageMapNotFound { index } => {
+                write!(f, "No storage map found for storage slot {index}")
+            },
+            Self::StorageMapRootMismatch { index, expected, actual } => {
+                write!(
+                    f,
+                    "Storage slot {index} holds map root {actual}, but the map has root {expected}"
+                )
+            },
+            Self::StubDataIncorrectLength { actual, expected } => {
+                write!(f, "Account stub data has {actual} elements, but {expected} were expected")
+            },
+            Self::UnsupportedAuthScheme(msg) => {
+                write!(f, "Unsupported authentication scheme: {msg}")
+            },
+            Self::WalletInvalidAssetFilter(msg) => {
+                write!(f, "Invalid wallet asset filter: {msg}")
+            },
+            Self::WalletInvalidRecoveryConfig(msg) => {
+                write!(f, "Invalid wallet recovery configuration: {msg}")
+            },
+            Self::WalletInvalidSessionKey(msg) => write!(f, "Invalid wallet session key: {msg}"),
+            Self::WalletInvalidSpendingLimit(msg) => {
+                write!(f, "Invalid wallet spending limit: {msg}")
+            },
+        }
     }
 }
 
@@ -71,10 +291,14 @@ impl std::error::Error for AccountError {}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AccountDeltaError {
+    AssetAmountTooBig(u128),
     DuplicateStorageItemUpdate(usize),
     DuplicateVaultUpdate(Asset),
     InconsistentNonceUpdate(String),
     ImmutableStorageSlot(usize),
+    InsufficientVaultBalance { asset: Asset, balance: u64 },
+    NonFungibleAssetAlreadyInVault(Asset),
+    NonFungibleAssetNotInVault(Asset),
     TooManyAddedAsset { actual: usize, max: usize },
     TooManyClearedStorageItems { actual: usize, max: usize },
     TooManyRemovedAssets { actual: usize, max: usize },
@@ -88,7 +312,46 @@ impl std::error::Error for AccountDeltaError {}
 
 impl fmt::Display for AccountDeltaError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::AssetAmountTooBig(amount) => {
+                write!(f, "Asset amount {amount} exceeds the maximum amount of a fungible asset")
+            },
+            Self::DuplicateStorageItemUpdate(index) => {
+                write!(f, "Storage item {index} is updated more than once")
+            },
+            Self::DuplicateVaultUpdate(asset) => {
+                write!(f, "Asset {asset:?} is updated more than once in the vault delta")
+            },
+            Self::InconsistentNonceUpdate(msg) => write!(f, "Inconsistent nonce update: {msg}"),
+            Self::ImmutableStorageSlot(index) => write!(f, "Storage slot {index} is immutable"),
+            Self::InsufficientVaultBalance { asset, balance } => {
+                write!(f, "Cannot remove asset {asset:?} from a vault with balance {balance}")
+            },
+            Self::NonFungibleAssetAlreadyInVault(asset) => {
+                write!(f, "Non-fungible asset {asset:?} is already in the vault")
+            },
+            Self::NonFungibleAssetNotInVault(asset) => {
+                write!(f, "Non-fungible asset {asset:?} is not in the vault")
+            },
+            Self::TooManyAddedAsset { actual, max } => {
+                write!(f, "Delta adds {actual} assets, but at most {max} are allowed")
+            },
+            Self::TooManyClearedStorageItems { actual, max } => {
+                write!(f, "Delta clears {actual} storage items, but at most {max} are allowed")
+            },
+            Self::TooManyRemovedAssets { actual, max } => {
+                write!(f, "Delta removes {actual} assets, but at most {max} are allowed")
+            },
+            Self::TooManyUpdatedStorageItems { actual, max } => {
+                write!(f, "Delta updates {actual} storage items, but at most {max} are allowed")
+            },
+            Self::DuplicateStorageMapLeaf { key } => {
+                write!(f, "Storage map leaf {key} is updated more than once")
+            },
+            Self::StorageMapDeltaWithoutStorageItemChange(index) => {
+                write!(f, "Storage map in slot {index} is updated without updating the slot")
+            },
+        }
     }
 }
 
@@ -98,14 +361,27 @@ impl fmt::Display for AccountDeltaError {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AssetError {
     AmountTooBig(u64),
-    AssetAmountNotSufficient(u64, u64),
+    AssetAmountNotSufficient {
+        available: u64,
+        requested: u64,
+    },
     FungibleAssetInvalidTag(u32),
     FungibleAssetInvalidWord(Word),
-    InconsistentFaucetIds(AccountId, AccountId),
+    InconsistentFaucetIds {
+        expected: AccountId,
+        actual: AccountId,
+    },
+    InconsistentSubTokenIds {
+        expected: u32,
+        actual: u32,
+    },
     InvalidAccountId(String),
     InvalidFieldElement(String),
     NonFungibleAssetInvalidTag(u32),
-    NotAFungibleFaucetId(AccountId, AccountType),
+    NotAFungibleFaucetId {
+        faucet_id: AccountId,
+        account_type: AccountType,
+    },
     NotANonFungibleFaucetId(AccountId),
     NotAnAsset(Word),
     TokenSymbolError(String),
@@ -117,7 +393,7 @@ impl AssetError {
     }
 
     pub fn asset_amount_not_sufficient(available: u64, requested: u64) -> Self {
-        Self::AssetAmountNotSufficient(available, requested)
+        Self::AssetAmountNotSufficient { available, requested }
     }
 
     pub fn fungible_asset_invalid_tag(tag: u32) -> Self {
@@ -128,8 +404,12 @@ impl AssetError {
         Self::FungibleAssetInvalidWord(word)
     }
 
-    pub fn inconsistent_faucet_ids(id1: AccountId, id2: AccountId) -> Self {
-        Self::InconsistentFaucetIds(id1, id2)
+    pub fn inconsistent_faucet_ids(expected: AccountId, actual: AccountId) -> Self {
+        Self::InconsistentFaucetIds { expected, actual }
+    }
+
+    pub fn inconsistent_sub_token_ids(expected: u32, actual: u32) -> Self {
+        Self::InconsistentSubTokenIds { expected, actual }
     }
 
     pub fn invalid_account_id(err: String) -> Self {
@@ -144,8 +424,8 @@ impl AssetError {
         Self::NonFungibleAssetInvalidTag(tag)
     }
 
-    pub fn not_a_fungible_faucet_id(id: AccountId, account_type: AccountType) -> Self {
-        Self::NotAFungibleFaucetId(id, account_type)
+    pub fn not_a_fungible_faucet_id(faucet_id: AccountId, account_type: AccountType) -> Self {
+        Self::NotAFungibleFaucetId { faucet_id, account_type }
     }
 
     pub fn not_a_non_fungible_faucet_id(id: AccountId) -> Self {
@@ -159,7 +439,43 @@ impl AssetError {
 
 impl fmt::Display for AssetError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::AmountTooBig(amount) => {
+                write!(f, "Amount {amount} exceeds the maximum amount of a fungible asset")
+            },
+            Self::AssetAmountNotSufficient { available, requested } => {
+                write!(f, "Requested amount {requested} exceeds the available amount {available}")
+            },
+            Self::FungibleAssetInvalidTag(tag) => write!(f, "Invalid fungible asset tag {tag}"),
+            Self::FungibleAssetInvalidWord(word) => {
+                write!(f, "Word {word:?} is not a valid fungible asset")
+            },
+            Self::InconsistentFaucetIds { expected, actual } => {
+                write!(
+                    f,
+                    "Asset of faucet {actual} cannot be combined with asset of faucet {expected}"
+                )
+            },
+            Self::InconsistentSubTokenIds { expected, actual } => {
+                write!(
+                    f,
+                    "Asset of sub-token {actual} cannot be combined with sub-token {expected}"
+                )
+            },
+            Self::InvalidAccountId(msg) => write!(f, "Invalid faucet ID: {msg}"),
+            Self::InvalidFieldElement(msg) => write!(f, "Invalid field element: {msg}"),
+            Self::NonFungibleAssetInvalidTag(tag) => {
+                write!(f, "Invalid non-fungible asset tag {tag}")
+            },
+            Self::NotAFungibleFaucetId { faucet_id, account_type } => {
+                write!(f, "Account {faucet_id} of type {account_type:?} is not a fungible faucet")
+            },
+            Self::NotANonFungibleFaucetId(faucet_id) => {
+                write!(f, "Account {faucet_id} is not a non-fungible faucet")
+            },
+            Self::NotAnAsset(word) => write!(f, "Word {word:?} is not a valid asset"),
+            Self::TokenSymbolError(msg) => write!(f, "Invalid token symbol: {msg}"),
+        }
     }
 }
 
@@ -178,12 +494,43 @@ pub enum AssetVaultError {
     NotANonFungibleAsset(Asset),
     NotAFungibleFaucetId(AccountId),
     NonFungibleAssetNotFound(NonFungibleAsset),
+    PartialVaultKeyNotTracked(Digest),
+    PartialVaultRootMismatch { expected: Digest, actual: Digest },
     SubtractFungibleAssetBalanceError(AssetError),
 }
 
 impl fmt::Display for AssetVaultError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::AddFungibleAssetBalanceError(err) => {
+                write!(f, "Failed to add fungible asset to the vault: {err}")
+            },
+            Self::DuplicateAsset(err) => write!(f, "Duplicate asset in the vault: {err}"),
+            Self::DuplicateNonFungibleAsset(asset) => {
+                write!(f, "Non-fungible asset {asset} is already in the vault")
+            },
+            Self::FungibleAssetNotFound(asset) => {
+                write!(f, "Fungible asset {asset} is not in the vault")
+            },
+            Self::NotANonFungibleAsset(asset) => {
+                write!(f, "Asset {asset:?} is not a non-fungible asset")
+            },
+            Self::NotAFungibleFaucetId(faucet_id) => {
+                write!(f, "Account {faucet_id} is not a fungible faucet")
+            },
+            Self::NonFungibleAssetNotFound(asset) => {
+                write!(f, "Non-fungible asset {asset} is not in the vault")
+            },
+            Self::PartialVaultKeyNotTracked(key) => {
+                write!(f, "Vault key {key} is not tracked by the partial vault")
+            },
+            Self::PartialVaultRootMismatch { expected, actual } => {
+                write!(f, "Partial vault has root {actual}, but root {expected} was expected")
+            },
+            Self::SubtractFungibleAssetBalanceError(err) => {
+                write!(f, "Failed to remove fungible asset from the vault: {err}")
+            },
+        }
     }
 }
 
@@ -197,8 +544,13 @@ impl std::error::Error for AssetVaultError {}
 pub enum NoteError {
     DuplicateFungibleAsset(AccountId),
     DuplicateNonFungibleAsset(NonFungibleAsset),
-    InconsistentNoteTag(NoteType, u64),
+    EmptyNoteAssets,
+    InconsistentNoteTag { note_type: NoteType, tag: u64 },
     InvalidAssetData(AssetError),
+    InvalidAuctionClaimant(AccountId),
+    InvalidNoteAuxPayload(u64),
+    InvalidNoteAuxSchema(u8),
+    InvalidNoteInputs(String),
     InvalidNoteSender(AccountError),
     InvalidNoteTagUseCase(u16),
     InvalidNoteType(NoteType),
@@ -212,6 +564,7 @@ pub enum NoteError {
     ScriptCompilationError(AssemblyError),
     TooManyAssets(usize),
     TooManyInputs(usize),
+    TooManyNotes(usize),
 }
 
 impl NoteError {
@@ -223,6 +576,10 @@ impl NoteError {
         Self::DuplicateNonFungibleAsset(asset)
     }
 
+    pub fn invalid_note_inputs(msg: impl Into<String>) -> Self {
+        Self::InvalidNoteInputs(msg.into())
+    }
+
     pub fn invalid_origin_index(msg: String) -> Self {
         Self::InvalidOriginIndex(msg)
     }
@@ -238,7 +595,63 @@ impl NoteError {
 
 impl fmt::Display for NoteError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::DuplicateFungibleAsset(faucet_id) => {
+                write!(f, "Note contains more than one asset of faucet {faucet_id}")
+            },
+            Self::DuplicateNonFungibleAsset(asset) => {
+                write!(f, "Note contains non-fungible asset {asset} more than once")
+            },
+            Self::EmptyNoteAssets => write!(f, "Note must contain at least one asset"),
+            Self::InconsistentNoteTag { note_type, tag } => {
+                write!(f, "Note tag {tag} is inconsistent with note type {note_type:?}")
+            },
+            Self::InvalidAssetData(err) => write!(f, "Invalid note asset: {err}"),
+            Self::InvalidAuctionClaimant(account_id) => {
+                write!(f, "Account {account_id} cannot settle the auction")
+            },
+            Self::InvalidNoteAuxPayload(payload) => {
+                write!(f, "Note aux payload {payload} does not fit into 56 bits")
+            },
+            Self::InvalidNoteAuxSchema(schema) => write!(f, "Unknown note aux schema {schema}"),
+            Self::InvalidNoteInputs(msg) => write!(f, "Invalid note inputs: {msg}"),
+            Self::InvalidNoteSender(err) => write!(f, "Invalid note sender: {err}"),
+            Self::InvalidNoteTagUseCase(use_case) => {
+                write!(f, "Invalid note tag use case {use_case}")
+            },
+            Self::InvalidNoteType(note_type) => {
+                write!(f, "Note type {note_type:?} is not allowed here")
+            },
+            Self::InvalidNoteTypeValue(value) => write!(f, "Invalid note type value {value}"),
+            Self::InvalidOriginIndex(msg) => write!(f, "Invalid note origin index: {msg}"),
+            Self::InvalidStubDataLen(len) => write!(f, "Invalid note stub data length {len}"),
+            Self::NetworkExecutionRequiresOnChainAccount => {
+                write!(f, "Network execution requires an on-chain account")
+            },
+            Self::NetworkExecutionRequiresPublicNote(note_type) => {
+                write!(
+                    f,
+                    "Network execution requires a public note, but the note type is {note_type:?}"
+                )
+            },
+            Self::NoteDeserializationError(err) => write!(f, "Failed to deserialize note: {err}"),
+            Self::PublicUseCaseRequiresPublicNote(note_type) => {
+                write!(
+                    f,
+                    "Public use case requires a public note, but the note type is {note_type:?}"
+                )
+            },
+            Self::ScriptCompilationError(err) => write!(f, "Failed to compile note script: {err}"),
+            Self::TooManyAssets(num_assets) => {
+                write!(f, "Note contains {num_assets} assets, which exceeds the maximum")
+            },
+            Self::TooManyInputs(num_inputs) => {
+                write!(f, "Note contains {num_inputs} inputs, which exceeds the maximum")
+            },
+            Self::TooManyNotes(num_notes) => {
+                write!(f, "{num_notes} notes exceed the maximum number of notes")
+            },
+        }
     }
 }
 
@@ -271,13 +684,60 @@ impl ChainMmrError {
 
 impl fmt::Display for ChainMmrError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::BlockNumTooBig { chain_length, block_num } => {
+                write!(f, "Block {block_num} is not part of a chain of length {chain_length}")
+            },
+            Self::DuplicateBlock { block_num } => {
+                write!(f, "Block {block_num} is provided more than once")
+            },
+            Self::UntrackedBlock { block_num } => {
+                write!(f, "Block {block_num} is not tracked by the chain MMR")
+            },
+        }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for ChainMmrError {}
 
+// PROTO CONVERSION ERROR
+// ================================================================================================
+
+#[cfg(feature = "proto")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProtoConversionError {
+    DeserializationFailed(DeserializationError),
+    InvalidAccountId(AccountError),
+    InvalidAsset(AssetError),
+    InvalidFieldElement(u64),
+    InvalidKernelVersion(u32),
+    InvalidNote(NoteError),
+    InvalidProvenTransaction(ProvenTransactionError),
+    MissingField(&'static str),
+}
+
+#[cfg(feature = "proto")]
+impl fmt::Display for ProtoConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DeserializationFailed(err) => write!(f, "Failed to deserialize: {err}"),
+            Self::InvalidAccountId(err) => write!(f, "Invalid account ID: {err}"),
+            Self::InvalidAsset(err) => write!(f, "Invalid asset: {err}"),
+            Self::InvalidFieldElement(value) => {
+                write!(f, "Value {value} is not a valid field element")
+            },
+            Self::InvalidKernelVersion(version) => write!(f, "Invalid kernel version {version}"),
+            Self::InvalidNote(err) => write!(f, "Invalid note: {err}"),
+            Self::InvalidProvenTransaction(err) => write!(f, "Invalid proven transaction: {err}"),
+            Self::MissingField(field) => write!(f, "Field `{field}` is missing"),
+        }
+    }
+}
+
+#[cfg(all(feature = "proto", feature = "std"))]
+impl std::error::Error for ProtoConversionError {}
+
 // TRANSACTION SCRIPT ERROR
 // ================================================================================================
 
@@ -288,18 +748,84 @@ pub enum TransactionScriptError {
 
 impl fmt::Display for TransactionScriptError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::ScriptCompilationError(err) => {
+                write!(f, "Failed to compile transaction script: {err}")
+            },
+        }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for TransactionScriptError {}
 
+// ADVICE INPUTS ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdviceInputsError {
+    InvalidMerklePath(MerkleError),
+    MapKeyConflict(Digest),
+}
+
+impl fmt::Display for AdviceInputsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMerklePath(err) => write!(f, "Invalid Merkle path: {err}"),
+            Self::MapKeyConflict(key) => {
+                write!(f, "Advice map key {key} is mapped to conflicting values")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AdviceInputsError {}
+
+// TRANSACTION ARGS ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionArgsError {
+    AccountSaltConflict { current: Word, other: Word },
+    AdviceMapKeyConflict(Digest),
+    MissingNoteArgs(NoteId),
+    NoteArgsConflict(NoteId),
+    TransactionScriptConflict { current: Digest, other: Digest },
+}
+
+impl fmt::Display for TransactionArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AccountSaltConflict { current, other } => {
+                write!(f, "Account salt {other:?} conflicts with the salt {current:?} already set")
+            },
+            Self::AdviceMapKeyConflict(key) => {
+                write!(f, "Advice map key {key} is mapped to conflicting values")
+            },
+            Self::MissingNoteArgs(note_id) => write!(f, "No arguments provided for note {note_id}"),
+            Self::NoteArgsConflict(note_id) => {
+                write!(f, "Conflicting arguments provided for note {note_id}")
+            },
+            Self::TransactionScriptConflict { current, other } => {
+                write!(
+                    f,
+                    "Transaction script {other} conflicts with the script {current} already set"
+                )
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionArgsError {}
+
 // TRANSACTION INPUT ERROR
 // ================================================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionInputError {
+    AccountIdAnchorBlockNotInChainMmr(u32),
     AccountSeedNotProvidedForNewAccount,
     AccountSeedProvidedForExistingAccount,
     DuplicateInputNote(Digest),
@@ -307,14 +833,57 @@ pub enum TransactionInputError {
     InconsistentChainLength { expected: u32, actual: u32 },
     InconsistentChainRoot { expected: Digest, actual: Digest },
     InputNoteBlockNotInChainMmr(NoteId),
-    InputNoteNotInBlock(NoteId, u32),
+    InputNoteNotInBlock { note_id: NoteId, block_num: u32 },
     InvalidAccountSeed(AccountError),
+    NonEmptyAccountVaultWithPartialVault,
+    PartialVaultForNewAccount,
     TooManyInputNotes { max: usize, actual: usize },
 }
 
 impl fmt::Display for TransactionInputError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::AccountIdAnchorBlockNotInChainMmr(block_num) => {
+                write!(
+                    f,
+                    "Anchor block {block_num} of the account ID is not tracked by the chain MMR"
+                )
+            },
+            Self::AccountSeedNotProvidedForNewAccount => {
+                write!(f, "Seed must be provided for new accounts")
+            },
+            Self::AccountSeedProvidedForExistingAccount => {
+                write!(f, "Seed must not be provided for existing accounts")
+            },
+            Self::DuplicateInputNote(nullifier) => {
+                write!(f, "Note with nullifier {nullifier} is consumed more than once")
+            },
+            Self::InconsistentAccountSeed { expected, actual } => {
+                write!(f, "Account seed produces account ID {actual}, but {expected} was expected")
+            },
+            Self::InconsistentChainLength { expected, actual } => {
+                write!(f, "Chain MMR has length {actual}, but length {expected} was expected")
+            },
+            Self::InconsistentChainRoot { expected, actual } => {
+                write!(f, "Chain MMR has root {actual}, but root {expected} was expected")
+            },
+            Self::InputNoteBlockNotInChainMmr(note_id) => {
+                write!(f, "Block of input note {note_id} is not tracked by the chain MMR")
+            },
+            Self::InputNoteNotInBlock { note_id, block_num } => {
+                write!(f, "Input note {note_id} was not created in block {block_num}")
+            },
+            Self::InvalidAccountSeed(err) => write!(f, "Invalid account seed: {err}"),
+            Self::NonEmptyAccountVaultWithPartialVault => {
+                write!(f, "A partial vault cannot be used for an account with a non-empty vault")
+            },
+            Self::PartialVaultForNewAccount => {
+                write!(f, "A partial vault cannot be used for a new account")
+            },
+            Self::TooManyInputNotes { max, actual } => {
+                write!(f, "Transaction consumes {actual} notes, but at most {max} are allowed")
+            },
+        }
     }
 }
 
@@ -327,17 +896,41 @@ impl std::error::Error for TransactionInputError {}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionOutputError {
     DuplicateOutputNote(NoteId),
+    DuplicateSerialNumber(Word),
+    FinalAccountCommitmentInvalid(Digest),
     FinalAccountDataNotFound,
     FinalAccountStubDataInvalid(AccountError),
     OutputNoteDataNotFound,
     OutputNoteDataInvalid(NoteError),
-    OutputNotesCommitmentInconsistent(Digest, Digest),
+    OutputNotesCommitmentInconsistent { expected: Digest, actual: Digest },
     TooManyOutputNotes { max: usize, actual: usize },
 }
 
 impl fmt::Display for TransactionOutputError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::DuplicateOutputNote(note_id) => {
+                write!(f, "Note {note_id} is created more than once")
+            },
+            Self::DuplicateSerialNumber(serial_num) => {
+                write!(f, "Serial number {serial_num:?} is used by more than one output note")
+            },
+            Self::FinalAccountCommitmentInvalid(commitment) => {
+                write!(f, "Final account commitment {commitment} is invalid")
+            },
+            Self::FinalAccountDataNotFound => write!(f, "Final account data not found"),
+            Self::FinalAccountStubDataInvalid(err) => {
+                write!(f, "Final account stub data is invalid: {err}")
+            },
+            Self::OutputNoteDataNotFound => write!(f, "Output note data not found"),
+            Self::OutputNoteDataInvalid(err) => write!(f, "Output note data is invalid: {err}"),
+            Self::OutputNotesCommitmentInconsistent { expected, actual } => {
+                write!(f, "Output notes commitment is {actual}, but {expected} was expected")
+            },
+            Self::TooManyOutputNotes { max, actual } => {
+                write!(f, "Transaction creates {actual} notes, but at most {max} are allowed")
+            },
+        }
     }
 }
 
@@ -349,35 +942,79 @@ impl std::error::Error for TransactionOutputError {}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ProvenTransactionError {
-    AccountFinalHashMismatch(Digest, Digest),
-    AccountIdMismatch(AccountId, AccountId),
+    AccountDeltaFinalHashMismatch(AccountId),
+    AccountFinalHashMismatch {
+        tx_final_hash: Digest,
+        details_hash: Digest,
+    },
+    AccountUpdateTooLarge {
+        account_id: AccountId,
+        max: usize,
+        actual: usize,
+    },
+    AccountIdMismatch {
+        tx_account_id: AccountId,
+        details_account_id: AccountId,
+    },
+    DuplicateInputNoteNullifier(Nullifier),
     InputNotesError(TransactionInputError),
     NoteDetailsForUnknownNotes(Vec<NoteId>),
+    NotePayloadTooLarge {
+        note_id: NoteId,
+        max: usize,
+        actual: usize,
+    },
     OffChainAccountWithDetails(AccountId),
     OnChainAccountMissingDetails(AccountId),
     NewOnChainAccountRequiresFullDetails(AccountId),
     ExistingOnChainAccountRequiresDeltaDetails(AccountId),
     OutputNotesError(TransactionOutputError),
+    TooManyInputNotes {
+        max: usize,
+        actual: usize,
+    },
+    TooManyOutputNotes {
+        max: usize,
+        actual: usize,
+    },
 }
 
 impl fmt::Display for ProvenTransactionError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ProvenTransactionError::AccountFinalHashMismatch(account_final_hash, details_hash) => {
-                write!(f, "Proven transaction account_final_hash {account_final_hash} and account_details.hash must match {details_hash}.")
+            ProvenTransactionError::AccountDeltaFinalHashMismatch(account_id) => {
+                write!(f, "Account delta of on-chain account {account_id} is inconsistent with the change of the account hash")
             },
-            ProvenTransactionError::AccountIdMismatch(tx_id, details_id) => {
+            ProvenTransactionError::AccountFinalHashMismatch { tx_final_hash, details_hash } => {
                 write!(
                     f,
-                    "Proven transaction account_id {tx_id} and account_details.id must match {details_id}.",
+                    "Final account hash {tx_final_hash} does not match details hash {details_hash}"
                 )
             },
+            ProvenTransactionError::AccountUpdateTooLarge { account_id, max, actual } => {
+                write!(f, "Update of account {account_id} takes {actual} bytes, but at most {max} are allowed")
+            },
+            ProvenTransactionError::AccountIdMismatch { tx_account_id, details_account_id } => {
+                write!(
+                    f,
+                    "Account {tx_account_id} does not match details account {details_account_id}"
+                )
+            },
+            ProvenTransactionError::DuplicateInputNoteNullifier(nullifier) => {
+                write!(f, "Input note with nullifier {nullifier} is consumed more than once")
+            },
             ProvenTransactionError::InputNotesError(inner) => {
                 write!(f, "Invalid input notes: {inner}")
             },
             ProvenTransactionError::NoteDetailsForUnknownNotes(note_ids) => {
                 write!(f, "Note details for unknown note ids: {note_ids:?}")
             },
+            ProvenTransactionError::NotePayloadTooLarge { note_id, max, actual } => {
+                write!(
+                    f,
+                    "Payload of note {note_id} takes {actual} bytes, but at most {max} are allowed"
+                )
+            },
             ProvenTransactionError::OffChainAccountWithDetails(account_id) => {
                 write!(f, "Off-chain account {account_id} should not have account details")
             },
@@ -393,6 +1030,12 @@ impl fmt::Display for ProvenTransactionError {
             ProvenTransactionError::ExistingOnChainAccountRequiresDeltaDetails(account_id) => {
                 write!(f, "Existing on-chain account {account_id} should only provide deltas")
             },
+            ProvenTransactionError::TooManyInputNotes { max, actual } => {
+                write!(f, "Transaction consumes {actual} notes, but at most {max} are allowed")
+            },
+            ProvenTransactionError::TooManyOutputNotes { max, actual } => {
+                write!(f, "Transaction creates {actual} notes, but at most {max} are allowed")
+            },
         }
     }
 }
@@ -400,12 +1043,263 @@ impl fmt::Display for ProvenTransactionError {
 #[cfg(feature = "std")]
 impl std::error::Error for ProvenTransactionError {}
 
+// TRANSACTION VALIDITY ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionValidityError {
+    AccountHashMismatch {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
+    InsufficientProofSecurityLevel {
+        required: u32,
+        actual: u32,
+    },
+    InvalidTransaction(ProvenTransactionError),
+    NullifierAlreadySpent(Nullifier),
+    ProofVerificationFailed(VerificationError),
+    ReferenceBlockTooOld {
+        block_num: u32,
+        chain_tip: u32,
+    },
+    UnknownReferenceBlock(Digest),
+    UnsupportedKernelVersion(KernelVersion),
+}
+
+impl fmt::Display for TransactionValidityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AccountHashMismatch { account_id, expected, actual } => {
+                write!(
+                    f,
+                    "Account {account_id} has state hash {actual}, but {expected} was expected"
+                )
+            },
+            Self::InsufficientProofSecurityLevel { required, actual } => {
+                write!(f, "Proof has security level {actual}, but at least {required} is required")
+            },
+            Self::InvalidTransaction(err) => write!(f, "Invalid transaction: {err}"),
+            Self::NullifierAlreadySpent(nullifier) => {
+                write!(f, "Note with nullifier {nullifier} was already consumed")
+            },
+            Self::ProofVerificationFailed(err) => write!(f, "Proof verification failed: {err}"),
+            Self::ReferenceBlockTooOld { block_num, chain_tip } => {
+                write!(f, "Reference block {block_num} is too old for the chain tip {chain_tip}")
+            },
+            Self::UnknownReferenceBlock(block_hash) => {
+                write!(f, "Reference block {block_hash} is unknown")
+            },
+            Self::UnsupportedKernelVersion(version) => {
+                write!(f, "Kernel version {version} is not supported")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionValidityError {}
+
+// TRANSACTION BATCH ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchError {
+    DuplicateInputNote(Nullifier),
+    DuplicateOutputNote(NoteId),
+    TooManyNotes(usize),
+    TooManyTransactions(usize),
+    UnauthenticatedNoteMismatch {
+        id: NoteId,
+        expected: NoteMetadata,
+        actual: NoteMetadata,
+    },
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateInputNote(nullifier) => {
+                write!(f, "Note with nullifier {nullifier} is consumed more than once in the batch")
+            },
+            Self::DuplicateOutputNote(note_id) => {
+                write!(f, "Note {note_id} is created more than once in the batch")
+            },
+            Self::TooManyNotes(num_notes) => {
+                write!(
+                    f,
+                    "Batch creates {num_notes} notes, but at most {MAX_NOTES_PER_BATCH} allowed"
+                )
+            },
+            Self::TooManyTransactions(num_txs) => {
+                write!(f, "Batch contains {num_txs} transactions, which exceeds the maximum")
+            },
+            Self::UnauthenticatedNoteMismatch { id, expected, actual } => {
+                write!(
+                    f,
+                    "Unauthenticated note {id} has metadata {actual:?}, expected {expected:?}"
+                )
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {}
+
+// TRANSACTION GRAPH ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionGraphError {
+    ConflictingAccountUpdates {
+        account_id: AccountId,
+        init_state_hash: Digest,
+    },
+    DependencyCycle(Vec<TransactionId>),
+    DuplicateInputNote(Nullifier),
+    DuplicateOutputNote(NoteId),
+    DuplicateTransaction(TransactionId),
+}
+
+impl fmt::Display for TransactionGraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConflictingAccountUpdates { account_id, init_state_hash } => {
+                write!(
+                    f,
+                    "Account {account_id} is updated from state {init_state_hash} more than once"
+                )
+            },
+            Self::DependencyCycle(tx_ids) => {
+                write!(f, "Transactions form a dependency cycle: ")?;
+                for (i, tx_id) in tx_ids.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{tx_id}")?;
+                }
+                Ok(())
+            },
+            Self::DuplicateInputNote(nullifier) => {
+                write!(
+                    f,
+                    "Note with nullifier {nullifier} is consumed by more than one transaction"
+                )
+            },
+            Self::DuplicateOutputNote(note_id) => {
+                write!(f, "Note {note_id} is created by more than one transaction")
+            },
+            Self::DuplicateTransaction(tx_id) => {
+                write!(f, "Transaction {tx_id} is added more than once")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionGraphError {}
+
+// BLOCK HEADER ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockHeaderError {
+    BlockNumMismatch { expected: u32, actual: u32 },
+    ChainLengthMismatch { expected: u32, actual: usize },
+    ChainRootMismatch { expected: Digest, actual: Digest },
+    PrevHashMismatch { expected: Digest, actual: Digest },
+    TimestampBeforePrevBlock { prev: u32, actual: u32 },
+    TimestampTooFarInFuture { max: u32, actual: u32 },
+    VersionDecreased { prev: u32, actual: u32 },
+}
+
+impl fmt::Display for BlockHeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BlockNumMismatch { expected, actual } => {
+                write!(f, "Block number is {actual}, but {expected} was expected")
+            },
+            Self::ChainLengthMismatch { expected, actual } => {
+                write!(f, "Chain length is {actual}, but {expected} was expected")
+            },
+            Self::ChainRootMismatch { expected, actual } => {
+                write!(f, "Chain root is {actual}, but {expected} was expected")
+            },
+            Self::PrevHashMismatch { expected, actual } => {
+                write!(f, "Previous block hash is {actual}, but {expected} was expected")
+            },
+            Self::TimestampBeforePrevBlock { prev, actual } => {
+                write!(f, "Timestamp {actual} is before the timestamp {prev} of the previous block")
+            },
+            Self::TimestampTooFarInFuture { max, actual } => {
+                write!(f, "Timestamp {actual} is after the latest allowed timestamp {max}")
+            },
+            Self::VersionDecreased { prev, actual } => {
+                write!(f, "Version {actual} is lower than the version {prev} of the previous block")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BlockHeaderError {}
+
+// ACCOUNT TREE ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountTreeError {
+    DuplicateAccountUpdate(AccountId),
+}
+
+impl fmt::Display for AccountTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateAccountUpdate(account_id) => {
+                write!(f, "Account {account_id} is updated more than once")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AccountTreeError {}
+
+// NULLIFIER TREE ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NullifierTreeError {
+    GenesisBlockNullifier(Nullifier),
+    NullifierAlreadySpent(Nullifier),
+}
+
+impl fmt::Display for NullifierTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GenesisBlockNullifier(nullifier) => {
+                write!(f, "Nullifier {nullifier} cannot be recorded in the genesis block")
+            },
+            Self::NullifierAlreadySpent(nullifier) => {
+                write!(f, "Nullifier {nullifier} was already spent")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NullifierTreeError {}
+
 // BLOCK VALIDATION ERROR
 // ================================================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BlockError {
     DuplicateNoteFound(NoteId),
+    NoteNotFound(BlockNoteIndex),
+    NoteRootMismatch { expected: Digest, actual: Digest },
     TooManyNotesInBatch(usize),
     TooManyTransactionBatches(usize),
 }
@@ -416,6 +1310,17 @@ impl fmt::Display for BlockError {
             BlockError::DuplicateNoteFound(id) => {
                 write!(f, "Duplicate note {id} found in the block")
             },
+            BlockError::NoteNotFound(index) => {
+                write!(
+                    f,
+                    "No note found at index {} of batch {} in the block",
+                    index.note_idx_in_batch(),
+                    index.batch_idx()
+                )
+            },
+            BlockError::NoteRootMismatch { expected, actual } => {
+                write!(f, "Note root mismatch. Expected: {expected}, actual: {actual}")
+            },
             BlockError::TooManyNotesInBatch(actual) => {
                 write!(f, "Too many notes in a batch. Max: {MAX_NOTES_PER_BATCH}, actual: {actual}")
             },
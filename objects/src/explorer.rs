@@ -0,0 +1,321 @@
+//! Explorer-friendly JSON rendering of notes and accounts.
+//!
+//! Unlike the plain `serde` encoding, which mirrors the internal structure of objects, the
+//! rendering produced here decodes words into named fields (e.g., note metadata, tag components,
+//! asset details, storage slot types) so that block explorers and indexers can display objects
+//! without hand-decoding field elements. Digests and words are rendered as hex strings, and field
+//! elements as integers.
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use serde_json::{json, Value};
+
+use crate::{
+    accounts::{Account, AccountId, AccountStorage, AccountType, StorageSlotType},
+    assets::Asset,
+    notes::{Note, NoteMetadata, NoteTag},
+    Digest, Word,
+};
+
+// NOTE
+// ================================================================================================
+
+impl Note {
+    /// Returns an explorer-friendly, pretty-printed JSON rendering of this note.
+    ///
+    /// The note script is annotated with its name if its root is present in `known_scripts`, which
+    /// maps script roots to human-readable script names (e.g., "P2ID").
+    pub fn to_pretty_json(&self, known_scripts: &BTreeMap<Digest, &str>) -> String {
+        let script_root = self.script().hash();
+
+        let note = json!({
+            "id": self.id().inner().to_hex(),
+            "nullifier": self.nullifier().inner().to_hex(),
+            "metadata": metadata_to_json(self.metadata()),
+            "assets": self.assets().iter().map(asset_to_json).collect::<Vec<_>>(),
+            "recipient": {
+                "digest": self.recipient().digest().to_hex(),
+                "serial_num": word_to_hex(self.serial_num()),
+                "script": {
+                    "root": script_root.to_hex(),
+                    "name": known_scripts.get(&script_root),
+                },
+                "inputs": self.inputs().values().iter().map(|input| input.as_int()).collect::<Vec<_>>(),
+            },
+        });
+
+        serde_json::to_string_pretty(&note).expect("note JSON rendering failed")
+    }
+}
+
+// ACCOUNT
+// ================================================================================================
+
+impl Account {
+    /// Returns an explorer-friendly, pretty-printed JSON rendering of this account.
+    pub fn to_pretty_json(&self) -> String {
+        let code = self.code();
+
+        let account = json!({
+            "id": account_id_to_json(self.id()),
+            "hash": self.hash().to_hex(),
+            "nonce": self.nonce().as_int(),
+            "vault": {
+                "root": self.vault().commitment().to_hex(),
+                "assets": self.vault().assets().map(|asset| asset_to_json(&asset)).collect::<Vec<_>>(),
+            },
+            "storage": storage_to_json(self.storage()),
+            "faucet": faucet_to_json(self),
+            "code": {
+                "root": code.root().to_hex(),
+                "procedures": code
+                    .procedures()
+                    .iter()
+                    .map(|&root| json!({
+                        "root": root.to_hex(),
+                        "requires_auth": code.requires_auth(root),
+                    }))
+                    .collect::<Vec<_>>(),
+            },
+        });
+
+        serde_json::to_string_pretty(&account).expect("account JSON rendering failed")
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn word_to_hex(word: Word) -> String {
+    Digest::from(word).to_hex()
+}
+
+fn account_id_to_json(account_id: AccountId) -> Value {
+    json!({
+        "hex": account_id.to_hex(),
+        "label": account_id.to_labeled_string(),
+        "account_type": format!("{:?}", account_id.account_type()),
+        "storage_type": format!("{:?}", account_id.storage_type()),
+    })
+}
+
+/// Decodes the reserved faucet data slot of faucet accounts; returns `null` for other accounts or
+/// if the slot does not hold valid faucet data.
+fn faucet_to_json(account: &Account) -> Value {
+    match account.account_type() {
+        AccountType::FungibleFaucet => match account.faucet_total_issuance() {
+            Ok(total_issuance) => json!({ "total_issuance": total_issuance }),
+            Err(_) => Value::Null,
+        },
+        AccountType::NonFungibleFaucet => match account.faucet_issued_assets_root() {
+            Ok(root) => json!({ "issued_assets_root": root.to_hex() }),
+            Err(_) => Value::Null,
+        },
+        AccountType::RegularAccountImmutableCode | AccountType::RegularAccountUpdatableCode => {
+            Value::Null
+        },
+    }
+}
+
+fn metadata_to_json(metadata: &NoteMetadata) -> Value {
+    json!({
+        "sender": account_id_to_json(metadata.sender()),
+        "note_type": format!("{:?}", metadata.note_type()),
+        "tag": tag_to_json(metadata.tag()),
+        "aux": metadata.aux().as_int(),
+    })
+}
+
+/// Decodes a note tag according to the layout described in [NoteTag].
+fn tag_to_json(tag: NoteTag) -> Value {
+    let value = tag.inner();
+    let execution_hint = format!("{:?}", tag.execution_hint());
+
+    if tag.is_single_target() {
+        // the remaining bits of a single-target tag are the 31 most significant bits of the
+        // target account ID
+        json!({
+            "value": value,
+            "execution_hint": execution_hint,
+            "target": "Account",
+            "account_id_prefix": format!("{:#018x}", (value as u64) << 33),
+        })
+    } else {
+        json!({
+            "value": value,
+            "execution_hint": execution_hint,
+            "target": "UseCase",
+            "use_case_id": (value >> 16) & 0x3fff,
+            "payload": value & 0xffff,
+        })
+    }
+}
+
+fn asset_to_json(asset: &Asset) -> Value {
+    match asset {
+        Asset::Fungible(asset) => json!({
+            "type": "Fungible",
+            "faucet_id": asset.faucet_id().to_hex(),
+            "sub_token_id": asset.sub_token_id(),
+            "amount": asset.amount(),
+        }),
+        Asset::NonFungible(asset) => json!({
+            "type": "NonFungible",
+            "faucet_id": asset.faucet_id().to_hex(),
+            "value": word_to_hex((*asset).into()),
+        }),
+    }
+}
+
+/// Renders all storage slots with a non-default type or a non-empty value, together with the
+/// entries of all storage maps.
+fn storage_to_json(storage: &AccountStorage) -> Value {
+    let slots = storage
+        .layout()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, slot_type)| {
+            let value = storage.get_item(index as u8);
+            if slot_type.is_default() && value == Digest::default() {
+                return None;
+            }
+
+            let slot_type = match *slot_type {
+                StorageSlotType::Value { value_arity } => {
+                    json!({ "kind": "Value", "value_arity": value_arity })
+                },
+                StorageSlotType::Map { value_arity } => {
+                    json!({ "kind": "Map", "value_arity": value_arity })
+                },
+                StorageSlotType::Array { depth, value_arity } => {
+                    json!({ "kind": "Array", "depth": depth, "value_arity": value_arity })
+                },
+            };
+
+            Some(json!({
+                "index": index,
+                "reserved": index == AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX as usize,
+                "type": slot_type,
+                "value": value.to_hex(),
+            }))
+        })
+        .collect::<Vec<_>>();
+
+    let maps = storage
+        .maps()
+        .iter()
+        .map(|map| {
+            json!({
+                "root": map.root().to_hex(),
+                "entries": map
+                    .entries()
+                    .map(|(key, value)| json!({ "key": key.to_hex(), "value": word_to_hex(*value) }))
+                    .collect::<Vec<_>>(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "root": storage.root().to_hex(),
+        "layout_commitment": storage.layout_commitment().to_hex(),
+        "slots": slots,
+        "maps": maps,
+    })
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+
+    use assembly::ast::ProgramAst;
+    use serde_json::Value;
+
+    use crate::{
+        accounts::{
+            account_id::testing::ACCOUNT_ID_SENDER,
+            testing::{build_account, build_assets},
+            AccountId, StorageMap,
+        },
+        notes::{
+            Note, NoteAssets, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient,
+            NoteScript, NoteTag, NoteType,
+        },
+        testing::accounts::{fungible_faucet, non_fungible_faucet},
+        Digest, Felt, ONE, ZERO,
+    };
+
+    #[test]
+    fn note_to_pretty_json() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let (asset_0, asset_1) = build_assets();
+        let tag = NoteTag::for_public_use_case(42, 7, NoteExecutionHint::Local).unwrap();
+        let metadata = NoteMetadata::new(sender, NoteType::Public, tag, ZERO).unwrap();
+
+        let code = ProgramAst::parse("begin push.1 drop end").unwrap();
+        let script_root = Digest::new([ONE, ZERO, ZERO, ZERO]);
+        let script = NoteScript::from_parts(code, script_root);
+        let inputs = NoteInputs::new(vec![Felt::new(5)]).unwrap();
+        let recipient = NoteRecipient::new([ONE; 4], script, inputs);
+        let note = Note::new(NoteAssets::new(vec![asset_0, asset_1]).unwrap(), metadata, recipient);
+
+        let known_scripts = BTreeMap::from([(script_root, "TEST")]);
+        let json: Value = serde_json::from_str(&note.to_pretty_json(&known_scripts)).unwrap();
+
+        assert_eq!(json["id"], note.id().inner().to_hex());
+        assert_eq!(json["metadata"]["sender"]["hex"], sender.to_hex());
+        assert_eq!(json["metadata"]["note_type"], "Public");
+        assert_eq!(json["metadata"]["tag"]["execution_hint"], "Local");
+        assert_eq!(json["metadata"]["tag"]["target"], "UseCase");
+        assert_eq!(json["metadata"]["tag"]["use_case_id"], 42);
+        assert_eq!(json["metadata"]["tag"]["payload"], 7);
+        assert_eq!(json["assets"][0]["type"], "Fungible");
+        assert_eq!(json["assets"][1]["type"], "Fungible");
+        assert_eq!(json["recipient"]["script"]["name"], "TEST");
+        assert_eq!(json["recipient"]["inputs"][0], 5);
+
+        // unknown scripts are rendered without a name
+        let json: Value = serde_json::from_str(&note.to_pretty_json(&BTreeMap::new())).unwrap();
+        assert_eq!(json["recipient"]["script"]["name"], Value::Null);
+    }
+
+    #[test]
+    fn account_to_pretty_json() {
+        let (asset_0, _) = build_assets();
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let storage_map = StorageMap::with_entries([(Digest::new(word), word)]).unwrap();
+        let account = build_account(vec![asset_0], ONE, vec![word], Some(storage_map));
+
+        let json: Value = serde_json::from_str(&account.to_pretty_json()).unwrap();
+
+        assert_eq!(json["id"]["hex"], account.id().to_hex());
+        assert_eq!(json["nonce"], 1);
+        assert_eq!(json["vault"]["assets"].as_array().unwrap().len(), 1);
+
+        // slot 0 holds the value item, slot 100 the map, and slot 255 the layout commitment
+        let slots = json["storage"]["slots"].as_array().unwrap();
+        assert_eq!(slots.len(), 3);
+        assert_eq!(slots[0]["type"]["kind"], "Value");
+        assert_eq!(slots[1]["index"], 100);
+        assert_eq!(slots[1]["type"]["kind"], "Map");
+        assert_eq!(slots[2]["reserved"], true);
+        assert_eq!(json["storage"]["maps"][0]["entries"].as_array().unwrap().len(), 1);
+
+        let procedures = json["code"]["procedures"].as_array().unwrap();
+        assert_eq!(procedures.len(), account.code().num_procedures());
+        assert_eq!(json["faucet"], Value::Null);
+
+        // the reserved slot of faucets is decoded into the faucet data
+        let json: Value = serde_json::from_str(&fungible_faucet(100).to_pretty_json()).unwrap();
+        assert_eq!(json["faucet"]["total_issuance"], 100);
+
+        let faucet = non_fungible_faucet();
+        let json: Value = serde_json::from_str(&faucet.to_pretty_json()).unwrap();
+        assert_eq!(
+            json["faucet"]["issued_assets_root"],
+            faucet.storage().maps()[0].root().to_hex()
+        );
+    }
+}
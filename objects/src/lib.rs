@@ -13,18 +13,35 @@ pub mod block;
 pub mod notes;
 pub mod transaction;
 
+#[cfg(feature = "proto")]
+pub mod proto;
+
+#[cfg(any(feature = "testing", test))]
+pub mod testing;
+
+mod borrowed;
+mod commitment;
+mod compression;
 mod constants;
 mod errors;
+mod size;
+
+#[cfg(feature = "serde")]
+mod explorer;
 
 // RE-EXPORTS
 // ================================================================================================
 
 pub use block::BlockHeader;
 pub use constants::*;
+#[cfg(feature = "proto")]
+pub use errors::ProtoConversionError;
 pub use errors::{
-    AccountDeltaError, AccountError, AssetError, AssetVaultError, BlockError, ChainMmrError,
-    NoteError, ProvenTransactionError, TransactionInputError, TransactionOutputError,
-    TransactionScriptError,
+    AccountDeltaError, AccountError, AccountTreeError, AdviceInputsError, AssetError,
+    AssetVaultError, BatchError, BlockError, BlockHeaderError, ChainMmrError, NoteError,
+    NullifierTreeError, ProvenTransactionError, TransactionArgsError, TransactionGraphError,
+    TransactionInputError, TransactionOutputError, TransactionScriptError,
+    TransactionValidityError,
 };
 pub use miden_crypto::hash::rpo::{Rpo256 as Hasher, RpoDigest as Digest};
 pub use vm_core::{Felt, FieldElement, StarkField, Word, EMPTY_WORD, ONE, WORD_SIZE, ZERO};
@@ -33,27 +50,41 @@ pub mod assembly {
     pub use assembly::{
         ast::{AstSerdeOptions, ModuleAst, ProgramAst},
         Assembler, AssemblyContext, AssemblyError, Library, LibraryNamespace, LibraryPath,
-        MaslLibrary, Version,
+        MaslLibrary, Module, Version,
     };
 }
 
 pub mod crypto {
     pub use miden_crypto::{dsa, hash, merkle, rand, utils};
+
+    pub mod commitment {
+        pub use crate::commitment::{
+            CommitmentScheme, DefaultCommitmentScheme, RpoCommitment, RpxCommitment,
+        };
+    }
 }
 
 pub mod utils {
     pub use miden_crypto::utils::{bytes_to_hex_string, collections, hex_to_bytes, HexParseError};
     pub use vm_core::utils::*;
 
+    pub use crate::size::SizeHint;
+
+    pub mod compression {
+        pub use crate::compression::{read_compressed, write_compressed, MAX_PAYLOAD_SIZE};
+    }
+
     pub mod serde {
         pub use miden_crypto::utils::{
-            ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+            ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
         };
     }
 }
 
 pub mod vm {
     pub use miden_verifier::ExecutionProof;
-    pub use vm_core::{code_blocks::CodeBlock, Program, ProgramInfo};
-    pub use vm_processor::{AdviceInputs, AdviceMap, StackInputs, StackOutputs};
+    pub use vm_core::{code_blocks::CodeBlock, DebugOptions, Program, ProgramInfo, SignatureKind};
+    pub use vm_processor::{
+        AdviceInputs, AdviceMap, ContextId, ProcessState, StackInputs, StackOutputs,
+    };
 }
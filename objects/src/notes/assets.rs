@@ -4,13 +4,13 @@ use super::{
     Asset, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher,
     NoteError, Serializable, Word, WORD_SIZE, ZERO,
 };
-use crate::MAX_ASSETS_PER_NOTE;
+use crate::{borrowed::BorrowedReader, transaction::KernelLimits};
 
 // NOTE ASSETS
 // ================================================================================================
 /// An asset container for a note.
 ///
-/// A note must contain at least 1 asset and can contain up to 256 assets. No duplicates are
+/// A note must contain at least 1 asset and can contain up to 255 assets. No duplicates are
 /// allowed, but the order of assets is unspecified.
 ///
 /// All the assets in a note can be reduced to a single commitment which is computed by
@@ -27,7 +27,7 @@ impl NoteAssets {
     // CONSTANTS
     // --------------------------------------------------------------------------------------------
     /// The maximum number of assets which can be carried by a single note.
-    pub const MAX_NUM_ASSETS: usize = MAX_ASSETS_PER_NOTE;
+    pub const MAX_NUM_ASSETS: usize = KernelLimits::CURRENT.max_note_assets();
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -35,7 +35,7 @@ impl NoteAssets {
     ///
     /// # Errors
     /// Returns an error if:
-    /// - The list contains more than 256 assets.
+    /// - The list contains more than [NoteAssets::MAX_NUM_ASSETS] assets.
     /// - There are duplicate assets in the list.
     pub fn new(assets: Vec<Asset>) -> Result<Self, NoteError> {
         if assets.len() > Self::MAX_NUM_ASSETS {
@@ -149,6 +149,98 @@ impl PartialEq for NoteAssets {
 
 impl Eq for NoteAssets {}
 
+// NOTE ASSETS REF
+// ================================================================================================
+
+/// A borrowed view of serialized [NoteAssets].
+///
+/// The view references the serialized assets directly, and thus, can be used to inspect note
+/// assets (e.g., to compute their commitment) without allocating memory for them. The encoding
+/// of each asset is validated when the view is created, but unlike [NoteAssets], the view does not
+/// check that the assets are unique; this is checked when the view is converted into
+/// [NoteAssets] via [NoteAssetsRef::to_note_assets()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteAssetsRef<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> NoteAssetsRef<'a> {
+    /// Number of bytes in a serialized asset.
+    const ASSET_SIZE: usize = 32;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a view of the [NoteAssets] serialized at the start of the provided bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes do not start with a valid encoding of note assets.
+    pub fn read_from_bytes(bytes: &'a [u8]) -> Result<Self, DeserializationError> {
+        Self::read_borrowed(&mut BorrowedReader::new(bytes))
+    }
+
+    /// Returns a view of the [NoteAssets] serialized at the current position of the provided
+    /// reader.
+    pub(crate) fn read_borrowed(
+        source: &mut BorrowedReader<'a>,
+    ) -> Result<Self, DeserializationError> {
+        let count = source.read_u8()? as usize + 1;
+        let data = source.read_borrowed(count * Self::ASSET_SIZE)?;
+        for chunk in data.chunks_exact(Self::ASSET_SIZE) {
+            let bytes: &[u8; 32] = chunk.try_into().expect("chunk is 32 bytes long");
+            Asset::try_from(bytes)
+                .map_err(|err| DeserializationError::InvalidValue(format!("{err}")))?;
+        }
+
+        Ok(Self { data })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a commitment to the note's assets.
+    ///
+    /// The commitment is the same as the one of the corresponding [NoteAssets], and is computed
+    /// without allocating memory for the assets.
+    pub fn commitment(&self) -> Digest {
+        let mut elements = [ZERO; (NoteAssets::MAX_NUM_ASSETS + 1) * WORD_SIZE];
+        for (i, asset) in self.iter().enumerate() {
+            elements[i * WORD_SIZE..(i + 1) * WORD_SIZE].copy_from_slice(&Word::from(asset));
+        }
+
+        // pad the elements to the next multiple of 2 words, as done for [NoteAssets]
+        let num_words = self.num_assets() + self.num_assets() % 2;
+        Hasher::hash_elements(&elements[..num_words * WORD_SIZE])
+    }
+
+    /// Returns the number of assets.
+    pub fn num_assets(&self) -> usize {
+        self.data.len() / Self::ASSET_SIZE
+    }
+
+    /// Returns the asset at the specified index, or None if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Asset> {
+        let offset = index.checked_mul(Self::ASSET_SIZE)?;
+        let bytes = self.data.get(offset..offset + Self::ASSET_SIZE)?;
+        Some(parse_asset(bytes))
+    }
+
+    /// Returns an iterator over all assets.
+    pub fn iter(&self) -> impl Iterator<Item = Asset> + 'a {
+        self.data.chunks_exact(Self::ASSET_SIZE).map(parse_asset)
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns [NoteAssets] containing the assets of this view.
+    ///
+    /// # Errors
+    /// Returns an error if the assets are not unique.
+    pub fn to_note_assets(&self) -> Result<NoteAssets, NoteError> {
+        NoteAssets::new(self.iter().collect())
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -187,6 +279,12 @@ fn compute_asset_commitment(assets: &[Asset]) -> Digest {
     Hasher::hash_elements(&asset_elements)
 }
 
+/// Parses an asset from bytes which were validated when a [NoteAssetsRef] was created.
+fn parse_asset(bytes: &[u8]) -> Asset {
+    let bytes: &[u8; 32] = bytes.try_into().expect("asset is 32 bytes long");
+    Asset::try_from(bytes).expect("asset was validated")
+}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -211,10 +309,16 @@ impl Deserializable for NoteAssets {
 
 #[cfg(test)]
 mod tests {
-    use super::{compute_asset_commitment, NoteAssets};
+    use alloc::vec::Vec;
+
+    use super::{compute_asset_commitment, NoteAssets, NoteAssetsRef};
     use crate::{
-        accounts::account_id::{testing::ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, AccountId},
+        accounts::account_id::{
+            testing::{ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN},
+            AccountId,
+        },
         assets::{Asset, FungibleAsset},
+        utils::serde::Serializable,
         Digest, Felt,
     };
 
@@ -240,4 +344,37 @@ mod tests {
         assert_eq!(assets.assets, vec![expected_asset]);
         assert_eq!(assets.hash, compute_asset_commitment(&[expected_asset]));
     }
+
+    #[test]
+    fn note_assets_ref() {
+        let assets: Vec<Asset> =
+            [ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN]
+                .into_iter()
+                .map(|id| FungibleAsset::new(AccountId::try_from(id).unwrap(), 100).unwrap().into())
+                .collect();
+
+        // odd and even numbers of assets are padded differently
+        for num_assets in [1, 2] {
+            let note_assets = NoteAssets::new(assets[..num_assets].to_vec()).unwrap();
+            let bytes = note_assets.to_bytes();
+            let assets_ref = NoteAssetsRef::read_from_bytes(&bytes).unwrap();
+
+            assert_eq!(assets_ref.num_assets(), num_assets);
+            assert_eq!(assets_ref.commitment(), note_assets.commitment());
+            assert!(assets_ref.iter().eq(note_assets.iter().copied()));
+            assert_eq!(assets_ref.get(num_assets - 1), Some(assets[num_assets - 1]));
+            assert_eq!(assets_ref.get(num_assets), None);
+            assert_eq!(assets_ref.to_note_assets().unwrap(), note_assets);
+
+            // truncated assets are rejected
+            assert!(NoteAssetsRef::read_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        }
+
+        // duplicate assets are detected when converting into note assets
+        let mut bytes = NoteAssets::new(assets[..1].to_vec()).unwrap().to_bytes();
+        bytes[0] = 1;
+        bytes.extend_from_slice(&assets[0].to_bytes());
+        let assets_ref = NoteAssetsRef::read_from_bytes(&bytes).unwrap();
+        assert!(assets_ref.to_note_assets().is_err());
+    }
 }
@@ -1,10 +1,11 @@
 use alloc::vec::Vec;
+use core::slice;
 
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher, NoteError,
-    Serializable, Word, WORD_SIZE, ZERO,
+    AccountId, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher,
+    NoteError, Serializable, Word, WORD_SIZE, ZERO,
 };
-use crate::{MAX_INPUTS_PER_NOTE, ONE};
+use crate::{transaction::KernelLimits, StarkField, ONE};
 
 // NOTE INPUTS
 // ================================================================================================
@@ -17,6 +18,10 @@ use crate::{MAX_INPUTS_PER_NOTE, ONE};
 /// All inputs associated with a note can be reduced to a single commitment which is computed by
 /// first padding the inputs with ZEROs to the next multiple of 8, and then by computing a
 /// sequential hash of the resulting elements.
+///
+/// Inputs can be encoded from typed values via [NoteInputsBuilder] and decoded via
+/// [NoteInputsReader], which makes sure the values are read in the order in which they were
+/// encoded.
 #[derive(Clone, Debug)]
 pub struct NoteInputs {
     values: Vec<Felt>,
@@ -24,17 +29,15 @@ pub struct NoteInputs {
 }
 
 impl NoteInputs {
-    /// Maximum number of input values associated with a single note.
-    const MAX_INPUTS_PER_NOTE: usize = MAX_INPUTS_PER_NOTE;
-
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Returns [NoteInputs] instantiated from the provided values.
     ///
     /// # Errors
-    /// Returns an error if the number of provided inputs is greater than 128.
+    /// Returns an error if the number of provided inputs is greater than
+    /// [KernelLimits::max_note_inputs()].
     pub fn new(values: Vec<Felt>) -> Result<Self, NoteError> {
-        if values.len() > Self::MAX_INPUTS_PER_NOTE {
+        if values.len() > KernelLimits::CURRENT.max_note_inputs() {
             return Err(NoteError::too_many_inputs(values.len()));
         }
 
@@ -46,6 +49,11 @@ impl NoteInputs {
         Ok(Self { values, hash })
     }
 
+    /// Returns a new [NoteInputsBuilder] for encoding note inputs from typed values.
+    pub fn builder() -> NoteInputsBuilder {
+        NoteInputsBuilder::default()
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -80,6 +88,11 @@ impl NoteInputs {
         self.values.to_vec()
     }
 
+    /// Returns a [NoteInputsReader] for decoding typed values from these inputs.
+    pub fn reader(&self) -> NoteInputsReader<'_> {
+        NoteInputsReader { values: self.values.iter(), position: 0 }
+    }
+
     // UTILITIES
     // --------------------------------------------------------------------------------------------
 
@@ -104,6 +117,147 @@ impl PartialEq for NoteInputs {
 
 impl Eq for NoteInputs {}
 
+// NOTE INPUTS BUILDER
+// ================================================================================================
+
+/// A builder which encodes typed values into [NoteInputs].
+///
+/// Values are appended in the order of the `push_*` calls; words are appended element by element,
+/// starting with the first element of the word. Note scripts load the inputs into memory in the
+/// same order via `miden::note::get_inputs`.
+#[derive(Debug, Clone, Default)]
+pub struct NoteInputsBuilder {
+    values: Vec<Felt>,
+    error: Option<NoteError>,
+}
+
+impl NoteInputsBuilder {
+    /// Appends the provided field element to the inputs.
+    pub fn push_felt(mut self, value: Felt) -> Self {
+        self.values.push(value);
+        self
+    }
+
+    /// Appends the provided u32 value to the inputs.
+    pub fn push_u32(self, value: u32) -> Self {
+        self.push_felt(value.into())
+    }
+
+    /// Appends the provided u64 value to the inputs.
+    ///
+    /// The value must be a valid field element, i.e., smaller than the field modulus; otherwise,
+    /// [NoteInputsBuilder::build()] returns an error.
+    pub fn push_u64(mut self, value: u64) -> Self {
+        if value >= Felt::MODULUS {
+            self.error.get_or_insert(NoteError::invalid_note_inputs(format!(
+                "input {} value {value} is not a valid field element",
+                self.values.len()
+            )));
+        }
+        self.push_felt(Felt::new(value))
+    }
+
+    /// Appends the provided account ID to the inputs.
+    pub fn push_account_id(self, account_id: AccountId) -> Self {
+        self.push_felt(account_id.into())
+    }
+
+    /// Appends the elements of the provided word to the inputs.
+    pub fn push_word(mut self, word: Word) -> Self {
+        self.values.extend(word);
+        self
+    }
+
+    /// Returns the [NoteInputs] encoding the values appended to this builder.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the u64 values is not a valid field element.
+    /// - The number of inputs is greater than [KernelLimits::max_note_inputs()].
+    pub fn build(self) -> Result<NoteInputs, NoteError> {
+        match self.error {
+            Some(err) => Err(err),
+            None => NoteInputs::new(self.values),
+        }
+    }
+}
+
+// NOTE INPUTS READER
+// ================================================================================================
+
+/// A reader which decodes typed values from [NoteInputs].
+///
+/// Values must be read in the order in which they were encoded. The layout of the inputs is
+/// validated while reading: each read fails if the inputs are exhausted or the value is not valid
+/// for the requested type, and [NoteInputsReader::finish()] fails if any inputs are left unread.
+#[derive(Debug, Clone)]
+pub struct NoteInputsReader<'a> {
+    values: slice::Iter<'a, Felt>,
+    position: usize,
+}
+
+impl<'a> NoteInputsReader<'a> {
+    /// Reads the next input as a field element.
+    pub fn read_felt(&mut self) -> Result<Felt, NoteError> {
+        let value = self.values.next().copied().ok_or_else(|| {
+            NoteError::invalid_note_inputs(format!("input {} is missing", self.position))
+        })?;
+        self.position += 1;
+        Ok(value)
+    }
+
+    /// Reads the next input as a u32 value.
+    pub fn read_u32(&mut self) -> Result<u32, NoteError> {
+        let value = self.read_felt()?.as_int();
+        u32::try_from(value).map_err(|_| {
+            NoteError::invalid_note_inputs(format!(
+                "input {} value {value} is not a valid u32 value",
+                self.position - 1
+            ))
+        })
+    }
+
+    /// Reads the next input as a u64 value.
+    pub fn read_u64(&mut self) -> Result<u64, NoteError> {
+        Ok(self.read_felt()?.as_int())
+    }
+
+    /// Reads the next input as an account ID.
+    pub fn read_account_id(&mut self) -> Result<AccountId, NoteError> {
+        let value = self.read_felt()?;
+        AccountId::try_from(value).map_err(|err| {
+            NoteError::invalid_note_inputs(format!(
+                "input {} is not a valid account ID: {err}",
+                self.position - 1
+            ))
+        })
+    }
+
+    /// Reads the next four inputs as a word.
+    pub fn read_word(&mut self) -> Result<Word, NoteError> {
+        Ok([self.read_felt()?, self.read_felt()?, self.read_felt()?, self.read_felt()?])
+    }
+
+    /// Returns the number of inputs which have not been read yet.
+    pub fn num_remaining(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Consumes the reader, making sure all inputs have been read.
+    ///
+    /// # Errors
+    /// Returns an error if any of the inputs has not been read.
+    pub fn finish(self) -> Result<(), NoteError> {
+        match self.num_remaining() {
+            0 => Ok(()),
+            num_remaining => Err(NoteError::invalid_note_inputs(format!(
+                "{num_remaining} inputs were not read after input {}",
+                self.position
+            ))),
+        }
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -145,7 +299,10 @@ impl Deserializable for NoteInputs {
 mod tests {
     use miden_crypto::utils::Deserializable;
 
-    use super::{Felt, NoteInputs, Serializable};
+    use super::{AccountId, Felt, NoteError, NoteInputs, Serializable};
+    use crate::{
+        accounts::account_id::testing::ACCOUNT_ID_SENDER, notes::NoteTag, StarkField, ONE,
+    };
 
     #[test]
     fn test_input_ordering() {
@@ -167,4 +324,60 @@ mod tests {
         let parsed_note_inputs = NoteInputs::read_from_bytes(&bytes).unwrap();
         assert_eq!(note_inputs, parsed_note_inputs);
     }
+
+    #[test]
+    fn test_typed_inputs() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let word = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+        let tag = NoteTag::from(7_u32);
+
+        let note_inputs = NoteInputs::builder()
+            .push_word(word)
+            .push_account_id(sender)
+            .push_u64(1_000)
+            .push_u32(tag.inner())
+            .build()
+            .unwrap();
+        assert_eq!(
+            note_inputs.values(),
+            [
+                ONE,
+                Felt::new(2),
+                Felt::new(3),
+                Felt::new(4),
+                sender.into(),
+                Felt::new(1_000),
+                7_u32.into()
+            ]
+        );
+
+        let mut reader = note_inputs.reader();
+        assert_eq!(reader.read_word().unwrap(), word);
+        assert_eq!(reader.read_account_id().unwrap(), sender);
+        assert_eq!(reader.read_u64().unwrap(), 1_000);
+        assert_eq!(reader.read_u32().unwrap(), 7);
+        reader.finish().unwrap();
+    }
+
+    #[test]
+    fn test_typed_inputs_validation() {
+        // u64 values must be valid field elements
+        let result = NoteInputs::builder().push_u64(Felt::MODULUS).build();
+        assert!(matches!(result, Err(NoteError::InvalidNoteInputs(_))));
+
+        let note_inputs =
+            NoteInputs::builder().push_u64(u32::MAX as u64 + 1).push_u32(1).build().unwrap();
+
+        // values must be valid for the requested types
+        assert!(note_inputs.reader().read_u32().is_err());
+        assert!(note_inputs.reader().read_account_id().is_err());
+
+        // reading past the end of the inputs fails, and so does leaving inputs unread
+        assert!(note_inputs.reader().read_word().is_err());
+        let mut reader = note_inputs.reader();
+        reader.read_u64().unwrap();
+        assert!(reader.clone().finish().is_err());
+        reader.read_u32().unwrap();
+        reader.finish().unwrap();
+    }
 }
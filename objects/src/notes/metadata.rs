@@ -1,8 +1,8 @@
 use alloc::string::ToString;
 
 use super::{
-    AccountId, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, NoteError,
-    NoteTag, NoteType, Serializable, Word,
+    AccountId, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, NoteAux,
+    NoteError, NoteTag, NoteType, Serializable, Word,
 };
 
 // NOTE METADATA
@@ -28,7 +28,7 @@ pub struct NoteMetadata {
     /// A value which can be used by the recipient(s) to identify notes intended for them.
     tag: NoteTag,
 
-    /// An arbitrary user-defined value.
+    /// An arbitrary user-defined value, which is interpreted as a [NoteAux] by the standard notes.
     aux: Felt,
 }
 
@@ -47,6 +47,20 @@ impl NoteMetadata {
         Ok(Self { sender, note_type, tag, aux })
     }
 
+    /// Returns a new [NoteMetadata] instantiated with the specified parameters, where the `aux`
+    /// value is encoded from the provided structured value.
+    ///
+    /// # Errors
+    /// Returns an error if the note type and note tag are inconsistent.
+    pub fn with_structured_aux(
+        sender: AccountId,
+        note_type: NoteType,
+        tag: NoteTag,
+        aux: NoteAux,
+    ) -> Result<Self, NoteError> {
+        Self::new(sender, note_type, tag, aux.into())
+    }
+
     /// Returns the account which created the note.
     pub fn sender(&self) -> AccountId {
         self.sender
@@ -67,6 +81,15 @@ impl NoteMetadata {
         self.aux
     }
 
+    /// Returns the note's aux field decoded according to the [NoteAuxSchema](super::NoteAuxSchema)
+    /// encoded in it.
+    ///
+    /// # Errors
+    /// Returns an error if the aux field does not encode a known schema.
+    pub fn structured_aux(&self) -> Result<NoteAux, NoteError> {
+        NoteAux::try_from(self.aux)
+    }
+
     /// Returns `true` if the note is off-chain.
     pub fn is_offchain(&self) -> bool {
         self.note_type == NoteType::OffChain
@@ -98,7 +121,7 @@ impl TryFrom<Word> for NoteMetadata {
         let note_type = elements[2].try_into()?;
         let tag: u64 = elements[0].into();
         let tag: u32 =
-            tag.try_into().map_err(|_| NoteError::InconsistentNoteTag(note_type, tag))?;
+            tag.try_into().map_err(|_| NoteError::InconsistentNoteTag { note_type, tag })?;
         Self::new(sender, note_type, tag.into(), elements[3])
     }
 }
@@ -15,17 +15,20 @@ use crate::{
 };
 
 mod assets;
-pub use assets::NoteAssets;
+pub use assets::{NoteAssets, NoteAssetsRef};
 
 mod details;
 pub use details::NoteDetails;
 
 mod inputs;
-pub use inputs::NoteInputs;
+pub use inputs::{NoteInputs, NoteInputsBuilder, NoteInputsReader};
 
 mod metadata;
 pub use metadata::NoteMetadata;
 
+mod note_aux;
+pub use note_aux::{NoteAux, NoteAuxSchema};
+
 mod note_header;
 pub use note_header::NoteHeader;
 
@@ -44,12 +47,18 @@ pub use nullifier::Nullifier;
 mod origin;
 pub use origin::{NoteInclusionProof, NoteOrigin};
 
+mod partial;
+pub use partial::PartialNote;
+
 mod recipient;
 pub use recipient::NoteRecipient;
 
 mod script;
 pub use script::NoteScript;
 
+mod serial_num;
+pub use serial_num::NoteSerialNumGenerator;
+
 // CONSTANTS
 // ================================================================================================
 
@@ -163,7 +172,7 @@ impl Note {
     /// > hash(note_id, note_metadata)
     ///
     pub fn authentication_hash(&self) -> Digest {
-        Hasher::merge(&[self.id().inner(), Word::from(self.metadata()).into()])
+        self.header.authentication_hash()
     }
 }
 
@@ -0,0 +1,202 @@
+use super::{Felt, NoteError};
+use crate::Hasher;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of low-order bits of the `aux` value which hold the payload of the schema.
+const PAYLOAD_BITS: u32 = 56;
+
+// NOTE AUX SCHEMA
+// ================================================================================================
+
+/// Registry of the schemas which define how the `aux` value of a note's metadata is interpreted.
+///
+/// The schema is encoded in the most significant 8 bits of the `aux` value, so that values written
+/// without a schema (i.e., values smaller than 2^56) are interpreted as [NoteAuxSchema::Raw].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum NoteAuxSchema {
+    /// An arbitrary application-defined value.
+    Raw = 0,
+
+    /// The truncated hash of a memo attached to the note off-chain.
+    MemoHash = 1,
+
+    /// A reference identifying the payment made by the note, e.g., an invoice number.
+    PaymentReference = 2,
+
+    /// The ID of the order the note belongs to, e.g., for notes created by a trading venue.
+    OrderId = 3,
+}
+
+impl TryFrom<u8> for NoteAuxSchema {
+    type Error = NoteError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Raw),
+            1 => Ok(Self::MemoHash),
+            2 => Ok(Self::PaymentReference),
+            3 => Ok(Self::OrderId),
+            _ => Err(NoteError::InvalidNoteAuxSchema(value)),
+        }
+    }
+}
+
+// NOTE AUX
+// ================================================================================================
+
+/// A structured `aux` value of a note's metadata.
+///
+/// The value consists of a [NoteAuxSchema] and a payload of at most 56 bits, which are encoded
+/// into a single field element as `(schema << 56) | payload`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NoteAux {
+    schema: NoteAuxSchema,
+    payload: u64,
+}
+
+impl NoteAux {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The maximum payload of a structured `aux` value.
+    pub const MAX_PAYLOAD: u64 = (1 << PAYLOAD_BITS) - 1;
+
+    /// The `aux` value of notes which do not carry any auxiliary data.
+    pub const EMPTY: Self = Self { schema: NoteAuxSchema::Raw, payload: 0 };
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NoteAux] with the specified schema and payload.
+    ///
+    /// # Errors
+    /// Returns an error if the payload is greater than [NoteAux::MAX_PAYLOAD].
+    pub fn new(schema: NoteAuxSchema, payload: u64) -> Result<Self, NoteError> {
+        if payload > Self::MAX_PAYLOAD {
+            return Err(NoteError::InvalidNoteAuxPayload(payload));
+        }
+
+        Ok(Self { schema, payload })
+    }
+
+    /// Returns a [NoteAux] holding the hash of the provided memo.
+    ///
+    /// The payload is the lowest 56 bits of the first element of the memo's hash, and thus,
+    /// recipients holding the memo can check it against the note, but cannot recover the memo
+    /// from the note.
+    pub fn memo_hash(memo: &[u8]) -> Self {
+        let hash = Hasher::hash(memo);
+        Self {
+            schema: NoteAuxSchema::MemoHash,
+            payload: hash[0].as_int() & Self::MAX_PAYLOAD,
+        }
+    }
+
+    /// Returns a [NoteAux] holding the specified payment reference.
+    ///
+    /// # Errors
+    /// Returns an error if the reference is greater than [NoteAux::MAX_PAYLOAD].
+    pub fn payment_reference(reference: u64) -> Result<Self, NoteError> {
+        Self::new(NoteAuxSchema::PaymentReference, reference)
+    }
+
+    /// Returns a [NoteAux] holding the specified order ID.
+    ///
+    /// # Errors
+    /// Returns an error if the order ID is greater than [NoteAux::MAX_PAYLOAD].
+    pub fn order_id(order_id: u64) -> Result<Self, NoteError> {
+        Self::new(NoteAuxSchema::OrderId, order_id)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the schema of the `aux` value.
+    pub fn schema(&self) -> NoteAuxSchema {
+        self.schema
+    }
+
+    /// Returns the payload of the `aux` value.
+    pub fn payload(&self) -> u64 {
+        self.payload
+    }
+
+    /// Returns true if the `aux` value holds the hash of the provided memo.
+    pub fn matches_memo(&self, memo: &[u8]) -> bool {
+        *self == Self::memo_hash(memo)
+    }
+}
+
+impl Default for NoteAux {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<NoteAux> for Felt {
+    fn from(aux: NoteAux) -> Self {
+        Felt::new(((aux.schema as u64) << PAYLOAD_BITS) | aux.payload)
+    }
+}
+
+impl TryFrom<Felt> for NoteAux {
+    type Error = NoteError;
+
+    fn try_from(aux: Felt) -> Result<Self, Self::Error> {
+        let value = aux.as_int();
+        let schema = NoteAuxSchema::try_from((value >> PAYLOAD_BITS) as u8)?;
+
+        Ok(Self {
+            schema,
+            payload: value & Self::MAX_PAYLOAD,
+        })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{NoteAux, NoteAuxSchema, PAYLOAD_BITS};
+    use crate::{Felt, NoteError, ZERO};
+
+    #[test]
+    fn note_aux_encoding() {
+        for aux in [
+            NoteAux::EMPTY,
+            NoteAux::new(NoteAuxSchema::Raw, 27).unwrap(),
+            NoteAux::memo_hash(b"invoice #42"),
+            NoteAux::payment_reference(NoteAux::MAX_PAYLOAD).unwrap(),
+            NoteAux::order_id(1234).unwrap(),
+        ] {
+            assert_eq!(NoteAux::try_from(Felt::from(aux)), Ok(aux));
+        }
+
+        // values without a schema are raw values
+        assert_eq!(NoteAux::try_from(ZERO), Ok(NoteAux::EMPTY));
+        assert_eq!(NoteAux::try_from(Felt::new(27)).unwrap().schema(), NoteAuxSchema::Raw);
+        assert_eq!(Felt::from(NoteAux::order_id(5).unwrap()), Felt::new((3 << PAYLOAD_BITS) | 5));
+
+        assert!(NoteAux::memo_hash(b"invoice #42").matches_memo(b"invoice #42"));
+        assert!(!NoteAux::memo_hash(b"invoice #42").matches_memo(b"invoice #43"));
+    }
+
+    #[test]
+    fn invalid_note_aux() {
+        assert_eq!(
+            NoteAux::order_id(NoteAux::MAX_PAYLOAD + 1),
+            Err(NoteError::InvalidNoteAuxPayload(NoteAux::MAX_PAYLOAD + 1))
+        );
+        assert_eq!(
+            NoteAux::try_from(Felt::new(4 << PAYLOAD_BITS)),
+            Err(NoteError::InvalidNoteAuxSchema(4))
+        );
+    }
+}
@@ -1,8 +1,8 @@
 use alloc::vec::Vec;
 
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, NoteId, NoteMetadata,
-    Serializable, Word,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher, NoteId,
+    NoteMetadata, Serializable, Word,
 };
 
 // NOTE HEADER
@@ -35,6 +35,13 @@ impl NoteHeader {
     pub fn metadata(&self) -> &NoteMetadata {
         &self.note_metadata
     }
+
+    /// Returns the note's authentication hash, computed as hash(note_id, note_metadata).
+    ///
+    /// See [Note::authentication_hash()](super::Note::authentication_hash) for details.
+    pub fn authentication_hash(&self) -> Digest {
+        Hasher::merge(&[self.note_id.inner(), Word::from(self.metadata()).into()])
+    }
 }
 
 // CONVERSIONS FROM NOTE HEADER
@@ -1,10 +1,13 @@
 use alloc::string::String;
 use core::fmt::Display;
 
-use super::{Digest, Felt, Hasher, NoteDetails, Word};
-use crate::utils::{
-    serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    HexParseError,
+use super::{Digest, Felt, NoteDetails, Word};
+use crate::{
+    crypto::commitment::{CommitmentScheme, DefaultCommitmentScheme},
+    utils::{
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+        HexParseError,
+    },
 };
 
 // NOTE ID
@@ -31,7 +34,7 @@ pub struct NoteId(Digest);
 impl NoteId {
     /// Returns a new [NoteId] instantiated from the provided note components.
     pub fn new(recipient: Digest, asset_commitment: Digest) -> Self {
-        Self(Hasher::merge(&[recipient, asset_commitment]))
+        Self(DefaultCommitmentScheme::merge(&[recipient, asset_commitment]))
     }
 
     /// Returns the elements representation of this note ID.
@@ -2,10 +2,13 @@ use alloc::string::String;
 use core::fmt::{Debug, Display, Formatter};
 
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher,
-    NoteDetails, Serializable, Word, WORD_SIZE, ZERO,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, NoteDetails,
+    Serializable, Word, WORD_SIZE, ZERO,
+};
+use crate::{
+    crypto::commitment::{CommitmentScheme, DefaultCommitmentScheme},
+    utils::{hex_to_bytes, HexParseError},
 };
-use crate::utils::{hex_to_bytes, HexParseError};
 
 // NULLIFIER
 // ================================================================================================
@@ -38,7 +41,7 @@ impl Nullifier {
         elements[4..8].copy_from_slice(script_hash.as_elements());
         elements[8..12].copy_from_slice(inputs_hash.as_elements());
         elements[12..].copy_from_slice(asset_hash.as_elements());
-        Self(Hasher::hash_elements(&elements))
+        Self(DefaultCommitmentScheme::hash_elements(&elements))
     }
 
     /// Returns the elements of this nullifier.
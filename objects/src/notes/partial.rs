@@ -0,0 +1,86 @@
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, NoteAssets, NoteHeader,
+    NoteId, NoteMetadata, Serializable,
+};
+
+// PARTIAL NOTE
+// ================================================================================================
+
+/// A note for which only the recipient digest is known, but not the recipient details.
+///
+/// Partial notes are created when a note is sent to a recipient communicated as a single digest,
+/// e.g., by an exchange which does not disclose the script, inputs and serial number of the note.
+/// Since the note ID commits only to the recipient digest and the assets, the ID of a partial
+/// note can be computed, but the note cannot be consumed without learning the recipient details.
+///
+/// See [NoteRecipient](super::NoteRecipient) for details on how the recipient digest is computed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PartialNote {
+    metadata: NoteMetadata,
+    recipient_digest: Digest,
+    assets: NoteAssets,
+}
+
+impl PartialNote {
+    /// Returns a new [PartialNote] instantiated from the provided parameters.
+    pub fn new(metadata: NoteMetadata, recipient_digest: Digest, assets: NoteAssets) -> Self {
+        Self { metadata, recipient_digest, assets }
+    }
+
+    /// Returns the ID corresponding to this note.
+    pub fn id(&self) -> NoteId {
+        NoteId::new(self.recipient_digest, self.assets.commitment())
+    }
+
+    /// Returns the metadata associated with this note.
+    pub fn metadata(&self) -> &NoteMetadata {
+        &self.metadata
+    }
+
+    /// Returns the digest of the recipient associated with this note.
+    pub fn recipient_digest(&self) -> Digest {
+        self.recipient_digest
+    }
+
+    /// Returns a list of assets associated with this note.
+    pub fn assets(&self) -> &NoteAssets {
+        &self.assets
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<&PartialNote> for NoteHeader {
+    fn from(note: &PartialNote) -> Self {
+        NoteHeader::new(note.id(), note.metadata)
+    }
+}
+
+impl From<PartialNote> for NoteHeader {
+    fn from(note: PartialNote) -> Self {
+        (&note).into()
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for PartialNote {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.metadata.write_into(target);
+        self.recipient_digest.write_into(target);
+        self.assets.write_into(target);
+    }
+}
+
+impl Deserializable for PartialNote {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let metadata = NoteMetadata::read_from(source)?;
+        let recipient_digest = Digest::read_from(source)?;
+        let assets = NoteAssets::read_from(source)?;
+
+        Ok(Self::new(metadata, recipient_digest, assets))
+    }
+}
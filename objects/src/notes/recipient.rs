@@ -4,9 +4,10 @@ use core::fmt::Debug;
 use miden_crypto::Felt;
 
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Hasher, NoteInputs,
-    NoteScript, Serializable, Word,
+    AccountId, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Hasher,
+    NoteInputs, NoteScript, Serializable, Word, ZERO,
 };
+use crate::crypto::commitment::{CommitmentScheme, DefaultCommitmentScheme};
 
 /// Value that describes under which condition a note can be consumed.
 ///
@@ -33,6 +34,20 @@ impl NoteRecipient {
         Self { serial_num, script, inputs, digest }
     }
 
+    /// Returns the serial number of the note with the specified index among the notes created by
+    /// the specified account at the specified nonce.
+    ///
+    /// The serial number is computed as hash(sender, nonce, counter, 0), and thus, serial numbers
+    /// of the notes created by an account never collide as long as the counter is unique for every
+    /// note created in a transaction.
+    ///
+    /// Since the serial number is derived from public data only, anyone can link the nullifier of
+    /// the note to the note. Use [NoteSerialNumGenerator](super::NoteSerialNumGenerator) to derive
+    /// serial numbers which cannot be predicted.
+    pub fn derive_serial_num(sender: AccountId, nonce: Felt, counter: u32) -> Word {
+        Hasher::hash_elements(&[sender.into(), nonce, Felt::from(counter), ZERO]).into()
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -69,9 +84,9 @@ impl NoteRecipient {
 }
 
 fn compute_recipient_digest(serial_num: Word, script: &NoteScript, inputs: &NoteInputs) -> Digest {
-    let serial_num_hash = Hasher::merge(&[serial_num.into(), Digest::default()]);
-    let merge_script = Hasher::merge(&[serial_num_hash, script.hash()]);
-    Hasher::merge(&[merge_script, inputs.commitment()])
+    let serial_num_hash = DefaultCommitmentScheme::merge(&[serial_num.into(), Digest::default()]);
+    let merge_script = DefaultCommitmentScheme::merge(&[serial_num_hash, script.hash()]);
+    DefaultCommitmentScheme::merge(&[merge_script, inputs.commitment()])
 }
 
 // SERIALIZATION
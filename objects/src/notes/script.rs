@@ -1,6 +1,9 @@
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 
-use assembly::ast::AstSerdeOptions;
+use assembly::{
+    ast::{AstSerdeOptions, ModuleImports, Node, ProcedureAst},
+    LibraryPath, ProcedureId, ProcedureName,
+};
 use miden_crypto::Felt;
 
 use super::{Assembler, AssemblyContext, CodeBlock, Digest, NoteError, ProgramAst};
@@ -154,8 +157,87 @@ impl Serializable for NoteScript {
 impl Deserializable for NoteScript {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let hash = Digest::read_from(source)?;
-        let code = ProgramAst::read_from(source)?;
+        let code = read_program_ast(source)?;
 
         Ok(Self::from_parts(code, hash))
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Deserializes a [ProgramAst] from the provided source.
+///
+/// This mirrors [ProgramAst::read_from()], but returns an error (rather than panicking) if any of
+/// the imported library paths consists of a single component.
+fn read_program_ast<R: ByteReader>(source: &mut R) -> Result<ProgramAst, DeserializationError> {
+    let options = AstSerdeOptions::read_from(source)?;
+
+    let import_info = if options.serialize_imports {
+        let num_imports = source.read_u16()?;
+        let mut imports = BTreeMap::new();
+        for _ in 0..num_imports {
+            let path = read_import_path(source)?;
+            imports.insert(path.last().to_string(), path);
+        }
+
+        let num_invoked_procs = source.read_u16()?;
+        let mut invoked_procs = BTreeMap::new();
+        for _ in 0..num_invoked_procs {
+            let proc_id = ProcedureId::read_from(source)?;
+            let proc_name = ProcedureName::read_from(source)?;
+            let path = read_import_path(source)?;
+            invoked_procs.insert(proc_id, (proc_name, path));
+        }
+
+        ModuleImports::new(imports, invoked_procs)
+    } else {
+        ModuleImports::default()
+    };
+
+    let num_local_procs = source.read_u16()?;
+    let local_procs = source.read_many::<ProcedureAst>(num_local_procs.into())?;
+    let body_len = source.read_u16()?;
+    let body = source.read_many::<Node>(body_len.into())?;
+
+    ProgramAst::new(body, local_procs)
+        .map(|program| program.with_import_info(import_info))
+        .map_err(|err| DeserializationError::UnknownError(err.message().clone()))
+}
+
+/// Deserializes a library path of an imported module; the path must consist of at least two
+/// components (i.e., a library namespace and a module name).
+fn read_import_path<R: ByteReader>(source: &mut R) -> Result<LibraryPath, DeserializationError> {
+    let path = LibraryPath::read_from(source)?;
+    if path.num_components() < 2 {
+        return Err(DeserializationError::InvalidValue(format!(
+            "imported library path {path} must contain at least two components"
+        )));
+    }
+
+    Ok(path)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{Deserializable, NoteScript, Serializable};
+    use crate::Digest;
+
+    #[test]
+    fn test_note_script_single_component_import() {
+        // a note script importing a module whose path consists of a single component
+        let mut bytes = Vec::new();
+        Digest::default().write_into(&mut bytes);
+        bytes.push(1);
+        bytes.extend_from_slice(&1_u16.to_le_bytes());
+        bytes.extend_from_slice(&3_u16.to_le_bytes());
+        bytes.extend_from_slice(b"std");
+
+        assert!(NoteScript::read_from_bytes(&bytes).is_err());
+    }
+}
@@ -0,0 +1,118 @@
+use miden_crypto::rand::{FeltRng, RpoRandomCoin};
+
+use super::{AccountId, Digest, Felt, Hasher, Word, ZERO};
+
+// NOTE SERIAL NUMBER GENERATOR
+// ================================================================================================
+
+/// A deterministic generator of serial numbers for the notes created by an account.
+///
+/// The generator is an [RpoRandomCoin] seeded with a secret seed of the note creator and the state
+/// of the sender account, i.e., its ID and nonce. Thus, a wallet can reproduce the serial numbers
+/// of the notes it created in a transaction from the secret seed and the state of the account
+/// before the transaction, while the serial numbers cannot be predicted by anyone who does not
+/// know the seed.
+///
+/// To avoid reusing serial numbers, a new generator must be created for every transaction, since
+/// the nonce of the account is incremented by transactions which create notes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteSerialNumGenerator {
+    rng: RpoRandomCoin,
+}
+
+impl NoteSerialNumGenerator {
+    /// Returns a new [NoteSerialNumGenerator] for the notes created by the specified account at the
+    /// specified nonce.
+    pub fn new(seed: Word, sender: AccountId, nonce: Felt) -> Self {
+        let state = Hasher::merge(&[seed.into(), Digest::from([sender.into(), nonce, ZERO, ZERO])]);
+        Self { rng: RpoRandomCoin::new(state.into()) }
+    }
+
+    /// Returns the next serial number.
+    pub fn next_serial_num(&mut self) -> Word {
+        self.rng.draw_word()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountId, Felt, NoteSerialNumGenerator, ZERO};
+    use crate::{
+        accounts::account_id::testing::{
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        assembly::{Assembler, ProgramAst},
+        notes::{
+            Note, NoteAssets, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient,
+            NoteScript, NoteTag, NoteType,
+        },
+        transaction::{OutputNote, OutputNotes},
+        TransactionOutputError, ONE,
+    };
+
+    #[test]
+    fn test_serial_num_generator() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let seed = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+
+        // the serial numbers are reproducible from the seed and the state of the sender
+        let mut generator = NoteSerialNumGenerator::new(seed, sender, ONE);
+        let serial_nums = [generator.next_serial_num(), generator.next_serial_num()];
+        let mut generator = NoteSerialNumGenerator::new(seed, sender, ONE);
+        assert_eq!(serial_nums, [generator.next_serial_num(), generator.next_serial_num()]);
+        assert_ne!(serial_nums[0], serial_nums[1]);
+
+        // the serial numbers depend on the seed, the sender and its nonce
+        let other_sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        for mut other_generator in [
+            NoteSerialNumGenerator::new([ZERO; 4], sender, ONE),
+            NoteSerialNumGenerator::new(seed, other_sender, ONE),
+            NoteSerialNumGenerator::new(seed, sender, Felt::new(2)),
+        ] {
+            assert!(!serial_nums.contains(&other_generator.next_serial_num()));
+        }
+    }
+
+    #[test]
+    fn test_derive_serial_num() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let serial_num = NoteRecipient::derive_serial_num(sender, ONE, 0);
+
+        assert_eq!(serial_num, NoteRecipient::derive_serial_num(sender, ONE, 0));
+        assert_ne!(serial_num, NoteRecipient::derive_serial_num(sender, ONE, 1));
+        assert_ne!(serial_num, NoteRecipient::derive_serial_num(sender, Felt::new(2), 0));
+    }
+
+    #[test]
+    fn test_output_notes_reject_reused_serial_num() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let program = ProgramAst::parse("begin push.1 drop end").unwrap();
+        let (script, _) = NoteScript::new(program, &Assembler::default()).unwrap();
+        let tag = NoteTag::from_account_id(sender, NoteExecutionHint::Local).unwrap();
+        let metadata = NoteMetadata::new(sender, NoteType::Public, tag, ZERO).unwrap();
+
+        let make_note = |serial_num, input| {
+            let inputs = NoteInputs::new(vec![input]).unwrap();
+            let recipient = NoteRecipient::new(serial_num, script.clone(), inputs);
+            OutputNote::Full(Note::new(NoteAssets::default(), metadata, recipient))
+        };
+
+        let serial_num = NoteRecipient::derive_serial_num(sender, ONE, 0);
+        let other_serial_num = NoteRecipient::derive_serial_num(sender, ONE, 1);
+        assert!(OutputNotes::new(vec![
+            make_note(serial_num, ONE),
+            make_note(other_serial_num, Felt::new(2))
+        ])
+        .is_ok());
+
+        // notes with different details reusing a serial number are rejected
+        assert_eq!(
+            OutputNotes::new(vec![make_note(serial_num, ONE), make_note(serial_num, Felt::new(2))]),
+            Err(TransactionOutputError::DuplicateSerialNumber(serial_num))
+        );
+    }
+}
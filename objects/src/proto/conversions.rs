@@ -0,0 +1,410 @@
+use alloc::vec::Vec;
+
+use miden_verifier::ExecutionProof;
+
+use crate::{
+    accounts::{delta::AccountUpdateDetails, AccountId},
+    assets::Asset,
+    notes::{
+        Note, NoteAssets, NoteHeader, NoteId, NoteInputs, NoteMetadata, NoteRecipient, NoteScript,
+        NoteTag, NoteType, Nullifier, PartialNote,
+    },
+    proto,
+    transaction::{
+        InputNoteCommitment, KernelVersion, OutputNote, ProvenTransaction, ProvenTransactionBuilder,
+    },
+    utils::serde::{Deserializable, Serializable},
+    BlockHeader, Digest, Felt, NoteError, ProtoConversionError, Word,
+};
+
+// DIGEST
+// ================================================================================================
+
+impl From<Word> for proto::Digest {
+    fn from(word: Word) -> Self {
+        let [d0, d1, d2, d3] = word.map(|element| element.as_int());
+        Self { d0, d1, d2, d3 }
+    }
+}
+
+impl From<Digest> for proto::Digest {
+    fn from(digest: Digest) -> Self {
+        Word::from(digest).into()
+    }
+}
+
+impl TryFrom<proto::Digest> for Word {
+    type Error = ProtoConversionError;
+
+    fn try_from(digest: proto::Digest) -> Result<Self, Self::Error> {
+        Ok([
+            felt_from_u64(digest.d0)?,
+            felt_from_u64(digest.d1)?,
+            felt_from_u64(digest.d2)?,
+            felt_from_u64(digest.d3)?,
+        ])
+    }
+}
+
+impl TryFrom<proto::Digest> for Digest {
+    type Error = ProtoConversionError;
+
+    fn try_from(digest: proto::Digest) -> Result<Self, Self::Error> {
+        Word::try_from(digest).map(Digest::from)
+    }
+}
+
+// ACCOUNT ID
+// ================================================================================================
+
+impl From<AccountId> for proto::AccountId {
+    fn from(account_id: AccountId) -> Self {
+        Self { id: account_id.into() }
+    }
+}
+
+impl TryFrom<proto::AccountId> for AccountId {
+    type Error = ProtoConversionError;
+
+    fn try_from(account_id: proto::AccountId) -> Result<Self, Self::Error> {
+        AccountId::try_from(account_id.id).map_err(ProtoConversionError::InvalidAccountId)
+    }
+}
+
+// NOTE METADATA
+// ================================================================================================
+
+impl From<NoteMetadata> for proto::NoteMetadata {
+    fn from(metadata: NoteMetadata) -> Self {
+        Self {
+            sender: Some(metadata.sender().into()),
+            note_type: metadata.note_type() as u32,
+            tag: metadata.tag().inner(),
+            aux: metadata.aux().as_int(),
+        }
+    }
+}
+
+impl TryFrom<proto::NoteMetadata> for NoteMetadata {
+    type Error = ProtoConversionError;
+
+    fn try_from(metadata: proto::NoteMetadata) -> Result<Self, Self::Error> {
+        let sender = required(metadata.sender, "sender")?.try_into()?;
+        let note_type = u8::try_from(metadata.note_type)
+            .map_err(|_| NoteError::InvalidNoteTypeValue(metadata.note_type.into()))
+            .and_then(NoteType::try_from)
+            .map_err(ProtoConversionError::InvalidNote)?;
+        let aux = felt_from_u64(metadata.aux)?;
+
+        NoteMetadata::new(sender, note_type, NoteTag::from(metadata.tag), aux)
+            .map_err(ProtoConversionError::InvalidNote)
+    }
+}
+
+// NOTE
+// ================================================================================================
+
+impl From<Note> for proto::Note {
+    fn from(note: Note) -> Self {
+        (&note).into()
+    }
+}
+
+impl From<&Note> for proto::Note {
+    fn from(note: &Note) -> Self {
+        Self {
+            metadata: Some((*note.metadata()).into()),
+            assets: note.assets().iter().map(|asset| Word::from(*asset).into()).collect(),
+            serial_num: Some(note.serial_num().into()),
+            script: note.script().to_bytes(),
+            inputs: note.inputs().values().iter().map(|input| input.as_int()).collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::Note> for Note {
+    type Error = ProtoConversionError;
+
+    fn try_from(note: proto::Note) -> Result<Self, Self::Error> {
+        let metadata = required(note.metadata, "metadata")?.try_into()?;
+
+        let assets = note_assets_from_proto(note.assets)?;
+
+        let serial_num = required(note.serial_num, "serial_num")?.try_into()?;
+        let script = NoteScript::read_from_bytes(&note.script)
+            .map_err(ProtoConversionError::DeserializationFailed)?;
+        let inputs = note.inputs.into_iter().map(felt_from_u64).collect::<Result<Vec<_>, _>>()?;
+        let inputs = NoteInputs::new(inputs).map_err(ProtoConversionError::InvalidNote)?;
+
+        Ok(Note::new(assets, metadata, NoteRecipient::new(serial_num, script, inputs)))
+    }
+}
+
+// PARTIAL NOTE
+// ================================================================================================
+
+impl From<&PartialNote> for proto::PartialNote {
+    fn from(note: &PartialNote) -> Self {
+        Self {
+            metadata: Some((*note.metadata()).into()),
+            recipient: Some(note.recipient_digest().into()),
+            assets: note.assets().iter().map(|asset| Word::from(*asset).into()).collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::PartialNote> for PartialNote {
+    type Error = ProtoConversionError;
+
+    fn try_from(note: proto::PartialNote) -> Result<Self, Self::Error> {
+        let metadata = required(note.metadata, "metadata")?.try_into()?;
+        let recipient = required(note.recipient, "recipient")?.try_into()?;
+        let assets = note_assets_from_proto(note.assets)?;
+
+        Ok(PartialNote::new(metadata, recipient, assets))
+    }
+}
+
+// NOTE HEADER
+// ================================================================================================
+
+impl From<NoteHeader> for proto::NoteHeader {
+    fn from(header: NoteHeader) -> Self {
+        Self {
+            note_id: Some(Digest::from(header.id()).into()),
+            metadata: Some((*header.metadata()).into()),
+        }
+    }
+}
+
+impl TryFrom<proto::NoteHeader> for NoteHeader {
+    type Error = ProtoConversionError;
+
+    fn try_from(header: proto::NoteHeader) -> Result<Self, Self::Error> {
+        let note_id = Digest::try_from(required(header.note_id, "note_id")?)?;
+        let metadata = required(header.metadata, "metadata")?.try_into()?;
+
+        Ok(NoteHeader::new(NoteId::from(note_id), metadata))
+    }
+}
+
+// OUTPUT NOTE
+// ================================================================================================
+
+impl From<OutputNote> for proto::OutputNote {
+    fn from(note: OutputNote) -> Self {
+        (&note).into()
+    }
+}
+
+impl From<&OutputNote> for proto::OutputNote {
+    fn from(note: &OutputNote) -> Self {
+        let note = match note {
+            OutputNote::Full(note) => proto::output_note::Note::Full(note.into()),
+            OutputNote::Partial(note) => proto::output_note::Note::Partial(note.into()),
+            OutputNote::Header(header) => proto::output_note::Note::Header((*header).into()),
+        };
+
+        Self { note: Some(note) }
+    }
+}
+
+impl TryFrom<proto::OutputNote> for OutputNote {
+    type Error = ProtoConversionError;
+
+    fn try_from(note: proto::OutputNote) -> Result<Self, Self::Error> {
+        match required(note.note, "note")? {
+            proto::output_note::Note::Full(note) => Ok(OutputNote::Full(note.try_into()?)),
+            proto::output_note::Note::Partial(note) => Ok(OutputNote::Partial(note.try_into()?)),
+            proto::output_note::Note::Header(header) => Ok(OutputNote::Header(header.try_into()?)),
+        }
+    }
+}
+
+// INPUT NOTE COMMITMENT
+// ================================================================================================
+
+impl From<&InputNoteCommitment> for proto::InputNoteCommitment {
+    fn from(note: &InputNoteCommitment) -> Self {
+        Self {
+            nullifier: Some(note.nullifier().inner().into()),
+            header: note.header().map(|header| (*header).into()),
+        }
+    }
+}
+
+impl TryFrom<proto::InputNoteCommitment> for InputNoteCommitment {
+    type Error = ProtoConversionError;
+
+    fn try_from(note: proto::InputNoteCommitment) -> Result<Self, Self::Error> {
+        let nullifier = Digest::try_from(required(note.nullifier, "nullifier")?)?;
+        let header = note.header.map(NoteHeader::try_from).transpose()?;
+
+        Ok(InputNoteCommitment::new(Nullifier::from(nullifier), header))
+    }
+}
+
+// KERNEL VERSION
+// ================================================================================================
+
+impl From<KernelVersion> for proto::KernelVersion {
+    fn from(version: KernelVersion) -> Self {
+        Self {
+            major: version.major().into(),
+            minor: version.minor().into(),
+            patch: version.patch().into(),
+            root: Some(version.root().into()),
+        }
+    }
+}
+
+impl TryFrom<proto::KernelVersion> for KernelVersion {
+    type Error = ProtoConversionError;
+
+    fn try_from(version: proto::KernelVersion) -> Result<Self, Self::Error> {
+        let to_u8 = |value: u32| {
+            u8::try_from(value).map_err(|_| ProtoConversionError::InvalidKernelVersion(value))
+        };
+
+        Ok(KernelVersion::new(
+            to_u8(version.major)?,
+            to_u8(version.minor)?,
+            to_u8(version.patch)?,
+            required(version.root, "root")?.try_into()?,
+        ))
+    }
+}
+
+// PROVEN TRANSACTION
+// ================================================================================================
+
+impl From<ProvenTransaction> for proto::ProvenTransaction {
+    fn from(tx: ProvenTransaction) -> Self {
+        (&tx).into()
+    }
+}
+
+impl From<&ProvenTransaction> for proto::ProvenTransaction {
+    fn from(tx: &ProvenTransaction) -> Self {
+        let account_update = tx.account_update();
+
+        Self {
+            account_id: Some(tx.account_id().into()),
+            initial_account_hash: Some(account_update.init_state_hash().into()),
+            final_account_hash: Some(account_update.final_state_hash().into()),
+            account_update_details: account_update.details().to_bytes(),
+            input_notes: tx.input_notes().iter().map(Into::into).collect(),
+            output_notes: tx.output_notes().iter().map(Into::into).collect(),
+            block_ref: Some(tx.block_ref().into()),
+            proof: tx.proof().to_bytes(),
+            kernel_version: Some(tx.kernel_version().into()),
+        }
+    }
+}
+
+impl TryFrom<proto::ProvenTransaction> for ProvenTransaction {
+    type Error = ProtoConversionError;
+
+    fn try_from(tx: proto::ProvenTransaction) -> Result<Self, Self::Error> {
+        let account_id = required(tx.account_id, "account_id")?.try_into()?;
+        let initial_account_hash = required(tx.initial_account_hash, "initial_account_hash")?;
+        let final_account_hash = required(tx.final_account_hash, "final_account_hash")?;
+        let block_ref = required(tx.block_ref, "block_ref")?;
+        let kernel_version = required(tx.kernel_version, "kernel_version")?.try_into()?;
+
+        let account_update_details =
+            AccountUpdateDetails::read_from_bytes(&tx.account_update_details)
+                .map_err(ProtoConversionError::DeserializationFailed)?;
+        let input_notes = tx
+            .input_notes
+            .into_iter()
+            .map(InputNoteCommitment::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let output_notes = tx
+            .output_notes
+            .into_iter()
+            .map(OutputNote::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let proof = ExecutionProof::read_from_bytes(&tx.proof)
+            .map_err(ProtoConversionError::DeserializationFailed)?;
+
+        ProvenTransactionBuilder::new(
+            account_id,
+            initial_account_hash.try_into()?,
+            final_account_hash.try_into()?,
+            block_ref.try_into()?,
+            kernel_version,
+            proof,
+        )
+        .account_update_details(account_update_details)
+        .add_input_notes(input_notes)
+        .add_output_notes(output_notes)
+        .build()
+        .map_err(ProtoConversionError::InvalidProvenTransaction)
+    }
+}
+
+// BLOCK HEADER
+// ================================================================================================
+
+impl From<BlockHeader> for proto::BlockHeader {
+    fn from(header: BlockHeader) -> Self {
+        Self {
+            version: header.version(),
+            prev_hash: Some(header.prev_hash().into()),
+            block_num: header.block_num(),
+            chain_root: Some(header.chain_root().into()),
+            account_root: Some(header.account_root().into()),
+            nullifier_root: Some(header.nullifier_root().into()),
+            note_root: Some(header.note_root().into()),
+            batch_root: Some(header.batch_root().into()),
+            proof_hash: Some(header.proof_hash().into()),
+            timestamp: header.timestamp(),
+        }
+    }
+}
+
+impl TryFrom<proto::BlockHeader> for BlockHeader {
+    type Error = ProtoConversionError;
+
+    fn try_from(header: proto::BlockHeader) -> Result<Self, Self::Error> {
+        Ok(BlockHeader::new(
+            header.version,
+            required(header.prev_hash, "prev_hash")?.try_into()?,
+            header.block_num,
+            required(header.chain_root, "chain_root")?.try_into()?,
+            required(header.account_root, "account_root")?.try_into()?,
+            required(header.nullifier_root, "nullifier_root")?.try_into()?,
+            required(header.note_root, "note_root")?.try_into()?,
+            required(header.batch_root, "batch_root")?.try_into()?,
+            required(header.proof_hash, "proof_hash")?.try_into()?,
+            header.timestamp,
+        ))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the value of a message field which must be present.
+fn required<T>(field: Option<T>, name: &'static str) -> Result<T, ProtoConversionError> {
+    field.ok_or(ProtoConversionError::MissingField(name))
+}
+
+/// Returns a field element for the provided value, or an error if the value is not a canonical
+/// field element.
+fn felt_from_u64(value: u64) -> Result<Felt, ProtoConversionError> {
+    Felt::try_from(value).map_err(|_| ProtoConversionError::InvalidFieldElement(value))
+}
+
+/// Returns the note assets encoded as the provided list of words.
+fn note_assets_from_proto(assets: Vec<proto::Digest>) -> Result<NoteAssets, ProtoConversionError> {
+    let assets = assets
+        .into_iter()
+        .map(|asset| {
+            Asset::try_from(Word::try_from(asset)?).map_err(ProtoConversionError::InvalidAsset)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    NoteAssets::new(assets).map_err(ProtoConversionError::InvalidNote)
+}
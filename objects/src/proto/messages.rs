@@ -0,0 +1,172 @@
+//! Protobuf messages mirroring the schema defined in `objects/proto/objects.proto`.
+
+use alloc::vec::Vec;
+
+// PRIMITIVES
+// ================================================================================================
+
+/// A word of four field elements, e.g., an RPO256 digest or an encoded asset.
+#[derive(Clone, Copy, PartialEq, Eq, ::prost::Message)]
+pub struct Digest {
+    #[prost(fixed64, tag = "1")]
+    pub d0: u64,
+    #[prost(fixed64, tag = "2")]
+    pub d1: u64,
+    #[prost(fixed64, tag = "3")]
+    pub d2: u64,
+    #[prost(fixed64, tag = "4")]
+    pub d3: u64,
+}
+
+// ACCOUNTS
+// ================================================================================================
+
+#[derive(Clone, Copy, PartialEq, Eq, ::prost::Message)]
+pub struct AccountId {
+    #[prost(fixed64, tag = "1")]
+    pub id: u64,
+}
+
+// NOTES
+// ================================================================================================
+
+#[derive(Clone, Copy, PartialEq, Eq, ::prost::Message)]
+pub struct NoteMetadata {
+    #[prost(message, optional, tag = "1")]
+    pub sender: Option<AccountId>,
+    #[prost(uint32, tag = "2")]
+    pub note_type: u32,
+    #[prost(fixed32, tag = "3")]
+    pub tag: u32,
+    #[prost(fixed64, tag = "4")]
+    pub aux: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+pub struct Note {
+    #[prost(message, optional, tag = "1")]
+    pub metadata: Option<NoteMetadata>,
+    /// Assets of the note, each encoded as a word.
+    #[prost(message, repeated, tag = "2")]
+    pub assets: Vec<Digest>,
+    #[prost(message, optional, tag = "3")]
+    pub serial_num: Option<Digest>,
+    /// Note script serialized using the `miden-objects` binary encoding.
+    #[prost(bytes = "vec", tag = "4")]
+    pub script: Vec<u8>,
+    #[prost(fixed64, repeated, tag = "5")]
+    pub inputs: Vec<u64>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ::prost::Message)]
+pub struct NoteHeader {
+    #[prost(message, optional, tag = "1")]
+    pub note_id: Option<Digest>,
+    #[prost(message, optional, tag = "2")]
+    pub metadata: Option<NoteMetadata>,
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+pub struct PartialNote {
+    #[prost(message, optional, tag = "1")]
+    pub metadata: Option<NoteMetadata>,
+    #[prost(message, optional, tag = "2")]
+    pub recipient: Option<Digest>,
+    /// Assets of the note, each encoded as a word.
+    #[prost(message, repeated, tag = "3")]
+    pub assets: Vec<Digest>,
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+pub struct OutputNote {
+    #[prost(oneof = "output_note::Note", tags = "1, 2, 3")]
+    pub note: Option<output_note::Note>,
+}
+
+/// Nested message and enum types in `OutputNote`.
+pub mod output_note {
+    #[derive(Clone, PartialEq, Eq, ::prost::Oneof)]
+    pub enum Note {
+        #[prost(message, tag = "1")]
+        Full(super::Note),
+        #[prost(message, tag = "2")]
+        Header(super::NoteHeader),
+        #[prost(message, tag = "3")]
+        Partial(super::PartialNote),
+    }
+}
+
+// TRANSACTIONS
+// ================================================================================================
+
+#[derive(Clone, Copy, PartialEq, Eq, ::prost::Message)]
+pub struct InputNoteCommitment {
+    #[prost(message, optional, tag = "1")]
+    pub nullifier: Option<Digest>,
+    /// Header of the note; set only for unauthenticated notes.
+    #[prost(message, optional, tag = "2")]
+    pub header: Option<NoteHeader>,
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+pub struct KernelVersion {
+    #[prost(uint32, tag = "1")]
+    pub major: u32,
+    #[prost(uint32, tag = "2")]
+    pub minor: u32,
+    #[prost(uint32, tag = "3")]
+    pub patch: u32,
+    #[prost(message, optional, tag = "4")]
+    pub root: Option<Digest>,
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+pub struct ProvenTransaction {
+    #[prost(message, optional, tag = "1")]
+    pub account_id: Option<AccountId>,
+    #[prost(message, optional, tag = "2")]
+    pub initial_account_hash: Option<Digest>,
+    #[prost(message, optional, tag = "3")]
+    pub final_account_hash: Option<Digest>,
+    /// Account update details serialized using the `miden-objects` binary encoding.
+    #[prost(bytes = "vec", tag = "4")]
+    pub account_update_details: Vec<u8>,
+    #[prost(message, repeated, tag = "6")]
+    pub output_notes: Vec<OutputNote>,
+    #[prost(message, optional, tag = "7")]
+    pub block_ref: Option<Digest>,
+    /// Execution proof serialized using the `miden-objects` binary encoding.
+    #[prost(bytes = "vec", tag = "8")]
+    pub proof: Vec<u8>,
+    #[prost(message, optional, tag = "9")]
+    pub kernel_version: Option<KernelVersion>,
+    #[prost(message, repeated, tag = "10")]
+    pub input_notes: Vec<InputNoteCommitment>,
+}
+
+// BLOCKS
+// ================================================================================================
+
+#[derive(Clone, Copy, PartialEq, Eq, ::prost::Message)]
+pub struct BlockHeader {
+    #[prost(uint32, tag = "1")]
+    pub version: u32,
+    #[prost(message, optional, tag = "2")]
+    pub prev_hash: Option<Digest>,
+    #[prost(fixed32, tag = "3")]
+    pub block_num: u32,
+    #[prost(message, optional, tag = "4")]
+    pub chain_root: Option<Digest>,
+    #[prost(message, optional, tag = "5")]
+    pub account_root: Option<Digest>,
+    #[prost(message, optional, tag = "6")]
+    pub nullifier_root: Option<Digest>,
+    #[prost(message, optional, tag = "7")]
+    pub note_root: Option<Digest>,
+    #[prost(message, optional, tag = "8")]
+    pub batch_root: Option<Digest>,
+    #[prost(message, optional, tag = "9")]
+    pub proof_hash: Option<Digest>,
+    #[prost(fixed32, tag = "10")]
+    pub timestamp: u32,
+}
@@ -0,0 +1,15 @@
+//! Protobuf representations of the core Miden rollup objects.
+//!
+//! The messages in this module mirror the schema defined in `objects/proto/objects.proto`. Each
+//! message can be converted from the corresponding native type via [From], and back via
+//! [TryFrom], which validates the message contents. Components without a dedicated message (e.g.,
+//! note scripts or execution proofs) are embedded as bytes using the binary encoding of
+//! `miden-objects`.
+
+mod messages;
+pub use messages::*;
+
+mod conversions;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,140 @@
+use alloc::vec;
+
+use assembly::ast::ProgramAst;
+use prost::Message;
+use winter_rand_utils::rand_array;
+
+use crate::{
+    accounts::{
+        account_id::testing::{ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER},
+        AccountId,
+    },
+    assets::FungibleAsset,
+    notes::{
+        Note, NoteAssets, NoteHeader, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag,
+        NoteType, PartialNote,
+    },
+    proto,
+    transaction::{InputNote, InputNoteCommitment, KernelVersion, OutputNote},
+    BlockHeader, Digest, Felt, ProtoConversionError, Word, ZERO,
+};
+
+#[test]
+fn digest_round_trip() {
+    let digest: Digest = rand_array().into();
+    let message = proto::Digest::from(digest);
+    let decoded = proto::Digest::decode(message.encode_to_vec().as_slice()).unwrap();
+
+    assert_eq!(Digest::try_from(decoded).unwrap(), digest);
+}
+
+#[test]
+fn digest_with_non_canonical_element_fails() {
+    let message = proto::Digest { d0: u64::MAX, d1: 0, d2: 0, d3: 0 };
+
+    assert!(matches!(
+        Word::try_from(message),
+        Err(ProtoConversionError::InvalidFieldElement(u64::MAX))
+    ));
+}
+
+#[test]
+fn note_round_trip() {
+    let note = mock_note();
+    let message = proto::Note::from(&note);
+    let decoded = proto::Note::decode(message.encode_to_vec().as_slice()).unwrap();
+
+    assert_eq!(Note::try_from(decoded).unwrap(), note);
+}
+
+#[test]
+fn output_note_round_trip() {
+    let note = mock_note();
+    let header = NoteHeader::from(&note);
+    let partial =
+        PartialNote::new(*note.metadata(), note.recipient().digest(), note.assets().clone());
+
+    for output_note in
+        [OutputNote::Full(note), OutputNote::Partial(partial), OutputNote::Header(header)]
+    {
+        let message = proto::OutputNote::from(&output_note);
+        let decoded = proto::OutputNote::decode(message.encode_to_vec().as_slice()).unwrap();
+
+        assert_eq!(OutputNote::try_from(decoded).unwrap(), output_note);
+    }
+}
+
+#[test]
+fn input_note_commitment_round_trip() {
+    let note = mock_note();
+    let unauthenticated = InputNoteCommitment::from(InputNote::unauthenticated(note.clone()));
+    assert_eq!(unauthenticated.header(), Some(note.header()));
+
+    for input_note in [InputNoteCommitment::from(note.nullifier()), unauthenticated] {
+        let message = proto::InputNoteCommitment::from(&input_note);
+        let decoded =
+            proto::InputNoteCommitment::decode(message.encode_to_vec().as_slice()).unwrap();
+
+        assert_eq!(InputNoteCommitment::try_from(decoded).unwrap(), input_note);
+    }
+}
+
+#[test]
+fn note_metadata_with_missing_sender_fails() {
+    let note = mock_note();
+    let mut message = proto::NoteMetadata::from(*note.metadata());
+    message.sender = None;
+
+    assert!(matches!(
+        NoteMetadata::try_from(message),
+        Err(ProtoConversionError::MissingField("sender"))
+    ));
+}
+
+#[test]
+fn block_header_round_trip() {
+    let header = BlockHeader::mock(7, Some(rand_array().into()), Some(rand_array().into()), &[]);
+    let message = proto::BlockHeader::from(header);
+    let decoded = proto::BlockHeader::decode(message.encode_to_vec().as_slice()).unwrap();
+
+    assert_eq!(BlockHeader::try_from(decoded).unwrap(), header);
+}
+
+#[test]
+fn kernel_version_round_trip() {
+    let version = KernelVersion::new(0, 4, 1, rand_array().into());
+    let message = proto::KernelVersion::from(version);
+    let decoded = proto::KernelVersion::decode(message.encode_to_vec().as_slice()).unwrap();
+
+    assert_eq!(KernelVersion::try_from(decoded).unwrap(), version);
+}
+
+#[test]
+fn kernel_version_out_of_range_fails() {
+    let mut message = proto::KernelVersion::from(KernelVersion::new(0, 4, 1, Digest::default()));
+    message.minor = 256;
+
+    assert!(matches!(
+        KernelVersion::try_from(message),
+        Err(ProtoConversionError::InvalidKernelVersion(256))
+    ));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn mock_note() -> Note {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset = FungibleAsset::new(faucet_id, 100).unwrap();
+
+    let code = ProgramAst::parse("begin push.1 drop end").unwrap();
+    let script = NoteScript::from_parts(code, rand_array().into());
+    let inputs = NoteInputs::new(vec![Felt::new(1), Felt::new(2), Felt::new(3)]).unwrap();
+    let recipient = NoteRecipient::new(rand_array(), script, inputs);
+
+    let metadata =
+        NoteMetadata::new(sender, NoteType::Public, NoteTag::from(0x1234_5678), ZERO).unwrap();
+
+    Note::new(NoteAssets::new(vec![asset.into()]).unwrap(), metadata, recipient)
+}
@@ -0,0 +1,83 @@
+//! Size accounting for serialized objects.
+
+use crate::{
+    accounts::{delta::AccountUpdateDetails, Account, AccountDelta},
+    notes::{Note, NoteDetails},
+    transaction::{OutputNote, ProvenTransaction, TxAccountUpdate},
+    utils::serde::{ByteWriter, Serializable},
+};
+
+// SIZE HINT
+// ================================================================================================
+
+/// Defines the size of an object in its serialized form.
+///
+/// The size is computed by serializing the object into a writer which only counts the written
+/// bytes. Thus, it is exact and deterministic, but computing it requires traversing the whole
+/// object.
+pub trait SizeHint {
+    /// Returns the number of bytes in the serialized representation of this object.
+    fn size_hint(&self) -> usize;
+}
+
+macro_rules! impl_size_hint {
+    ($($ty:ty),*) => {
+        $(
+            impl SizeHint for $ty {
+                fn size_hint(&self) -> usize {
+                    serialized_size(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_size_hint!(
+    Account,
+    AccountDelta,
+    AccountUpdateDetails,
+    Note,
+    NoteDetails,
+    OutputNote,
+    ProvenTransaction,
+    TxAccountUpdate
+);
+
+// HELPERS
+// ================================================================================================
+
+/// A [ByteWriter] which discards the written bytes and only counts them.
+struct ByteCounter(usize);
+
+impl ByteWriter for ByteCounter {
+    fn write_u8(&mut self, _value: u8) {
+        self.0 += 1;
+    }
+
+    fn write_bytes(&mut self, values: &[u8]) {
+        self.0 += values.len();
+    }
+}
+
+/// Returns the number of bytes in the serialized representation of the provided object.
+fn serialized_size<T: Serializable>(object: &T) -> usize {
+    let mut counter = ByteCounter(0);
+    object.write_into(&mut counter);
+    counter.0
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::SizeHint;
+    use crate::{accounts::testing::build_account, utils::serde::Serializable, Felt, ONE, ZERO};
+
+    #[test]
+    fn size_hint_matches_serialized_size() {
+        let storage_items = (0..10).map(|i| [Felt::new(i), ONE, ZERO, ZERO]).collect();
+        let account = build_account(vec![], ONE, storage_items, None);
+        assert_eq!(account.size_hint(), account.to_bytes().len());
+    }
+}
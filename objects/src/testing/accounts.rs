@@ -0,0 +1,290 @@
+//! Ready-made accounts for tests.
+//!
+//! All accounts use the code returned by [make_account_code()], and thus can be used in tests of
+//! the data structures of the protocol, but cannot be executed by the transaction kernel.
+
+use alloc::vec::Vec;
+
+use crate::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2,
+        },
+        code::testing::make_account_code,
+        Account, AccountId, AccountStorage, SlotItem, StorageMap, StorageSlot,
+    },
+    assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    Digest, Felt, Word, ONE, ZERO,
+};
+
+// ASSET CONSTANTS
+// ================================================================================================
+
+pub const FUNGIBLE_ASSET_AMOUNT: u64 = 100;
+pub const FUNGIBLE_FAUCET_INITIAL_BALANCE: u64 = 50000;
+
+pub const NON_FUNGIBLE_ASSET_DATA: [u8; 4] = [1, 2, 3, 4];
+pub const NON_FUNGIBLE_ASSET_DATA_2: [u8; 4] = [5, 6, 7, 8];
+
+// STORAGE CONSTANTS
+// ================================================================================================
+
+/// The storage slot in which faucets keep their data: the total issuance for fungible faucets, and
+/// the root of the map of issued assets for non-fungible faucets.
+///
+/// Must match the slot reserved by the transaction kernel.
+pub const FAUCET_STORAGE_DATA_SLOT: u8 = AccountStorage::FAUCET_DATA_SLOT_INDEX;
+
+/// The storage slot in which basic wallets keep their public key.
+pub const WALLET_PUB_KEY_SLOT: u8 = 0;
+pub const WALLET_PUB_KEY: Word = [Felt::new(11), Felt::new(12), Felt::new(13), Felt::new(14)];
+
+pub const STORAGE_INDEX_0: u8 = 20;
+pub const STORAGE_VALUE_0: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+pub const STORAGE_INDEX_1: u8 = 30;
+pub const STORAGE_VALUE_1: Word = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+
+pub const STORAGE_INDEX_2: u8 = 40;
+pub const STORAGE_LEAVES_2: [(Digest, Word); 2] = [
+    (
+        Digest::new([Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)]),
+        [Felt::new(1_u64), Felt::new(2_u64), Felt::new(3_u64), Felt::new(4_u64)],
+    ),
+    (
+        Digest::new([Felt::new(105), Felt::new(106), Felt::new(107), Felt::new(108)]),
+        [Felt::new(5_u64), Felt::new(6_u64), Felt::new(7_u64), Felt::new(8_u64)],
+    ),
+];
+
+// ASSETS
+// ================================================================================================
+
+/// Returns the non-fungible asset with [NON_FUNGIBLE_ASSET_DATA] issued by the specified faucet.
+pub fn non_fungible_asset(faucet_id: u64) -> Asset {
+    build_non_fungible_asset(faucet_id, NON_FUNGIBLE_ASSET_DATA.to_vec())
+}
+
+/// Returns the non-fungible asset with [NON_FUNGIBLE_ASSET_DATA_2] issued by the specified faucet.
+pub fn non_fungible_asset_2(faucet_id: u64) -> Asset {
+    build_non_fungible_asset(faucet_id, NON_FUNGIBLE_ASSET_DATA_2.to_vec())
+}
+
+/// Returns a vault with [FUNGIBLE_ASSET_AMOUNT] of the assets of three fungible faucets, and the
+/// non-fungible asset returned by [non_fungible_asset()].
+///
+/// The assets are issued by the faucets with the following IDs:
+/// - ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN
+/// - ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1
+/// - ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2
+/// - ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN
+pub fn account_vault() -> AssetVault {
+    let mut assets: Vec<Asset> = [
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+    ]
+    .into_iter()
+    .map(|faucet_id| fungible_asset(faucet_id, FUNGIBLE_ASSET_AMOUNT))
+    .collect();
+    assets.push(non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN));
+
+    AssetVault::new(&assets).unwrap()
+}
+
+// STORAGE
+// ================================================================================================
+
+pub fn storage_item_0() -> SlotItem {
+    SlotItem {
+        index: STORAGE_INDEX_0,
+        slot: StorageSlot::new_value(STORAGE_VALUE_0),
+    }
+}
+
+pub fn storage_item_1() -> SlotItem {
+    SlotItem {
+        index: STORAGE_INDEX_1,
+        slot: StorageSlot::new_value(STORAGE_VALUE_1),
+    }
+}
+
+pub fn storage_map_2() -> StorageMap {
+    StorageMap::with_entries(STORAGE_LEAVES_2).unwrap()
+}
+
+pub fn storage_item_2() -> SlotItem {
+    SlotItem {
+        index: STORAGE_INDEX_2,
+        slot: StorageSlot::new_map(Word::from(storage_map_2().root())),
+    }
+}
+
+/// Returns a storage with two value slots and a map slot, as defined by the `STORAGE_*`
+/// constants.
+pub fn account_storage() -> AccountStorage {
+    AccountStorage::new(
+        vec![storage_item_0(), storage_item_1(), storage_item_2()],
+        vec![storage_map_2()],
+    )
+    .unwrap()
+}
+
+// ACCOUNTS
+// ================================================================================================
+
+/// Returns an on-chain basic wallet holding [FUNGIBLE_ASSET_AMOUNT] of the asset of the fungible
+/// faucet with ID `ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN`.
+pub fn basic_wallet_on_chain() -> Account {
+    build_basic_wallet(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN)
+}
+
+/// Returns an off-chain basic wallet holding [FUNGIBLE_ASSET_AMOUNT] of the asset of the fungible
+/// faucet with ID `ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN`.
+pub fn basic_wallet_off_chain() -> Account {
+    build_basic_wallet(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN)
+}
+
+/// Returns an on-chain fungible faucet which has issued the specified amount of its asset.
+pub fn fungible_faucet(issuance: u64) -> Account {
+    let storage = AccountStorage::new(
+        vec![SlotItem {
+            index: FAUCET_STORAGE_DATA_SLOT,
+            slot: StorageSlot::new_value([ZERO, ZERO, ZERO, Felt::new(issuance)]),
+        }],
+        vec![],
+    )
+    .unwrap();
+
+    build_account(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, AssetVault::default(), storage)
+}
+
+/// Returns an on-chain non-fungible faucet which has issued the asset returned by
+/// [non_fungible_asset_2()].
+pub fn non_fungible_faucet() -> Account {
+    let asset = non_fungible_asset_2(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN);
+    let issued_assets =
+        StorageMap::with_entries([(Word::from(asset).into(), asset.into())]).unwrap();
+    let storage = AccountStorage::new(
+        vec![SlotItem {
+            index: FAUCET_STORAGE_DATA_SLOT,
+            slot: StorageSlot::new_map(*issued_assets.root()),
+        }],
+        vec![issued_assets],
+    )
+    .unwrap();
+
+    build_account(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, AssetVault::default(), storage)
+}
+
+/// Returns an on-chain account with the storage returned by [account_storage()] and the vault
+/// returned by [account_vault()].
+pub fn account_with_storage_map() -> Account {
+    build_account(
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        account_vault(),
+        account_storage(),
+    )
+}
+
+/// Returns an on-chain account holding the assets of [account_vault()] together with the
+/// specified number of additional non-fungible assets.
+pub fn account_with_large_vault(num_non_fungible_assets: u32) -> Account {
+    let mut assets: Vec<Asset> = account_vault().assets().collect();
+    assets.extend((0..num_non_fungible_assets).map(|i| {
+        build_non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, i.to_le_bytes().to_vec())
+    }));
+
+    build_account(
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2,
+        AssetVault::new(&assets).unwrap(),
+        AccountStorage::new(vec![], vec![]).unwrap(),
+    )
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_account(account_id: u64, vault: AssetVault, storage: AccountStorage) -> Account {
+    let account_id = AccountId::try_from(account_id).unwrap();
+    Account::new(account_id, vault, storage, make_account_code(), ONE)
+}
+
+fn build_basic_wallet(account_id: u64) -> Account {
+    let vault = AssetVault::new(&[fungible_asset(
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        FUNGIBLE_ASSET_AMOUNT,
+    )])
+    .unwrap();
+    let storage = AccountStorage::new(
+        vec![SlotItem {
+            index: WALLET_PUB_KEY_SLOT,
+            slot: StorageSlot::new_value(WALLET_PUB_KEY),
+        }],
+        vec![],
+    )
+    .unwrap();
+
+    build_account(account_id, vault, storage)
+}
+
+fn fungible_asset(faucet_id: u64, amount: u64) -> Asset {
+    let faucet_id = AccountId::try_from(faucet_id).unwrap();
+    FungibleAsset::new(faucet_id, amount).unwrap().into()
+}
+
+fn build_non_fungible_asset(faucet_id: u64, data: Vec<u8>) -> Asset {
+    let faucet_id = AccountId::try_from(faucet_id).unwrap();
+    let details = NonFungibleAssetDetails::new(faucet_id, data).unwrap();
+    NonFungibleAsset::new(&details).unwrap().into()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::AccountType,
+        utils::serde::{Deserializable, Serializable},
+    };
+
+    #[test]
+    fn account_fixtures() {
+        let wallet = basic_wallet_on_chain();
+        assert!(wallet.id().is_on_chain());
+        assert_eq!(wallet.vault().assets().count(), 1);
+        assert_eq!(wallet.storage().get_item(WALLET_PUB_KEY_SLOT), WALLET_PUB_KEY.into());
+        assert!(!basic_wallet_off_chain().id().is_on_chain());
+
+        let faucet = fungible_faucet(FUNGIBLE_FAUCET_INITIAL_BALANCE);
+        assert_eq!(faucet.id().account_type(), AccountType::FungibleFaucet);
+        assert_eq!(
+            faucet.storage().get_item(FAUCET_STORAGE_DATA_SLOT),
+            [ZERO, ZERO, ZERO, Felt::new(FUNGIBLE_FAUCET_INITIAL_BALANCE)].into()
+        );
+
+        let faucet = non_fungible_faucet();
+        assert_eq!(faucet.id().account_type(), AccountType::NonFungibleFaucet);
+        assert_eq!(
+            faucet.storage().get_item(FAUCET_STORAGE_DATA_SLOT),
+            faucet.storage().maps()[0].root()
+        );
+
+        let account = account_with_storage_map();
+        assert_eq!(account.storage().get_item(STORAGE_INDEX_2), storage_map_2().root());
+        assert_eq!(account.vault().assets().count(), 4);
+
+        let account = account_with_large_vault(100);
+        assert_eq!(account.vault().assets().count(), 104);
+
+        for account in [wallet, faucet, account] {
+            assert_eq!(Account::read_from_bytes(&account.to_bytes()).unwrap(), account);
+        }
+    }
+}
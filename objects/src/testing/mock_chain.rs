@@ -0,0 +1,513 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::fmt;
+
+use crate::{
+    accounts::{Account, AccountId},
+    block::{AccountTree, BlockNoteIndex, BlockNoteTree, NoteBatch, NullifierTree},
+    crypto::merkle::{Mmr, PartialMmr},
+    notes::{Note, NoteId, Nullifier},
+    transaction::{
+        ChainMmr, ExecutedTransaction, InputNote, InputNotes, OutputNote, TransactionInputs,
+    },
+    AccountError, BlockHeader, Digest, TransactionInputError, Word, MAX_NOTES_PER_BATCH,
+};
+
+/// Timestamp of the genesis block.
+const TIMESTAMP_START: u32 = 1693348223;
+
+/// Number of seconds between two consecutive blocks.
+const TIMESTAMP_STEP: u32 = 10;
+
+// MOCK CHAIN
+// ================================================================================================
+
+/// An in-memory chain which can be used to set up multi-block, multi-transaction test scenarios.
+///
+/// The chain maintains the account tree, the nullifier tree, the note trees of all blocks, and
+/// the chain MMR. Accounts, notes, and nullifiers added to the chain are kept in a pending state
+/// until the next call to [MockChain::seal_block()], at which point they are committed to a new
+/// block.
+///
+/// Once a block has been sealed, [MockChain::get_transaction_inputs()] can be used to build the
+/// inputs of a transaction consuming notes created in any of the sealed blocks, and
+/// [MockChain::apply_executed_transaction()] can be used to record the effects of an executed
+/// transaction in the pending block.
+#[derive(Debug, Clone)]
+pub struct MockChain {
+    /// History of all blocks in the chain; the chain root of each block commits to all blocks
+    /// preceding it.
+    chain: Mmr,
+
+    /// Headers of all sealed blocks.
+    blocks: Vec<BlockHeader>,
+
+    /// Tree of the nullifiers of all consumed notes, mapping each nullifier to the number of the
+    /// block in which it was consumed.
+    nullifiers: NullifierTree,
+
+    /// Tree of the latest state hashes of all existing accounts.
+    account_tree: AccountTree,
+
+    /// Latest states of all committed accounts together with their seeds; the seed is set only
+    /// for accounts which have not been updated by a transaction yet.
+    accounts: BTreeMap<AccountId, (Account, Option<Word>)>,
+
+    /// Notes committed to a sealed block, together with their inclusion proofs.
+    notes: BTreeMap<NoteId, InputNote>,
+
+    /// Accounts waiting to be committed to the next block.
+    pending_accounts: BTreeMap<AccountId, (Account, Option<Word>)>,
+
+    /// Notes waiting to be committed to the next block.
+    pending_notes: Vec<Note>,
+
+    /// Nullifiers waiting to be committed to the next block.
+    pending_nullifiers: Vec<Nullifier>,
+}
+
+impl MockChain {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [MockChain] without any blocks.
+    pub fn new() -> Self {
+        Self {
+            chain: Mmr::default(),
+            blocks: Vec::new(),
+            nullifiers: NullifierTree::new(),
+            account_tree: AccountTree::new(),
+            accounts: BTreeMap::new(),
+            notes: BTreeMap::new(),
+            pending_accounts: BTreeMap::new(),
+            pending_notes: Vec::new(),
+            pending_nullifiers: Vec::new(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of sealed blocks in the chain.
+    pub fn num_blocks(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Returns the header of the block with the specified number, if the block has been sealed.
+    pub fn block_header(&self, block_num: u32) -> Option<&BlockHeader> {
+        self.blocks.get(block_num as usize)
+    }
+
+    /// Returns the header of the latest sealed block, if any.
+    pub fn latest_block_header(&self) -> Option<&BlockHeader> {
+        self.blocks.last()
+    }
+
+    /// Returns the latest committed state of the account with the specified ID.
+    pub fn account(&self, account_id: AccountId) -> Option<&Account> {
+        self.accounts.get(&account_id).map(|(account, _)| account)
+    }
+
+    /// Returns the seed of the account with the specified ID, if the account has been committed
+    /// and has not been updated by a transaction yet.
+    pub fn account_seed(&self, account_id: AccountId) -> Option<Word> {
+        self.accounts.get(&account_id).and_then(|(_, seed)| *seed)
+    }
+
+    /// Returns the note with the specified ID together with its inclusion proof, if the note has
+    /// been committed to a sealed block.
+    pub fn note(&self, note_id: NoteId) -> Option<&InputNote> {
+        self.notes.get(&note_id)
+    }
+
+    /// Returns true if the specified nullifier has been committed to a sealed block.
+    pub fn is_nullifier_consumed(&self, nullifier: Nullifier) -> bool {
+        self.nullifiers.contains(nullifier)
+    }
+
+    /// Returns the nullifier tree of the chain.
+    pub fn nullifiers(&self) -> &NullifierTree {
+        &self.nullifiers
+    }
+
+    /// Returns the account tree of the chain.
+    pub fn account_tree(&self) -> &AccountTree {
+        &self.account_tree
+    }
+
+    /// Returns a [ChainMmr] for a transaction executed against the specified reference block,
+    /// tracking authentication paths for the specified blocks.
+    ///
+    /// # Panics
+    /// Panics if the reference block has not been sealed or if any of the tracked blocks is not
+    /// older than the reference block.
+    pub fn chain_mmr(&self, block_ref: u32, tracked_blocks: &[u32]) -> ChainMmr {
+        let forest = block_ref as usize;
+        assert!(forest < self.blocks.len(), "block {block_ref} has not been sealed");
+
+        let peaks = self.chain.peaks(forest).expect("forest is within the MMR");
+        let mut partial_mmr = PartialMmr::from_peaks(peaks);
+        let mut blocks = Vec::with_capacity(tracked_blocks.len());
+        for &block_num in tracked_blocks {
+            let pos = block_num as usize;
+            let proof = self.chain.open(pos, forest).expect("block is older than the reference");
+            partial_mmr
+                .track(pos, self.blocks[pos].hash(), &proof.merkle_path)
+                .expect("path was obtained from the MMR");
+            blocks.push(self.blocks[pos]);
+        }
+
+        ChainMmr::new(partial_mmr, blocks).expect("all blocks are tracked")
+    }
+
+    /// Returns [TransactionInputs] for a transaction executed by the specified account against
+    /// the specified reference block and consuming the specified notes.
+    ///
+    /// The latest committed state of the account is used; for accounts which have not been
+    /// updated by a transaction yet, the account seed is included in the inputs.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The reference block has not been sealed.
+    /// - The account has not been committed to a sealed block.
+    /// - Any of the notes has not been committed to a block at or before the reference block.
+    /// - Any of the notes has already been consumed.
+    /// - The resulting inputs are invalid.
+    pub fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        note_ids: &[NoteId],
+    ) -> Result<TransactionInputs, MockChainError> {
+        let block_header =
+            *self.block_header(block_ref).ok_or(MockChainError::BlockNotFound(block_ref))?;
+        let (account, account_seed) = self
+            .accounts
+            .get(&account_id)
+            .cloned()
+            .ok_or(MockChainError::AccountNotFound(account_id))?;
+
+        let mut input_notes = Vec::with_capacity(note_ids.len());
+        let mut tracked_blocks = Vec::new();
+        for &note_id in note_ids {
+            let note = self.notes.get(&note_id).ok_or(MockChainError::NoteNotFound(note_id))?;
+            let note_block_num =
+                note.origin().expect("notes in the chain are authenticated").block_num;
+            if note_block_num > block_ref {
+                return Err(MockChainError::NoteNotFound(note_id));
+            }
+            if self.is_nullifier_consumed(note.note().nullifier()) {
+                return Err(MockChainError::NoteAlreadyConsumed(note_id));
+            }
+
+            if note_block_num != block_ref && !tracked_blocks.contains(&note_block_num) {
+                tracked_blocks.push(note_block_num);
+            }
+            input_notes.push(note.clone());
+        }
+
+        let input_notes = InputNotes::new(input_notes).map_err(MockChainError::InvalidInputs)?;
+        let block_chain = self.chain_mmr(block_ref, &tracked_blocks);
+
+        TransactionInputs::new(account, account_seed, block_header, block_chain, input_notes)
+            .map_err(MockChainError::InvalidInputs)
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the provided account to the pending block.
+    ///
+    /// The seed must be provided for new accounts. If an account with the same ID already exists
+    /// in the chain, its state is replaced once the pending block is sealed.
+    pub fn add_account(&mut self, account: Account, seed: Option<Word>) {
+        self.pending_accounts.insert(account.id(), (account, seed));
+    }
+
+    /// Adds the provided note to the pending block.
+    ///
+    /// # Errors
+    /// Returns an error if the note has already been added to the chain.
+    pub fn add_note(&mut self, note: Note) -> Result<(), MockChainError> {
+        let note_id = note.id();
+        if self.notes.contains_key(&note_id)
+            || self.pending_notes.iter().any(|pending| pending.id() == note_id)
+        {
+            return Err(MockChainError::DuplicateNote(note_id));
+        }
+
+        self.pending_notes.push(note);
+        Ok(())
+    }
+
+    /// Adds the provided nullifier to the pending block, marking the corresponding note as
+    /// consumed.
+    ///
+    /// # Errors
+    /// Returns an error if the nullifier has already been added to the chain.
+    pub fn add_nullifier(&mut self, nullifier: Nullifier) -> Result<(), MockChainError> {
+        if self.is_nullifier_consumed(nullifier) || self.pending_nullifiers.contains(&nullifier) {
+            return Err(MockChainError::DuplicateNullifier(nullifier));
+        }
+
+        self.pending_nullifiers.push(nullifier);
+        Ok(())
+    }
+
+    /// Records the effects of the provided transaction in the pending block.
+    ///
+    /// The account delta is applied to the latest committed state of the account, the nullifiers
+    /// of the consumed notes are added to the pending block, and so are all output notes whose
+    /// details are known.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account has not been committed to a sealed block.
+    /// - The account delta cannot be applied to the account.
+    /// - Any of the consumed notes has already been consumed, or any of the output notes has
+    ///   already been added to the chain.
+    pub fn apply_executed_transaction(
+        &mut self,
+        transaction: &ExecutedTransaction,
+    ) -> Result<(), MockChainError> {
+        let account_id = transaction.account_id();
+        let (mut account, _) = self
+            .pending_accounts
+            .get(&account_id)
+            .or_else(|| self.accounts.get(&account_id))
+            .cloned()
+            .ok_or(MockChainError::AccountNotFound(account_id))?;
+        account
+            .apply_delta(transaction.account_delta())
+            .map_err(MockChainError::InvalidAccountDelta)?;
+
+        for note in transaction.input_notes().iter() {
+            self.add_nullifier(note.note().nullifier())?;
+        }
+        for note in transaction.output_notes().iter() {
+            if let OutputNote::Full(note) = note {
+                self.add_note(note.clone())?;
+            }
+        }
+        self.add_account(account, None);
+
+        Ok(())
+    }
+
+    /// Commits all pending accounts, notes, and nullifiers to a new block, and returns the header
+    /// of this block.
+    pub fn seal_block(&mut self) -> BlockHeader {
+        let block_num = self.blocks.len() as u32;
+
+        // update the account tree; new accounts are added to the tree only once they have been
+        // updated by a transaction
+        for (account_id, (account, seed)) in core::mem::take(&mut self.pending_accounts) {
+            if !account.is_new() {
+                self.account_tree.insert(account_id, account.hash());
+            }
+            self.accounts.insert(account_id, (account, seed));
+        }
+
+        // update the nullifier tree
+        for nullifier in self.pending_nullifiers.drain(..) {
+            self.nullifiers
+                .insert(nullifier, block_num)
+                .expect("nullifiers are unique and are not consumed in the genesis block");
+        }
+
+        // build the note tree of the block
+        let pending_notes = core::mem::take(&mut self.pending_notes);
+        let note_batches = pending_notes
+            .chunks(MAX_NOTES_PER_BATCH)
+            .map(|batch| batch.iter().cloned().map(OutputNote::Full).collect())
+            .collect::<Vec<NoteBatch>>();
+        let note_tree =
+            BlockNoteTree::from_note_batches(&note_batches).expect("note tree capacity exceeded");
+
+        let prev_block = self.blocks.last();
+        let header = BlockHeader::new(
+            0,
+            prev_block.map_or(Digest::default(), |header| header.hash()),
+            block_num,
+            self.chain.peaks(self.chain.forest()).expect("forest is valid").hash_peaks(),
+            self.account_tree.root(),
+            self.nullifiers.root(),
+            note_tree.root(),
+            Digest::default(),
+            Digest::default(),
+            prev_block.map_or(TIMESTAMP_START, |header| header.timestamp() + TIMESTAMP_STEP),
+        );
+
+        // record the inclusion proofs of the notes created in the block
+        for (batch_idx, batch) in pending_notes.chunks(MAX_NOTES_PER_BATCH).enumerate() {
+            for (note_idx_in_batch, note) in batch.iter().enumerate() {
+                let index = BlockNoteIndex::new(batch_idx, note_idx_in_batch);
+                let proof = note_tree
+                    .get_note_inclusion_proof(&header, index)
+                    .expect("note is in the tree");
+                self.notes.insert(note.id(), InputNote::authenticated(note.clone(), proof));
+            }
+        }
+
+        self.chain.add(header.hash());
+        self.blocks.push(header);
+
+        header
+    }
+}
+
+impl Default for MockChain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// MOCK CHAIN ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MockChainError {
+    AccountNotFound(AccountId),
+    BlockNotFound(u32),
+    DuplicateNote(NoteId),
+    DuplicateNullifier(Nullifier),
+    InvalidAccountDelta(AccountError),
+    InvalidInputs(TransactionInputError),
+    NoteAlreadyConsumed(NoteId),
+    NoteNotFound(NoteId),
+}
+
+impl fmt::Display for MockChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MockChainError {}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use assembly::ast::ProgramAst;
+
+    use super::{MockChain, MockChainError};
+    use crate::{
+        accounts::{
+            account_id::testing::{ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER},
+            testing::build_account,
+            AccountId,
+        },
+        assets::FungibleAsset,
+        notes::{
+            Note, NoteAssets, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag,
+            NoteType,
+        },
+        Digest, Felt, ONE, ZERO,
+    };
+
+    #[test]
+    fn test_notes_across_blocks() {
+        let account = build_account(Vec::new(), ONE, Vec::new(), None);
+        let account_id = account.id();
+        let note_0 = build_note(0);
+        let note_1 = build_note(1);
+
+        let mut chain = MockChain::new();
+        chain.add_account(account.clone(), None);
+        chain.add_note(note_0.clone()).unwrap();
+        let block_0 = chain.seal_block();
+        chain.add_note(note_1.clone()).unwrap();
+        let block_1 = chain.seal_block();
+        let block_2 = chain.seal_block();
+
+        // block headers are chained together
+        assert_eq!(block_0.prev_hash(), Digest::default());
+        assert_eq!(block_1.prev_hash(), block_0.hash());
+        assert_eq!(block_2.prev_hash(), block_1.hash());
+        assert_eq!(block_2.block_num(), 2);
+        assert!(block_2.timestamp() > block_1.timestamp());
+        assert_eq!(block_0.account_root(), chain.account_tree().root());
+
+        // notes created in older blocks are authenticated via the chain MMR
+        let tx_inputs = chain
+            .get_transaction_inputs(account_id, 2, &[note_0.id(), note_1.id()])
+            .unwrap();
+        assert_eq!(tx_inputs.account(), &account);
+        assert_eq!(tx_inputs.block_header(), &block_2);
+        assert_eq!(tx_inputs.block_chain().chain_length(), 2);
+        assert!(tx_inputs.block_chain().contains_block(0));
+        assert!(tx_inputs.block_chain().contains_block(1));
+        assert_eq!(tx_inputs.input_notes().num_notes(), 2);
+
+        // notes created in the reference block are authenticated via the block header
+        let tx_inputs = chain.get_transaction_inputs(account_id, 1, &[note_1.id()]).unwrap();
+        assert!(!tx_inputs.block_chain().contains_block(1));
+
+        // notes created after the reference block are not available
+        assert_eq!(
+            chain.get_transaction_inputs(account_id, 0, &[note_1.id()]),
+            Err(MockChainError::NoteNotFound(note_1.id()))
+        );
+        assert_eq!(
+            chain.get_transaction_inputs(account_id, 3, &[]),
+            Err(MockChainError::BlockNotFound(3))
+        );
+    }
+
+    #[test]
+    fn test_pending_objects_and_nullifiers() {
+        let account = build_account(Vec::new(), ONE, Vec::new(), None);
+        let account_id = account.id();
+        let note = build_note(0);
+
+        let mut chain = MockChain::new();
+        chain.add_account(account, None);
+        chain.add_note(note.clone()).unwrap();
+        assert_eq!(chain.add_note(note.clone()), Err(MockChainError::DuplicateNote(note.id())));
+
+        // pending objects are not available until the block is sealed
+        assert!(chain.account(account_id).is_none());
+        assert!(chain.note(note.id()).is_none());
+        chain.seal_block();
+        assert!(chain.account(account_id).is_some());
+        assert!(chain.note(note.id()).is_some());
+        assert_eq!(chain.add_note(note.clone()), Err(MockChainError::DuplicateNote(note.id())));
+
+        // consumed notes cannot be used as transaction inputs
+        chain.add_nullifier(note.nullifier()).unwrap();
+        let block = chain.seal_block();
+        assert!(chain.is_nullifier_consumed(note.nullifier()));
+        assert_eq!(block.nullifier_root(), chain.nullifiers().root());
+        assert_eq!(
+            chain.add_nullifier(note.nullifier()),
+            Err(MockChainError::DuplicateNullifier(note.nullifier()))
+        );
+        assert_eq!(
+            chain.get_transaction_inputs(account_id, 1, &[note.id()]),
+            Err(MockChainError::NoteAlreadyConsumed(note.id()))
+        );
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn build_note(serial_num: u64) -> Note {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset = FungibleAsset::new(faucet_id, 100).unwrap();
+
+        let code = ProgramAst::parse("begin push.1 drop end").unwrap();
+        let script = NoteScript::from_parts(code, Digest::default());
+        let serial_num = [Felt::new(serial_num), ZERO, ZERO, ZERO];
+        let recipient =
+            NoteRecipient::new(serial_num, script, NoteInputs::new(Vec::new()).unwrap());
+        let metadata =
+            NoteMetadata::new(sender, NoteType::Public, NoteTag::from(0x1234_5678), ZERO).unwrap();
+
+        Note::new(NoteAssets::new(vec![asset.into()]).unwrap(), metadata, recipient)
+    }
+}
@@ -0,0 +1,14 @@
+//! Utilities for testing code which depends on the state of the chain.
+
+pub mod accounts;
+
+mod mock_chain;
+pub use mock_chain::{MockChain, MockChainError};
+
+mod rng;
+pub use rng::DeterministicRng;
+
+#[cfg(feature = "testing")]
+pub mod strategies;
+
+pub mod test_vectors;
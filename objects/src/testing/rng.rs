@@ -0,0 +1,103 @@
+use miden_crypto::rand::{FeltRng, RpoRandomCoin};
+use rand::{Error, RngCore, SeedableRng};
+
+use crate::{Felt, Word};
+
+// DETERMINISTIC RNG
+// ================================================================================================
+
+/// A seedable [FeltRng] which produces the same sequence of values on every run and on every
+/// platform.
+///
+/// The generator is backed by an [RpoRandomCoin], and thus draws all values, including raw bytes,
+/// from field elements computed via the RPO permutation. It can be used wherever tests or
+/// benchmarks require randomness, e.g., for creating notes, grinding account seeds, generating
+/// keys, or signing transactions with a
+/// [BasicAuthenticator](https://docs.rs/miden-tx/latest/miden_tx/host/struct.BasicAuthenticator.html).
+///
+/// The generator must never be used to generate secrets outside of tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeterministicRng(RpoRandomCoin);
+
+impl DeterministicRng {
+    /// Returns a new [DeterministicRng] seeded with the specified word.
+    pub fn new(seed: Word) -> Self {
+        Self(RpoRandomCoin::new(seed))
+    }
+}
+
+impl Default for DeterministicRng {
+    fn default() -> Self {
+        Self::seed_from_u64(0)
+    }
+}
+
+impl SeedableRng for DeterministicRng {
+    type Seed = [u8; 32];
+
+    /// Returns a new [DeterministicRng] seeded with the specified bytes; every 8 bytes of the seed
+    /// are interpreted as a little-endian integer, reduced modulo the field modulus.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut word = [Felt::new(0); 4];
+        for (element, bytes) in word.iter_mut().zip(seed.chunks_exact(8)) {
+            *element = Felt::new(u64::from_le_bytes(bytes.try_into().expect("chunk has 8 bytes")));
+        }
+        Self::new(word)
+    }
+}
+
+impl RngCore for DeterministicRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+impl FeltRng for DeterministicRng {
+    fn draw_element(&mut self) -> Felt {
+        self.0.draw_element()
+    }
+
+    fn draw_word(&mut self) -> Word {
+        self.0.draw_word()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic_rng_is_reproducible() {
+        let mut rng = DeterministicRng::seed_from_u64(42);
+        let mut other = DeterministicRng::seed_from_u64(42);
+        assert_eq!(rng.draw_word(), other.draw_word());
+        assert_eq!(rng.next_u64(), other.next_u64());
+
+        let mut different = DeterministicRng::seed_from_u64(43);
+        assert_ne!(rng.draw_word(), different.draw_word());
+
+        // the sequence is pinned so that golden tests relying on it remain stable
+        let word = DeterministicRng::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)])
+            .draw_word();
+        assert_eq!(
+            word,
+            RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)])
+                .draw_word()
+        );
+    }
+}
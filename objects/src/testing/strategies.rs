@@ -0,0 +1,330 @@
+//! [proptest] strategies generating valid protocol objects.
+//!
+//! [Arbitrary] is implemented for [AccountType], [AccountStorageType], [AccountId],
+//! [FungibleAsset], [NonFungibleAsset], [Asset], [NoteType], [NoteMetadata], and [AccountDelta],
+//! so that these objects can be generated via [any()]. The strategies defined in this module can
+//! be used to generate objects with additional constraints (e.g., account IDs of a given type).
+
+use alloc::vec::Vec;
+
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    collection::{btree_map, vec},
+    option, prop_oneof,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+use crate::{
+    accounts::{
+        account_id::testing::account_id, AccountDelta, AccountId, AccountStorageDelta,
+        AccountStorageType, AccountType, AccountVaultDelta, StorageMapDelta,
+    },
+    assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    notes::{NoteMetadata, NoteTag, NoteType},
+    Felt, Word,
+};
+
+/// Maximum number of storage slots and vault assets updated by a generated [AccountDelta].
+const MAX_DELTA_UPDATES: usize = 8;
+
+/// Bits of a note tag which allow the tag to be used with notes of any type.
+const LOCAL_ANY_NOTE_TYPE_TAG_BITS: u32 = 0xC0000000;
+
+// STRATEGIES
+// ================================================================================================
+
+/// Returns a strategy generating field elements.
+pub fn felt_strategy() -> impl Strategy<Value = Felt> {
+    any::<u64>().prop_map(Felt::new)
+}
+
+/// Returns a strategy generating words.
+pub fn word_strategy() -> impl Strategy<Value = Word> {
+    any::<[u64; 4]>().prop_map(|word| word.map(Felt::new))
+}
+
+/// Returns a strategy generating account IDs of the specified type and storage type.
+pub fn account_id_strategy(
+    account_type: AccountType,
+    storage_type: AccountStorageType,
+) -> impl Strategy<Value = AccountId> {
+    // the 4 most significant bits of the ID hold the account metadata; the remaining bits must
+    // contain at least the minimum number of ones for the ID to be valid
+    any::<u64>()
+        .prop_map(|rest| rest >> 4)
+        .prop_filter("account ID has too few ones", |rest| {
+            rest.count_ones() >= AccountId::MIN_ACCOUNT_ONES
+        })
+        .prop_map(move |rest| {
+            AccountId::try_from(account_id(account_type, storage_type, rest))
+                .expect("generated account ID is valid")
+        })
+}
+
+/// Returns a strategy generating fungible assets issued by the specified faucet.
+///
+/// # Panics
+/// Panics if the specified account is not a fungible faucet.
+pub fn fungible_asset_strategy(faucet_id: AccountId) -> impl Strategy<Value = FungibleAsset> {
+    assert_eq!(faucet_id.account_type(), AccountType::FungibleFaucet);
+    (0..=FungibleAsset::MAX_AMOUNT).prop_map(move |amount| {
+        FungibleAsset::new(faucet_id, amount).expect("generated fungible asset is valid")
+    })
+}
+
+/// Returns a strategy generating non-fungible assets issued by the specified faucet.
+///
+/// # Panics
+/// Panics if the specified account is not a non-fungible faucet.
+pub fn non_fungible_asset_strategy(
+    faucet_id: AccountId,
+) -> impl Strategy<Value = NonFungibleAsset> {
+    assert_eq!(faucet_id.account_type(), AccountType::NonFungibleFaucet);
+    vec(any::<u8>(), 0..64).prop_map(move |data| {
+        let details = NonFungibleAssetDetails::new(faucet_id, data)
+            .expect("generated non-fungible asset details are valid");
+        NonFungibleAsset::new(&details).expect("generated non-fungible asset is valid")
+    })
+}
+
+/// Returns a strategy generating note metadata for notes sent by the specified account.
+pub fn note_metadata_strategy(sender: AccountId) -> impl Strategy<Value = NoteMetadata> {
+    (any::<NoteType>(), any::<u32>(), felt_strategy()).prop_map(move |(note_type, tag, aux)| {
+        // tags which are inconsistent with the note type are changed into local tags, which can
+        // be used with notes of any type
+        let tag = match NoteTag::from(tag).validate(note_type) {
+            Ok(tag) => tag,
+            Err(_) => NoteTag::from(tag | LOCAL_ANY_NOTE_TYPE_TAG_BITS),
+        };
+        NoteMetadata::new(sender, note_type, tag, aux).expect("generated note metadata is valid")
+    })
+}
+
+/// Returns a strategy generating storage map deltas.
+pub fn storage_map_delta_strategy() -> impl Strategy<Value = StorageMapDelta> {
+    btree_map(any::<[u64; 4]>(), option::of(word_strategy()), 0..MAX_DELTA_UPDATES).prop_map(
+        |leaves| {
+            let mut cleared_leaves = Vec::new();
+            let mut updated_leaves = Vec::new();
+            for (key, value) in leaves {
+                let key = key.map(Felt::new);
+                match value {
+                    Some(value) => updated_leaves.push((key, value)),
+                    None => cleared_leaves.push(key),
+                }
+            }
+            StorageMapDelta::from(cleared_leaves, updated_leaves)
+        },
+    )
+}
+
+/// Returns a strategy generating account storage deltas.
+///
+/// Each generated slot is either cleared, or updated with a new value and, optionally, a storage
+/// map delta.
+pub fn account_storage_delta_strategy() -> impl Strategy<Value = AccountStorageDelta> {
+    let slot_update = option::of((word_strategy(), option::of(storage_map_delta_strategy())));
+    btree_map(0..=254_u8, slot_update, 0..MAX_DELTA_UPDATES).prop_map(|slots| {
+        let mut delta = AccountStorageDelta::default();
+        for (idx, update) in slots {
+            match update {
+                Some((value, map_delta)) => {
+                    delta.updated_items.push((idx, value));
+                    if let Some(map_delta) = map_delta {
+                        delta.updated_maps.push((idx, map_delta));
+                    }
+                },
+                None => delta.cleared_items.push(idx),
+            }
+        }
+        delta
+    })
+}
+
+/// Returns a strategy generating account vault deltas.
+pub fn account_vault_delta_strategy() -> impl Strategy<Value = AccountVaultDelta> {
+    vec((any::<Asset>(), any::<bool>()), 0..MAX_DELTA_UPDATES).prop_map(|assets| {
+        // the same asset cannot be updated more than once, so duplicates are skipped
+        let mut delta = AccountVaultDelta::empty();
+        for (asset, added) in assets {
+            if delta
+                .added_assets
+                .iter()
+                .chain(&delta.removed_assets)
+                .any(|a| a.is_same(&asset))
+            {
+                continue;
+            }
+            match added {
+                true => delta.added_assets.push(asset),
+                false => delta.removed_assets.push(asset),
+            }
+        }
+        delta
+    })
+}
+
+// ARBITRARY IMPLEMENTATIONS
+// ================================================================================================
+
+impl Arbitrary for AccountType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            Just(AccountType::FungibleFaucet),
+            Just(AccountType::NonFungibleFaucet),
+            Just(AccountType::RegularAccountImmutableCode),
+            Just(AccountType::RegularAccountUpdatableCode),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for AccountStorageType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![Just(AccountStorageType::OnChain), Just(AccountStorageType::OffChain)].boxed()
+    }
+}
+
+impl Arbitrary for AccountId {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (any::<AccountType>(), any::<AccountStorageType>())
+            .prop_flat_map(|(account_type, storage_type)| {
+                account_id_strategy(account_type, storage_type)
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for FungibleAsset {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        any::<AccountStorageType>()
+            .prop_flat_map(|storage_type| {
+                account_id_strategy(AccountType::FungibleFaucet, storage_type)
+            })
+            .prop_flat_map(fungible_asset_strategy)
+            .boxed()
+    }
+}
+
+impl Arbitrary for NonFungibleAsset {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        any::<AccountStorageType>()
+            .prop_flat_map(|storage_type| {
+                account_id_strategy(AccountType::NonFungibleFaucet, storage_type)
+            })
+            .prop_flat_map(non_fungible_asset_strategy)
+            .boxed()
+    }
+}
+
+impl Arbitrary for Asset {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<FungibleAsset>().prop_map(Asset::from),
+            any::<NonFungibleAsset>().prop_map(Asset::from),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for NoteType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![Just(NoteType::OffChain), Just(NoteType::Encrypted), Just(NoteType::Public)]
+            .boxed()
+    }
+}
+
+impl Arbitrary for NoteMetadata {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        any::<AccountId>().prop_flat_map(note_metadata_strategy).boxed()
+    }
+}
+
+impl Arbitrary for AccountDelta {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (account_storage_delta_strategy(), account_vault_delta_strategy(), 1..=u32::MAX)
+            .prop_map(|(storage, vault, nonce)| {
+                // the nonce must be updated if and only if the delta is not empty
+                let nonce = match storage.is_empty() && vault.is_empty() {
+                    true => None,
+                    false => Some(Felt::from(nonce)),
+                };
+                AccountDelta::new(storage, vault, nonce).expect("generated account delta is valid")
+            })
+            .boxed()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::utils::serde::{Deserializable, Serializable};
+
+    proptest! {
+        #[test]
+        fn account_id_serialization(id in any::<AccountId>()) {
+            prop_assert_eq!(AccountId::read_from_bytes(&id.to_bytes()).unwrap(), id);
+            prop_assert_eq!(AccountId::from_hex(&id.to_hex()).unwrap(), id);
+        }
+
+        #[test]
+        fn account_id_metadata(
+            (account_type, storage_type, id) in (any::<AccountType>(), any::<AccountStorageType>())
+                .prop_flat_map(|(account_type, storage_type)| {
+                    (Just(account_type), Just(storage_type), account_id_strategy(account_type, storage_type))
+                })
+        ) {
+            prop_assert_eq!(id.account_type(), account_type);
+            prop_assert_eq!(id.storage_type(), storage_type);
+        }
+
+        #[test]
+        fn asset_serialization(asset in any::<Asset>()) {
+            prop_assert_eq!(Asset::read_from_bytes(&asset.to_bytes()).unwrap(), asset);
+            prop_assert_eq!(Asset::try_from(Word::from(asset)).unwrap(), asset);
+        }
+
+        #[test]
+        fn note_metadata_serialization(metadata in any::<NoteMetadata>()) {
+            prop_assert_eq!(NoteMetadata::read_from_bytes(&metadata.to_bytes()).unwrap(), metadata);
+            prop_assert_eq!(NoteMetadata::try_from(Word::from(metadata)).unwrap(), metadata);
+        }
+
+        #[test]
+        fn account_delta_serialization(delta in any::<AccountDelta>()) {
+            prop_assert_eq!(AccountDelta::read_from_bytes(&delta.to_bytes()).unwrap(), delta);
+        }
+    }
+}
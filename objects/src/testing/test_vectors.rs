@@ -0,0 +1,497 @@
+//! Golden test vectors for protocol commitments.
+//!
+//! The vectors contain serialized objects together with the digests committed to by the kernel:
+//! note IDs and nullifiers of notes, hashes of accounts, and IDs of transactions (together with
+//! the input and output note commitments they are computed from). Implementations of the protocol
+//! in other languages (e.g., JS provers, indexers) can validate their hashing against the fixture
+//! in `objects/test-vectors/vectors.json`, which is also available as [TEST_VECTORS_JSON].
+//!
+//! All values are hex-encoded with a `0x` prefix: objects are encoded via their [Serializable]
+//! implementation, and digests and words via [Digest::to_hex()].
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        testing::{build_account, build_assets},
+        Account, AccountId, StorageMap,
+    },
+    assembly::{Assembler, ProgramAst},
+    assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    notes::{
+        Note, NoteAssets, NoteExecutionHint, NoteHeader, NoteId, NoteInputs, NoteMetadata,
+        NoteRecipient, NoteScript, NoteTag, NoteType, Nullifier,
+    },
+    transaction::{InputNotes, OutputNote, OutputNotes, TransactionId},
+    utils::serde::{Deserializable, Serializable},
+    Digest, Felt, Word,
+};
+
+/// The test vectors fixture, serialized as JSON.
+pub const TEST_VECTORS_JSON: &str = include_str!("../../test-vectors/vectors.json");
+
+/// Source of the script used by all notes in the test vectors.
+const NOTE_SCRIPT: &str = "begin push.1 push.2 add drop end";
+
+// TEST VECTORS
+// ================================================================================================
+
+/// A set of golden test vectors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TestVectors {
+    pub notes: Vec<NoteVector>,
+    pub accounts: Vec<AccountVector>,
+    pub transactions: Vec<TransactionVector>,
+}
+
+/// A serialized [Note] together with its ID and nullifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NoteVector {
+    pub name: String,
+    pub note: String,
+    pub note_id: String,
+    pub nullifier: String,
+}
+
+/// A serialized [Account] together with its hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AccountVector {
+    pub name: String,
+    pub account: String,
+    pub account_hash: String,
+}
+
+/// The public data of a transaction together with its ID.
+///
+/// The input notes commitment is computed from the nullifiers of the input notes, and the output
+/// notes commitment is computed from the IDs and metadata of the output notes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TransactionVector {
+    pub name: String,
+    pub init_account_hash: String,
+    pub final_account_hash: String,
+    pub block_ref: String,
+    pub input_nullifiers: Vec<String>,
+    pub output_notes: Vec<OutputNoteVector>,
+    pub input_notes_commitment: String,
+    pub output_notes_commitment: String,
+    pub transaction_id: String,
+}
+
+/// The ID and metadata of a note created by a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct OutputNoteVector {
+    pub note_id: String,
+    pub metadata: String,
+}
+
+impl TestVectors {
+    /// Returns the test vectors generated from the current implementation of the protocol.
+    ///
+    /// The generated vectors are deterministic; as long as the commitments computed by the kernel
+    /// do not change, the returned vectors are equal to the vectors in the fixture.
+    pub fn generate() -> Self {
+        let notes = build_notes();
+        let accounts = build_accounts();
+
+        let note_vectors = notes
+            .iter()
+            .map(|(name, note)| NoteVector {
+                name: name.to_string(),
+                note: bytes_to_hex(&note.to_bytes()),
+                note_id: note.id().to_hex(),
+                nullifier: note.nullifier().to_hex(),
+            })
+            .collect();
+
+        let account_vectors = accounts
+            .iter()
+            .map(|(name, account)| AccountVector {
+                name: name.to_string(),
+                account: bytes_to_hex(&account.to_bytes()),
+                account_hash: account.hash().to_hex(),
+            })
+            .collect();
+
+        let (initial_account, final_account) = (&accounts[0].1, &accounts[1].1);
+        let block_ref = Digest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+        let transaction_vectors = [
+            ("no_notes", &[][..], &[][..]),
+            ("consume_notes", &notes[..2], &[][..]),
+            ("consume_and_create_notes", &notes[..1], &notes[1..]),
+        ]
+        .into_iter()
+        .map(|(name, input_notes, output_notes)| {
+            let input_nullifiers = input_notes.iter().map(|(_, note)| note.nullifier()).collect();
+            let output_notes =
+                output_notes.iter().map(|(_, note)| *note.header()).collect::<Vec<_>>();
+            transaction_vector(
+                name,
+                initial_account.hash(),
+                final_account.hash(),
+                block_ref,
+                input_nullifiers,
+                output_notes,
+            )
+        })
+        .collect();
+
+        Self {
+            notes: note_vectors,
+            accounts: account_vectors,
+            transactions: transaction_vectors,
+        }
+    }
+
+    /// Returns the test vectors contained in the fixture.
+    #[cfg(feature = "serde")]
+    pub fn fixture() -> Self {
+        Self::from_json(TEST_VECTORS_JSON).expect("test vectors fixture is valid")
+    }
+
+    /// Parses test vectors from their JSON representation.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, TestVectorError> {
+        serde_json::from_str(json).map_err(|err| TestVectorError::InvalidJson(err.to_string()))
+    }
+
+    /// Returns the pretty-printed JSON representation of these test vectors.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("test vectors JSON rendering failed")
+    }
+
+    /// Recomputes all digests from the inputs of the vectors and checks them against the expected
+    /// digests.
+    ///
+    /// # Errors
+    /// Returns an error if an input cannot be decoded or if any of the recomputed digests differs
+    /// from the expected digest.
+    pub fn validate(&self) -> Result<(), TestVectorError> {
+        self.notes.iter().try_for_each(NoteVector::validate)?;
+        self.accounts.iter().try_for_each(AccountVector::validate)?;
+        self.transactions.iter().try_for_each(TransactionVector::validate)
+    }
+}
+
+impl NoteVector {
+    /// Checks the ID and nullifier of the note against the expected values.
+    pub fn validate(&self) -> Result<(), TestVectorError> {
+        let note = Note::read_from_bytes(&hex_to_bytes(&self.name, &self.note)?)
+            .map_err(|err| TestVectorError::InvalidObject(self.name.clone(), err.to_string()))?;
+
+        check(&self.name, "note_id", &self.note_id, note.id().to_hex())?;
+        check(&self.name, "nullifier", &self.nullifier, note.nullifier().to_hex())
+    }
+}
+
+impl AccountVector {
+    /// Checks the hash of the account against the expected value.
+    pub fn validate(&self) -> Result<(), TestVectorError> {
+        let account = Account::read_from_bytes(&hex_to_bytes(&self.name, &self.account)?)
+            .map_err(|err| TestVectorError::InvalidObject(self.name.clone(), err.to_string()))?;
+
+        check(&self.name, "account_hash", &self.account_hash, account.hash().to_hex())
+    }
+}
+
+impl TransactionVector {
+    /// Checks the note commitments and the ID of the transaction against the expected values.
+    pub fn validate(&self) -> Result<(), TestVectorError> {
+        let input_nullifiers = self
+            .input_nullifiers
+            .iter()
+            .map(|nullifier| parse_digest(&self.name, nullifier).map(Nullifier::from))
+            .collect::<Result<Vec<_>, _>>()?;
+        let output_notes = self
+            .output_notes
+            .iter()
+            .map(|note| {
+                let note_id = NoteId::from(parse_digest(&self.name, &note.note_id)?);
+                let metadata =
+                    NoteMetadata::try_from(Word::from(parse_digest(&self.name, &note.metadata)?))
+                        .map_err(|err| {
+                        TestVectorError::InvalidObject(self.name.clone(), err.to_string())
+                    })?;
+                Ok(NoteHeader::new(note_id, metadata))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let expected = transaction_vector(
+            &self.name,
+            parse_digest(&self.name, &self.init_account_hash)?,
+            parse_digest(&self.name, &self.final_account_hash)?,
+            parse_digest(&self.name, &self.block_ref)?,
+            input_nullifiers,
+            output_notes,
+        );
+
+        check(
+            &self.name,
+            "input_notes_commitment",
+            &self.input_notes_commitment,
+            expected.input_notes_commitment,
+        )?;
+        check(
+            &self.name,
+            "output_notes_commitment",
+            &self.output_notes_commitment,
+            expected.output_notes_commitment,
+        )?;
+        check(&self.name, "transaction_id", &self.transaction_id, expected.transaction_id)
+    }
+}
+
+// TEST VECTOR ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TestVectorError {
+    DigestMismatch {
+        vector: String,
+        field: &'static str,
+        expected: String,
+        actual: String,
+    },
+    InvalidHex(String, String),
+    InvalidJson(String),
+    InvalidObject(String, String),
+}
+
+impl fmt::Display for TestVectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TestVectorError {}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the notes used in the test vectors.
+fn build_notes() -> Vec<(&'static str, Note)> {
+    let assembler = Assembler::default();
+    let program = ProgramAst::parse(NOTE_SCRIPT).expect("note script is valid");
+    let (script, _) = NoteScript::new(program, &assembler).expect("note script compiles");
+
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let fungible_faucet = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let non_fungible_faucet = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+    let fungible_asset: Asset = FungibleAsset::new(fungible_faucet, 100).unwrap().into();
+    let non_fungible_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(non_fungible_faucet, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    let note = |assets: Vec<Asset>, note_type, tag, inputs: Vec<Felt>, serial_num: [u64; 4]| {
+        let metadata = NoteMetadata::new(sender, note_type, tag, Felt::new(7)).unwrap();
+        let recipient = NoteRecipient::new(
+            serial_num.map(Felt::new),
+            script.clone(),
+            NoteInputs::new(inputs).unwrap(),
+        );
+        Note::new(NoteAssets::new(assets).unwrap(), metadata, recipient)
+    };
+
+    vec![
+        (
+            "public_note_with_fungible_asset",
+            note(
+                vec![fungible_asset],
+                NoteType::Public,
+                NoteTag::from_account_id(target, NoteExecutionHint::Local).unwrap(),
+                vec![target.into()],
+                [1, 2, 3, 4],
+            ),
+        ),
+        (
+            "off_chain_note_without_inputs",
+            note(
+                vec![non_fungible_asset],
+                NoteType::OffChain,
+                NoteTag::for_local_use_case(1, 2).unwrap(),
+                vec![],
+                [5, 6, 7, 8],
+            ),
+        ),
+        (
+            "encrypted_note_with_multiple_assets",
+            note(
+                vec![fungible_asset, non_fungible_asset],
+                NoteType::Encrypted,
+                NoteTag::for_local_use_case(3, 4).unwrap(),
+                (1..=9).map(Felt::new).collect(),
+                [9, 10, 11, 12],
+            ),
+        ),
+    ]
+}
+
+/// Returns the accounts used in the test vectors.
+fn build_accounts() -> Vec<(&'static str, Account)> {
+    let (asset_0, asset_1) = build_assets();
+    let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let storage_map = StorageMap::with_entries([(
+        Digest::new([Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)]),
+        word,
+    )])
+    .unwrap();
+
+    vec![
+        ("account", build_account(vec![asset_0], Felt::new(1), vec![word], None)),
+        (
+            "account_with_storage_map",
+            build_account(vec![asset_0, asset_1], Felt::new(2), vec![word], Some(storage_map)),
+        ),
+    ]
+}
+
+/// Returns the vector of a transaction with the specified public data.
+fn transaction_vector(
+    name: &str,
+    init_account_hash: Digest,
+    final_account_hash: Digest,
+    block_ref: Digest,
+    input_nullifiers: Vec<Nullifier>,
+    output_notes: Vec<NoteHeader>,
+) -> TransactionVector {
+    let output_note_vectors = output_notes
+        .iter()
+        .map(|header| OutputNoteVector {
+            note_id: header.id().to_hex(),
+            metadata: Digest::from(Word::from(*header.metadata())).to_hex(),
+        })
+        .collect();
+    let input_nullifier_vectors =
+        input_nullifiers.iter().map(|nullifier| nullifier.to_hex()).collect();
+
+    let input_notes_commitment =
+        InputNotes::new(input_nullifiers).expect("input notes are valid").commitment();
+    let output_notes_commitment =
+        OutputNotes::new(output_notes.into_iter().map(OutputNote::Header).collect())
+            .expect("output notes are valid")
+            .commitment();
+    let transaction_id = TransactionId::new(
+        init_account_hash,
+        final_account_hash,
+        input_notes_commitment,
+        output_notes_commitment,
+        block_ref,
+    );
+
+    TransactionVector {
+        name: name.to_string(),
+        init_account_hash: init_account_hash.to_hex(),
+        final_account_hash: final_account_hash.to_hex(),
+        block_ref: block_ref.to_hex(),
+        input_nullifiers: input_nullifier_vectors,
+        output_notes: output_note_vectors,
+        input_notes_commitment: input_notes_commitment.to_hex(),
+        output_notes_commitment: output_notes_commitment.to_hex(),
+        transaction_id: transaction_id.to_hex(),
+    }
+}
+
+/// Returns an error if the expected value of the specified field differs from the actual value.
+fn check(
+    vector: &str,
+    field: &'static str,
+    expected: &str,
+    actual: String,
+) -> Result<(), TestVectorError> {
+    if expected != actual {
+        return Err(TestVectorError::DigestMismatch {
+            vector: vector.to_string(),
+            field,
+            expected: expected.to_string(),
+            actual,
+        });
+    }
+
+    Ok(())
+}
+
+fn parse_digest(vector: &str, hex: &str) -> Result<Digest, TestVectorError> {
+    Digest::try_from(hex)
+        .map_err(|err| TestVectorError::InvalidHex(vector.to_string(), err.to_string()))
+}
+
+fn bytes_to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(2 + bytes.len() * 2);
+    hex.push_str("0x");
+    for byte in bytes {
+        hex.push_str(&format!("{byte:02x}"));
+    }
+    hex
+}
+
+fn hex_to_bytes(vector: &str, hex: &str) -> Result<Vec<u8>, TestVectorError> {
+    let invalid_hex =
+        |reason: &str| TestVectorError::InvalidHex(vector.to_string(), reason.to_string());
+
+    let digits = hex.strip_prefix("0x").ok_or_else(|| invalid_hex("missing 0x prefix"))?;
+    if digits.len() % 2 != 0 {
+        return Err(invalid_hex("odd number of hex digits"));
+    }
+
+    (0..digits.len())
+        .step_by(2)
+        .map(|idx| {
+            digits
+                .get(idx..idx + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(|| invalid_hex("invalid hex digit"))
+        })
+        .collect()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::{TestVectorError, TestVectors};
+    use crate::Digest;
+
+    #[test]
+    fn test_vectors_fixture_is_valid() {
+        TestVectors::fixture().validate().unwrap();
+    }
+
+    /// If the commitments computed by the kernel change intentionally, the fixture must be
+    /// regenerated via `TestVectors::generate().to_json()`.
+    #[test]
+    fn test_vectors_match_fixture() {
+        let generated = TestVectors::generate();
+        generated.validate().unwrap();
+        assert_eq!(generated, TestVectors::fixture());
+    }
+
+    #[test]
+    fn test_vectors_detect_mismatch() {
+        let mut vectors = TestVectors::fixture();
+        vectors.transactions[0].transaction_id = Digest::default().to_hex();
+        assert!(matches!(
+            vectors.validate(),
+            Err(TestVectorError::DigestMismatch { field: "transaction_id", .. })
+        ));
+    }
+}
@@ -0,0 +1,309 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use super::{Digest, Felt, Word};
+use crate::{
+    crypto::merkle::{InnerNodeInfo, MerklePath, MerkleStore},
+    vm::AdviceInputs,
+    AdviceInputsError, WORD_SIZE,
+};
+
+// ADVICE INPUTS BUILDER
+// ================================================================================================
+
+/// A builder of [AdviceInputs] which keeps track of the size of the advice data.
+///
+/// In addition to assembling the advice stack, the advice map, and the Merkle store, the builder
+/// detects conflicting advice map entries: inserting a value under a key which is already mapped
+/// to a different value is rejected, while re-inserting the same value is allowed (this is common
+/// for content-addressed data, e.g., identical note inputs of several notes).
+///
+/// The size of the advice data can be inspected via [AdviceInputsBuilder::size_estimate()] before
+/// the advice inputs are built, e.g., to reject transactions whose advice data exceeds a limit.
+#[derive(Debug, Clone, Default)]
+pub struct AdviceInputsBuilder {
+    stack: Vec<Felt>,
+    map: BTreeMap<Digest, Vec<Felt>>,
+    store: MerkleStore,
+}
+
+impl AdviceInputsBuilder {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [AdviceInputsBuilder] with empty advice stack, advice map, and Merkle store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // ADVICE STACK
+    // --------------------------------------------------------------------------------------------
+
+    /// Extends the advice stack with the provided elements.
+    pub fn extend_stack<I>(&mut self, elements: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Felt>,
+    {
+        self.stack.extend(elements);
+        self
+    }
+
+    /// Extends the advice stack with the elements of the provided words, in order.
+    pub fn extend_stack_with_words<I>(&mut self, words: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Word>,
+    {
+        self.stack.extend(words.into_iter().flatten());
+        self
+    }
+
+    // ADVICE MAP
+    // --------------------------------------------------------------------------------------------
+
+    /// Inserts the provided values into the advice map under the specified key.
+    ///
+    /// # Errors
+    /// Returns an error if the key is already mapped to different values. In this case, the advice
+    /// map is left unchanged.
+    pub fn insert_map_entry(
+        &mut self,
+        key: Digest,
+        values: Vec<Felt>,
+    ) -> Result<&mut Self, AdviceInputsError> {
+        match self.map.get(&key) {
+            Some(existing) if *existing != values => {
+                return Err(AdviceInputsError::MapKeyConflict(key))
+            },
+            Some(_) => (),
+            None => {
+                self.map.insert(key, values);
+            },
+        }
+        Ok(self)
+    }
+
+    /// Inserts the provided entries into the advice map.
+    ///
+    /// # Errors
+    /// Returns an error if any of the keys is already mapped to different values, either in the
+    /// advice map or by another provided entry. In this case, the advice map is left unchanged.
+    pub fn extend_map<I>(&mut self, entries: I) -> Result<&mut Self, AdviceInputsError>
+    where
+        I: IntoIterator<Item = (Digest, Vec<Felt>)>,
+    {
+        let entries: Vec<(Digest, Vec<Felt>)> = entries.into_iter().collect();
+
+        let mut new_entries: BTreeMap<&Digest, &Vec<Felt>> = BTreeMap::new();
+        for (key, values) in entries.iter() {
+            let existing = self.map.get(key).or_else(|| new_entries.get(key).copied());
+            match existing {
+                Some(existing) if existing != values => {
+                    return Err(AdviceInputsError::MapKeyConflict(*key))
+                },
+                Some(_) => (),
+                None => {
+                    new_entries.insert(key, values);
+                },
+            }
+        }
+
+        for (key, values) in entries {
+            self.map.entry(key).or_insert(values);
+        }
+        Ok(self)
+    }
+
+    // MERKLE STORE
+    // --------------------------------------------------------------------------------------------
+
+    /// Inserts the provided inner nodes into the Merkle store.
+    ///
+    /// Since the nodes of the Merkle store are addressed by their hashes, inserting the same node
+    /// several times has no effect.
+    pub fn extend_merkle_store<I>(&mut self, nodes: I) -> &mut Self
+    where
+        I: IntoIterator<Item = InnerNodeInfo>,
+    {
+        self.store.extend(nodes);
+        self
+    }
+
+    /// Inserts all nodes of the provided Merkle store into the Merkle store.
+    pub fn add_merkle_store(&mut self, store: &MerkleStore) -> &mut Self {
+        self.store.extend(store.inner_nodes());
+        self
+    }
+
+    /// Inserts the nodes of the provided Merkle path, opening to `node` at the specified index,
+    /// into the Merkle store, and returns the root of the path.
+    ///
+    /// # Errors
+    /// Returns an error if the index is not valid for the depth of the path.
+    pub fn add_merkle_path(
+        &mut self,
+        index: u64,
+        node: Digest,
+        path: MerklePath,
+    ) -> Result<Digest, AdviceInputsError> {
+        self.store
+            .add_merkle_path(index, node, path)
+            .map_err(AdviceInputsError::InvalidMerklePath)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the advice stack built so far.
+    pub fn stack(&self) -> &[Felt] {
+        &self.stack
+    }
+
+    /// Returns the values mapped to the specified key in the advice map, if any.
+    pub fn map_entry(&self, key: &Digest) -> Option<&[Felt]> {
+        self.map.get(key).map(Vec::as_slice)
+    }
+
+    /// Returns an estimate of the size of the advice data inserted so far.
+    pub fn size_estimate(&self) -> AdviceInputsSize {
+        // the Merkle store is pre-populated with the roots of empty subtrees, which are not
+        // counted as advice data
+        let empty_store_nodes = <MerkleStore>::default().num_internal_nodes();
+
+        AdviceInputsSize {
+            stack_elements: self.stack.len(),
+            map_entries: self.map.len(),
+            map_elements: self.map.values().map(Vec::len).sum(),
+            merkle_store_nodes: self.store.num_internal_nodes().saturating_sub(empty_store_nodes),
+        }
+    }
+
+    // BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes the builder and returns the resulting [AdviceInputs].
+    pub fn build(self) -> AdviceInputs {
+        AdviceInputs::default()
+            .with_stack(self.stack)
+            .with_map(self.map)
+            .with_merkle_store(self.store)
+    }
+}
+
+// ADVICE INPUTS SIZE
+// ================================================================================================
+
+/// An estimate of the size of advice data, as returned by [AdviceInputsBuilder::size_estimate()].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AdviceInputsSize {
+    /// Number of elements on the advice stack.
+    pub stack_elements: usize,
+    /// Number of entries in the advice map.
+    pub map_entries: usize,
+    /// Total number of elements of all values in the advice map.
+    pub map_elements: usize,
+    /// Number of inner nodes in the Merkle store.
+    pub merkle_store_nodes: usize,
+}
+
+impl AdviceInputsSize {
+    /// Size of a single element in bytes.
+    const ELEMENT_SIZE: usize = 8;
+
+    /// Size of a digest in bytes.
+    const DIGEST_SIZE: usize = WORD_SIZE * Self::ELEMENT_SIZE;
+
+    /// Returns the estimated number of bytes of the advice data.
+    ///
+    /// Each element accounts for 8 bytes, each advice map key for 32 bytes, and each Merkle store
+    /// node for 96 bytes (the node together with its two children).
+    pub fn num_bytes(&self) -> usize {
+        (self.stack_elements + self.map_elements) * Self::ELEMENT_SIZE
+            + self.map_entries * Self::DIGEST_SIZE
+            + self.merkle_store_nodes * 3 * Self::DIGEST_SIZE
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{AdviceInputsBuilder, AdviceInputsSize};
+    use crate::{
+        crypto::merkle::{MerkleTree, NodeIndex},
+        AdviceInputsError, Digest, ONE, ZERO,
+    };
+
+    #[test]
+    fn test_map_conflicts() {
+        let key = Digest::from([ONE, ZERO, ZERO, ZERO]);
+        let other_key = Digest::from([ZERO, ONE, ZERO, ZERO]);
+        let mut builder = AdviceInputsBuilder::new();
+
+        builder.insert_map_entry(key, vec![ONE, ONE]).unwrap();
+        // re-inserting the same values is not a conflict
+        builder.insert_map_entry(key, vec![ONE, ONE]).unwrap();
+        assert_eq!(
+            builder.insert_map_entry(key, vec![ONE]).unwrap_err(),
+            AdviceInputsError::MapKeyConflict(key)
+        );
+
+        // the advice map is not modified on conflict
+        let result = builder.extend_map([(other_key, vec![ONE]), (key, vec![ZERO])]);
+        assert_eq!(result.unwrap_err(), AdviceInputsError::MapKeyConflict(key));
+        assert_eq!(builder.map_entry(&key), Some([ONE, ONE].as_slice()));
+        assert_eq!(builder.map_entry(&other_key), None);
+
+        // conflicting entries within the same batch are detected as well
+        let result = builder.extend_map([(other_key, vec![ONE]), (other_key, vec![ZERO])]);
+        assert_eq!(result.unwrap_err(), AdviceInputsError::MapKeyConflict(other_key));
+        assert_eq!(builder.map_entry(&other_key), None);
+
+        let advice_inputs = builder.build();
+        assert_eq!(advice_inputs.mapped_values(&key), Some([ONE, ONE].as_slice()));
+    }
+
+    #[test]
+    fn test_size_estimate() {
+        let mut builder = AdviceInputsBuilder::new();
+        assert_eq!(builder.size_estimate(), AdviceInputsSize::default());
+        assert_eq!(builder.size_estimate().num_bytes(), 0);
+
+        builder.extend_stack([ONE, ZERO]);
+        builder.extend_stack_with_words([[ONE; 4]]);
+        builder.insert_map_entry(Digest::from([ONE; 4]), vec![ONE; 3]).unwrap();
+
+        let leaves: Vec<_> = (0..4u32).map(|i| [ONE, ZERO, ZERO, i.into()]).collect();
+        let tree = MerkleTree::new(leaves.clone()).unwrap();
+        builder.extend_merkle_store(tree.inner_nodes());
+
+        // inserting the same nodes again does not increase the size of the Merkle store
+        let path = tree.get_path(NodeIndex::new(2, 1).unwrap()).unwrap();
+        let root = builder.add_merkle_path(1, leaves[1].into(), path).unwrap();
+        assert_eq!(root, tree.root());
+
+        let size = builder.size_estimate();
+        assert_eq!(
+            size,
+            AdviceInputsSize {
+                stack_elements: 6,
+                map_entries: 1,
+                map_elements: 3,
+                merkle_store_nodes: 3,
+            }
+        );
+        assert_eq!(size.num_bytes(), (6 + 3) * 8 + 32 + 3 * 96);
+    }
+
+    #[test]
+    fn test_invalid_merkle_path() {
+        let leaves: Vec<_> = (0..4u32).map(|i| [ONE, ZERO, ZERO, i.into()]).collect();
+        let tree = MerkleTree::new(leaves.clone()).unwrap();
+        let path = tree.get_path(NodeIndex::new(2, 1).unwrap()).unwrap();
+
+        let mut builder = AdviceInputsBuilder::new();
+        let result = builder.add_merkle_path(4, leaves[1].into(), path);
+        assert!(matches!(result, Err(AdviceInputsError::InvalidMerklePath(_))));
+    }
+}
@@ -1,8 +1,10 @@
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+use core::num::NonZeroUsize;
 
 use crate::{
-    crypto::merkle::{InnerNodeInfo, MmrPeaks, PartialMmr},
-    BlockHeader, ChainMmrError,
+    crypto::merkle::{InOrderIndex, InnerNodeInfo, MmrPeaks, PartialMmr},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    BlockHeader, ChainMmrError, Digest,
 };
 
 // CHAIN MMR
@@ -113,6 +115,120 @@ impl ChainMmr {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+/// A chain MMR is serialized as the number of leaves and the peaks of the partial MMR, followed by
+/// the `(in_order_index, digest)` pairs of the tracked authentication nodes, a flag indicating
+/// whether the latest block of an odd-sized chain is tracked, and the headers of tracked blocks.
+impl Serializable for ChainMmr {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let peaks = self.mmr.peaks();
+        target.write_usize(peaks.num_leaves());
+        target.write_usize(peaks.peaks().len());
+        target.write_many(peaks.peaks());
+
+        let nodes = self.mmr.nodes().collect::<Vec<_>>();
+        target.write_usize(nodes.len());
+        for (index, node) in nodes {
+            target.write_usize(index.inner() as usize);
+            node.write_into(target);
+        }
+
+        let forest = self.mmr.forest();
+        target.write_bool(forest & 1 != 0 && self.mmr.is_tracked(forest - 1));
+
+        target.write_usize(self.blocks.len());
+        target.write_many(self.blocks.values());
+    }
+}
+
+impl Deserializable for ChainMmr {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_leaves = source.read_usize()?;
+        let num_peaks = source.read_usize()?;
+        let peaks = (0..num_peaks)
+            .map(|_| Digest::read_from(source))
+            .collect::<Result<Vec<_>, _>>()?;
+        let peaks = MmrPeaks::new(num_leaves, peaks)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        let num_nodes = source.read_usize()?;
+        let mut nodes = BTreeMap::new();
+        for _ in 0..num_nodes {
+            let index = NonZeroUsize::new(source.read_usize()?).ok_or_else(|| {
+                DeserializationError::InvalidValue("in-order index must be non-zero".to_string())
+            })?;
+            nodes.insert(InOrderIndex::new(index), Digest::read_from(source)?);
+        }
+
+        let track_latest = source.read_bool()?;
+
+        let num_blocks = source.read_usize()?;
+        let blocks = (0..num_blocks)
+            .map(|_| BlockHeader::read_from(source))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mmr = PartialMmr::from_parts(peaks, nodes, track_latest);
+        Self::new(mmr, blocks).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+/// A chain MMR is serialized as a struct with the following fields:
+/// - `peaks`: the peaks of the partial MMR.
+/// - `nodes`: a list of `(in_order_index, digest)` pairs of the authentication nodes tracked by
+///   the partial MMR.
+/// - `track_latest`: a flag indicating whether the latest block of an odd-sized chain is tracked.
+/// - `blocks`: a list of headers of the blocks tracked by the chain MMR.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ChainMmr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let forest = self.mmr.forest();
+        let nodes =
+            self.mmr.nodes().map(|(index, node)| (index.inner(), *node)).collect::<Vec<_>>();
+        let track_latest = forest & 1 != 0 && self.mmr.is_tracked(forest - 1);
+        let blocks = self.blocks.values().collect::<Vec<_>>();
+
+        let mut state = serializer.serialize_struct("ChainMmr", 4)?;
+        state.serialize_field("peaks", &self.mmr.peaks())?;
+        state.serialize_field("nodes", &nodes)?;
+        state.serialize_field("track_latest", &track_latest)?;
+        state.serialize_field("blocks", &blocks)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ChainMmr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        struct ChainMmrParts {
+            peaks: MmrPeaks,
+            nodes: Vec<(u64, Digest)>,
+            track_latest: bool,
+            blocks: Vec<BlockHeader>,
+        }
+
+        let parts = ChainMmrParts::deserialize(deserializer)?;
+        let nodes = parts
+            .nodes
+            .into_iter()
+            .map(|(index, node)| {
+                NonZeroUsize::new(index as usize)
+                    .map(|index| (InOrderIndex::new(index), node))
+                    .ok_or_else(|| D::Error::custom("in-order index must be non-zero"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let mmr = PartialMmr::from_parts(parts.peaks, nodes, parts.track_latest);
+        Self::new(mmr, parts.blocks).map_err(D::Error::custom)
+    }
+}
+
 // TESTS
 // ================================================================================================
 
@@ -122,6 +238,7 @@ mod tests {
     use crate::{
         alloc::vec::Vec,
         crypto::merkle::{Mmr, PartialMmr},
+        utils::serde::{Deserializable, Serializable},
         BlockHeader, Digest,
     };
 
@@ -170,6 +287,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_chain_mmr_serde() {
+        let chain_mmr = build_chain_mmr();
+        let bytes = chain_mmr.to_bytes();
+        assert_eq!(ChainMmr::read_from_bytes(&bytes).unwrap(), chain_mmr);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_chain_mmr_json() {
+        let chain_mmr = build_chain_mmr();
+        let json = serde_json::to_string(&chain_mmr).unwrap();
+        let deserialized: ChainMmr = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, chain_mmr);
+    }
+
+    /// Builds a chain of 5 blocks tracking blocks 1 and 4; the latter is the odd leaf of the MMR.
+    fn build_chain_mmr() -> ChainMmr {
+        let mut partial_mmr = PartialMmr::from_peaks(Mmr::default().peaks(0).unwrap());
+        let headers = (0..5).map(int_to_block_header).collect::<Vec<_>>();
+        for header in headers.iter() {
+            partial_mmr.add(header.hash(), matches!(header.block_num(), 1 | 4));
+        }
+        ChainMmr::new(partial_mmr, vec![headers[1], headers[4]]).unwrap()
+    }
+
     fn int_to_block_header(block_num: u32) -> BlockHeader {
         BlockHeader::new(
             0,
@@ -1,10 +1,11 @@
-use core::cell::OnceCell;
+use core::{cell::OnceCell, cmp::Ordering};
 
 use super::{
-    Account, AccountDelta, AccountId, AccountStub, AdviceInputs, BlockHeader, InputNotes,
-    OutputNotes, Program, TransactionArgs, TransactionId, TransactionInputs, TransactionOutputs,
-    TransactionWitness,
+    Account, AccountDelta, AccountId, AccountStub, AdviceInputs, BlockHeader, Digest,
+    ExecutedTransactionSummary, InputNotes, OutputNotes, Program, TransactionArgs, TransactionId,
+    TransactionInputs, TransactionOutputs, TransactionWitness,
 };
+use crate::accounts::salt_account_hash;
 
 // EXECUTED TRANSACTION
 // ================================================================================================
@@ -19,6 +20,8 @@ use super::{
 ///   stateless manner. This includes all public transaction inputs, but also all nondeterministic
 ///   inputs that the host provided to Miden VM while executing the transaction (i.e., advice
 ///   witness).
+///
+/// Executed transactions are compared and ordered by their IDs.
 #[derive(Debug, Clone)]
 pub struct ExecutedTransaction {
     id: OnceCell<TransactionId>,
@@ -83,6 +86,12 @@ impl ExecutedTransaction {
         self.tx_inputs.account()
     }
 
+    /// Returns the commitment to the account state after the transaction was executed, i.e., the
+    /// hash of the final account salted with the final account salt of the transaction arguments.
+    pub fn final_account_commitment(&self) -> Digest {
+        salt_account_hash(self.final_account().hash(), self.tx_args.final_account_salt())
+    }
+
     /// Returns description of the account after the transaction was executed.
     pub fn final_account(&self) -> &AccountStub {
         &self.tx_outputs.account
@@ -118,6 +127,11 @@ impl ExecutedTransaction {
         &self.tx_inputs
     }
 
+    /// Returns a user-facing summary of the effects of this transaction on its account.
+    pub fn summary(&self) -> ExecutedTransactionSummary {
+        self.into()
+    }
+
     /// Returns all the data requested by the VM from the advice provider while executing the
     /// transaction program.
     pub fn advice_witness(&self) -> &AdviceInputs {
@@ -140,6 +154,26 @@ impl ExecutedTransaction {
     }
 }
 
+impl PartialEq for ExecutedTransaction {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for ExecutedTransaction {}
+
+impl PartialOrd for ExecutedTransaction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ExecutedTransaction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id().cmp(&other.id())
+    }
+}
+
 impl From<ExecutedTransaction> for TransactionWitness {
     fn from(tx: ExecutedTransaction) -> Self {
         let (_, _, tx_witness) = tx.into_parts();
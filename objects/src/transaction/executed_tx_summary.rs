@@ -0,0 +1,254 @@
+use alloc::vec::Vec;
+
+use super::{AccountDelta, AccountId, Digest, ExecutedTransaction, Felt, OutputNote, OutputNotes};
+use crate::{
+    assets::Asset,
+    notes::{NoteId, NoteTag, NoteType},
+};
+
+// EXECUTED TRANSACTION SUMMARY
+// ================================================================================================
+
+/// A user-facing description of the effects of an executed transaction on its account.
+///
+/// The summary lists the assets which entered and left the account vault, the notes created by
+/// the transaction, and the change of the account nonce. Since an account delta records at most
+/// one change per asset, fungible assets are reported as the net flow per faucet.
+///
+/// This is distinct from [TransactionSummary](super::TransactionSummary), which is the message
+/// signed to authenticate a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ExecutedTransactionSummary {
+    account_id: AccountId,
+    init_nonce: Felt,
+    final_nonce: Felt,
+    inflows: Vec<Asset>,
+    outflows: Vec<Asset>,
+    created_notes: Vec<CreatedNoteSummary>,
+}
+
+impl ExecutedTransactionSummary {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ExecutedTransactionSummary] describing a transaction against the specified
+    /// account which made the provided changes and created the provided notes.
+    pub fn new(
+        account_id: AccountId,
+        init_nonce: Felt,
+        final_nonce: Felt,
+        account_delta: &AccountDelta,
+        output_notes: &OutputNotes,
+    ) -> Self {
+        let vault = account_delta.vault();
+
+        Self {
+            account_id,
+            init_nonce,
+            final_nonce,
+            inflows: vault.added_assets.clone(),
+            outflows: vault.removed_assets.clone(),
+            created_notes: output_notes.iter().map(CreatedNoteSummary::from).collect(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account against which the transaction was executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the nonce of the account before the transaction.
+    pub fn init_nonce(&self) -> Felt {
+        self.init_nonce
+    }
+
+    /// Returns the nonce of the account after the transaction.
+    pub fn final_nonce(&self) -> Felt {
+        self.final_nonce
+    }
+
+    /// Returns true if the transaction changed the nonce of the account.
+    pub fn nonce_changed(&self) -> bool {
+        self.init_nonce != self.final_nonce
+    }
+
+    /// Returns the assets which entered the account vault.
+    pub fn inflows(&self) -> &[Asset] {
+        &self.inflows
+    }
+
+    /// Returns the assets which left the account vault.
+    pub fn outflows(&self) -> &[Asset] {
+        &self.outflows
+    }
+
+    /// Returns the notes created by the transaction, in the order in which they were created.
+    pub fn created_notes(&self) -> &[CreatedNoteSummary] {
+        &self.created_notes
+    }
+}
+
+impl From<&ExecutedTransaction> for ExecutedTransactionSummary {
+    fn from(tx: &ExecutedTransaction) -> Self {
+        Self::new(
+            tx.account_id(),
+            tx.initial_account().nonce(),
+            tx.final_account().nonce(),
+            tx.account_delta(),
+            tx.output_notes(),
+        )
+    }
+}
+
+// CREATED NOTE SUMMARY
+// ================================================================================================
+
+/// A user-facing description of a note created by a transaction.
+///
+/// The recipient and the assets of the note are unknown if only the header of the note was
+/// output by the transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CreatedNoteSummary {
+    id: NoteId,
+    note_type: NoteType,
+    tag: NoteTag,
+    recipient: Option<Digest>,
+    assets: Option<Vec<Asset>>,
+}
+
+impl CreatedNoteSummary {
+    /// Returns the ID of the note.
+    pub fn id(&self) -> NoteId {
+        self.id
+    }
+
+    /// Returns the type of the note.
+    pub fn note_type(&self) -> NoteType {
+        self.note_type
+    }
+
+    /// Returns the tag of the note.
+    pub fn tag(&self) -> NoteTag {
+        self.tag
+    }
+
+    /// Returns the digest of the recipient of the note, if known.
+    pub fn recipient(&self) -> Option<Digest> {
+        self.recipient
+    }
+
+    /// Returns the assets carried by the note, if known.
+    pub fn assets(&self) -> Option<&[Asset]> {
+        self.assets.as_deref()
+    }
+}
+
+impl From<&OutputNote> for CreatedNoteSummary {
+    fn from(note: &OutputNote) -> Self {
+        Self {
+            id: note.id(),
+            note_type: note.metadata().note_type(),
+            tag: note.metadata().tag(),
+            recipient: note.recipient_digest(),
+            assets: note.assets().map(|assets| assets.iter().copied().collect()),
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+                ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            },
+            AccountStorageDelta, AccountVaultDelta,
+        },
+        assets::FungibleAsset,
+        notes::{NoteHeader, NoteMetadata},
+        testing::accounts::non_fungible_asset,
+    };
+
+    fn fungible_asset(faucet_id: u64, amount: u64) -> Asset {
+        FungibleAsset::new(faucet_id.try_into().unwrap(), amount).unwrap().into()
+    }
+
+    #[test]
+    fn executed_transaction_summary() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let nft = non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN);
+        let vault = AccountVaultDelta {
+            added_assets: vec![fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 50)],
+            removed_assets: vec![fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 20), nft],
+        };
+        let delta =
+            AccountDelta::new(AccountStorageDelta::default(), vault, Some(Felt::new(2))).unwrap();
+
+        let metadata = NoteMetadata::new(
+            account_id,
+            NoteType::OffChain,
+            NoteTag::from(0xc000_0007),
+            Felt::new(0),
+        )
+        .unwrap();
+        let header = NoteHeader::new(NoteId::new(Digest::default(), Digest::default()), metadata);
+        let output_notes = OutputNotes::new(vec![OutputNote::Header(header)]).unwrap();
+
+        let summary = ExecutedTransactionSummary::new(
+            account_id,
+            Felt::new(1),
+            Felt::new(2),
+            &delta,
+            &output_notes,
+        );
+
+        assert!(summary.nonce_changed());
+        assert_eq!(summary.inflows(), [fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 50)]);
+        assert_eq!(
+            summary.outflows(),
+            [fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 20), nft]
+        );
+
+        let created_note = &summary.created_notes()[0];
+        assert_eq!(created_note.id(), header.id());
+        assert_eq!(created_note.note_type(), NoteType::OffChain);
+        assert_eq!(created_note.recipient(), None);
+        assert_eq!(created_note.assets(), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn executed_transaction_summary_json() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let vault = AccountVaultDelta {
+            added_assets: vec![fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 50)],
+            removed_assets: vec![],
+        };
+        let delta =
+            AccountDelta::new(AccountStorageDelta::default(), vault, Some(Felt::new(2))).unwrap();
+        let summary = ExecutedTransactionSummary::new(
+            account_id,
+            Felt::new(1),
+            Felt::new(2),
+            &delta,
+            &OutputNotes::new(vec![]).unwrap(),
+        );
+
+        let json = serde_json::to_string(&summary).unwrap();
+        let deserialized: ExecutedTransactionSummary = serde_json::from_str(&json).unwrap();
+        assert_eq!(summary, deserialized);
+    }
+}
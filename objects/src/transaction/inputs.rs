@@ -1,21 +1,30 @@
 use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
 use core::fmt::Debug;
 
-use super::{BlockHeader, ChainMmr, Digest, Felt, Hasher, Word};
+use super::{BlockHeader, ChainMmr, Digest, Felt, Hasher, KernelLimits, Word, ZERO};
 use crate::{
-    accounts::{Account, AccountId},
-    notes::{Note, NoteId, NoteInclusionProof, NoteOrigin, Nullifier},
+    accounts::{
+        salt_account_hash, Account, AccountId, AccountIdAnchor, AccountStub, NetworkParams,
+    },
+    assets::PartialVault,
+    notes::{Note, NoteHeader, NoteId, NoteInclusionProof, NoteOrigin, Nullifier},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    TransactionInputError, MAX_INPUT_NOTES_PER_TX,
+    TransactionInputError,
 };
 
 // TRANSACTION INPUTS
 // ================================================================================================
 
 /// Contains the data required to execute a transaction.
+///
+/// The vault of the account against which the transaction is executed can be provided either in
+/// full (as a part of the [Account]), or as a [PartialVault] containing only the assets touched by
+/// the transaction. In the latter case, the vault of the provided [Account] is expected to be
+/// empty and is ignored.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TransactionInputs {
     account: Account,
+    partial_vault: Option<PartialVault>,
     account_seed: Option<Word>,
     block_header: BlockHeader,
     block_chain: ChainMmr,
@@ -28,9 +37,8 @@ impl TransactionInputs {
     /// Returns new [TransactionInputs] instantiated with the specified parameters.
     ///
     /// # Errors
-    /// Returns an error if:
-    /// - For a new account, account seed is not provided or the provided seed is invalid.
-    /// - For an existing account, account seed was provided.
+    /// Returns an error if the inputs are inconsistent, as described in
+    /// [TransactionInputs::validate()].
     pub fn new(
         account: Account,
         account_seed: Option<Word>,
@@ -38,11 +46,79 @@ impl TransactionInputs {
         block_chain: ChainMmr,
         input_notes: InputNotes,
     ) -> Result<Self, TransactionInputError> {
-        // make sure the provided seed is valid in the context of the provided account
-        validate_account_seed(&account, account_seed)?;
+        let tx_inputs = Self {
+            account,
+            partial_vault: None,
+            account_seed,
+            block_header,
+            block_chain,
+            input_notes,
+        };
+        tx_inputs.validate()?;
 
-        // make sure block_chain and block_header are consistent
+        Ok(tx_inputs)
+    }
+
+    /// Returns new [TransactionInputs] instantiated with the specified parameters, where the vault
+    /// of the account is described by the provided [PartialVault].
+    ///
+    /// The vault of the provided account must be empty; the account vault commitment is taken
+    /// from the partial vault instead.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The vault of the provided account is not empty.
+    /// - The provided account is a new account.
+    /// - Any of the conditions described in [TransactionInputs::new()] are violated.
+    pub fn with_partial_vault(
+        account: Account,
+        partial_vault: PartialVault,
+        account_seed: Option<Word>,
+        block_header: BlockHeader,
+        block_chain: ChainMmr,
+        input_notes: InputNotes,
+    ) -> Result<Self, TransactionInputError> {
+        if account.is_new() {
+            return Err(TransactionInputError::PartialVaultForNewAccount);
+        }
+
+        if !account.vault().is_empty() {
+            return Err(TransactionInputError::NonEmptyAccountVaultWithPartialVault);
+        }
 
+        let mut tx_inputs =
+            Self::new(account, account_seed, block_header, block_chain, input_notes)?;
+        tx_inputs.partial_vault = Some(partial_vault);
+
+        Ok(tx_inputs)
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that these inputs are consistent, i.e., that the transaction kernel prologue would
+    /// accept the referenced block, the account seed, and the input notes.
+    ///
+    /// The checks are performed when [TransactionInputs] are constructed, and thus this needs to
+    /// be called only to re-validate inputs obtained from an untrusted source before spending
+    /// resources on executing the transaction.
+    ///
+    /// The seed of a new account is checked to derive the account ID, but the proof-of-work
+    /// difficulty of the seed is not checked, as it depends on the parameters of the network (see
+    /// [TransactionInputs::validate_account_seed()]).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The chain MMR is inconsistent with the chain length or chain root of the block header.
+    /// - For a new account, account seed is not provided or the provided seed is invalid.
+    /// - For an existing account, account seed was provided.
+    /// - The block of an authenticated input note is not tracked by the chain MMR, or the note is
+    ///   not included in that block.
+    pub fn validate(&self) -> Result<(), TransactionInputError> {
+        let block_header = &self.block_header;
+        let block_chain = &self.block_chain;
+
+        // make sure block_chain and block_header are consistent
         let block_num = block_header.block_num();
         if block_chain.chain_length() != block_header.block_num() as usize {
             return Err(TransactionInputError::InconsistentChainLength {
@@ -58,35 +134,64 @@ impl TransactionInputs {
             });
         }
 
-        // make sure that block_chain has authentication paths for all input notes; for input notes
-        // which were created in the current block we skip this check because their authentication
-        // paths are derived implicitly
-        for note in input_notes.iter() {
-            let note_block_num = note.origin().block_num;
-
-            let block_header = if note_block_num == block_num {
-                &block_header
-            } else {
-                match block_chain.get_block(note_block_num) {
-                    Some(block_header) => block_header,
-                    None => Err(TransactionInputError::InputNoteBlockNotInChainMmr(note.id()))?,
+        // make sure the provided seed is valid in the context of the provided account
+        validate_account_seed(
+            &self.account,
+            self.account_seed,
+            block_header,
+            block_chain,
+            &NetworkParams::NO_PROOF_OF_WORK,
+        )?;
+
+        // make sure that block_chain has authentication paths for all authenticated input notes;
+        // for input notes which were created in the current block we skip this check because their
+        // authentication paths are derived implicitly. unauthenticated input notes are not checked
+        // here as their inclusion is verified by the block producer.
+        for note in self.input_notes.iter() {
+            if let InputNote::Authenticated { note, proof } = note {
+                let note_block_num = proof.origin().block_num;
+
+                let block_header = if note_block_num == block_num {
+                    block_header
+                } else {
+                    match block_chain.get_block(note_block_num) {
+                        Some(block_header) => block_header,
+                        None => Err(TransactionInputError::InputNoteBlockNotInChainMmr(note.id()))?,
+                    }
+                };
+
+                // this check may have non-negligible performance impact as we need to verify
+                // inclusion proofs for all notes; TODO: consider enabling this via a feature flag
+                if !is_in_block(note, proof, block_header) {
+                    return Err(TransactionInputError::InputNoteNotInBlock {
+                        note_id: note.id(),
+                        block_num: note_block_num,
+                    });
                 }
-            };
-
-            // this check may have non-negligible performance impact as we need to verify inclusion
-            // proofs for all notes; TODO: consider enabling this via a feature flag
-            if !note.is_in_block(block_header) {
-                return Err(TransactionInputError::InputNoteNotInBlock(note.id(), note_block_num));
             }
         }
 
-        Ok(Self {
-            account,
-            account_seed,
-            block_header,
-            block_chain,
-            input_notes,
-        })
+        Ok(())
+    }
+
+    /// Checks that the seed of a new account derives the account ID and satisfies the
+    /// proof-of-work difficulty of the network with the provided parameters.
+    ///
+    /// # Errors
+    /// Returns an error if the account is new and the account seed is missing, does not derive the
+    /// account ID, or does not satisfy the proof-of-work difficulty, or if the account is not new
+    /// and an account seed was provided.
+    pub fn validate_account_seed(
+        &self,
+        network_params: &NetworkParams,
+    ) -> Result<(), TransactionInputError> {
+        validate_account_seed(
+            &self.account,
+            self.account_seed,
+            &self.block_header,
+            &self.block_chain,
+            network_params,
+        )
     }
 
     // PUBLIC ACCESSORS
@@ -97,6 +202,59 @@ impl TransactionInputs {
         &self.account
     }
 
+    /// Returns the partial vault of the account, if the account vault was provided as a
+    /// [PartialVault].
+    pub fn partial_vault(&self) -> Option<&PartialVault> {
+        self.partial_vault.as_ref()
+    }
+
+    /// Returns the commitment to the vault of the account against which the transaction is to be
+    /// executed.
+    pub fn account_vault_root(&self) -> Digest {
+        match &self.partial_vault {
+            Some(partial_vault) => partial_vault.commitment(),
+            None => self.account.vault().commitment(),
+        }
+    }
+
+    /// Returns a stub of the account against which the transaction is to be executed.
+    ///
+    /// Unlike converting [TransactionInputs::account()] into an [AccountStub] directly, this takes
+    /// into account the partial vault of the account, if one was provided.
+    pub fn account_stub(&self) -> AccountStub {
+        AccountStub::new(
+            self.account.id(),
+            self.account.nonce(),
+            self.account_vault_root(),
+            self.account.storage().root(),
+            self.account.code().root(),
+        )
+    }
+
+    /// Returns the initial hash of the account against which the transaction is to be executed.
+    ///
+    /// For new accounts this is [Digest::default()].
+    pub fn account_init_hash(&self) -> Digest {
+        if self.account.is_new() {
+            Digest::default()
+        } else {
+            self.account_stub().hash()
+        }
+    }
+
+    /// Returns the commitment to the initial state of the account against which the transaction
+    /// is to be executed, i.e., the initial account hash salted with the provided salt (see
+    /// [salt_account_hash()]).
+    ///
+    /// For new accounts this is [Digest::default()].
+    pub fn account_init_commitment(&self, init_salt: Option<Word>) -> Digest {
+        if self.account.is_new() {
+            Digest::default()
+        } else {
+            salt_account_hash(self.account_stub().hash(), init_salt)
+        }
+    }
+
     /// For newly-created accounts, returns the account seed; for existing accounts, returns None.
     pub fn account_seed(&self) -> Option<Word> {
         self.account_seed
@@ -107,6 +265,14 @@ impl TransactionInputs {
         &self.block_header
     }
 
+    /// Returns the timestamp of the block referenced by the transaction.
+    ///
+    /// This is the timestamp exposed to transaction and note scripts via
+    /// `miden::tx::get_block_timestamp`.
+    pub fn block_timestamp(&self) -> u32 {
+        self.block_header.timestamp()
+    }
+
     /// Returns chain MMR containing authentication paths for all notes consumed by the
     /// transaction.
     pub fn block_chain(&self) -> &ChainMmr {
@@ -122,6 +288,8 @@ impl TransactionInputs {
     // --------------------------------------------------------------------------------------------
 
     /// Consumes these transaction inputs and returns their underlying components.
+    ///
+    /// The partial vault of the account, if any, is discarded.
     pub fn into_parts(self) -> (Account, Option<Word>, BlockHeader, ChainMmr, InputNotes) {
         (
             self.account,
@@ -133,45 +301,162 @@ impl TransactionInputs {
     }
 }
 
-// TO NULLIFIER TRAIT
+// SERIALIZATION
 // ================================================================================================
 
-/// Defines how a note object can be reduced to a nullifier.
+impl Serializable for TransactionInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account.write_into(target);
+        self.partial_vault.write_into(target);
+        self.account_seed.write_into(target);
+        self.block_header.write_into(target);
+        self.block_chain.write_into(target);
+        self.input_notes.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account = Account::read_from(source)?;
+        let partial_vault = <Option<PartialVault>>::read_from(source)?;
+        let account_seed = <Option<Word>>::read_from(source)?;
+        let block_header = BlockHeader::read_from(source)?;
+        let block_chain = ChainMmr::read_from(source)?;
+        let input_notes = InputNotes::read_from(source)?;
+
+        match partial_vault {
+            Some(partial_vault) => Self::with_partial_vault(
+                account,
+                partial_vault,
+                account_seed,
+                block_header,
+                block_chain,
+                input_notes,
+            ),
+            None => Self::new(account, account_seed, block_header, block_chain, input_notes),
+        }
+        .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+/// Transaction inputs are serialized as a struct with fields `account`, `partial_vault`,
+/// `account_seed`, `block_header`, `block_chain`, and `input_notes`. On deserialization, the
+/// inputs are validated in the same way as in [TransactionInputs::new()].
+#[cfg(feature = "serde")]
+impl serde::Serialize for TransactionInputs {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("TransactionInputs", 6)?;
+        state.serialize_field("account", &self.account)?;
+        state.serialize_field("partial_vault", &self.partial_vault)?;
+        state.serialize_field("account_seed", &self.account_seed)?;
+        state.serialize_field("block_header", &self.block_header)?;
+        state.serialize_field("block_chain", &self.block_chain)?;
+        state.serialize_field("input_notes", &self.input_notes)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TransactionInputs {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct TransactionInputsParts {
+            account: Account,
+            partial_vault: Option<PartialVault>,
+            account_seed: Option<Word>,
+            block_header: BlockHeader,
+            block_chain: ChainMmr,
+            input_notes: InputNotes,
+        }
+
+        let parts = TransactionInputsParts::deserialize(deserializer)?;
+        match parts.partial_vault {
+            Some(partial_vault) => Self::with_partial_vault(
+                parts.account,
+                partial_vault,
+                parts.account_seed,
+                parts.block_header,
+                parts.block_chain,
+                parts.input_notes,
+            ),
+            None => Self::new(
+                parts.account,
+                parts.account_seed,
+                parts.block_header,
+                parts.block_chain,
+                parts.input_notes,
+            ),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+
+// TO INPUT NOTE COMMITMENT
+// ================================================================================================
+
+/// Defines how a note object can be reduced to the data committed to by the input notes
+/// commitment of a transaction: the nullifier of the note and, for unauthenticated notes, the
+/// authentication hash of the note.
 ///
-/// This trait is implemented on both [InputNote] and [Nullifier] so that we can treat them
-/// generically as [InputNotes].
-pub trait ToNullifier:
+/// This trait is implemented on [InputNote], [InputNoteCommitment], and [Nullifier] so that we can
+/// treat them generically as [InputNotes].
+pub trait ToInputNoteCommitments:
     Debug + Clone + PartialEq + Eq + Serializable + Deserializable + Sized
 {
+    /// Returns the nullifier of the note.
     fn nullifier(&self) -> Nullifier;
+
+    /// Returns the authentication hash of the note if the note is unauthenticated, and None
+    /// otherwise.
+    fn note_hash(&self) -> Option<Digest>;
+}
+
+impl ToInputNoteCommitments for InputNote {
+    fn nullifier(&self) -> Nullifier {
+        self.note().nullifier()
+    }
+
+    fn note_hash(&self) -> Option<Digest> {
+        match self {
+            InputNote::Authenticated { .. } => None,
+            InputNote::Unauthenticated { note } => Some(note.authentication_hash()),
+        }
+    }
 }
 
-impl ToNullifier for InputNote {
+impl ToInputNoteCommitments for InputNoteCommitment {
     fn nullifier(&self) -> Nullifier {
-        self.note.nullifier()
+        self.nullifier
+    }
+
+    fn note_hash(&self) -> Option<Digest> {
+        self.header.map(|header| header.authentication_hash())
     }
 }
 
-impl ToNullifier for Nullifier {
+impl ToInputNoteCommitments for Nullifier {
     fn nullifier(&self) -> Nullifier {
         *self
     }
+
+    fn note_hash(&self) -> Option<Digest> {
+        None
+    }
 }
 
-impl From<InputNotes> for InputNotes<Nullifier> {
+impl From<InputNotes> for InputNotes<InputNoteCommitment> {
     fn from(value: InputNotes) -> Self {
-        Self {
-            notes: value.notes.iter().map(|note| note.nullifier()).collect(),
-            commitment: build_input_notes_commitment(&value.notes),
-        }
+        (&value).into()
     }
 }
 
-impl From<&InputNotes> for InputNotes<Nullifier> {
+impl From<&InputNotes> for InputNotes<InputNoteCommitment> {
     fn from(value: &InputNotes) -> Self {
         Self {
-            notes: value.notes.iter().map(|note| note.nullifier()).collect(),
-            commitment: build_input_notes_commitment(&value.notes),
+            notes: value.notes.iter().map(InputNoteCommitment::from).collect(),
+            commitment: value.commitment,
         }
     }
 }
@@ -182,42 +467,70 @@ impl From<&InputNotes> for InputNotes<Nullifier> {
 /// Contains a list of input notes for a transaction. The list can be empty if the transaction does
 /// not consume any notes.
 ///
-/// For the purposes of this struct, anything that can be reduced to a [Nullifier] can be an input
-/// note. However, [ToNullifier] trait is currently implemented only for [InputNote] and [Nullifier],
-/// and so these are the only two allowed input note types.
+/// For the purposes of this struct, anything that can be reduced to a [Nullifier] (and, for
+/// unauthenticated notes, to a note hash) can be an input note. However,
+/// [ToInputNoteCommitments] trait is currently implemented only for [InputNote],
+/// [InputNoteCommitment], and [Nullifier], and so these are the only allowed input note types.
 #[derive(Debug, Clone)]
-pub struct InputNotes<T: ToNullifier = InputNote> {
+pub struct InputNotes<T: ToInputNoteCommitments = InputNote> {
     notes: Vec<T>,
     commitment: Digest,
 }
 
-impl<T: ToNullifier> InputNotes<T> {
+impl<T: ToInputNoteCommitments> InputNotes<T> {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Returns new [InputNotes] instantiated from the provided vector of notes.
     ///
     /// # Errors
     /// Returns an error if:
-    /// - The total number of notes is greater than 1024.
+    /// - The total number of notes is greater than [KernelLimits::max_input_notes()].
     /// - The vector of notes contains duplicates.
     pub fn new(notes: Vec<T>) -> Result<Self, TransactionInputError> {
-        if notes.len() > MAX_INPUT_NOTES_PER_TX {
-            return Err(TransactionInputError::TooManyInputNotes {
-                max: MAX_INPUT_NOTES_PER_TX,
-                actual: notes.len(),
-            });
+        let max = KernelLimits::CURRENT.max_input_notes();
+        if notes.len() > max {
+            return Err(TransactionInputError::TooManyInputNotes { max, actual: notes.len() });
         }
 
+        Self::try_from_iter(notes)
+    }
+
+    /// Returns new [InputNotes] instantiated from the notes yielded by the provided iterator.
+    ///
+    /// The notes are consumed one at a time: the commitment is updated with each note as it is
+    /// received, and the construction is aborted as soon as the number of notes exceeds the
+    /// limit, without draining the rest of the iterator.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The total number of notes is greater than [KernelLimits::max_input_notes()].
+    /// - The iterator yields duplicate notes.
+    pub fn try_from_iter<I>(notes: I) -> Result<Self, TransactionInputError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let max = KernelLimits::CURRENT.max_input_notes();
+        let notes = notes.into_iter();
+
+        let mut input_notes = Vec::with_capacity(notes.size_hint().0.min(max));
         let mut seen_notes = BTreeSet::new();
-        for note in notes.iter() {
+        let mut commitment = InputNotesCommitmentBuilder::new();
+        for note in notes {
+            if input_notes.len() == max {
+                return Err(TransactionInputError::TooManyInputNotes { max, actual: max + 1 });
+            }
             if !seen_notes.insert(note.nullifier().inner()) {
                 return Err(TransactionInputError::DuplicateInputNote(note.nullifier().inner()));
             }
-        }
 
-        let commitment = build_input_notes_commitment(&notes);
+            commitment.add_note(&note);
+            input_notes.push(note);
+        }
 
-        Ok(Self { notes, commitment })
+        Ok(Self {
+            notes: input_notes,
+            commitment: commitment.finalize(),
+        })
     }
 
     // PUBLIC ACCESSORS
@@ -243,6 +556,11 @@ impl<T: ToNullifier> InputNotes<T> {
         &self.notes[idx]
     }
 
+    /// Returns the number of unauthenticated notes in this [InputNotes].
+    pub fn num_unauthenticated_notes(&self) -> usize {
+        self.notes.iter().filter(|note| note.note_hash().is_some()).count()
+    }
+
     // ITERATORS
     // --------------------------------------------------------------------------------------------
 
@@ -260,7 +578,7 @@ impl<T: ToNullifier> InputNotes<T> {
     }
 }
 
-impl<T: ToNullifier> IntoIterator for InputNotes<T> {
+impl<T: ToInputNoteCommitments> IntoIterator for InputNotes<T> {
     type Item = T;
     type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
@@ -269,15 +587,15 @@ impl<T: ToNullifier> IntoIterator for InputNotes<T> {
     }
 }
 
-impl<T: ToNullifier> PartialEq for InputNotes<T> {
+impl<T: ToInputNoteCommitments> PartialEq for InputNotes<T> {
     fn eq(&self, other: &Self) -> bool {
         self.notes == other.notes
     }
 }
 
-impl<T: ToNullifier> Eq for InputNotes<T> {}
+impl<T: ToInputNoteCommitments> Eq for InputNotes<T> {}
 
-impl<T: ToNullifier> Default for InputNotes<T> {
+impl<T: ToInputNoteCommitments> Default for InputNotes<T> {
     fn default() -> Self {
         Self {
             notes: Vec::new(),
@@ -289,7 +607,7 @@ impl<T: ToNullifier> Default for InputNotes<T> {
 // SERIALIZATION
 // ------------------------------------------------------------------------------------------------
 
-impl<T: ToNullifier> Serializable for InputNotes<T> {
+impl<T: ToInputNoteCommitments> Serializable for InputNotes<T> {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         // assert is OK here because we enforce max number of notes in the constructor
         assert!(self.notes.len() <= u16::MAX.into());
@@ -298,7 +616,7 @@ impl<T: ToNullifier> Serializable for InputNotes<T> {
     }
 }
 
-impl<T: ToNullifier> Deserializable for InputNotes<T> {
+impl<T: ToInputNoteCommitments> Deserializable for InputNotes<T> {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let num_notes = source.read_u16()?;
         let notes = source.read_many::<T>(num_notes.into())?;
@@ -306,24 +624,77 @@ impl<T: ToNullifier> Deserializable for InputNotes<T> {
     }
 }
 
+/// Input notes are serialized as a list of notes; the commitment is recomputed on deserialization.
+#[cfg(feature = "serde")]
+impl<T: ToInputNoteCommitments + serde::Serialize> serde::Serialize for InputNotes<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.notes.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: ToInputNoteCommitments + serde::Deserialize<'de>> serde::Deserialize<'de>
+    for InputNotes<T>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let notes = <Vec<T> as serde::Deserialize>::deserialize(deserializer)?;
+        Self::new(notes).map_err(serde::de::Error::custom)
+    }
+}
+
 // HELPER FUNCTIONS
 // ------------------------------------------------------------------------------------------------
 
-/// Returns the commitment to the input notes represented by the specified nullifiers.
+/// Returns the commitment to the specified input notes.
 ///
-/// For a non-empty list of notes, this is a sequential hash of all (nullifier, ZERO) pairs for
-/// the notes consumed in the transaction. For an empty list, [ZERO; 4] is returned.
-pub fn build_input_notes_commitment<T: ToNullifier>(notes: &[T]) -> Digest {
-    if notes.is_empty() {
-        return Digest::default();
+/// For a non-empty list of notes, this is a sequential hash of all (nullifier, EMPTY_WORD_OR_HASH)
+/// pairs for the notes consumed in the transaction, where EMPTY_WORD_OR_HASH is [ZERO; 4] for
+/// authenticated notes and the authentication hash of the note for unauthenticated notes. For an
+/// empty list, [ZERO; 4] is returned.
+pub fn build_input_notes_commitment<T: ToInputNoteCommitments>(notes: &[T]) -> Digest {
+    let mut commitment = InputNotesCommitmentBuilder::new();
+    for note in notes {
+        commitment.add_note(note);
     }
+    commitment.finalize()
+}
 
-    let mut elements: Vec<Felt> = Vec::new();
-    for note in notes {
-        elements.extend_from_slice(note.nullifier().as_elements());
-        elements.extend_from_slice(&Word::default());
+/// Computes the commitment described in [build_input_notes_commitment()] one note at a time.
+///
+/// Each note contributes exactly one rate-width block of 8 elements to the sequential hash, and
+/// so no padding is ever required: absorbing the notes block by block yields the same digest as
+/// hashing the concatenated elements of all notes, without materializing them.
+struct InputNotesCommitmentBuilder {
+    state: [Felt; Hasher::STATE_WIDTH],
+    num_notes: usize,
+}
+
+impl InputNotesCommitmentBuilder {
+    fn new() -> Self {
+        Self {
+            state: [ZERO; Hasher::STATE_WIDTH],
+            num_notes: 0,
+        }
+    }
+
+    fn add_note<T: ToInputNoteCommitments>(&mut self, note: &T) {
+        let rate = &mut self.state[Hasher::RATE_RANGE];
+        rate[..4].copy_from_slice(note.nullifier().as_elements());
+        match note.note_hash() {
+            Some(note_hash) => rate[4..].copy_from_slice(note_hash.as_elements()),
+            None => rate[4..].copy_from_slice(&Word::default()),
+        }
+        Hasher::apply_permutation(&mut self.state);
+        self.num_notes += 1;
+    }
+
+    fn finalize(self) -> Digest {
+        if self.num_notes == 0 {
+            return Digest::default();
+        }
+
+        Digest::new(self.state[Hasher::DIGEST_RANGE].try_into().expect("digest has four elements"))
     }
-    Hasher::hash_elements(&elements)
 }
 
 // INPUT NOTE
@@ -332,73 +703,234 @@ pub fn build_input_notes_commitment<T: ToNullifier>(notes: &[T]) -> Digest {
 /// An input note for a transaction.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-pub struct InputNote {
-    note: Note,
-    proof: NoteInclusionProof,
+pub enum InputNote {
+    /// Input notes whose existence in the chain is verified by the transaction kernel.
+    Authenticated { note: Note, proof: NoteInclusionProof },
+
+    /// Input notes whose existence in the chain is not verified by the transaction kernel, but
+    /// instead is delegated to the block producer. This allows consuming notes which have not yet
+    /// been included in a block, e.g., notes created by other transactions of the same block.
+    Unauthenticated { note: Note },
 }
 
 impl InputNote {
-    /// Returns a new instance of an [InputNote] with the specified note and proof.
-    pub fn new(note: Note, proof: NoteInclusionProof) -> Self {
-        Self { note, proof }
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an authenticated [InputNote] with the specified note and inclusion proof.
+    pub fn authenticated(note: Note, proof: NoteInclusionProof) -> Self {
+        Self::Authenticated { note, proof }
+    }
+
+    /// Returns an unauthenticated [InputNote] for the specified note.
+    pub fn unauthenticated(note: Note) -> Self {
+        Self::Unauthenticated { note }
     }
 
+    // ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
     /// Returns the ID of the note.
     pub fn id(&self) -> NoteId {
-        self.note.id()
+        self.note().id()
     }
 
     /// Returns a reference to the underlying note.
     pub fn note(&self) -> &Note {
-        &self.note
+        match self {
+            Self::Authenticated { note, .. } => note,
+            Self::Unauthenticated { note } => note,
+        }
     }
 
-    /// Returns a reference to the inclusion proof of the note.
-    pub fn proof(&self) -> &NoteInclusionProof {
-        &self.proof
+    /// Returns a reference to the inclusion proof of the note, or None if the note is
+    /// unauthenticated.
+    pub fn proof(&self) -> Option<&NoteInclusionProof> {
+        match self {
+            Self::Authenticated { proof, .. } => Some(proof),
+            Self::Unauthenticated { .. } => None,
+        }
     }
 
-    /// Returns a reference to the origin of the note.
-    pub fn origin(&self) -> &NoteOrigin {
-        self.proof.origin()
+    /// Returns a reference to the origin of the note, or None if the note is unauthenticated.
+    pub fn origin(&self) -> Option<&NoteOrigin> {
+        self.proof().map(|proof| proof.origin())
     }
 
-    /// Returns true if this note belongs to the note tree of the specified block.
-    fn is_in_block(&self, block_header: &BlockHeader) -> bool {
-        let note_index = self.origin().node_index.value();
-        let note_hash = self.note.authentication_hash();
-        self.proof.note_path().verify(note_index, note_hash, &block_header.note_root())
+    /// Returns true if the existence of this note in the chain is verified by the transaction
+    /// kernel.
+    pub fn is_authenticated(&self) -> bool {
+        matches!(self, Self::Authenticated { .. })
     }
 }
 
+/// Returns true if the specified note belongs to the note tree of the specified block.
+fn is_in_block(note: &Note, proof: &NoteInclusionProof, block_header: &BlockHeader) -> bool {
+    let note_index = proof.origin().node_index.value();
+    let note_hash = note.authentication_hash();
+    proof.note_path().verify(note_index, note_hash, &block_header.note_root())
+}
+
 // SERIALIZATION
 // ------------------------------------------------------------------------------------------------
 
 impl Serializable for InputNote {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        self.note.write_into(target);
-        self.proof.write_into(target);
+        match self {
+            Self::Authenticated { note, proof } => {
+                target.write_u8(0);
+                note.write_into(target);
+                proof.write_into(target);
+            },
+            Self::Unauthenticated { note } => {
+                target.write_u8(1);
+                note.write_into(target);
+            },
+        }
     }
 }
 
 impl Deserializable for InputNote {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let note = Note::read_from(source)?;
-        let proof = NoteInclusionProof::read_from(source)?;
+        match source.read_u8()? {
+            0 => {
+                let note = Note::read_from(source)?;
+                let proof = NoteInclusionProof::read_from(source)?;
+                Ok(Self::Authenticated { note, proof })
+            },
+            1 => {
+                let note = Note::read_from(source)?;
+                Ok(Self::Unauthenticated { note })
+            },
+            v => Err(DeserializationError::InvalidValue(format!("Invalid input note type: {v}"))),
+        }
+    }
+}
+
+// INPUT NOTE COMMITMENT
+// ================================================================================================
 
-        Ok(Self { note, proof })
+/// The data of an input note committed to by the input notes commitment of a transaction.
+///
+/// For authenticated notes this is just the nullifier of the note. For unauthenticated notes, the
+/// header of the note is included as well, so that the block producer can verify that the note
+/// was created either in a previous block, or by another transaction in the same block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InputNoteCommitment {
+    nullifier: Nullifier,
+    header: Option<NoteHeader>,
+}
+
+impl InputNoteCommitment {
+    /// Returns a new [InputNoteCommitment] instantiated from the nullifier of an input note and,
+    /// for unauthenticated notes, the header of the note.
+    pub fn new(nullifier: Nullifier, header: Option<NoteHeader>) -> Self {
+        Self { nullifier, header }
+    }
+
+    /// Returns the nullifier of the input note.
+    pub fn nullifier(&self) -> Nullifier {
+        self.nullifier
+    }
+
+    /// Returns the header of the input note if the note is unauthenticated, and None otherwise.
+    pub fn header(&self) -> Option<&NoteHeader> {
+        self.header.as_ref()
+    }
+
+    /// Returns true if the existence of the input note in the chain was verified by the
+    /// transaction kernel.
+    pub fn is_authenticated(&self) -> bool {
+        self.header.is_none()
+    }
+}
+
+impl From<InputNote> for InputNoteCommitment {
+    fn from(note: InputNote) -> Self {
+        (&note).into()
+    }
+}
+
+impl From<&InputNote> for InputNoteCommitment {
+    fn from(note: &InputNote) -> Self {
+        match note {
+            InputNote::Authenticated { note, .. } => Self {
+                nullifier: note.nullifier(),
+                header: None,
+            },
+            InputNote::Unauthenticated { note } => Self {
+                nullifier: note.nullifier(),
+                header: Some(*note.header()),
+            },
+        }
+    }
+}
+
+impl From<Nullifier> for InputNoteCommitment {
+    fn from(nullifier: Nullifier) -> Self {
+        Self { nullifier, header: None }
+    }
+}
+
+impl Serializable for InputNoteCommitment {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.nullifier.write_into(target);
+        self.header.write_into(target);
+    }
+}
+
+impl Deserializable for InputNoteCommitment {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let nullifier = Nullifier::read_from(source)?;
+        let header = <Option<NoteHeader>>::read_from(source)?;
+
+        Ok(Self { nullifier, header })
     }
 }
 
 /// Validates that the provided seed is valid for this account.
+///
+/// The seed digest must satisfy the difficulty specified by `network_params`. For accounts with
+/// anchored IDs, the anchor block must additionally be either the block referenced by the
+/// transaction (i.e., `block_header`), or a block tracked by `block_chain`.
 pub fn validate_account_seed(
     account: &Account,
     account_seed: Option<Word>,
+    block_header: &BlockHeader,
+    block_chain: &ChainMmr,
+    network_params: &NetworkParams,
 ) -> Result<(), TransactionInputError> {
     match (account.is_new(), account_seed) {
         (true, Some(seed)) => {
-            let account_id = AccountId::new(seed, account.code().root(), account.storage().root())
-                .map_err(TransactionInputError::InvalidAccountSeed)?;
+            let code_root = account.code().root();
+            let storage_root = account.storage().root();
+            let account_id = match account.id().anchor_epoch() {
+                None => AccountId::new_with_params(seed, code_root, storage_root, network_params),
+                Some(epoch) => {
+                    let anchor_block_num = BlockHeader::epoch_block_num(epoch);
+                    let anchor_block = if anchor_block_num == block_header.block_num() {
+                        block_header
+                    } else {
+                        block_chain.get_block(anchor_block_num).ok_or(
+                            TransactionInputError::AccountIdAnchorBlockNotInChainMmr(
+                                anchor_block_num,
+                            ),
+                        )?
+                    };
+
+                    AccountId::new_anchored_with_params(
+                        seed,
+                        AccountIdAnchor::new(epoch, anchor_block.hash()),
+                        account.id().account_type(),
+                        account.id().storage_type(),
+                        code_root,
+                        storage_root,
+                        network_params,
+                    )
+                },
+            }
+            .map_err(TransactionInputError::InvalidAccountSeed)?;
             if account_id != account.id() {
                 return Err(TransactionInputError::InconsistentAccountSeed {
                     expected: account.id(),
@@ -413,3 +945,68 @@ pub fn validate_account_seed(
         (false, None) => Ok(()),
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{build_input_notes_commitment, InputNoteCommitment, InputNotes};
+    use crate::{
+        accounts::account_id::testing::ACCOUNT_ID_OFF_CHAIN_SENDER,
+        notes::{NoteHeader, NoteId, NoteMetadata, NoteTag, NoteType, Nullifier},
+        transaction::KernelLimits,
+        Digest, Felt, Hasher, TransactionInputError, ZERO,
+    };
+
+    fn nullifier(seed: u64) -> Nullifier {
+        Nullifier::from([Felt::new(seed), ZERO, ZERO, ZERO])
+    }
+
+    #[test]
+    fn input_notes_commitment_matches_sequential_hash() {
+        let metadata = NoteMetadata::new(
+            ACCOUNT_ID_OFF_CHAIN_SENDER.try_into().unwrap(),
+            NoteType::Public,
+            NoteTag::from(0),
+            ZERO,
+        )
+        .unwrap();
+        let header = NoteHeader::new(NoteId::new(Digest::default(), Digest::default()), metadata);
+        let notes = [
+            InputNoteCommitment::new(nullifier(1), None),
+            InputNoteCommitment::new(nullifier(2), Some(header)),
+        ];
+
+        let mut elements = Vec::new();
+        elements.extend_from_slice(nullifier(1).as_elements());
+        elements.extend_from_slice(&[ZERO; 4]);
+        elements.extend_from_slice(nullifier(2).as_elements());
+        elements.extend_from_slice(header.authentication_hash().as_elements());
+
+        assert_eq!(build_input_notes_commitment(&notes), Hasher::hash_elements(&elements));
+        assert_eq!(build_input_notes_commitment::<Nullifier>(&[]), Digest::default());
+    }
+
+    #[test]
+    fn input_notes_from_iter() {
+        let input_notes = InputNotes::try_from_iter((1..4).map(nullifier)).unwrap();
+        let notes = (1..4).map(nullifier).collect::<Vec<_>>();
+        assert_eq!(input_notes.commitment(), build_input_notes_commitment(&notes));
+        assert_eq!(input_notes, InputNotes::new(notes).unwrap());
+
+        assert_eq!(
+            InputNotes::try_from_iter([nullifier(1), nullifier(2), nullifier(1)]),
+            Err(TransactionInputError::DuplicateInputNote(nullifier(1).inner()))
+        );
+
+        // the limit is enforced without draining the iterator, which is unbounded here
+        let max = KernelLimits::CURRENT.max_input_notes();
+        assert_eq!(
+            InputNotes::try_from_iter((1..).map(nullifier)),
+            Err(TransactionInputError::TooManyInputNotes { max, actual: max + 1 })
+        );
+    }
+}
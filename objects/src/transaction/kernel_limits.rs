@@ -0,0 +1,56 @@
+use crate::{
+    accounts::AccountStorage, MAX_ASSETS_PER_NOTE, MAX_INPUTS_PER_NOTE, MAX_INPUT_NOTES_PER_TX,
+    MAX_OUTPUT_NOTES_PER_TX,
+};
+
+// KERNEL LIMITS
+// ================================================================================================
+
+/// Limits enforced by the transaction kernel.
+///
+/// Transactions and notes exceeding these limits cannot be executed or proven, and thus the
+/// objects describing them are validated against the same limits when they are constructed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KernelLimits {
+    max_input_notes: usize,
+    max_output_notes: usize,
+    max_note_inputs: usize,
+    max_note_assets: usize,
+    num_storage_slots: usize,
+}
+
+impl KernelLimits {
+    /// Limits enforced by the current version of the transaction kernel.
+    pub const CURRENT: Self = Self {
+        max_input_notes: MAX_INPUT_NOTES_PER_TX,
+        max_output_notes: MAX_OUTPUT_NOTES_PER_TX,
+        max_note_inputs: MAX_INPUTS_PER_NOTE,
+        max_note_assets: MAX_ASSETS_PER_NOTE,
+        num_storage_slots: AccountStorage::NUM_STORAGE_SLOTS,
+    };
+
+    /// Returns the maximum number of notes which can be consumed by a single transaction.
+    pub const fn max_input_notes(&self) -> usize {
+        self.max_input_notes
+    }
+
+    /// Returns the maximum number of notes which can be created by a single transaction.
+    pub const fn max_output_notes(&self) -> usize {
+        self.max_output_notes
+    }
+
+    /// Returns the maximum number of inputs of a single note.
+    pub const fn max_note_inputs(&self) -> usize {
+        self.max_note_inputs
+    }
+
+    /// Returns the maximum number of assets carried by a single note.
+    pub const fn max_note_assets(&self) -> usize {
+        self.max_note_assets
+    }
+
+    /// Returns the number of storage slots of an account, including the reserved slot.
+    pub const fn num_storage_slots(&self) -> usize {
+        self.num_storage_slots
+    }
+}
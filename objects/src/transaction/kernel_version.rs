@@ -0,0 +1,103 @@
+use core::fmt::{self, Display};
+
+use vm_core::ToElements;
+
+use super::{Digest, Hasher};
+use crate::{
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    vm::ProgramInfo,
+};
+
+// KERNEL VERSION
+// ================================================================================================
+
+/// Identifies the version of the transaction kernel used to execute and prove a transaction.
+///
+/// A kernel version consists of:
+/// - The semantic version of the kernel, which is assigned by the transaction kernel library and
+///   is meant to be human-readable.
+/// - The kernel root, which commits to the kernel main program and to all kernel procedures, and
+///   thus uniquely identifies the kernel against which a transaction proof must be verified.
+///
+/// The kernel root is computed as the hash of the elements of the [ProgramInfo] of the kernel
+/// main program (see [KernelVersion::compute_root()]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct KernelVersion {
+    major: u8,
+    minor: u8,
+    patch: u8,
+    root: Digest,
+}
+
+impl KernelVersion {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [KernelVersion] instantiated from the provided semantic version and kernel
+    /// root.
+    pub const fn new(major: u8, minor: u8, patch: u8, root: Digest) -> Self {
+        Self { major, minor, patch, root }
+    }
+
+    /// Returns the kernel root for the provided [ProgramInfo] of the kernel main program.
+    pub fn compute_root(program_info: &ProgramInfo) -> Digest {
+        Hasher::hash_elements(&program_info.to_elements())
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the major version of the kernel.
+    pub fn major(&self) -> u8 {
+        self.major
+    }
+
+    /// Returns the minor version of the kernel.
+    pub fn minor(&self) -> u8 {
+        self.minor
+    }
+
+    /// Returns the patch version of the kernel.
+    pub fn patch(&self) -> u8 {
+        self.patch
+    }
+
+    /// Returns the kernel root.
+    pub fn root(&self) -> Digest {
+        self.root
+    }
+
+    /// Returns true if the kernel root of this version matches the provided [ProgramInfo].
+    pub fn matches(&self, program_info: &ProgramInfo) -> bool {
+        self.root == Self::compute_root(program_info)
+    }
+}
+
+impl Display for KernelVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{} ({})", self.major, self.minor, self.patch, self.root.to_hex())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for KernelVersion {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.major);
+        target.write_u8(self.minor);
+        target.write_u8(self.patch);
+        self.root.write_into(target);
+    }
+}
+
+impl Deserializable for KernelVersion {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let major = source.read_u8()?;
+        let minor = source.read_u8()?;
+        let patch = source.read_u8()?;
+        let root = Digest::read_from(source)?;
+
+        Ok(Self::new(major, minor, patch, root))
+    }
+}
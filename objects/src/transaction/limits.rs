@@ -0,0 +1,80 @@
+use super::ProvenTransaction;
+use crate::{
+    utils::SizeHint, ProvenTransactionError, MAX_ACCOUNT_UPDATE_SIZE, MAX_NOTE_PAYLOAD_SIZE,
+};
+
+// PROTOCOL LIMITS
+// ================================================================================================
+
+/// Limits on the size of the data published by a transaction.
+///
+/// The limits are expressed in bytes of the serialized representation of the data (see
+/// [SizeHint]), and thus, whether a transaction is within the limits does not depend on the
+/// environment in which it is checked. The limits apply to:
+/// - The payload of every output note, i.e., the full note for public notes and the note header
+///   for private notes.
+/// - The account update, i.e., the full account state for new on-chain accounts and the account
+///   delta for existing on-chain accounts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtocolLimits {
+    max_note_payload_size: usize,
+    max_account_update_size: usize,
+}
+
+impl ProtocolLimits {
+    /// Returns new [ProtocolLimits] instantiated with the specified maximum sizes (in bytes).
+    pub const fn new(max_note_payload_size: usize, max_account_update_size: usize) -> Self {
+        Self {
+            max_note_payload_size,
+            max_account_update_size,
+        }
+    }
+
+    /// Returns the maximum size (in bytes) of the payload of a single output note.
+    pub fn max_note_payload_size(&self) -> usize {
+        self.max_note_payload_size
+    }
+
+    /// Returns the maximum size (in bytes) of the account update of a single transaction.
+    pub fn max_account_update_size(&self) -> usize {
+        self.max_account_update_size
+    }
+
+    /// Checks that the provided transaction is within these limits.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The payload of any of the output notes is larger than the maximum note payload size.
+    /// - The account update is larger than the maximum account update size.
+    pub fn validate(&self, transaction: &ProvenTransaction) -> Result<(), ProvenTransactionError> {
+        for note in transaction.output_notes().iter() {
+            let size = note.size_hint();
+            if size > self.max_note_payload_size {
+                return Err(ProvenTransactionError::NotePayloadTooLarge {
+                    note_id: note.id(),
+                    max: self.max_note_payload_size,
+                    actual: size,
+                });
+            }
+        }
+
+        let size = transaction.account_update().size_hint();
+        if size > self.max_account_update_size {
+            return Err(ProvenTransactionError::AccountUpdateTooLarge {
+                account_id: transaction.account_id(),
+                max: self.max_account_update_size,
+                actual: size,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for ProtocolLimits {
+    /// Returns [ProtocolLimits] with the maximum sizes defined by [MAX_NOTE_PAYLOAD_SIZE] and
+    /// [MAX_ACCOUNT_UPDATE_SIZE].
+    fn default() -> Self {
+        Self::new(MAX_NOTE_PAYLOAD_SIZE, MAX_ACCOUNT_UPDATE_SIZE)
+    }
+}
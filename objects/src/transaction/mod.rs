@@ -1,26 +1,42 @@
 use super::{
     accounts::{Account, AccountDelta, AccountId, AccountStub},
-    notes::Nullifier,
     vm::{AdviceInputs, Program},
     BlockHeader, Digest, Felt, Hasher, Word, WORD_SIZE, ZERO,
 };
 
+mod advice_inputs;
 mod chain_mmr;
 mod executed_tx;
+mod executed_tx_summary;
 mod inputs;
+mod kernel_limits;
+mod kernel_version;
+mod limits;
 mod outputs;
 mod prepared_tx;
 mod proven_tx;
 mod transaction_id;
 mod tx_args;
+mod tx_summary;
 mod tx_witness;
+mod validity;
 
+pub use advice_inputs::{AdviceInputsBuilder, AdviceInputsSize};
 pub use chain_mmr::ChainMmr;
 pub use executed_tx::ExecutedTransaction;
-pub use inputs::{InputNote, InputNotes, ToNullifier, TransactionInputs};
+pub use executed_tx_summary::{CreatedNoteSummary, ExecutedTransactionSummary};
+pub(crate) use inputs::build_input_notes_commitment;
+pub use inputs::{
+    InputNote, InputNoteCommitment, InputNotes, ToInputNoteCommitments, TransactionInputs,
+};
+pub use kernel_limits::KernelLimits;
+pub use kernel_version::KernelVersion;
+pub use limits::ProtocolLimits;
 pub use outputs::{OutputNote, OutputNotes, TransactionOutputs};
 pub use prepared_tx::PreparedTransaction;
 pub use proven_tx::{ProvenTransaction, ProvenTransactionBuilder, TxAccountUpdate};
 pub use transaction_id::TransactionId;
 pub use tx_args::{TransactionArgs, TransactionScript};
+pub use tx_summary::{StorageChange, TransactionSummary, VaultChange};
 pub use tx_witness::TransactionWitness;
+pub use validity::{ChainState, TransactionProofVerifier};
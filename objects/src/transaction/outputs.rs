@@ -6,8 +6,9 @@ use vm_processor::DeserializationError;
 
 use crate::{
     accounts::AccountStub,
-    notes::{Note, NoteAssets, NoteHeader, NoteId, NoteMetadata},
-    Digest, Felt, Hasher, TransactionOutputError, Word, MAX_OUTPUT_NOTES_PER_TX,
+    notes::{Note, NoteAssets, NoteHeader, NoteId, NoteMetadata, PartialNote},
+    transaction::KernelLimits,
+    Digest, Felt, Hasher, TransactionOutputError, Word,
 };
 
 // TRANSACTION OUTPUTS
@@ -15,6 +16,7 @@ use crate::{
 
 /// Describes the result of executing a transaction.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct TransactionOutputs {
     pub account: AccountStub,
     pub output_notes: OutputNotes,
@@ -38,21 +40,27 @@ impl OutputNotes {
     ///
     /// # Errors
     /// Returns an error if:
-    /// - The total number of notes is greater than 1024.
+    /// - The total number of notes is greater than [KernelLimits::max_output_notes()].
     /// - The vector of notes contains duplicates.
+    /// - Any two notes with known details have the same serial number.
     pub fn new(notes: Vec<OutputNote>) -> Result<Self, TransactionOutputError> {
-        if notes.len() > MAX_OUTPUT_NOTES_PER_TX {
-            return Err(TransactionOutputError::TooManyOutputNotes {
-                max: MAX_OUTPUT_NOTES_PER_TX,
-                actual: notes.len(),
-            });
+        let max = KernelLimits::CURRENT.max_output_notes();
+        if notes.len() > max {
+            return Err(TransactionOutputError::TooManyOutputNotes { max, actual: notes.len() });
         }
 
         let mut seen_notes = BTreeSet::new();
+        let mut seen_serial_nums = BTreeSet::new();
         for note in notes.iter() {
             if !seen_notes.insert(note.id()) {
                 return Err(TransactionOutputError::DuplicateOutputNote(note.id()));
             }
+            if let OutputNote::Full(note) = note {
+                let serial_num = Digest::from(note.serial_num());
+                if !seen_serial_nums.insert(serial_num) {
+                    return Err(TransactionOutputError::DuplicateSerialNumber(serial_num.into()));
+                }
+            }
         }
 
         let commitment = build_output_notes_commitment(&notes);
@@ -114,6 +122,23 @@ impl Deserializable for OutputNotes {
     }
 }
 
+/// Output notes are serialized as a list of notes; the commitment is recomputed on
+/// deserialization.
+#[cfg(feature = "serde")]
+impl serde::Serialize for OutputNotes {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.notes.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for OutputNotes {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let notes = <Vec<OutputNote> as serde::Deserialize>::deserialize(deserializer)?;
+        Self::new(notes).map_err(serde::de::Error::custom)
+    }
+}
+
 // HELPER FUNCTIONS
 // ------------------------------------------------------------------------------------------------
 
@@ -143,11 +168,19 @@ fn build_output_notes_commitment(notes: &[OutputNote]) -> Digest {
 
 const FULL: u8 = 0;
 const HEADER: u8 = 1;
+const PARTIAL: u8 = 2;
 
 /// The types of note outputs supported by the transaction kernel.
+///
+/// - Full notes are notes for which all details are known.
+/// - Partial notes are notes for which the assets are known, but only the digest of the recipient
+///   is known, e.g., notes sent to a recipient communicated as a single digest.
+/// - Headers are notes for which only the ID and the metadata are known.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum OutputNote {
     Full(Note),
+    Partial(PartialNote),
     Header(NoteHeader),
 }
 
@@ -156,6 +189,7 @@ impl OutputNote {
     pub fn assets(&self) -> Option<&NoteAssets> {
         match self {
             OutputNote::Full(note) => Some(note.assets()),
+            OutputNote::Partial(note) => Some(note.assets()),
             OutputNote::Header(_) => None,
         }
     }
@@ -166,6 +200,7 @@ impl OutputNote {
     pub fn id(&self) -> NoteId {
         match self {
             OutputNote::Full(note) => note.id(),
+            OutputNote::Partial(note) => note.id(),
             OutputNote::Header(note) => note.id(),
         }
     }
@@ -176,6 +211,7 @@ impl OutputNote {
     pub fn recipient_digest(&self) -> Option<Digest> {
         match self {
             OutputNote::Full(note) => Some(note.recipient().digest()),
+            OutputNote::Partial(note) => Some(note.recipient_digest()),
             OutputNote::Header(_) => None,
         }
     }
@@ -184,6 +220,7 @@ impl OutputNote {
     pub fn metadata(&self) -> &NoteMetadata {
         match self {
             OutputNote::Full(note) => note.metadata(),
+            OutputNote::Partial(note) => note.metadata(),
             OutputNote::Header(note) => note.metadata(),
         }
     }
@@ -194,6 +231,8 @@ impl OutputNote {
             OutputNote::Full(note) if note.metadata().is_offchain() => {
                 OutputNote::Header(*note.header())
             },
+            // partial notes cannot be public since their details are not known
+            OutputNote::Partial(note) => OutputNote::Header(note.into()),
             _ => self.clone(),
         }
     }
@@ -212,6 +251,7 @@ impl From<&OutputNote> for NoteHeader {
     fn from(value: &OutputNote) -> Self {
         match value {
             OutputNote::Full(note) => note.into(),
+            OutputNote::Partial(note) => note.into(),
             OutputNote::Header(note) => *note,
         }
     }
@@ -227,6 +267,10 @@ impl Serializable for OutputNote {
                 target.write(FULL);
                 target.write(note);
             },
+            OutputNote::Partial(note) => {
+                target.write(PARTIAL);
+                target.write(note);
+            },
             OutputNote::Header(note) => {
                 target.write(HEADER);
                 target.write(note);
@@ -239,6 +283,7 @@ impl Deserializable for OutputNote {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         match source.read_u8()? {
             FULL => Ok(OutputNote::Full(Note::read_from(source)?)),
+            PARTIAL => Ok(OutputNote::Partial(PartialNote::read_from(source)?)),
             HEADER => Ok(OutputNote::Header(NoteHeader::read_from(source)?)),
             v => Err(DeserializationError::InvalidValue(format!("Invalid note type: {v}"))),
         }
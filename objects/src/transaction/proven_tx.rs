@@ -1,12 +1,25 @@
 use alloc::{string::ToString, vec::Vec};
+use core::cmp::Ordering;
 
 use miden_verifier::ExecutionProof;
+use winter_air::{
+    proof::{Context, StarkProof},
+    FieldExtension, ProofOptions, TraceInfo, TraceLayout,
+};
 
-use super::{AccountId, Digest, InputNotes, Nullifier, OutputNote, OutputNotes, TransactionId};
+use super::{
+    AccountId, Digest, InputNoteCommitment, InputNotes, KernelLimits, KernelVersion, OutputNote,
+    OutputNotes, TransactionId,
+};
 use crate::{
     accounts::delta::AccountUpdateDetails,
-    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    ProvenTransactionError,
+    compression::{read_compressed, write_compressed},
+    notes::NoteHeader,
+    utils::serde::{
+        ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
+    },
+    Felt, ProvenTransactionError, StarkField, MAX_ACCOUNT_UPDATE_SIZE, MAX_NOTE_PAYLOAD_SIZE,
+    MAX_OUTPUT_NOTES_PER_TX,
 };
 
 // PROVEN TRANSACTION
@@ -14,6 +27,8 @@ use crate::{
 
 /// Result of executing and proving a transaction. Contains all the data required to verify that a
 /// transaction was executed correctly.
+///
+/// Proven transactions are ordered by their IDs.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ProvenTransaction {
     /// A unique identifier for the transaction, see [TransactionId] for additional details.
@@ -22,8 +37,9 @@ pub struct ProvenTransaction {
     /// Account update data.
     account_update: TxAccountUpdate,
 
-    /// A list of nullifiers for all notes consumed by the transaction.
-    input_notes: InputNotes<Nullifier>,
+    /// Committed details of all notes consumed by the transaction: the nullifiers of all notes, and
+    /// the headers of unauthenticated notes.
+    input_notes: InputNotes<InputNoteCommitment>,
 
     /// Notes created by the transaction. For private notes, this will contain only note headers,
     /// while for public notes this will also contain full note details.
@@ -32,6 +48,9 @@ pub struct ProvenTransaction {
     /// The block hash of the last known block at the time the transaction was executed.
     block_ref: Digest,
 
+    /// The version of the transaction kernel used to execute and prove the transaction.
+    kernel_version: KernelVersion,
+
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
 }
@@ -53,10 +72,20 @@ impl ProvenTransaction {
     }
 
     /// Returns a reference to the notes consumed by the transaction.
-    pub fn input_notes(&self) -> &InputNotes<Nullifier> {
+    pub fn input_notes(&self) -> &InputNotes<InputNoteCommitment> {
         &self.input_notes
     }
 
+    /// Returns an iterator over the headers of the unauthenticated notes consumed by the
+    /// transaction.
+    ///
+    /// The existence of these notes in the chain was not verified by the transaction kernel, and
+    /// thus the block producer must verify that each of them was created either in a previous
+    /// block, or by another transaction in the same block.
+    pub fn unauthenticated_notes(&self) -> impl Iterator<Item = &NoteHeader> {
+        self.input_notes.iter().filter_map(InputNoteCommitment::header)
+    }
+
     /// Returns a reference to the notes produced by the transaction.
     pub fn output_notes(&self) -> &OutputNotes {
         &self.output_notes
@@ -72,6 +101,11 @@ impl ProvenTransaction {
         self.block_ref
     }
 
+    /// Returns the version of the transaction kernel the transaction was executed and proven with.
+    pub fn kernel_version(&self) -> KernelVersion {
+        self.kernel_version
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -79,7 +113,7 @@ impl ProvenTransaction {
         if self.account_id().is_on_chain() {
             let is_new_account = self.account_update.init_state_hash() == Digest::default();
             match self.account_update.details() {
-                AccountUpdateDetails::Private => {
+                AccountUpdateDetails::Private | AccountUpdateDetails::PrivateSalted(_) => {
                     return Err(ProvenTransactionError::OnChainAccountMissingDetails(
                         self.account_id(),
                     ))
@@ -93,24 +127,33 @@ impl ProvenTransaction {
                         );
                     }
                     if account.id() != self.account_id() {
-                        return Err(ProvenTransactionError::AccountIdMismatch(
-                            self.account_id(),
-                            account.id(),
-                        ));
+                        return Err(ProvenTransactionError::AccountIdMismatch {
+                            tx_account_id: self.account_id(),
+                            details_account_id: account.id(),
+                        });
                     }
                     if account.hash() != self.account_update.final_state_hash() {
-                        return Err(ProvenTransactionError::AccountFinalHashMismatch(
-                            self.account_update.final_state_hash(),
-                            account.hash(),
-                        ));
+                        return Err(ProvenTransactionError::AccountFinalHashMismatch {
+                            tx_final_hash: self.account_update.final_state_hash(),
+                            details_hash: account.hash(),
+                        });
                     }
                 },
-                AccountUpdateDetails::Delta(_) => {
+                AccountUpdateDetails::Delta(ref delta) => {
                     if is_new_account {
                         return Err(ProvenTransactionError::NewOnChainAccountRequiresFullDetails(
                             self.account_id(),
                         ));
                     }
+                    // the account state (and thus its hash) changes if and only if the nonce
+                    // is updated by the delta
+                    let is_state_changed = self.account_update.init_state_hash()
+                        != self.account_update.final_state_hash();
+                    if delta.nonce().is_some() != is_state_changed {
+                        return Err(ProvenTransactionError::AccountDeltaFinalHashMismatch(
+                            self.account_id(),
+                        ));
+                    }
                 },
             }
         } else if !self.account_update.is_private() {
@@ -121,31 +164,51 @@ impl ProvenTransaction {
     }
 }
 
+impl PartialOrd for ProvenTransaction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ProvenTransaction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
 impl Serializable for ProvenTransaction {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        self.account_update.write_into(target);
+        // account updates of on-chain accounts and details of public notes make up most of the
+        // size of a proven transaction, and thus are compressed; input notes consist of hashes
+        // which cannot be compressed. Output notes are compressed one by one, so that the size of
+        // every decompressed note can be bounded while reading.
+        write_compressed(target, &self.account_update.to_bytes());
         self.input_notes.write_into(target);
-        self.output_notes.write_into(target);
+        write_compressed_output_notes(target, &self.output_notes);
         self.block_ref.write_into(target);
+        self.kernel_version.write_into(target);
         self.proof.write_into(target);
     }
 }
 
 impl Deserializable for ProvenTransaction {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let account_update = TxAccountUpdate::read_from(source)?;
+        let account_update =
+            read_compressed_payload::<TxAccountUpdate, _>(source, MAX_ACCOUNT_UPDATE_SIZE)?;
 
-        let input_notes = InputNotes::<Nullifier>::read_from(source)?;
-        let output_notes = OutputNotes::read_from(source)?;
+        let input_notes = InputNotes::<InputNoteCommitment>::read_from(source)?;
+        let output_notes = read_compressed_output_notes(source)?;
 
         let block_ref = Digest::read_from(source)?;
-        let proof = ExecutionProof::read_from(source)?;
+        let kernel_version = KernelVersion::read_from(source)?;
+        let proof = read_execution_proof(source)?;
 
         let id = TransactionId::new(
             account_update.init_state_hash(),
             account_update.final_state_hash(),
             input_notes.commitment(),
             output_notes.commitment(),
+            block_ref,
         );
 
         let proven_transaction = Self {
@@ -154,6 +217,7 @@ impl Deserializable for ProvenTransaction {
             input_notes,
             output_notes,
             block_ref,
+            kernel_version,
             proof,
         };
 
@@ -181,8 +245,8 @@ pub struct ProvenTransactionBuilder {
     /// State changes to the account due to the transaction.
     account_update_details: AccountUpdateDetails,
 
-    /// List of [Nullifier]s of all consumed notes by the transaction.
-    input_notes: Vec<Nullifier>,
+    /// List of [InputNoteCommitment]s of all notes consumed by the transaction.
+    input_notes: Vec<InputNoteCommitment>,
 
     /// List of [OutputNote]s of all notes created by the transaction.
     output_notes: Vec<OutputNote>,
@@ -190,6 +254,9 @@ pub struct ProvenTransactionBuilder {
     /// Block [Digest] of the transaction's reference block.
     block_ref: Digest,
 
+    /// Version of the transaction kernel used to execute and prove the transaction.
+    kernel_version: KernelVersion,
+
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
 }
@@ -204,6 +271,7 @@ impl ProvenTransactionBuilder {
         initial_account_hash: Digest,
         final_account_hash: Digest,
         block_ref: Digest,
+        kernel_version: KernelVersion,
         proof: ExecutionProof,
     ) -> Self {
         Self {
@@ -214,6 +282,7 @@ impl ProvenTransactionBuilder {
             input_notes: Vec::new(),
             output_notes: Vec::new(),
             block_ref,
+            kernel_version,
             proof,
         }
     }
@@ -228,11 +297,14 @@ impl ProvenTransactionBuilder {
     }
 
     /// Add notes consumed by the transaction.
-    pub fn add_input_notes<T>(mut self, notes: T) -> Self
+    ///
+    /// Authenticated notes can be specified by their nullifiers.
+    pub fn add_input_notes<I, T>(mut self, notes: I) -> Self
     where
-        T: IntoIterator<Item = Nullifier>,
+        I: IntoIterator<Item = T>,
+        T: Into<InputNoteCommitment>,
     {
-        self.input_notes.extend(notes);
+        self.input_notes.extend(notes.into_iter().map(Into::into));
         self
     }
 
@@ -248,10 +320,37 @@ impl ProvenTransactionBuilder {
     /// Builds the [ProvenTransaction].
     ///
     /// # Errors
-    ///
-    /// An error will be returned if an on-chain account is used without provided on-chain detail.
-    /// Or if the account details, i.e. account id and final hash, don't match the transaction.
+    /// Returns an error if:
+    /// - The number of input notes is greater than [KernelLimits::max_input_notes()].
+    /// - The number of output notes is greater than [KernelLimits::max_output_notes()].
+    /// - An input note nullifier is present more than once.
+    /// - An on-chain account is used without provided on-chain details, or an off-chain account is
+    ///   used with on-chain details.
+    /// - The account details, i.e. account id and final hash, don't match the transaction.
+    /// - The account delta of an existing on-chain account is inconsistent with the change of the
+    ///   account hash.
     pub fn build(self) -> Result<ProvenTransaction, ProvenTransactionError> {
+        let limits = KernelLimits::CURRENT;
+        if self.input_notes.len() > limits.max_input_notes() {
+            return Err(ProvenTransactionError::TooManyInputNotes {
+                max: limits.max_input_notes(),
+                actual: self.input_notes.len(),
+            });
+        }
+
+        if self.output_notes.len() > limits.max_output_notes() {
+            return Err(ProvenTransactionError::TooManyOutputNotes {
+                max: limits.max_output_notes(),
+                actual: self.output_notes.len(),
+            });
+        }
+
+        for (pos, note) in self.input_notes.iter().enumerate() {
+            if self.input_notes[..pos].iter().any(|x| x.nullifier() == note.nullifier()) {
+                return Err(ProvenTransactionError::DuplicateInputNoteNullifier(note.nullifier()));
+            }
+        }
+
         let input_notes =
             InputNotes::new(self.input_notes).map_err(ProvenTransactionError::InputNotesError)?;
         let output_notes = OutputNotes::new(self.output_notes)
@@ -261,6 +360,7 @@ impl ProvenTransactionBuilder {
             self.final_account_hash,
             input_notes.commitment(),
             output_notes.commitment(),
+            self.block_ref,
         );
         let account_update = TxAccountUpdate::new(
             self.account_id,
@@ -275,6 +375,7 @@ impl ProvenTransactionBuilder {
             input_notes,
             output_notes,
             block_ref: self.block_ref,
+            kernel_version: self.kernel_version,
             proof: self.proof,
         };
 
@@ -301,7 +402,8 @@ pub struct TxAccountUpdate {
 
     /// A set of changes which can be applied the the account's state prior to the transaction to
     /// get the account state after the transaction. For private accounts this is set to
-    /// [AccountUpdateDetails::Private].
+    /// [AccountUpdateDetails::Private], or to [AccountUpdateDetails::PrivateSalted] if the final
+    /// state hash is salted.
     details: AccountUpdateDetails,
 }
 
@@ -370,12 +472,206 @@ impl Deserializable for TxAccountUpdate {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Serializes the provided output notes as the number of notes followed by the compressed
+/// payload of every note.
+fn write_compressed_output_notes<W: ByteWriter>(target: &mut W, output_notes: &OutputNotes) {
+    // assert is OK here because the number of notes is bounded by the constructor of OutputNotes
+    assert!(output_notes.num_notes() <= u16::MAX.into());
+    target.write_u16(output_notes.num_notes() as u16);
+    for note in output_notes.iter() {
+        write_compressed(target, &note.to_bytes());
+    }
+}
+
+/// Deserializes output notes written by [write_compressed_output_notes()].
+///
+/// The payload of every note is bounded by [MAX_NOTE_PAYLOAD_SIZE], and thus, the notes are
+/// decompressed one at a time without allocating more than the bound for any of them.
+fn read_compressed_output_notes<R: ByteReader>(
+    source: &mut R,
+) -> Result<OutputNotes, DeserializationError> {
+    let num_notes = source.read_u16()? as usize;
+    if num_notes > MAX_OUTPUT_NOTES_PER_TX {
+        return Err(DeserializationError::InvalidValue(format!(
+            "number of output notes {num_notes} exceeds the maximum of {MAX_OUTPUT_NOTES_PER_TX}"
+        )));
+    }
+
+    let notes = (0..num_notes)
+        .map(|_| read_compressed_payload::<OutputNote, _>(source, MAX_NOTE_PAYLOAD_SIZE))
+        .collect::<Result<Vec<_>, _>>()?;
+    OutputNotes::new(notes).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+}
+
+/// Deserializes an object from a payload compressed with [write_compressed()], making sure that
+/// the decompressed payload is not larger than `max_len` bytes and does not contain any trailing
+/// bytes.
+fn read_compressed_payload<D: Deserializable, R: ByteReader>(
+    source: &mut R,
+    max_len: usize,
+) -> Result<D, DeserializationError> {
+    let payload = read_compressed(source, max_len)?;
+    let mut reader = SliceReader::new(&payload);
+    let value = D::read_from(&mut reader)?;
+    if reader.has_more_bytes() {
+        return Err(DeserializationError::InvalidValue(
+            "compressed payload contains trailing bytes".to_string(),
+        ));
+    }
+
+    Ok(value)
+}
+
+/// Deserializes an [ExecutionProof] from the provided source.
+///
+/// This mirrors [ExecutionProof::read_from()], but validates the proof context before
+/// instantiating it, so that malformed proof parameters result in an error rather than a panic.
+fn read_execution_proof<R: ByteReader>(
+    source: &mut R,
+) -> Result<ExecutionProof, DeserializationError> {
+    let context = read_proof_context(source)?;
+    let num_unique_queries = source.read_u8()?;
+    let commitments = source.read()?;
+    let trace_queries = (0..context.trace_layout().num_segments())
+        .map(|_| source.read())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let proof = StarkProof {
+        context,
+        num_unique_queries,
+        commitments,
+        trace_queries,
+        constraint_queries: source.read()?,
+        ood_frame: source.read()?,
+        fri_proof: source.read()?,
+        pow_nonce: source.read_u64()?,
+    };
+    let hash_fn = source.read()?;
+
+    Ok(ExecutionProof::new(proof, hash_fn))
+}
+
+/// Deserializes the [Context] of a STARK proof generated over the base field of the Miden VM.
+fn read_proof_context<R: ByteReader>(source: &mut R) -> Result<Context, DeserializationError> {
+    let trace_layout: TraceLayout = source.read()?;
+
+    // the trace length is stored as a power of two; the trace length must fit into a u32 value
+    let trace_length_log2 = source.read_u8()? as u32;
+    if !(TraceInfo::MIN_TRACE_LENGTH.ilog2()..=u32::MAX.ilog2()).contains(&trace_length_log2) {
+        return Err(DeserializationError::InvalidValue(format!(
+            "invalid trace length 2^{trace_length_log2}"
+        )));
+    }
+    let trace_length = 1_usize << trace_length_log2;
+
+    let num_meta_bytes = source.read_u16()? as usize;
+    let trace_meta = source.read_vec(num_meta_bytes)?;
+
+    let num_modulus_bytes = source.read_u8()? as usize;
+    let field_modulus_bytes = source.read_vec(num_modulus_bytes)?;
+    if field_modulus_bytes != Felt::get_modulus_le_bytes() {
+        return Err(DeserializationError::InvalidValue(
+            "proof was not generated over the base field of the Miden VM".to_string(),
+        ));
+    }
+
+    let options = read_proof_options(source)?;
+    if trace_length as u64 * options.blowup_factor() as u64 > u32::MAX as u64 {
+        return Err(DeserializationError::InvalidValue(format!(
+            "LDE domain size of 2^{trace_length_log2} * {} is too big",
+            options.blowup_factor()
+        )));
+    }
+
+    let trace_info = TraceInfo::new_multi_segment(trace_layout, trace_length, trace_meta);
+    Ok(Context::new::<Felt>(&trace_info, options))
+}
+
+/// Deserializes [ProofOptions] from the provided source, returning an error (rather than
+/// panicking) if the options are invalid.
+fn read_proof_options<R: ByteReader>(source: &mut R) -> Result<ProofOptions, DeserializationError> {
+    let num_queries = source.read_u8()? as usize;
+    let blowup_factor = source.read_u8()? as usize;
+    let grinding_factor = source.read_u8()? as u32;
+    let field_extension: FieldExtension = source.read()?;
+    let fri_folding_factor = source.read_u8()? as usize;
+    let fri_remainder_max_degree = source.read_u8()? as usize;
+
+    if num_queries == 0 {
+        return Err(DeserializationError::InvalidValue(
+            "number of queries must be greater than 0".to_string(),
+        ));
+    }
+    if !blowup_factor.is_power_of_two()
+        || !(ProofOptions::MIN_BLOWUP_FACTOR..=128).contains(&blowup_factor)
+    {
+        return Err(DeserializationError::InvalidValue(format!(
+            "invalid blowup factor {blowup_factor}"
+        )));
+    }
+    if grinding_factor > 32 {
+        return Err(DeserializationError::InvalidValue(format!(
+            "invalid grinding factor {grinding_factor}"
+        )));
+    }
+    if !fri_folding_factor.is_power_of_two() || !(2..=16).contains(&fri_folding_factor) {
+        return Err(DeserializationError::InvalidValue(format!(
+            "invalid FRI folding factor {fri_folding_factor}"
+        )));
+    }
+    if !(fri_remainder_max_degree + 1).is_power_of_two() {
+        return Err(DeserializationError::InvalidValue(format!(
+            "invalid FRI remainder max degree {fri_remainder_max_degree}"
+        )));
+    }
+
+    Ok(ProofOptions::new(
+        num_queries,
+        blowup_factor,
+        grinding_factor,
+        field_extension,
+        fri_folding_factor,
+        fri_remainder_max_degree,
+    ))
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
-    use super::ProvenTransaction;
+    use alloc::vec::Vec;
+
+    use miden_verifier::ExecutionProof;
+    use winter_air::proof::StarkProof;
+
+    use super::{
+        read_compressed_output_notes, read_compressed_payload, write_compressed_output_notes,
+        OutputNotes, ProvenTransaction, ProvenTransactionBuilder, TxAccountUpdate,
+    };
+    use crate::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_OFF_CHAIN_SENDER, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            },
+            delta::{AccountStorageDeltaBuilder, AccountUpdateDetails},
+            salt_account_hash,
+            testing::build_account,
+            AccountDelta, AccountId, AccountVaultDelta,
+        },
+        compression::write_compressed,
+        notes::{NoteHeader, NoteId, NoteMetadata, NoteTag, NoteType, Nullifier},
+        transaction::{KernelVersion, OutputNote, ProtocolLimits},
+        utils::{
+            serde::{ByteWriter, Deserializable, Serializable, SliceReader},
+            SizeHint,
+        },
+        Digest, Felt, ProvenTransactionError, EMPTY_WORD, MAX_ACCOUNT_UPDATE_SIZE,
+        MAX_INPUT_NOTES_PER_TX, MAX_NOTE_PAYLOAD_SIZE, MAX_OUTPUT_NOTES_PER_TX, ONE, ZERO,
+    };
 
     fn check_if_sync<T: Sync>() {}
     fn check_if_send<T: Send>() {}
@@ -393,4 +689,291 @@ mod tests {
     fn proven_transaction_is_send() {
         check_if_send::<ProvenTransaction>();
     }
+
+    #[test]
+    fn proven_transaction_builder_validates_notes() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let nullifier = |i: u64| Nullifier::from(Digest::from([Felt::new(i), ZERO, ZERO, ZERO]));
+
+        // duplicate nullifiers
+        let result = builder(account_id, initial_hash(), final_hash())
+            .add_input_notes([nullifier(1), nullifier(2), nullifier(1)])
+            .build();
+        assert_eq!(result, Err(ProvenTransactionError::DuplicateInputNoteNullifier(nullifier(1))));
+
+        // too many input notes
+        let result = builder(account_id, initial_hash(), final_hash())
+            .add_input_notes((0..=MAX_INPUT_NOTES_PER_TX as u64).map(nullifier))
+            .build();
+        assert_eq!(
+            result,
+            Err(ProvenTransactionError::TooManyInputNotes {
+                max: MAX_INPUT_NOTES_PER_TX,
+                actual: MAX_INPUT_NOTES_PER_TX + 1
+            })
+        );
+
+        // too many output notes
+        let metadata = NoteMetadata::new(
+            account_id,
+            NoteType::OffChain,
+            NoteTag::for_local_use_case(0, 0).unwrap(),
+            ZERO,
+        )
+        .unwrap();
+        let output_notes = (0..=MAX_OUTPUT_NOTES_PER_TX as u64).map(|i| {
+            let note_id = NoteId::from(Digest::from([Felt::new(i), ZERO, ZERO, ZERO]));
+            OutputNote::Header(NoteHeader::new(note_id, metadata))
+        });
+        let result = builder(account_id, initial_hash(), final_hash())
+            .add_output_notes(output_notes)
+            .build();
+        assert_eq!(
+            result,
+            Err(ProvenTransactionError::TooManyOutputNotes {
+                max: MAX_OUTPUT_NOTES_PER_TX,
+                actual: MAX_OUTPUT_NOTES_PER_TX + 1
+            })
+        );
+    }
+
+    #[test]
+    fn proven_transaction_builder_validates_account_delta() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let storage_delta = AccountStorageDeltaBuilder::new()
+            .add_updated_items([(0_u8, [ONE, ONE, ONE, ONE])])
+            .build()
+            .unwrap();
+        let delta =
+            AccountDelta::new(storage_delta, AccountVaultDelta::empty(), Some(Felt::new(2)))
+                .unwrap();
+
+        // a delta which updates the account changes the account hash
+        let result = builder(account_id, initial_hash(), final_hash())
+            .account_update_details(AccountUpdateDetails::Delta(delta.clone()))
+            .build();
+        assert!(result.is_ok());
+
+        let result = builder(account_id, initial_hash(), initial_hash())
+            .account_update_details(AccountUpdateDetails::Delta(delta))
+            .build();
+        assert_eq!(result, Err(ProvenTransactionError::AccountDeltaFinalHashMismatch(account_id)));
+
+        // an empty delta does not change the account hash
+        let result = builder(account_id, initial_hash(), initial_hash())
+            .account_update_details(AccountUpdateDetails::Delta(AccountDelta::default()))
+            .build();
+        assert!(result.is_ok());
+
+        let result = builder(account_id, initial_hash(), final_hash())
+            .account_update_details(AccountUpdateDetails::Delta(AccountDelta::default()))
+            .build();
+        assert_eq!(result, Err(ProvenTransactionError::AccountDeltaFinalHashMismatch(account_id)));
+    }
+
+    #[test]
+    fn proven_transaction_id_commits_to_block_ref() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let tx = builder(account_id, initial_hash(), final_hash()).build().unwrap();
+        let other_tx = ProvenTransactionBuilder::new(
+            account_id,
+            initial_hash(),
+            final_hash(),
+            Digest::from([ONE, ZERO, ZERO, ZERO]),
+            KernelVersion::new(0, 1, 0, Digest::default()),
+            dummy_proof(),
+        )
+        .build()
+        .unwrap();
+        assert_ne!(tx.id(), other_tx.id());
+
+        // transactions are ordered by their IDs
+        let mut txs = vec![tx.clone(), other_tx.clone()];
+        txs.sort();
+        assert!(txs[0].id() < txs[1].id());
+        assert_eq!(tx.cmp(&other_tx), tx.id().cmp(&other_tx.id()));
+    }
+
+    #[test]
+    fn proven_transaction_builder_validates_salted_account_update() {
+        let salt = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+
+        // salted commitments can be recorded for off-chain accounts
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let final_commitment = salt_account_hash(final_hash(), Some(salt));
+        let tx = builder(account_id, initial_hash(), final_commitment)
+            .account_update_details(AccountUpdateDetails::PrivateSalted(salt))
+            .build()
+            .unwrap();
+        assert!(tx.account_update().is_private());
+        assert_eq!(tx.account_update().details().account_salt(), Some(salt));
+        let account_update = tx.account_update();
+        assert_eq!(
+            TxAccountUpdate::read_from_bytes(&account_update.to_bytes()).unwrap(),
+            *account_update
+        );
+
+        // on-chain accounts must provide their public details
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let result = builder(account_id, initial_hash(), final_commitment)
+            .account_update_details(AccountUpdateDetails::PrivateSalted(salt))
+            .build();
+        assert_eq!(result, Err(ProvenTransactionError::OnChainAccountMissingDetails(account_id)));
+
+        // an empty salt leaves the account hash unchanged
+        assert_eq!(salt_account_hash(final_hash(), Some(EMPTY_WORD)), final_hash());
+        assert_eq!(salt_account_hash(final_hash(), None), final_hash());
+    }
+
+    #[test]
+    fn protocol_limits() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let storage_delta = AccountStorageDeltaBuilder::new()
+            .add_updated_items([(0_u8, [ONE, ONE, ONE, ONE])])
+            .build()
+            .unwrap();
+        let delta =
+            AccountDelta::new(storage_delta, AccountVaultDelta::empty(), Some(Felt::new(2)))
+                .unwrap();
+        let metadata = NoteMetadata::new(
+            account_id,
+            NoteType::OffChain,
+            NoteTag::for_local_use_case(0, 0).unwrap(),
+            ZERO,
+        )
+        .unwrap();
+        let note = OutputNote::Header(NoteHeader::new(NoteId::from(final_hash()), metadata));
+
+        let tx = builder(account_id, initial_hash(), final_hash())
+            .account_update_details(AccountUpdateDetails::Delta(delta))
+            .add_output_notes([note.clone()])
+            .build()
+            .unwrap();
+        let note_size = note.size_hint();
+        let update_size = tx.account_update().size_hint();
+
+        assert!(ProtocolLimits::default().validate(&tx).is_ok());
+        assert!(ProtocolLimits::new(note_size, update_size).validate(&tx).is_ok());
+        assert_eq!(
+            ProtocolLimits::new(note_size - 1, update_size).validate(&tx),
+            Err(ProvenTransactionError::NotePayloadTooLarge {
+                note_id: note.id(),
+                max: note_size - 1,
+                actual: note_size
+            })
+        );
+        assert_eq!(
+            ProtocolLimits::new(note_size, update_size - 1).validate(&tx),
+            Err(ProvenTransactionError::AccountUpdateTooLarge {
+                account_id,
+                max: update_size - 1,
+                actual: update_size
+            })
+        );
+    }
+
+    #[test]
+    fn account_update_compression() {
+        let storage_items = (0..200).map(|i| [Felt::new(i), ONE, ZERO, ZERO]).collect();
+        let account = build_account(vec![], ONE, storage_items, None);
+        let account_update = TxAccountUpdate::new(
+            account.id(),
+            Digest::default(),
+            account.hash(),
+            AccountUpdateDetails::New(account),
+        );
+
+        let bytes = account_update.to_bytes();
+        let mut compressed = Vec::new();
+        write_compressed(&mut compressed, &bytes);
+        assert!(compressed.len() < bytes.len() / 2);
+
+        let decompressed: TxAccountUpdate =
+            read_compressed_payload(&mut SliceReader::new(&compressed), bytes.len()).unwrap();
+        assert_eq!(decompressed, account_update);
+
+        // payloads larger than the bound of the reader are rejected
+        assert!(read_compressed_payload::<TxAccountUpdate, _>(
+            &mut SliceReader::new(&compressed),
+            bytes.len() - 1
+        )
+        .is_err());
+
+        // trailing bytes in a compressed payload are rejected
+        let mut compressed = Vec::new();
+        write_compressed(&mut compressed, &[bytes.as_slice(), &[0]].concat());
+        assert!(read_compressed_payload::<TxAccountUpdate, _>(
+            &mut SliceReader::new(&compressed),
+            MAX_ACCOUNT_UPDATE_SIZE
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn output_notes_compression() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let metadata = NoteMetadata::new(
+            account_id,
+            NoteType::OffChain,
+            NoteTag::for_local_use_case(0, 0).unwrap(),
+            ZERO,
+        )
+        .unwrap();
+        let output_notes = OutputNotes::new(
+            (0..3)
+                .map(|i| {
+                    let note_id = NoteId::from(Digest::from([Felt::new(i), ZERO, ZERO, ZERO]));
+                    OutputNote::Header(NoteHeader::new(note_id, metadata))
+                })
+                .collect(),
+        )
+        .unwrap();
+
+        let mut bytes = Vec::new();
+        write_compressed_output_notes(&mut bytes, &output_notes);
+        let decompressed = read_compressed_output_notes(&mut SliceReader::new(&bytes)).unwrap();
+        assert_eq!(decompressed, output_notes);
+
+        // a note expanding beyond the maximum note payload size is rejected before its payload is
+        // decompressed
+        let mut bytes = Vec::new();
+        bytes.write_u16(1);
+        bytes.write_usize(MAX_NOTE_PAYLOAD_SIZE + 1);
+        bytes.write_usize((MAX_NOTE_PAYLOAD_SIZE + 1) << 1);
+        assert!(read_compressed_output_notes(&mut SliceReader::new(&bytes)).is_err());
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    fn builder(
+        account_id: AccountId,
+        initial_account_hash: Digest,
+        final_account_hash: Digest,
+    ) -> ProvenTransactionBuilder {
+        ProvenTransactionBuilder::new(
+            account_id,
+            initial_account_hash,
+            final_account_hash,
+            Digest::default(),
+            KernelVersion::new(0, 1, 0, Digest::default()),
+            dummy_proof(),
+        )
+    }
+
+    fn initial_hash() -> Digest {
+        Digest::from([ONE, ZERO, ZERO, ZERO])
+    }
+
+    fn final_hash() -> Digest {
+        Digest::from([ONE, ONE, ZERO, ZERO])
+    }
+
+    fn dummy_proof() -> ExecutionProof {
+        // the hash function is deserialized from its byte encoding (BLAKE3-192)
+        ExecutionProof::new(StarkProof::new_dummy(), Deserializable::read_from_bytes(&[0]).unwrap())
+    }
 }
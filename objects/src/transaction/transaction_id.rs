@@ -13,11 +13,14 @@ use crate::utils::serde::{
 ///
 /// Transaction ID is computed as:
 ///
-/// hash(init_account_hash, final_account_hash, input_notes_hash, output_notes_hash)
+/// hash(init_account_hash, final_account_hash, input_notes_hash, output_notes_hash, block_ref)
 ///
 /// This achieves the following properties:
 /// - Transactions are identical if and only if they have the same ID.
 /// - Computing transaction ID can be done solely from public transaction data.
+/// - Executed and proven transactions have the same ID, so the ID can be used as the canonical key
+///   for deduplicating and ordering transactions (e.g., in mempools); transactions are ordered by
+///   their IDs.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TransactionId(Digest);
 
@@ -28,12 +31,14 @@ impl TransactionId {
         final_account_hash: Digest,
         input_notes_hash: Digest,
         output_notes_hash: Digest,
+        block_ref: Digest,
     ) -> Self {
-        let mut elements = [ZERO; 4 * WORD_SIZE];
+        let mut elements = [ZERO; 5 * WORD_SIZE];
         elements[..4].copy_from_slice(init_account_hash.as_elements());
         elements[4..8].copy_from_slice(final_account_hash.as_elements());
         elements[8..12].copy_from_slice(input_notes_hash.as_elements());
-        elements[12..].copy_from_slice(output_notes_hash.as_elements());
+        elements[12..16].copy_from_slice(output_notes_hash.as_elements());
+        elements[16..].copy_from_slice(block_ref.as_elements());
         Self(Hasher::hash_elements(&elements))
     }
 
@@ -80,6 +85,7 @@ impl From<&ProvenTransaction> for TransactionId {
             tx.account_update().final_state_hash(),
             tx.input_notes().commitment(),
             tx.output_notes().commitment(),
+            tx.block_ref(),
         )
     }
 }
@@ -89,10 +95,11 @@ impl From<&ExecutedTransaction> for TransactionId {
         let input_notes_hash = tx.input_notes().commitment();
         let output_notes_hash = tx.output_notes().commitment();
         Self::new(
-            tx.initial_account().init_hash(),
-            tx.final_account().hash(),
+            tx.tx_inputs().account_init_commitment(tx.tx_args().init_account_salt()),
+            tx.final_account_commitment(),
             input_notes_hash,
             output_notes_hash,
+            tx.block_header().hash(),
         )
     }
 }
@@ -8,7 +8,7 @@ use crate::{
     assembly::{Assembler, AssemblyContext, ProgramAst},
     notes::{NoteDetails, NoteId, NoteInputs},
     vm::CodeBlock,
-    TransactionScriptError,
+    TransactionArgsError, TransactionScriptError,
 };
 
 // TRANSACTION ARGS
@@ -22,11 +22,15 @@ use crate::{
 ///   are different from note inputs, as the user executing the transaction can specify arbitrary
 ///   note args.
 /// - Advice map: Provides data needed by the runtime, like the details of a public note.
+/// - Account salts: the salts of the commitments to the initial and final states of an off-chain
+///   account recorded on-chain, see [salt_account_hash()](crate::accounts::salt_account_hash).
 #[derive(Clone, Debug, Default)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
     note_args: BTreeMap<NoteId, Word>,
     advice_map: AdviceMap,
+    init_account_salt: Option<Word>,
+    final_account_salt: Option<Word>,
 }
 
 impl TransactionArgs {
@@ -52,6 +56,8 @@ impl TransactionArgs {
             tx_script,
             note_args: note_args.unwrap_or_default(),
             advice_map,
+            init_account_salt: None,
+            final_account_salt: None,
         }
     }
 
@@ -107,6 +113,121 @@ impl TransactionArgs {
         }
     }
 
+    /// Extends the advice map with the provided key-value pairs.
+    ///
+    /// # Errors
+    /// Returns an error if any of the provided keys is already present in the advice map with a
+    /// different value, or if the provided entries map the same key to different values. In this
+    /// case, the advice map is left unchanged.
+    pub fn extend_advice_map<T>(&mut self, entries: T) -> Result<(), TransactionArgsError>
+    where
+        T: IntoIterator<Item = (Digest, Vec<Felt>)>,
+    {
+        let entries: Vec<(Digest, Vec<Felt>)> = entries.into_iter().collect();
+        self.check_advice_map_conflicts(&entries)?;
+
+        self.advice_map.extend(entries);
+        Ok(())
+    }
+
+    /// Extends the note arguments with the provided arguments.
+    ///
+    /// # Errors
+    /// Returns an error if arguments for any of the provided notes have already been specified
+    /// with a different value. In this case, the note arguments are left unchanged.
+    pub fn extend_note_args(
+        &mut self,
+        note_args: BTreeMap<NoteId, Word>,
+    ) -> Result<(), TransactionArgsError> {
+        self.check_note_args_conflicts(&note_args)?;
+
+        self.note_args.extend(note_args);
+        Ok(())
+    }
+
+    /// Sets the salts of the commitments to the account state recorded on-chain.
+    ///
+    /// `init_salt` is the salt with which the commitment to the current state of the account was
+    /// recorded, and `final_salt` is the salt with which the commitment to the state of the account
+    /// after the transaction is to be recorded. None, or an empty salt, means that the respective
+    /// commitment is the unsalted account hash.
+    ///
+    /// Salts can be used only with off-chain accounts; the final salt of a transaction must be
+    /// provided as the initial salt of the next transaction against the account.
+    pub fn set_account_salts(&mut self, init_salt: Option<Word>, final_salt: Option<Word>) {
+        self.init_account_salt = init_salt;
+        self.final_account_salt = final_salt;
+    }
+
+    /// Merges the provided transaction arguments into these transaction arguments.
+    ///
+    /// The transaction script and the account salts of the provided arguments are used only if
+    /// these arguments do not specify them. Note arguments and advice map entries are combined,
+    /// with entries present in both sets of arguments required to have the same values.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Both sets of arguments specify different transaction scripts.
+    /// - Both sets of arguments specify different initial or final account salts.
+    /// - Both sets of arguments specify different arguments for the same note.
+    /// - Both sets of arguments specify different advice map values for the same key.
+    ///
+    /// In case of an error, these transaction arguments are left unchanged.
+    pub fn merge(&mut self, other: TransactionArgs) -> Result<(), TransactionArgsError> {
+        if let (Some(current), Some(other)) = (&self.tx_script, &other.tx_script) {
+            if current.hash() != other.hash() {
+                return Err(TransactionArgsError::TransactionScriptConflict {
+                    current: *current.hash(),
+                    other: *other.hash(),
+                });
+            }
+        }
+        for (current, other) in [
+            (self.init_account_salt, other.init_account_salt),
+            (self.final_account_salt, other.final_account_salt),
+        ] {
+            if let (Some(current), Some(other)) = (current, other) {
+                if current != other {
+                    return Err(TransactionArgsError::AccountSaltConflict { current, other });
+                }
+            }
+        }
+        self.check_note_args_conflicts(&other.note_args)?;
+        let advice_entries: Vec<(Digest, Vec<Felt>)> = other.advice_map.into_iter().collect();
+        self.check_advice_map_conflicts(&advice_entries)?;
+
+        if self.tx_script.is_none() {
+            self.tx_script = other.tx_script;
+        }
+        self.init_account_salt = self.init_account_salt.or(other.init_account_salt);
+        self.final_account_salt = self.final_account_salt.or(other.final_account_salt);
+        self.note_args.extend(other.note_args);
+        self.advice_map.extend(advice_entries);
+        Ok(())
+    }
+
+    /// Removes all entries from the advice map, including the transaction script inputs.
+    pub(crate) fn clear_advice_map(&mut self) {
+        self.advice_map = AdviceMap::default();
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that note arguments have been specified for all of the provided notes.
+    ///
+    /// # Errors
+    /// Returns an error if arguments for any of the provided notes are missing.
+    pub fn validate_note_args<T>(&self, note_ids: T) -> Result<(), TransactionArgsError>
+    where
+        T: IntoIterator<Item = NoteId>,
+    {
+        match note_ids.into_iter().find(|note_id| !self.note_args.contains_key(note_id)) {
+            Some(note_id) => Err(TransactionArgsError::MissingNoteArgs(note_id)),
+            None => Ok(()),
+        }
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -124,6 +245,51 @@ impl TransactionArgs {
     pub fn advice_map(&self) -> &AdviceMap {
         &self.advice_map
     }
+
+    /// Returns the salt of the commitment to the account state before the transaction.
+    pub fn init_account_salt(&self) -> Option<Word> {
+        self.init_account_salt
+    }
+
+    /// Returns the salt of the commitment to the account state after the transaction.
+    pub fn final_account_salt(&self) -> Option<Word> {
+        self.final_account_salt
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an error if any of the provided notes already has different arguments specified.
+    fn check_note_args_conflicts(
+        &self,
+        note_args: &BTreeMap<NoteId, Word>,
+    ) -> Result<(), TransactionArgsError> {
+        match note_args
+            .iter()
+            .find(|(note_id, args)| self.note_args.get(note_id).is_some_and(|cur| cur != *args))
+        {
+            Some((note_id, _)) => Err(TransactionArgsError::NoteArgsConflict(*note_id)),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns an error if any of the provided keys is already mapped to a different value in the
+    /// advice map, or if the provided entries map the same key to different values.
+    fn check_advice_map_conflicts(
+        &self,
+        entries: &[(Digest, Vec<Felt>)],
+    ) -> Result<(), TransactionArgsError> {
+        let mut batch: BTreeMap<Digest, &[Felt]> = BTreeMap::new();
+        for (key, value) in entries {
+            let cur = self.advice_map.get(key).or_else(|| batch.get(key).copied());
+            if cur.is_some_and(|cur| cur != value.as_slice()) {
+                return Err(TransactionArgsError::AdviceMapKeyConflict(*key));
+            }
+            batch.insert(*key, value.as_slice());
+        }
+
+        Ok(())
+    }
 }
 
 // TRANSACTION SCRIPT
@@ -207,3 +373,95 @@ impl TransactionScript {
         &self.inputs
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, vec::Vec};
+
+    use super::{TransactionArgs, TransactionScript};
+    use crate::{
+        assembly::ProgramAst, notes::NoteId, Digest, Felt, TransactionArgsError, ONE, ZERO,
+    };
+
+    #[test]
+    fn test_extend_advice_map() {
+        let key = Digest::from([ONE, ZERO, ZERO, ZERO]);
+        let mut tx_args = TransactionArgs::default();
+
+        tx_args.extend_advice_map([(key, vec![ONE, ONE])]).unwrap();
+        // re-inserting the same value is not a conflict
+        tx_args.extend_advice_map([(key, vec![ONE, ONE])]).unwrap();
+
+        let other_key = Digest::from([ZERO, ONE, ZERO, ZERO]);
+        let result = tx_args.extend_advice_map([(other_key, vec![ONE]), (key, vec![ZERO])]);
+        assert_eq!(result, Err(TransactionArgsError::AdviceMapKeyConflict(key)));
+
+        // the advice map is not modified on conflict
+        assert_eq!(tx_args.advice_map().get(&key), Some([ONE, ONE].as_slice()));
+        assert_eq!(tx_args.advice_map().get(&other_key), None);
+
+        // the provided entries must not conflict with each other either
+        let result = tx_args.extend_advice_map([
+            (other_key, vec![ONE]),
+            (other_key, vec![ONE]),
+            (other_key, vec![ZERO]),
+        ]);
+        assert_eq!(result, Err(TransactionArgsError::AdviceMapKeyConflict(other_key)));
+        assert_eq!(tx_args.advice_map().get(&other_key), None);
+    }
+
+    #[test]
+    fn test_merge_tx_args() {
+        let note_1 = NoteId::from([ONE, ZERO, ZERO, ZERO]);
+        let note_2 = NoteId::from([ZERO, ONE, ZERO, ZERO]);
+        let key = Digest::from([ONE, ONE, ZERO, ZERO]);
+
+        let mut tx_args = TransactionArgs::with_note_args(BTreeMap::from([(note_1, [ONE; 4])]));
+        let mut other = TransactionArgs::with_tx_script(mock_tx_script(1));
+        other.extend_note_args(BTreeMap::from([(note_2, [ZERO; 4])])).unwrap();
+        other.extend_advice_map([(key, vec![ONE])]).unwrap();
+
+        tx_args.merge(other).unwrap();
+        assert_eq!(tx_args.tx_script().map(|script| *script.hash()), Some(mock_tx_script(1).hash));
+        assert_eq!(tx_args.get_note_args(note_1), Some(&[ONE; 4]));
+        assert_eq!(tx_args.get_note_args(note_2), Some(&[ZERO; 4]));
+        assert_eq!(tx_args.advice_map().get(&key), Some([ONE].as_slice()));
+        assert!(tx_args.validate_note_args([note_1, note_2]).is_ok());
+
+        // conflicting note args
+        let other = TransactionArgs::with_note_args(BTreeMap::from([(note_2, [ONE; 4])]));
+        assert_eq!(tx_args.merge(other), Err(TransactionArgsError::NoteArgsConflict(note_2)));
+
+        // conflicting transaction scripts
+        let other = TransactionArgs::with_tx_script(mock_tx_script(2));
+        assert_eq!(
+            tx_args.merge(other),
+            Err(TransactionArgsError::TransactionScriptConflict {
+                current: mock_tx_script(1).hash,
+                other: mock_tx_script(2).hash
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_note_args() {
+        let note_1 = NoteId::from([ONE, ZERO, ZERO, ZERO]);
+        let note_2 = NoteId::from([ZERO, ONE, ZERO, ZERO]);
+        let tx_args = TransactionArgs::with_note_args(BTreeMap::from([(note_1, [ONE; 4])]));
+
+        assert!(tx_args.validate_note_args([note_1]).is_ok());
+        assert_eq!(
+            tx_args.validate_note_args([note_1, note_2]),
+            Err(TransactionArgsError::MissingNoteArgs(note_2))
+        );
+    }
+
+    fn mock_tx_script(seed: u64) -> TransactionScript {
+        let code = ProgramAst::parse("begin push.1 drop end").unwrap();
+        let hash = Digest::from([Felt::new(seed), ZERO, ZERO, ZERO]);
+        TransactionScript::from_parts(code, hash, Vec::new()).unwrap()
+    }
+}
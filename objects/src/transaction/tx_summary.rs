@@ -0,0 +1,317 @@
+use alloc::{format, vec::Vec};
+
+use super::{AccountDelta, Digest, Felt, Hasher, Word, ZERO};
+use crate::{
+    accounts::AccountVaultDelta,
+    assets::Asset,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    AccountDeltaError, ONE,
+};
+
+// VAULT CHANGE
+// ================================================================================================
+
+/// A change made to the vault of an account by a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VaultChange {
+    /// The asset was added to the vault.
+    Added(Asset),
+    /// The asset was removed from the vault.
+    Removed(Asset),
+}
+
+impl VaultChange {
+    /// Returns the commitment to this change, computed as hash(ASSET, [is_added, 0, 0, 0]).
+    pub fn commitment(&self) -> Digest {
+        let (asset, is_added) = match self {
+            Self::Added(asset) => (asset, ONE),
+            Self::Removed(asset) => (asset, ZERO),
+        };
+        Hasher::merge(&[Word::from(*asset).into(), [is_added, ZERO, ZERO, ZERO].into()])
+    }
+}
+
+// STORAGE CHANGE
+// ================================================================================================
+
+/// A change made to the storage of an account by a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageChange {
+    /// The storage slot at `index` was set to `value`.
+    Item { index: u8, value: Word },
+    /// The entry for `key` of the storage map at `index` was set to `value`.
+    MapItem { index: u8, key: Word, value: Word },
+}
+
+impl StorageChange {
+    /// Returns the commitment to this change, computed as hash(VALUE, [index, 0, 0, 0]) for
+    /// storage slots, and as hash(hash(KEY, VALUE), [index, 1, 0, 0]) for storage map entries.
+    pub fn commitment(&self) -> Digest {
+        match self {
+            Self::Item { index, value } => {
+                Hasher::merge(&[(*value).into(), [Felt::from(*index), ZERO, ZERO, ZERO].into()])
+            },
+            Self::MapItem { index, key, value } => Hasher::merge(&[
+                Hasher::merge(&[(*key).into(), (*value).into()]),
+                [Felt::from(*index), ONE, ZERO, ZERO].into(),
+            ]),
+        }
+    }
+}
+
+// TRANSACTION SUMMARY
+// ================================================================================================
+
+/// A summary of the changes made by a transaction, to which the message signed by the
+/// authentication procedures of the Miden library commits.
+///
+/// The commitment to the summary is computed as:
+///
+/// hash(output_notes_hash, hash(input_notes_hash, account_delta_commitment))
+///
+/// where the commitment to the account delta is computed as:
+///
+/// hash(hash(vault_delta_commitment, storage_delta_commitment), [nonce, 0, 0, 0])
+///
+/// The commitments to the vault and storage deltas are sequential hashes over the changes made to
+/// the account vault and the account storage, in the order in which the transaction kernel made
+/// them, i.e., `commitment = hash(commitment, change.commitment())` starting from the empty word.
+/// The nonce is the nonce of the account at the time the message is signed, i.e., after the nonce
+/// was incremented.
+///
+/// This allows signers to verify that the changes they present to the user are the changes which
+/// get signed, by recomputing the message via [TransactionSummary::to_commitment()], without
+/// access to the state of the account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionSummary {
+    vault_changes: Vec<VaultChange>,
+    storage_changes: Vec<StorageChange>,
+    nonce: Felt,
+    input_notes_hash: Digest,
+    output_notes_hash: Digest,
+}
+
+impl TransactionSummary {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionSummary] instantiated from the provided transaction components.
+    pub fn new(
+        vault_changes: Vec<VaultChange>,
+        storage_changes: Vec<StorageChange>,
+        nonce: Felt,
+        input_notes_hash: Digest,
+        output_notes_hash: Digest,
+    ) -> Self {
+        Self {
+            vault_changes,
+            storage_changes,
+            nonce,
+            input_notes_hash,
+            output_notes_hash,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the changes made to the account vault by the transaction, in the order in which
+    /// they were made.
+    pub fn vault_changes(&self) -> &[VaultChange] {
+        &self.vault_changes
+    }
+
+    /// Returns the changes made to the account storage by the transaction, in the order in which
+    /// they were made.
+    pub fn storage_changes(&self) -> &[StorageChange] {
+        &self.storage_changes
+    }
+
+    /// Returns the nonce of the account after the transaction.
+    pub fn nonce(&self) -> Felt {
+        self.nonce
+    }
+
+    /// Returns the commitment to the notes consumed by the transaction.
+    pub fn input_notes_hash(&self) -> Digest {
+        self.input_notes_hash
+    }
+
+    /// Returns the commitment to the notes created by the transaction.
+    pub fn output_notes_hash(&self) -> Digest {
+        self.output_notes_hash
+    }
+
+    /// Returns the net changes made to the account vault by the transaction.
+    ///
+    /// # Errors
+    /// Returns an error if the vault changes cannot be normalized (see
+    /// [AccountVaultDelta::normalize()]).
+    pub fn vault_delta(&self) -> Result<AccountVaultDelta, AccountDeltaError> {
+        let mut added_assets = Vec::new();
+        let mut removed_assets = Vec::new();
+        for change in self.vault_changes.iter() {
+            match change {
+                VaultChange::Added(asset) => added_assets.push(*asset),
+                VaultChange::Removed(asset) => removed_assets.push(*asset),
+            }
+        }
+
+        AccountVaultDelta::from_iterators(added_assets, removed_assets).normalize()
+    }
+
+    /// Returns the sequential commitment to the changes made to the account vault.
+    pub fn vault_delta_commitment(&self) -> Digest {
+        self.vault_changes.iter().fold(Digest::default(), |commitment, change| {
+            Hasher::merge(&[commitment, change.commitment()])
+        })
+    }
+
+    /// Returns the sequential commitment to the changes made to the account storage.
+    pub fn storage_delta_commitment(&self) -> Digest {
+        self.storage_changes.iter().fold(Digest::default(), |commitment, change| {
+            Hasher::merge(&[commitment, change.commitment()])
+        })
+    }
+
+    /// Returns the commitment to the account delta, computed as
+    /// hash(hash(vault_delta_commitment, storage_delta_commitment), [nonce, 0, 0, 0]).
+    pub fn account_delta_commitment(&self) -> Digest {
+        let vault_and_storage =
+            Hasher::merge(&[self.vault_delta_commitment(), self.storage_delta_commitment()]);
+        Hasher::merge(&[vault_and_storage, [self.nonce, ZERO, ZERO, ZERO].into()])
+    }
+
+    /// Returns the commitment to this summary, which is the message signed to authenticate the
+    /// transaction.
+    pub fn to_commitment(&self) -> Digest {
+        let notes_and_delta =
+            Hasher::merge(&[self.input_notes_hash, self.account_delta_commitment()]);
+        Hasher::merge(&[self.output_notes_hash, notes_and_delta])
+    }
+
+    /// Returns true if the vault delta and the nonce of the provided account delta match the
+    /// changes of this summary.
+    ///
+    /// The storage delta is not checked, since it omits the storage map entries which were set
+    /// back to their initial values, which cannot be told apart from the changes alone.
+    pub fn matches_account_delta(&self, account_delta: &AccountDelta) -> bool {
+        let Ok(vault_delta) = self.vault_delta() else {
+            return false;
+        };
+        let expected = account_delta.vault();
+
+        vault_delta.added_assets.len() == expected.added_assets.len()
+            && vault_delta.removed_assets.len() == expected.removed_assets.len()
+            && vault_delta
+                .added_assets
+                .iter()
+                .all(|asset| expected.added_assets.contains(asset))
+            && vault_delta
+                .removed_assets
+                .iter()
+                .all(|asset| expected.removed_assets.contains(asset))
+            && account_delta.nonce().map_or(true, |nonce| nonce == self.nonce)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for VaultChange {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Added(asset) => {
+                target.write_u8(0);
+                asset.write_into(target);
+            },
+            Self::Removed(asset) => {
+                target.write_u8(1);
+                asset.write_into(target);
+            },
+        }
+    }
+}
+
+impl Deserializable for VaultChange {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::Added(Asset::read_from(source)?)),
+            1 => Ok(Self::Removed(Asset::read_from(source)?)),
+            tag => {
+                Err(DeserializationError::InvalidValue(format!("invalid vault change tag {tag}")))
+            },
+        }
+    }
+}
+
+impl Serializable for StorageChange {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Item { index, value } => {
+                target.write_u8(0);
+                target.write_u8(*index);
+                value.write_into(target);
+            },
+            Self::MapItem { index, key, value } => {
+                target.write_u8(1);
+                target.write_u8(*index);
+                key.write_into(target);
+                value.write_into(target);
+            },
+        }
+    }
+}
+
+impl Deserializable for StorageChange {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => {
+                let index = source.read_u8()?;
+                let value = Word::read_from(source)?;
+                Ok(Self::Item { index, value })
+            },
+            1 => {
+                let index = source.read_u8()?;
+                let key = Word::read_from(source)?;
+                let value = Word::read_from(source)?;
+                Ok(Self::MapItem { index, key, value })
+            },
+            tag => {
+                Err(DeserializationError::InvalidValue(format!("invalid storage change tag {tag}")))
+            },
+        }
+    }
+}
+
+impl Serializable for TransactionSummary {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.vault_changes.len());
+        target.write_many(self.vault_changes.iter());
+        target.write_usize(self.storage_changes.len());
+        target.write_many(self.storage_changes.iter());
+        self.nonce.write_into(target);
+        self.input_notes_hash.write_into(target);
+        self.output_notes_hash.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionSummary {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_vault_changes = source.read_usize()?;
+        let vault_changes = source.read_many::<VaultChange>(num_vault_changes)?;
+        let num_storage_changes = source.read_usize()?;
+        let storage_changes = source.read_many::<StorageChange>(num_storage_changes)?;
+        let nonce = Felt::read_from(source)?;
+        let input_notes_hash = Digest::read_from(source)?;
+        let output_notes_hash = Digest::read_from(source)?;
+
+        Ok(Self::new(
+            vault_changes,
+            storage_changes,
+            nonce,
+            input_notes_hash,
+            output_notes_hash,
+        ))
+    }
+}
@@ -19,14 +19,17 @@ use super::{
 /// - Advice witness which contains all data requested by the VM from the advice provider while
 ///   executing the transaction program.
 ///
-/// TODO: currently, the advice witness contains redundant and irrelevant data (e.g., tx inputs
-/// and tx outputs). we should optimize it to contain only the minimum data required for
-/// executing/proving the transaction.
+/// By default, the advice inputs for re-executing the transaction are built from the transaction
+/// inputs and arguments, and are then extended with the advice witness. Thus, they contain the
+/// full account, chain MMR, and note data even if only a small part of it was accessed during
+/// execution. The advice inputs of a witness can be reduced to the accessed data via
+/// [TransactionWitness::minimize()].
 pub struct TransactionWitness {
     program: Program,
     tx_inputs: TransactionInputs,
     tx_args: TransactionArgs,
     advice_witness: AdviceInputs,
+    is_minimal: bool,
 }
 
 impl TransactionWitness {
@@ -44,9 +47,37 @@ impl TransactionWitness {
             tx_inputs,
             tx_args,
             advice_witness,
+            is_minimal: false,
         }
     }
 
+    // MINIMIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a minimized version of this witness, whose advice inputs contain only the advice
+    /// data which was accessed while executing the transaction.
+    ///
+    /// The advice witness recorded by the transaction executor contains the initial advice stack
+    /// and only the advice map entries and Merkle store nodes which were read during execution.
+    /// In a minimized witness, the advice witness is the only source of advice data: the advice
+    /// inputs derived from the transaction inputs are no longer added to it, and the advice map of
+    /// the transaction arguments is cleared. This reduces the amount of advice data which must be
+    /// loaded to re-execute and prove the transaction.
+    ///
+    /// Only the advice map of the transaction arguments is removed from the witness itself. The
+    /// transaction inputs are retained in full, as they are needed to build the proven transaction
+    /// (e.g., the initial account state and the input notes), and thus minimization does not
+    /// reduce the size of the transaction inputs shipped with the witness.
+    ///
+    /// The advice witness must have been recorded while executing this transaction, as is the
+    /// case for witnesses obtained from an [ExecutedTransaction](super::ExecutedTransaction);
+    /// otherwise, re-executing the minimized witness fails.
+    pub fn minimize(mut self) -> Self {
+        self.tx_args.clear_advice_map();
+        self.is_minimal = true;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -85,4 +116,10 @@ impl TransactionWitness {
     pub fn advice_witness(&self) -> &AdviceInputs {
         &self.advice_witness
     }
+
+    /// Returns true if this witness was minimized, i.e., if the advice witness is the only source
+    /// of advice data for re-executing the transaction.
+    pub fn is_minimal(&self) -> bool {
+        self.is_minimal
+    }
 }
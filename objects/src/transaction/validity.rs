@@ -0,0 +1,319 @@
+use super::{KernelVersion, ProtocolLimits, ProvenTransaction};
+use crate::{
+    accounts::AccountId, notes::Nullifier, Digest, ProvenTransactionError,
+    TransactionValidityError, MAX_INPUT_NOTES_PER_TX, MAX_OUTPUT_NOTES_PER_TX,
+    MAX_REFERENCE_BLOCK_DEPTH,
+};
+
+// CHAIN STATE
+// ================================================================================================
+
+/// The state of the chain against which transactions are validated by
+/// [ProvenTransaction::check_stateful()].
+pub trait ChainState {
+    /// Returns the commitment to the current state of the specified account recorded in the
+    /// account tree, or None if the account is not in the tree.
+    fn account_hash(&self, account_id: AccountId) -> Option<Digest>;
+
+    /// Returns `true` if the specified nullifier is recorded in the nullifier tree.
+    fn is_nullifier_spent(&self, nullifier: Nullifier) -> bool;
+
+    /// Returns the number of the block with the specified hash, or None if the block is not in
+    /// the chain.
+    fn block_num(&self, block_hash: Digest) -> Option<u32>;
+
+    /// Returns the number of the latest block in the chain.
+    fn chain_tip(&self) -> u32;
+
+    /// Returns the maximum number of blocks by which the reference block of a transaction may lag
+    /// behind the chain tip.
+    fn max_reference_block_depth(&self) -> u32 {
+        MAX_REFERENCE_BLOCK_DEPTH
+    }
+}
+
+// TRANSACTION PROOF VERIFIER
+// ================================================================================================
+
+/// Verifies the proofs of transactions against the transaction kernels they were proven with.
+///
+/// Used by [ProvenTransaction::check_stateless()], as the transaction kernel is not known to this
+/// crate.
+pub trait TransactionProofVerifier {
+    /// Returns `true` if this verifier can verify the transactions proven with the specified
+    /// version of the transaction kernel.
+    fn supports_kernel(&self, version: KernelVersion) -> bool;
+
+    /// Verifies the proof of the provided transaction.
+    ///
+    /// # Errors
+    /// Returns an error if the proof is invalid or if its security level is insufficient.
+    fn verify_proof(&self, transaction: &ProvenTransaction)
+        -> Result<(), TransactionValidityError>;
+}
+
+// PROVEN TRANSACTION VALIDATION
+// ================================================================================================
+
+impl ProvenTransaction {
+    /// Checks the validity of this transaction independently of the state of the chain.
+    ///
+    /// The checks are performed in order of increasing cost, so that a mempool can reject invalid
+    /// transactions before verifying their proofs. A transaction which passes these checks remains
+    /// valid regardless of the changes to the chain, and thus needs to be checked only once.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The transaction consumes or creates more notes than allowed by the protocol.
+    /// - The transaction consumes a note more than once.
+    /// - The transaction exceeds the provided protocol limits.
+    /// - The transaction was proven with a kernel not supported by the provided verifier.
+    /// - The proof of the transaction is invalid.
+    pub fn check_stateless(
+        &self,
+        verifier: &impl TransactionProofVerifier,
+        limits: &ProtocolLimits,
+    ) -> Result<(), TransactionValidityError> {
+        if self.input_notes().num_notes() > MAX_INPUT_NOTES_PER_TX {
+            return Err(TransactionValidityError::InvalidTransaction(
+                ProvenTransactionError::TooManyInputNotes {
+                    max: MAX_INPUT_NOTES_PER_TX,
+                    actual: self.input_notes().num_notes(),
+                },
+            ));
+        }
+        if self.output_notes().num_notes() > MAX_OUTPUT_NOTES_PER_TX {
+            return Err(TransactionValidityError::InvalidTransaction(
+                ProvenTransactionError::TooManyOutputNotes {
+                    max: MAX_OUTPUT_NOTES_PER_TX,
+                    actual: self.output_notes().num_notes(),
+                },
+            ));
+        }
+
+        let input_notes = self.input_notes();
+        for (pos, note) in input_notes.iter().enumerate() {
+            if input_notes.iter().take(pos).any(|x| x.nullifier() == note.nullifier()) {
+                return Err(TransactionValidityError::InvalidTransaction(
+                    ProvenTransactionError::DuplicateInputNoteNullifier(note.nullifier()),
+                ));
+            }
+        }
+
+        limits.validate(self).map_err(TransactionValidityError::InvalidTransaction)?;
+
+        if !verifier.supports_kernel(self.kernel_version()) {
+            return Err(TransactionValidityError::UnsupportedKernelVersion(self.kernel_version()));
+        }
+
+        verifier.verify_proof(self)
+    }
+
+    /// Checks the validity of this transaction against the provided state of the chain.
+    ///
+    /// These checks must be repeated whenever the chain advances. Unauthenticated input notes are
+    /// not checked, as they may be created by other transactions of the same block (see
+    /// [ProvenTransaction::unauthenticated_notes()]).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The initial account state hash of the transaction does not match the commitment recorded
+    ///   in the account tree, or, for new accounts, if the account is already in the tree.
+    /// - Any of the notes consumed by the transaction was already consumed.
+    /// - The reference block of the transaction is not in the chain, or lags behind the chain tip
+    ///   by more than the maximum reference block depth of the chain.
+    pub fn check_stateful(
+        &self,
+        chain_state: &impl ChainState,
+    ) -> Result<(), TransactionValidityError> {
+        let account_id = self.account_id();
+        let expected = chain_state.account_hash(account_id).unwrap_or_default();
+        let actual = self.account_update().init_state_hash();
+        if expected != actual {
+            return Err(TransactionValidityError::AccountHashMismatch {
+                account_id,
+                expected,
+                actual,
+            });
+        }
+
+        for note in self.input_notes().iter() {
+            if chain_state.is_nullifier_spent(note.nullifier()) {
+                return Err(TransactionValidityError::NullifierAlreadySpent(note.nullifier()));
+            }
+        }
+
+        let block_num = chain_state
+            .block_num(self.block_ref())
+            .ok_or(TransactionValidityError::UnknownReferenceBlock(self.block_ref()))?;
+        let chain_tip = chain_state.chain_tip();
+        if chain_tip.saturating_sub(block_num) > chain_state.max_reference_block_depth() {
+            return Err(TransactionValidityError::ReferenceBlockTooOld { block_num, chain_tip });
+        }
+
+        Ok(())
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, vec::Vec};
+
+    use miden_verifier::ExecutionProof;
+    use winter_air::proof::StarkProof;
+
+    use super::{ChainState, TransactionProofVerifier};
+    use crate::{
+        accounts::{account_id::testing::ACCOUNT_ID_OFF_CHAIN_SENDER, AccountId},
+        notes::Nullifier,
+        transaction::{
+            InputNoteCommitment, KernelVersion, ProtocolLimits, ProvenTransaction,
+            ProvenTransactionBuilder,
+        },
+        utils::serde::Deserializable,
+        Digest, TransactionValidityError, MAX_REFERENCE_BLOCK_DEPTH, ONE, ZERO,
+    };
+
+    struct MockChainState {
+        accounts: BTreeMap<AccountId, Digest>,
+        nullifiers: Vec<Nullifier>,
+        blocks: BTreeMap<Digest, u32>,
+        chain_tip: u32,
+    }
+
+    impl ChainState for MockChainState {
+        fn account_hash(&self, account_id: AccountId) -> Option<Digest> {
+            self.accounts.get(&account_id).copied()
+        }
+
+        fn is_nullifier_spent(&self, nullifier: Nullifier) -> bool {
+            self.nullifiers.contains(&nullifier)
+        }
+
+        fn block_num(&self, block_hash: Digest) -> Option<u32> {
+            self.blocks.get(&block_hash).copied()
+        }
+
+        fn chain_tip(&self) -> u32 {
+            self.chain_tip
+        }
+    }
+
+    struct MockVerifier {
+        kernel_version: KernelVersion,
+    }
+
+    impl TransactionProofVerifier for MockVerifier {
+        fn supports_kernel(&self, version: KernelVersion) -> bool {
+            version == self.kernel_version
+        }
+
+        fn verify_proof(&self, _: &ProvenTransaction) -> Result<(), TransactionValidityError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn check_stateless() {
+        let tx = transaction();
+        let verifier = MockVerifier { kernel_version: kernel_version() };
+        assert_eq!(tx.check_stateless(&verifier, &ProtocolLimits::default()), Ok(()));
+
+        let result = tx.check_stateless(&verifier, &ProtocolLimits::new(0, 0));
+        assert!(matches!(result, Err(TransactionValidityError::InvalidTransaction(_))));
+
+        let verifier = MockVerifier {
+            kernel_version: KernelVersion::new(0, 2, 0, Digest::default()),
+        };
+        assert_eq!(
+            tx.check_stateless(&verifier, &ProtocolLimits::default()),
+            Err(TransactionValidityError::UnsupportedKernelVersion(kernel_version()))
+        );
+    }
+
+    #[test]
+    fn check_stateful() {
+        let tx = transaction();
+        let chain_state = |account_hash, nullifiers, chain_tip| MockChainState {
+            accounts: BTreeMap::from([(tx.account_id(), account_hash)]),
+            nullifiers,
+            blocks: BTreeMap::from([(block_ref(), 10)]),
+            chain_tip,
+        };
+
+        assert_eq!(tx.check_stateful(&chain_state(initial_hash(), vec![], 10)), Ok(()));
+        assert_eq!(
+            tx.check_stateful(&chain_state(initial_hash(), vec![], 10 + MAX_REFERENCE_BLOCK_DEPTH)),
+            Ok(())
+        );
+
+        assert_eq!(
+            tx.check_stateful(&chain_state(Digest::default(), vec![], 10)),
+            Err(TransactionValidityError::AccountHashMismatch {
+                account_id: tx.account_id(),
+                expected: Digest::default(),
+                actual: initial_hash(),
+            })
+        );
+        assert_eq!(
+            tx.check_stateful(&chain_state(initial_hash(), vec![nullifier()], 10)),
+            Err(TransactionValidityError::NullifierAlreadySpent(nullifier()))
+        );
+        assert_eq!(
+            tx.check_stateful(&chain_state(initial_hash(), vec![], 11 + MAX_REFERENCE_BLOCK_DEPTH)),
+            Err(TransactionValidityError::ReferenceBlockTooOld {
+                block_num: 10,
+                chain_tip: 11 + MAX_REFERENCE_BLOCK_DEPTH,
+            })
+        );
+
+        let mut state = chain_state(initial_hash(), vec![], 10);
+        state.blocks.clear();
+        assert_eq!(
+            tx.check_stateful(&state),
+            Err(TransactionValidityError::UnknownReferenceBlock(block_ref()))
+        );
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    fn transaction() -> ProvenTransaction {
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        // the hash function is deserialized from its byte encoding (BLAKE3-192)
+        let proof = ExecutionProof::new(
+            StarkProof::new_dummy(),
+            Deserializable::read_from_bytes(&[0]).unwrap(),
+        );
+        ProvenTransactionBuilder::new(
+            account_id,
+            initial_hash(),
+            Digest::from([ONE, ONE, ZERO, ZERO]),
+            block_ref(),
+            kernel_version(),
+            proof,
+        )
+        .add_input_notes([InputNoteCommitment::from(nullifier())])
+        .build()
+        .unwrap()
+    }
+
+    fn initial_hash() -> Digest {
+        Digest::from([ONE, ZERO, ZERO, ZERO])
+    }
+
+    fn block_ref() -> Digest {
+        Digest::from([ZERO, ONE, ZERO, ZERO])
+    }
+
+    fn nullifier() -> Nullifier {
+        Nullifier::from(Digest::from([ONE, ONE, ONE, ZERO]))
+    }
+
+    fn kernel_version() -> KernelVersion {
+        KernelVersion::new(0, 1, 0, Digest::default())
+    }
+}
@@ -0,0 +1,17 @@
+// Runs the smoke test exported by the `miden-wasm-smoke` crate compiled for wasm32-unknown-unknown.
+//
+// Usage: node wasm-smoke/smoke.mjs <path to miden_wasm_smoke.wasm>
+
+import { readFile } from "node:fs/promises";
+
+const bytes = await readFile(process.argv[2]);
+const { instance } = await WebAssembly.instantiate(bytes, {});
+
+for (const name of ["execute_transaction", "prove_transaction"]) {
+    const status = instance.exports[name]();
+    if (status !== 0) {
+        console.error(`${name} failed with status ${status}`);
+        process.exit(1);
+    }
+    console.log(`${name}: ok`);
+}
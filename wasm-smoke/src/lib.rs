@@ -0,0 +1,234 @@
+//! Smoke test for executing and proving transactions on wasm32 targets.
+//!
+//! All Miden crates are used with default features disabled (i.e., as `no_std` + `alloc`
+//! libraries), which is how they are expected to be used in browser environments. The crate
+//! exports C-ABI functions which can be invoked from any wasm runtime, and exposes the same checks
+//! as Rust functions so that they also run as part of the native test suite.
+//!
+//! The transaction executed here is intentionally minimal: it runs a trivial transaction script
+//! against an existing account at the genesis block, without consuming any notes.
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    accounts::{Account, AccountCode, AccountId, AccountStorage},
+    assembly::{ModuleAst, ProgramAst},
+    assets::AssetVault,
+    crypto::merkle::{MmrPeaks, PartialMmr},
+    notes::NoteId,
+    transaction::{
+        ChainMmr, ExecutedTransaction, InputNote, InputNotes, ProvenTransaction, TransactionArgs,
+        TransactionInputs,
+    },
+    BlockHeader, Digest, Felt, Word, MIN_PROOF_SECURITY_LEVEL,
+};
+use miden_tx::{
+    DataStore, DataStoreError, ProvingOptions, TransactionExecutor, TransactionExecutorError,
+    TransactionProver, TransactionProverError, TransactionVerifier, TransactionVerifierError,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// ID of a regular on-chain account with updatable code.
+const ACCOUNT_ID: u64 = 0x1000_0000_0000_003f;
+
+const ACCOUNT_CODE: &str = "
+    export.account_procedure_1
+        push.1.2
+        add
+    end
+";
+
+const TX_SCRIPT: &str = "
+    begin
+        push.1 drop
+    end
+";
+
+// WASM EXPORTS
+// ================================================================================================
+
+/// Executes the smoke test transaction; returns 0 on success and 1 on failure.
+#[no_mangle]
+pub extern "C" fn execute_transaction() -> u32 {
+    status(execute_smoke_transaction().map(|_| ()))
+}
+
+/// Executes, proves, and verifies the smoke test transaction; returns 0 on success and 1 on
+/// failure.
+#[no_mangle]
+pub extern "C" fn prove_transaction() -> u32 {
+    status(prove_smoke_transaction().map(|_| ()))
+}
+
+fn status(result: Result<(), SmokeTestError>) -> u32 {
+    match result {
+        Ok(()) => 0,
+        Err(_) => 1,
+    }
+}
+
+// SMOKE TESTS
+// ================================================================================================
+
+/// Errors which can occur while running the smoke test.
+#[derive(Debug)]
+pub enum SmokeTestError {
+    Execution(TransactionExecutorError),
+    Proving(TransactionProverError),
+    Verification(TransactionVerifierError),
+}
+
+/// Executes a minimal transaction against an existing account.
+pub fn execute_smoke_transaction() -> Result<ExecutedTransaction, SmokeTestError> {
+    let data_store = SmokeDataStore::new();
+    let account_id = data_store.tx_inputs.account().id();
+    let block_ref = data_store.tx_inputs.block_header().block_num();
+
+    let mut executor: TransactionExecutor<_, ()> = TransactionExecutor::new(data_store, None);
+    executor.load_account(account_id).map_err(SmokeTestError::Execution)?;
+
+    let tx_script = ProgramAst::parse(TX_SCRIPT).expect("failed to parse transaction script");
+    let tx_script = executor
+        .compile_tx_script(tx_script, vec![], vec![])
+        .map_err(SmokeTestError::Execution)?;
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    executor
+        .execute_transaction(account_id, block_ref, &[], tx_args)
+        .map_err(SmokeTestError::Execution)
+}
+
+/// Executes, proves, and verifies a minimal transaction against an existing account.
+pub fn prove_smoke_transaction() -> Result<ProvenTransaction, SmokeTestError> {
+    let executed_transaction = execute_smoke_transaction()?;
+
+    let prover = TransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover
+        .prove_transaction(executed_transaction)
+        .map_err(SmokeTestError::Proving)?;
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    verifier
+        .verify(proven_transaction.clone())
+        .map_err(SmokeTestError::Verification)?;
+
+    Ok(proven_transaction)
+}
+
+// DATA STORE
+// ================================================================================================
+
+/// A data store holding the inputs of a single transaction against an account at genesis.
+struct SmokeDataStore {
+    tx_inputs: TransactionInputs,
+    account_code: ModuleAst,
+}
+
+impl SmokeDataStore {
+    fn new() -> Self {
+        let account_code = ModuleAst::parse(ACCOUNT_CODE).expect("failed to parse account code");
+        let code = AccountCode::new(account_code.clone(), &TransactionKernel::assembler())
+            .expect("failed to compile account code");
+        let storage = AccountStorage::new(vec![], vec![]).expect("failed to build storage");
+        let account = Account::new(
+            AccountId::try_from(ACCOUNT_ID).expect("invalid account ID"),
+            AssetVault::default(),
+            storage,
+            code,
+            Felt::new(1),
+        );
+
+        // the chain is empty at genesis, and so the chain MMR has no peaks
+        let peaks = MmrPeaks::new(0, vec![]).expect("failed to build MMR peaks");
+        let chain_root = peaks.hash_peaks();
+        let block_chain = ChainMmr::new(PartialMmr::from_peaks(peaks), vec![])
+            .expect("failed to build chain MMR");
+
+        let empty = Digest::default();
+        let block_header =
+            BlockHeader::new(0, empty, 0, chain_root, empty, empty, empty, empty, empty, 0);
+
+        let tx_inputs =
+            TransactionInputs::new(account, None, block_header, block_chain, InputNotes::default())
+                .expect("failed to build transaction inputs");
+
+        Self { tx_inputs, account_code }
+    }
+}
+
+impl DataStore for SmokeDataStore {
+    fn get_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(Account, Option<Word>), DataStoreError> {
+        if account_id != self.tx_inputs.account().id() {
+            return Err(DataStoreError::AccountNotFound(account_id));
+        }
+
+        Ok((self.tx_inputs.account().clone(), self.tx_inputs.account_seed()))
+    }
+
+    fn get_block_header(&self, block_num: u32) -> Result<BlockHeader, DataStoreError> {
+        if block_num != self.tx_inputs.block_header().block_num() {
+            return Err(DataStoreError::BlockNotFound(block_num));
+        }
+
+        Ok(*self.tx_inputs.block_header())
+    }
+
+    fn get_chain_mmr(&self, block_ref: u32, _blocks: &[u32]) -> Result<ChainMmr, DataStoreError> {
+        if block_ref != self.tx_inputs.block_header().block_num() {
+            return Err(DataStoreError::BlockNotFound(block_ref));
+        }
+
+        Ok(self.tx_inputs.block_chain().clone())
+    }
+
+    fn get_notes(&self, note_ids: &[NoteId]) -> Result<Vec<InputNote>, DataStoreError> {
+        note_ids
+            .iter()
+            .map(|&note_id| {
+                self.tx_inputs
+                    .input_notes()
+                    .iter()
+                    .find(|note| note.id() == note_id)
+                    .cloned()
+                    .ok_or(DataStoreError::NoteNotFound(note_id))
+            })
+            .collect()
+    }
+
+    fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError> {
+        if account_id != self.tx_inputs.account().id() {
+            return Err(DataStoreError::AccountNotFound(account_id));
+        }
+
+        Ok(self.account_code.clone())
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::ONE;
+
+    use super::{execute_smoke_transaction, prove_smoke_transaction};
+
+    #[test]
+    fn smoke_execute_transaction() {
+        let executed_transaction = execute_smoke_transaction().unwrap();
+
+        assert_eq!(executed_transaction.input_notes().num_notes(), 0);
+        assert_eq!(executed_transaction.account_delta().nonce(), None);
+        assert_eq!(executed_transaction.final_account().nonce(), ONE);
+    }
+
+    #[test]
+    fn smoke_prove_transaction() {
+        let proven_transaction = prove_smoke_transaction().unwrap();
+        assert_eq!(proven_transaction.input_notes().num_notes(), 0);
+    }
+}